@@ -0,0 +1,253 @@
+use std::hint::black_box;
+use std::net::{IpAddr, Ipv4Addr};
+use std::str::FromStr;
+use std::sync::{Arc, LazyLock as Lazy};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use holo_bgp::af::{AddressFamily, Ipv4Unicast};
+use holo_bgp::neighbor::{Neighbor, NeighborUpdateQueues, Neighbors, PeerType};
+use holo_bgp::packet::attribute::{AsPath, AsPathSegment, Attrs, BaseAttrs};
+use holo_bgp::packet::consts::{Afi, AsPathSegmentType, Origin, Safi};
+use holo_bgp::packet::message::{EncodeCxt, NegotiatedCapability};
+use holo_bgp::policy::{self, RoutePolicyInfo};
+use holo_bgp::rib::RouteOrigin;
+use holo_bgp::tasks::messages::input::PolicyResultMsg;
+use holo_bgp::tasks::messages::output::NbrTxMsg;
+use holo_bgp::update_group::UpdateGroups;
+use holo_utils::bgp::{AfiSafi, RouteType};
+use holo_utils::policy::{
+    BgpPolicyAction, BgpSetMed, DefaultPolicyType, MatchSets, Policies, Policy,
+    PolicyAction, PolicyResult, PolicyStmt, PolicyType,
+};
+use holo_utils::protocol::Protocol;
+use ipnetwork::{IpNetwork, Ipv4Network};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+const NUM_PEERS: usize = 500;
+const NUM_PREFIXES: u32 = 1000;
+const PEER_AS: u32 = 65001;
+
+static ATTRS: Lazy<Attrs> = Lazy::new(|| Attrs {
+    base: BaseAttrs {
+        origin: Origin::Igp,
+        as_path: AsPath {
+            segments: [AsPathSegment {
+                seg_type: AsPathSegmentType::Sequence,
+                members: [1, 2, 3].into(),
+            }]
+            .into(),
+        },
+        as4_path: None,
+        nexthop: Some(Ipv4Addr::from_str("10.0.1.1").unwrap().into()),
+        ll_nexthop: None,
+        med: None,
+        local_pref: None,
+        aggregator: None,
+        as4_aggregator: None,
+        atomic_aggregate: false,
+        originator_id: None,
+        cluster_list: None,
+    },
+    comm: None,
+    ext_comm: None,
+    extv6_comm: None,
+    large_comm: None,
+    ls: None,
+    unknown: Default::default(),
+});
+
+static CAPABILITIES: Lazy<Vec<NegotiatedCapability>> = Lazy::new(|| {
+    vec![
+        NegotiatedCapability::MultiProtocol {
+            afi: Afi::Ipv4,
+            safi: Safi::Unicast,
+        },
+        NegotiatedCapability::FourOctetAsNumber,
+    ]
+});
+
+// Export policy that sets the MED of all routes.
+static POLICIES: Lazy<Policies> = Lazy::new(|| {
+    let mut stmt = PolicyStmt::new("10".to_owned());
+    stmt.action_add(PolicyAction::Bgp(BgpPolicyAction::SetMed(
+        BgpSetMed::Set(100),
+    )));
+    stmt.action_add(PolicyAction::Accept(true));
+    let policy = Policy {
+        name: "EXPORT".to_owned(),
+        stmts: [(stmt.name.clone(), stmt)].into(),
+    };
+    [(policy.name.clone(), Arc::new(policy))].into()
+});
+
+static MATCH_SETS: Lazy<MatchSets> = Lazy::new(Default::default);
+
+static ROUTES: Lazy<Vec<(IpNetwork, RoutePolicyInfo)>> = Lazy::new(|| {
+    (0..NUM_PREFIXES)
+        .map(|i| {
+            let addr = Ipv4Addr::from(0x0a000000 | (i << 8));
+            let prefix = Ipv4Network::new(addr, 24).unwrap().into();
+            let rpinfo = RoutePolicyInfo {
+                origin: RouteOrigin::Protocol(Protocol::STATIC),
+                attrs: ATTRS.clone(),
+                route_type: RouteType::Internal,
+            };
+            (prefix, rpinfo)
+        })
+        .collect()
+});
+
+// Creates the benchmark neighbors, along with the receiving end of their Tx
+// channel.
+fn neighbors() -> (Neighbors, UnboundedReceiver<NbrTxMsg>) {
+    let (msg_txp, msg_txc) = mpsc::unbounded_channel();
+    let neighbors = (0..NUM_PEERS)
+        .map(|i| {
+            let addr = IpAddr::from(Ipv4Addr::from(0xac100000 | i as u32));
+            let mut nbr = Neighbor::new(addr, PeerType::External);
+            nbr.config.peer_as = PEER_AS;
+            nbr.shared_subnet = true;
+            nbr.capabilities_nego = CAPABILITIES.iter().cloned().collect();
+            nbr.config
+                .apply_policy
+                .export_policy
+                .insert("EXPORT".to_owned());
+            nbr.msg_txp = Some(msg_txp.clone());
+            (addr, nbr)
+        })
+        .collect();
+    (neighbors, msg_txc)
+}
+
+// Evaluates the export policies on behalf of a neighbor or update group, and
+// returns the accepted routes.
+fn export(
+    nbr_addr: IpAddr,
+    group_id: Option<u32>,
+) -> Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)> {
+    let (policy_resultp, mut policy_resultc) = mpsc::unbounded_channel();
+    let policies = POLICIES.values().cloned().collect::<Vec<_>>();
+    match group_id {
+        Some(group_id) => policy::update_group_apply(
+            group_id,
+            nbr_addr,
+            AfiSafi::Ipv4Unicast,
+            ROUTES.clone(),
+            &policies,
+            &MATCH_SETS,
+            DefaultPolicyType::RejectRoute,
+            &policy_resultp,
+        ),
+        None => policy::neighbor_apply(
+            PolicyType::Export,
+            nbr_addr,
+            AfiSafi::Ipv4Unicast,
+            ROUTES.clone(),
+            &policies,
+            &MATCH_SETS,
+            DefaultPolicyType::RejectRoute,
+            &policy_resultp,
+        ),
+    }
+
+    match policy_resultc.try_recv().unwrap() {
+        PolicyResultMsg::Neighbor { routes, .. }
+        | PolicyResultMsg::UpdateGroup { routes, .. } => routes,
+    }
+}
+
+// Adds the accepted routes to the given update queues, updating their
+// attributes for transmission to the given neighbor.
+fn enqueue(
+    nbr: &Neighbor,
+    routes: Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)>,
+    update_queues: &mut NeighborUpdateQueues,
+) {
+    let update_queue = Ipv4Unicast::update_queue(update_queues);
+    for (prefix, result) in routes {
+        let (IpNetwork::V4(prefix), PolicyResult::Accept(rpinfo)) =
+            (prefix, result)
+        else {
+            continue;
+        };
+        let mut attrs = rpinfo.attrs;
+        Ipv4Unicast::nexthop_tx_change(nbr, &mut attrs.base);
+        update_queue.reach.entry(attrs).or_default().insert(prefix);
+    }
+}
+
+// Drains the neighbors' Tx channel, encoding the messages that weren't
+// encoded yet as the neighbor Tx task would do.
+fn transmit(msg_txc: &mut UnboundedReceiver<NbrTxMsg>) {
+    let cxt = EncodeCxt {
+        capabilities: CAPABILITIES.iter().cloned().collect(),
+    };
+    while let Ok(msg) = msg_txc.try_recv() {
+        match msg {
+            NbrTxMsg::SendMessageList { msg_list, .. } => {
+                for msg in msg_list {
+                    black_box(msg.encode(&cxt));
+                }
+            }
+            NbrTxMsg::SendEncoded { buf, .. } => {
+                black_box(buf);
+            }
+            _ => (),
+        }
+    }
+}
+
+// Evaluates the export policies and builds the UPDATE messages separately for
+// each peer.
+fn updates_per_peer(
+    neighbors: &mut Neighbors,
+    msg_txc: &mut UnboundedReceiver<NbrTxMsg>,
+) {
+    for nbr in neighbors.values_mut() {
+        let routes = export(nbr.remote_addr, None);
+        let mut update_queues = NeighborUpdateQueues::default();
+        enqueue(nbr, routes, &mut update_queues);
+        let msg_list = update_queues.build_updates();
+        nbr.message_list_send(msg_list);
+    }
+    transmit(msg_txc);
+}
+
+// Groups the peers into update groups, evaluates the export policies and
+// builds the UPDATE messages only once per group, replicating the encoded
+// messages to all group members.
+fn updates_per_group(
+    neighbors: &mut Neighbors,
+    msg_txc: &mut UnboundedReceiver<NbrTxMsg>,
+) {
+    let mut update_groups = UpdateGroups::default();
+    for nbr in neighbors.values_mut() {
+        update_groups.join(nbr, &POLICIES);
+    }
+
+    for group in update_groups.groups.values_mut() {
+        let leader = &neighbors[group.members.first().unwrap()];
+        let routes = export(leader.remote_addr, Some(group.id));
+        enqueue(leader, routes, &mut group.update_queues);
+        group.send_updates(neighbors);
+    }
+    transmit(msg_txc);
+
+    for nbr in neighbors.values_mut() {
+        update_groups.leave(nbr);
+    }
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let (mut neighbors, mut msg_txc) = neighbors();
+
+    c.bench_function("UPDATE generation per peer", |b| {
+        b.iter(|| updates_per_peer(&mut neighbors, &mut msg_txc))
+    });
+    c.bench_function("UPDATE generation per update group", |b| {
+        b.iter(|| updates_per_group(&mut neighbors, &mut msg_txc))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
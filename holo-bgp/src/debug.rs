@@ -23,11 +23,14 @@ pub enum Debug<'a> {
     InstanceStart,
     InstanceStop(InstanceInactiveReason),
     InstanceStatusCheck(&'a str),
+    InitialConvergence,
     NbrFsmEvent(&'a IpAddr, &'a fsm::Event),
     NbrFsmTransition(&'a IpAddr, &'a fsm::State, &'a fsm::State),
     NbrMsgRx(&'a IpAddr, &'a Message),
     NbrMsgTx(&'a IpAddr, &'a Message),
     NbrAttrError(AttrType, AttrError),
+    NbrUpdateGroupJoin(&'a IpAddr, u32),
+    NbrUpdateGroupLeave(&'a IpAddr, u32),
     BestPathFound(IpNetwork, &'a Route),
     BestPathNotFound(IpNetwork),
     NhtUpdate(IpAddr, Option<u32>),
@@ -48,7 +51,8 @@ impl<'a> Debug<'a> {
         match self {
             Debug::InstanceCreate
             | Debug::InstanceDelete
-            | Debug::InstanceStart => {
+            | Debug::InstanceStart
+            | Debug::InitialConvergence => {
                 // Parent span(s): bgp-instance
                 debug!("{}", self);
             }
@@ -98,6 +102,13 @@ impl<'a> Debug<'a> {
                 // Parent span(s): bgp-instance
                 debug!(?attr_type, ?action, "{}", self);
             }
+            Debug::NbrUpdateGroupJoin(nbr_addr, group_id)
+            | Debug::NbrUpdateGroupLeave(nbr_addr, group_id) => {
+                // Parent span(s): bgp-instance
+                debug_span!("neighbor", %nbr_addr).in_scope(|| {
+                    debug!(%group_id, "{}", self);
+                });
+            }
             Debug::BestPathFound(prefix, route) => {
                 // Parent span(s): bgp-instance
                 debug!(%prefix, origin = ?route.origin, "{}", self);
@@ -136,6 +147,9 @@ impl<'a> std::fmt::Display for Debug<'a> {
             Debug::InstanceStatusCheck(..) => {
                 write!(f, "checking instance status")
             }
+            Debug::InitialConvergence => {
+                write!(f, "initial convergence reached")
+            }
             Debug::NbrFsmEvent(..) => {
                 write!(f, "event")
             }
@@ -148,6 +162,12 @@ impl<'a> std::fmt::Display for Debug<'a> {
             Debug::NbrAttrError(..) => {
                 write!(f, "malformed attribute")
             }
+            Debug::NbrUpdateGroupJoin(..) => {
+                write!(f, "joined update group")
+            }
+            Debug::NbrUpdateGroupLeave(..) => {
+                write!(f, "left update group")
+            }
             Debug::BestPathFound(..) => {
                 write!(f, "best path found")
             }
@@ -22,6 +22,7 @@ pub enum Error {
     NbrBadAs(IpAddr, u32, u32),
     NbrBadIdentifier(IpAddr, Ipv4Addr),
     // Other
+    NbrTcpAuthUnavailable(IpAddr),
     InstanceStartError(Box<Error>),
 }
 
@@ -66,6 +67,11 @@ impl Error {
                     warn!(%identifier, "{}", self);
                 });
             }
+            Error::NbrTcpAuthUnavailable(addr) => {
+                warn_span!("neighbor", %addr).in_scope(|| {
+                    warn!("{}", self);
+                });
+            }
             Error::InstanceStartError(error) => {
                 error!(error = %with_source(error), "{}", self);
             }
@@ -84,6 +90,9 @@ impl std::fmt::Display for Error {
             Error::NbrBadIdentifier(..) => {
                 write!(f, "BGP identifier conflict")
             }
+            Error::NbrTcpAuthUnavailable(..) => {
+                write!(f, "TCP-AO keychain unavailable or without active keys")
+            }
             Error::InstanceStartError(..) => {
                 write!(f, "failed to start instance")
             }
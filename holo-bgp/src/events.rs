@@ -22,14 +22,16 @@ use crate::error::{Error, IoError, NbrRxError};
 use crate::instance::{InstanceUpView, PolicyApplyTasks};
 use crate::neighbor::{fsm, Neighbor, Neighbors, PeerType};
 use crate::packet::attribute::Attrs;
-use crate::packet::consts::{Afi, Safi};
+use crate::packet::consts::{Afi, OrfWhenToRefresh, Safi};
 use crate::packet::message::{
-    Capability, Message, MpReachNlri, MpUnreachNlri, RouteRefreshMsg, UpdateMsg,
+    Capability, Message, MpReachNlri, MpUnreachNlri, NegotiatedCapability,
+    OrfEntry, RouteRefreshMsg, RouteRefreshOrf, UpdateMsg,
 };
 use crate::policy::RoutePolicyInfo;
 use crate::rib::{Rib, Route, RouteOrigin, RoutingTable};
 use crate::tasks::messages::output::PolicyApplyMsg;
-use crate::{network, rib};
+use crate::update_group::{UpdateGroup, UpdateGroupKey};
+use crate::{link_state, network, rib, southbound};
 
 // ===== TCP connection request =====
 
@@ -50,6 +52,10 @@ pub(crate) fn process_tcp_accept(
         return Ok(());
     }
 
+    // Reject the connection if the neighbor's TCP authentication can't be
+    // enforced.
+    nbr.tcp_auth(&instance.shared.keychains)?;
+
     // Initialize the accepted stream.
     network::accepted_stream_init(
         &stream,
@@ -119,6 +125,11 @@ pub(crate) fn process_nbr_msg(
                 }
                 Message::Update(msg) => {
                     nbr.fsm_event(instance, fsm::Event::RcvdUpdate);
+                    if let Some(afi_safi) = msg.eor_afi_safi() {
+                        nbr.eor_rcvd.insert(afi_safi);
+                        initial_convergence_check(instance, neighbors);
+                        return Ok(());
+                    }
                     process_nbr_update(instance, nbr, msg)?;
                 }
                 Message::Notification(msg) => {
@@ -217,6 +228,9 @@ fn process_nbr_update(
                         &instance.state.policy_apply_tasks,
                     );
                 }
+                MpReachNlri::BgpLs { .. } => {
+                    // BGP-LS NLRIs are originated but never consumed.
+                }
             }
         } else {
             // Treat as withdraw.
@@ -231,6 +245,7 @@ fn process_nbr_update(
                         nbr, rib, prefixes, ibus_tx,
                     );
                 }
+                MpReachNlri::BgpLs { .. } => {}
             }
         }
     }
@@ -258,6 +273,7 @@ fn process_nbr_update(
                     nbr, rib, prefixes, ibus_tx,
                 );
             }
+            MpUnreachNlri::BgpLs { .. } => {}
         }
     }
 
@@ -395,38 +411,284 @@ fn process_nbr_route_refresh(
         return Ok(());
     }
 
+    // Process the ORF entries, if any.
+    //
+    // RFC 5291 - Section 5:
+    // If the When-to-refresh indicates IMMEDIATE, then after processing all
+    // the ORF entries carried in the message the speaker re-advertises to
+    // the peer routes from the Adj-RIB-Out associated with the peer that
+    // have the same AFI/SAFI as what is carried in the message, and taking
+    // into account all the ORF entries for that AFI/SAFI received from the
+    // peer. The speaker MUST re-advertise all the routes that have been
+    // affected by the ORF entries carried in the message, but MAY also
+    // re-advertise the routes that have not been affected.
+    //
+    // If the When-to-refresh indicates DEFER, then after processing all the
+    // ORF entries carried in the message the speaker defers re-advertisement
+    // of routes to the peer until the speaker receives a subsequent
+    // ROUTE-REFRESH message for the same AFI/SAFI from the peer.
+    if let Some(orf) = msg.orf
+        && nbr.is_orf_receive_enabled(afi, safi)
+    {
+        match (afi, safi) {
+            (Afi::Ipv4, Safi::Unicast) => {
+                process_nbr_orf::<Ipv4Unicast>(instance, nbr, orf);
+            }
+            (Afi::Ipv6, Safi::Unicast) => {
+                process_nbr_orf::<Ipv6Unicast>(instance, nbr, orf);
+            }
+            _ => {
+                // Ignore unsupported AFI/SAFI combination.
+            }
+        }
+        return Ok(());
+    }
+
+    // Re-evaluate the Adj-RIB-Out, taking into account any ORF entries
+    // received earlier whose processing was deferred.
     match (afi, safi) {
         (Afi::Ipv4, Safi::Unicast) => {
-            process_nbr_route_refresh_af::<Ipv4Unicast>(instance, nbr)
+            process_nbr_soft_reset_out_af::<Ipv4Unicast>(instance, nbr);
         }
         (Afi::Ipv6, Safi::Unicast) => {
-            process_nbr_route_refresh_af::<Ipv6Unicast>(instance, nbr)
+            process_nbr_soft_reset_out_af::<Ipv6Unicast>(instance, nbr);
+        }
+        (Afi::BgpLs, Safi::BgpLs) => {
+            // Resend the entire BGP-LS database.
+            link_state::initial_update(instance, nbr);
         }
         _ => {
             // Ignore unsupported AFI/SAFI combination.
-            return Ok(());
         }
     }
 
-    // Send UPDATE message(s) to the neighbor.
-    let msg_list = nbr.update_queues.build_updates();
-    nbr.message_list_send(msg_list);
-
     Ok(())
 }
 
-fn process_nbr_route_refresh_af<A>(
+fn process_nbr_orf<A>(
+    instance: &mut InstanceUpView<'_>,
+    nbr: &mut Neighbor,
+    orf: RouteRefreshOrf,
+) where
+    A: AddressFamily,
+{
+    // Update the neighbor's Address Prefix ORF.
+    let entries = nbr.orf_prefixes.entry(A::AFI_SAFI).or_default();
+    for entry in orf.entries {
+        match entry {
+            OrfEntry::Add(entry) => {
+                entries.insert(entry.seq, entry);
+            }
+            OrfEntry::Remove(entry) => {
+                entries.remove(&entry.seq);
+            }
+            OrfEntry::RemoveAll => {
+                entries.clear();
+            }
+        }
+    }
+    if entries.is_empty() {
+        nbr.orf_prefixes.remove(&A::AFI_SAFI);
+    }
+
+    // Re-evaluate the Adj-RIB-Out taking into account the updated ORF. Routes
+    // denied by the updated ORF are withdrawn.
+    if orf.when_to_refresh == OrfWhenToRefresh::Immediate {
+        process_nbr_soft_reset_out_af::<A>(instance, nbr);
+    }
+}
+
+// Checks whether the initial convergence has been reached, which happens once
+// all enabled neighbors are established and have sent their End-of-RIB markers.
+//
+// The check is also performed when the instance starts and whenever neighbors
+// are enabled, disabled or deleted, so the signal is sent right away when
+// there are no enabled neighbors to wait for.
+pub(crate) fn initial_convergence_check(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &Neighbors,
+) {
+    if instance.state.initial_convergence {
+        return;
+    }
+
+    if !neighbors
+        .values()
+        .filter(|nbr| nbr.config.enabled)
+        .all(|nbr| nbr.is_converged())
+    {
+        return;
+    }
+
+    Debug::InitialConvergence.log();
+    instance.state.initial_convergence = true;
+    southbound::tx::initial_convergence(&instance.tx.ibus);
+}
+
+// ===== neighbor soft reset =====
+
+// Requests the neighbor to re-advertise its Adj-RIB-Out using the Route Refresh
+// capability, or re-applies the import policies to the routes stored in the
+// pre-policy Adj-RIB-In if that capability wasn't negotiated.
+pub(crate) fn process_nbr_soft_reset_in(
+    instance: &mut InstanceUpView<'_>,
+    nbr: &mut Neighbor,
+) {
+    if nbr.state != fsm::State::Established {
+        return;
+    }
+
+    if nbr
+        .capabilities_nego
+        .contains(&NegotiatedCapability::RouteRefresh)
+    {
+        let msg_list = [(Afi::Ipv4, Safi::Unicast), (Afi::Ipv6, Safi::Unicast)]
+            .into_iter()
+            .filter(|(afi, safi)| nbr.is_af_enabled(*afi, *safi))
+            .map(|(afi, safi)| {
+                Message::RouteRefresh(RouteRefreshMsg {
+                    afi: afi as u16,
+                    safi: safi as u8,
+                    orf: None,
+                })
+            })
+            .collect::<Vec<_>>();
+        nbr.message_list_send(msg_list);
+    } else {
+        process_nbr_soft_reset_in_af::<Ipv4Unicast>(instance, nbr);
+        process_nbr_soft_reset_in_af::<Ipv6Unicast>(instance, nbr);
+    }
+}
+
+fn process_nbr_soft_reset_in_af<A>(
+    instance: &mut InstanceUpView<'_>,
+    nbr: &Neighbor,
+) where
+    A: AddressFamily,
+{
+    // Check if the address-family is enabled for this session.
+    if !nbr.is_af_enabled(A::AFI, A::SAFI) {
+        return;
+    }
+
+    // Get pre-policy Adj-RIB-In routes.
+    let table = A::table(&mut instance.state.rib.tables);
+    let routes = table
+        .prefixes
+        .iter()
+        .filter_map(|(prefix, dest)| {
+            dest.adj_rib
+                .get(&nbr.remote_addr)
+                .and_then(|adj_rib| adj_rib.in_pre.as_ref())
+                .map(|route| ((*prefix).into(), route.policy_info()))
+        })
+        .collect::<Vec<_>>();
+    if routes.is_empty() {
+        return;
+    }
+
+    // Get policy configuration for the address family.
+    let apply_policy_cfg = &nbr
+        .config
+        .afi_safi
+        .get(&A::AFI_SAFI)
+        .map(|afi_safi| &afi_safi.apply_policy)
+        .unwrap_or(&nbr.config.apply_policy);
+
+    // Enqueue import policy application.
+    let msg = PolicyApplyMsg::Neighbor {
+        policy_type: PolicyType::Import,
+        nbr_addr: nbr.remote_addr,
+        afi_safi: A::AFI_SAFI,
+        routes,
+        policies: apply_policy_cfg
+            .import_policy
+            .iter()
+            .map(|policy| instance.shared.policies.get(policy).unwrap().clone())
+            .collect(),
+        match_sets: instance.shared.policy_match_sets.clone(),
+        default_policy: apply_policy_cfg.default_import_policy,
+    };
+    instance.state.policy_apply_tasks.enqueue(msg);
+}
+
+// Re-sends the Adj-RIB-Out to the neighbor.
+pub(crate) fn process_nbr_soft_reset_out(
+    instance: &mut InstanceUpView<'_>,
+    nbr: &mut Neighbor,
+) {
+    if nbr.state != fsm::State::Established {
+        return;
+    }
+
+    process_nbr_soft_reset_out_af::<Ipv4Unicast>(instance, nbr);
+    process_nbr_soft_reset_out_af::<Ipv6Unicast>(instance, nbr);
+    link_state::initial_update(instance, nbr);
+}
+
+fn process_nbr_soft_reset_out_af<A>(
     instance: &mut InstanceUpView<'_>,
     nbr: &mut Neighbor,
 ) where
     A: AddressFamily,
 {
+    // Check if the address-family is enabled for this session.
+    if !nbr.is_af_enabled(A::AFI, A::SAFI) {
+        return;
+    }
+
+    // Flush the post-policy Adj-RIB-Out, so that all routes accepted by the
+    // export policies are re-sent. Routes that are no longer accepted are
+    // withdrawn.
     let table = A::table(&mut instance.state.rib.tables);
     let update_queue = A::update_queue(&mut nbr.update_queues);
-    for (prefix, dest) in &table.prefixes {
-        let route = dest.local.as_ref().unwrap();
-        let attrs = route.attrs.get();
-        update_queue.reach.entry(attrs).or_default().insert(*prefix);
+    for (prefix, dest) in table.prefixes.iter_mut() {
+        if let Some(adj_rib) = dest.adj_rib.get_mut(&nbr.remote_addr)
+            && adj_rib.out_post.take().is_some()
+        {
+            update_queue.unreach.insert(*prefix);
+        }
+    }
+
+    // Re-run the export policies over the best routes.
+    nbr.initial_routing_update::<A>(instance);
+}
+
+// ===== neighbor export policy update =====
+
+// Moves the neighbor to the update group matching its new outbound parameters,
+// and re-evaluates the export policies for all of its routes.
+pub(crate) fn process_nbr_export_policy_update(
+    instance: &mut InstanceUpView<'_>,
+    nbr: &mut Neighbor,
+) {
+    if nbr.state != fsm::State::Established {
+        return;
+    }
+
+    instance
+        .state
+        .update_groups
+        .update(nbr, &instance.shared.policies);
+    process_nbr_soft_reset_out(instance, nbr);
+}
+
+// ===== policy definition update =====
+
+// Re-evaluates the update group membership of all established neighbors after
+// a policy definition or the policy match sets have changed.
+pub(crate) fn process_policy_update(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+) {
+    for nbr in neighbors
+        .values_mut()
+        .filter(|nbr| nbr.state == fsm::State::Established)
+    {
+        instance
+            .state
+            .update_groups
+            .update(nbr, &instance.shared.policies);
     }
 }
 
@@ -552,6 +814,17 @@ where
         let dest = table.prefixes.entry(prefix).or_default();
         let adj_rib = dest.adj_rib.entry(nbr.remote_addr).or_default();
 
+        // Apply the Address Prefix ORF received from the neighbor as an
+        // additional filtering stage.
+        let result = match result {
+            PolicyResult::Accept(_)
+                if !nbr.orf_permits(A::AFI_SAFI, &prefix.into()) =>
+            {
+                PolicyResult::Reject
+            }
+            result => result,
+        };
+
         // Update post-policy Adj-RIB-Out routes.
         match result {
             PolicyResult::Accept(rpinfo) => {
@@ -581,6 +854,7 @@ where
 
                     // Update neighbor's Tx queue.
                     let update_queue = A::update_queue(&mut nbr.update_queues);
+                    update_queue.unreach.remove(&prefix);
                     update_queue.reach.entry(attrs).or_default().insert(prefix);
                 }
             }
@@ -598,6 +872,165 @@ where
     let msg_list = nbr.update_queues.build_updates();
     nbr.message_list_send(msg_list);
 
+    // Send End-of-RIB marker if this completes the initial routing update.
+    if nbr.eor_pending.remove(&A::AFI_SAFI) {
+        let msg = Message::Update(UpdateMsg::eor(A::AFI_SAFI));
+        nbr.message_send(msg);
+    }
+
+    Ok(())
+}
+
+// ===== update group policy export result =====
+
+pub(crate) fn process_update_group_policy_export<A>(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+    group_id: u32,
+    prefixes: Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)>,
+) -> Result<(), Error>
+where
+    A: AddressFamily,
+{
+    // Lookup update group.
+    let Some(group) = instance.state.update_groups.groups.get_mut(&group_id)
+    else {
+        return Ok(());
+    };
+    let Some(leader) = group.leader() else {
+        return Ok(());
+    };
+
+    let rib = &mut instance.state.rib;
+    let table = A::table(&mut rib.tables);
+    for (prefix, result) in prefixes {
+        // Get RIB destination.
+        let prefix = A::IpNetwork::get(prefix).unwrap();
+        let dest = table.prefixes.entry(prefix).or_default();
+
+        // Update post-policy Adj-RIB-Out routes of all group members.
+        match result {
+            PolicyResult::Accept(rpinfo) => {
+                let route_attrs =
+                    rib.attr_sets.get_route_attr_sets(&rpinfo.attrs);
+
+                let mut updated = vec![];
+                for nbr_addr in &group.members {
+                    let adj_rib = dest.adj_rib.entry(*nbr_addr).or_default();
+
+                    // Routes can't be advertised back to the internal peer
+                    // they were learned from, nor to peers whose received
+                    // ORF rejects them.
+                    if neighbor_split_horizon(
+                        nbr_addr,
+                        &rpinfo.origin,
+                        rpinfo.route_type,
+                    ) || !neighbors[nbr_addr]
+                        .orf_permits(A::AFI_SAFI, &prefix.into())
+                    {
+                        if adj_rib.out_post.take().is_some() {
+                            let nbr = neighbors.get_mut(nbr_addr).unwrap();
+                            let update_queue =
+                                A::update_queue(&mut nbr.update_queues);
+                            update_queue.unreach.insert(prefix);
+                        }
+                        continue;
+                    }
+
+                    let route = Route::new(
+                        rpinfo.origin,
+                        route_attrs.clone(),
+                        rpinfo.route_type,
+                    );
+                    if let Some(adj_rib_route) = &mut adj_rib.out_post {
+                        if adj_rib_route.attrs != route.attrs {
+                            **adj_rib_route = route;
+                            updated.push(*nbr_addr);
+                        }
+                    } else {
+                        adj_rib.out_post = Some(Box::new(route));
+                        updated.push(*nbr_addr);
+                    }
+                }
+                if updated.is_empty() {
+                    continue;
+                }
+
+                // Update route's attributes before transmission.
+                //
+                // All group members share the same outbound parameters, hence
+                // the group leader can be used for that.
+                let mut attrs = rpinfo.attrs;
+                let nbr = neighbors.get(&leader).unwrap();
+                attrs_tx_update::<A>(nbr, instance.config.asn, &mut attrs);
+
+                if updated.len() != group.members.len() {
+                    // The route needs to be advertised only to some of the
+                    // group members, so enqueue it individually for each
+                    // updated neighbor.
+                    for nbr_addr in updated {
+                        let nbr = neighbors.get_mut(&nbr_addr).unwrap();
+                        let update_queue =
+                            A::update_queue(&mut nbr.update_queues);
+                        update_queue
+                            .reach
+                            .entry(attrs.clone())
+                            .or_default()
+                            .insert(prefix);
+                    }
+                } else {
+                    // Update group's Tx queue.
+                    let update_queue =
+                        A::update_queue(&mut group.update_queues);
+                    update_queue.reach.entry(attrs).or_default().insert(prefix);
+                }
+            }
+            PolicyResult::Reject => {
+                let mut withdrawn = vec![];
+                for nbr_addr in &group.members {
+                    if let Some(adj_rib) = dest.adj_rib.get_mut(nbr_addr)
+                        && adj_rib.out_post.take().is_some()
+                    {
+                        withdrawn.push(*nbr_addr);
+                    }
+                }
+                if withdrawn.is_empty() {
+                    continue;
+                }
+
+                if withdrawn.len() != group.members.len() {
+                    // The route was advertised only to some of the group
+                    // members, so withdraw it individually from each of them.
+                    for nbr_addr in withdrawn {
+                        let nbr = neighbors.get_mut(&nbr_addr).unwrap();
+                        let update_queue =
+                            A::update_queue(&mut nbr.update_queues);
+                        update_queue.unreach.insert(prefix);
+                    }
+                } else {
+                    // Update group's Tx queue.
+                    let update_queue =
+                        A::update_queue(&mut group.update_queues);
+                    update_queue.unreach.insert(prefix);
+                }
+            }
+        }
+    }
+
+    // Send UPDATE message(s) that are specific to individual group members.
+    for nbr_addr in &group.members {
+        let Some(nbr) = neighbors.get_mut(nbr_addr) else {
+            continue;
+        };
+        let msg_list = nbr.update_queues.build_updates();
+        if !msg_list.is_empty() {
+            nbr.message_list_send(msg_list);
+        }
+    }
+
+    // Send UPDATE message(s) to all group members.
+    group.send_updates(neighbors);
+
     Ok(())
 }
 
@@ -628,6 +1061,23 @@ where
     A::nexthop_tx_change(nbr, &mut attrs.base);
 }
 
+// ===== keychain update event =====
+
+pub(crate) fn process_keychain_update(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+    keychain_name: &str,
+) -> Result<(), Error> {
+    // Update the TCP-AO keys of all neighbors using this keychain.
+    for nbr in neighbors.values_mut().filter(|nbr| {
+        nbr.config.transport.ao_keychain.as_deref() == Some(keychain_name)
+    }) {
+        nbr.tcp_auth_update(instance);
+    }
+
+    Ok(())
+}
+
 // ===== BGP decision process =====
 
 pub(crate) fn decision_process<A>(
@@ -692,24 +1142,25 @@ where
     }
 
     // Phase 3: Route Dissemination.
-    for nbr in neighbors
-        .values_mut()
-        .filter(|nbr| nbr.state == fsm::State::Established)
-    {
-        // Skip neighbors that haven't this address-family enabled.
-        if !nbr.is_af_enabled(A::AFI, A::SAFI) {
+    //
+    // Routes are disseminated on a per update group basis, so that export
+    // policies are evaluated only once for all neighbors sharing the same
+    // outbound parameters.
+    for group in instance.state.update_groups.groups.values_mut() {
+        // Skip groups that haven't this address-family enabled.
+        if !group.is_af_enabled(A::AFI, A::SAFI) {
             continue;
         }
 
         // Withdraw unfeasible routes immediately.
         if !unreach.is_empty() {
-            withdraw_routes::<A>(nbr, table, &unreach);
+            withdraw_routes::<A>(group, neighbors, table, &unreach);
         }
 
         // Advertise best routes.
         if !reach.is_empty() {
-            advertise_routes::<A>(
-                nbr,
+            update_group_advertise_routes::<A>(
+                group,
                 table,
                 &reach,
                 instance.shared,
@@ -722,29 +1173,36 @@ where
 }
 
 fn withdraw_routes<A>(
-    nbr: &mut Neighbor,
+    group: &mut UpdateGroup,
+    neighbors: &mut Neighbors,
     table: &mut RoutingTable<A>,
     routes: &[A::IpNetwork],
 ) where
     A: AddressFamily,
 {
-    // Update Adj-RIB-Out.
+    // Update Adj-RIB-Out of all group members.
     for prefix in routes {
         let dest = table.prefixes.get_mut(prefix).unwrap();
-        let Some(adj_rib) = dest.adj_rib.get_mut(&nbr.remote_addr) else {
-            continue;
-        };
+        let mut withdraw = false;
+        for nbr_addr in &group.members {
+            let Some(adj_rib) = dest.adj_rib.get_mut(nbr_addr) else {
+                continue;
+            };
+
+            adj_rib.out_pre = None;
+            if adj_rib.out_post.take().is_some() {
+                withdraw = true;
+            }
+        }
 
-        adj_rib.out_pre = None;
-        if adj_rib.out_post.take().is_some() {
-            let update_queue = A::update_queue(&mut nbr.update_queues);
+        if withdraw {
+            let update_queue = A::update_queue(&mut group.update_queues);
             update_queue.unreach.insert(*prefix);
         }
     }
 
-    // Send UPDATE message(s) to the neighbor.
-    let msg_list = nbr.update_queues.build_updates();
-    nbr.message_list_send(msg_list);
+    // Send UPDATE message(s) to all group members.
+    group.send_updates(neighbors);
 }
 
 pub(crate) fn advertise_routes<A>(
@@ -795,24 +1253,99 @@ pub(crate) fn advertise_routes<A>(
     policy_apply_tasks.enqueue(msg);
 }
 
+fn update_group_advertise_routes<A>(
+    group: &UpdateGroup,
+    table: &mut RoutingTable<A>,
+    routes: &[(A::IpNetwork, Box<Route>)],
+    shared: &InstanceShared,
+    policy_apply_tasks: &PolicyApplyTasks,
+) where
+    A: AddressFamily,
+{
+    let Some(leader) = group.leader() else {
+        return;
+    };
+
+    // Update pre-policy Adj-RIB-Out routes of all group members.
+    for (prefix, route) in routes.iter().filter(|(_, route)| {
+        update_group_redistribute_filter(&group.key, route)
+    }) {
+        let dest = table.prefixes.get_mut(prefix).unwrap();
+        for nbr_addr in group.members.iter().filter(|nbr_addr| {
+            !neighbor_split_horizon(nbr_addr, &route.origin, route.route_type)
+        }) {
+            let adj_rib = dest.adj_rib.entry(*nbr_addr).or_default();
+            adj_rib.out_pre = Some(route.clone());
+        }
+    }
+
+    // Get policy configuration for the address family.
+    let export_policy_key = group.key.export_policy.get(&A::AFI_SAFI).unwrap();
+
+    // Enqueue export policy application.
+    let msg = PolicyApplyMsg::UpdateGroup {
+        group_id: group.id,
+        nbr_addr: leader,
+        afi_safi: A::AFI_SAFI,
+        routes: routes
+            .iter()
+            .filter(|(_, route)| {
+                update_group_redistribute_filter(&group.key, route)
+            })
+            .map(|(prefix, route)| ((*prefix).into(), route.policy_info()))
+            .collect(),
+        policies: export_policy_key
+            .policies
+            .iter()
+            .map(|policy| shared.policies.get(policy).unwrap().clone())
+            .collect(),
+        match_sets: shared.policy_match_sets.clone(),
+        default_policy: export_policy_key.default_policy,
+    };
+    policy_apply_tasks.enqueue(msg);
+}
+
 // Determines whether to redistribute a route to a neighbor.
 fn neighbor_redistribute_filter(nbr: &Neighbor, route: &Route) -> bool {
+    redistribute_filter(
+        nbr.peer_type,
+        nbr.config.peer_as,
+        nbr.config.as_path_options.disable_peer_as_filter,
+        route,
+    ) && !neighbor_split_horizon(
+        &nbr.remote_addr,
+        &route.origin,
+        route.route_type,
+    )
+}
+
+// Determines whether to redistribute a route to the members of an update
+// group.
+//
+// Filtering that depends on the individual group members is performed
+// separately.
+fn update_group_redistribute_filter(
+    key: &UpdateGroupKey,
+    route: &Route,
+) -> bool {
+    redistribute_filter(
+        key.peer_type,
+        key.peer_as,
+        key.disable_peer_as_filter,
+        route,
+    )
+}
+
+fn redistribute_filter(
+    peer_type: PeerType,
+    peer_as: u32,
+    disable_peer_as_filter: bool,
+    route: &Route,
+) -> bool {
     // Suppress advertisements to peers if their AS number is present
     // in the AS path of the route, unless overridden by configuration.
-    if !nbr.config.as_path_options.disable_peer_as_filter
-        && route.attrs.base.value.as_path.contains(nbr.config.peer_as)
-    {
-        return false;
-    }
-
-    // RFC 4271 - Section 9.2:
-    // "When a BGP speaker receives an UPDATE message from an internal
-    // peer, the receiving BGP speaker SHALL NOT re-distribute the
-    // routing information contained in that UPDATE message to other
-    // internal peers".
-    if route.route_type == RouteType::Internal
-        && let RouteOrigin::Neighbor { remote_addr, .. } = &route.origin
-        && *remote_addr == nbr.remote_addr
+    if !disable_peer_as_filter
+        && route.attrs.base.value.as_path.contains(peer_as)
     {
         return false;
     }
@@ -830,7 +1363,7 @@ fn neighbor_redistribute_filter(nbr: &Neighbor, route: &Route) -> bool {
             }
 
             // Do not advertise to external peers.
-            if nbr.peer_type == PeerType::External
+            if peer_type == PeerType::External
                 && (comm == WellKnownCommunities::NoExport
                     || comm == WellKnownCommunities::NoExportSubconfed)
             {
@@ -841,3 +1374,25 @@ fn neighbor_redistribute_filter(nbr: &Neighbor, route: &Route) -> bool {
 
     true
 }
+
+// Determines whether the route was learned from the given internal peer, in
+// which case it must not be advertised back to it.
+fn neighbor_split_horizon(
+    nbr_addr: &IpAddr,
+    origin: &RouteOrigin,
+    route_type: RouteType,
+) -> bool {
+    // RFC 4271 - Section 9.2:
+    // "When a BGP speaker receives an UPDATE message from an internal
+    // peer, the receiving BGP speaker SHALL NOT re-distribute the
+    // routing information contained in that UPDATE message to other
+    // internal peers".
+    if route_type == RouteType::Internal
+        && let RouteOrigin::Neighbor { remote_addr, .. } = origin
+        && remote_addr == nbr_addr
+    {
+        return true;
+    }
+
+    false
+}
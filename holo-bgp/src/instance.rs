@@ -24,6 +24,7 @@ use tokio::sync::mpsc;
 use crate::af::{Ipv4Unicast, Ipv6Unicast};
 use crate::debug::{Debug, InstanceInactiveReason};
 use crate::error::{Error, IoError};
+use crate::link_state::LsDb;
 use crate::neighbor::{fsm, Neighbors};
 use crate::northbound::configuration::InstanceCfg;
 use crate::packet::consts::{CeaseSubcode, ErrorCode};
@@ -34,7 +35,8 @@ use crate::tasks::messages::input::{
 };
 use crate::tasks::messages::output::PolicyApplyMsg;
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
-use crate::{events, network, southbound, tasks};
+use crate::update_group::UpdateGroups;
+use crate::{events, link_state, network, southbound, tasks};
 
 #[derive(Debug)]
 pub struct Instance {
@@ -72,6 +74,12 @@ pub struct InstanceState {
     pub decision_process_task: Option<TimeoutTask>,
     // BGP RIB.
     pub rib: Rib,
+    // Update groups.
+    pub update_groups: UpdateGroups,
+    // BGP-LS database.
+    pub ls_db: LsDb,
+    // Whether the initial convergence has been reached.
+    pub initial_convergence: bool,
 }
 
 #[derive(Debug)]
@@ -157,6 +165,15 @@ impl Instance {
             Ok(state) => {
                 // Store instance initial state.
                 self.state = Some(state);
+
+                // Request the link-state topologies exported by the IGPs,
+                // since earlier updates were discarded.
+                southbound::tx::link_state_dump(&self.tx.ibus);
+
+                // Check whether there are any neighbors to wait for before
+                // signaling the initial convergence.
+                let (mut instance, neighbors) = self.as_up().unwrap();
+                events::initial_convergence_check(&mut instance, neighbors);
             }
             Err(error) => {
                 Error::InstanceStartError(Box::new(error)).log();
@@ -367,6 +384,9 @@ impl InstanceState {
             policy_apply_tasks,
             decision_process_task: None,
             rib: Default::default(),
+            update_groups: Default::default(),
+            ls_db: Default::default(),
+            initial_convergence: false,
         })
     }
 
@@ -443,9 +463,43 @@ async fn process_ibus_msg(
             // Router ID update notification.
             southbound::rx::process_router_id_update(instance, router_id).await;
         }
+        IbusMsg::KeychainUpd(keychain) => {
+            // Update the local copy of the keychain.
+            instance
+                .shared
+                .keychains
+                .insert(keychain.name.clone(), keychain.clone());
+
+            // Update all neighbors using this keychain.
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                events::process_keychain_update(
+                    &mut instance,
+                    neighbors,
+                    &keychain.name,
+                )?;
+            }
+        }
+        IbusMsg::KeychainDel(keychain_name) => {
+            // Remove the local copy of the keychain.
+            instance.shared.keychains.remove(&keychain_name);
+
+            // Update all neighbors using this keychain.
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                events::process_keychain_update(
+                    &mut instance,
+                    neighbors,
+                    &keychain_name,
+                )?;
+            }
+        }
         IbusMsg::PolicyMatchSetsUpd(match_sets) => {
             // Update the local copy of the policy match sets.
             instance.shared.policy_match_sets = match_sets;
+
+            // Update the neighbors' update groups.
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                events::process_policy_update(&mut instance, neighbors);
+            }
         }
         IbusMsg::PolicyUpd(policy) => {
             // Update the local copy of the policy definition.
@@ -453,10 +507,44 @@ async fn process_ibus_msg(
                 .shared
                 .policies
                 .insert(policy.name.clone(), policy.clone());
+
+            // Update the neighbors' update groups.
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                events::process_policy_update(&mut instance, neighbors);
+            }
         }
         IbusMsg::PolicyDel(policy_name) => {
             // Remove the local copy of the policy definition.
             instance.shared.policies.remove(&policy_name);
+
+            // Update the neighbors' update groups.
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                events::process_policy_update(&mut instance, neighbors);
+            }
+        }
+        IbusMsg::LinkStateUpd(msg) => {
+            // Update the BGP-LS database.
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                link_state::process_topology_update(
+                    &mut instance,
+                    neighbors,
+                    &msg,
+                );
+            }
+        }
+        IbusMsg::LinkStateDel {
+            protocol,
+            instance: name,
+        } => {
+            // Remove the topology from the BGP-LS database.
+            if let Some((mut instance, neighbors)) = instance.as_up() {
+                link_state::process_topology_delete(
+                    &mut instance,
+                    neighbors,
+                    protocol,
+                    name,
+                );
+            }
         }
         // Ignore other events.
         _ => {}
@@ -535,6 +623,26 @@ fn process_protocol_msg(
                         instance, neighbors, nbr_addr, routes,
                     )?
                 }
+                (_, AfiSafi::BgpLs) => {
+                    // BGP-LS routes aren't subject to routing policies.
+                }
+            },
+            PolicyResultMsg::UpdateGroup {
+                group_id,
+                afi_safi,
+                routes,
+            } => match afi_safi {
+                AfiSafi::Ipv4Unicast => {
+                    events::process_update_group_policy_export::<Ipv4Unicast>(
+                        instance, neighbors, group_id, routes,
+                    )?
+                }
+                AfiSafi::Ipv6Unicast => {
+                    events::process_update_group_policy_export::<Ipv6Unicast>(
+                        instance, neighbors, group_id, routes,
+                    )?
+                }
+                AfiSafi::BgpLs => {}
             },
         },
         // Decision process.
@@ -15,6 +15,7 @@ pub mod debug;
 pub mod error;
 pub mod events;
 pub mod instance;
+pub mod link_state;
 pub mod neighbor;
 pub mod network;
 pub mod northbound;
@@ -23,3 +24,4 @@ pub mod policy;
 pub mod rib;
 pub mod southbound;
 pub mod tasks;
+pub mod update_group;
@@ -0,0 +1,419 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
+use holo_utils::bgp::AfiSafi;
+use holo_utils::link_state::{
+    LsLinkKey, LsNode, LsNodeKey, LsPrefixKey, LsPrefixRouteType,
+    LsTopologyMsg,
+};
+use holo_utils::protocol::Protocol;
+use holo_utils::sr::Sid;
+use num_traits::ToPrimitive;
+
+use crate::instance::InstanceUpView;
+use crate::neighbor::{fsm, Neighbor, Neighbors, PeerType};
+use crate::packet::attribute::{AsPath, Attrs, BaseAttrs, ATTR_MIN_LEN_EXT};
+use crate::packet::bgp_ls::{
+    LsAdjSid, LsAttr, LsLinkDesc, LsLinkNlri, LsNlri, LsNodeDesc, LsNodeNlri,
+    LsPrefixDesc, LsPrefixNlri, LsPrefixSid, LsSid, LsSrCapabilities,
+    LsSrRange,
+};
+use crate::packet::consts::{
+    Afi, LsNodeFlags, LsOspfRouteType, LsProtocolId, Origin, Safi,
+};
+use crate::packet::message::{
+    Message, MpReachNlri, MpUnreachNlri, UpdateMsg,
+};
+use crate::rib;
+
+// BGP-LS database.
+//
+// Contains the link-state information exported by the local IGP instances,
+// already converted to BGP-LS NLRIs and attributes.
+#[derive(Debug, Default)]
+pub struct LsDb {
+    pub topologies: BTreeMap<(Protocol, String), BTreeMap<LsNlri, LsAttr>>,
+}
+
+// ===== impl LsDb =====
+
+impl LsDb {
+    // Returns the total number of BGP-LS NLRIs.
+    pub(crate) fn nlri_count(&self) -> usize {
+        self.topologies.values().map(|nlris| nlris.len()).sum()
+    }
+
+    // Returns an iterator over all BGP-LS NLRIs and their attributes.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&LsNlri, &LsAttr)> {
+        self.topologies.values().flat_map(|nlris| nlris.iter())
+    }
+}
+
+// ===== global functions =====
+
+// Processes a topology update from a local IGP instance, advertising the
+// resulting changes to all BGP-LS enabled neighbors.
+pub(crate) fn process_topology_update(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+    msg: &LsTopologyMsg,
+) {
+    let protocol_id = match msg.protocol {
+        Protocol::OSPFV2 => LsProtocolId::Ospfv2,
+        Protocol::OSPFV3 => LsProtocolId::Ospfv3,
+        _ => return,
+    };
+
+    // Convert the IGP topology to BGP-LS NLRIs.
+    let asn = instance.config.asn;
+    let mut nlris = BTreeMap::new();
+    for (key, node) in &msg.topology.nodes {
+        let nlri = LsNlri::Node(LsNodeNlri {
+            protocol_id,
+            identifier: 0,
+            local: node_desc(asn, key),
+        });
+        nlris.insert(nlri, node_attr(key, node));
+    }
+    for (key, link) in &msg.topology.links {
+        let nlri = LsNlri::Link(LsLinkNlri {
+            protocol_id,
+            identifier: 0,
+            local: node_desc(asn, &key.local),
+            remote: node_desc(asn, &key.remote),
+            link: link_desc(key),
+        });
+        let attr = LsAttr {
+            igp_metric: Some(link.metric),
+            adj_sids: link
+                .adj_sids
+                .iter()
+                .map(|adj_sid| LsAdjSid {
+                    flags: adj_sid.flags,
+                    weight: adj_sid.weight,
+                    sid: ls_sid(adj_sid.sid),
+                })
+                .collect(),
+            ..Default::default()
+        };
+        nlris.insert(nlri, attr);
+    }
+    for (key, prefix) in &msg.topology.prefixes {
+        let nlri = LsNlri::Prefix(prefix_nlri(protocol_id, asn, key));
+        let attr = LsAttr {
+            prefix_metric: Some(prefix.metric),
+            prefix_sids: prefix
+                .prefix_sids
+                .iter()
+                .map(|prefix_sid| LsPrefixSid {
+                    flags: prefix_sid.flags,
+                    algo: prefix_sid.algo.to_u8().unwrap(),
+                    sid: ls_sid(prefix_sid.sid),
+                })
+                .collect(),
+            ..Default::default()
+        };
+        nlris.insert(nlri, attr);
+    }
+
+    // Compute the differences from the previous topology.
+    let key = (msg.protocol, msg.instance.clone());
+    let old_nlris = instance
+        .state
+        .ls_db
+        .topologies
+        .remove(&key)
+        .unwrap_or_default();
+    let reach = nlris
+        .iter()
+        .filter(|(nlri, attr)| old_nlris.get(*nlri) != Some(*attr))
+        .map(|(nlri, attr)| (nlri.clone(), attr.clone()))
+        .collect::<Vec<_>>();
+    let unreach = old_nlris
+        .into_keys()
+        .filter(|nlri| !nlris.contains_key(nlri))
+        .collect::<Vec<_>>();
+    if !nlris.is_empty() {
+        instance.state.ls_db.topologies.insert(key, nlris);
+    }
+
+    // Advertise the changes.
+    for nbr in neighbors.values_mut() {
+        send_updates(nbr, asn, reach.clone(), unreach.clone());
+    }
+}
+
+// Processes the removal of the topology of a local IGP instance, withdrawing
+// all of its NLRIs from the BGP-LS enabled neighbors.
+pub(crate) fn process_topology_delete(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+    protocol: Protocol,
+    name: String,
+) {
+    let Some(nlris) = instance.state.ls_db.topologies.remove(&(protocol, name))
+    else {
+        return;
+    };
+
+    let unreach = nlris.into_keys().collect::<Vec<_>>();
+    for nbr in neighbors.values_mut() {
+        send_updates(nbr, instance.config.asn, vec![], unreach.clone());
+    }
+}
+
+// Sends the entire BGP-LS database to the given neighbor.
+pub(crate) fn initial_update(
+    instance: &InstanceUpView<'_>,
+    nbr: &mut Neighbor,
+) {
+    let reach = instance
+        .state
+        .ls_db
+        .iter()
+        .map(|(nlri, attr)| (nlri.clone(), attr.clone()))
+        .collect();
+    send_updates(nbr, instance.config.asn, reach, vec![]);
+
+    // Send End-of-RIB marker.
+    if nbr.is_af_enabled(Afi::BgpLs, Safi::BgpLs) {
+        let msg = Message::Update(UpdateMsg::eor(AfiSafi::BgpLs));
+        nbr.message_send(msg);
+    }
+}
+
+// ===== helper functions =====
+
+fn node_desc(asn: u32, key: &LsNodeKey) -> LsNodeDesc {
+    LsNodeDesc {
+        asn: Some(asn),
+        ospf_area_id: Some(key.area_id),
+        igp_router_id: Some(key.router_id),
+        pseudonode: key.pseudonode,
+    }
+}
+
+fn node_attr(key: &LsNodeKey, node: &LsNode) -> LsAttr {
+    // Pseudonodes don't have any attribute.
+    if key.pseudonode.is_some() {
+        return LsAttr::default();
+    }
+
+    let mut flags = LsNodeFlags::empty();
+    if node.abr {
+        flags.insert(LsNodeFlags::ABR);
+    }
+    if node.asbr {
+        flags.insert(LsNodeFlags::EXTERNAL);
+    }
+    let sr_capabilities = (!node.srgb.is_empty()).then(|| LsSrCapabilities {
+        flags: 0,
+        ranges: node
+            .srgb
+            .iter()
+            .map(|range| LsSrRange {
+                range: range.range,
+                first: ls_sid(range.first),
+            })
+            .collect(),
+    });
+    let sr_algos = (!node.sr_algos.is_empty()).then(|| {
+        node.sr_algos
+            .iter()
+            .map(|algo| algo.to_u8().unwrap())
+            .collect()
+    });
+
+    LsAttr {
+        node_flags: Some(flags),
+        node_name: node.hostname.clone(),
+        sr_capabilities,
+        sr_algos,
+        ..Default::default()
+    }
+}
+
+fn link_desc(key: &LsLinkKey) -> LsLinkDesc {
+    let descriptors = &key.descriptors;
+    let mut link = LsLinkDesc::default();
+    if let Some(local_id) = descriptors.local_id
+        && let Some(remote_id) = descriptors.remote_id
+    {
+        link.local_remote_ids = Some((local_id, remote_id));
+    }
+    match descriptors.local_addr {
+        Some(IpAddr::V4(addr)) => link.ipv4_iface_addr = Some(addr),
+        Some(IpAddr::V6(addr)) => link.ipv6_iface_addr = Some(addr),
+        None => (),
+    }
+    match descriptors.remote_addr {
+        Some(IpAddr::V4(addr)) => link.ipv4_nbr_addr = Some(addr),
+        Some(IpAddr::V6(addr)) => link.ipv6_nbr_addr = Some(addr),
+        None => (),
+    }
+    link
+}
+
+fn prefix_nlri(
+    protocol_id: LsProtocolId,
+    asn: u32,
+    key: &LsPrefixKey,
+) -> LsPrefixNlri {
+    LsPrefixNlri {
+        protocol_id,
+        identifier: 0,
+        local: node_desc(asn, &key.node),
+        prefix: LsPrefixDesc {
+            ospf_route_type: Some(ospf_route_type(key.route_type)),
+            prefix: key.prefix,
+        },
+    }
+}
+
+fn ospf_route_type(route_type: LsPrefixRouteType) -> LsOspfRouteType {
+    match route_type {
+        LsPrefixRouteType::IntraArea => LsOspfRouteType::IntraArea,
+        LsPrefixRouteType::InterArea => LsOspfRouteType::InterArea,
+        LsPrefixRouteType::External1 => LsOspfRouteType::External1,
+        LsPrefixRouteType::External2 => LsOspfRouteType::External2,
+        LsPrefixRouteType::Nssa1 => LsOspfRouteType::Nssa1,
+        LsPrefixRouteType::Nssa2 => LsOspfRouteType::Nssa2,
+    }
+}
+
+fn ls_sid(sid: Sid) -> LsSid {
+    match sid {
+        Sid::Index(index) => LsSid::Index(index),
+        Sid::Label(label) => LsSid::Label(label.get()),
+    }
+}
+
+fn send_updates(
+    nbr: &mut Neighbor,
+    local_asn: u32,
+    reach: Vec<(LsNlri, LsAttr)>,
+    unreach: Vec<LsNlri>,
+) {
+    if nbr.state != fsm::State::Established
+        || !nbr.is_af_enabled(Afi::BgpLs, Safi::BgpLs)
+    {
+        return;
+    }
+
+    let mut msgs = vec![];
+
+    // Reachable NLRIs, grouped by their BGP-LS attribute.
+    let mut reach_groups: BTreeMap<LsAttr, Vec<LsNlri>> = BTreeMap::new();
+    for (nlri, attr) in reach {
+        reach_groups.entry(attr).or_default().push(nlri);
+    }
+    let nexthop = nbr.conn_info.as_ref().unwrap().local_addr;
+    for (attr, nlris) in reach_groups {
+        let attrs = attrs_tx(nbr, local_asn, attr);
+        let nexthop_len = match nexthop {
+            IpAddr::V4(_) => 4,
+            IpAddr::V6(_) => 16,
+        };
+        let max = Message::MAX_LEN
+            - UpdateMsg::MIN_LEN
+            - attrs.length()
+            - ATTR_MIN_LEN_EXT
+            - MpReachNlri::MIN_LEN
+            - nexthop_len;
+        for chunk in chunk_nlris(nlris, max) {
+            let mp_reach = MpReachNlri::BgpLs {
+                nlris: chunk,
+                nexthop,
+            };
+            msgs.push(Message::Update(UpdateMsg {
+                reach: None,
+                unreach: None,
+                mp_reach: Some(mp_reach),
+                mp_unreach: None,
+                attrs: Some(attrs.clone()),
+            }));
+        }
+    }
+
+    // Unreachable NLRIs.
+    let max = Message::MAX_LEN
+        - UpdateMsg::MIN_LEN
+        - ATTR_MIN_LEN_EXT
+        - MpUnreachNlri::MIN_LEN;
+    for chunk in chunk_nlris(unreach, max) {
+        let mp_unreach = MpUnreachNlri::BgpLs { nlris: chunk };
+        msgs.push(Message::Update(UpdateMsg {
+            reach: None,
+            unreach: None,
+            mp_reach: None,
+            mp_unreach: Some(mp_unreach),
+            attrs: None,
+        }));
+    }
+
+    if !msgs.is_empty() {
+        nbr.message_list_send(msgs);
+    }
+}
+
+// Builds the path attributes of locally originated BGP-LS NLRIs.
+fn attrs_tx(nbr: &Neighbor, local_asn: u32, ls: LsAttr) -> Attrs {
+    let mut attrs = Attrs {
+        base: BaseAttrs {
+            origin: Origin::Igp,
+            as_path: AsPath::default(),
+            as4_path: None,
+            nexthop: None,
+            ll_nexthop: None,
+            med: None,
+            local_pref: None,
+            aggregator: None,
+            as4_aggregator: None,
+            atomic_aggregate: false,
+            originator_id: None,
+            cluster_list: None,
+        },
+        comm: None,
+        ext_comm: None,
+        extv6_comm: None,
+        large_comm: None,
+        ls: Some(ls),
+        unknown: Default::default(),
+    };
+    match nbr.peer_type {
+        PeerType::Internal => {
+            attrs.base.local_pref = Some(rib::DFLT_LOCAL_PREF);
+        }
+        PeerType::External => {
+            attrs.base.as_path.prepend(local_asn);
+        }
+    }
+    attrs
+}
+
+// Splits the given NLRIs into chunks that fit within the provided number of
+// bytes.
+fn chunk_nlris(nlris: Vec<LsNlri>, max: u16) -> Vec<Vec<LsNlri>> {
+    let mut chunks = vec![];
+    let mut chunk = vec![];
+    let mut chunk_len = 0;
+    for nlri in nlris {
+        let nlri_len = nlri.length();
+        if chunk_len + nlri_len > max && !chunk.is_empty() {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_len = 0;
+        }
+        chunk_len += nlri_len;
+        chunk.push(nlri);
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+    chunks
+}
@@ -10,34 +10,39 @@ use std::sync::atomic::{self, AtomicU32};
 use std::sync::Arc;
 use std::time::Duration;
 
+use bytes::Bytes;
 use chrono::{DateTime, Utc};
 use holo_protocol::InstanceChannelsTx;
 use holo_utils::bgp::AfiSafi;
 use holo_utils::ibus::IbusSender;
-use holo_utils::socket::{TcpConnInfo, TcpStream, TTL_MAX};
+use holo_utils::ip::IpAddrKind;
+use holo_utils::keychain::Keychains;
+use holo_utils::socket::{Socket, TcpAuth, TcpConnInfo, TcpStream, TTL_MAX};
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
 use holo_utils::{Sender, UnboundedSender};
+use ipnetwork::IpNetwork;
 use tokio::sync::mpsc;
 
 use crate::af::{AddressFamily, Ipv4Unicast, Ipv6Unicast};
 use crate::debug::Debug;
 use crate::error::Error;
-use crate::instance::{Instance, InstanceUpView};
+use crate::instance::{Instance, InstanceState, InstanceUpView};
 use crate::northbound::configuration::{InstanceCfg, NeighborCfg};
 use crate::packet::attribute::Attrs;
 use crate::packet::consts::{
-    Afi, ErrorCode, FsmErrorSubcode, Safi, AS_TRANS, BGP_VERSION,
+    Afi, CeaseSubcode, ErrorCode, FsmErrorSubcode, OrfMatch, OrfMode, OrfType,
+    Safi, AS_TRANS, BGP_VERSION,
 };
 use crate::packet::message::{
     Capability, DecodeCxt, EncodeCxt, KeepaliveMsg, Message,
-    NegotiatedCapability, NotificationMsg, OpenMsg,
+    NegotiatedCapability, NotificationMsg, OpenMsg, OrfTuple, PrefixOrfEntry,
 };
 use crate::rib::{Rib, Route};
 use crate::tasks::messages::input::{NbrRxMsg, NbrTimerMsg, TcpConnectMsg};
 use crate::tasks::messages::output::NbrTxMsg;
 #[cfg(feature = "testing")]
 use crate::tasks::messages::ProtocolOutputMsg;
-use crate::{events, rib, tasks};
+use crate::{events, link_state, network, rib, tasks};
 
 // Large hold-time used during session initialization.
 const LARGE_HOLDTIME: u16 = 240;
@@ -50,23 +55,28 @@ pub struct Neighbor {
     pub state: fsm::State,
     pub peer_type: PeerType,
     pub conn_info: Option<TcpConnInfo>,
+    pub conn_socket: Option<Socket>,
     pub shared_subnet: bool,
     pub identifier: Option<Ipv4Addr>,
     pub holdtime_nego: Option<u16>,
     pub capabilities_adv: BTreeSet<Capability>,
     pub capabilities_rcvd: BTreeSet<Capability>,
     pub capabilities_nego: BTreeSet<NegotiatedCapability>,
+    pub orf_prefixes: BTreeMap<AfiSafi, BTreeMap<u32, PrefixOrfEntry>>,
     pub notification_sent: Option<(DateTime<Utc>, NotificationMsg)>,
     pub notification_rcvd: Option<(DateTime<Utc>, NotificationMsg)>,
     pub last_established: Option<DateTime<Utc>>,
+    pub eor_rcvd: BTreeSet<AfiSafi>,
+    pub eor_pending: BTreeSet<AfiSafi>,
     pub statistics: NeighborStatistics,
     pub tasks: NeighborTasks,
     pub update_queues: NeighborUpdateQueues,
+    pub update_group: Option<u32>,
     pub msg_txp: Option<UnboundedSender<NbrTxMsg>>,
 }
 
 // BGP peer type.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum PeerType {
     Internal,
     External,
@@ -189,25 +199,30 @@ pub mod fsm {
 
 impl Neighbor {
     // Creates a new neighbor in the Idle state with default configuration.
-    pub(crate) fn new(remote_addr: IpAddr, peer_type: PeerType) -> Neighbor {
+    pub fn new(remote_addr: IpAddr, peer_type: PeerType) -> Neighbor {
         Neighbor {
             remote_addr,
             config: Default::default(),
             state: fsm::State::Idle,
             peer_type,
             conn_info: None,
+            conn_socket: None,
             shared_subnet: false,
             identifier: None,
             holdtime_nego: None,
             capabilities_adv: Default::default(),
             capabilities_rcvd: Default::default(),
             capabilities_nego: Default::default(),
+            orf_prefixes: Default::default(),
             notification_sent: None,
             notification_rcvd: None,
             last_established: None,
+            eor_rcvd: Default::default(),
+            eor_pending: Default::default(),
             statistics: Default::default(),
             tasks: Default::default(),
             update_queues: Default::default(),
+            update_group: None,
             msg_txp: None,
         }
     }
@@ -221,7 +236,7 @@ impl Neighbor {
         Debug::NbrFsmEvent(&self.remote_addr, &event).log();
 
         // Process FSM event.
-        let rib = &mut instance.state.rib;
+        let state = &mut *instance.state;
         let next_state = match self.state {
             // Idle state
             fsm::State::Idle => match event {
@@ -233,7 +248,10 @@ impl Neighbor {
                     if self.config.transport.passive_mode {
                         Some(fsm::State::Active)
                     } else {
-                        self.connect(&instance.tx.protocol_input.tcp_connect);
+                        self.connect(
+                            &instance.shared.keychains,
+                            &instance.tx.protocol_input.tcp_connect,
+                        );
                         Some(fsm::State::Connect)
                     }
                 }
@@ -243,7 +261,7 @@ impl Neighbor {
             fsm::State::Connect => match event {
                 fsm::Event::Start => None,
                 fsm::Event::Stop(_) => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::Connected(stream, conn_info) => {
@@ -263,16 +281,19 @@ impl Neighbor {
                     Some(fsm::State::OpenSent)
                 }
                 fsm::Event::ConnFail => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdError(error) => {
                     let msg = NotificationMsg::from(error);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::Timer(fsm::Timer::ConnectRetry) => {
-                    self.connect(&instance.tx.protocol_input.tcp_connect);
+                    self.connect(
+                        &instance.shared.keychains,
+                        &instance.tx.protocol_input.tcp_connect,
+                    );
                     self.connect_retry_start(
                         &instance.tx.protocol_input.nbr_timer,
                     );
@@ -280,7 +301,7 @@ impl Neighbor {
                 }
                 _ => {
                     // FSM error.
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
             },
@@ -288,7 +309,7 @@ impl Neighbor {
             fsm::State::Active => match event {
                 fsm::Event::Start => None,
                 fsm::Event::Stop(_) => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::Connected(stream, conn_info) => {
@@ -308,16 +329,19 @@ impl Neighbor {
                     Some(fsm::State::OpenSent)
                 }
                 fsm::Event::ConnFail => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdError(error) => {
                     let msg = NotificationMsg::from(error);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::Timer(fsm::Timer::ConnectRetry) => {
-                    self.connect(&instance.tx.protocol_input.tcp_connect);
+                    self.connect(
+                        &instance.shared.keychains,
+                        &instance.tx.protocol_input.tcp_connect,
+                    );
                     self.connect_retry_start(
                         &instance.tx.protocol_input.nbr_timer,
                     );
@@ -325,7 +349,7 @@ impl Neighbor {
                 }
                 _ => {
                     // FSM error.
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
             },
@@ -333,11 +357,11 @@ impl Neighbor {
             fsm::State::OpenSent => match event {
                 fsm::Event::Start => None,
                 fsm::Event::Stop(msg) => {
-                    self.session_close(rib, instance.tx, msg);
+                    self.session_close(state, instance.tx, msg);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::ConnFail => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     self.connect_retry_start(
                         &instance.tx.protocol_input.nbr_timer,
                     );
@@ -345,7 +369,7 @@ impl Neighbor {
                 }
                 fsm::Event::RcvdError(error) => {
                     let msg = NotificationMsg::from(error);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdOpen(msg) => {
@@ -356,7 +380,7 @@ impl Neighbor {
                     let error_code = ErrorCode::HoldTimerExpired;
                     let error_subcode = 0;
                     let msg = NotificationMsg::new(error_code, error_subcode);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 _ => {
@@ -365,7 +389,7 @@ impl Neighbor {
                     let error_subcode =
                         FsmErrorSubcode::UnexpectedMessageInOpenSent;
                     let msg = NotificationMsg::new(error_code, error_subcode);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
             },
@@ -373,16 +397,16 @@ impl Neighbor {
             fsm::State::OpenConfirm => match event {
                 fsm::Event::Start => None,
                 fsm::Event::Stop(msg) => {
-                    self.session_close(rib, instance.tx, msg);
+                    self.session_close(state, instance.tx, msg);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::ConnFail => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdError(error) => {
                     let msg = NotificationMsg::from(error);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdOpen(_msg) => {
@@ -390,7 +414,7 @@ impl Neighbor {
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdNotif(_) => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdKalive => {
@@ -401,7 +425,7 @@ impl Neighbor {
                     let error_code = ErrorCode::HoldTimerExpired;
                     let error_subcode = 0;
                     let msg = NotificationMsg::new(error_code, error_subcode);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 _ => {
@@ -410,7 +434,7 @@ impl Neighbor {
                     let error_subcode =
                         FsmErrorSubcode::UnexpectedMessageInOpenConfirm;
                     let msg = NotificationMsg::new(error_code, error_subcode);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
             },
@@ -418,20 +442,20 @@ impl Neighbor {
             fsm::State::Established => match event {
                 fsm::Event::Start => None,
                 fsm::Event::Stop(msg) => {
-                    self.session_close(rib, instance.tx, msg);
+                    self.session_close(state, instance.tx, msg);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::ConnFail => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdError(error) => {
                     let msg = NotificationMsg::from(error);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdNotif(_) => {
-                    self.session_close(rib, instance.tx, None);
+                    self.session_close(state, instance.tx, None);
                     Some(fsm::State::Idle)
                 }
                 fsm::Event::RcvdKalive | fsm::Event::RcvdUpdate => {
@@ -442,7 +466,7 @@ impl Neighbor {
                     let error_code = ErrorCode::HoldTimerExpired;
                     let error_subcode = 0;
                     let msg = NotificationMsg::new(error_code, error_subcode);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
                 _ => {
@@ -451,7 +475,7 @@ impl Neighbor {
                     let error_subcode =
                         FsmErrorSubcode::UnexpectedMessageInEstablished;
                     let msg = NotificationMsg::new(error_code, error_subcode);
-                    self.session_close(rib, instance.tx, Some(msg));
+                    self.session_close(state, instance.tx, Some(msg));
                     Some(fsm::State::Idle)
                 }
             },
@@ -512,6 +536,20 @@ impl Neighbor {
         // Store TCP connection information.
         self.conn_info = Some(conn_info);
 
+        // Keep a handle to the connected socket, used to update its TCP
+        // authentication keys.
+        #[cfg(not(feature = "testing"))]
+        {
+            use holo_utils::socket::TcpStreamExt;
+
+            use crate::error::IoError;
+
+            self.conn_socket = stream
+                .try_clone_socket()
+                .map_err(|error| IoError::TcpSocketError(error).log())
+                .ok();
+        }
+
         // Split TCP stream into two halves.
         let (read_half, write_half) = stream.into_split();
 
@@ -567,15 +605,24 @@ impl Neighbor {
         let msg = NbrTxMsg::UpdateCapabilities(self.capabilities_nego.clone());
         let _ = self.msg_txp.as_ref().unwrap().send(msg);
 
-        // Send initial routing updates.
+        // Join the update group matching the neighbor's outbound parameters.
+        instance
+            .state
+            .update_groups
+            .join(self, &instance.shared.policies);
+
+        // Send initial routing updates. The End-of-RIB markers of the unicast
+        // address-families are sent once their export policies are applied.
+        self.eor_pending = [AfiSafi::Ipv4Unicast, AfiSafi::Ipv6Unicast].into();
         self.initial_routing_update::<Ipv4Unicast>(instance);
         self.initial_routing_update::<Ipv6Unicast>(instance);
+        link_state::initial_update(instance, self);
     }
 
     // Closes the BGP session, performing necessary cleanup and releasing resources.
     fn session_close(
         &mut self,
-        rib: &mut Rib,
+        state: &mut InstanceState,
         instance_tx: &InstanceChannelsTx<Instance>,
         send_notif: Option<NotificationMsg>,
     ) {
@@ -589,15 +636,22 @@ impl Neighbor {
         // Set the ConnectRetryTimer to zero.
         self.connect_retry_stop();
 
+        // Leave the update group.
+        state.update_groups.leave(self);
+
         // Release all resources.
         self.conn_info = None;
+        self.conn_socket = None;
         self.identifier = None;
         self.holdtime_nego = None;
         self.capabilities_adv.clear();
         self.capabilities_rcvd.clear();
         self.capabilities_nego.clear();
-        self.clear_routes::<Ipv4Unicast>(rib, &instance_tx.ibus);
-        self.clear_routes::<Ipv6Unicast>(rib, &instance_tx.ibus);
+        self.orf_prefixes.clear();
+        self.eor_rcvd.clear();
+        self.eor_pending.clear();
+        self.clear_routes::<Ipv4Unicast>(&mut state.rib, &instance_tx.ibus);
+        self.clear_routes::<Ipv6Unicast>(&mut state.rib, &instance_tx.ibus);
         self.tasks = Default::default();
         self.msg_txp = None;
 
@@ -628,7 +682,7 @@ impl Neighbor {
     //
     // This method is more efficient for handling a large number of messages,
     // as they are sent all at once.
-    pub(crate) fn message_list_send(&mut self, msg_list: Vec<Message>) {
+    pub fn message_list_send(&mut self, msg_list: Vec<Message>) {
         for msg in &msg_list {
             Debug::NbrMsgTx(&self.remote_addr, msg).log();
 
@@ -648,6 +702,29 @@ impl Neighbor {
         let _ = self.msg_txp.as_ref().unwrap().send(msg);
     }
 
+    // Enqueues a list of already encoded BGP messages for transmission.
+    //
+    // This is used to replicate the UPDATE messages built for an update group
+    // to all of its members, avoiding encoding the same messages repeatedly.
+    pub(crate) fn message_list_send_encoded(
+        &mut self,
+        msg_list: &[Message],
+        buf: Bytes,
+    ) {
+        for msg in msg_list {
+            Debug::NbrMsgTx(&self.remote_addr, msg).log();
+
+            // Update statistics.
+            self.statistics.msgs_sent.update(msg);
+        }
+
+        // Ignore any possible error as the connection might have gone down
+        // already.
+        let nbr_addr = self.remote_addr;
+        let msg = NbrTxMsg::SendEncoded { nbr_addr, buf };
+        let _ = self.msg_txp.as_ref().unwrap().send(msg);
+    }
+
     // Sends a BGP OPEN message based on the local configuration.
     fn open_send(&mut self, instance_cfg: &InstanceCfg, identifier: Ipv4Addr) {
         // Base capabilities.
@@ -676,6 +753,36 @@ impl Neighbor {
                 safi: Safi::Unicast,
             });
         }
+        if let Some(afi_safi) = self.config.afi_safi.get(&AfiSafi::BgpLs)
+            && afi_safi.enabled
+        {
+            capabilities.insert(Capability::MultiProtocol {
+                afi: Afi::BgpLs,
+                safi: Safi::BgpLs,
+            });
+        }
+
+        // Outbound Route Filtering capability.
+        let orf_tuples = [
+            (AfiSafi::Ipv4Unicast, Afi::Ipv4, Safi::Unicast),
+            (AfiSafi::Ipv6Unicast, Afi::Ipv6, Safi::Unicast),
+        ]
+        .into_iter()
+        .filter(|(afi_safi, _, _)| {
+            self.config.afi_safi.get(afi_safi).is_some_and(|afi_safi| {
+                afi_safi.enabled && afi_safi.prefix_orf_receive
+            })
+        })
+        .map(|(_, afi, safi)| OrfTuple {
+            afi,
+            safi,
+            orf_type: OrfType::AddressPrefix,
+            mode: OrfMode::Receive,
+        })
+        .collect::<BTreeSet<_>>();
+        if !orf_tuples.is_empty() {
+            capabilities.insert(Capability::OutboundRouteFiltering(orf_tuples));
+        }
 
         // Keep track of the advertised capabilities.
         self.capabilities_adv.clone_from(&capabilities);
@@ -719,7 +826,7 @@ impl Neighbor {
                 }
                 _ => None,
             };
-            self.session_close(&mut instance.state.rib, instance.tx, msg);
+            self.session_close(instance.state, instance.tx, msg);
 
             // Transition to the Idle state.
             return fsm::State::Idle;
@@ -822,9 +929,100 @@ impl Neighbor {
         self.tasks.autostart = None;
     }
 
+    // Returns the TCP authentication method used by the neighbor, if any.
+    //
+    // Fails when TCP-AO is configured but its keychain doesn't exist or has no
+    // active keys, in which case the session must be kept down.
+    pub(crate) fn tcp_auth(
+        &self,
+        keychains: &Keychains,
+    ) -> Result<Option<TcpAuth>, Error> {
+        let transport = &self.config.transport;
+        if !transport.secure_session_enabled {
+            return Ok(None);
+        }
+
+        if let Some(keychain) = &transport.ao_keychain {
+            return keychains
+                .get(keychain)
+                .cloned()
+                .map(TcpAuth::Ao)
+                .filter(TcpAuth::is_usable)
+                .map(Some)
+                .ok_or(Error::NbrTcpAuthUnavailable(self.remote_addr));
+        }
+        Ok(transport.md5_key.clone().map(TcpAuth::Md5))
+    }
+
+    // Updates the neighbor's TCP authentication in the listening sockets and
+    // in the established connection, if any.
+    //
+    // The session is reset when the TCP authentication can't be enforced
+    // anymore (e.g. all TCP-AO keys expired).
+    pub(crate) fn tcp_auth_update(&mut self, instance: &mut InstanceUpView<'_>) {
+        self.listen_sockets_auth_update(instance);
+
+        match self.tcp_auth(&instance.shared.keychains) {
+            Ok(Some(tcp_auth)) => {
+                if let Some(conn_socket) = &self.conn_socket {
+                    network::conn_socket_auth_update(
+                        conn_socket,
+                        &self.remote_addr,
+                        &tcp_auth,
+                    );
+                }
+            }
+            Ok(None) => (),
+            Err(error) => {
+                if self.conn_info.is_some() {
+                    error.log();
+                    let msg = NotificationMsg::new(
+                        ErrorCode::Cease,
+                        CeaseSubcode::OtherConfigurationChange,
+                    );
+                    self.fsm_event(instance, fsm::Event::Stop(Some(msg)));
+                }
+            }
+        }
+    }
+
+    // Updates the neighbor's TCP authentication in the listening sockets.
+    //
+    // When the TCP authentication is unavailable, incoming connections from
+    // the neighbor are rejected once accepted.
+    pub(crate) fn listen_sockets_auth_update(
+        &self,
+        instance: &InstanceUpView<'_>,
+    ) {
+        let tcp_auth =
+            self.tcp_auth(&instance.shared.keychains).ok().flatten();
+        for listener in
+            instance.state.listening_sockets.iter().filter(|listener| {
+                listener.af == self.remote_addr.address_family()
+            })
+        {
+            network::listen_socket_auth_update(
+                &listener.socket,
+                &self.remote_addr,
+                tcp_auth.as_ref(),
+            );
+        }
+    }
+
     // Starts a TCP connection task to the neighbor's remote address.
-    fn connect(&mut self, tcp_connectp: &Sender<TcpConnectMsg>) {
-        let task = tasks::tcp_connect(self, tcp_connectp);
+    fn connect(
+        &mut self,
+        keychains: &Keychains,
+        tcp_connectp: &Sender<TcpConnectMsg>,
+    ) {
+        let tcp_auth = match self.tcp_auth(keychains) {
+            Ok(tcp_auth) => tcp_auth,
+            Err(error) => {
+                error.log();
+                return;
+            }
+        };
+        let task = tasks::tcp_connect(self, tcp_auth, tcp_connectp);
         self.tasks.connect = Some(task);
     }
 
@@ -878,8 +1076,13 @@ impl Neighbor {
 
     // Sends an initial routing update for the specified address-family after
     // the session is established.
-    fn initial_routing_update<A>(&mut self, instance: &mut InstanceUpView<'_>)
-    where
+    //
+    // This is also used to re-evaluate the Adj-RIB-Out after the Address Prefix
+    // ORF entries received from the neighbor have changed.
+    pub(crate) fn initial_routing_update<A>(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+    ) where
         A: AddressFamily,
     {
         // Check if the address-family is enabled for this session.
@@ -946,23 +1149,60 @@ impl Neighbor {
 
     // Check if the given address-family is enabled for this session.
     pub(crate) fn is_af_enabled(&self, afi: Afi, safi: Safi) -> bool {
-        // Check if the corresponding multi-protocol capability has been
-        // negotiated.
-        let cap = NegotiatedCapability::MultiProtocol { afi, safi };
-        if self.capabilities_nego.contains(&cap) {
-            return true;
-        }
+        af_enabled(&self.capabilities_nego, afi, safi)
+    }
 
-        // If the peer doesn't support BGP capabilities, the IPv4 unicast
-        // address-family is enabled by default.
-        if self.capabilities_nego.is_empty()
-            && afi == Afi::Ipv4
-            && safi == Safi::Unicast
-        {
-            return true;
-        }
+    // Returns whether the session is established and End-of-RIB markers were
+    // received for all negotiated address-families.
+    pub fn is_converged(&self) -> bool {
+        self.state == fsm::State::Established
+            && [
+                (AfiSafi::Ipv4Unicast, Afi::Ipv4, Safi::Unicast),
+                (AfiSafi::Ipv6Unicast, Afi::Ipv6, Safi::Unicast),
+                (AfiSafi::BgpLs, Afi::BgpLs, Safi::BgpLs),
+            ]
+            .into_iter()
+            .filter(|(_, afi, safi)| self.is_af_enabled(*afi, *safi))
+            .all(|(afi_safi, _, _)| self.eor_rcvd.contains(&afi_safi))
+    }
 
-        false
+    // Checks whether Address Prefix ORF entries can be accepted from the
+    // neighbor for the given address-family.
+    //
+    // This requires the local system to have advertised the ability to
+    // receive them, and the neighbor to have advertised the ability to send
+    // them.
+    pub(crate) fn is_orf_receive_enabled(&self, afi: Afi, safi: Safi) -> bool {
+        let orf_mode = |capabilities: &BTreeSet<Capability>| {
+            capabilities
+                .iter()
+                .filter_map(|cap| cap.as_outbound_route_filtering())
+                .flatten()
+                .find(|tuple| {
+                    tuple.afi == afi
+                        && tuple.safi == safi
+                        && tuple.orf_type == OrfType::AddressPrefix
+                })
+                .map(|tuple| tuple.mode)
+        };
+
+        matches!(
+            orf_mode(&self.capabilities_adv),
+            Some(OrfMode::Receive | OrfMode::Both)
+        ) && matches!(
+            orf_mode(&self.capabilities_rcvd),
+            Some(OrfMode::Send | OrfMode::Both)
+        )
+    }
+
+    // Checks whether the given prefix is permitted by the Address Prefix ORF
+    // entries received from the neighbor.
+    pub(crate) fn orf_permits(
+        &self,
+        afi_safi: AfiSafi,
+        prefix: &IpNetwork,
+    ) -> bool {
+        orf_prefix_filter(self.orf_prefixes.get(&afi_safi), prefix)
     }
 }
 
@@ -989,7 +1229,7 @@ impl MessageStatistics {
 // ===== impl NeighborUpdateQueues =====
 
 impl NeighborUpdateQueues {
-    pub(crate) fn build_updates(&mut self) -> Vec<Message> {
+    pub fn build_updates(&mut self) -> Vec<Message> {
         [
             self.ipv4_unicast.build_updates(),
             self.ipv6_unicast.build_updates(),
@@ -1020,3 +1260,49 @@ where
         }
     }
 }
+
+// ===== global functions =====
+
+// Checks whether the given prefix is permitted by the provided Address Prefix
+// ORF entries.
+//
+// The entries are evaluated in ascending sequence order, and the first
+// matching entry determines the result. If no entry matches, the prefix is
+// implicitly denied. An empty or non-existent ORF permits all prefixes.
+pub(crate) fn orf_prefix_filter(
+    entries: Option<&BTreeMap<u32, PrefixOrfEntry>>,
+    prefix: &IpNetwork,
+) -> bool {
+    let Some(entries) = entries.filter(|entries| !entries.is_empty()) else {
+        return true;
+    };
+
+    entries
+        .values()
+        .find(|entry| entry.matches(prefix))
+        .is_some_and(|entry| entry.match_type == OrfMatch::Permit)
+}
+
+// Check if the given address-family is enabled based on the provided set of
+// negotiated capabilities.
+pub(crate) fn af_enabled(
+    capabilities_nego: &BTreeSet<NegotiatedCapability>,
+    afi: Afi,
+    safi: Safi,
+) -> bool {
+    // Check if the corresponding multi-protocol capability has been
+    // negotiated.
+    let cap = NegotiatedCapability::MultiProtocol { afi, safi };
+    if capabilities_nego.contains(&cap) {
+        return true;
+    }
+
+    // If the peer doesn't support BGP capabilities, the IPv4 unicast
+    // address-family is enabled by default.
+    if capabilities_nego.is_empty() && afi == Afi::Ipv4 && safi == Safi::Unicast
+    {
+        return true;
+    }
+
+    false
+}
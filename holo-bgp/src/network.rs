@@ -10,8 +10,8 @@ use std::sync::Arc;
 
 use holo_utils::ip::{AddressFamily, IpAddrExt, IpAddrKind};
 use holo_utils::socket::{
-    OwnedReadHalf, OwnedWriteHalf, SocketExt, TcpConnInfo, TcpListener,
-    TcpSocket, TcpSocketExt, TcpStream, TcpStreamExt, TTL_MAX,
+    OwnedReadHalf, OwnedWriteHalf, Socket, SocketExt, TcpAuth, TcpConnInfo,
+    TcpListener, TcpSocket, TcpSocketExt, TcpStream, TcpStreamExt, TTL_MAX,
 };
 use holo_utils::{capabilities, Sender, UnboundedReceiver};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -60,14 +60,27 @@ pub(crate) fn listen_socket(
     }
 }
 
-pub(crate) fn listen_socket_md5sig_update(
+pub(crate) fn listen_socket_auth_update(
     socket: &TcpListener,
     nbr_addr: &IpAddr,
-    password: Option<&str>,
+    auth: Option<&TcpAuth>,
 ) {
     #[cfg(not(feature = "testing"))]
     {
-        if let Err(error) = socket.set_md5sig(nbr_addr, password) {
+        if let Err(error) = socket.set_tcp_auth(nbr_addr, auth) {
+            IoError::TcpAuthError(error).log();
+        }
+    }
+}
+
+pub(crate) fn conn_socket_auth_update(
+    socket: &Socket,
+    nbr_addr: &IpAddr,
+    auth: &TcpAuth,
+) {
+    #[cfg(not(feature = "testing"))]
+    {
+        if let Err(error) = socket.set_tcp_auth(nbr_addr, Some(auth)) {
             IoError::TcpAuthError(error).log();
         }
     }
@@ -139,7 +152,7 @@ pub(crate) async fn connect(
     ttl: u8,
     ttl_security: Option<u8>,
     tcp_mss: Option<u16>,
-    tcp_password: &Option<String>,
+    tcp_auth: &Option<TcpAuth>,
 ) -> Result<(TcpStream, TcpConnInfo), Error> {
     let af = remote_addr.address_family();
 
@@ -180,10 +193,10 @@ pub(crate) async fn connect(
             .map_err(IoError::TcpSocketError)?;
     }
 
-    // Set the TCP MD5 password.
-    if let Some(tcp_password) = tcp_password {
+    // Set the TCP authentication option (TCP-MD5 or TCP-AO).
+    if let Some(tcp_auth) = tcp_auth {
         socket
-            .set_md5sig(&remote_addr, Some(tcp_password))
+            .set_tcp_auth(&remote_addr, Some(tcp_auth))
             .map_err(IoError::TcpAuthError)?;
     }
 
@@ -224,6 +237,12 @@ pub(crate) async fn nbr_write_loop(
                     }
                 }
             }
+            // Send list of pre-encoded messages to the peer.
+            NbrTxMsg::SendEncoded { buf, .. } => {
+                if let Err(error) = stream.write_all(&buf).await {
+                    IoError::TcpSendError(error).log();
+                }
+            }
             // Update negotiated capabilities.
             NbrTxMsg::UpdateCapabilities(caps) => cxt.capabilities = caps,
         }
@@ -23,6 +23,7 @@ use holo_utils::policy::{ApplyPolicyCfg, DefaultPolicyType};
 use holo_utils::yang::DataNodeRefExt;
 use holo_yang::TryFromYang;
 
+use crate::events;
 use crate::instance::Instance;
 use crate::neighbor::{fsm, Neighbor, PeerType};
 use crate::network;
@@ -48,6 +49,7 @@ pub enum Event {
     NeighborDelete(IpAddr),
     NeighborReset(IpAddr, NotificationMsg),
     NeighborUpdateAuth(IpAddr),
+    NeighborUpdateExportPolicy(IpAddr),
 }
 
 pub static VALIDATION_CALLBACKS: Lazy<ValidationCallbacks> =
@@ -79,6 +81,7 @@ pub struct MultipathCfg {
     pub ebgp_allow_multiple_as: bool,
     pub ebgp_max_paths: u32,
     pub ibgp_max_paths: u32,
+    pub link_bandwidth: bool,
 }
 
 #[derive(Debug)]
@@ -104,6 +107,7 @@ pub struct NeighborCfg {
     pub apply_policy: ApplyPolicyCfg,
     pub prefix_limit: PrefixLimitCfg,
     pub afi_safi: BTreeMap<AfiSafi, NeighborAfiSafiCfg>,
+    pub shutdown_communication: Option<String>,
 }
 
 #[derive(Debug)]
@@ -125,6 +129,7 @@ pub struct NeighborTransportCfg {
     pub passive_mode: bool,
     pub ttl_security: Option<u8>,
     pub secure_session_enabled: bool,
+    pub ao_keychain: Option<String>,
     pub md5_key: Option<String>,
 }
 
@@ -134,6 +139,7 @@ pub struct NeighborAfiSafiCfg {
     pub prefix_limit: PrefixLimitCfg,
     pub send_default_route: bool,
     pub apply_policy: ApplyPolicyCfg,
+    pub prefix_orf_receive: bool,
 }
 
 #[derive(Debug)]
@@ -227,6 +233,11 @@ fn load_callbacks() -> Callbacks<Instance> {
             let max = args.dnode.get_u32();
             instance.config.multipath.ibgp_max_paths = max;
         })
+        .path(bgp::global::use_multiple_paths::link_bandwidth::PATH)
+        .modify_apply(|instance, args| {
+            let link_bandwidth = args.dnode.get_bool();
+            instance.config.multipath.link_bandwidth = link_bandwidth;
+        })
         .path(bgp::global::route_selection_options::always_compare_med::PATH)
         .modify_apply(|instance, args| {
             let compare = args.dnode.get_bool();
@@ -348,6 +359,14 @@ fn load_callbacks() -> Callbacks<Instance> {
             let max = args.dnode.get_u32();
             afi_safi.multipath.ibgp_max_paths = max;
         })
+        .path(bgp::global::afi_safis::afi_safi::use_multiple_paths::link_bandwidth::PATH)
+        .modify_apply(|instance, args| {
+            let afi_safi = args.list_entry.into_afi_safi().unwrap();
+            let afi_safi = instance.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let link_bandwidth = args.dnode.get_bool();
+            afi_safi.multipath.link_bandwidth = link_bandwidth;
+        })
         .path(bgp::global::afi_safis::afi_safi::apply_policy::import_policy::PATH)
         .create_apply(|instance, args| {
             let afi_safi = args.list_entry.into_afi_safi().unwrap();
@@ -577,6 +596,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let enabled = args.dnode.get_bool();
             nbr.config.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdate(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::peer_as::PATH)
         .modify_apply(|instance, args| {
@@ -634,6 +656,20 @@ fn load_callbacks() -> Callbacks<Instance> {
         .delete_apply(|_instance, _args| {
             // Nothing to do.
         })
+        .path(bgp::neighbors::neighbor::shutdown_communication::PATH)
+        .modify_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            let communication = args.dnode.get_string();
+            nbr.config.shutdown_communication = Some(communication);
+        })
+        .delete_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            nbr.config.shutdown_communication = None;
+        })
         .path(bgp::neighbors::neighbor::timers::connect_retry_interval::PATH)
         .modify_apply(|instance, args| {
             let nbr_addr = args.list_entry.into_neighbor().unwrap();
@@ -816,6 +852,36 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::NeighborReset(nbr.remote_addr, msg));
             event_queue.insert(Event::NeighborUpdateAuth(nbr.remote_addr));
         })
+        .path(bgp::neighbors::neighbor::transport::secure_session::options::ao_keychain::PATH)
+        .modify_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            let keychain = args.dnode.get_string();
+            nbr.config.transport.ao_keychain = Some(keychain);
+
+            let event_queue = args.event_queue;
+            let msg = NotificationMsg::new(
+                ErrorCode::Cease,
+                CeaseSubcode::OtherConfigurationChange,
+            );
+            event_queue.insert(Event::NeighborReset(nbr.remote_addr, msg));
+            event_queue.insert(Event::NeighborUpdateAuth(nbr.remote_addr));
+        })
+        .delete_apply(|instance, args| {
+            let nbr_addr = args.list_entry.into_neighbor().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+
+            nbr.config.transport.ao_keychain = None;
+
+            let event_queue = args.event_queue;
+            let msg = NotificationMsg::new(
+                ErrorCode::Cease,
+                CeaseSubcode::OtherConfigurationChange,
+            );
+            event_queue.insert(Event::NeighborReset(nbr.remote_addr, msg));
+            event_queue.insert(Event::NeighborUpdateAuth(nbr.remote_addr));
+        })
         .path(bgp::neighbors::neighbor::transport::secure_session::options::md5_key_string::PATH)
         .modify_apply(|instance, args| {
             let nbr_addr = args.list_entry.into_neighbor().unwrap();
@@ -877,6 +943,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let disable = args.dnode.get_bool();
             nbr.config.as_path_options.disable_peer_as_filter = disable;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateExportPolicy(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::apply_policy::import_policy::PATH)
         .create_apply(|instance, args| {
@@ -909,6 +978,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             nbr.config.apply_policy.export_policy.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateExportPolicy(nbr_addr));
         })
         .delete_apply(|instance, args| {
             let nbr_addr = args.list_entry.into_neighbor().unwrap();
@@ -916,6 +988,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             nbr.config.apply_policy.export_policy.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateExportPolicy(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::apply_policy::default_export_policy::PATH)
         .modify_apply(|instance, args| {
@@ -925,6 +1000,9 @@ fn load_callbacks() -> Callbacks<Instance> {
             let default = args.dnode.get_string();
             let default = DefaultPolicyType::try_from_yang(&default).unwrap();
             nbr.config.apply_policy.default_export_policy = default;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateExportPolicy(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::prefix_limit::max_prefixes::PATH)
         .modify_apply(|instance, args| {
@@ -1006,6 +1084,15 @@ fn load_callbacks() -> Callbacks<Instance> {
             let enabled = args.dnode.get_bool();
             afi_safi.enabled = enabled;
         })
+        .path(bgp::neighbors::neighbor::afi_safis::afi_safi::prefix_orf_receive::PATH)
+        .modify_apply(|instance, args| {
+            let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
+            let nbr = instance.neighbors.get_mut(&nbr_addr).unwrap();
+            let afi_safi = nbr.config.afi_safi.get_mut(&afi_safi).unwrap();
+
+            let prefix_orf_receive = args.dnode.get_bool();
+            afi_safi.prefix_orf_receive = prefix_orf_receive;
+        })
         .path(bgp::neighbors::neighbor::afi_safis::afi_safi::apply_policy::import_policy::PATH)
         .create_apply(|instance, args| {
             let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
@@ -1041,6 +1128,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             afi_safi.apply_policy.export_policy.insert(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateExportPolicy(nbr_addr));
         })
         .delete_apply(|instance, args| {
             let (nbr_addr, afi_safi) = args.list_entry.into_neighbor_afi_safi().unwrap();
@@ -1049,6 +1139,9 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let policy = args.dnode.get_string();
             afi_safi.apply_policy.export_policy.remove(&policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateExportPolicy(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::afi_safis::afi_safi::apply_policy::default_export_policy::PATH)
         .modify_apply(|instance, args| {
@@ -1059,6 +1152,9 @@ fn load_callbacks() -> Callbacks<Instance> {
             let default = args.dnode.get_string();
             let default = DefaultPolicyType::try_from_yang(&default).unwrap();
             afi_safi.apply_policy.default_export_policy = default;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::NeighborUpdateExportPolicy(nbr_addr));
         })
         .path(bgp::neighbors::neighbor::afi_safis::afi_safi::ipv4_unicast::prefix_limit::max_prefixes::PATH)
         .modify_apply(|instance, args| {
@@ -1229,11 +1325,16 @@ impl Provider for Instance {
                 if nbr.config.enabled {
                     nbr.fsm_event(&mut instance, fsm::Event::Start);
                 } else {
-                    let error_code = ErrorCode::Cease;
-                    let error_subcode = CeaseSubcode::AdministrativeShutdown;
-                    let msg = NotificationMsg::new(error_code, error_subcode);
+                    let msg = NotificationMsg::new_cease(
+                        CeaseSubcode::AdministrativeShutdown,
+                        nbr.config.shutdown_communication.as_deref(),
+                    );
                     nbr.fsm_event(&mut instance, fsm::Event::Stop(Some(msg)));
                 }
+
+                // The initial convergence might no longer depend on this
+                // neighbor.
+                events::initial_convergence_check(&mut instance, neighbors);
             }
             Event::NeighborDelete(nbr_addr) => {
                 let Some((mut instance, neighbors)) = self.as_up() else {
@@ -1241,13 +1342,13 @@ impl Provider for Instance {
                 };
                 let nbr = neighbors.get_mut(&nbr_addr).unwrap();
 
-                // Unset neighbor's password in the listening sockets.
+                // Unset neighbor's authentication in the listening sockets.
                 for listener in
                     instance.state.listening_sockets.iter().filter(|listener| {
                         listener.af == nbr_addr.address_family()
                     })
                 {
-                    network::listen_socket_md5sig_update(
+                    network::listen_socket_auth_update(
                         &listener.socket,
                         &nbr_addr,
                         None,
@@ -1260,6 +1361,10 @@ impl Provider for Instance {
                 let msg = NotificationMsg::new(error_code, error_subcode);
                 nbr.fsm_event(&mut instance, fsm::Event::Stop(Some(msg)));
                 neighbors.remove(&nbr_addr);
+
+                // The initial convergence might no longer depend on this
+                // neighbor.
+                events::initial_convergence_check(&mut instance, neighbors);
             }
             Event::NeighborReset(nbr_addr, msg) => {
                 let Some((mut instance, neighbors)) = self.as_up() else {
@@ -1270,32 +1375,24 @@ impl Provider for Instance {
                 nbr.fsm_event(&mut instance, fsm::Event::Stop(Some(msg)));
             }
             Event::NeighborUpdateAuth(nbr_addr) => {
-                let Some((instance, neighbors)) = self.as_up() else {
+                let Some((mut instance, neighbors)) = self.as_up() else {
                     return;
                 };
                 let nbr = neighbors.get_mut(&nbr_addr).unwrap();
 
-                // Get neighbor password.
-                let key = if nbr.config.transport.secure_session_enabled
-                    && let Some(key) = &nbr.config.transport.md5_key
-                {
-                    Some(key.clone())
-                } else {
-                    None
+                // Set/unset authentication in the listening sockets and in
+                // the established connection.
+                nbr.tcp_auth_update(&mut instance);
+            }
+            Event::NeighborUpdateExportPolicy(nbr_addr) => {
+                let Some((mut instance, neighbors)) = self.as_up() else {
+                    return;
                 };
+                let nbr = neighbors.get_mut(&nbr_addr).unwrap();
 
-                // Set/unset password in the listening sockets.
-                for listener in
-                    instance.state.listening_sockets.iter().filter(|listener| {
-                        listener.af == nbr_addr.address_family()
-                    })
-                {
-                    network::listen_socket_md5sig_update(
-                        &listener.socket,
-                        &nbr_addr,
-                        key.as_deref(),
-                    );
-                }
+                // Move the neighbor to the update group matching its new
+                // outbound parameters and re-advertise its Adj-RIB-Out.
+                events::process_nbr_export_policy_update(&mut instance, nbr);
             }
         }
     }
@@ -1335,12 +1432,15 @@ impl Default for MultipathCfg {
             bgp::global::use_multiple_paths::ebgp::maximum_paths::DFLT;
         let ibgp_max_paths =
             bgp::global::use_multiple_paths::ibgp::maximum_paths::DFLT;
+        let link_bandwidth =
+            bgp::global::use_multiple_paths::link_bandwidth::DFLT;
 
         MultipathCfg {
             enabled,
             ebgp_allow_multiple_as,
             ebgp_max_paths,
             ibgp_max_paths,
+            link_bandwidth,
         }
     }
 }
@@ -1377,6 +1477,7 @@ impl Default for NeighborCfg {
             apply_policy: Default::default(),
             prefix_limit: Default::default(),
             afi_safi: Default::default(),
+            shutdown_communication: None,
         }
     }
 }
@@ -1414,6 +1515,7 @@ impl Default for NeighborTransportCfg {
             passive_mode,
             ttl_security: None,
             secure_session_enabled,
+            ao_keychain: None,
             md5_key: None,
         }
     }
@@ -1423,12 +1525,15 @@ impl Default for NeighborAfiSafiCfg {
     fn default() -> NeighborAfiSafiCfg {
         let enabled =
             bgp::neighbors::neighbor::afi_safis::afi_safi::enabled::DFLT;
+        let prefix_orf_receive =
+            bgp::neighbors::neighbor::afi_safis::afi_safi::prefix_orf_receive::DFLT;
 
         NeighborAfiSafiCfg {
             enabled,
             prefix_limit: Default::default(),
             send_default_route: false,
             apply_policy: Default::default(),
+            prefix_orf_receive,
         }
     }
 }
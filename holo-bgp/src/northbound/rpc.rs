@@ -4,21 +4,68 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::net::IpAddr;
 use std::sync::LazyLock as Lazy;
 
+use holo_northbound::paths::control_plane_protocol::bgp;
 use holo_northbound::rpc::{Callbacks, CallbacksBuilder, Provider};
+use holo_utils::yang::DataNodeRefExt;
+use yang2::data::Data;
 
-//use holo_utils::yang::DataNodeRefExt;
-//use yang2::data::Data;
-use crate::instance::Instance;
+use crate::events;
+use crate::instance::{Instance, InstanceUpView};
+use crate::neighbor::{fsm, Neighbors};
+use crate::packet::consts::CeaseSubcode;
+use crate::packet::message::NotificationMsg;
 
 pub static CALLBACKS: Lazy<Callbacks<Instance>> = Lazy::new(load_callbacks);
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum ClearType {
+    Admin,
+    Hard,
+    Soft,
+    SoftInbound,
+}
+
 // ===== callbacks =====
 
 fn load_callbacks() -> Callbacks<Instance> {
-    // TODO: YANG actions are not supported yet.
-    CallbacksBuilder::<Instance>::default().build()
+    CallbacksBuilder::<Instance>::default()
+        .path(bgp::neighbors::clear::PATH)
+        .rpc(|instance, args| {
+            Box::pin(async move {
+                let rpc = args.data.find_path(args.rpc_path).unwrap();
+
+                // Parse input parameters.
+                let remote_addr = rpc.get_ip_relative("./holo-bgp:remote-addr");
+                let communication = rpc
+                    .get_string_relative("./holo-bgp:shutdown-communication");
+                let clear_type = if rpc.find_path("./hard").is_ok() {
+                    ClearType::Hard
+                } else if rpc.find_path("./soft").is_ok() {
+                    ClearType::Soft
+                } else if rpc.find_path("./soft-inbound").is_ok() {
+                    ClearType::SoftInbound
+                } else {
+                    ClearType::Admin
+                };
+
+                // Clear neighbors.
+                if let Some((mut instance, neighbors)) = instance.as_up() {
+                    clear_neighbors(
+                        &mut instance,
+                        neighbors,
+                        remote_addr,
+                        clear_type,
+                        communication.as_deref(),
+                    );
+                }
+
+                Ok(())
+            })
+        })
+        .build()
 }
 
 // ===== impl Instance =====
@@ -28,3 +75,41 @@ impl Provider for Instance {
         Some(&CALLBACKS)
     }
 }
+
+// ===== helper functions =====
+
+fn clear_neighbors(
+    instance: &mut InstanceUpView<'_>,
+    neighbors: &mut Neighbors,
+    remote_addr: Option<IpAddr>,
+    clear_type: ClearType,
+    communication: Option<&str>,
+) {
+    for nbr in neighbors
+        .values_mut()
+        // Filter by neighbor address.
+        .filter(|nbr| {
+            remote_addr.is_none() || remote_addr.unwrap() == nbr.remote_addr
+        })
+    {
+        match clear_type {
+            ClearType::Admin | ClearType::Hard => {
+                // RFC 8538 - Section 3:
+                // The Hard Reset subcode is only meaningful when the "N" bit
+                // of the Graceful Restart capability was exchanged. Since the
+                // Graceful Restart capability isn't supported yet, a hard
+                // clear is signaled as a regular Administrative Reset.
+                let error_subcode = CeaseSubcode::AdministrativeReset;
+                let msg =
+                    NotificationMsg::new_cease(error_subcode, communication);
+                nbr.fsm_event(instance, fsm::Event::Stop(Some(msg)));
+            }
+            ClearType::Soft => {
+                events::process_nbr_soft_reset_out(instance, nbr);
+            }
+            ClearType::SoftInbound => {
+                events::process_nbr_soft_reset_in(instance, nbr);
+            }
+        }
+    }
+}
@@ -93,6 +93,7 @@ fn load_callbacks() -> Callbacks<Instance> {
             let total = match afi_safi {
                 AfiSafi::Ipv4Unicast => state.rib.tables.ipv4_unicast.prefixes.iter().count(),
                 AfiSafi::Ipv6Unicast => state.rib.tables.ipv6_unicast.prefixes.iter().count(),
+                AfiSafi::BgpLs => state.ls_db.nlri_count(),
             };
             Some(total as u32)
         })
@@ -415,6 +416,11 @@ fn load_callbacks() -> Callbacks<Instance> {
             let nbr = args.list_entry.as_neighbor().unwrap();
             nbr.notification_rcvd.as_ref().map(|(_, notif)| notif.data.clone())
         })
+        .path(bgp::neighbors::neighbor::errors::received::shutdown_communication::PATH)
+        .get_element_string(|_instance, args| {
+            let nbr = args.list_entry.as_neighbor().unwrap();
+            nbr.notification_rcvd.as_ref().and_then(|(_, notif)| notif.shutdown_communication())
+        })
         .path(bgp::neighbors::neighbor::errors::sent::last_notification::PATH)
         .attributes(NodeAttributes::TIME)
         .get_element_date_and_time(|_instance, args| {
@@ -1957,6 +1963,7 @@ fn afi_safi_tuple(afi: Afi, safi: Safi) -> Option<AfiSafi> {
     match (afi, safi) {
         (Afi::Ipv4, Safi::Unicast) => Some(AfiSafi::Ipv4Unicast),
         (Afi::Ipv6, Safi::Unicast) => Some(AfiSafi::Ipv6Unicast),
+        (Afi::BgpLs, Safi::BgpLs) => Some(AfiSafi::BgpLs),
         _ => None,
     }
 }
@@ -12,15 +12,26 @@ use num_traits::FromPrimitive;
 use crate::neighbor::{fsm, PeerType};
 use crate::northbound::configuration::PrivateAsRemove;
 use crate::packet::consts::{
-    AddPathMode, AsPathSegmentType, CapabilityCode, CeaseSubcode, ErrorCode,
-    FsmErrorSubcode, MessageHeaderErrorSubcode, OpenMessageErrorSubcode,
-    RouteRefreshErrorSubcode, Safi, UpdateMessageErrorSubcode,
+    AddPathMode, Afi, AsPathSegmentType, CapabilityCode, CeaseSubcode,
+    ErrorCode, FsmErrorSubcode, MessageHeaderErrorSubcode,
+    OpenMessageErrorSubcode, RouteRefreshErrorSubcode, Safi,
+    UpdateMessageErrorSubcode,
 };
 use crate::packet::message::NotificationMsg;
 use crate::rib::{RouteIneligibleReason, RouteOrigin, RouteRejectReason};
 
 // ===== ToYang implementations =====
 
+impl ToYang for Afi {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            Afi::Ipv4 => "ipv4".into(),
+            Afi::Ipv6 => "ipv6".into(),
+            Afi::BgpLs => "bgp-ls".into(),
+        }
+    }
+}
+
 impl ToYang for Safi {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
@@ -74,6 +85,9 @@ impl ToYang for CapabilityCode {
             CapabilityCode::EnhancedRouteRefresh => {
                 "holo-bgp:enhanced-route-refresh".into()
             }
+            CapabilityCode::OutboundRouteFiltering => {
+                "holo-bgp:outbound-route-filtering".into()
+            }
         }
     }
 }
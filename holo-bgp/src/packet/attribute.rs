@@ -16,6 +16,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::debug::Debug;
 use crate::neighbor::PeerType;
+use crate::packet::bgp_ls::{LsAttr, LsNlri};
 use crate::packet::consts::{
     Afi, AsPathSegmentType, AttrFlags, AttrType, Origin, Safi,
 };
@@ -37,6 +38,7 @@ pub struct Attrs {
     pub ext_comm: Option<ExtComms>,
     pub extv6_comm: Option<Extv6Comms>,
     pub large_comm: Option<LargeComms>,
+    pub ls: Option<LsAttr>,
     pub unknown: Box<[UnknownAttr]>,
 }
 
@@ -233,6 +235,11 @@ impl Attrs {
             extv6_comm.encode(buf);
         }
 
+        // BGP-LS attribute.
+        if let Some(ls) = &self.ls {
+            ls.encode(buf);
+        }
+
         // LARGE_COMMUNITY attribute.
         if let Some(large_comm) = &self.large_comm {
             large_comm.encode(buf);
@@ -273,6 +280,7 @@ impl Attrs {
         let mut ext_comm = None;
         let mut extv6_comm = None;
         let mut large_comm = None;
+        let mut ls = None;
         let mut unknown = vec![];
         let mut withdraw = false;
 
@@ -420,6 +428,7 @@ impl Attrs {
                         AttrType::Extv6Community => {
                             Extv6Comms::decode(&mut buf, &mut extv6_comm)
                         }
+                        AttrType::BgpLs => LsAttr::decode(&mut buf, &mut ls),
                         AttrType::LargeCommunity => {
                             LargeComms::decode(&mut buf, &mut large_comm)
                         }
@@ -508,6 +517,7 @@ impl Attrs {
                 ext_comm,
                 extv6_comm,
                 large_comm,
+                ls,
                 unknown: unknown.into(),
             });
         }
@@ -552,6 +562,9 @@ impl Attrs {
         if let Some(extv6_comm) = &self.extv6_comm {
             length += extv6_comm.length();
         }
+        if let Some(ls) = &self.ls {
+            length += ls.length();
+        }
         if let Some(large_comm) = &self.large_comm {
             length += large_comm.length();
         }
@@ -1112,6 +1125,24 @@ impl MpReachNlri {
                     encode_ipv6_prefix(buf, prefix);
                 }
             }
+            MpReachNlri::BgpLs { nlris, nexthop } => {
+                buf.put_u16(Afi::BgpLs as u16);
+                buf.put_u8(Safi::BgpLs as u8);
+                match nexthop {
+                    IpAddr::V4(nexthop) => {
+                        buf.put_u8(Ipv4Addr::LENGTH as u8);
+                        buf.put_ipv4(nexthop);
+                    }
+                    IpAddr::V6(nexthop) => {
+                        buf.put_u8(Ipv6Addr::LENGTH as u8);
+                        buf.put_ipv6(nexthop);
+                    }
+                }
+                buf.put_u8(0);
+                for nlri in nlris {
+                    nlri.encode(buf);
+                }
+            }
         }
 
         // Rewrite attribute length.
@@ -1136,7 +1167,11 @@ impl MpReachNlri {
 
         // Parse SAFI.
         let safi = buf.get_u8();
-        if Safi::from_u8(safi) != Some(Safi::Unicast) {
+        let expected_safi = match afi {
+            Afi::Ipv4 | Afi::Ipv6 => Safi::Unicast,
+            Afi::BgpLs => Safi::BgpLs,
+        };
+        if Safi::from_u8(safi) != Some(expected_safi) {
             // Ignore unsupported SAFI.
             return Err(AttrError::Discard);
         };
@@ -1200,6 +1235,32 @@ impl MpReachNlri {
                     ll_nexthop,
                 });
             }
+            Afi::BgpLs => {
+                let mut nlris = Vec::new();
+
+                // Parse nexthop.
+                let nexthop_len = buf.get_u8() as usize;
+                if nexthop_len > buf.remaining() {
+                    return Err(AttrError::Reset);
+                }
+                let nexthop = if nexthop_len == Ipv4Addr::LENGTH {
+                    buf.get_ipv4().into()
+                } else if nexthop_len == Ipv6Addr::LENGTH {
+                    buf.get_ipv6().into()
+                } else {
+                    return Err(AttrError::Reset);
+                };
+
+                // Parse NLRIs.
+                let _reserved = buf.get_u8();
+                while buf.remaining() > 0 {
+                    if let Some(nlri) = LsNlri::decode(buf)? {
+                        nlris.push(nlri);
+                    }
+                }
+
+                *mp_reach = Some(MpReachNlri::BgpLs { nlris, nexthop });
+            }
         }
 
         Ok(())
@@ -1211,7 +1272,7 @@ impl MpReachNlri {
 impl MpUnreachNlri {
     pub const MIN_LEN: u16 = 3;
 
-    fn encode(&self, buf: &mut BytesMut) {
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
         buf.put_u8((AttrFlags::OPTIONAL | AttrFlags::EXTENDED).bits());
         buf.put_u8(AttrType::MpUnreachNlri as u8);
 
@@ -1235,6 +1296,13 @@ impl MpUnreachNlri {
                     encode_ipv6_prefix(buf, prefix);
                 }
             }
+            MpUnreachNlri::BgpLs { nlris } => {
+                buf.put_u16(Afi::BgpLs as u16);
+                buf.put_u8(Safi::BgpLs as u8);
+                for nlri in nlris {
+                    nlri.encode(buf);
+                }
+            }
         }
 
         // Rewrite attribute length.
@@ -1259,7 +1327,11 @@ impl MpUnreachNlri {
 
         // Parse SAFI.
         let safi = buf.get_u8();
-        if Safi::from_u8(safi) != Some(Safi::Unicast) {
+        let expected_safi = match afi {
+            Afi::Ipv4 | Afi::Ipv6 => Safi::Unicast,
+            Afi::BgpLs => Safi::BgpLs,
+        };
+        if Safi::from_u8(safi) != Some(expected_safi) {
             // Ignore unsupported SAFI.
             return Err(AttrError::Discard);
         };
@@ -1292,6 +1364,17 @@ impl MpUnreachNlri {
 
                 *mp_unreach = Some(MpUnreachNlri::Ipv6Unicast { prefixes });
             }
+            Afi::BgpLs => {
+                let mut nlris = Vec::new();
+
+                while buf.remaining() > 0 {
+                    if let Some(nlri) = LsNlri::decode(buf)? {
+                        nlris.push(nlri);
+                    }
+                }
+
+                *mp_unreach = Some(MpUnreachNlri::BgpLs { nlris });
+            }
         }
 
         Ok(())
@@ -1432,7 +1515,8 @@ fn attribute_flags(attr_type: AttrType) -> AttrFlags {
         | AttrType::OriginatorId
         | AttrType::ClusterList
         | AttrType::MpReachNlri
-        | AttrType::MpUnreachNlri => AttrFlags::OPTIONAL,
+        | AttrType::MpUnreachNlri
+        | AttrType::BgpLs => AttrFlags::OPTIONAL,
 
         // Optional transitive.
         AttrType::Aggregator
@@ -0,0 +1,844 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+use holo_utils::bytes::{BytesExt, BytesMutExt};
+use holo_utils::ip::{Ipv4AddrExt, Ipv6AddrExt};
+use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
+use num_traits::FromPrimitive;
+use serde::{Deserialize, Serialize};
+
+use crate::packet::attribute::ATTR_MIN_LEN_EXT;
+use crate::packet::consts::{
+    AttrFlags, AttrType, LsNlriType, LsNodeFlags, LsOspfRouteType,
+    LsProtocolId, LsTlvType,
+};
+use crate::packet::error::AttrError;
+use crate::packet::message::{encode_ipv4_prefix, encode_ipv6_prefix};
+
+pub const LS_TLV_HDR_LEN: u16 = 4;
+
+//
+// BGP-LS NLRI.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |            NLRI Type          |     Total NLRI Length         |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |  Protocol-ID  |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                           Identifier                          |
+// |                            (8 octets)                         |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |            Descriptor TLVs (variable)                       //
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub enum LsNlri {
+    Node(LsNodeNlri),
+    Link(LsLinkNlri),
+    Prefix(LsPrefixNlri),
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsNodeNlri {
+    pub protocol_id: LsProtocolId,
+    pub identifier: u64,
+    pub local: LsNodeDesc,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsLinkNlri {
+    pub protocol_id: LsProtocolId,
+    pub identifier: u64,
+    pub local: LsNodeDesc,
+    pub remote: LsNodeDesc,
+    pub link: LsLinkDesc,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsPrefixNlri {
+    pub protocol_id: LsProtocolId,
+    pub identifier: u64,
+    pub local: LsNodeDesc,
+    pub prefix: LsPrefixDesc,
+}
+
+// Node Descriptor Sub-TLVs.
+//
+// Only the OSPF encoding of the IGP Router-ID is supported.
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsNodeDesc {
+    pub asn: Option<u32>,
+    pub ospf_area_id: Option<Ipv4Addr>,
+    pub igp_router_id: Option<Ipv4Addr>,
+    pub pseudonode: Option<u32>,
+}
+
+// Link Descriptor TLVs.
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsLinkDesc {
+    pub local_remote_ids: Option<(u32, u32)>,
+    pub ipv4_iface_addr: Option<Ipv4Addr>,
+    pub ipv4_nbr_addr: Option<Ipv4Addr>,
+    pub ipv6_iface_addr: Option<Ipv6Addr>,
+    pub ipv6_nbr_addr: Option<Ipv6Addr>,
+}
+
+// Prefix Descriptor TLVs.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsPrefixDesc {
+    pub ospf_route_type: Option<LsOspfRouteType>,
+    pub prefix: IpNetwork,
+}
+
+//
+// BGP-LS Attribute.
+//
+// The attribute value is a set of Node, Link and Prefix Attribute TLVs.
+//
+#[derive(Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsAttr {
+    // Node Attribute TLVs.
+    pub node_flags: Option<LsNodeFlags>,
+    pub node_name: Option<String>,
+    pub sr_capabilities: Option<LsSrCapabilities>,
+    pub sr_algos: Option<Vec<u8>>,
+    // Link Attribute TLVs.
+    pub igp_metric: Option<u32>,
+    pub adj_sids: Vec<LsAdjSid>,
+    // Prefix Attribute TLVs.
+    pub prefix_metric: Option<u32>,
+    pub prefix_sids: Vec<LsPrefixSid>,
+    // Unknown TLVs.
+    pub unknown: Vec<LsUnknownTlv>,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsSrCapabilities {
+    pub flags: u8,
+    pub ranges: Vec<LsSrRange>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsSrRange {
+    pub range: u32,
+    pub first: LsSid,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub enum LsSid {
+    Index(u32),
+    Label(u32),
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsAdjSid {
+    pub flags: u8,
+    pub weight: u8,
+    pub sid: LsSid,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsPrefixSid {
+    pub flags: u8,
+    pub algo: u8,
+    pub sid: LsSid,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsUnknownTlv {
+    pub tlv_type: u16,
+    pub value: Bytes,
+}
+
+// ===== impl LsNlri =====
+
+impl LsNlri {
+    pub const HDR_LEN: u16 = 13;
+
+    pub(crate) fn nlri_type(&self) -> LsNlriType {
+        match self {
+            LsNlri::Node(_) => LsNlriType::Node,
+            LsNlri::Link(_) => LsNlriType::Link,
+            LsNlri::Prefix(nlri) => match nlri.prefix.prefix {
+                IpNetwork::V4(_) => LsNlriType::Ipv4TopologyPrefix,
+                IpNetwork::V6(_) => LsNlriType::Ipv6TopologyPrefix,
+            },
+        }
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u16(self.nlri_type() as u16);
+
+        // The length field will be initialized later.
+        let start_pos = buf.len();
+        buf.put_u16(0);
+
+        match self {
+            LsNlri::Node(nlri) => {
+                buf.put_u8(nlri.protocol_id as u8);
+                buf.put_u64(nlri.identifier);
+                nlri.local.encode(buf, LsTlvType::LocalNodeDesc);
+            }
+            LsNlri::Link(nlri) => {
+                buf.put_u8(nlri.protocol_id as u8);
+                buf.put_u64(nlri.identifier);
+                nlri.local.encode(buf, LsTlvType::LocalNodeDesc);
+                nlri.remote.encode(buf, LsTlvType::RemoteNodeDesc);
+                nlri.link.encode(buf);
+            }
+            LsNlri::Prefix(nlri) => {
+                buf.put_u8(nlri.protocol_id as u8);
+                buf.put_u64(nlri.identifier);
+                nlri.local.encode(buf, LsTlvType::LocalNodeDesc);
+                nlri.prefix.encode(buf);
+            }
+        }
+
+        // Rewrite NLRI length.
+        let nlri_len = (buf.len() - start_pos - 2) as u16;
+        buf[start_pos..start_pos + 2].copy_from_slice(&nlri_len.to_be_bytes());
+    }
+
+    pub(crate) fn decode(buf: &mut Bytes) -> Result<Option<Self>, AttrError> {
+        if buf.remaining() < 4 {
+            return Err(AttrError::Withdraw);
+        }
+        let nlri_type = buf.get_u16();
+        let nlri_len = buf.get_u16() as usize;
+        if nlri_len > buf.remaining() {
+            return Err(AttrError::Withdraw);
+        }
+        let mut buf = buf.copy_to_bytes(nlri_len);
+
+        // Ignore unknown NLRI types.
+        let Some(nlri_type) = LsNlriType::from_u16(nlri_type) else {
+            return Ok(None);
+        };
+
+        // Parse Protocol-ID and Identifier.
+        if buf.remaining() < (Self::HDR_LEN - 4) as usize {
+            return Err(AttrError::Withdraw);
+        }
+        let protocol_id = buf.get_u8();
+        let identifier = buf.get_u64();
+        let Some(protocol_id) = LsProtocolId::from_u8(protocol_id) else {
+            return Ok(None);
+        };
+
+        // Parse descriptor TLVs.
+        let mut local = None;
+        let mut remote = None;
+        let mut link = LsLinkDesc::default();
+        let mut ospf_route_type = None;
+        let mut prefix = None;
+        while buf.remaining() > 0 {
+            let (tlv_type, mut tlv_buf) = tlv_decode(&mut buf)?;
+            let tlv_len = tlv_buf.remaining();
+            match LsTlvType::from_u16(tlv_type) {
+                Some(LsTlvType::LocalNodeDesc) => {
+                    local = Some(LsNodeDesc::decode(&mut tlv_buf)?);
+                }
+                Some(LsTlvType::RemoteNodeDesc) => {
+                    remote = Some(LsNodeDesc::decode(&mut tlv_buf)?);
+                }
+                Some(LsTlvType::LinkLocalRemoteIds) => {
+                    if tlv_len != 8 {
+                        return Err(AttrError::Withdraw);
+                    }
+                    let local_id = tlv_buf.get_u32();
+                    let remote_id = tlv_buf.get_u32();
+                    link.local_remote_ids = Some((local_id, remote_id));
+                }
+                Some(LsTlvType::Ipv4IfaceAddr) => {
+                    if tlv_len != Ipv4Addr::LENGTH {
+                        return Err(AttrError::Withdraw);
+                    }
+                    link.ipv4_iface_addr = Some(tlv_buf.get_ipv4());
+                }
+                Some(LsTlvType::Ipv4NbrAddr) => {
+                    if tlv_len != Ipv4Addr::LENGTH {
+                        return Err(AttrError::Withdraw);
+                    }
+                    link.ipv4_nbr_addr = Some(tlv_buf.get_ipv4());
+                }
+                Some(LsTlvType::Ipv6IfaceAddr) => {
+                    if tlv_len != Ipv6Addr::LENGTH {
+                        return Err(AttrError::Withdraw);
+                    }
+                    link.ipv6_iface_addr = Some(tlv_buf.get_ipv6());
+                }
+                Some(LsTlvType::Ipv6NbrAddr) => {
+                    if tlv_len != Ipv6Addr::LENGTH {
+                        return Err(AttrError::Withdraw);
+                    }
+                    link.ipv6_nbr_addr = Some(tlv_buf.get_ipv6());
+                }
+                Some(LsTlvType::OspfRouteType) => {
+                    if tlv_len != 1 {
+                        return Err(AttrError::Withdraw);
+                    }
+                    ospf_route_type =
+                        LsOspfRouteType::from_u8(tlv_buf.get_u8());
+                }
+                Some(LsTlvType::IpReachInfo) => {
+                    prefix = Some(decode_ip_reach(&mut tlv_buf, nlri_type)?);
+                }
+                _ => {
+                    // Ignore unsupported TLV.
+                }
+            }
+        }
+
+        // Check for mandatory descriptors.
+        let Some(local) = local else {
+            return Err(AttrError::Withdraw);
+        };
+        let nlri = match nlri_type {
+            LsNlriType::Node => LsNlri::Node(LsNodeNlri {
+                protocol_id,
+                identifier,
+                local,
+            }),
+            LsNlriType::Link => {
+                let Some(remote) = remote else {
+                    return Err(AttrError::Withdraw);
+                };
+                LsNlri::Link(LsLinkNlri {
+                    protocol_id,
+                    identifier,
+                    local,
+                    remote,
+                    link,
+                })
+            }
+            LsNlriType::Ipv4TopologyPrefix | LsNlriType::Ipv6TopologyPrefix => {
+                let Some(prefix) = prefix else {
+                    return Err(AttrError::Withdraw);
+                };
+                LsNlri::Prefix(LsPrefixNlri {
+                    protocol_id,
+                    identifier,
+                    local,
+                    prefix: LsPrefixDesc {
+                        ospf_route_type,
+                        prefix,
+                    },
+                })
+            }
+        };
+
+        Ok(Some(nlri))
+    }
+
+    pub(crate) fn length(&self) -> u16 {
+        Self::HDR_LEN
+            + match self {
+                LsNlri::Node(nlri) => nlri.local.length(),
+                LsNlri::Link(nlri) => {
+                    nlri.local.length()
+                        + nlri.remote.length()
+                        + nlri.link.length()
+                }
+                LsNlri::Prefix(nlri) => {
+                    nlri.local.length() + nlri.prefix.length()
+                }
+            }
+    }
+}
+
+// ===== impl LsNodeDesc =====
+
+impl LsNodeDesc {
+    fn encode(&self, buf: &mut BytesMut, tlv_type: LsTlvType) {
+        let start_pos = tlv_encode_start(buf, tlv_type);
+        if let Some(asn) = self.asn {
+            let start_pos = tlv_encode_start(buf, LsTlvType::AsNumber);
+            buf.put_u32(asn);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(area_id) = &self.ospf_area_id {
+            let start_pos = tlv_encode_start(buf, LsTlvType::OspfAreaId);
+            buf.put_ipv4(area_id);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(router_id) = &self.igp_router_id {
+            let start_pos = tlv_encode_start(buf, LsTlvType::IgpRouterId);
+            buf.put_ipv4(router_id);
+            if let Some(pseudonode) = self.pseudonode {
+                buf.put_u32(pseudonode);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    fn decode(buf: &mut Bytes) -> Result<Self, AttrError> {
+        let mut desc = LsNodeDesc::default();
+
+        while buf.remaining() > 0 {
+            let (stlv_type, mut stlv_buf) = tlv_decode(buf)?;
+            let stlv_len = stlv_buf.remaining();
+            match LsTlvType::from_u16(stlv_type) {
+                Some(LsTlvType::AsNumber) => {
+                    if stlv_len != 4 {
+                        return Err(AttrError::Withdraw);
+                    }
+                    desc.asn = Some(stlv_buf.get_u32());
+                }
+                Some(LsTlvType::OspfAreaId) => {
+                    if stlv_len != Ipv4Addr::LENGTH {
+                        return Err(AttrError::Withdraw);
+                    }
+                    desc.ospf_area_id = Some(stlv_buf.get_ipv4());
+                }
+                Some(LsTlvType::IgpRouterId) => match stlv_len {
+                    4 => {
+                        desc.igp_router_id = Some(stlv_buf.get_ipv4());
+                    }
+                    8 => {
+                        desc.igp_router_id = Some(stlv_buf.get_ipv4());
+                        desc.pseudonode = Some(stlv_buf.get_u32());
+                    }
+                    _ => return Err(AttrError::Withdraw),
+                },
+                _ => {
+                    // Ignore unsupported Sub-TLV.
+                }
+            }
+        }
+
+        Ok(desc)
+    }
+
+    fn length(&self) -> u16 {
+        let mut length = LS_TLV_HDR_LEN;
+        if self.asn.is_some() {
+            length += LS_TLV_HDR_LEN + 4;
+        }
+        if self.ospf_area_id.is_some() {
+            length += LS_TLV_HDR_LEN + Ipv4Addr::LENGTH as u16;
+        }
+        if self.igp_router_id.is_some() {
+            length += LS_TLV_HDR_LEN + Ipv4Addr::LENGTH as u16;
+            if self.pseudonode.is_some() {
+                length += 4;
+            }
+        }
+        length
+    }
+}
+
+// ===== impl LsLinkDesc =====
+
+impl LsLinkDesc {
+    fn encode(&self, buf: &mut BytesMut) {
+        if let Some((local_id, remote_id)) = self.local_remote_ids {
+            let start_pos =
+                tlv_encode_start(buf, LsTlvType::LinkLocalRemoteIds);
+            buf.put_u32(local_id);
+            buf.put_u32(remote_id);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(addr) = &self.ipv4_iface_addr {
+            let start_pos = tlv_encode_start(buf, LsTlvType::Ipv4IfaceAddr);
+            buf.put_ipv4(addr);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(addr) = &self.ipv4_nbr_addr {
+            let start_pos = tlv_encode_start(buf, LsTlvType::Ipv4NbrAddr);
+            buf.put_ipv4(addr);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(addr) = &self.ipv6_iface_addr {
+            let start_pos = tlv_encode_start(buf, LsTlvType::Ipv6IfaceAddr);
+            buf.put_ipv6(addr);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(addr) = &self.ipv6_nbr_addr {
+            let start_pos = tlv_encode_start(buf, LsTlvType::Ipv6NbrAddr);
+            buf.put_ipv6(addr);
+            tlv_encode_end(buf, start_pos);
+        }
+    }
+
+    fn length(&self) -> u16 {
+        let mut length = 0;
+        if self.local_remote_ids.is_some() {
+            length += LS_TLV_HDR_LEN + 8;
+        }
+        if self.ipv4_iface_addr.is_some() {
+            length += LS_TLV_HDR_LEN + Ipv4Addr::LENGTH as u16;
+        }
+        if self.ipv4_nbr_addr.is_some() {
+            length += LS_TLV_HDR_LEN + Ipv4Addr::LENGTH as u16;
+        }
+        if self.ipv6_iface_addr.is_some() {
+            length += LS_TLV_HDR_LEN + Ipv6Addr::LENGTH as u16;
+        }
+        if self.ipv6_nbr_addr.is_some() {
+            length += LS_TLV_HDR_LEN + Ipv6Addr::LENGTH as u16;
+        }
+        length
+    }
+}
+
+// ===== impl LsPrefixDesc =====
+
+impl LsPrefixDesc {
+    fn encode(&self, buf: &mut BytesMut) {
+        if let Some(route_type) = self.ospf_route_type {
+            let start_pos = tlv_encode_start(buf, LsTlvType::OspfRouteType);
+            buf.put_u8(route_type as u8);
+            tlv_encode_end(buf, start_pos);
+        }
+        let start_pos = tlv_encode_start(buf, LsTlvType::IpReachInfo);
+        match &self.prefix {
+            IpNetwork::V4(prefix) => encode_ipv4_prefix(buf, prefix),
+            IpNetwork::V6(prefix) => encode_ipv6_prefix(buf, prefix),
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    fn length(&self) -> u16 {
+        let mut length = 0;
+        if self.ospf_route_type.is_some() {
+            length += LS_TLV_HDR_LEN + 1;
+        }
+        let plen_wire = self.prefix.prefix().div_ceil(8) as u16;
+        length += LS_TLV_HDR_LEN + 1 + plen_wire;
+        length
+    }
+}
+
+// ===== impl LsAttr =====
+
+impl LsAttr {
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u8((AttrFlags::OPTIONAL | AttrFlags::EXTENDED).bits());
+        buf.put_u8(AttrType::BgpLs as u8);
+
+        // The length field will be initialized later.
+        let start_pos = buf.len();
+        buf.put_u16(0);
+
+        // Encode TLVs in ascending order of type.
+        if let Some(node_flags) = &self.node_flags {
+            let start_pos = tlv_encode_start(buf, LsTlvType::NodeFlags);
+            buf.put_u8(node_flags.bits());
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(node_name) = &self.node_name {
+            let start_pos = tlv_encode_start(buf, LsTlvType::NodeName);
+            buf.put_slice(node_name.as_bytes());
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(sr_cap) = &self.sr_capabilities {
+            let start_pos = tlv_encode_start(buf, LsTlvType::SrCapabilities);
+            buf.put_u8(sr_cap.flags);
+            buf.put_u8(0);
+            for range in &sr_cap.ranges {
+                buf.put_u24(range.range);
+                let start_pos = tlv_encode_start(buf, LsTlvType::SidLabel);
+                range.first.encode(buf);
+                tlv_encode_end(buf, start_pos);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(sr_algos) = &self.sr_algos {
+            let start_pos = tlv_encode_start(buf, LsTlvType::SrAlgorithm);
+            buf.put_slice(sr_algos);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(metric) = self.igp_metric {
+            let start_pos = tlv_encode_start(buf, LsTlvType::IgpMetric);
+            if metric <= u16::MAX as u32 {
+                buf.put_u16(metric as u16);
+            } else {
+                buf.put_u24(metric);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
+        for adj_sid in &self.adj_sids {
+            let start_pos = tlv_encode_start(buf, LsTlvType::AdjSid);
+            buf.put_u8(adj_sid.flags);
+            buf.put_u8(adj_sid.weight);
+            buf.put_u16(0);
+            adj_sid.sid.encode(buf);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(metric) = self.prefix_metric {
+            let start_pos = tlv_encode_start(buf, LsTlvType::PrefixMetric);
+            buf.put_u32(metric);
+            tlv_encode_end(buf, start_pos);
+        }
+        for prefix_sid in &self.prefix_sids {
+            let start_pos = tlv_encode_start(buf, LsTlvType::PrefixSid);
+            buf.put_u8(prefix_sid.flags);
+            buf.put_u8(prefix_sid.algo);
+            buf.put_u16(0);
+            prefix_sid.sid.encode(buf);
+            tlv_encode_end(buf, start_pos);
+        }
+        for tlv in &self.unknown {
+            buf.put_u16(tlv.tlv_type);
+            buf.put_u16(tlv.value.len() as u16);
+            buf.put_slice(&tlv.value);
+        }
+
+        // Rewrite attribute length.
+        let attr_len = (buf.len() - start_pos - 2) as u16;
+        buf[start_pos..start_pos + 2].copy_from_slice(&attr_len.to_be_bytes());
+    }
+
+    // RFC 9552 - Section 8.2.2:
+    // "A BGP-LS Attribute that is syntactically or semantically incorrect
+    // SHOULD be handled using the "attribute discard" approach".
+    pub(crate) fn decode(
+        buf: &mut Bytes,
+        ls: &mut Option<Self>,
+    ) -> Result<(), AttrError> {
+        let mut attr = LsAttr::default();
+
+        while buf.remaining() > 0 {
+            let (tlv_type, mut tlv_buf) =
+                tlv_decode(buf).map_err(|_| AttrError::Discard)?;
+            let tlv_len = tlv_buf.remaining();
+            match LsTlvType::from_u16(tlv_type) {
+                Some(LsTlvType::NodeFlags) => {
+                    if tlv_len != 1 {
+                        return Err(AttrError::Discard);
+                    }
+                    let flags = tlv_buf.get_u8();
+                    attr.node_flags =
+                        Some(LsNodeFlags::from_bits_truncate(flags));
+                }
+                Some(LsTlvType::NodeName) => {
+                    let name = String::from_utf8(tlv_buf.to_vec())
+                        .map_err(|_| AttrError::Discard)?;
+                    attr.node_name = Some(name);
+                }
+                Some(LsTlvType::SrCapabilities) => {
+                    if tlv_len < 2 {
+                        return Err(AttrError::Discard);
+                    }
+                    let flags = tlv_buf.get_u8();
+                    let _reserved = tlv_buf.get_u8();
+                    let mut ranges = vec![];
+                    while tlv_buf.remaining() > 0 {
+                        if tlv_buf.remaining() < 3 {
+                            return Err(AttrError::Discard);
+                        }
+                        let range = tlv_buf.get_u24();
+                        let (stlv_type, mut stlv_buf) =
+                            tlv_decode(&mut tlv_buf)
+                                .map_err(|_| AttrError::Discard)?;
+                        if stlv_type != LsTlvType::SidLabel as u16 {
+                            return Err(AttrError::Discard);
+                        }
+                        let first = LsSid::decode(&mut stlv_buf)?;
+                        ranges.push(LsSrRange { range, first });
+                    }
+                    attr.sr_capabilities =
+                        Some(LsSrCapabilities { flags, ranges });
+                }
+                Some(LsTlvType::SrAlgorithm) => {
+                    attr.sr_algos = Some(tlv_buf.to_vec());
+                }
+                Some(LsTlvType::IgpMetric) => {
+                    let metric = match tlv_len {
+                        1 => (tlv_buf.get_u8() & 0x3f) as u32,
+                        2 => tlv_buf.get_u16() as u32,
+                        3 => tlv_buf.get_u24(),
+                        _ => return Err(AttrError::Discard),
+                    };
+                    attr.igp_metric = Some(metric);
+                }
+                Some(LsTlvType::AdjSid) => {
+                    if tlv_len < 4 {
+                        return Err(AttrError::Discard);
+                    }
+                    let flags = tlv_buf.get_u8();
+                    let weight = tlv_buf.get_u8();
+                    let _reserved = tlv_buf.get_u16();
+                    let sid = LsSid::decode(&mut tlv_buf)?;
+                    attr.adj_sids.push(LsAdjSid { flags, weight, sid });
+                }
+                Some(LsTlvType::PrefixMetric) => {
+                    if tlv_len != 4 {
+                        return Err(AttrError::Discard);
+                    }
+                    attr.prefix_metric = Some(tlv_buf.get_u32());
+                }
+                Some(LsTlvType::PrefixSid) => {
+                    if tlv_len < 4 {
+                        return Err(AttrError::Discard);
+                    }
+                    let flags = tlv_buf.get_u8();
+                    let algo = tlv_buf.get_u8();
+                    let _reserved = tlv_buf.get_u16();
+                    let sid = LsSid::decode(&mut tlv_buf)?;
+                    attr.prefix_sids.push(LsPrefixSid { flags, algo, sid });
+                }
+                _ => {
+                    attr.unknown.push(LsUnknownTlv {
+                        tlv_type,
+                        value: tlv_buf,
+                    });
+                }
+            }
+        }
+
+        *ls = Some(attr);
+        Ok(())
+    }
+
+    pub(crate) fn length(&self) -> u16 {
+        let mut length = ATTR_MIN_LEN_EXT;
+        if self.node_flags.is_some() {
+            length += LS_TLV_HDR_LEN + 1;
+        }
+        if let Some(node_name) = &self.node_name {
+            length += LS_TLV_HDR_LEN + node_name.len() as u16;
+        }
+        if let Some(sr_cap) = &self.sr_capabilities {
+            length += LS_TLV_HDR_LEN + 2;
+            for range in &sr_cap.ranges {
+                length += 3 + LS_TLV_HDR_LEN + range.first.length();
+            }
+        }
+        if let Some(sr_algos) = &self.sr_algos {
+            length += LS_TLV_HDR_LEN + sr_algos.len() as u16;
+        }
+        if let Some(metric) = self.igp_metric {
+            length += LS_TLV_HDR_LEN;
+            length += if metric <= u16::MAX as u32 { 2 } else { 3 };
+        }
+        for adj_sid in &self.adj_sids {
+            length += LS_TLV_HDR_LEN + 4 + adj_sid.sid.length();
+        }
+        if self.prefix_metric.is_some() {
+            length += LS_TLV_HDR_LEN + 4;
+        }
+        for prefix_sid in &self.prefix_sids {
+            length += LS_TLV_HDR_LEN + 4 + prefix_sid.sid.length();
+        }
+        for tlv in &self.unknown {
+            length += LS_TLV_HDR_LEN + tlv.value.len() as u16;
+        }
+        length
+    }
+}
+
+// ===== impl LsSid =====
+
+impl LsSid {
+    fn encode(&self, buf: &mut BytesMut) {
+        match self {
+            LsSid::Index(index) => buf.put_u32(*index),
+            LsSid::Label(label) => buf.put_u24(*label),
+        }
+    }
+
+    fn decode(buf: &mut Bytes) -> Result<Self, AttrError> {
+        match buf.remaining() {
+            3 => Ok(LsSid::Label(buf.get_u24() & 0xfffff)),
+            4 => Ok(LsSid::Index(buf.get_u32())),
+            _ => Err(AttrError::Discard),
+        }
+    }
+
+    fn length(&self) -> u16 {
+        match self {
+            LsSid::Index(_) => 4,
+            LsSid::Label(_) => 3,
+        }
+    }
+}
+
+// ===== helper functions =====
+
+fn tlv_encode_start(buf: &mut BytesMut, tlv_type: LsTlvType) -> usize {
+    buf.put_u16(tlv_type as u16);
+    let start_pos = buf.len();
+    // The length field will be initialized later.
+    buf.put_u16(0);
+    start_pos
+}
+
+fn tlv_encode_end(buf: &mut BytesMut, start_pos: usize) {
+    let tlv_len = (buf.len() - start_pos - 2) as u16;
+    buf[start_pos..start_pos + 2].copy_from_slice(&tlv_len.to_be_bytes());
+}
+
+fn tlv_decode(buf: &mut Bytes) -> Result<(u16, Bytes), AttrError> {
+    if buf.remaining() < LS_TLV_HDR_LEN as usize {
+        return Err(AttrError::Withdraw);
+    }
+    let tlv_type = buf.get_u16();
+    let tlv_len = buf.get_u16() as usize;
+    if tlv_len > buf.remaining() {
+        return Err(AttrError::Withdraw);
+    }
+    Ok((tlv_type, buf.copy_to_bytes(tlv_len)))
+}
+
+fn decode_ip_reach(
+    buf: &mut Bytes,
+    nlri_type: LsNlriType,
+) -> Result<IpNetwork, AttrError> {
+    if buf.remaining() < 1 {
+        return Err(AttrError::Withdraw);
+    }
+    let plen = buf.get_u8();
+    let plen_wire = plen.div_ceil(8) as usize;
+    if plen_wire > buf.remaining() {
+        return Err(AttrError::Withdraw);
+    }
+
+    let prefix = match nlri_type {
+        LsNlriType::Ipv4TopologyPrefix => {
+            let mut prefix_bytes = [0; Ipv4Addr::LENGTH];
+            if plen_wire > prefix_bytes.len() {
+                return Err(AttrError::Withdraw);
+            }
+            buf.copy_to_slice(&mut prefix_bytes[..plen_wire]);
+            Ipv4Network::new(Ipv4Addr::from(prefix_bytes), plen)
+                .map(IpNetwork::V4)
+        }
+        _ => {
+            let mut prefix_bytes = [0; Ipv6Addr::LENGTH];
+            if plen_wire > prefix_bytes.len() {
+                return Err(AttrError::Withdraw);
+            }
+            buf.copy_to_slice(&mut prefix_bytes[..plen_wire]);
+            Ipv6Network::new(Ipv6Addr::from(prefix_bytes), plen)
+                .map(IpNetwork::V6)
+        }
+    };
+
+    prefix.map_err(|_| AttrError::Withdraw)
+}
@@ -5,7 +5,6 @@
 //
 
 use bitflags::bitflags;
-use holo_utils::ip::AddressFamily;
 use num_derive::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
@@ -53,7 +52,7 @@ pub enum CapabilityCode {
     // RFC 2918
     RouteRefresh = 2,
     // RFC 5291
-    //OutboundRouteFiltering = 3,
+    OutboundRouteFiltering = 3,
     // RFC 8950
     //ExtendedNextHop = 5,
     // RFC 8654
@@ -84,6 +83,56 @@ pub enum AddPathMode {
     ReceiveSend = 3,
 }
 
+// Outbound Route Filtering (ORF) Types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/bgp-parameters/bgp-parameters.xhtml#bgp-parameters-9
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfType {
+    // RFC 5292
+    AddressPrefix = 64,
+}
+
+// Send/Receive value for a per-AFI/SAFI instance of the ORF Capability.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfMode {
+    Receive = 1,
+    Send = 2,
+    Both = 3,
+}
+
+// ROUTE-REFRESH message When-to-refresh values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfWhenToRefresh {
+    Immediate = 1,
+    Defer = 2,
+}
+
+// ORF entry Action values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfAction {
+    Add = 0,
+    Remove = 1,
+    RemoveAll = 2,
+}
+
+// ORF entry Match values.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfMatch {
+    Permit = 0,
+    Deny = 1,
+}
+
 // BGP Error (Notification) Codes.
 //
 // IANA registry:
@@ -204,7 +253,18 @@ pub enum RouteRefreshErrorSubcode {
 }
 
 // Address Family identifiers (AFI).
-pub type Afi = AddressFamily;
+//
+// IANA registry:
+// https://www.iana.org/assignments/address-family-numbers/address-family-numbers.xhtml
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum Afi {
+    Ipv4 = 1,
+    Ipv6 = 2,
+    // RFC 9552
+    BgpLs = 16388,
+}
 
 // Subsequent Address Family Identifiers (SAFI).
 //
@@ -293,8 +353,8 @@ pub enum AttrType {
     //Aigp = 26,
     // RFC 6514
     //PeDistinguisherLabels = 27,
-    // RFC-ietf-idr-rfc7752bis-16
-    //BgpLs = 29,
+    // RFC 9552
+    BgpLs = 29,
     // RFC 8092
     LargeCommunity = 32,
     // RFC 8205
@@ -337,3 +397,100 @@ pub type WellKnownCommunities = holo_utils::bgp::WellKnownCommunities;
 pub enum AigpType {
     Aigp = 1,
 }
+
+// BGP-LS NLRI Types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/bgp-ls-parameters/bgp-ls-parameters.xhtml#nlri-types
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum LsNlriType {
+    Node = 1,
+    Link = 2,
+    Ipv4TopologyPrefix = 3,
+    Ipv6TopologyPrefix = 4,
+}
+
+// BGP-LS Protocol-IDs.
+//
+// IANA registry:
+// https://www.iana.org/assignments/bgp-ls-parameters/bgp-ls-parameters.xhtml#protocol-ids
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum LsProtocolId {
+    IsisL1 = 1,
+    IsisL2 = 2,
+    Ospfv2 = 3,
+    Direct = 4,
+    Static = 5,
+    Ospfv3 = 6,
+    // RFC 9086
+    Bgp = 7,
+}
+
+// BGP-LS NLRI and Attribute TLVs.
+//
+// IANA registry:
+// https://www.iana.org/assignments/bgp-ls-parameters/bgp-ls-parameters.xhtml#node-descriptor-link-descriptor-prefix-descriptor-attribute-tlv
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum LsTlvType {
+    LocalNodeDesc = 256,
+    RemoteNodeDesc = 257,
+    LinkLocalRemoteIds = 258,
+    Ipv4IfaceAddr = 259,
+    Ipv4NbrAddr = 260,
+    Ipv6IfaceAddr = 261,
+    Ipv6NbrAddr = 262,
+    MultiTopologyId = 263,
+    OspfRouteType = 264,
+    IpReachInfo = 265,
+    AsNumber = 512,
+    BgpLsIdentifier = 513,
+    OspfAreaId = 514,
+    IgpRouterId = 515,
+    NodeFlags = 1024,
+    NodeName = 1026,
+    // RFC 9085
+    SrCapabilities = 1034,
+    SrAlgorithm = 1035,
+    IgpMetric = 1095,
+    // RFC 9085
+    AdjSid = 1099,
+    PrefixMetric = 1155,
+    // RFC 9085
+    PrefixSid = 1158,
+    // RFC 9085
+    SidLabel = 1161,
+}
+
+// BGP-LS OSPF Route Types.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum LsOspfRouteType {
+    IntraArea = 1,
+    InterArea = 2,
+    External1 = 3,
+    External2 = 4,
+    Nssa1 = 5,
+    Nssa2 = 6,
+}
+
+// BGP-LS Node Flag Bits.
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct LsNodeFlags: u8 {
+        const OVERLOAD = 0x80;
+        const ATTACHED = 0x40;
+        const EXTERNAL = 0x20;
+        const ABR = 0x10;
+        const ROUTER = 0x08;
+        const V6 = 0x04;
+    }
+}
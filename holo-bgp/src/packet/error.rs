@@ -16,6 +16,7 @@ pub enum DecodeError {
     MessageHeader(MessageHeaderError),
     OpenMessage(OpenMessageError),
     UpdateMessage(UpdateMessageError),
+    RouteRefreshMessage(RouteRefreshMessageError),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -51,6 +52,13 @@ pub enum UpdateMessageError {
     InvalidNetworkField,
 }
 
+// ROUTE-REFRESH message errors.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum RouteRefreshMessageError {
+    InvalidMessageLength(u16),
+}
+
 // Attribute errors.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[derive(Deserialize, Serialize)]
@@ -68,6 +76,7 @@ impl std::fmt::Display for DecodeError {
             DecodeError::MessageHeader(error) => error.fmt(f),
             DecodeError::OpenMessage(error) => error.fmt(f),
             DecodeError::UpdateMessage(error) => error.fmt(f),
+            DecodeError::RouteRefreshMessage(error) => error.fmt(f),
         }
     }
 }
@@ -92,6 +101,12 @@ impl From<UpdateMessageError> for DecodeError {
     }
 }
 
+impl From<RouteRefreshMessageError> for DecodeError {
+    fn from(error: RouteRefreshMessageError) -> DecodeError {
+        DecodeError::RouteRefreshMessage(error)
+    }
+}
+
 // ===== impl MessageHeaderError =====
 
 impl std::fmt::Display for MessageHeaderError {
@@ -164,3 +179,17 @@ impl std::fmt::Display for UpdateMessageError {
         }
     }
 }
+
+// ===== impl RouteRefreshMessageError =====
+
+impl std::fmt::Display for RouteRefreshMessageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ROUTE-REFRESH message error: ")?;
+
+        match self {
+            RouteRefreshMessageError::InvalidMessageLength(len) => {
+                write!(f, "invalid message length: {}", len)
+            }
+        }
+    }
+}
@@ -4,29 +4,32 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::BTreeSet;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use enum_as_inner::EnumAsInner;
+use holo_utils::bgp::AfiSafi;
 use holo_utils::bytes::{BytesExt, BytesMutExt, TLS_BUF};
 use holo_utils::ip::{
     Ipv4AddrExt, Ipv4NetworkExt, Ipv6AddrExt, Ipv6NetworkExt,
 };
-use ipnetwork::{Ipv4Network, Ipv6Network};
+use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::neighbor::PeerType;
 use crate::packet::attribute::Attrs;
+use crate::packet::bgp_ls::LsNlri;
 use crate::packet::consts::{
-    AddPathMode, Afi, CapabilityCode, ErrorCode, MessageHeaderErrorSubcode,
-    MessageType, OpenMessageErrorSubcode, OpenParamType, Safi,
-    UpdateMessageErrorSubcode, BGP_VERSION,
+    AddPathMode, Afi, CapabilityCode, CeaseSubcode, ErrorCode,
+    MessageHeaderErrorSubcode, MessageType, OpenMessageErrorSubcode,
+    OpenParamType, OrfAction, OrfMatch, OrfMode, OrfType, OrfWhenToRefresh,
+    RouteRefreshErrorSubcode, Safi, UpdateMessageErrorSubcode, BGP_VERSION,
 };
 use crate::packet::error::{
     DecodeError, DecodeResult, MessageHeaderError, OpenMessageError,
-    UpdateMessageError,
+    RouteRefreshMessageError, UpdateMessageError,
 };
 
 //
@@ -122,6 +125,7 @@ pub enum Capability {
     AddPath(BTreeSet<AddPathTuple>),
     RouteRefresh,
     EnhancedRouteRefresh,
+    OutboundRouteFiltering(BTreeSet<OrfTuple>),
 }
 
 // This is a stripped down version of `Capability`, containing only data that
@@ -135,6 +139,7 @@ pub enum NegotiatedCapability {
     AddPath,
     RouteRefresh,
     EnhancedRouteRefresh,
+    OutboundRouteFiltering,
 }
 
 #[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -145,6 +150,36 @@ pub struct AddPathTuple {
     pub mode: AddPathMode,
 }
 
+//
+// Outbound Route Filtering Capability.
+//
+// Encoding format (repeated for each AFI/SAFI):
+//
+// +--------------------------------------------------+
+// | Address Family Identifier (2 octets)             |
+// +--------------------------------------------------+
+// | Reserved (1 octet)                               |
+// +--------------------------------------------------+
+// | Subsequent Address Family Identifier (1 octet)   |
+// +--------------------------------------------------+
+// | Number of ORFs (1 octet)                         |
+// +--------------------------------------------------+
+// | ORF Type (1 octet)                               |
+// +--------------------------------------------------+
+// | Send/Receive (1 octet)                           |
+// +--------------------------------------------------+
+// | ...                                              |
+// +--------------------------------------------------+
+//
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct OrfTuple {
+    pub afi: Afi,
+    pub safi: Safi,
+    pub orf_type: OrfType,
+    pub mode: OrfMode,
+}
+
 //
 // UPDATE Message.
 //
@@ -197,6 +232,10 @@ pub enum MpReachNlri {
         nexthop: Ipv6Addr,
         ll_nexthop: Option<Ipv6Addr>,
     },
+    BgpLs {
+        nlris: Vec<LsNlri>,
+        nexthop: IpAddr,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -204,6 +243,7 @@ pub enum MpReachNlri {
 pub enum MpUnreachNlri {
     Ipv4Unicast { prefixes: Vec<Ipv4Network> },
     Ipv6Unicast { prefixes: Vec<Ipv6Network> },
+    BgpLs { nlris: Vec<LsNlri> },
 }
 
 //
@@ -245,11 +285,76 @@ pub struct KeepaliveMsg {}
 // |      AFI      | Res.  | SAFI  |
 // +-------+-------+-------+-------+
 //
+// Encoding format (optional ORF data, RFC 5291):
+//
+// +--------------------------------------------------+
+// | When-to-refresh (1 octet)                        |
+// +--------------------------------------------------+
+// | ORF Type (1 octet)                               |
+// +--------------------------------------------------+
+// | Length of ORFs (2 octets)                        |
+// +--------------------------------------------------+
+// | First ORF entry (variable)                       |
+// +--------------------------------------------------+
+// | ...                                              |
+// +--------------------------------------------------+
+//
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[derive(Deserialize, Serialize)]
 pub struct RouteRefreshMsg {
     pub afi: u16,
     pub safi: u8,
+    pub orf: Option<RouteRefreshOrf>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct RouteRefreshOrf {
+    pub when_to_refresh: OrfWhenToRefresh,
+    pub entries: Vec<OrfEntry>,
+}
+
+//
+// Address Prefix ORF entry.
+//
+// Encoding format:
+//
+// +--------------------------------+
+// | Action (2 bits)                |
+// +--------------------------------+
+// | Match (1 bit)                  |
+// +--------------------------------+
+// | Reserved (5 bits)              |
+// +--------------------------------+
+// | Sequence (4 octets)            |
+// +--------------------------------+
+// | Minlen (1 octet)               |
+// +--------------------------------+
+// | Maxlen (1 octet)               |
+// +--------------------------------+
+// | Length (1 octet)               |
+// +--------------------------------+
+// | Prefix (variable length)       |
+// +--------------------------------+
+//
+// The type-specific part is omitted when the Action is REMOVE-ALL.
+//
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum OrfEntry {
+    Add(PrefixOrfEntry),
+    Remove(PrefixOrfEntry),
+    RemoveAll,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct PrefixOrfEntry {
+    pub seq: u32,
+    pub match_type: OrfMatch,
+    pub prefix: IpNetwork,
+    pub min_len: u8,
+    pub max_len: u8,
 }
 
 // BGP message decoding context.
@@ -534,6 +639,30 @@ impl Capability {
                 buf.put_u8(CapabilityCode::EnhancedRouteRefresh as u8);
                 buf.put_u8(0);
             }
+            Capability::OutboundRouteFiltering(tuples) => {
+                buf.put_u8(CapabilityCode::OutboundRouteFiltering as u8);
+                buf.put_u8(0);
+
+                // Group ORF types by AFI/SAFI.
+                let mut afi_safis: BTreeMap<(Afi, Safi), Vec<&OrfTuple>> =
+                    BTreeMap::new();
+                for tuple in tuples {
+                    afi_safis
+                        .entry((tuple.afi, tuple.safi))
+                        .or_default()
+                        .push(tuple);
+                }
+                for ((afi, safi), tuples) in afi_safis {
+                    buf.put_u16(afi as u16);
+                    buf.put_u8(0);
+                    buf.put_u8(safi as u8);
+                    buf.put_u8(tuples.len() as u8);
+                    for tuple in tuples {
+                        buf.put_u8(tuple.orf_type as u8);
+                        buf.put_u8(tuple.mode as u8);
+                    }
+                }
+            }
         }
 
         // Rewrite the "Capability Length" field.
@@ -620,6 +749,42 @@ impl Capability {
 
                 Capability::EnhancedRouteRefresh
             }
+            Some(CapabilityCode::OutboundRouteFiltering) => {
+                let mut tuples = BTreeSet::new();
+                while buf_cap.remaining() > 0 {
+                    if buf_cap.remaining() < 5 {
+                        return Err(OpenMessageError::MalformedOptParam.into());
+                    }
+                    let afi = buf_cap.get_u16();
+                    let _reserved = buf_cap.get_u8();
+                    let safi = buf_cap.get_u8();
+                    let orfs = buf_cap.get_u8();
+                    if buf_cap.remaining() < orfs as usize * 2 {
+                        return Err(OpenMessageError::MalformedOptParam.into());
+                    }
+                    for _ in 0..orfs {
+                        let orf_type = buf_cap.get_u8();
+                        let mode = buf_cap.get_u8();
+
+                        // Ignore unknown values.
+                        let (Some(afi), Some(safi), Some(orf_type), Some(mode)) = (
+                            Afi::from_u16(afi),
+                            Safi::from_u8(safi),
+                            OrfType::from_u8(orf_type),
+                            OrfMode::from_u8(mode),
+                        ) else {
+                            continue;
+                        };
+                        tuples.insert(OrfTuple {
+                            afi,
+                            safi,
+                            orf_type,
+                            mode,
+                        });
+                    }
+                }
+                Capability::OutboundRouteFiltering(tuples)
+            }
             _ => {
                 // Ignore unknown capability.
                 return Ok(None);
@@ -640,6 +805,9 @@ impl Capability {
             Capability::EnhancedRouteRefresh => {
                 CapabilityCode::EnhancedRouteRefresh
             }
+            Capability::OutboundRouteFiltering(..) => {
+                CapabilityCode::OutboundRouteFiltering
+            }
         }
     }
 
@@ -656,6 +824,9 @@ impl Capability {
             Capability::EnhancedRouteRefresh => {
                 NegotiatedCapability::EnhancedRouteRefresh
             }
+            Capability::OutboundRouteFiltering(..) => {
+                NegotiatedCapability::OutboundRouteFiltering
+            }
         }
     }
 }
@@ -698,12 +869,15 @@ impl UpdateMsg {
                 &self.mp_unreach,
                 cxt,
             );
-
-            // Rewrite the "Total Path Attribute Length" field.
-            let len = (buf.len() - start_pos - 2) as u16;
-            buf[start_pos..start_pos + 2].copy_from_slice(&len.to_be_bytes());
+        } else if let Some(mp_unreach) = &self.mp_unreach {
+            // Withdrawal-only UPDATE message.
+            mp_unreach.encode(buf);
         }
 
+        // Rewrite the "Total Path Attribute Length" field.
+        let len = (buf.len() - start_pos - 2) as u16;
+        buf[start_pos..start_pos + 2].copy_from_slice(&len.to_be_bytes());
+
         // Network Layer Reachability Information.
         if let Some(reach) = &self.reach {
             // Encode prefixes.
@@ -793,6 +967,59 @@ impl UpdateMsg {
             attrs,
         })
     }
+
+    // Returns an End-of-RIB marker for the given address-family (RFC 4724 -
+    // Section 2).
+    pub fn eor(afi_safi: AfiSafi) -> Self {
+        let mp_unreach = match afi_safi {
+            AfiSafi::Ipv4Unicast => None,
+            AfiSafi::Ipv6Unicast => {
+                Some(MpUnreachNlri::Ipv6Unicast { prefixes: vec![] })
+            }
+            AfiSafi::BgpLs => Some(MpUnreachNlri::BgpLs { nlris: vec![] }),
+        };
+        UpdateMsg {
+            reach: None,
+            unreach: None,
+            mp_reach: None,
+            mp_unreach,
+            attrs: None,
+        }
+    }
+
+    // Returns whether this is an End-of-RIB marker (RFC 4724 - Section 2).
+    pub fn is_eor(&self) -> bool {
+        self.eor_afi_safi().is_some()
+    }
+
+    // Returns the address-family of the End-of-RIB marker, or `None` if this
+    // isn't an End-of-RIB marker.
+    pub fn eor_afi_safi(&self) -> Option<AfiSafi> {
+        if self.reach.is_some()
+            || self.unreach.is_some()
+            || self.mp_reach.is_some()
+        {
+            return None;
+        }
+
+        match &self.mp_unreach {
+            None if self.attrs.is_none() => Some(AfiSafi::Ipv4Unicast),
+            Some(MpUnreachNlri::Ipv4Unicast { prefixes })
+                if prefixes.is_empty() =>
+            {
+                Some(AfiSafi::Ipv4Unicast)
+            }
+            Some(MpUnreachNlri::Ipv6Unicast { prefixes })
+                if prefixes.is_empty() =>
+            {
+                Some(AfiSafi::Ipv6Unicast)
+            }
+            Some(MpUnreachNlri::BgpLs { nlris }) if nlris.is_empty() => {
+                Some(AfiSafi::BgpLs)
+            }
+            _ => None,
+        }
+    }
 }
 
 // ===== impl NotificationMsg =====
@@ -811,6 +1038,71 @@ impl NotificationMsg {
         }
     }
 
+    // Maximum length of the Shutdown Communication, in octets.
+    pub const SHUTDOWN_COMM_MAX_LEN: usize = 255;
+
+    // Creates a CEASE NOTIFICATION message, carrying the provided Shutdown
+    // Communication when the subcode allows it.
+    //
+    // In the case of a Hard Reset, the Administrative Reset subcode and the
+    // Shutdown Communication are encapsulated in the data field (RFC 8538).
+    pub(crate) fn new_cease(
+        error_subcode: CeaseSubcode,
+        communication: Option<&str>,
+    ) -> Self {
+        let mut msg = NotificationMsg::new(ErrorCode::Cease, error_subcode);
+        match error_subcode {
+            CeaseSubcode::AdministrativeShutdown
+            | CeaseSubcode::AdministrativeReset => {
+                if let Some(communication) = communication {
+                    msg.data = encode_shutdown_communication(communication);
+                }
+            }
+            CeaseSubcode::HardReset => {
+                msg.data.push(CeaseSubcode::AdministrativeReset as u8);
+                if let Some(communication) = communication {
+                    msg.data
+                        .extend(encode_shutdown_communication(communication));
+                }
+            }
+            _ => (),
+        }
+        msg
+    }
+
+    // Returns the Shutdown Communication carried in this message, if any.
+    pub fn shutdown_communication(&self) -> Option<String> {
+        if self.error_code != ErrorCode::Cease as u8 {
+            return None;
+        }
+
+        let data = match CeaseSubcode::from_u8(self.error_subcode)? {
+            CeaseSubcode::AdministrativeShutdown
+            | CeaseSubcode::AdministrativeReset => &self.data[..],
+            CeaseSubcode::HardReset => {
+                // Skip the encapsulated Cease subcode.
+                let (subcode, data) = self.data.split_first()?;
+                if *subcode != CeaseSubcode::AdministrativeShutdown as u8
+                    && *subcode != CeaseSubcode::AdministrativeReset as u8
+                {
+                    return None;
+                }
+                data
+            }
+            _ => return None,
+        };
+
+        // RFC 9003 - Section 2:
+        // "If the Shutdown Communication is malformed, the BGP speaker
+        // receiving the message MUST NOT use it".
+        let (len, data) = data.split_first()?;
+        let communication = data.get(..*len as usize)?;
+        if communication.is_empty() {
+            return None;
+        }
+        String::from_utf8(communication.to_vec()).ok()
+    }
+
     fn encode(&self, buf: &mut BytesMut) {
         buf.put_u8(MessageType::Notification as u8);
         buf.put_u8(self.error_code);
@@ -900,6 +1192,14 @@ impl From<DecodeError> for NotificationMsg {
                     }
                 } as u8;
             }
+            DecodeError::RouteRefreshMessage(error) => {
+                error_code = ErrorCode::RouteRefreshMessageError as u8;
+                error_subcode = match error {
+                    RouteRefreshMessageError::InvalidMessageLength(..) => {
+                        RouteRefreshErrorSubcode::InvalidMessageLength
+                    }
+                } as u8;
+            }
         }
 
         // TODO: set notification data.
@@ -941,17 +1241,219 @@ impl RouteRefreshMsg {
         buf.put_u16(self.afi);
         buf.put_u8(0);
         buf.put_u8(self.safi);
+        if let Some(orf) = &self.orf {
+            orf.encode(buf);
+        }
     }
 
     fn decode(buf: &mut Bytes, msg_len: u16) -> DecodeResult<Self> {
-        if msg_len != Self::LEN {
+        if msg_len < Self::LEN {
             return Err(MessageHeaderError::BadMessageLength(msg_len).into());
         }
 
         let afi = buf.get_u16();
         let _reserved = buf.get_u8();
         let safi = buf.get_u8();
-        Ok(RouteRefreshMsg { afi, safi })
+
+        // Parse ORF data, if any.
+        let mut orf = None;
+        let orf_len = (msg_len - Self::LEN) as usize;
+        if orf_len > 0 {
+            let mut buf_orf = buf.copy_to_bytes(orf_len);
+            let Some(decoded) = RouteRefreshOrf::decode(&mut buf_orf, afi)
+            else {
+                // Malformed ORFs are reported using the ROUTE-REFRESH
+                // Message Error code (RFC 5291 and RFC 7313).
+                return Err(RouteRefreshMessageError::InvalidMessageLength(
+                    msg_len,
+                )
+                .into());
+            };
+            orf = Some(decoded);
+        }
+
+        Ok(RouteRefreshMsg { afi, safi, orf })
+    }
+}
+
+// ===== impl RouteRefreshOrf =====
+
+impl RouteRefreshOrf {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u8(self.when_to_refresh as u8);
+        if self.entries.is_empty() {
+            return;
+        }
+
+        buf.put_u8(OrfType::AddressPrefix as u8);
+        let len_pos = buf.len();
+        buf.put_u16(0);
+        for entry in &self.entries {
+            entry.encode(buf);
+        }
+
+        // Rewrite the "Length of ORFs" field.
+        let orf_len = buf.len() - len_pos - 2;
+        buf[len_pos..len_pos + 2]
+            .copy_from_slice(&(orf_len as u16).to_be_bytes());
+    }
+
+    // Decodes the ORF data of a ROUTE-REFRESH message, returning `None` if
+    // it's malformed.
+    fn decode(buf: &mut Bytes, afi: u16) -> Option<Self> {
+        let when_to_refresh = OrfWhenToRefresh::from_u8(buf.get_u8())?;
+
+        let mut entries = vec![];
+        while buf.remaining() > 0 {
+            if buf.remaining() < 3 {
+                return None;
+            }
+            let orf_type = buf.get_u8();
+            let orf_len = buf.get_u16() as usize;
+            if orf_len > buf.remaining() {
+                return None;
+            }
+            let mut buf_entries = buf.copy_to_bytes(orf_len);
+
+            // Ignore unknown ORF types.
+            if OrfType::from_u8(orf_type).is_none() {
+                continue;
+            }
+
+            while buf_entries.remaining() > 0 {
+                let entry = OrfEntry::decode(&mut buf_entries, afi)?;
+                entries.push(entry);
+            }
+        }
+
+        Some(RouteRefreshOrf {
+            when_to_refresh,
+            entries,
+        })
+    }
+}
+
+// ===== impl OrfEntry =====
+
+impl OrfEntry {
+    fn encode(&self, buf: &mut BytesMut) {
+        let (action, entry) = match self {
+            OrfEntry::Add(entry) => (OrfAction::Add, Some(entry)),
+            OrfEntry::Remove(entry) => (OrfAction::Remove, Some(entry)),
+            OrfEntry::RemoveAll => (OrfAction::RemoveAll, None),
+        };
+        let match_type = entry.map(|entry| entry.match_type as u8).unwrap_or(0);
+        buf.put_u8(((action as u8) << 6) | (match_type << 5));
+
+        if let Some(entry) = entry {
+            buf.put_u32(entry.seq);
+            buf.put_u8(entry.min_len);
+            buf.put_u8(entry.max_len);
+            match &entry.prefix {
+                IpNetwork::V4(prefix) => encode_ipv4_prefix(buf, prefix),
+                IpNetwork::V6(prefix) => encode_ipv6_prefix(buf, prefix),
+            }
+        }
+    }
+
+    // Decodes an Address Prefix ORF entry, returning `None` if it's
+    // malformed.
+    fn decode(buf: &mut Bytes, afi: u16) -> Option<Self> {
+        let flags = buf.get_u8();
+        let action = OrfAction::from_u8(flags >> 6)?;
+        let match_type = OrfMatch::from_u8((flags >> 5) & 0x01)?;
+        if action == OrfAction::RemoveAll {
+            return Some(OrfEntry::RemoveAll);
+        }
+
+        if buf.remaining() < 7 {
+            return None;
+        }
+        let seq = buf.get_u32();
+        let min_len = buf.get_u8();
+        let max_len = buf.get_u8();
+        let plen = buf.get_u8();
+        let plen_wire = prefix_wire_len(plen);
+        if plen_wire > buf.remaining() {
+            return None;
+        }
+        let prefix = match Afi::from_u16(afi)? {
+            Afi::Ipv4 => {
+                if plen > Ipv4Network::MAX_PREFIXLEN {
+                    return None;
+                }
+                let mut prefix_bytes = [0; Ipv4Addr::LENGTH];
+                buf.copy_to_slice(&mut prefix_bytes[..plen_wire]);
+                Ipv4Network::new(Ipv4Addr::from(prefix_bytes), plen)
+                    .ok()?
+                    .apply_mask()
+                    .into()
+            }
+            Afi::Ipv6 => {
+                if plen > Ipv6Network::MAX_PREFIXLEN {
+                    return None;
+                }
+                let mut prefix_bytes = [0; Ipv6Addr::LENGTH];
+                buf.copy_to_slice(&mut prefix_bytes[..plen_wire]);
+                Ipv6Network::new(Ipv6Addr::from(prefix_bytes), plen)
+                    .ok()?
+                    .apply_mask()
+                    .into()
+            }
+            Afi::BgpLs => return None,
+        };
+
+        let entry = PrefixOrfEntry {
+            seq,
+            match_type,
+            prefix,
+            min_len,
+            max_len,
+        };
+        match action {
+            OrfAction::Add => Some(OrfEntry::Add(entry)),
+            OrfAction::Remove => Some(OrfEntry::Remove(entry)),
+            OrfAction::RemoveAll => unreachable!(),
+        }
+    }
+}
+
+// ===== impl PrefixOrfEntry =====
+
+impl PrefixOrfEntry {
+    // Checks whether the given prefix matches this entry.
+    //
+    // RFC 5292 - Section 3:
+    // The prefix matches if its address is covered by the entry's prefix and
+    // its length is in the range [minlen, maxlen], where a zero minlen or
+    // maxlen takes the value of the entry's prefix length or the maximum
+    // prefix length of the address family, respectively. If both are zero,
+    // only an exact match is accepted.
+    pub fn matches(&self, prefix: &IpNetwork) -> bool {
+        if !self.prefix.contains(prefix.ip())
+            || prefix.prefix() < self.prefix.prefix()
+        {
+            return false;
+        }
+
+        let plen = prefix.prefix();
+        if self.min_len == 0 && self.max_len == 0 {
+            return plen == self.prefix.prefix();
+        }
+        let min_len = if self.min_len != 0 {
+            self.min_len
+        } else {
+            self.prefix.prefix()
+        };
+        let max_len = if self.max_len != 0 {
+            self.max_len
+        } else {
+            match self.prefix {
+                IpNetwork::V4(_) => Ipv4Network::MAX_PREFIXLEN,
+                IpNetwork::V6(_) => Ipv6Network::MAX_PREFIXLEN,
+            }
+        };
+        plen >= min_len && plen <= max_len
     }
 }
 
@@ -1041,3 +1543,19 @@ pub(crate) fn decode_ipv6_prefix(
 fn prefix_wire_len(len: u8) -> usize {
     (len as usize + 7) / 8
 }
+
+// Encodes a Shutdown Communication as a length-prefixed UTF-8 string,
+// truncating it on a character boundary if necessary.
+fn encode_shutdown_communication(communication: &str) -> Vec<u8> {
+    let mut len = communication
+        .len()
+        .min(NotificationMsg::SHUTDOWN_COMM_MAX_LEN);
+    while !communication.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    let mut data = Vec::with_capacity(len + 1);
+    data.push(len as u8);
+    data.extend_from_slice(&communication.as_bytes()[..len]);
+    data
+}
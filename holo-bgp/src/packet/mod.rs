@@ -5,6 +5,7 @@
 //
 
 pub mod attribute;
+pub mod bgp_ls;
 pub mod consts;
 pub mod error;
 pub mod message;
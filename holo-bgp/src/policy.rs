@@ -39,7 +39,7 @@ pub struct RoutePolicyInfo {
 
 // Applies neighbor import or export routing policies to a provided list of
 // routes and sends the resulting policy decisions to the specified channel.
-pub(crate) fn neighbor_apply(
+pub fn neighbor_apply(
     policy_type: PolicyType,
     nbr_addr: IpAddr,
     afi_safi: AfiSafi,
@@ -50,7 +50,72 @@ pub(crate) fn neighbor_apply(
     policy_resultp: &UnboundedSender<PolicyResultMsg>,
 ) {
     // Process policies for each route and collect the results.
-    let routes = routes
+    let routes = process_routes(
+        nbr_addr,
+        afi_safi,
+        routes,
+        policies,
+        match_sets,
+        default_policy,
+    );
+
+    // Send the resulting policy decisions to the specified channel.
+    let _ = policy_resultp.send(PolicyResultMsg::Neighbor {
+        policy_type,
+        nbr_addr,
+        afi_safi,
+        routes,
+    });
+}
+
+// Applies export routing policies on behalf of an update group to a provided
+// list of routes and sends the resulting policy decisions to the specified
+// channel.
+//
+// The provided neighbor address is the one of any group member, given that
+// neighbors whose policies match on the neighbor address are never grouped
+// together.
+pub fn update_group_apply(
+    group_id: u32,
+    nbr_addr: IpAddr,
+    afi_safi: AfiSafi,
+    routes: Vec<(IpNetwork, RoutePolicyInfo)>,
+    policies: &[Arc<Policy>],
+    match_sets: &MatchSets,
+    default_policy: DefaultPolicyType,
+    policy_resultp: &UnboundedSender<PolicyResultMsg>,
+) {
+    // Process policies for each route and collect the results.
+    let routes = process_routes(
+        nbr_addr,
+        afi_safi,
+        routes,
+        policies,
+        match_sets,
+        default_policy,
+    );
+
+    // Send the resulting policy decisions to the specified channel.
+    let _ = policy_resultp.send(PolicyResultMsg::UpdateGroup {
+        group_id,
+        afi_safi,
+        routes,
+    });
+}
+
+// ===== helper functions =====
+
+// Processes routing policies for a list of routes and returns the policy
+// results.
+fn process_routes(
+    nbr_addr: IpAddr,
+    afi_safi: AfiSafi,
+    routes: Vec<(IpNetwork, RoutePolicyInfo)>,
+    policies: &[Arc<Policy>],
+    match_sets: &MatchSets,
+    default_policy: DefaultPolicyType,
+) -> Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)> {
+    routes
         .into_iter()
         .map(|(prefix, rpinfo)| {
             let result = process_policies(
@@ -65,19 +130,9 @@ pub(crate) fn neighbor_apply(
 
             (prefix, result)
         })
-        .collect();
-
-    // Send the resulting policy decisions to the specified channel.
-    let _ = policy_resultp.send(PolicyResultMsg::Neighbor {
-        policy_type,
-        nbr_addr,
-        afi_safi,
-        routes,
-    });
+        .collect()
 }
 
-// ===== helper functions =====
-
 // Processes routing policies for a specific route and returns the policy
 // result.
 fn process_policies(
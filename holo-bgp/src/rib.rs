@@ -72,7 +72,8 @@ pub struct LocalRoute {
     pub attrs: RouteAttrs,
     pub route_type: RouteType,
     pub last_modified: Instant,
-    pub nexthops: BTreeSet<IpAddr>,
+    // Nexthops and their relative weights.
+    pub nexthops: BTreeMap<IpAddr, Option<u32>>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -409,6 +410,7 @@ impl RouteAttrs {
             ext_comm: self.ext_comm.as_ref().map(|set| set.value.clone()),
             extv6_comm: self.extv6_comm.as_ref().map(|set| set.value.clone()),
             large_comm: self.large_comm.as_ref().map(|set| set.value.clone()),
+            ls: None,
             unknown: self.unknown.clone(),
         }
     }
@@ -486,13 +488,14 @@ fn compute_nexthops<A>(
     best_route: &Route,
     selection_cfg: &RouteSelectionCfg,
     mpath_cfg: &MultipathCfg,
-) -> BTreeSet<IpAddr>
+) -> BTreeMap<IpAddr, Option<u32>>
 where
     A: AddressFamily,
 {
     // If multipath isn't enabled, return the nexthop of the best route.
     if !mpath_cfg.enabled {
-        return [A::nexthop_rx_extract(&best_route.attrs.base.value)].into();
+        let nexthop = A::nexthop_rx_extract(&best_route.attrs.base.value);
+        return [(nexthop, None)].into();
     }
 
     // Otherwise, return as many ECMP nexthops as allowed by the configuration.
@@ -500,7 +503,8 @@ where
         RouteType::Internal => mpath_cfg.ibgp_max_paths,
         RouteType::External => mpath_cfg.ebgp_max_paths,
     };
-    dest.adj_rib
+    let routes = dest
+        .adj_rib
         .values()
         .filter_map(|adj_rib| adj_rib.in_post.as_ref())
         .filter(|route| {
@@ -508,11 +512,45 @@ where
                 && route.compare(best_route, selection_cfg, Some(mpath_cfg))
                     == RouteCompare::MultipathEqual
         })
-        .map(|route| A::nexthop_rx_extract(&route.attrs.base.value))
         .take(max_paths as usize)
+        .collect::<Vec<_>>();
+
+    // Weight the nexthops according to the Link Bandwidth Extended Community,
+    // provided that all multipath routes carry it.
+    let weights = mpath_cfg
+        .link_bandwidth
+        .then(|| {
+            routes
+                .iter()
+                .map(|route| route_link_bandwidth(route))
+                .collect::<Option<Vec<_>>>()
+        })
+        .flatten();
+
+    routes
+        .iter()
+        .enumerate()
+        .map(|(i, route)| {
+            let nexthop = A::nexthop_rx_extract(&route.attrs.base.value);
+            let weight = weights.as_ref().map(|weights| weights[i]);
+            (nexthop, weight)
+        })
         .collect()
 }
 
+// Returns the link bandwidth advertised for the given route, converted to
+// kilobits per second.
+fn route_link_bandwidth(route: &Route) -> Option<u32> {
+    let ext_comm = route.attrs.ext_comm.as_ref()?;
+    let bandwidth = ext_comm
+        .value
+        .0
+        .iter()
+        .find_map(|ext_comm| ext_comm.link_bandwidth())?;
+    let kbps = (bandwidth * 8.0 / 1000.0) as u32;
+    Some(kbps.max(1))
+}
+
 // ===== global functions =====
 
 pub(crate) fn best_path<A>(
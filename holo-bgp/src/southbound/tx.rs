@@ -22,6 +22,14 @@ pub(crate) fn router_id_query(ibus_tx: &IbusSender) {
     let _ = ibus_tx.send(IbusMsg::RouterIdQuery);
 }
 
+pub(crate) fn initial_convergence(ibus_tx: &IbusSender) {
+    let _ = ibus_tx.send(IbusMsg::BgpInitialConvergence);
+}
+
+pub(crate) fn link_state_dump(ibus_tx: &IbusSender) {
+    let _ = ibus_tx.send(IbusMsg::LinkStateDump);
+}
+
 pub(crate) fn route_install(
     ibus_tx: &IbusSender,
     prefix: impl Into<IpNetwork>,
@@ -32,10 +40,11 @@ pub(crate) fn route_install(
     let nexthops = route
         .nexthops
         .iter()
-        .map(|nexthop| Nexthop::Recursive {
+        .map(|(nexthop, weight)| Nexthop::Recursive {
             addr: *nexthop,
             labels: vec![],
             resolved: Default::default(),
+            weight: *weight,
         })
         .collect::<BTreeSet<_>>();
 
@@ -48,6 +57,7 @@ pub(crate) fn route_install(
         tag: None,
         opaque_attrs: RouteOpaqueAttrs::None,
         nexthops: nexthops.clone(),
+        backup_nexthops: Default::default(),
     };
     let msg = IbusMsg::RouteIpAdd(msg);
     let _ = ibus_tx.send(msg);
@@ -7,7 +7,7 @@
 use std::sync::{atomic, Arc};
 use std::time::Duration;
 
-use holo_utils::socket::{OwnedReadHalf, OwnedWriteHalf, TcpListener};
+use holo_utils::socket::{OwnedReadHalf, OwnedWriteHalf, TcpAuth, TcpListener};
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
 use holo_utils::{Sender, UnboundedReceiver, UnboundedSender};
 use tokio::time::sleep;
@@ -51,6 +51,7 @@ pub mod messages {
     use std::net::IpAddr;
     use std::sync::Arc;
 
+    use bytes::Bytes;
     use holo_utils::bgp::AfiSafi;
     use holo_utils::policy::{
         DefaultPolicyType, MatchSets, Policy, PolicyResult, PolicyType,
@@ -116,6 +117,11 @@ pub mod messages {
                 afi_safi: AfiSafi,
                 routes: Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)>,
             },
+            UpdateGroup {
+                group_id: u32,
+                afi_safi: AfiSafi,
+                routes: Vec<(IpNetwork, PolicyResult<RoutePolicyInfo>)>,
+            },
         }
 
         impl TcpAcceptMsg {
@@ -165,6 +171,10 @@ pub mod messages {
                 nbr_addr: IpAddr,
                 msg_list: Vec<Message>,
             },
+            SendEncoded {
+                nbr_addr: IpAddr,
+                buf: Bytes,
+            },
             UpdateCapabilities(BTreeSet<NegotiatedCapability>),
         }
 
@@ -179,6 +189,15 @@ pub mod messages {
                 match_sets: Arc<MatchSets>,
                 default_policy: DefaultPolicyType,
             },
+            UpdateGroup {
+                group_id: u32,
+                nbr_addr: IpAddr,
+                afi_safi: AfiSafi,
+                routes: Vec<(IpNetwork, RoutePolicyInfo)>,
+                policies: Vec<Arc<Policy>>,
+                match_sets: Arc<MatchSets>,
+                default_policy: DefaultPolicyType,
+            },
         }
     }
 }
@@ -215,6 +234,7 @@ pub(crate) fn tcp_listener(
 // TCP connect task.
 pub(crate) fn tcp_connect(
     nbr: &Neighbor,
+    tcp_auth: Option<TcpAuth>,
     tcp_connectp: &Sender<messages::input::TcpConnectMsg>,
 ) -> Task<()> {
     #[cfg(not(feature = "testing"))]
@@ -227,7 +247,6 @@ pub(crate) fn tcp_connect(
         let ttl = nbr.tx_ttl();
         let ttl_security = nbr.config.transport.ttl_security;
         let tcp_mss = nbr.config.transport.tcp_mss;
-        let tcp_password = nbr.config.transport.md5_key.clone();
         let tcp_connectp = tcp_connectp.clone();
         Task::spawn(
             async move {
@@ -238,7 +257,7 @@ pub(crate) fn tcp_connect(
                         ttl,
                         ttl_security,
                         tcp_mss,
-                        &tcp_password,
+                        &tcp_auth,
                     )
                     .await;
 
@@ -443,6 +462,26 @@ pub(crate) fn policy_apply(
                             &policy_resultp,
                         );
                     }
+                    messages::output::PolicyApplyMsg::UpdateGroup {
+                        group_id,
+                        nbr_addr,
+                        afi_safi,
+                        routes,
+                        policies,
+                        match_sets,
+                        default_policy,
+                    } => {
+                        policy::update_group_apply(
+                            group_id,
+                            nbr_addr,
+                            afi_safi,
+                            routes,
+                            &policies,
+                            &match_sets,
+                            default_policy,
+                            &policy_resultp,
+                        );
+                    }
                 }
             }
         })
@@ -0,0 +1,262 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::IpAddr;
+
+use bytes::BytesMut;
+use holo_utils::bgp::AfiSafi;
+use holo_utils::policy::{
+    BgpPolicyCondition, DefaultPolicyType, Policies, PolicyCondition,
+};
+
+use crate::debug::Debug;
+use crate::neighbor::{
+    self, Neighbor, NeighborUpdateQueues, Neighbors, PeerType,
+};
+use crate::packet::consts::{Afi, Safi};
+use crate::packet::message::{EncodeCxt, NegotiatedCapability};
+
+// Collection of update groups.
+//
+// An update group is a set of neighbors that share the same outbound
+// parameters. Export policies are evaluated only once per group, and the
+// resulting UPDATE messages are encoded once and replicated to all group
+// members.
+#[derive(Debug, Default)]
+pub struct UpdateGroups {
+    pub groups: BTreeMap<u32, UpdateGroup>,
+    keys: BTreeMap<UpdateGroupKey, u32>,
+    next_id: u32,
+}
+
+#[derive(Debug)]
+pub struct UpdateGroup {
+    pub id: u32,
+    pub key: UpdateGroupKey,
+    pub members: BTreeSet<IpAddr>,
+    pub update_queues: NeighborUpdateQueues,
+}
+
+// Outbound parameters shared by all members of an update group.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct UpdateGroupKey {
+    pub peer_type: PeerType,
+    pub peer_as: u32,
+    // Local address of the session, relevant only when the next hop is
+    // rewritten before transmission (eBGP peers outside the shared subnet).
+    pub local_addr: Option<IpAddr>,
+    pub capabilities: BTreeSet<NegotiatedCapability>,
+    pub disable_peer_as_filter: bool,
+    pub export_policy: BTreeMap<AfiSafi, ExportPolicyKey>,
+    // Set when the export policies match on the neighbor address, in which
+    // case the neighbor can't share its update group with other neighbors.
+    pub nbr_addr: Option<IpAddr>,
+}
+
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ExportPolicyKey {
+    pub policies: BTreeSet<String>,
+    pub default_policy: DefaultPolicyType,
+}
+
+// ===== impl UpdateGroups =====
+
+impl UpdateGroups {
+    // Adds the neighbor to the update group matching its outbound parameters,
+    // creating a new group if necessary.
+    pub fn join(&mut self, nbr: &mut Neighbor, policies: &Policies) {
+        // Leave the current update group first, if any.
+        self.leave(nbr);
+
+        let key = UpdateGroupKey::new(nbr, policies);
+        let group_id = match self.keys.get(&key) {
+            Some(group_id) => *group_id,
+            None => {
+                self.next_id += 1;
+                let group_id = self.next_id;
+                let group = UpdateGroup {
+                    id: group_id,
+                    key: key.clone(),
+                    members: Default::default(),
+                    update_queues: Default::default(),
+                };
+                self.groups.insert(group_id, group);
+                self.keys.insert(key, group_id);
+                group_id
+            }
+        };
+
+        Debug::NbrUpdateGroupJoin(&nbr.remote_addr, group_id).log();
+
+        let group = self.groups.get_mut(&group_id).unwrap();
+        group.members.insert(nbr.remote_addr);
+        nbr.update_group = Some(group_id);
+    }
+
+    // Moves the neighbor to another update group if its outbound parameters
+    // have changed.
+    //
+    // Returns whether the neighbor's update group has changed.
+    pub fn update(
+        &mut self,
+        nbr: &mut Neighbor,
+        policies: &Policies,
+    ) -> bool {
+        let key = UpdateGroupKey::new(nbr, policies);
+        if let Some(group_id) = nbr.update_group
+            && self.keys.get(&key) == Some(&group_id)
+        {
+            return false;
+        }
+
+        self.join(nbr, policies);
+        true
+    }
+
+    // Removes the neighbor from its update group, deleting the group if it's
+    // left empty.
+    pub fn leave(&mut self, nbr: &mut Neighbor) {
+        let Some(group_id) = nbr.update_group.take() else {
+            return;
+        };
+        let Some(group) = self.groups.get_mut(&group_id) else {
+            return;
+        };
+
+        Debug::NbrUpdateGroupLeave(&nbr.remote_addr, group_id).log();
+
+        group.members.remove(&nbr.remote_addr);
+        if group.members.is_empty() {
+            let group = self.groups.remove(&group_id).unwrap();
+            self.keys.remove(&group.key);
+        }
+    }
+}
+
+// ===== impl UpdateGroup =====
+
+impl UpdateGroup {
+    // Check if the given address-family is enabled for the group members.
+    pub(crate) fn is_af_enabled(&self, afi: Afi, safi: Safi) -> bool {
+        neighbor::af_enabled(&self.key.capabilities, afi, safi)
+    }
+
+    // Returns the address of the first group member.
+    //
+    // Since all members share the same outbound parameters, any of them can
+    // be used as a representative of the whole group.
+    pub(crate) fn leader(&self) -> Option<IpAddr> {
+        self.members.first().copied()
+    }
+
+    // Builds UPDATE messages from the group's update queues and replicates
+    // them to all group members.
+    //
+    // The messages are encoded only once, given that all group members share
+    // the same set of negotiated capabilities.
+    pub fn send_updates(&mut self, neighbors: &mut Neighbors) {
+        let msg_list = self.update_queues.build_updates();
+        if msg_list.is_empty() {
+            return;
+        }
+
+        // Encode messages.
+        let cxt = EncodeCxt {
+            capabilities: self.key.capabilities.clone(),
+        };
+        let mut buf = BytesMut::new();
+        for msg in &msg_list {
+            buf.extend_from_slice(&msg.encode(&cxt));
+        }
+        let buf = buf.freeze();
+
+        // Replicate the encoded messages to all group members.
+        for nbr_addr in &self.members {
+            let Some(nbr) = neighbors.get_mut(nbr_addr) else {
+                continue;
+            };
+            nbr.message_list_send_encoded(&msg_list, buf.clone());
+        }
+    }
+}
+
+// ===== impl UpdateGroupKey =====
+
+impl UpdateGroupKey {
+    fn new(nbr: &Neighbor, policies: &Policies) -> UpdateGroupKey {
+        // Get export policy configuration for each address family.
+        let export_policy = [AfiSafi::Ipv4Unicast, AfiSafi::Ipv6Unicast]
+            .into_iter()
+            .map(|afi_safi| {
+                let apply_policy_cfg = nbr
+                    .config
+                    .afi_safi
+                    .get(&afi_safi)
+                    .map(|afi_safi| &afi_safi.apply_policy)
+                    .unwrap_or(&nbr.config.apply_policy);
+                let key = ExportPolicyKey {
+                    policies: apply_policy_cfg.export_policy.clone(),
+                    default_policy: apply_policy_cfg.default_export_policy,
+                };
+                (afi_safi, key)
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        // Neighbors whose export policies depend on the neighbor address
+        // can't be grouped with other neighbors.
+        let nbr_addr = export_policy
+            .values()
+            .any(|key| policies_match_neighbor(&key.policies, policies))
+            .then_some(nbr.remote_addr);
+
+        // The local address is used to rewrite the next hop of routes
+        // advertised to eBGP peers outside the shared subnet.
+        let local_addr = match nbr.peer_type {
+            PeerType::External if !nbr.shared_subnet => {
+                nbr.conn_info.as_ref().map(|conn_info| conn_info.local_addr)
+            }
+            _ => None,
+        };
+
+        UpdateGroupKey {
+            peer_type: nbr.peer_type,
+            peer_as: nbr.config.peer_as,
+            local_addr,
+            capabilities: nbr.capabilities_nego.clone(),
+            disable_peer_as_filter: nbr
+                .config
+                .as_path_options
+                .disable_peer_as_filter,
+            export_policy,
+            nbr_addr,
+        }
+    }
+}
+
+// ===== helper functions =====
+
+// Checks whether any of the given policies has a condition that matches on
+// the neighbor address.
+fn policies_match_neighbor(
+    policy_names: &BTreeSet<String>,
+    policies: &Policies,
+) -> bool {
+    policy_names
+        .iter()
+        .filter_map(|policy_name| policies.get(policy_name))
+        .flat_map(|policy| policy.stmts.values())
+        .flat_map(|stmt| stmt.conditions.values())
+        .any(|condition| {
+            matches!(
+                condition,
+                PolicyCondition::MatchNeighborSet(..)
+                    | PolicyCondition::Bgp(
+                        BgpPolicyCondition::MatchNeighbor { .. }
+                    )
+            )
+        })
+}
@@ -6,4 +6,6 @@
 
 #![feature(lazy_cell)]
 
+mod neighbor;
 mod packet;
+mod update_group;
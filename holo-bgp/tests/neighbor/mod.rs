@@ -0,0 +1,78 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::net::IpAddr;
+use std::str::FromStr;
+
+use holo_bgp::neighbor::{fsm, Neighbor, PeerType};
+use holo_bgp::packet::consts::{Afi, Safi};
+use holo_bgp::packet::message::NegotiatedCapability;
+use holo_utils::bgp::AfiSafi;
+
+//
+// Helper functions.
+//
+
+fn neighbor(afs: &[(Afi, Safi)]) -> Neighbor {
+    let remote_addr = IpAddr::from_str("10.0.1.2").unwrap();
+    let mut nbr = Neighbor::new(remote_addr, PeerType::External);
+    nbr.state = fsm::State::Established;
+    nbr.capabilities_nego = afs
+        .iter()
+        .map(|(afi, safi)| NegotiatedCapability::MultiProtocol {
+            afi: *afi,
+            safi: *safi,
+        })
+        .collect();
+    nbr
+}
+
+//
+// Tests.
+//
+
+#[test]
+fn test_converged_established_only() {
+    let mut nbr = neighbor(&[(Afi::Ipv4, Safi::Unicast)]);
+    nbr.eor_rcvd.insert(AfiSafi::Ipv4Unicast);
+    assert!(nbr.is_converged());
+
+    nbr.state = fsm::State::OpenConfirm;
+    assert!(!nbr.is_converged());
+}
+
+#[test]
+fn test_converged_all_afs() {
+    let mut nbr =
+        neighbor(&[(Afi::Ipv4, Safi::Unicast), (Afi::Ipv6, Safi::Unicast)]);
+    assert!(!nbr.is_converged());
+
+    nbr.eor_rcvd.insert(AfiSafi::Ipv4Unicast);
+    assert!(!nbr.is_converged());
+
+    nbr.eor_rcvd.insert(AfiSafi::Ipv6Unicast);
+    assert!(nbr.is_converged());
+}
+
+#[test]
+fn test_converged_ignores_unnegotiated_afs() {
+    let mut nbr = neighbor(&[(Afi::Ipv6, Safi::Unicast)]);
+    nbr.eor_rcvd.insert(AfiSafi::Ipv4Unicast);
+    assert!(!nbr.is_converged());
+
+    nbr.eor_rcvd.insert(AfiSafi::Ipv6Unicast);
+    assert!(nbr.is_converged());
+}
+
+#[test]
+fn test_converged_no_capabilities() {
+    // Peers without capabilities support only the IPv4 unicast AF.
+    let mut nbr = neighbor(&[]);
+    assert!(!nbr.is_converged());
+
+    nbr.eor_rcvd.insert(AfiSafi::Ipv4Unicast);
+    assert!(nbr.is_converged());
+}
@@ -11,6 +11,7 @@ mod route_refresh;
 mod update;
 
 use holo_bgp::neighbor::PeerType;
+use holo_bgp::packet::error::DecodeError;
 use holo_bgp::packet::message::{
     DecodeCxt, EncodeCxt, Message, NegotiatedCapability,
 };
@@ -40,3 +41,13 @@ fn test_decode_msg(bytes: &[u8], msg_expected: &Message) {
     let msg_actual = Message::decode(&bytes, &cxt).unwrap();
     assert_eq!(*msg_expected, msg_actual);
 }
+
+fn test_decode_msg_error(bytes: &[u8]) -> DecodeError {
+    let cxt = DecodeCxt {
+        peer_type: PeerType::Internal,
+        peer_as: 65550,
+        capabilities: [NegotiatedCapability::FourOctetAsNumber].into(),
+    };
+
+    Message::decode(bytes, &cxt).unwrap_err()
+}
@@ -6,7 +6,9 @@
 
 use std::sync::LazyLock as Lazy;
 
-use holo_bgp::packet::consts::{ErrorCode, MessageHeaderErrorSubcode};
+use holo_bgp::packet::consts::{
+    CeaseSubcode, ErrorCode, MessageHeaderErrorSubcode,
+};
 use holo_bgp::packet::message::{Message, NotificationMsg};
 
 use super::{test_decode_msg, test_encode_msg};
@@ -26,6 +28,21 @@ static NOTIFICATION1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
     )
 });
 
+static NOTIFICATION2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x1b, 0x03, 0x06, 0x02, 0x05,
+            0x68, 0x65, 0x6c, 0x6c, 0x6f,
+        ],
+        Message::Notification(NotificationMsg {
+            error_code: ErrorCode::Cease as u8,
+            error_subcode: CeaseSubcode::AdministrativeShutdown as u8,
+            data: vec![0x05, 0x68, 0x65, 0x6c, 0x6c, 0x6f],
+        }),
+    )
+});
+
 #[test]
 fn test_encode_notification1() {
     let (ref bytes, ref msg) = *NOTIFICATION1;
@@ -37,3 +54,24 @@ fn test_decode_notification1() {
     let (ref bytes, ref msg) = *NOTIFICATION1;
     test_decode_msg(bytes, msg);
 }
+
+#[test]
+fn test_encode_notification2() {
+    let (ref bytes, ref msg) = *NOTIFICATION2;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_notification2() {
+    let (ref bytes, ref msg) = *NOTIFICATION2;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_shutdown_communication_notification2() {
+    let (_, ref msg) = *NOTIFICATION2;
+    let Message::Notification(msg) = msg else {
+        unreachable!()
+    };
+    assert_eq!(msg.shutdown_communication().as_deref(), Some("hello"));
+}
@@ -8,8 +8,8 @@ use std::net::Ipv4Addr;
 use std::str::FromStr;
 use std::sync::LazyLock as Lazy;
 
-use holo_bgp::packet::consts::{Afi, Safi, BGP_VERSION};
-use holo_bgp::packet::message::{Capability, Message, OpenMsg};
+use holo_bgp::packet::consts::{Afi, OrfMode, OrfType, Safi, BGP_VERSION};
+use holo_bgp::packet::message::{Capability, Message, OpenMsg, OrfTuple};
 
 use super::{test_decode_msg, test_encode_msg};
 
@@ -85,6 +85,40 @@ static OPEN3: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
     )
 });
 
+static OPEN4: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x30, 0x01, 0x04, 0x00, 0x01,
+            0x00, 0xb4, 0x01, 0x01, 0x01, 0x01, 0x13, 0x02, 0x06, 0x01, 0x04,
+            0x00, 0x01, 0x00, 0x01, 0x02, 0x09, 0x03, 0x07, 0x00, 0x01, 0x00,
+            0x01, 0x01, 0x40, 0x01,
+        ],
+        Message::Open(OpenMsg {
+            version: BGP_VERSION,
+            my_as: 1,
+            holdtime: 180,
+            identifier: Ipv4Addr::from_str("1.1.1.1").unwrap(),
+            capabilities: [
+                Capability::MultiProtocol {
+                    afi: Afi::Ipv4,
+                    safi: Safi::Unicast,
+                },
+                Capability::OutboundRouteFiltering(
+                    [OrfTuple {
+                        afi: Afi::Ipv4,
+                        safi: Safi::Unicast,
+                        orf_type: OrfType::AddressPrefix,
+                        mode: OrfMode::Receive,
+                    }]
+                    .into(),
+                ),
+            ]
+            .into(),
+        }),
+    )
+});
+
 #[test]
 fn test_encode_open1() {
     let (ref bytes, ref msg) = *OPEN1;
@@ -120,3 +154,15 @@ fn test_decode_open3() {
     let (ref bytes, ref msg) = *OPEN3;
     test_decode_msg(bytes, msg);
 }
+
+#[test]
+fn test_encode_open4() {
+    let (ref bytes, ref msg) = *OPEN4;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_open4() {
+    let (ref bytes, ref msg) = *OPEN4;
+    test_decode_msg(bytes, msg);
+}
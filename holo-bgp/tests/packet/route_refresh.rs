@@ -4,12 +4,19 @@
 // SPDX-License-Identifier: MIT
 //
 
+use std::str::FromStr;
 use std::sync::LazyLock as Lazy;
 
-use holo_bgp::packet::consts::{Afi, Safi};
-use holo_bgp::packet::message::{Message, RouteRefreshMsg};
+use holo_bgp::packet::consts::{
+    Afi, ErrorCode, OrfMatch, OrfWhenToRefresh, RouteRefreshErrorSubcode, Safi,
+};
+use holo_bgp::packet::message::{
+    Message, NotificationMsg, OrfEntry, PrefixOrfEntry, RouteRefreshMsg,
+    RouteRefreshOrf,
+};
+use ipnetwork::IpNetwork;
 
-use super::{test_decode_msg, test_encode_msg};
+use super::{test_decode_msg, test_decode_msg_error, test_encode_msg};
 
 static ROUTE_REFRESH1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
     (
@@ -21,10 +28,58 @@ static ROUTE_REFRESH1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
         Message::RouteRefresh(RouteRefreshMsg {
             afi: Afi::Ipv4 as u16,
             safi: Safi::Unicast as u8,
+            orf: None,
         }),
     )
 });
 
+static ROUTE_REFRESH2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x2f, 0x05, 0x00, 0x01, 0x00,
+            0x01, 0x01, 0x40, 0x00, 0x14, 0x80, 0x00, 0x00, 0x00, 0x00, 0x0a,
+            0x10, 0x18, 0x08, 0x0a, 0x20, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00,
+            0x0c, 0xac, 0x10,
+        ],
+        Message::RouteRefresh(RouteRefreshMsg {
+            afi: Afi::Ipv4 as u16,
+            safi: Safi::Unicast as u8,
+            orf: Some(RouteRefreshOrf {
+                when_to_refresh: OrfWhenToRefresh::Immediate,
+                entries: vec![
+                    OrfEntry::RemoveAll,
+                    OrfEntry::Add(PrefixOrfEntry {
+                        seq: 10,
+                        match_type: OrfMatch::Permit,
+                        prefix: IpNetwork::from_str("10.0.0.0/8").unwrap(),
+                        min_len: 16,
+                        max_len: 24,
+                    }),
+                    OrfEntry::Add(PrefixOrfEntry {
+                        seq: 20,
+                        match_type: OrfMatch::Deny,
+                        prefix: IpNetwork::from_str("172.16.0.0/12").unwrap(),
+                        min_len: 0,
+                        max_len: 0,
+                    }),
+                ],
+            }),
+        }),
+    )
+});
+
+// Address Prefix ORF entry whose prefix length (33) exceeds the maximum prefix
+// length of the address family.
+static ROUTE_REFRESH3: Lazy<Vec<u8>> = Lazy::new(|| {
+    vec![
+        0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+        0xff, 0xff, 0xff, 0xff, 0x00, 0x28, 0x05, 0x00, 0x01, 0x00, 0x01, 0x01,
+        0x40, 0x00, 0x0d, 0x00, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x00, 0x21, 0x0a,
+        0x00, 0x00, 0x00, 0x00,
+    ]
+});
+
 #[test]
 fn test_encode_route_refresh1() {
     let (ref bytes, ref msg) = *ROUTE_REFRESH1;
@@ -36,3 +91,63 @@ fn test_decode_route_refresh1() {
     let (ref bytes, ref msg) = *ROUTE_REFRESH1;
     test_decode_msg(bytes, msg);
 }
+
+#[test]
+fn test_encode_route_refresh2() {
+    let (ref bytes, ref msg) = *ROUTE_REFRESH2;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_route_refresh2() {
+    let (ref bytes, ref msg) = *ROUTE_REFRESH2;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_route_refresh3() {
+    let bytes = &*ROUTE_REFRESH3;
+    let error = test_decode_msg_error(bytes);
+    let msg = NotificationMsg::from(error);
+    assert_eq!(msg.error_code, ErrorCode::RouteRefreshMessageError as u8);
+    assert_eq!(
+        msg.error_subcode,
+        RouteRefreshErrorSubcode::InvalidMessageLength as u8
+    );
+}
+
+#[test]
+fn test_prefix_orf_match() {
+    let entry = |prefix: &str, min_len, max_len| PrefixOrfEntry {
+        seq: 10,
+        match_type: OrfMatch::Permit,
+        prefix: IpNetwork::from_str(prefix).unwrap(),
+        min_len,
+        max_len,
+    };
+    let prefix = |prefix: &str| IpNetwork::from_str(prefix).unwrap();
+
+    // Exact match.
+    let exact = entry("10.0.0.0/8", 0, 0);
+    assert!(exact.matches(&prefix("10.0.0.0/8")));
+    assert!(!exact.matches(&prefix("10.1.0.0/16")));
+
+    // Prefix length range.
+    let range = entry("10.0.0.0/8", 16, 24);
+    assert!(!range.matches(&prefix("10.0.0.0/8")));
+    assert!(range.matches(&prefix("10.1.0.0/16")));
+    assert!(range.matches(&prefix("10.1.1.0/24")));
+    assert!(!range.matches(&prefix("10.1.1.0/25")));
+    assert!(!range.matches(&prefix("11.1.0.0/16")));
+
+    // Only minimum length.
+    let min = entry("10.0.0.0/8", 24, 0);
+    assert!(!min.matches(&prefix("10.1.0.0/16")));
+    assert!(min.matches(&prefix("10.1.1.1/32")));
+
+    // Only maximum length.
+    let max = entry("10.0.0.0/8", 0, 16);
+    assert!(max.matches(&prefix("10.0.0.0/8")));
+    assert!(max.matches(&prefix("10.1.0.0/16")));
+    assert!(!max.matches(&prefix("10.1.1.0/24")));
+}
@@ -11,11 +11,12 @@ use std::sync::LazyLock as Lazy;
 use holo_bgp::packet::attribute::{
     Aggregator, AsPath, AsPathSegment, Attrs, BaseAttrs, ClusterList, CommList,
 };
-use holo_bgp::packet::consts::{AsPathSegmentType, Origin};
+use holo_bgp::packet::bgp_ls::{LsNlri, LsNodeDesc, LsNodeNlri};
+use holo_bgp::packet::consts::{AsPathSegmentType, LsProtocolId, Origin};
 use holo_bgp::packet::message::{
     Message, MpReachNlri, MpUnreachNlri, ReachNlri, UnreachNlri, UpdateMsg,
 };
-use holo_utils::bgp::{Comm, ExtComm, Extv6Comm, LargeComm};
+use holo_utils::bgp::{AfiSafi, Comm, ExtComm, Extv6Comm, LargeComm};
 use ipnetwork::{Ipv4Network, Ipv6Network};
 
 use super::{test_decode_msg, test_encode_msg};
@@ -135,12 +136,66 @@ static UPDATE2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
                 large_comm: Some(CommList(
                     [LargeComm([0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1])].into(),
                 )),
+                ls: None,
                 unknown: Box::new([]),
             }),
         }),
     )
 });
 
+static UPDATE3: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x47, 0x02, 0x00, 0x00, 0x00,
+            0x30, 0x90, 0x0f, 0x00, 0x2c, 0x40, 0x04, 0x47, 0x00, 0x01, 0x00,
+            0x25, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x00, 0x18, 0x02, 0x00, 0x00, 0x04, 0x00, 0x00, 0xfd, 0xe8,
+            0x02, 0x02, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x00,
+            0x04, 0x01, 0x01, 0x01, 0x01,
+        ],
+        Message::Update(UpdateMsg {
+            reach: None,
+            unreach: None,
+            mp_reach: None,
+            mp_unreach: Some(MpUnreachNlri::BgpLs {
+                nlris: vec![LsNlri::Node(LsNodeNlri {
+                    protocol_id: LsProtocolId::Ospfv2,
+                    identifier: 0,
+                    local: LsNodeDesc {
+                        asn: Some(65000),
+                        ospf_area_id: Some(
+                            Ipv4Addr::from_str("0.0.0.0").unwrap(),
+                        ),
+                        igp_router_id: Some(
+                            Ipv4Addr::from_str("1.1.1.1").unwrap(),
+                        ),
+                        pseudonode: None,
+                    },
+                })],
+            }),
+            attrs: None,
+        }),
+    )
+});
+
+static UPDATE4: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x1e, 0x02, 0x00, 0x00, 0x00,
+            0x07, 0x90, 0x0f, 0x00, 0x03, 0x00, 0x02, 0x01,
+        ],
+        Message::Update(UpdateMsg {
+            reach: None,
+            unreach: None,
+            mp_reach: None,
+            mp_unreach: Some(MpUnreachNlri::Ipv6Unicast { prefixes: vec![] }),
+            attrs: None,
+        }),
+    )
+});
+
 #[test]
 fn test_encode_update1() {
     let (ref bytes, ref msg) = *UPDATE1;
@@ -164,3 +219,46 @@ fn test_decode_update2() {
     let (ref bytes, ref msg) = *UPDATE2;
     test_decode_msg(bytes, msg);
 }
+
+#[test]
+fn test_encode_update3() {
+    let (ref bytes, ref msg) = *UPDATE3;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_update3() {
+    let (ref bytes, ref msg) = *UPDATE3;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_encode_update4() {
+    let (ref bytes, ref msg) = *UPDATE4;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_update4() {
+    let (ref bytes, ref msg) = *UPDATE4;
+    test_decode_msg(bytes, msg);
+}
+
+#[test]
+fn test_eor() {
+    for afi_safi in [AfiSafi::Ipv4Unicast, AfiSafi::Ipv6Unicast, AfiSafi::BgpLs]
+    {
+        let msg = UpdateMsg::eor(afi_safi);
+        assert!(msg.is_eor());
+        assert_eq!(msg.eor_afi_safi(), Some(afi_safi));
+    }
+
+    let eor_afi_safi = |msg: &Message| match msg {
+        Message::Update(msg) => msg.eor_afi_safi(),
+        _ => unreachable!(),
+    };
+    assert_eq!(eor_afi_safi(&UPDATE1.1), Some(AfiSafi::Ipv4Unicast));
+    assert_eq!(eor_afi_safi(&UPDATE2.1), None);
+    assert_eq!(eor_afi_safi(&UPDATE3.1), None);
+    assert_eq!(eor_afi_safi(&UPDATE4.1), Some(AfiSafi::Ipv6Unicast));
+}
@@ -0,0 +1,236 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use holo_bgp::neighbor::{Neighbor, PeerType};
+use holo_bgp::packet::consts::{Afi, Safi};
+use holo_bgp::packet::message::NegotiatedCapability;
+use holo_bgp::update_group::UpdateGroups;
+use holo_utils::policy::{
+    Policies, Policy, PolicyAction, PolicyCondition, PolicyStmt,
+};
+use holo_utils::socket::TcpConnInfo;
+
+//
+// Helper functions.
+//
+
+fn neighbor(addr: &str, peer_type: PeerType, peer_as: u32) -> Neighbor {
+    let remote_addr = IpAddr::from_str(addr).unwrap();
+    let mut nbr = Neighbor::new(remote_addr, peer_type);
+    nbr.config.peer_as = peer_as;
+    nbr.shared_subnet = true;
+    nbr.capabilities_nego = [
+        NegotiatedCapability::MultiProtocol {
+            afi: Afi::Ipv4,
+            safi: Safi::Unicast,
+        },
+        NegotiatedCapability::FourOctetAsNumber,
+    ]
+    .into();
+    nbr
+}
+
+fn policy(name: &str, conditions: Vec<PolicyCondition>) -> Arc<Policy> {
+    let mut stmt = PolicyStmt::new("10".to_owned());
+    for condition in conditions {
+        stmt.condition_add(condition);
+    }
+    stmt.action_add(PolicyAction::Accept(true));
+    Arc::new(Policy {
+        name: name.to_owned(),
+        stmts: [(stmt.name.clone(), stmt)].into(),
+    })
+}
+
+fn policies(policies: Vec<Arc<Policy>>) -> Policies {
+    policies
+        .into_iter()
+        .map(|policy| (policy.name.clone(), policy))
+        .collect::<BTreeMap<_, _>>()
+}
+
+fn set_export_policy(nbr: &mut Neighbor, policy: &str) {
+    nbr.config
+        .apply_policy
+        .export_policy
+        .insert(policy.to_owned());
+}
+
+//
+// Tests.
+//
+
+#[test]
+fn test_group_shared() {
+    let policies = policies(vec![]);
+    let mut groups = UpdateGroups::default();
+    let mut nbr1 = neighbor("10.0.1.1", PeerType::External, 65001);
+    let mut nbr2 = neighbor("10.0.1.2", PeerType::External, 65001);
+
+    groups.join(&mut nbr1, &policies);
+    groups.join(&mut nbr2, &policies);
+
+    // Neighbors with the same outbound parameters share the same group.
+    assert_eq!(groups.groups.len(), 1);
+    assert!(nbr1.update_group.is_some());
+    assert_eq!(nbr1.update_group, nbr2.update_group);
+    let group = &groups.groups[&nbr1.update_group.unwrap()];
+    assert_eq!(group.members, [nbr1.remote_addr, nbr2.remote_addr].into());
+}
+
+#[test]
+fn test_group_keying() {
+    let policies = policies(vec![policy("EXPORT", vec![])]);
+    let mut groups = UpdateGroups::default();
+    let mut nbr1 = neighbor("10.0.1.1", PeerType::External, 65001);
+
+    // Different peer AS.
+    let mut nbr2 = neighbor("10.0.1.2", PeerType::External, 65002);
+
+    // Different peer type.
+    let mut nbr3 = neighbor("10.0.1.3", PeerType::Internal, 65001);
+
+    // Different set of negotiated capabilities.
+    let mut nbr4 = neighbor("10.0.1.4", PeerType::External, 65001);
+    nbr4.capabilities_nego
+        .insert(NegotiatedCapability::RouteRefresh);
+
+    // Different export policies.
+    let mut nbr5 = neighbor("10.0.1.5", PeerType::External, 65001);
+    set_export_policy(&mut nbr5, "EXPORT");
+
+    // Next hop rewritten using the session's local address.
+    let mut nbr6 = neighbor("10.0.1.6", PeerType::External, 65001);
+    nbr6.shared_subnet = false;
+    nbr6.conn_info = Some(TcpConnInfo {
+        local_addr: IpAddr::from(Ipv4Addr::new(10, 0, 2, 1)),
+        local_port: 179,
+        remote_addr: nbr6.remote_addr,
+        remote_port: 50000,
+    });
+
+    // Different AS_PATH loop prevention behavior.
+    let mut nbr7 = neighbor("10.0.1.7", PeerType::External, 65001);
+    nbr7.config.as_path_options.disable_peer_as_filter = true;
+
+    for nbr in [
+        &mut nbr1, &mut nbr2, &mut nbr3, &mut nbr4, &mut nbr5, &mut nbr6,
+        &mut nbr7,
+    ] {
+        groups.join(nbr, &policies);
+    }
+    assert_eq!(groups.groups.len(), 7);
+}
+
+#[test]
+fn test_group_policy_match_neighbor() {
+    let policies = policies(vec![policy(
+        "EXPORT",
+        vec![PolicyCondition::MatchNeighborSet("NBRS".to_owned())],
+    )]);
+    let mut groups = UpdateGroups::default();
+    let mut nbr1 = neighbor("10.0.1.1", PeerType::External, 65001);
+    let mut nbr2 = neighbor("10.0.1.2", PeerType::External, 65001);
+    set_export_policy(&mut nbr1, "EXPORT");
+    set_export_policy(&mut nbr2, "EXPORT");
+
+    groups.join(&mut nbr1, &policies);
+    groups.join(&mut nbr2, &policies);
+
+    // Neighbors whose export policies match on the neighbor address can't be
+    // grouped together.
+    assert_eq!(groups.groups.len(), 2);
+    assert_ne!(nbr1.update_group, nbr2.update_group);
+}
+
+#[test]
+fn test_group_leave() {
+    let policies = policies(vec![]);
+    let mut groups = UpdateGroups::default();
+    let mut nbr1 = neighbor("10.0.1.1", PeerType::External, 65001);
+    let mut nbr2 = neighbor("10.0.1.2", PeerType::External, 65001);
+    groups.join(&mut nbr1, &policies);
+    groups.join(&mut nbr2, &policies);
+    let group_id = nbr1.update_group.unwrap();
+
+    // The group is kept while it has members left.
+    groups.leave(&mut nbr1);
+    assert!(nbr1.update_group.is_none());
+    assert_eq!(groups.groups[&group_id].members, [nbr2.remote_addr].into());
+
+    // Empty groups are deleted.
+    groups.leave(&mut nbr2);
+    assert!(nbr2.update_group.is_none());
+    assert!(groups.groups.is_empty());
+
+    // A new group is created when a neighbor joins again.
+    groups.join(&mut nbr1, &policies);
+    assert_ne!(nbr1.update_group, Some(group_id));
+    assert_eq!(groups.groups.len(), 1);
+}
+
+#[test]
+fn test_group_update_apply_policy() {
+    let policies = policies(vec![policy("EXPORT", vec![])]);
+    let mut groups = UpdateGroups::default();
+    let mut nbr1 = neighbor("10.0.1.1", PeerType::External, 65001);
+    let mut nbr2 = neighbor("10.0.1.2", PeerType::External, 65001);
+    groups.join(&mut nbr1, &policies);
+    groups.join(&mut nbr2, &policies);
+    let group_id = nbr1.update_group.unwrap();
+
+    // Unchanged outbound parameters.
+    assert!(!groups.update(&mut nbr1, &policies));
+    assert_eq!(nbr1.update_group, Some(group_id));
+
+    // Export policy change.
+    set_export_policy(&mut nbr1, "EXPORT");
+    assert!(groups.update(&mut nbr1, &policies));
+    assert_ne!(nbr1.update_group, Some(group_id));
+    assert_eq!(groups.groups[&group_id].members, [nbr2.remote_addr].into());
+
+    // Both neighbors have the same export policy again.
+    set_export_policy(&mut nbr2, "EXPORT");
+    assert!(groups.update(&mut nbr2, &policies));
+    assert_eq!(nbr1.update_group, nbr2.update_group);
+    assert!(!groups.groups.contains_key(&group_id));
+    assert_eq!(groups.groups.len(), 1);
+}
+
+#[test]
+fn test_group_update_policy_definition() {
+    let mut groups = UpdateGroups::default();
+    let mut nbr1 = neighbor("10.0.1.1", PeerType::External, 65001);
+    let mut nbr2 = neighbor("10.0.1.2", PeerType::External, 65001);
+    set_export_policy(&mut nbr1, "EXPORT");
+    set_export_policy(&mut nbr2, "EXPORT");
+
+    let policies_old = policies(vec![policy("EXPORT", vec![])]);
+    groups.join(&mut nbr1, &policies_old);
+    groups.join(&mut nbr2, &policies_old);
+    assert_eq!(groups.groups.len(), 1);
+
+    // The policy definition now matches on the neighbor address.
+    let policies_new = policies(vec![policy(
+        "EXPORT",
+        vec![PolicyCondition::MatchNeighborSet("NBRS".to_owned())],
+    )]);
+    assert!(groups.update(&mut nbr1, &policies_new));
+    assert!(groups.update(&mut nbr2, &policies_new));
+    assert_eq!(groups.groups.len(), 2);
+    assert_ne!(nbr1.update_group, nbr2.update_group);
+
+    // And back.
+    assert!(groups.update(&mut nbr1, &policies_old));
+    assert!(groups.update(&mut nbr2, &policies_old));
+    assert_eq!(groups.groups.len(), 1);
+    assert_eq!(nbr1.update_group, nbr2.update_group);
+}
@@ -9,13 +9,16 @@
 pub mod northbound;
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
 
+use chrono::{DateTime, Utc};
 use derive_new::new;
 use holo_northbound::{
     process_northbound_msg, NbDaemonReceiver, NbDaemonSender, NbProviderSender,
     ProviderBase,
 };
-use holo_utils::ibus::{IbusReceiver, IbusSender};
+use holo_utils::ibus::{IbusMsg, IbusReceiver, IbusSender};
 use holo_utils::keychain::Keychain;
 use tokio::sync::mpsc;
 use tracing::Instrument;
@@ -42,6 +45,15 @@ impl Master {
         let mut resources = vec![];
 
         loop {
+            // Wait until the closest key lifetime boundary, if any. A small
+            // margin is added so that lifetimes ending at the boundary are
+            // already expired when it's processed.
+            let next_boundary = self.next_lifetime_boundary();
+            let timeout = next_boundary.as_ref().map(|(time, _)| {
+                (*time - Utc::now()).to_std().unwrap_or_default()
+                    + Duration::from_secs(1)
+            });
+
             tokio::select! {
                 Some(request) = nb_rx.recv() => {
                     process_northbound_msg(
@@ -54,8 +66,50 @@ impl Master {
                 Ok(_) = ibus_rx.recv() => {
                     // Ignore for now.
                 }
+                _ = tokio::time::sleep(timeout.unwrap_or_default()),
+                    if timeout.is_some() =>
+                {
+                    // The set of active keys changed, so protocols need to
+                    // update their authentication state (e.g. the TCP-AO keys
+                    // of their sockets).
+                    let (_, names) = next_boundary.unwrap();
+                    for keychain in
+                        names.iter().filter_map(|name| self.keychains.get(name))
+                    {
+                        self.notify_update(keychain);
+                    }
+                }
+            }
+        }
+    }
+
+    // Returns the closest key lifetime boundary among all key-chains, along
+    // with the names of the key-chains it applies to.
+    fn next_lifetime_boundary(&self) -> Option<(DateTime<Utc>, Vec<String>)> {
+        let mut next: Option<(DateTime<Utc>, Vec<String>)> = None;
+        for keychain in self.keychains.values() {
+            let Some(boundary) = keychain.next_lifetime_boundary() else {
+                continue;
+            };
+            match &mut next {
+                Some((time, names)) if *time == boundary => {
+                    names.push(keychain.name.clone());
+                }
+                Some((time, _)) if *time < boundary => (),
+                _ => next = Some((boundary, vec![keychain.name.clone()])),
             }
         }
+        next
+    }
+
+    // Notifies protocols that the given key-chain has been updated.
+    fn notify_update(&self, keychain: &Keychain) {
+        // Create a reference-counted copy of the keychain to be shared among
+        // all protocol instances.
+        let keychain = Arc::new(keychain.clone());
+
+        let msg = IbusMsg::KeychainUpd(keychain);
+        let _ = self.ibus_tx.send(msg);
     }
 }
 
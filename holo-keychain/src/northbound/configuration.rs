@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::sync::{Arc, LazyLock as Lazy};
+use std::sync::LazyLock as Lazy;
 use std::time::Duration;
 
 use async_trait::async_trait;
@@ -438,13 +438,9 @@ impl Provider for Master {
                     .max()
                     .unwrap_or(0);
 
-                // Create a reference-counted copy of the keychain to be shared among all
-                // protocol instances.
-                let keychain = Arc::new(keychain.clone());
-
                 // Notify protocols that the keychain has been updated.
-                let msg = IbusMsg::KeychainUpd(keychain);
-                let _ = self.ibus_tx.send(msg);
+                let keychain = &self.keychains[&name];
+                self.notify_update(keychain);
             }
             Event::KeychainDelete(name) => {
                 // Notify protocols that the keychain has been deleted.
@@ -14,7 +14,6 @@ use crate::error::Error;
 use crate::instance::InstanceUpView;
 use crate::interface::Interface;
 use crate::neighbor::{self, Neighbor};
-use crate::network;
 use crate::packet::StatusCode;
 
 pub type InterfaceId = usize;
@@ -634,16 +633,15 @@ impl Neighbors {
         lsr_id: &Ipv4Addr,
         status_code: StatusCode,
     ) {
-        if instance
-            .state
-            .ipv4
-            .adjacencies
-            .get_by_lsr_id(lsr_id)
-            .is_none()
-        {
+        if instance.state.adjacencies.get_by_lsr_id(lsr_id).is_none() {
             let (nbr_idx, nbr) =
                 instance.state.neighbors.get_mut_by_lsr_id(lsr_id).unwrap();
 
+            // Keep the neighbor while waiting for it to restart.
+            if !nbr.is_operational() && nbr.gr.is_some() {
+                return;
+            }
+
             // Send error notification.
             if nbr.is_operational() {
                 nbr.send_notification(
@@ -659,19 +657,29 @@ impl Neighbors {
                 );
             }
 
-            // Unset neighbor password (if any).
+            // Unset neighbor authentication (if any).
             let nbr = &instance.state.neighbors[nbr_idx];
-            network::tcp::listen_socket_md5sig_update(
-                &instance.state.ipv4.session_socket,
-                &nbr.trans_addr,
-                None,
-            );
+            instance
+                .state
+                .listen_socket_auth_update(&nbr.trans_addr, None);
 
             // Delete neighbor.
             instance.state.neighbors.delete(nbr_idx);
         }
     }
 
+    pub(crate) fn update_trans_addr(
+        &mut self,
+        nbr_idx: NeighborIndex,
+        trans_addr: IpAddr,
+    ) {
+        let nbr = &mut self.arena[nbr_idx];
+
+        self.addr_tree.remove(&nbr.trans_addr);
+        nbr.trans_addr = trans_addr;
+        self.addr_tree.insert(nbr.trans_addr, nbr_idx);
+    }
+
     pub(crate) fn update_id(&mut self, nbr_idx: NeighborIndex, id: NeighborId) {
         let nbr = &mut self.arena[nbr_idx];
 
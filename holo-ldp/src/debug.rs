@@ -6,6 +6,7 @@
 
 use std::net::{IpAddr, Ipv4Addr};
 
+use holo_utils::ip::AddressFamily;
 use holo_utils::mpls::Label;
 use tracing::{debug, debug_span, Span};
 
@@ -25,8 +26,8 @@ pub enum Debug<'a> {
     InstanceStatusCheck(&'a str),
     InterfaceCreate(&'a str),
     InterfaceDelete(&'a str),
-    InterfaceStart(&'a str),
-    InterfaceStop(&'a str, InterfaceInactiveReason),
+    InterfaceStart(&'a str, AddressFamily),
+    InterfaceStop(&'a str, AddressFamily, InterfaceInactiveReason),
     TargetedNbrCreate(&'a IpAddr),
     TargetedNbrDelete(&'a IpAddr),
     TargetedNbrStart(&'a IpAddr),
@@ -48,12 +49,23 @@ pub enum Debug<'a> {
     NbrMsgRx(&'a Ipv4Addr, &'a Message),
     NbrMsgTx(&'a Ipv4Addr, &'a Message),
     NbrInitBackoffTimeout(&'a Ipv4Addr),
+    NbrTransPrefTimeout(&'a Ipv4Addr),
+    NbrGrHelperStart(&'a Ipv4Addr, u32),
+    NbrGrHelperExit(&'a Ipv4Addr),
+    NbrGrTimeout(&'a Ipv4Addr),
+    GrFwdHoldtimeStart(u16),
+    GrFwdHoldtimeExpiry,
+    TargetedNbrProtTimeout(&'a IpAddr),
     FecCreate(&'a Fec),
     FecDelete(&'a Fec),
     FecLabelUpdate(&'a Fec, &'a Option<Label>),
     NexthopCreate(&'a Nexthop),
     NexthopDelete(&'a Nexthop),
     NexthopLabelUpdate(&'a Nexthop, &'a Option<Label>),
+    PwCreate(&'a str),
+    PwDelete(&'a str),
+    PwUp(&'a str),
+    PwDown(&'a str),
 }
 
 // Reason why an LDP instance is inactive.
@@ -64,7 +76,7 @@ pub enum InstanceInactiveReason {
 }
 
 // Reason why LDP is inactive on an interface.
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum InterfaceInactiveReason {
     InstanceDown,
     AdminDown,
@@ -93,24 +105,29 @@ impl<'a> Debug<'a> {
                 // Parent span(s): ldp-instance
                 debug!(%status, "{}", self);
             }
-            Debug::InterfaceCreate(name)
-            | Debug::InterfaceDelete(name)
-            | Debug::InterfaceStart(name) => {
+            Debug::InterfaceCreate(name) | Debug::InterfaceDelete(name) => {
                 // Parent span(s): ldp-instance
                 debug_span!("interface", %name).in_scope(|| {
                     debug!("{}", self);
                 });
             }
-            Debug::InterfaceStop(name, reason) => {
+            Debug::InterfaceStart(name, af) => {
+                // Parent span(s): ldp-instance
+                debug_span!("interface", %name).in_scope(|| {
+                    debug!(%af, "{}", self);
+                });
+            }
+            Debug::InterfaceStop(name, af, reason) => {
                 // Parent span(s): ldp-instance
                 debug_span!("interface", %name).in_scope(|| {
-                    debug!(%reason, "{}", self);
+                    debug!(%af, %reason, "{}", self);
                 });
             }
             Debug::TargetedNbrCreate(addr)
             | Debug::TargetedNbrDelete(addr)
             | Debug::TargetedNbrStart(addr)
-            | Debug::TargetedNbrStop(addr) => {
+            | Debug::TargetedNbrStop(addr)
+            | Debug::TargetedNbrProtTimeout(addr) => {
                 // Parent span(s): ldp-instance
                 debug_span!("targeted-nbr", address = %addr).in_scope(|| {
                     debug!("{}", self);
@@ -176,12 +193,29 @@ impl<'a> Debug<'a> {
                     })
                 });
             }
-            Debug::NbrInitBackoffTimeout(lsr_id) => {
+            Debug::NbrInitBackoffTimeout(lsr_id)
+            | Debug::NbrTransPrefTimeout(lsr_id)
+            | Debug::NbrGrHelperExit(lsr_id)
+            | Debug::NbrGrTimeout(lsr_id) => {
                 // Parent span(s): ldp-instance
                 debug_span!("neighbor", %lsr_id).in_scope(|| {
                     debug!("{}", self);
                 });
             }
+            Debug::NbrGrHelperStart(lsr_id, reconnect_time) => {
+                // Parent span(s): ldp-instance
+                debug_span!("neighbor", %lsr_id).in_scope(|| {
+                    debug!(%reconnect_time, "{}", self);
+                });
+            }
+            Debug::GrFwdHoldtimeStart(holdtime) => {
+                // Parent span(s): ldp-instance
+                debug!(%holdtime, "{}", self);
+            }
+            Debug::GrFwdHoldtimeExpiry => {
+                // Parent span(s): ldp-instance
+                debug!("{}", self);
+            }
             Debug::FecCreate(fec) | Debug::FecDelete(fec) => {
                 // Parent span(s): ldp-instance
                 debug_span!("lib", prefix = %fec.inner.prefix).in_scope(|| {
@@ -228,6 +262,15 @@ impl<'a> Debug<'a> {
                     );
                 });
             }
+            Debug::PwCreate(name)
+            | Debug::PwDelete(name)
+            | Debug::PwUp(name)
+            | Debug::PwDown(name) => {
+                // Parent span(s): ldp-instance
+                debug_span!("pseudowire", %name).in_scope(|| {
+                    debug!("{}", self);
+                });
+            }
         }
     }
 }
@@ -274,6 +317,9 @@ impl<'a> std::fmt::Display for Debug<'a> {
             Debug::TargetedNbrStop(..) => {
                 write!(f, "stopping targeted neighbor")
             }
+            Debug::TargetedNbrProtTimeout(..) => {
+                write!(f, "session protection timer expired")
+            }
             Debug::AdjacencyCreate(..) => {
                 write!(f, "adjacency created")
             }
@@ -307,6 +353,24 @@ impl<'a> std::fmt::Display for Debug<'a> {
             Debug::NbrInitBackoffTimeout(..) => {
                 write!(f, "initialization backoff timer expired")
             }
+            Debug::NbrTransPrefTimeout(..) => {
+                write!(f, "dual-stack transport preference timer expired")
+            }
+            Debug::NbrGrHelperStart(..) => {
+                write!(f, "starting graceful restart helper mode")
+            }
+            Debug::NbrGrHelperExit(..) => {
+                write!(f, "exiting graceful restart helper mode")
+            }
+            Debug::NbrGrTimeout(..) => {
+                write!(f, "graceful restart timer expired")
+            }
+            Debug::GrFwdHoldtimeStart(..) => {
+                write!(f, "starting forwarding state holding timer")
+            }
+            Debug::GrFwdHoldtimeExpiry => {
+                write!(f, "forwarding state holding timer expired")
+            }
             Debug::FecCreate(..) => {
                 write!(f, "FEC created")
             }
@@ -325,6 +389,18 @@ impl<'a> std::fmt::Display for Debug<'a> {
             Debug::NexthopLabelUpdate(..) => {
                 write!(f, "nexthop label updated")
             }
+            Debug::PwCreate(..) => {
+                write!(f, "pseudowire created")
+            }
+            Debug::PwDelete(..) => {
+                write!(f, "pseudowire deleted")
+            }
+            Debug::PwUp(..) => {
+                write!(f, "pseudowire up")
+            }
+            Debug::PwDown(..) => {
+                write!(f, "pseudowire down")
+            }
         }
     }
 }
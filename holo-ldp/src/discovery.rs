@@ -4,12 +4,13 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
 use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use holo_utils::ip::{AddressFamily, IpAddrKind};
 use holo_utils::socket::UdpSocket;
 use holo_utils::task::{IntervalTask, TimeoutTask};
 use holo_utils::Sender;
@@ -24,8 +25,8 @@ use crate::instance::{InstanceState, InstanceUpView};
 use crate::northbound::configuration::TargetedNbrCfg;
 use crate::northbound::notification;
 use crate::packet::messages::hello::{
-    HelloFlags, HelloMsg, TlvCommonHelloParams, TlvConfigSeqNo,
-    TlvIpv4TransAddr,
+    HelloFlags, HelloMsg, TlvCommonHelloParams, TlvConfigSeqNo, TlvDualStack,
+    TlvIpv4TransAddr, TlvIpv6TransAddr,
 };
 use crate::packet::messages::notification::StatusCode;
 use crate::packet::Pdu;
@@ -44,6 +45,9 @@ pub struct Adjacency {
     pub trans_addr: IpAddr,
     // Adjacency LSR-ID.
     pub lsr_id: Ipv4Addr,
+    // Transport connection preference advertised by the adjacent LSR (present
+    // only if it operates in dual-stack mode).
+    pub trans_pref: Option<AddressFamily>,
     // The holdtime value learned from the adjacent LSR (in seconds).
     pub holdtime_adjacent: u16,
     // The holdtime negotiated between this LSR and the adjacent LSR (in
@@ -75,6 +79,12 @@ pub struct TargetedNbr {
     pub configured: bool,
     // Indicates whether this is a dynamic targeted neighbor.
     pub dynamic: bool,
+    // Indicates whether this targeted neighbor protects a link session.
+    pub session_protection: bool,
+    // Indicates whether this targeted neighbor is used by pseudowires.
+    pub pseudowire: bool,
+    // Session protection timeout task.
+    pub protection_timeout: Option<TimeoutTask>,
     // Hello Tx interval task.
     pub hello_interval_task: Option<IntervalTask>,
 }
@@ -99,6 +109,7 @@ impl Adjacency {
             source,
             trans_addr,
             lsr_id,
+            trans_pref: None,
             holdtime_adjacent,
             holdtime_negotiated,
             hello_rcvd: 1,
@@ -145,7 +156,7 @@ impl Adjacency {
         match &self.source.ifname {
             Some(ifname) => {
                 let (_, iface) = interfaces.get_by_name(ifname).unwrap();
-                iface.next_hello().unwrap()
+                iface.next_hello(self.source.addr.address_family()).unwrap()
             }
             None => {
                 let (_, tnbr) =
@@ -189,6 +200,9 @@ impl TargetedNbr {
             config: TargetedNbrCfg::default(),
             configured: false,
             dynamic: false,
+            session_protection: false,
+            pseudowire: false,
+            protection_timeout: None,
             hello_interval_task: None,
         }
     }
@@ -217,7 +231,7 @@ impl TargetedNbr {
                 addr: self.addr,
             };
             if let Some((adj_idx, _)) =
-                instance.state.ipv4.adjacencies.get_by_source(&source)
+                instance.state.adjacencies.get_by_source(&source)
             {
                 adjacency_delete(instance, adj_idx, StatusCode::Shutdown);
             }
@@ -231,7 +245,7 @@ impl TargetedNbr {
     ) {
         let tnbr = &mut tneighbors[tnbr_idx];
 
-        let is_ready = tnbr.is_ready();
+        let is_ready = tnbr.is_ready(instance.state);
         let remove = tnbr.remove_check();
 
         if !tnbr.is_active() && is_ready {
@@ -254,12 +268,19 @@ impl TargetedNbr {
         self.hello_interval_task.is_some()
     }
 
-    fn is_ready(&self) -> bool {
-        self.dynamic || (self.configured && self.config.enabled)
+    fn is_ready(&self, instance_state: &InstanceState) -> bool {
+        instance_state.af(self.addr.address_family()).is_some()
+            && (self.dynamic
+                || self.session_protection
+                || self.pseudowire
+                || (self.configured && self.config.enabled))
     }
 
     pub(crate) fn remove_check(&self) -> bool {
-        !self.dynamic && !self.configured
+        !self.dynamic
+            && !self.configured
+            && !self.session_protection
+            && !self.pseudowire
     }
 
     pub(crate) fn generate_hello(
@@ -268,9 +289,12 @@ impl TargetedNbr {
     ) -> HelloMsg {
         // NOTE: do not attempt GTSM negotiation in multi-hop peering sessions.
         let mut flags = HelloFlags::TARGETED;
-        if self.config.enabled {
+        if self.config.enabled || self.session_protection || self.pseudowire {
             flags |= HelloFlags::REQ_TARGETED;
         }
+        let trans_addr = instance_state
+            .trans_addr(self.addr.address_family())
+            .unwrap();
 
         HelloMsg {
             // The message ID will be overwritten later.
@@ -279,10 +303,12 @@ impl TargetedNbr {
                 holdtime: self.config.hello_holdtime,
                 flags,
             },
-            ipv4_addr: Some(TlvIpv4TransAddr(instance_state.ipv4.trans_addr)),
-            ipv6_addr: None,
+            ipv4_addr: Ipv4Addr::get(trans_addr).map(TlvIpv4TransAddr),
+            ipv6_addr: Ipv6Addr::get(trans_addr).map(TlvIpv6TransAddr),
             cfg_seqno: Some(TlvConfigSeqNo(instance_state.cfg_seqno)),
-            dual_stack: None,
+            dual_stack: instance_state
+                .dual_stack_pref()
+                .map(|af| TlvDualStack(af.into())),
         }
     }
 
@@ -340,7 +366,7 @@ pub(crate) fn adjacency_delete(
     adj_idx: AdjacencyIndex,
     status_code: StatusCode,
 ) {
-    let adjacencies = &mut instance.state.ipv4.adjacencies;
+    let adjacencies = &mut instance.state.adjacencies;
     let adj = &adjacencies[adj_idx];
     let lsr_id = adj.lsr_id;
     let ifname = adj.source.ifname.clone();
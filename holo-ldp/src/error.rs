@@ -6,6 +6,9 @@
 
 use std::net::{IpAddr, Ipv4Addr};
 
+use holo_utils::mpls::Label;
+use holo_utils::DatabaseError;
+use ipnetwork::IpNetwork;
 use serde::{Deserialize, Serialize};
 use tracing::{error, warn, warn_span};
 
@@ -29,13 +32,19 @@ pub enum Error {
     UdpPduDecodeError(DecodeError),
     TcpConnClosed(Ipv4Addr),
     TcpInvalidConnRequest(Ipv4Addr),
+    TcpNonPreferredConnRequest(Ipv4Addr),
     TcpAdditionalTransportConn(Ipv4Addr),
+    TcpAuthUnavailable(Ipv4Addr),
+    HelloTransPrefMismatch(Ipv4Addr),
     NbrPduDecodeError(Ipv4Addr, DecodeError),
     NbrRcvdError(Ipv4Addr, StatusCode),
     NbrSentError(Ipv4Addr, StatusCode),
     NbrFsmUnexpectedEvent(Ipv4Addr, neighbor::fsm::State, neighbor::fsm::Event),
     InstanceStartError(Box<Error>),
     InterfaceStartError(String, Box<Error>),
+    #[serde(skip)]
+    GrNvmUpdate(DatabaseError),
+    GrLabelUnavailable(IpNetwork, Label),
 }
 
 // LDP I/O errors.
@@ -80,7 +89,10 @@ impl Error {
             }
             Error::TcpConnClosed(lsr_id)
             | Error::TcpInvalidConnRequest(lsr_id)
-            | Error::TcpAdditionalTransportConn(lsr_id) => {
+            | Error::TcpNonPreferredConnRequest(lsr_id)
+            | Error::TcpAdditionalTransportConn(lsr_id)
+            | Error::TcpAuthUnavailable(lsr_id)
+            | Error::HelloTransPrefMismatch(lsr_id) => {
                 warn_span!("neighbor", %lsr_id).in_scope(|| {
                     warn!("{}", self);
                 });
@@ -109,6 +121,12 @@ impl Error {
             Error::InterfaceStartError(name, error) => {
                 error!(%name, error = %with_source(error), "{}", self);
             }
+            Error::GrNvmUpdate(error) => {
+                error!(%error, "{}", self);
+            }
+            Error::GrLabelUnavailable(prefix, label) => {
+                warn!(%prefix, %label, "{}", self);
+            }
         }
     }
 }
@@ -138,9 +156,21 @@ impl std::fmt::Display for Error {
             Error::TcpInvalidConnRequest(..) => {
                 write!(f, "invalid connection request (passive neighbor)")
             }
+            Error::TcpNonPreferredConnRequest(..) => {
+                write!(
+                    f,
+                    "connection request using the non-preferred address family"
+                )
+            }
             Error::TcpAdditionalTransportConn(..) => {
                 write!(f, "rejecting additional transport connection")
             }
+            Error::TcpAuthUnavailable(..) => {
+                write!(f, "TCP-AO keychain unavailable or without active keys")
+            }
+            Error::HelloTransPrefMismatch(..) => {
+                write!(f, "dual-stack transport preference mismatch")
+            }
             Error::NbrPduDecodeError(..) => {
                 write!(f, "failed to decode PDU")
             }
@@ -159,6 +189,12 @@ impl std::fmt::Display for Error {
             Error::InterfaceStartError(..) => {
                 write!(f, "failed to start interface")
             }
+            Error::GrNvmUpdate(..) => {
+                write!(f, "failed to record local label bindings in non-volatile storage")
+            }
+            Error::GrLabelUnavailable(..) => {
+                write!(f, "failed to reclaim preserved local label")
+            }
         }
     }
 }
@@ -6,15 +6,16 @@
 
 use std::collections::{btree_map, VecDeque};
 use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
 
 use chrono::Utc;
-use holo_utils::ip::IpNetworkKind;
+use holo_utils::ip::{AddressFamily, IpAddrKind, IpNetworkKind};
 use holo_utils::mpls::Label;
 use holo_utils::socket::{SocketExt, TcpConnInfo, TcpStream, TTL_MAX};
 use tracing::{debug_span, Span};
 
 use crate::collections::{
-    AdjacencyId, Interfaces, NeighborId, NeighborIndex, TargetedNbrs,
+    AdjacencyId, Interfaces, NeighborId, NeighborIndex, Neighbors, TargetedNbrs,
 };
 use crate::debug::Debug;
 use crate::discovery::{self, Adjacency, AdjacencySource, TargetedNbr};
@@ -22,6 +23,7 @@ use crate::error::{Error, IoError};
 use crate::fec::{Fec, LabelMapping, LabelRequest};
 use crate::instance::InstanceUpView;
 use crate::neighbor::{fsm, LabelAdvMode, Neighbor, NeighborFlags};
+use crate::network::tcp;
 use crate::northbound::notification;
 use crate::packet::error::DecodeError;
 use crate::packet::messages::address::TlvAddressList;
@@ -36,7 +38,7 @@ use crate::packet::messages::{
     NotifMsg,
 };
 use crate::packet::{AddressMessageType, LabelMessageType, Message, Pdu};
-use crate::{network, southbound};
+use crate::{gr, policy, pw, southbound, tasks};
 
 // ===== UDP packet receipt =====
 
@@ -45,11 +47,14 @@ pub(crate) fn process_udp_pdu(
     interfaces: &mut Interfaces,
     tneighbors: &mut TargetedNbrs,
     src_addr: IpAddr,
+    ifindex: Option<u32>,
     pdu: Result<Pdu, DecodeError>,
     multicast: bool,
 ) {
     match multicast {
-        true => process_udp_pdu_multicast(instance, interfaces, src_addr, pdu),
+        true => process_udp_pdu_multicast(
+            instance, interfaces, tneighbors, src_addr, ifindex, pdu,
+        ),
         false => process_udp_pdu_unicast(instance, tneighbors, src_addr, pdu),
     }
 }
@@ -57,14 +62,26 @@ pub(crate) fn process_udp_pdu(
 fn process_udp_pdu_multicast(
     instance: &mut InstanceUpView<'_>,
     interfaces: &mut Interfaces,
+    tneighbors: &mut TargetedNbrs,
     src_addr: IpAddr,
+    ifindex: Option<u32>,
     pdu: Result<Pdu, DecodeError>,
 ) {
     // Lookup interface.
-    let (_, iface) = match interfaces.get_by_addr(&src_addr) {
-        Some(value) => value,
-        None => return,
+    //
+    // IPv6 link-local source addresses are ambiguous, so the receiving
+    // interface is used instead whenever it's known.
+    let iface = match ifindex {
+        Some(ifindex) => interfaces.get_by_ifindex(ifindex),
+        None => interfaces.get_by_addr(&src_addr),
     };
+    let Some((_, iface)) = iface else {
+        return;
+    };
+    let af = src_addr.address_family();
+    if !iface.is_af_active(af) {
+        return;
+    }
 
     let source = AdjacencySource::new(Some(iface.name.clone()), src_addr);
 
@@ -83,7 +100,9 @@ fn process_udp_pdu_multicast(
             return;
         }
 
-        let local_addr = iface.system.local_ipv4_addr();
+        let Some(local_addr) = iface.system.local_addr(af) else {
+            return;
+        };
         let holdtime_adjacent = hello.params.holdtime;
         let holdtime_negotiated =
             iface.calculate_adj_holdtime(holdtime_adjacent);
@@ -91,14 +110,53 @@ fn process_udp_pdu_multicast(
         process_hello(
             instance,
             local_addr,
-            source,
+            source.clone(),
             pdu.lsr_id,
             hello,
             holdtime_adjacent,
             holdtime_negotiated,
             span,
         );
+
+        // Protect the session using a targeted adjacency.
+        if instance.config.session_protection.enabled {
+            if let Some((_, adj)) =
+                instance.state.adjacencies.get_by_source(&source)
+            {
+                let trans_addr = adj.trans_addr;
+                session_protection_refresh(instance, tneighbors, trans_addr);
+            }
+        }
+    }
+}
+
+fn session_protection_refresh(
+    instance: &mut InstanceUpView<'_>,
+    tneighbors: &mut TargetedNbrs,
+    addr: IpAddr,
+) {
+    // Find or create targeted neighbor.
+    let (tnbr_idx, tnbr) = tneighbors.insert(addr);
+    tnbr.session_protection = true;
+
+    // Start or reset the protection timer.
+    match instance.config.session_protection.duration {
+        Some(duration) => {
+            if let Some(task) = tnbr.protection_timeout.as_mut() {
+                task.reset(Some(Duration::from_secs(duration.into())));
+            } else {
+                let task = tasks::tnbr_prot_timeout(
+                    tnbr,
+                    duration,
+                    &instance.tx.protocol_input.tnbr_prot_timeout,
+                );
+                tnbr.protection_timeout = Some(task);
+            }
+        }
+        None => tnbr.protection_timeout = None,
     }
+
+    TargetedNbr::update(instance, tneighbors, tnbr_idx);
 }
 
 fn process_udp_pdu_unicast(
@@ -124,6 +182,13 @@ fn process_udp_pdu_unicast(
             return;
         }
 
+        // Ignore the hello if the address family isn't enabled.
+        let Some(local_addr) =
+            instance.state.trans_addr(src_addr.address_family())
+        else {
+            return;
+        };
+
         // Find targeted neighbor (or create a dynamic one if possible).
         let (tnbr_idx, tnbr) = match tneighbors.get_mut_by_addr(&src_addr) {
             Some(value) => value,
@@ -150,7 +215,6 @@ fn process_udp_pdu_unicast(
         }
 
         // Process hello message.
-        let local_addr = IpAddr::V4(instance.state.ipv4.trans_addr);
         let holdtime_adjacent = hello.params.holdtime;
         let holdtime_negotiated =
             tnbr.calculate_adj_holdtime(holdtime_adjacent);
@@ -178,7 +242,7 @@ fn process_udp_pdu_error(
 
     // Update hello dropped counter.
     if let Some((_, adj)) =
-        instance.state.ipv4.adjacencies.get_mut_by_source(&source)
+        instance.state.adjacencies.get_mut_by_source(&source)
     {
         adj.hello_dropped += 1;
         adj.discontinuity_time = Utc::now();
@@ -198,14 +262,28 @@ fn process_hello(
     Debug::AdjacencyHelloRx(&span, &source, &lsr_id, &hello).log();
 
     // Use implicit transport address if necessary.
-    let trans_addr = hello
-        .ipv4_addr
-        .map(|tlv| tlv.0.into())
-        .unwrap_or_else(|| source.addr);
+    let af = source.addr.address_family();
+    let trans_addr = match af {
+        AddressFamily::Ipv4 => hello.ipv4_addr.map(|tlv| tlv.0.into()),
+        AddressFamily::Ipv6 => hello.ipv6_addr.map(|tlv| tlv.0.into()),
+    }
+    .unwrap_or(source.addr);
+
+    // When both LSRs operate in dual-stack mode, their transport connection
+    // preferences must match (RFC 7552 - Section 6.1).
+    let trans_pref = hello.dual_stack.map(|tlv| AddressFamily::from(tlv.0));
+    if let (Some(local_pref), Some(remote_pref)) =
+        (instance.state.dual_stack_pref(), trans_pref)
+    {
+        if local_pref != remote_pref {
+            Error::HelloTransPrefMismatch(lsr_id).log();
+            return;
+        }
+    }
 
     // Create new adjacency or update existing one.
     if let Some((_, adj)) =
-        instance.state.ipv4.adjacencies.get_mut_by_source(&source)
+        instance.state.adjacencies.get_mut_by_source(&source)
     {
         let mut shutdown_nbr = false;
 
@@ -215,12 +293,14 @@ fn process_hello(
         }
         // Shutdown associated neighbor if the advertised transport address has
         // changed.
-        if adj.trans_addr != trans_addr {
+        let old_trans_addr = adj.trans_addr;
+        if old_trans_addr != trans_addr {
             shutdown_nbr = true;
         }
 
         adj.local_addr = local_addr;
         adj.trans_addr = trans_addr;
+        adj.trans_pref = trans_pref;
         adj.holdtime_adjacent = holdtime_adjacent;
         adj.holdtime_negotiated = holdtime_negotiated;
         adj.hello_rcvd += 1;
@@ -231,7 +311,7 @@ fn process_hello(
             if let Some((nbr_idx, nbr)) =
                 instance.state.neighbors.get_mut_by_lsr_id(&lsr_id)
             {
-                if nbr.is_operational() {
+                if nbr.is_operational() && nbr.trans_addr == old_trans_addr {
                     // Send Shutdown notification.
                     nbr.send_shutdown(&instance.state.msg_id, None);
                     Neighbor::fsm(instance, nbr_idx, fsm::Event::ErrorSent);
@@ -239,7 +319,7 @@ fn process_hello(
             }
         }
     } else {
-        let id = instance.state.ipv4.adjacencies.next_id();
+        let id = instance.state.adjacencies.next_id();
         let mut adj = Adjacency::new(
             id,
             source,
@@ -249,6 +329,7 @@ fn process_hello(
             holdtime_adjacent,
             holdtime_negotiated,
         );
+        adj.trans_pref = trans_pref;
         adj.reset(holdtime_negotiated, &instance.tx.protocol_input.adj_timeout);
 
         notification::mpls_ldp_hello_adjacency_event(
@@ -259,32 +340,34 @@ fn process_hello(
             true,
         );
 
-        instance.state.ipv4.adjacencies.insert(adj);
+        instance.state.adjacencies.insert(adj);
     }
 
     // Find associated neighbor or create a new one.
-    let (_, nbr) = match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
-        Some(nbr) => nbr,
-        None => {
-            let id = instance.state.neighbors.next_id();
-            let kalive_interval = instance.config.session_ka_interval;
-            let nbr = Neighbor::new(id, lsr_id, trans_addr, kalive_interval);
-
-            // The neighbor password (if any) must be set in the TCP listening
-            // socket otherwise incoming SYN requests will be rejected.
-            if let Some(password) =
-                instance.config.get_neighbor_password(nbr.lsr_id)
-            {
-                network::tcp::listen_socket_md5sig_update(
-                    &instance.state.ipv4.session_socket,
-                    &nbr.trans_addr,
-                    Some(password),
-                );
-            }
+    let (nbr_idx, nbr) =
+        match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
+            Some(nbr) => nbr,
+            None => {
+                let id = instance.state.neighbors.next_id();
+                let kalive_interval = instance.config.session_ka_interval;
+                let nbr =
+                    Neighbor::new(id, lsr_id, trans_addr, kalive_interval);
+
+                // The neighbor authentication (if any) must be set in the TCP
+                // listening socket otherwise incoming SYN requests will be
+                // rejected.
+                if let Ok(Some(auth)) = instance
+                    .config
+                    .get_neighbor_auth(nbr.lsr_id, &instance.shared.keychains)
+                {
+                    instance
+                        .state
+                        .listen_socket_auth_update(&nbr.trans_addr, Some(auth));
+                }
 
-            instance.state.neighbors.insert(nbr)
-        }
-    };
+                instance.state.neighbors.insert(nbr)
+            }
+        };
 
     // Dynamic GTSM negotiation.
     if !hello.params.flags.contains(HelloFlags::TARGETED)
@@ -303,19 +386,141 @@ fn process_hello(
         nbr.cfg_seqno = cfg_seqno.0;
     }
 
-    // Start TCP connection when playing the active role of session
-    // establishment.
+    // Select the neighbor's transport address and start the TCP connection
+    // if possible.
+    if nbr_trans_addr_select(instance, nbr_idx) {
+        nbr_connect(instance, nbr_idx);
+    }
+}
+
+// Selects the transport address used to establish the LDP session with the
+// given neighbor, taking into account the dual-stack transport connection
+// preference (RFC 7552).
+//
+// Returns `false` if the session establishment should be deferred while
+// waiting for a Hello adjacency of the preferred address family.
+fn nbr_trans_addr_select(
+    instance: &mut InstanceUpView<'_>,
+    nbr_idx: NeighborIndex,
+) -> bool {
+    let nbr = &instance.state.neighbors[nbr_idx];
+
+    // The transport address can't change while a session is in progress.
+    if nbr.state != fsm::State::NonExistent {
+        return true;
+    }
+
+    let Some(adjacencies) =
+        instance.state.adjacencies.iter_by_lsr_id(&nbr.lsr_id)
+    else {
+        return false;
+    };
+    let adjacencies = adjacencies.collect::<Vec<_>>();
+
+    // When both LSRs operate in dual-stack mode, prefer a transport address
+    // of the agreed address family.
+    let dual_stack_pref = instance
+        .state
+        .dual_stack_pref()
+        .filter(|_| adjacencies.iter().any(|adj| adj.trans_pref.is_some()));
+    let pref_trans_addr = dual_stack_pref.and_then(|af| {
+        adjacencies
+            .iter()
+            .map(|adj| adj.trans_addr)
+            .find(|addr| addr.address_family() == af)
+    });
+    let trans_addr = match pref_trans_addr {
+        Some(trans_addr) => trans_addr,
+        None => {
+            // Wait for a Hello adjacency of the preferred address family
+            // before falling back to the other one.
+            let max_wait = instance.config.dual_stack_max_wait;
+            let nbr = &mut instance.state.neighbors[nbr_idx];
+            if dual_stack_pref.is_some()
+                && max_wait != 0
+                && !nbr.flags.contains(NeighborFlags::TRANS_PREF_TIMEOUT)
+            {
+                if nbr.tasks.trans_pref_timeout.is_none() {
+                    let task = tasks::nbr_trans_pref_timeout(
+                        nbr,
+                        max_wait,
+                        &instance.tx.protocol_input.nbr_trans_pref_timeout,
+                    );
+                    nbr.tasks.trans_pref_timeout = Some(task);
+                }
+                return false;
+            }
+
+            // Keep using the current transport address if possible.
+            let nbr = &instance.state.neighbors[nbr_idx];
+            match adjacencies
+                .iter()
+                .map(|adj| adj.trans_addr)
+                .find(|addr| *addr == nbr.trans_addr)
+            {
+                Some(trans_addr) => trans_addr,
+                None => match adjacencies.first() {
+                    Some(adj) => adj.trans_addr,
+                    None => return false,
+                },
+            }
+        }
+    };
+
+    // Update the neighbor's transport address if necessary.
+    let nbr = &mut instance.state.neighbors[nbr_idx];
+    nbr.tasks.trans_pref_timeout = None;
+    if nbr.trans_addr != trans_addr {
+        let old_trans_addr = nbr.trans_addr;
+        let auth = instance
+            .config
+            .get_neighbor_auth(nbr.lsr_id, &instance.shared.keychains)
+            .ok()
+            .flatten();
+        instance
+            .state
+            .neighbors
+            .update_trans_addr(nbr_idx, trans_addr);
+        if let Some(auth) = auth {
+            instance
+                .state
+                .listen_socket_auth_update(&old_trans_addr, None);
+            instance
+                .state
+                .listen_socket_auth_update(&trans_addr, Some(auth));
+        }
+    }
+
+    true
+}
+
+// Starts the TCP connection with the given neighbor when playing the active
+// role of session establishment.
+fn nbr_connect(instance: &mut InstanceUpView<'_>, nbr_idx: NeighborIndex) {
+    let nbr = &instance.state.neighbors[nbr_idx];
+    let Some(local_addr) =
+        instance.state.trans_addr(nbr.trans_addr.address_family())
+    else {
+        return;
+    };
+
+    let nbr = &mut instance.state.neighbors[nbr_idx];
     if nbr.state == fsm::State::NonExistent
-        && nbr.is_session_active_role(instance.state.ipv4.trans_addr)
+        && nbr.is_session_active_role(local_addr)
         && nbr.tasks.connect.is_none()
         && nbr.tasks.backoff_timeout.is_none()
     {
-        let password = instance.config.get_neighbor_password(nbr.lsr_id);
-        nbr.connect(
-            instance.state.ipv4.trans_addr,
-            password,
-            &instance.tx.protocol_input.tcp_connect,
-        );
+        let auth = match instance
+            .config
+            .get_neighbor_auth(nbr.lsr_id, &instance.shared.keychains)
+        {
+            Ok(auth) => auth,
+            Err(error) => {
+                error.log();
+                return;
+            }
+        };
+        nbr.connect(local_addr, auth, &instance.tx.protocol_input.tcp_connect);
     }
 }
 
@@ -327,7 +532,7 @@ pub(crate) fn process_adj_timeout(
     adj_id: AdjacencyId,
 ) -> Result<(), Error> {
     // Lookup adjacency.
-    let (adj_idx, adj) = instance.state.ipv4.adjacencies.get_by_id(adj_id)?;
+    let (adj_idx, adj) = instance.state.adjacencies.get_by_id(adj_id)?;
 
     Debug::AdjacencyTimeout(&adj.source, &adj.lsr_id).log();
 
@@ -354,8 +559,14 @@ pub(crate) fn process_tcp_accept(
     stream: TcpStream,
     conn_info: TcpConnInfo,
 ) {
-    // Lookup neighbor.
+    // Lookup local transport address.
     let source = conn_info.remote_addr;
+    let Some(local_addr) = instance.state.trans_addr(source.address_family())
+    else {
+        return;
+    };
+
+    // Lookup neighbor.
     let (nbr_idx, nbr) =
         match instance.state.neighbors.get_mut_by_trans_addr(&source) {
             Some(value) => value,
@@ -366,7 +577,7 @@ pub(crate) fn process_tcp_accept(
         };
 
     // Sanity checks.
-    if nbr.is_session_active_role(instance.state.ipv4.trans_addr) {
+    if nbr.is_session_active_role(local_addr) {
         Error::TcpInvalidConnRequest(nbr.lsr_id).log();
         return;
     }
@@ -375,11 +586,32 @@ pub(crate) fn process_tcp_accept(
         return;
     }
 
+    // Hold off while waiting for a Hello adjacency of the preferred
+    // address family (RFC 7552 - Section 6.1).
+    if nbr.tasks.trans_pref_timeout.is_some() {
+        Error::TcpNonPreferredConnRequest(nbr.lsr_id).log();
+        return;
+    }
+
+    // Reject the connection if the neighbor's TCP authentication can't be
+    // enforced.
+    if let Err(error) = instance
+        .config
+        .get_neighbor_auth(nbr.lsr_id, &instance.shared.keychains)
+    {
+        error.log();
+        return;
+    }
+
     // Enable GTSM in single-hop peering sessions.
     #[cfg(not(feature = "testing"))]
     {
         if nbr.flags.contains(NeighborFlags::GTSM) {
-            if let Err(error) = stream.set_ipv4_minttl(TTL_MAX) {
+            let result = match source {
+                IpAddr::V4(_) => stream.set_ipv4_minttl(TTL_MAX),
+                IpAddr::V6(_) => stream.set_ipv6_min_hopcount(TTL_MAX),
+            };
+            if let Err(error) = result {
                 IoError::TcpSocketError(error).log();
                 return;
             }
@@ -576,6 +808,20 @@ fn process_nbr_msg_notification(
         return Err(Error::NbrRcvdError(nbr.lsr_id, status_code.unwrap()));
     }
 
+    // RFC 5919 - Section 4:
+    // The End-of-LIB notification signals that the neighbor has completed
+    // its initial label advertisement.
+    let status_code = StatusCode::decode(msg.status.status_code);
+    if status_code == Some(StatusCode::EndOfLib) {
+        nbr.flags.insert(NeighborFlags::EOL_RCVD);
+    }
+
+    // RFC 8077 - Section 5.4.3:
+    // PW status changes are signaled using Notification messages.
+    if status_code == Some(StatusCode::PwStatus) {
+        pw::process_nbr_msg_notification(instance, nbr_idx, msg);
+    }
+
     Ok(())
 }
 
@@ -646,6 +892,9 @@ fn process_nbr_msg_init(
         nbr.flags.insert(NeighborFlags::CAP_UNREC_NOTIF);
     }
 
+    // Store the neighbor's graceful restart parameters.
+    nbr.ft_session_rcvd = msg.ft_session;
+
     Neighbor::fsm(instance, nbr_idx, fsm::Event::InitRcvd);
 
     Ok(())
@@ -753,6 +1002,9 @@ fn process_nbr_msg_address(
         }
     }
 
+    // Label changes might affect the LSPs used to reach the remote PEs.
+    pw::update_all(instance);
+
     Ok(())
 }
 
@@ -771,6 +1023,12 @@ fn process_nbr_msg_label(
 
     // Process each FEC element separately.
     for fec_elem in &msg.fec.0 {
+        // Pseudowire FEC elements are processed separately.
+        if let FecElem::Pwid(_) | FecElem::GenPwid(_) = fec_elem {
+            pw::process_nbr_msg_label(instance, nbr_idx, &msg, *fec_elem);
+            continue;
+        }
+
         match msg.msg_type {
             LabelMessageType::LabelMapping => {
                 let label = msg.get_label().unwrap();
@@ -801,6 +1059,9 @@ fn process_nbr_msg_label(
         }
     }
 
+    // Label changes might affect the LSPs used to reach the remote PEs.
+    pw::update_all(instance);
+
     Ok(())
 }
 
@@ -865,6 +1126,18 @@ pub(crate) fn process_nbr_msg_label_mapping(
         }
     }
 
+    // Retain mappings rejected by the label acceptance policies, but don't use
+    // them for forwarding.
+    if !policy::label_accept_check(
+        instance.config,
+        &instance.shared.policy_match_sets,
+        nbr.lsr_id,
+        &prefix,
+    ) {
+        nbr.rcvd_mappings.insert(prefix, LabelMapping { label });
+        return;
+    }
+
     // LMp.11 - 12: consider multiple nexthops in order to support multipath.
     for nexthop in fec.nexthops.values_mut() {
         // LMp.15: install FEC in the FIB.
@@ -874,9 +1147,22 @@ pub(crate) fn process_nbr_msg_label_mapping(
 
         // Ignore duplicate mapping.
         if nexthop.get_label() == Some(label) {
+            // Refresh label retained during graceful restart.
+            if nexthop.is_stale() {
+                nexthop.set_label(Some(label));
+            }
             continue;
         }
 
+        // Uninstall label retained during graceful restart.
+        if nexthop.is_stale() {
+            southbound::tx::label_uninstall(
+                &instance.tx.ibus,
+                &fec.inner,
+                nexthop,
+            );
+        }
+
         nexthop.set_label(Some(label));
         if fec.inner.local_label.is_some() {
             southbound::tx::label_install(
@@ -916,16 +1202,28 @@ fn process_nbr_msg_label_request(
             process_nbr_msg_label_request_wcard(instance, nbr_idx, msg, wcard);
             return;
         }
-        FecElem::Wildcard(FecElemWildcard::All) => unreachable!(),
+        FecElem::Wildcard(FecElemWildcard::All)
+        | FecElem::Pwid(_)
+        | FecElem::GenPwid(_) => unreachable!(),
     };
 
     // LRq.1: skip loop detection (not necessary).
 
     // LRq.2: is there a next hop for fec?
+    //
+    // FECs without a local label or filtered by the label advertisement
+    // policies are treated as unreachable.
     let has_nexthop = match instance.state.fecs.entry(prefix) {
         btree_map::Entry::Occupied(mut o) => {
             let fec = o.get_mut();
             !fec.nexthops.is_empty()
+                && fec.inner.local_label.is_some()
+                && policy::label_advertise_check(
+                    instance.config,
+                    &instance.shared.policy_match_sets,
+                    nbr.lsr_id,
+                    &prefix,
+                )
         }
         btree_map::Entry::Vacant(_) => false,
     };
@@ -997,7 +1295,15 @@ fn process_nbr_msg_label_request_wcard(
         })
     {
         // LRq.2: is there a next hop for fec?
-        if fec.nexthops.is_empty() {
+        if fec.nexthops.is_empty()
+            || fec.inner.local_label.is_none()
+            || !policy::label_advertise_check(
+                instance.config,
+                &instance.shared.policy_match_sets,
+                nbr.lsr_id,
+                &fec.inner.prefix,
+            )
+        {
             continue;
         }
 
@@ -1039,6 +1345,7 @@ fn process_nbr_msg_label_withdraw(
             process_nbr_msg_label_withdraw_wcard(instance, nbr_idx, msg, wcard);
             return;
         }
+        FecElem::Pwid(_) | FecElem::GenPwid(_) => unreachable!(),
     };
 
     let fec = instance
@@ -1147,6 +1454,11 @@ fn process_nbr_msg_label_withdraw_wcard(
             }
         }
     }
+
+    // The Wildcard FEC element also refers to all pseudowire FECs.
+    if wcard == FecElemWildcard::All {
+        pw::process_nbr_withdraw_all(instance, nbr_idx, msg.get_label());
+    }
 }
 
 fn process_nbr_msg_label_release(
@@ -1164,6 +1476,7 @@ fn process_nbr_msg_label_release(
             process_nbr_msg_label_release_wcard(instance, nbr_idx, msg, wcard);
             return;
         }
+        FecElem::Pwid(_) | FecElem::GenPwid(_) => unreachable!(),
     };
 
     // LRl.1: does FEC match a known FEC?
@@ -1309,18 +1622,156 @@ pub(crate) fn process_nbr_backoff_timeout(
     lsr_id: Ipv4Addr,
 ) {
     // Lookup neighbor.
-    let (_, nbr) = match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
-        Some(value) => value,
-        None => return,
-    };
+    let (nbr_idx, nbr) =
+        match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
+            Some(value) => value,
+            None => return,
+        };
 
     Debug::NbrInitBackoffTimeout(&nbr.lsr_id).log();
 
     nbr.tasks.backoff_timeout = None;
-    let password = instance.config.get_neighbor_password(nbr.lsr_id);
-    nbr.connect(
-        instance.state.ipv4.trans_addr,
-        password,
-        &instance.tx.protocol_input.tcp_connect,
-    );
+    nbr_connect(instance, nbr_idx);
+}
+
+// ===== neighbor dual-stack transport preference timeout =====
+
+pub(crate) fn process_nbr_trans_pref_timeout(
+    instance: &mut InstanceUpView<'_>,
+    lsr_id: Ipv4Addr,
+) {
+    // Lookup neighbor.
+    let (nbr_idx, nbr) =
+        match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
+            Some(value) => value,
+            None => return,
+        };
+
+    Debug::NbrTransPrefTimeout(&nbr.lsr_id).log();
+
+    // Stop waiting for a Hello adjacency of the preferred address family.
+    nbr.tasks.trans_pref_timeout = None;
+    nbr.flags.insert(NeighborFlags::TRANS_PREF_TIMEOUT);
+    if nbr_trans_addr_select(instance, nbr_idx) {
+        nbr_connect(instance, nbr_idx);
+    }
+}
+
+// ===== neighbor graceful restart timeout =====
+
+pub(crate) fn process_nbr_gr_timeout(
+    instance: &mut InstanceUpView<'_>,
+    lsr_id: Ipv4Addr,
+) {
+    // Lookup neighbor.
+    let (nbr_idx, nbr) =
+        match instance.state.neighbors.get_mut_by_lsr_id(&lsr_id) {
+            Some(value) => value,
+            None => return,
+        };
+
+    Debug::NbrGrTimeout(&nbr.lsr_id).log();
+
+    // Remove all stale label bindings.
+    let operational = nbr.is_operational();
+    gr::helper_exit(instance, nbr_idx);
+
+    // Delete the neighbor if it didn't come back in time.
+    if !operational {
+        Neighbors::delete_check(instance, &lsr_id, StatusCode::Shutdown);
+    }
+}
+
+// ===== MPLS forwarding state holding timeout =====
+
+pub(crate) fn process_gr_fwd_holdtime(instance: &mut InstanceUpView<'_>) {
+    Debug::GrFwdHoldtimeExpiry.log();
+
+    instance.state.gr_fwd_holdtime = None;
+
+    // Release the preserved labels that weren't reused.
+    let mut label_manager = instance.shared.label_manager.lock().unwrap();
+    let labels = std::mem::take(&mut instance.state.gr_preserved_labels);
+    for label in labels.into_values() {
+        label_manager.label_release(label);
+    }
+}
+
+// ===== targeted neighbor session protection timeout =====
+
+pub(crate) fn process_tnbr_prot_timeout(
+    instance: &mut InstanceUpView<'_>,
+    tneighbors: &mut TargetedNbrs,
+    addr: IpAddr,
+) {
+    // Lookup targeted neighbor.
+    let Some((tnbr_idx, tnbr)) = tneighbors.get_mut_by_addr(&addr) else {
+        return;
+    };
+
+    Debug::TargetedNbrProtTimeout(&tnbr.addr).log();
+
+    // Stop protecting the session.
+    tnbr.session_protection = false;
+    tnbr.protection_timeout = None;
+    TargetedNbr::update(instance, tneighbors, tnbr_idx);
+}
+
+// ===== keychain update =====
+
+pub(crate) fn process_keychain_update(
+    instance: &mut InstanceUpView<'_>,
+    keychain_name: &str,
+) {
+    // Update the TCP-AO keys of all neighbors using this keychain.
+    for nbr_idx in instance.state.neighbors.indexes().collect::<Vec<_>>() {
+        let nbr = &instance.state.neighbors[nbr_idx];
+        let keychain = instance
+            .config
+            .neighbors
+            .get(&nbr.lsr_id)
+            .and_then(|nbr_cfg| nbr_cfg.keychain.as_ref())
+            .or(instance.config.keychain.as_ref());
+        if keychain.map(String::as_str) != Some(keychain_name) {
+            continue;
+        }
+
+        nbr_tcp_auth_update(instance, nbr_idx);
+    }
+}
+
+// Updates the TCP authentication of the given neighbor in the listening socket
+// and in the established connection, if any.
+//
+// The session is shut down when the TCP authentication can't be enforced
+// anymore (e.g. all TCP-AO keys expired).
+pub(crate) fn nbr_tcp_auth_update(
+    instance: &mut InstanceUpView<'_>,
+    nbr_idx: NeighborIndex,
+) {
+    let nbr = &mut instance.state.neighbors[nbr_idx];
+    let trans_addr = nbr.trans_addr;
+    let auth = instance
+        .config
+        .get_neighbor_auth(nbr.lsr_id, &instance.shared.keychains);
+    match &auth {
+        Ok(Some(auth)) => {
+            if let Some(conn_socket) = &nbr.conn_socket {
+                tcp::conn_socket_auth_update(conn_socket, &trans_addr, auth);
+            }
+        }
+        Ok(None) => (),
+        Err(error) => {
+            if nbr.state != fsm::State::NonExistent {
+                // Send Shutdown notification.
+                error.log();
+                nbr.send_shutdown(&instance.state.msg_id, None);
+                Neighbor::fsm(instance, nbr_idx, fsm::Event::ErrorSent);
+            }
+        }
+    }
+
+    instance
+        .state
+        .listen_socket_auth_update(&trans_addr, auth.ok().flatten());
 }
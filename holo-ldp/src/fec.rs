@@ -40,6 +40,8 @@ pub struct Nexthop {
     pub ifindex: Option<u32>,
     // Optional remote label.
     label: Option<Label>,
+    // Indicates whether the remote label is stale (graceful restart).
+    stale: bool,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -79,6 +81,7 @@ impl Fec {
             addr,
             ifindex,
             label: None,
+            stale: false,
         };
 
         Debug::NexthopCreate(&nexthop).log();
@@ -122,6 +125,15 @@ impl Nexthop {
         Debug::NexthopLabelUpdate(self, &label).log();
 
         self.label = label;
+        self.stale = false;
+    }
+
+    pub(crate) fn is_stale(&self) -> bool {
+        self.stale
+    }
+
+    pub(crate) fn mark_stale(&mut self) {
+        self.stale = true;
     }
 }
 
@@ -0,0 +1,193 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeSet;
+use std::net::IpAddr;
+
+use holo_utils::mpls::Label;
+use holo_utils::protocol::Protocol;
+use holo_utils::Sender;
+use ipnetwork::IpNetwork;
+
+use crate::collections::NeighborIndex;
+use crate::debug::Debug;
+use crate::error::Error;
+use crate::instance::{InstanceState, InstanceUpView};
+use crate::neighbor::{Neighbor, NeighborGrHelper};
+use crate::northbound::configuration::InstanceCfg;
+use crate::northbound::notification;
+use crate::packet::messages::initialization::{FtFlags, TlvFtSession};
+use crate::tasks::messages::input::NbrGrTimeoutMsg;
+use crate::{southbound, tasks};
+
+// ===== global functions =====
+
+// Returns the Fault Tolerant Session TLV to be included in Initialization
+// messages, or `None` if graceful restart is disabled.
+pub(crate) fn ft_session_tlv(
+    config: &InstanceCfg,
+    state: &InstanceState,
+) -> Option<TlvFtSession> {
+    if !config.gr.enabled {
+        return None;
+    }
+
+    // While the local label bindings preserved across the last restart are
+    // held, advertise the remaining time of the MPLS Forwarding State Holding
+    // timer as the Recovery Time.
+    let recovery_time = match &state.gr_fwd_holdtime {
+        Some(task) => task.remaining().as_millis() as u32,
+        None => u32::from(config.gr.recovery_time) * 1000,
+    };
+
+    Some(TlvFtSession {
+        flags: FtFlags::LEARN,
+        reconnect_time: u32::from(config.gr.reconnect_time) * 1000,
+        recovery_time,
+    })
+}
+
+// Starts acting as a graceful restart helper for a neighbor whose session was
+// lost unexpectedly.
+pub(crate) fn helper_start(
+    nbr: &mut Neighbor,
+    addr_list: BTreeSet<IpAddr>,
+    reconnect_time: u32,
+    nbr_gr_timeoutp: &Sender<NbrGrTimeoutMsg>,
+) {
+    Debug::NbrGrHelperStart(&nbr.lsr_id, reconnect_time).log();
+
+    // Keep the addresses left over from a previous restart, if any.
+    let mut stale_addrs =
+        nbr.gr.take().map(|gr| gr.stale_addrs).unwrap_or_default();
+    stale_addrs.extend(addr_list);
+
+    let timeout = tasks::nbr_gr_timeout(nbr, reconnect_time, nbr_gr_timeoutp);
+    nbr.gr = Some(NeighborGrHelper {
+        stale_addrs,
+        timeout,
+    });
+}
+
+// Stops acting as a graceful restart helper for the given neighbor, removing
+// all label bindings that are still stale.
+pub(crate) fn helper_exit(
+    instance: &mut InstanceUpView<'_>,
+    nbr_idx: NeighborIndex,
+) {
+    let nbr = &mut instance.state.neighbors[nbr_idx];
+    let Some(gr) = nbr.gr.take() else {
+        return;
+    };
+
+    Debug::NbrGrHelperExit(&nbr.lsr_id).log();
+
+    for (prefix, fec) in instance.state.fecs.iter_mut() {
+        let old_fec_status = fec.is_operational();
+
+        // Uninstall stale labels.
+        for nexthop in fec.nexthops.values_mut().filter(|nexthop| {
+            nexthop.is_stale() && gr.stale_addrs.contains(&nexthop.addr)
+        }) {
+            southbound::tx::label_uninstall(
+                &instance.tx.ibus,
+                &fec.inner,
+                nexthop,
+            );
+            nexthop.set_label(None);
+        }
+        if old_fec_status != fec.is_operational() {
+            notification::mpls_ldp_fec_event(
+                &instance.tx.nb,
+                instance.name,
+                fec,
+            );
+        }
+
+        // Remove downstream label bindings that weren't refreshed.
+        if !nbr.rcvd_mappings.contains_key(prefix) {
+            fec.inner.downstream.remove(&nbr.lsr_id);
+        }
+    }
+}
+
+// Saves the local label bindings in non-volatile storage, so that they can be
+// reclaimed after a restart.
+pub(crate) fn local_labels_save(instance: &InstanceUpView<'_>) {
+    let Some(db) = &instance.shared.db else {
+        return;
+    };
+
+    let labels = instance
+        .state
+        .fecs
+        .iter()
+        .filter_map(|(prefix, fec)| {
+            fec.inner
+                .local_label
+                .filter(|label| !label.is_reserved())
+                .map(|label| (*prefix, label))
+        })
+        .collect::<Vec<_>>();
+
+    let mut db = db.lock().unwrap();
+    if let Err(error) = db.set(&nvm_key(instance.name), &labels) {
+        Error::GrNvmUpdate(error).log();
+    }
+}
+
+// Reclaims the local label bindings preserved across the last restart, and
+// starts the MPLS Forwarding State Holding timer.
+pub(crate) fn local_labels_restore(instance: &mut InstanceUpView<'_>) {
+    let Some(db) = &instance.shared.db else {
+        return;
+    };
+
+    // The preserved bindings are only valid for the first start after a
+    // restart.
+    let key = nvm_key(instance.name);
+    let mut db = db.lock().unwrap();
+    let Some(mut labels) = db.get::<Vec<(IpNetwork, Label)>>(&key) else {
+        return;
+    };
+    if let Err(error) = db.rem(&key) {
+        Error::GrNvmUpdate(error).log();
+    }
+    drop(db);
+
+    if !instance.config.gr.enabled || labels.is_empty() {
+        return;
+    }
+
+    // Dynamic labels are allocated sequentially, hence they need to be
+    // reclaimed in ascending order.
+    labels.sort_by_key(|(_, label)| *label);
+    let mut label_manager = instance.shared.label_manager.lock().unwrap();
+    for (prefix, label) in labels {
+        match label_manager.label_request_specific(label) {
+            Ok(label) => {
+                instance.state.gr_preserved_labels.insert(prefix, label);
+            }
+            Err(_) => {
+                Error::GrLabelUnavailable(prefix, label).log();
+            }
+        }
+    }
+
+    let holdtime = instance.config.gr.forwarding_holdtime;
+    Debug::GrFwdHoldtimeStart(holdtime).log();
+    let task = tasks::gr_fwd_holdtime(
+        holdtime,
+        &instance.tx.protocol_input.gr_fwd_holdtime,
+    );
+    instance.state.gr_fwd_holdtime = Some(task);
+}
+
+// ===== helper functions =====
+
+fn nvm_key(instance_name: &str) -> String {
+    format!("{}-{}-local-labels", Protocol::LDP, instance_name)
+}
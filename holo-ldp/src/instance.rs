@@ -10,14 +10,16 @@ use std::sync::atomic::{self, AtomicU32};
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use derive_new::new;
 use holo_protocol::{
     InstanceChannelsTx, InstanceShared, MessageReceiver, ProtocolInstance,
 };
-use holo_utils::ibus::IbusMsg;
+use holo_utils::ibus::{IbusMsg, LdpSyncState};
+use holo_utils::ip::{AddressFamily, IpAddrExt, IpAddrKind};
+use holo_utils::keychain::Keychains;
+use holo_utils::mpls::Label;
 use holo_utils::protocol::Protocol;
-use holo_utils::socket::{TcpListener, UdpSocket};
-use holo_utils::task::Task;
+use holo_utils::socket::{TcpAuth, TcpListener, UdpSocket};
+use holo_utils::task::{Task, TimeoutTask};
 use holo_utils::{Receiver, Sender};
 use ipnetwork::{IpNetwork, Ipv4Network, Ipv6Network};
 use tokio::sync::mpsc;
@@ -27,14 +29,18 @@ use crate::debug::{Debug, InstanceInactiveReason, InterfaceInactiveReason};
 use crate::discovery::TargetedNbr;
 use crate::error::{Error, IoError};
 use crate::fec::Fec;
+use crate::interface::IgpSyncReg;
+use crate::neighbor;
 use crate::network::{tcp, udp};
-use crate::northbound::configuration::InstanceCfg;
+use crate::northbound::configuration::{InstanceCfg, LabelPolicyCfg};
+use crate::pw::{AcIfaceSys, Pseudowire};
 use crate::tasks::messages::input::{
-    AdjTimeoutMsg, NbrBackoffTimeoutMsg, NbrKaTimeoutMsg, NbrRxPduMsg,
-    TcpAcceptMsg, TcpConnectMsg, UdpRxPduMsg,
+    AdjTimeoutMsg, GrFwdHoldtimeMsg, NbrBackoffTimeoutMsg, NbrGrTimeoutMsg,
+    NbrKaTimeoutMsg, NbrRxPduMsg, NbrTransPrefTimeoutMsg, TcpAcceptMsg,
+    TcpConnectMsg, TnbrProtTimeoutMsg, UdpRxPduMsg,
 };
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
-use crate::{events, southbound, tasks};
+use crate::{events, gr, policy, pw, southbound, tasks};
 
 #[derive(Debug)]
 pub struct Instance {
@@ -50,6 +56,8 @@ pub struct Instance {
     pub interfaces: Interfaces,
     // Instance targeted neighbors (configured or learned).
     pub tneighbors: TargetedNbrs,
+    // Interfaces registered for LDP-IGP synchronization.
+    pub igp_sync: BTreeMap<String, IgpSyncReg>,
     // Instance Tx channels.
     pub tx: InstanceChannelsTx<Instance>,
     // Shared data.
@@ -61,6 +69,7 @@ pub struct InstanceSys {
     pub router_id: Option<Ipv4Addr>,
     pub ipv4_addr_list: BTreeSet<Ipv4Network>,
     pub ipv6_addr_list: BTreeSet<Ipv6Network>,
+    pub ac_ifaces: BTreeMap<String, AcIfaceSys>,
 }
 
 #[derive(Debug)]
@@ -75,12 +84,24 @@ pub struct InstanceState {
     pub neighbors: Neighbors,
     // Known FECs and their associated label mappings.
     pub fecs: BTreeMap<IpNetwork, Fec>,
+    // Discovery adjacencies.
+    pub adjacencies: Adjacencies,
     // IPv4 instance state.
-    pub ipv4: InstanceIpv4State,
+    pub ipv4: Option<InstanceAfState>,
+    // IPv6 instance state.
+    pub ipv6: Option<InstanceAfState>,
+    // Preferred transport connection address family (dual-stack only).
+    pub trans_pref: AddressFamily,
+    // Local label bindings preserved across the last restart.
+    pub gr_preserved_labels: BTreeMap<IpNetwork, Label>,
+    // MPLS Forwarding State Holding timer.
+    pub gr_fwd_holdtime: Option<TimeoutTask>,
+    // Pseudowires.
+    pub pseudowires: BTreeMap<String, Pseudowire>,
 }
 
-#[derive(Debug, new)]
-pub struct InstanceIpv4State {
+#[derive(Debug)]
+pub struct InstanceAfState {
     // UDP discovery socket.
     pub disc_socket: Arc<UdpSocket>,
     // UDP extended discovery socket.
@@ -93,11 +114,8 @@ pub struct InstanceIpv4State {
     _edisc_rx_task: Task<()>,
     // TCP listener task.
     _tcp_listener_task: Task<()>,
-    // IPv4 transport address.
-    pub trans_addr: Ipv4Addr,
-    // Discovery adjacencies,
-    #[new(default)]
-    pub adjacencies: Adjacencies,
+    // Transport address.
+    pub trans_addr: IpAddr,
 }
 
 #[derive(Clone, Debug)]
@@ -116,6 +134,14 @@ pub struct ProtocolInputChannelsTx {
     pub nbr_ka_timeout: Sender<NbrKaTimeoutMsg>,
     // Neighbor backoff timeout event.
     pub nbr_backoff_timeout: Sender<NbrBackoffTimeoutMsg>,
+    // Neighbor dual-stack transport preference timeout event.
+    pub nbr_trans_pref_timeout: Sender<NbrTransPrefTimeoutMsg>,
+    // Neighbor graceful restart timeout event.
+    pub nbr_gr_timeout: Sender<NbrGrTimeoutMsg>,
+    // MPLS forwarding state holding timeout event.
+    pub gr_fwd_holdtime: Sender<GrFwdHoldtimeMsg>,
+    // Targeted neighbor session protection timeout event.
+    pub tnbr_prot_timeout: Sender<TnbrProtTimeoutMsg>,
 }
 
 #[derive(Debug)]
@@ -134,6 +160,14 @@ pub struct ProtocolInputChannelsRx {
     pub nbr_ka_timeout: Receiver<NbrKaTimeoutMsg>,
     // Neighbor backoff timeout event.
     pub nbr_backoff_timeout: Receiver<NbrBackoffTimeoutMsg>,
+    // Neighbor dual-stack transport preference timeout event.
+    pub nbr_trans_pref_timeout: Receiver<NbrTransPrefTimeoutMsg>,
+    // Neighbor graceful restart timeout event.
+    pub nbr_gr_timeout: Receiver<NbrGrTimeoutMsg>,
+    // MPLS forwarding state holding timeout event.
+    pub gr_fwd_holdtime: Receiver<GrFwdHoldtimeMsg>,
+    // Targeted neighbor session protection timeout event.
+    pub tnbr_prot_timeout: Receiver<TnbrProtTimeoutMsg>,
 }
 
 pub struct InstanceUpView<'a> {
@@ -159,6 +193,16 @@ impl Instance {
             Ok(()) if !self.is_active() => {
                 self.try_start(router_id.unwrap()).await;
             }
+            Ok(()) => {
+                // Update the dual-stack transport preference.
+                let state = self.state.as_mut().unwrap();
+                state.trans_pref = self.config.trans_pref();
+
+                // Start or stop address families as necessary.
+                for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+                    self.update_af(af).await;
+                }
+            }
             Err(reason) if self.is_active() => {
                 self.stop(reason);
             }
@@ -167,30 +211,111 @@ impl Instance {
     }
 
     async fn try_start(&mut self, router_id: Ipv4Addr) {
-        let trans_addr = router_id;
-        let proto_input_tx = &self.tx.protocol_input;
+        let mut state = InstanceState::new(router_id, self.config.trans_pref());
 
-        match InstanceState::new(router_id, trans_addr, proto_input_tx).await {
-            Ok(state) => {
-                Debug::InstanceStart.log();
+        // Create the state of the enabled address families.
+        for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+            let Some(trans_addr) = self.config.trans_addr(af, router_id) else {
+                continue;
+            };
+            match InstanceAfState::new(trans_addr, &self.tx.protocol_input)
+                .await
+            {
+                Ok(af_state) => *state.af_mut(af) = Some(af_state),
+                Err(error) => {
+                    Error::InstanceStartError(Box::new(error)).log();
+                    return;
+                }
+            }
+        }
 
-                // Store instance initial state.
-                self.state = Some(state);
+        Debug::InstanceStart.log();
+
+        // Store instance initial state.
+        self.state = Some(state);
+
+        // Reclaim the local label bindings preserved across a restart.
+        let (mut instance, interfaces, tneighbors) = self.as_up().unwrap();
+        gr::local_labels_restore(&mut instance);
+
+        // Try to start interfaces and targeted neighbors.
+        for iface in interfaces.iter_mut() {
+            iface.update(&mut instance);
+        }
+        for tnbr_idx in tneighbors.indexes().collect::<Vec<_>>() {
+            TargetedNbr::update(&mut instance, tneighbors, tnbr_idx);
+        }
+
+        // Create pseudowires.
+        pw::start_all(&mut instance);
+    }
 
-                // Try to start interfaces and targeted neighbors.
-                let (mut instance, interfaces, tneighbors) =
-                    self.as_up().unwrap();
-                for iface in interfaces.iter_mut() {
-                    iface.update(&mut instance);
+    // Starts or stops the given address family of an active instance
+    // according to its configuration.
+    async fn update_af(&mut self, af: AddressFamily) {
+        let state = self.state.as_ref().unwrap();
+        let trans_addr = self.config.trans_addr(af, state.router_id);
+        if state.af(af).map(|af_state| af_state.trans_addr) == trans_addr {
+            return;
+        }
+
+        // Stop the address family using the old transport address.
+        if state.af(af).is_some() {
+            let (mut instance, interfaces, tneighbors) = self.as_up().unwrap();
+            for iface in interfaces.iter_mut() {
+                if iface.is_af_active(af) {
+                    let reason = InterfaceInactiveReason::InstanceDown;
+                    iface.stop_af(&mut instance, af, reason);
                 }
-                for tnbr_idx in tneighbors.indexes().collect::<Vec<_>>() {
-                    TargetedNbr::update(&mut instance, tneighbors, tnbr_idx);
+            }
+            for tnbr in tneighbors
+                .iter_mut()
+                .filter(|tnbr| tnbr.addr.address_family() == af)
+            {
+                if tnbr.is_active() {
+                    tnbr.stop(&mut instance, true);
+                }
+            }
+
+            // Reset sessions established over this address family.
+            for nbr in instance
+                .state
+                .neighbors
+                .iter_mut()
+                .filter(|nbr| nbr.trans_addr.address_family() == af)
+            {
+                if nbr.state != neighbor::fsm::State::NonExistent {
+                    nbr.send_shutdown(&instance.state.msg_id, None);
                 }
+                nbr.tasks.connect = None;
             }
-            Err(error) => {
-                Error::InstanceStartError(Box::new(error)).log();
+
+            *instance.state.af_mut(af) = None;
+        }
+
+        // Start the address family using the new transport address.
+        if let Some(trans_addr) = trans_addr {
+            match InstanceAfState::new(trans_addr, &self.tx.protocol_input)
+                .await
+            {
+                Ok(af_state) => {
+                    let state = self.state.as_mut().unwrap();
+                    *state.af_mut(af) = Some(af_state);
+                }
+                Err(error) => {
+                    Error::InstanceStartError(Box::new(error)).log();
+                }
             }
         }
+
+        // Try to start interfaces and targeted neighbors.
+        let (mut instance, interfaces, tneighbors) = self.as_up().unwrap();
+        for iface in interfaces.iter_mut() {
+            iface.update(&mut instance);
+        }
+        for tnbr_idx in tneighbors.indexes().collect::<Vec<_>>() {
+            TargetedNbr::update(&mut instance, tneighbors, tnbr_idx);
+        }
     }
 
     fn stop(&mut self, reason: InstanceInactiveReason) {
@@ -200,8 +325,11 @@ impl Instance {
 
         Debug::InstanceStop(reason).log();
 
-        // Stop interfaces and targeted neighbors.
+        // Delete pseudowires.
         let (mut instance, interfaces, tneighbors) = self.as_up().unwrap();
+        pw::stop_all(&mut instance);
+
+        // Stop interfaces and targeted neighbors.
         for iface in interfaces.iter_mut() {
             if iface.is_active() {
                 let reason = InterfaceInactiveReason::InstanceDown;
@@ -227,8 +355,8 @@ impl Instance {
         &self,
         router_id: Option<Ipv4Addr>,
     ) -> Result<(), InstanceInactiveReason> {
-        if self.config.ipv4.is_none()
-            || !self.config.ipv4.as_ref().unwrap().enabled
+        if !self.config.af_enabled(AddressFamily::Ipv4)
+            && !self.config.af_enabled(AddressFamily::Ipv6)
         {
             return Err(InstanceInactiveReason::AdminDown);
         }
@@ -244,6 +372,43 @@ impl Instance {
         self.config.router_id.or(self.system.router_id)
     }
 
+    // Registers an IGP instance for LDP-IGP synchronization on the given
+    // interface, sending it the current synchronization state.
+    fn igp_sync_register(&mut self, ifname: String) {
+        let state =
+            igp_sync_state(self.state.as_ref(), &self.interfaces, &ifname);
+        let reg = self
+            .igp_sync
+            .entry(ifname.clone())
+            .or_insert(IgpSyncReg { clients: 0, state });
+        reg.clients += 1;
+        southbound::tx::ldp_sync_update(&self.tx.ibus, &ifname, reg.state);
+    }
+
+    // Unregisters an IGP instance for LDP-IGP synchronization on the given
+    // interface.
+    fn igp_sync_unregister(&mut self, ifname: &str) {
+        if let Some(reg) = self.igp_sync.get_mut(ifname) {
+            reg.clients -= 1;
+            if reg.clients == 0 {
+                self.igp_sync.remove(ifname);
+            }
+        }
+    }
+
+    // Reevaluates the LDP-IGP synchronization state of all registered
+    // interfaces, notifying the IGPs of any change.
+    pub(crate) fn igp_sync_update(&mut self) {
+        for (ifname, reg) in self.igp_sync.iter_mut() {
+            let state =
+                igp_sync_state(self.state.as_ref(), &self.interfaces, ifname);
+            if state != reg.state {
+                reg.state = state;
+                southbound::tx::ldp_sync_update(&self.tx.ibus, ifname, state);
+            }
+        }
+    }
+
     pub(crate) fn as_up(
         &mut self,
     ) -> Option<(InstanceUpView<'_>, &mut Interfaces, &mut TargetedNbrs)> {
@@ -286,6 +451,7 @@ impl ProtocolInstance for Instance {
             state: None,
             interfaces: Default::default(),
             tneighbors: Default::default(),
+            igp_sync: Default::default(),
             tx,
             shared,
         }
@@ -297,6 +463,13 @@ impl ProtocolInstance for Instance {
     }
 
     async fn shutdown(mut self) {
+        // Preserve the local label bindings for graceful restart.
+        if self.config.gr.enabled {
+            if let Some((instance, _, _)) = self.as_up() {
+                gr::local_labels_save(&instance);
+            }
+        }
+
         // Ensure instance is disabled before exiting.
         self.stop(InstanceInactiveReason::AdminDown);
         Debug::InstanceDelete.log();
@@ -317,6 +490,10 @@ impl ProtocolInstance for Instance {
                 error.log();
             }
         }
+
+        // Adjacency and session changes might affect LDP-IGP
+        // synchronization.
+        self.igp_sync_update();
     }
 
     fn protocol_input_channels(
@@ -328,6 +505,11 @@ impl ProtocolInstance for Instance {
         let (nbr_pdu_rxp, nbr_pdu_rxc) = mpsc::channel(4);
         let (nbr_ka_timeoutp, nbr_ka_timeoutc) = mpsc::channel(4);
         let (nbr_backoff_timeoutp, nbr_backoff_timeoutc) = mpsc::channel(4);
+        let (nbr_trans_pref_timeoutp, nbr_trans_pref_timeoutc) =
+            mpsc::channel(4);
+        let (nbr_gr_timeoutp, nbr_gr_timeoutc) = mpsc::channel(4);
+        let (gr_fwd_holdtimep, gr_fwd_holdtimec) = mpsc::channel(4);
+        let (tnbr_prot_timeoutp, tnbr_prot_timeoutc) = mpsc::channel(4);
 
         let tx = ProtocolInputChannelsTx {
             udp_pdu_rx: udp_pdu_rxp,
@@ -337,6 +519,10 @@ impl ProtocolInstance for Instance {
             nbr_pdu_rx: nbr_pdu_rxp,
             nbr_ka_timeout: nbr_ka_timeoutp,
             nbr_backoff_timeout: nbr_backoff_timeoutp,
+            nbr_trans_pref_timeout: nbr_trans_pref_timeoutp,
+            nbr_gr_timeout: nbr_gr_timeoutp,
+            gr_fwd_holdtime: gr_fwd_holdtimep,
+            tnbr_prot_timeout: tnbr_prot_timeoutp,
         };
         let rx = ProtocolInputChannelsRx {
             udp_pdu_rx: udp_pdu_rxc,
@@ -346,6 +532,10 @@ impl ProtocolInstance for Instance {
             nbr_pdu_rx: nbr_pdu_rxc,
             nbr_ka_timeout: nbr_ka_timeoutc,
             nbr_backoff_timeout: nbr_backoff_timeoutc,
+            nbr_trans_pref_timeout: nbr_trans_pref_timeoutc,
+            nbr_gr_timeout: nbr_gr_timeoutc,
+            gr_fwd_holdtime: gr_fwd_holdtimec,
+            tnbr_prot_timeout: tnbr_prot_timeoutc,
         };
 
         (tx, rx)
@@ -360,39 +550,182 @@ impl ProtocolInstance for Instance {
 // ===== impl InstanceCfg =====
 
 impl InstanceCfg {
-    // Retrieves the password for a specific neighbor identified by its LSR-ID.
-    // If a custom password isn't configured for the neighbor, it's inherited
-    // from the global configuration.
-    pub(crate) fn get_neighbor_password(
+    // Returns whether the given address family is enabled.
+    pub(crate) fn af_enabled(&self, af: AddressFamily) -> bool {
+        match af {
+            AddressFamily::Ipv4 => {
+                self.ipv4.as_ref().is_some_and(|ipv4| ipv4.enabled)
+            }
+            AddressFamily::Ipv6 => self
+                .ipv6
+                .as_ref()
+                .is_some_and(|ipv6| ipv6.enabled && ipv6.trans_addr.is_some()),
+        }
+    }
+
+    // Returns the transport address of the given address family, or `None` if
+    // the address family is disabled.
+    //
+    // The IPv4 transport address is always the Router-ID.
+    pub(crate) fn trans_addr(
         &self,
-        lsr_id: Ipv4Addr,
-    ) -> Option<&str> {
-        if let Some(nbr_cfg) = self.neighbors.get(&lsr_id) {
-            if nbr_cfg.password.is_some() {
-                return nbr_cfg.password.as_deref();
+        af: AddressFamily,
+        router_id: Ipv4Addr,
+    ) -> Option<IpAddr> {
+        if !self.af_enabled(af) {
+            return None;
+        }
+
+        match af {
+            AddressFamily::Ipv4 => Some(router_id.into()),
+            AddressFamily::Ipv6 => {
+                self.ipv6.as_ref().unwrap().trans_addr.map(IpAddr::V6)
             }
         }
+    }
 
-        self.password.as_deref()
+    // Returns the label policy of the given address family, or `None` if the
+    // address family isn't configured.
+    pub(crate) fn label_policy(
+        &self,
+        af: AddressFamily,
+    ) -> Option<&LabelPolicyCfg> {
+        match af {
+            AddressFamily::Ipv4 => {
+                self.ipv4.as_ref().map(|ipv4| &ipv4.label_policy)
+            }
+            AddressFamily::Ipv6 => {
+                self.ipv6.as_ref().map(|ipv6| &ipv6.label_policy)
+            }
+        }
+    }
+
+    // Returns the preferred transport connection address family for dual-stack
+    // operation (RFC 7552).
+    pub(crate) fn trans_pref(&self) -> AddressFamily {
+        if self.dual_stack_prefer_ipv4 {
+            AddressFamily::Ipv4
+        } else {
+            AddressFamily::Ipv6
+        }
+    }
+
+    // Retrieves the TCP authentication method for a specific neighbor
+    // identified by its LSR-ID. If custom authentication isn't configured for
+    // the neighbor, it's inherited from the global configuration.
+    //
+    // Fails when TCP-AO is configured but its keychain doesn't exist or has no
+    // active keys, in which case the session must be kept down.
+    pub(crate) fn get_neighbor_auth(
+        &self,
+        lsr_id: Ipv4Addr,
+        keychains: &Keychains,
+    ) -> Result<Option<TcpAuth>, Error> {
+        let (password, keychain) = match self.neighbors.get(&lsr_id) {
+            Some(nbr_cfg)
+                if nbr_cfg.password.is_some() || nbr_cfg.keychain.is_some() =>
+            {
+                (&nbr_cfg.password, &nbr_cfg.keychain)
+            }
+            _ => (&self.password, &self.keychain),
+        };
+
+        if let Some(keychain) = keychain {
+            return keychains
+                .get(keychain)
+                .cloned()
+                .map(TcpAuth::Ao)
+                .filter(TcpAuth::is_usable)
+                .map(Some)
+                .ok_or(Error::TcpAuthUnavailable(lsr_id));
+        }
+        Ok(password.clone().map(TcpAuth::Md5))
     }
 }
 
 // ===== impl InstanceState =====
 
 impl InstanceState {
+    fn new(router_id: Ipv4Addr, trans_pref: AddressFamily) -> InstanceState {
+        InstanceState {
+            msg_id: Arc::new(AtomicU32::new(0)),
+            cfg_seqno: 0,
+            router_id,
+            neighbors: Default::default(),
+            fecs: Default::default(),
+            adjacencies: Default::default(),
+            ipv4: None,
+            ipv6: None,
+            trans_pref,
+            gr_preserved_labels: Default::default(),
+            gr_fwd_holdtime: None,
+            pseudowires: Default::default(),
+        }
+    }
+
+    pub(crate) fn af(&self, af: AddressFamily) -> Option<&InstanceAfState> {
+        match af {
+            AddressFamily::Ipv4 => self.ipv4.as_ref(),
+            AddressFamily::Ipv6 => self.ipv6.as_ref(),
+        }
+    }
+
+    fn af_mut(&mut self, af: AddressFamily) -> &mut Option<InstanceAfState> {
+        match af {
+            AddressFamily::Ipv4 => &mut self.ipv4,
+            AddressFamily::Ipv6 => &mut self.ipv6,
+        }
+    }
+
+    // Returns the local transport address of the given address family.
+    pub(crate) fn trans_addr(&self, af: AddressFamily) -> Option<IpAddr> {
+        self.af(af).map(|af_state| af_state.trans_addr)
+    }
+
+    // Returns the preferred transport connection address family, or `None` if
+    // the instance isn't operating in dual-stack mode.
+    pub(crate) fn dual_stack_pref(&self) -> Option<AddressFamily> {
+        (self.ipv4.is_some() && self.ipv6.is_some()).then_some(self.trans_pref)
+    }
+
+    // Updates the TCP authentication of the listening socket matching the
+    // address family of the given neighbor address.
+    pub(crate) fn listen_socket_auth_update(
+        &self,
+        nbr_addr: &IpAddr,
+        auth: Option<TcpAuth>,
+    ) {
+        if let Some(af_state) = self.af(nbr_addr.address_family()) {
+            tcp::listen_socket_auth_update(
+                &af_state.session_socket,
+                nbr_addr,
+                auth.as_ref(),
+            );
+        }
+    }
+
+    pub(crate) fn get_next_msg_id(msg_id: &Arc<AtomicU32>) -> u32 {
+        msg_id.fetch_add(1, atomic::Ordering::Relaxed)
+    }
+}
+
+// ===== impl InstanceAfState =====
+
+impl InstanceAfState {
     async fn new(
-        router_id: Ipv4Addr,
-        trans_addr: Ipv4Addr,
+        trans_addr: IpAddr,
         proto_input_tx: &ProtocolInputChannelsTx,
-    ) -> Result<InstanceState, Error> {
+    ) -> Result<InstanceAfState, Error> {
+        let af = trans_addr.address_family();
+
         // Create UDP/TCP sockets.
-        let disc_socket = udp::discovery_socket(IpAddr::from([0, 0, 0, 0]))
+        let disc_socket = udp::discovery_socket(IpAddr::unspecified(af))
             .map(Arc::new)
             .map_err(IoError::UdpSocketError)?;
-        let edisc_socket = udp::discovery_socket(IpAddr::V4(trans_addr))
+        let edisc_socket = udp::discovery_socket(trans_addr)
             .map(Arc::new)
             .map_err(IoError::UdpSocketError)?;
-        let session_socket = tcp::listen_socket(IpAddr::V4(trans_addr))
+        let session_socket = tcp::listen_socket(trans_addr)
             .await
             .map(Arc::new)
             .map_err(IoError::TcpSocketError)?;
@@ -407,27 +740,16 @@ impl InstanceState {
         let tcp_listener_task =
             tasks::tcp_listener(&session_socket, &proto_input_tx.tcp_accept);
 
-        Ok(InstanceState {
-            msg_id: Arc::new(AtomicU32::new(0)),
-            cfg_seqno: 0,
-            router_id,
-            neighbors: Default::default(),
-            fecs: Default::default(),
-            ipv4: InstanceIpv4State::new(
-                disc_socket,
-                edisc_socket,
-                session_socket,
-                disc_rx_task,
-                edisc_rx_task,
-                tcp_listener_task,
-                trans_addr,
-            ),
+        Ok(InstanceAfState {
+            disc_socket,
+            edisc_socket,
+            session_socket,
+            _disc_rx_task: disc_rx_task,
+            _edisc_rx_task: edisc_rx_task,
+            _tcp_listener_task: tcp_listener_task,
+            trans_addr,
         })
     }
-
-    pub(crate) fn get_next_msg_id(msg_id: &Arc<AtomicU32>) -> u32 {
-        msg_id.fetch_add(1, atomic::Ordering::Relaxed)
-    }
 }
 
 // ===== impl ProtocolInputChannelsRx =====
@@ -457,12 +779,36 @@ impl MessageReceiver<ProtocolInputMsg> for ProtocolInputChannelsRx {
             msg = self.nbr_backoff_timeout.recv() => {
                 msg.map(ProtocolInputMsg::NbrBackoffTimeout)
             }
+            msg = self.nbr_trans_pref_timeout.recv() => {
+                msg.map(ProtocolInputMsg::NbrTransPrefTimeout)
+            }
+            msg = self.nbr_gr_timeout.recv() => {
+                msg.map(ProtocolInputMsg::NbrGrTimeout)
+            }
+            msg = self.gr_fwd_holdtime.recv() => {
+                msg.map(ProtocolInputMsg::GrFwdHoldtime)
+            }
+            msg = self.tnbr_prot_timeout.recv() => {
+                msg.map(ProtocolInputMsg::TnbrProtTimeout)
+            }
         }
     }
 }
 
 // ===== helper functions =====
 
+// Returns the LDP-IGP synchronization state of the given interface.
+fn igp_sync_state(
+    state: Option<&InstanceState>,
+    interfaces: &Interfaces,
+    ifname: &str,
+) -> LdpSyncState {
+    match (state, interfaces.get_by_name(ifname)) {
+        (Some(state), Some((_, iface))) => iface.igp_sync_state(state),
+        _ => LdpSyncState::Disabled,
+    }
+}
+
 async fn process_ibus_msg(
     instance: &mut Instance,
     msg: IbusMsg,
@@ -472,6 +818,10 @@ async fn process_ibus_msg(
         IbusMsg::InterfaceUpd(msg) => {
             southbound::rx::process_iface_update(instance, msg);
         }
+        // Interface delete notification.
+        IbusMsg::InterfaceDel(ifname) => {
+            southbound::rx::process_iface_del(instance, ifname);
+        }
         // Interface address addition notification.
         IbusMsg::InterfaceAddressAdd(msg) => {
             southbound::rx::process_addr_add(instance, msg);
@@ -484,6 +834,39 @@ async fn process_ibus_msg(
         IbusMsg::RouterIdUpdate(router_id) => {
             southbound::rx::process_router_id_update(instance, router_id).await;
         }
+        // Keychain update notification.
+        IbusMsg::KeychainUpd(keychain) => {
+            // Update the local copy of the keychain.
+            instance
+                .shared
+                .keychains
+                .insert(keychain.name.clone(), keychain.clone());
+
+            // Update all neighbors using this keychain.
+            if let Some((mut instance, _, _)) = instance.as_up() {
+                events::process_keychain_update(&mut instance, &keychain.name);
+            }
+        }
+        // Keychain delete notification.
+        IbusMsg::KeychainDel(keychain_name) => {
+            // Remove the local copy of the keychain.
+            instance.shared.keychains.remove(&keychain_name);
+
+            // Update all neighbors using this keychain.
+            if let Some((mut instance, _, _)) = instance.as_up() {
+                events::process_keychain_update(&mut instance, &keychain_name);
+            }
+        }
+        // Policy match sets update notification.
+        IbusMsg::PolicyMatchSetsUpd(match_sets) => {
+            // Update the local copy of the policy match sets.
+            instance.shared.policy_match_sets = match_sets;
+
+            // Reevaluate the label policies.
+            if let Some((mut instance, _, _)) = instance.as_up() {
+                policy::process_label_policy_update(&mut instance);
+            }
+        }
         // Route redistribute update notification.
         IbusMsg::RouteRedistributeAdd(msg) => {
             southbound::rx::process_route_add(instance, msg);
@@ -492,10 +875,28 @@ async fn process_ibus_msg(
         IbusMsg::RouteRedistributeDel(msg) => {
             southbound::rx::process_route_del(instance, msg);
         }
+        // Pseudowire installation status notification.
+        IbusMsg::RoutePwStatus(msg) if msg.protocol == Protocol::LDP => {
+            if let Some((mut instance, _, _)) = instance.as_up() {
+                pw::process_status_update(&mut instance, msg);
+            }
+        }
+        // LDP-IGP synchronization registration.
+        IbusMsg::LdpSyncReg(ifname) => {
+            instance.igp_sync_register(ifname);
+        }
+        // LDP-IGP synchronization unregistration.
+        IbusMsg::LdpSyncUnreg(ifname) => {
+            instance.igp_sync_unregister(&ifname);
+        }
         // Ignore other events.
         _ => {}
     }
 
+    // Interface or instance state changes might affect LDP-IGP
+    // synchronization.
+    instance.igp_sync_update();
+
     Ok(())
 }
 
@@ -513,6 +914,7 @@ fn process_protocol_msg(
                 interfaces,
                 tneighbors,
                 msg.src_addr,
+                msg.ifindex,
                 msg.pdu,
                 msg.multicast,
             );
@@ -546,6 +948,22 @@ fn process_protocol_msg(
         ProtocolInputMsg::NbrBackoffTimeout(msg) => {
             events::process_nbr_backoff_timeout(instance, msg.lsr_id);
         }
+        // Neighbor's dual-stack transport preference timeout has expired.
+        ProtocolInputMsg::NbrTransPrefTimeout(msg) => {
+            events::process_nbr_trans_pref_timeout(instance, msg.lsr_id);
+        }
+        // Neighbor's graceful restart timeout has expired.
+        ProtocolInputMsg::NbrGrTimeout(msg) => {
+            events::process_nbr_gr_timeout(instance, msg.lsr_id);
+        }
+        // MPLS forwarding state holding timer has expired.
+        ProtocolInputMsg::GrFwdHoldtime(_msg) => {
+            events::process_gr_fwd_holdtime(instance);
+        }
+        // Targeted neighbor's session protection timeout has expired.
+        ProtocolInputMsg::TnbrProtTimeout(msg) => {
+            events::process_tnbr_prot_timeout(instance, tneighbors, msg.addr);
+        }
     }
 
     Ok(())
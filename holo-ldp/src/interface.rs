@@ -5,11 +5,13 @@
 //
 
 use std::collections::BTreeSet;
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::atomic::AtomicU32;
 use std::sync::Arc;
 use std::time::Duration;
 
+use holo_utils::ibus::LdpSyncState;
+use holo_utils::ip::{AddressFamily, IpAddrKind};
 use holo_utils::socket::{UdpSocket, UdpSocketExt};
 use holo_utils::southbound::InterfaceFlags;
 use holo_utils::task::IntervalTask;
@@ -21,8 +23,8 @@ use crate::error::{Error, IoError};
 use crate::instance::{InstanceState, InstanceUpView};
 use crate::northbound::configuration::InterfaceCfg;
 use crate::packet::messages::hello::{
-    HelloFlags, HelloMsg, TlvCommonHelloParams, TlvConfigSeqNo,
-    TlvIpv4TransAddr,
+    HelloFlags, HelloMsg, TlvCommonHelloParams, TlvConfigSeqNo, TlvDualStack,
+    TlvIpv4TransAddr, TlvIpv6TransAddr,
 };
 use crate::packet::messages::notification::StatusCode;
 use crate::packet::Pdu;
@@ -34,7 +36,7 @@ pub struct Interface {
     pub name: String,
     pub system: InterfaceSys,
     pub config: InterfaceCfg,
-    pub state: Option<InterfaceState>,
+    pub state: InterfaceState,
 }
 
 #[derive(Debug, Default)]
@@ -45,8 +47,25 @@ pub struct InterfaceSys {
     pub ipv6_addr_list: BTreeSet<Ipv6Network>,
 }
 
+// LDP-IGP synchronization registration.
 #[derive(Debug)]
+pub struct IgpSyncReg {
+    // Number of IGP instances interested in the interface.
+    pub clients: usize,
+    // Last synchronization state sent to the IGP instances.
+    pub state: LdpSyncState,
+}
+
+#[derive(Debug, Default)]
 pub struct InterfaceState {
+    // IPv4 interface state.
+    pub ipv4: Option<InterfaceAfState>,
+    // IPv6 interface state.
+    pub ipv6: Option<InterfaceAfState>,
+}
+
+#[derive(Debug)]
+pub struct InterfaceAfState {
     // UDP discovery socket bound to this interface.
     pub disc_socket: Arc<UdpSocket>,
     // Hello Tx interval task.
@@ -66,23 +85,27 @@ impl Interface {
             name,
             system: InterfaceSys::default(),
             config: InterfaceCfg::default(),
-            state: None,
+            state: Default::default(),
         }
     }
 
-    fn start(&mut self, instance_state: &InstanceState) -> Result<(), Error> {
-        Debug::InterfaceStart(&self.name).log();
+    fn start(
+        &mut self,
+        instance_state: &InstanceState,
+        af: AddressFamily,
+    ) -> Result<(), Error> {
+        Debug::InterfaceStart(&self.name, af).log();
 
-        let disc_socket = network::udp::interface_discovery_socket(self)
+        let disc_socket = network::udp::interface_discovery_socket(self, af)
             .map(Arc::new)
             .map_err(IoError::UdpSocketError)?;
 
-        self.system
-            .join_multicast_ipv4(&instance_state.ipv4.disc_socket);
+        let instance_af = instance_state.af(af).unwrap();
+        self.system.join_multicast(&instance_af.disc_socket, af);
         let hello_interval_task =
-            tasks::iface_hello_interval(self, &disc_socket, instance_state);
+            tasks::iface_hello_interval(self, af, &disc_socket, instance_state);
 
-        self.state = Some(InterfaceState {
+        *self.state.af_mut(af) = Some(InterfaceAfState {
             disc_socket,
             hello_interval_task,
         });
@@ -90,23 +113,41 @@ impl Interface {
         Ok(())
     }
 
+    // Stops all active address families of the interface.
     pub(crate) fn stop(
         &mut self,
         instance: &mut InstanceUpView<'_>,
         reason: InterfaceInactiveReason,
     ) {
-        Debug::InterfaceStop(&self.name, reason).log();
+        for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+            if self.is_af_active(af) {
+                self.stop_af(instance, af, reason);
+            }
+        }
+    }
 
-        self.system
-            .leave_multicast_ipv4(&instance.state.ipv4.disc_socket);
-        self.state = None;
+    pub(crate) fn stop_af(
+        &mut self,
+        instance: &mut InstanceUpView<'_>,
+        af: AddressFamily,
+        reason: InterfaceInactiveReason,
+    ) {
+        Debug::InterfaceStop(&self.name, af, reason).log();
+
+        if let Some(instance_af) = instance.state.af(af) {
+            self.system.leave_multicast(&instance_af.disc_socket, af);
+        }
+        *self.state.af_mut(af) = None;
 
         // Delete adjacencies.
-        let adjacencies = &mut instance.state.ipv4.adjacencies;
+        let adjacencies = &instance.state.adjacencies;
         for adj_idx in adjacencies
             .get_by_iface(&self.name)
             .iter()
             .flat_map(|adjs| adjs.values().cloned())
+            .filter(|adj_idx| {
+                adjacencies[*adj_idx].source.addr.address_family() == af
+            })
             .collect::<Vec<InterfaceIndex>>()
         {
             discovery::adjacency_delete(
@@ -117,51 +158,77 @@ impl Interface {
         }
     }
 
-    // Enables or disables the interface if necessary.
+    // Enables or disables the interface address families if necessary.
     pub(crate) fn update(&mut self, instance: &mut InstanceUpView<'_>) {
-        match self.is_ready() {
-            Ok(()) if !self.is_active() => {
-                // Attempt to activate interface.
-                if let Err(error) = self.start(instance.state) {
-                    Error::InterfaceStartError(
-                        self.name.clone(),
-                        Box::new(error),
-                    )
-                    .log();
+        for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+            match self.is_ready(instance.state, af) {
+                Ok(()) if !self.is_af_active(af) => {
+                    // Attempt to activate interface.
+                    if let Err(error) = self.start(instance.state, af) {
+                        Error::InterfaceStartError(
+                            self.name.clone(),
+                            Box::new(error),
+                        )
+                        .log();
+                    }
                 }
+                Err(reason) if self.is_af_active(af) => {
+                    // Deactivate interface.
+                    self.stop_af(instance, af, reason);
+                }
+                _ => (),
             }
-            Err(reason) if self.is_active() => {
-                // Deactivate interface.
-                self.stop(instance, reason);
-            }
-            _ => (),
         }
     }
 
     pub(crate) fn sync_hello_tx(&mut self, instance_state: &InstanceState) {
-        let state = self.state.as_ref().unwrap();
-        let hello_interval_task = tasks::iface_hello_interval(
-            self,
-            &state.disc_socket,
-            instance_state,
-        );
-
-        let state = self.state.as_mut().unwrap();
-        state.hello_interval_task = hello_interval_task;
+        for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+            let Some(state) = self.state.af(af) else {
+                continue;
+            };
+            let hello_interval_task = tasks::iface_hello_interval(
+                self,
+                af,
+                &state.disc_socket,
+                instance_state,
+            );
+
+            let state = self.state.af_mut(af).as_mut().unwrap();
+            state.hello_interval_task = hello_interval_task;
+        }
     }
 
     pub(crate) fn is_active(&self) -> bool {
-        self.state.is_some()
+        self.state.ipv4.is_some() || self.state.ipv6.is_some()
     }
 
-    // Returns whether the interface is ready for LDP operation.
-    fn is_ready(&self) -> Result<(), InterfaceInactiveReason> {
-        if self.config.ipv4.is_none()
-            || !self.config.ipv4.as_ref().unwrap().enabled
-        {
+    pub(crate) fn is_af_active(&self, af: AddressFamily) -> bool {
+        self.state.af(af).is_some()
+    }
+
+    // Returns whether the interface is ready for LDP operation in the given
+    // address family.
+    fn is_ready(
+        &self,
+        instance_state: &InstanceState,
+        af: AddressFamily,
+    ) -> Result<(), InterfaceInactiveReason> {
+        let enabled = match af {
+            AddressFamily::Ipv4 => {
+                self.config.ipv4.as_ref().is_some_and(|ipv4| ipv4.enabled)
+            }
+            AddressFamily::Ipv6 => {
+                self.config.ipv6.as_ref().is_some_and(|ipv6| ipv6.enabled)
+            }
+        };
+        if !enabled {
             return Err(InterfaceInactiveReason::AdminDown);
         }
 
+        if instance_state.af(af).is_none() {
+            return Err(InterfaceInactiveReason::InstanceDown);
+        }
+
         if !self.system.flags.contains(InterfaceFlags::OPERATIVE) {
             return Err(InterfaceInactiveReason::OperationalDown);
         }
@@ -170,7 +237,7 @@ impl Interface {
             return Err(InterfaceInactiveReason::MissingIfindex);
         }
 
-        if self.system.ipv4_addr_list.is_empty() {
+        if self.system.local_addr(af).is_none() {
             return Err(InterfaceInactiveReason::MissingIpAddress);
         }
 
@@ -179,8 +246,10 @@ impl Interface {
 
     pub(crate) fn generate_hello(
         &self,
+        af: AddressFamily,
         instance_state: &InstanceState,
     ) -> HelloMsg {
+        let trans_addr = instance_state.trans_addr(af).unwrap();
         HelloMsg {
             // The message ID will be overwritten later.
             msg_id: 0,
@@ -188,15 +257,18 @@ impl Interface {
                 holdtime: self.config.hello_holdtime,
                 flags: HelloFlags::GTSM,
             },
-            ipv4_addr: Some(TlvIpv4TransAddr(instance_state.ipv4.trans_addr)),
-            ipv6_addr: None,
+            ipv4_addr: Ipv4Addr::get(trans_addr).map(TlvIpv4TransAddr),
+            ipv6_addr: Ipv6Addr::get(trans_addr).map(TlvIpv6TransAddr),
             cfg_seqno: Some(TlvConfigSeqNo(instance_state.cfg_seqno)),
-            dual_stack: None,
+            dual_stack: instance_state
+                .dual_stack_pref()
+                .map(|af| TlvDualStack(af.into())),
         }
     }
 
     pub(crate) async fn send_hello(
         disc_socket: Arc<UdpSocket>,
+        af: AddressFamily,
         router_id: Ipv4Addr,
         msg_id: Arc<AtomicU32>,
         mut hello: HelloMsg,
@@ -211,7 +283,7 @@ impl Interface {
 
         // Send multicast packet.
         if let Err(error) =
-            network::udp::send_packet_multicast(&disc_socket, pdu).await
+            network::udp::send_packet_multicast(&disc_socket, af, pdu).await
         {
             IoError::UdpSendError(error).log();
         }
@@ -228,11 +300,44 @@ impl Interface {
         std::cmp::min(self.config.hello_holdtime, hello_holdtime)
     }
 
-    pub(crate) fn next_hello(&self) -> Option<Duration> {
+    pub(crate) fn next_hello(&self, af: AddressFamily) -> Option<Duration> {
         self.state
-            .as_ref()
+            .af(af)
             .map(|state| state.hello_interval_task.remaining())
     }
+
+    // Returns the LDP-IGP synchronization state of the interface.
+    //
+    // The interface is considered synchronized when it has at least one
+    // adjacency and the label exchange with all of its neighbors is complete.
+    pub(crate) fn igp_sync_state(
+        &self,
+        instance_state: &InstanceState,
+    ) -> LdpSyncState {
+        if !self.is_active() {
+            return LdpSyncState::Disabled;
+        }
+
+        let mut adjs = instance_state
+            .adjacencies
+            .iter_by_iface(&self.name)
+            .into_iter()
+            .flatten()
+            .peekable();
+        if adjs.peek().is_none() {
+            return LdpSyncState::NotSynced;
+        }
+        if adjs.all(|adj| {
+            instance_state
+                .neighbors
+                .get_by_lsr_id(&adj.lsr_id)
+                .is_some_and(|(_, nbr)| nbr.is_label_exchange_complete())
+        }) {
+            LdpSyncState::Synced
+        } else {
+            LdpSyncState::NotSynced
+        }
+    }
 }
 
 impl Drop for Interface {
@@ -266,32 +371,79 @@ impl InterfaceSys {
         false
     }
 
-    fn join_multicast_ipv4(&self, disc_socket: &UdpSocket) {
+    fn join_multicast(&self, disc_socket: &UdpSocket, af: AddressFamily) {
         #[cfg(not(feature = "testing"))]
         {
-            if let Err(error) = disc_socket.join_multicast_ifindex_v4(
-                &network::udp::LDP_MCAST_ADDR_V4,
-                self.ifindex.unwrap(),
-            ) {
+            let ifindex = self.ifindex.unwrap();
+            let result = match af {
+                AddressFamily::Ipv4 => disc_socket.join_multicast_ifindex_v4(
+                    &network::udp::LDP_MCAST_ADDR_V4,
+                    ifindex,
+                ),
+                AddressFamily::Ipv6 => disc_socket.join_multicast_v6(
+                    &network::udp::LDP_MCAST_ADDR_V6,
+                    ifindex,
+                ),
+            };
+            if let Err(error) = result {
                 IoError::UdpMulticastJoinError(error).log();
             }
         }
     }
 
-    fn leave_multicast_ipv4(&self, disc_socket: &UdpSocket) {
+    fn leave_multicast(&self, disc_socket: &UdpSocket, af: AddressFamily) {
         #[cfg(not(feature = "testing"))]
         {
-            if let Err(error) = disc_socket.leave_multicast_ifindex_v4(
-                &network::udp::LDP_MCAST_ADDR_V4,
-                self.ifindex.unwrap(),
-            ) {
+            let ifindex = self.ifindex.unwrap();
+            let result = match af {
+                AddressFamily::Ipv4 => disc_socket.leave_multicast_ifindex_v4(
+                    &network::udp::LDP_MCAST_ADDR_V4,
+                    ifindex,
+                ),
+                AddressFamily::Ipv6 => disc_socket.leave_multicast_v6(
+                    &network::udp::LDP_MCAST_ADDR_V6,
+                    ifindex,
+                ),
+            };
+            if let Err(error) = result {
                 IoError::UdpMulticastJoinError(error).log();
             }
         }
     }
 
-    pub(crate) fn local_ipv4_addr(&self) -> IpAddr {
-        let addr = self.ipv4_addr_list.iter().next().unwrap();
-        IpAddr::from(addr.ip())
+    // Returns the local address used to source hello messages in the given
+    // address family. For IPv6, this is always a link-local address.
+    pub(crate) fn local_addr(&self, af: AddressFamily) -> Option<IpAddr> {
+        match af {
+            AddressFamily::Ipv4 => self
+                .ipv4_addr_list
+                .iter()
+                .next()
+                .map(|addr| IpAddr::V4(addr.ip())),
+            AddressFamily::Ipv6 => self
+                .ipv6_addr_list
+                .iter()
+                .map(|addr| addr.ip())
+                .find(|addr| addr.is_unicast_link_local())
+                .map(IpAddr::V6),
+        }
+    }
+}
+
+// ===== impl InterfaceState =====
+
+impl InterfaceState {
+    pub(crate) fn af(&self, af: AddressFamily) -> Option<&InterfaceAfState> {
+        match af {
+            AddressFamily::Ipv4 => self.ipv4.as_ref(),
+            AddressFamily::Ipv6 => self.ipv6.as_ref(),
+        }
+    }
+
+    fn af_mut(&mut self, af: AddressFamily) -> &mut Option<InterfaceAfState> {
+        match af {
+            AddressFamily::Ipv4 => &mut self.ipv4,
+            AddressFamily::Ipv6 => &mut self.ipv6,
+        }
     }
 }
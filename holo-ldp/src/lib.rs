@@ -16,11 +16,14 @@ pub mod discovery;
 pub mod error;
 pub mod events;
 pub mod fec;
+pub mod gr;
 pub mod instance;
 pub mod interface;
 pub mod neighbor;
 pub mod network;
 pub mod northbound;
 pub mod packet;
+pub mod policy;
+pub mod pw;
 pub mod southbound;
 pub mod tasks;
@@ -14,7 +14,7 @@ use bitflags::bitflags;
 use chrono::{DateTime, Utc};
 use holo_utils::ip::AddressFamily;
 use holo_utils::mpls::Label;
-use holo_utils::socket::{TcpConnInfo, TcpStream};
+use holo_utils::socket::{Socket, TcpAuth, TcpConnInfo, TcpStream};
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
 use holo_utils::{Sender, UnboundedSender};
 use ipnetwork::IpNetwork;
@@ -32,9 +32,12 @@ use crate::packet::messages::address::TlvAddressList;
 use crate::packet::messages::capability::{
     TlvCapDynamic, TlvCapTwcardFec, TlvCapUnrecNotif,
 };
-use crate::packet::messages::initialization::{InitFlags, TlvCommonSessParams};
+use crate::packet::messages::initialization::{
+    FtFlags, InitFlags, TlvCommonSessParams, TlvFtSession,
+};
 use crate::packet::messages::label::{
-    FecElem, FecElemWildcard, TlvFec, TlvLabel, TlvLabelRequestId,
+    FecElem, FecElemWildcard, PwStatusFlags, TlvFec, TlvLabel,
+    TlvLabelRequestId, TlvPwGroupId, TlvPwIfParams, TlvPwStatus,
     TypedWildcardFecElem,
 };
 use crate::packet::messages::notification::{StatusCode, TlvStatus};
@@ -43,13 +46,14 @@ use crate::packet::messages::{
 };
 use crate::packet::pdu::Pdu;
 use crate::packet::{AddressMessageType, LabelMessageType, Message};
+use crate::pw::{PwFecKey, PwMapping};
 use crate::tasks::messages::input::{
     NbrBackoffTimeoutMsg, NbrKaTimeoutMsg, NbrRxPduMsg, TcpConnectMsg,
 };
 use crate::tasks::messages::output::NbrTxPduMsg;
 #[cfg(feature = "testing")]
 use crate::tasks::messages::ProtocolOutputMsg;
-use crate::{southbound, tasks};
+use crate::{gr, policy, pw, southbound, tasks};
 
 #[derive(Debug)]
 pub struct Neighbor {
@@ -59,25 +63,38 @@ pub struct Neighbor {
     pub state: fsm::State,
     pub cfg_seqno: u32,
     pub conn_info: Option<TcpConnInfo>,
+    pub conn_socket: Option<Socket>,
     pub max_pdu_len: u16,
     pub init_attempts: usize,
     pub kalive_holdtime_rcvd: Option<u16>,
     pub kalive_holdtime_negotiated: Option<u16>,
     pub kalive_interval: u16,
     pub rcvd_label_adv_mode: Option<LabelAdvMode>,
+    pub ft_session_rcvd: Option<TlvFtSession>,
     pub addr_list: BTreeSet<IpAddr>,
     pub rcvd_mappings: BTreeMap<IpNetwork, LabelMapping>,
     pub sent_mappings: BTreeMap<IpNetwork, LabelMapping>,
     pub rcvd_requests: BTreeMap<IpNetwork, LabelRequest>,
     pub sent_requests: BTreeMap<IpNetwork, LabelRequest>,
     pub sent_withdraws: BTreeMap<IpNetwork, Label>,
+    pub rcvd_pw_mappings: BTreeMap<PwFecKey, PwMapping>,
     pub statistics: Statistics,
     pub uptime: Option<Instant>,
     pub pdu_txp: Option<UnboundedSender<NbrTxPduMsg>>,
+    pub gr: Option<NeighborGrHelper>,
     pub tasks: NeighborTasks,
     pub flags: NeighborFlags,
 }
 
+// Graceful restart helper state (RFC 3478).
+#[derive(Debug)]
+pub struct NeighborGrHelper {
+    // Neighbor addresses whose label bindings are retained as stale.
+    pub stale_addrs: BTreeSet<IpAddr>,
+    // Reconnect or recovery timer, depending on the session state.
+    pub timeout: TimeoutTask,
+}
+
 #[derive(Debug, Default)]
 pub struct NeighborTasks {
     pub connect: Option<Task<()>>,
@@ -85,6 +102,7 @@ pub struct NeighborTasks {
     pub tcp_rx: Option<Task<()>>,
     pub kalive_tx: Option<IntervalTask>,
     pub kalive_timeout: Option<TimeoutTask>,
+    pub trans_pref_timeout: Option<TimeoutTask>,
 }
 
 bitflags! {
@@ -94,6 +112,8 @@ bitflags! {
         const CAP_DYNAMIC = 0x02;
         const CAP_TYPED_WCARD = 0x04;
         const CAP_UNREC_NOTIF = 0x08;
+        const EOL_RCVD = 0x10;
+        const TRANS_PREF_TIMEOUT = 0x20;
     }
 }
 
@@ -188,21 +208,25 @@ impl Neighbor {
             state: fsm::State::NonExistent,
             cfg_seqno: 0,
             conn_info: None,
+            conn_socket: None,
             max_pdu_len: Pdu::DFLT_MAX_LEN,
             init_attempts: 0,
             kalive_holdtime_rcvd: None,
             kalive_holdtime_negotiated: None,
             kalive_interval,
             rcvd_label_adv_mode: None,
+            ft_session_rcvd: None,
             addr_list: BTreeSet::default(),
             rcvd_mappings: Default::default(),
             sent_mappings: Default::default(),
             rcvd_requests: Default::default(),
             sent_requests: Default::default(),
             sent_withdraws: Default::default(),
+            rcvd_pw_mappings: Default::default(),
             statistics: Default::default(),
             uptime: None,
             pdu_txp: None,
+            gr: None,
             tasks: Default::default(),
             flags: NeighborFlags::empty(),
         }
@@ -245,7 +269,7 @@ impl Neighbor {
                 }
 
                 if let Some(action) = action {
-                    Neighbor::fsm_action(instance, nbr_idx, action);
+                    Neighbor::fsm_action(instance, nbr_idx, event, action);
                 }
             }
             Err(error) => {
@@ -320,13 +344,19 @@ impl Neighbor {
     fn fsm_action(
         instance: &mut InstanceUpView<'_>,
         nbr_idx: NeighborIndex,
+        event: fsm::Event,
         action: fsm::Action,
     ) {
+        let ft_session = gr::ft_session_tlv(instance.config, instance.state);
         let nbr = &mut instance.state.neighbors[nbr_idx];
         match action {
             fsm::Action::SendInitAndKeepalive => {
                 // Send initialization message.
-                nbr.send_init(instance.config, &instance.state.msg_id);
+                nbr.send_init(
+                    instance.config,
+                    &instance.state.msg_id,
+                    ft_session,
+                );
 
                 // Send keepalive message.
                 nbr.send_keepalive(&instance.state.msg_id);
@@ -338,7 +368,11 @@ impl Neighbor {
             }
             fsm::Action::SendInit => {
                 // Send initialization message.
-                nbr.send_init(instance.config, &instance.state.msg_id);
+                nbr.send_init(
+                    instance.config,
+                    &instance.state.msg_id,
+                    ft_session,
+                );
                 Neighbor::fsm(instance, nbr_idx, fsm::Event::InitSent);
             }
             fsm::Action::SendKeepalive => {
@@ -362,22 +396,46 @@ impl Neighbor {
                     &instance.tx.protocol_input.nbr_ka_timeout,
                 );
 
-                // Send address message;
-                let addr_list = instance
-                    .system
-                    .ipv4_addr_list
-                    .iter()
-                    .map(|addr| addr.ip())
-                    .collect();
-                nbr.send_address(
-                    &instance.state.msg_id,
-                    AddressMessageType::Address,
-                    addr_list,
-                );
+                // Send address messages (one per enabled address family).
+                if instance.state.ipv4.is_some() {
+                    let addr_list = instance
+                        .system
+                        .ipv4_addr_list
+                        .iter()
+                        .map(|addr| addr.ip())
+                        .collect();
+                    nbr.send_address(
+                        &instance.state.msg_id,
+                        AddressMessageType::Address,
+                        TlvAddressList::Ipv4(addr_list),
+                    );
+                }
+                if instance.state.ipv6.is_some() {
+                    // Link-local addresses must not be advertised (RFC 7552).
+                    let addr_list = instance
+                        .system
+                        .ipv6_addr_list
+                        .iter()
+                        .map(|addr| addr.ip())
+                        .filter(|addr| !addr.is_unicast_link_local())
+                        .collect();
+                    nbr.send_address(
+                        &instance.state.msg_id,
+                        AddressMessageType::Address,
+                        TlvAddressList::Ipv6(addr_list),
+                    );
+                }
 
                 // Send label mappings
                 for fec in instance.state.fecs.values_mut() {
-                    if fec.inner.local_label.is_none() {
+                    if fec.inner.local_label.is_none()
+                        || !policy::label_advertise_check(
+                            instance.config,
+                            &instance.shared.policy_match_sets,
+                            nbr.lsr_id,
+                            &fec.inner.prefix,
+                        )
+                    {
                         continue;
                     }
                     nbr.send_label_mapping(&instance.state.msg_id, fec);
@@ -385,13 +443,51 @@ impl Neighbor {
 
                 // Signal completion of label advertisements.
                 if nbr.flags.contains(NeighborFlags::CAP_UNREC_NOTIF) {
-                    nbr.send_end_of_lib(
-                        &instance.state.msg_id,
-                        TypedWildcardFecElem::Prefix(AddressFamily::Ipv4),
-                    );
+                    for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+                        let af_state = match af {
+                            AddressFamily::Ipv4 => &instance.state.ipv4,
+                            AddressFamily::Ipv6 => &instance.state.ipv6,
+                        };
+                        if af_state.is_none() {
+                            continue;
+                        }
+                        nbr.send_end_of_lib(
+                            &instance.state.msg_id,
+                            TypedWildcardFecElem::Prefix(af),
+                        );
+                    }
+                }
+
+                // Start the recovery period of a restarting neighbor.
+                if nbr.gr.is_some() {
+                    let recovery_time = nbr.gr_recovery_time(instance.config);
+                    if recovery_time == 0 {
+                        gr::helper_exit(instance, nbr_idx);
+                    } else {
+                        let task = tasks::nbr_gr_timeout(
+                            nbr,
+                            recovery_time,
+                            &instance.tx.protocol_input.nbr_gr_timeout,
+                        );
+                        if let Some(gr) = &mut nbr.gr {
+                            gr.timeout = task;
+                        }
+                    }
                 }
+
+                // Advertise the pseudowires signaled over this session.
+                pw::update_all(instance);
             }
             fsm::Action::CloseSession => {
+                // Retain the label bindings learned from a graceful restart
+                // capable neighbor when the session is lost unexpectedly.
+                let reconnect_time = match event {
+                    fsm::Event::ConnectionDown => {
+                        nbr.gr_reconnect_time(instance.config)
+                    }
+                    _ => None,
+                };
+
                 // Iterate over all FECs.
                 for fec in instance.state.fecs.values_mut() {
                     let old_fec_status = fec.is_operational();
@@ -402,6 +498,12 @@ impl Neighbor {
                         .values_mut()
                         .filter(|nexthop| nbr.addr_list.contains(&nexthop.addr))
                     {
+                        if reconnect_time.is_some() {
+                            if nexthop.get_label().is_some() {
+                                nexthop.mark_stale();
+                            }
+                            continue;
+                        }
                         southbound::tx::label_uninstall(
                             &instance.tx.ibus,
                             &fec.inner,
@@ -418,13 +520,31 @@ impl Neighbor {
                     }
 
                     // Remove downstream and upstream label bindings (if any).
-                    fec.inner.downstream.remove(&nbr.lsr_id);
+                    if reconnect_time.is_none() {
+                        fec.inner.downstream.remove(&nbr.lsr_id);
+                    }
                     fec.inner.upstream.remove(&nbr.lsr_id);
                 }
 
                 // Close session.
+                let addr_list = std::mem::take(&mut nbr.addr_list);
                 nbr.close_session();
 
+                // Wait for the neighbor to reconnect, or remove the stale
+                // bindings left over from a previous restart.
+                match reconnect_time {
+                    Some(reconnect_time) => gr::helper_start(
+                        nbr,
+                        addr_list,
+                        reconnect_time,
+                        &instance.tx.protocol_input.nbr_gr_timeout,
+                    ),
+                    None => gr::helper_exit(instance, nbr_idx),
+                }
+
+                // Bring down the pseudowires signaled over this session.
+                pw::update_all(instance);
+
                 // Update the neighbor ID to prevent events from the old session
                 // from leaking into a new session.
                 let id = instance.state.neighbors.next_id();
@@ -462,11 +582,11 @@ impl Neighbor {
     pub(crate) fn connect<I: Into<IpAddr>>(
         &mut self,
         local_addr: I,
-        password: Option<&str>,
+        auth: Option<TcpAuth>,
         tcp_connectp: &Sender<TcpConnectMsg>,
     ) {
         let local_addr = local_addr.into();
-        let task = tasks::tcp_connect(self, local_addr, password, tcp_connectp);
+        let task = tasks::tcp_connect(self, local_addr, auth, tcp_connectp);
         self.tasks.connect = Some(task);
     }
 
@@ -481,6 +601,20 @@ impl Neighbor {
         self.conn_info = Some(conn_info);
         self.uptime = Some(Instant::now());
 
+        // Keep a handle to the connected socket, used to update its TCP
+        // authentication keys.
+        #[cfg(not(feature = "testing"))]
+        {
+            use holo_utils::socket::TcpStreamExt;
+
+            use crate::error::IoError;
+
+            self.conn_socket = stream
+                .try_clone_socket()
+                .map_err(|error| IoError::TcpSocketError(error).log())
+                .ok();
+        }
+
         // Split TCP stream into two halves.
         let (read_half, write_half) = stream.into_split();
 
@@ -507,25 +641,80 @@ impl Neighbor {
 
     pub(crate) fn close_session(&mut self) {
         self.conn_info = None;
+        self.conn_socket = None;
         self.kalive_holdtime_rcvd = None;
         self.kalive_holdtime_negotiated = None;
         self.rcvd_label_adv_mode = None;
+        self.ft_session_rcvd = None;
         self.addr_list.clear();
         self.rcvd_mappings.clear();
         self.sent_mappings.clear();
         self.rcvd_requests.clear();
         self.sent_requests.clear();
         self.sent_withdraws.clear();
+        self.rcvd_pw_mappings.clear();
         self.statistics = Default::default();
         self.uptime = None;
         self.tasks = Default::default();
         self.pdu_txp = None;
+        self.flags.remove(NeighborFlags::EOL_RCVD);
     }
 
     pub(crate) fn is_operational(&self) -> bool {
         self.state == fsm::State::Operational
     }
 
+    // Returns whether the initial label exchange with the neighbor has been
+    // completed.
+    //
+    // Completion can only be detected when the neighbor supports the
+    // Unrecognized Notification capability (End-of-LIB). Otherwise, the
+    // session being operational is considered enough.
+    pub(crate) fn is_label_exchange_complete(&self) -> bool {
+        self.is_operational()
+            && (!self.flags.contains(NeighborFlags::CAP_UNREC_NOTIF)
+                || self.flags.contains(NeighborFlags::EOL_RCVD))
+    }
+
+    // Returns the time to wait for the neighbor to reconnect after an
+    // unexpected session loss, or `None` if either side doesn't support
+    // graceful restart.
+    pub(crate) fn gr_reconnect_time(
+        &self,
+        instance_cfg: &InstanceCfg,
+    ) -> Option<u32> {
+        if !instance_cfg.gr.enabled {
+            return None;
+        }
+
+        // A zero FT Reconnect Timeout means the neighbor doesn't preserve its
+        // MPLS forwarding state across restarts.
+        let ft_session = self.ft_session_rcvd.as_ref()?;
+        if !ft_session.flags.contains(FtFlags::LEARN)
+            || ft_session.reconnect_time == 0
+        {
+            return None;
+        }
+
+        let reconnect_time = ft_session.reconnect_time.div_ceil(1000);
+        Some(std::cmp::min(
+            reconnect_time,
+            instance_cfg.gr.reconnect_time.into(),
+        ))
+    }
+
+    // Returns the time to wait for the neighbor to refresh its label bindings
+    // after reconnecting. A value of zero means the stale bindings should be
+    // removed immediately.
+    pub(crate) fn gr_recovery_time(&self, instance_cfg: &InstanceCfg) -> u32 {
+        let recovery_time = self
+            .ft_session_rcvd
+            .as_ref()
+            .map(|ft_session| ft_session.recovery_time.div_ceil(1000))
+            .unwrap_or(0);
+        std::cmp::min(recovery_time, instance_cfg.gr.recovery_time.into())
+    }
+
     pub(crate) fn kalive_timeout_remaining(&self) -> Option<Duration> {
         self.tasks
             .kalive_timeout
@@ -557,6 +746,7 @@ impl Neighbor {
         &mut self,
         instance_cfg: &InstanceCfg,
         msg_id: &Arc<AtomicU32>,
+        ft_session: Option<TlvFtSession>,
     ) {
         let msg = InitMsg {
             msg_id: InstanceState::get_next_msg_id(msg_id),
@@ -572,6 +762,7 @@ impl Neighbor {
             cap_dynamic: Some(TlvCapDynamic()),
             cap_twcard_fec: Some(TlvCapTwcardFec(true)),
             cap_unrec_notif: Some(TlvCapUnrecNotif(true)),
+            ft_session,
         };
         self.send_message(msg, true);
     }
@@ -622,6 +813,7 @@ impl Neighbor {
                     wcard_fec,
                 ))])
             }),
+            pw_status: None,
         };
         self.send_message(msg, true);
     }
@@ -653,12 +845,12 @@ impl Neighbor {
         &mut self,
         msg_id: &Arc<AtomicU32>,
         msg_type: AddressMessageType,
-        addr_list: BTreeSet<Ipv4Addr>,
+        addr_list: TlvAddressList,
     ) {
         let msg = AddressMsg {
             msg_id: InstanceState::get_next_msg_id(msg_id),
             msg_type,
-            addr_list: TlvAddressList::Ipv4(addr_list),
+            addr_list,
         };
         self.send_message(msg, false);
     }
@@ -681,6 +873,9 @@ impl Neighbor {
             fec,
             label,
             request_id,
+            pw_status: None,
+            pw_if_params: None,
+            pw_group_id: None,
         };
         self.send_message(msg, false);
     }
@@ -758,6 +953,68 @@ impl Neighbor {
             None,
         );
     }
+
+    pub(crate) fn send_pw_mapping(
+        &mut self,
+        msg_id: &Arc<AtomicU32>,
+        fec_elem: FecElem,
+        label: Label,
+        status: PwStatusFlags,
+        if_params: Option<TlvPwIfParams>,
+        group_id: Option<u32>,
+    ) {
+        let msg = LabelMsg {
+            msg_id: InstanceState::get_next_msg_id(msg_id),
+            msg_type: LabelMessageType::LabelMapping,
+            fec: TlvFec(vec![fec_elem]),
+            label: Some(TlvLabel(label)),
+            request_id: None,
+            pw_status: Some(TlvPwStatus(status)),
+            pw_if_params: if_params,
+            pw_group_id: group_id.map(TlvPwGroupId),
+        };
+        self.send_message(msg, false);
+    }
+
+    pub(crate) fn send_pw_withdraw(
+        &mut self,
+        msg_id: &Arc<AtomicU32>,
+        fec_elem: FecElem,
+        label: Label,
+    ) {
+        self.send_label(
+            msg_id,
+            LabelMessageType::LabelWithdraw,
+            fec_elem,
+            Some(label),
+            None,
+        );
+    }
+
+    // Signals a PW status change using the Notification message (RFC 8077 -
+    // Section 5.4.3).
+    pub(crate) fn send_pw_status(
+        &mut self,
+        msg_id: &Arc<AtomicU32>,
+        fec_elem: FecElem,
+        status: PwStatusFlags,
+    ) {
+        let msg = NotifMsg {
+            msg_id: InstanceState::get_next_msg_id(msg_id),
+            status: TlvStatus {
+                status_code: StatusCode::PwStatus.encode(false),
+                msg_id: 0,
+                msg_type: 0,
+            },
+            ext_status: None,
+            returned_pdu: None,
+            returned_msg: None,
+            returned_tlvs: None,
+            fec: Some(TlvFec(vec![fec_elem])),
+            pw_status: Some(TlvPwStatus(status)),
+        };
+        self.send_message(msg, false);
+    }
 }
 
 impl Drop for Neighbor {
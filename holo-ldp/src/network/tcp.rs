@@ -9,9 +9,10 @@ use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::sync::Arc;
 use std::time::Duration;
 
+use holo_utils::ip::{AddressFamily, IpAddrKind};
 use holo_utils::socket::{
-    OwnedReadHalf, OwnedWriteHalf, SocketExt, TcpConnInfo, TcpListener,
-    TcpSocket, TcpSocketExt, TcpStream, TcpStreamExt, TTL_MAX,
+    OwnedReadHalf, OwnedWriteHalf, Socket, SocketExt, TcpAuth, TcpConnInfo,
+    TcpListener, TcpSocket, TcpSocketExt, TcpStream, TcpStreamExt, TTL_MAX,
 };
 use holo_utils::task::TimeoutTask;
 use holo_utils::{capabilities, Sender, UnboundedReceiver};
@@ -45,8 +46,16 @@ pub(crate) async fn listen_socket(
         })?;
 
         // Set socket options.
-        socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
-        socket.set_ipv4_ttl(TTL_MAX)?;
+        match addr.address_family() {
+            AddressFamily::Ipv4 => {
+                socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+                socket.set_ipv4_ttl(TTL_MAX)?;
+            }
+            AddressFamily::Ipv6 => {
+                socket.set_ipv6_tclass(libc::IPTOS_PREC_INTERNETCONTROL)?;
+                socket.set_ipv6_unicast_hops(TTL_MAX)?;
+            }
+        }
 
         Ok(socket)
     }
@@ -56,14 +65,27 @@ pub(crate) async fn listen_socket(
     }
 }
 
-pub(crate) fn listen_socket_md5sig_update(
+pub(crate) fn listen_socket_auth_update(
     socket: &TcpListener,
     nbr_addr: &IpAddr,
-    password: Option<&str>,
+    auth: Option<&TcpAuth>,
+) {
+    #[cfg(not(feature = "testing"))]
+    {
+        if let Err(error) = socket.set_tcp_auth(nbr_addr, auth) {
+            IoError::TcpAuthError(error).log();
+        }
+    }
+}
+
+pub(crate) fn conn_socket_auth_update(
+    socket: &Socket,
+    nbr_addr: &IpAddr,
+    auth: &TcpAuth,
 ) {
     #[cfg(not(feature = "testing"))]
     {
-        if let Err(error) = socket.set_md5sig(nbr_addr, password) {
+        if let Err(error) = socket.set_tcp_auth(nbr_addr, Some(auth)) {
             IoError::TcpAuthError(error).log();
         }
     }
@@ -101,13 +123,28 @@ fn connect_socket(
     gtsm: bool,
 ) -> Result<TcpSocket, std::io::Error> {
     let sockaddr = SocketAddr::from((local_addr, 0));
-    let socket = TcpSocket::new_v4()?;
+    let socket = match local_addr.address_family() {
+        AddressFamily::Ipv4 => {
+            let socket = TcpSocket::new_v4()?;
+            socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            if gtsm {
+                socket.set_ipv4_ttl(TTL_MAX)?;
+                socket.set_ipv4_minttl(TTL_MAX)?;
+            }
+            socket
+        }
+        AddressFamily::Ipv6 => {
+            let socket = TcpSocket::new_v6()?;
+            socket.set_ipv6_only(true)?;
+            socket.set_ipv6_tclass(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            if gtsm {
+                socket.set_ipv6_unicast_hops(TTL_MAX)?;
+                socket.set_ipv6_min_hopcount(TTL_MAX)?;
+            }
+            socket
+        }
+    };
     socket.set_reuseaddr(true)?;
-    socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
-    if gtsm {
-        socket.set_ipv4_ttl(TTL_MAX)?;
-        socket.set_ipv4_minttl(TTL_MAX)?;
-    }
 
     socket.bind(sockaddr)?;
     Ok(socket)
@@ -118,16 +155,16 @@ pub(crate) async fn connect(
     local_addr: IpAddr,
     remote_addr: IpAddr,
     gtsm: bool,
-    password: &Option<String>,
+    auth: &Option<TcpAuth>,
 ) -> Result<(TcpStream, TcpConnInfo), Error> {
     // Create TCP socket.
     let socket =
         connect_socket(local_addr, gtsm).map_err(IoError::TcpSocketError)?;
 
-    // Set the TCP MD5 password.
-    if let Some(password) = password {
+    // Set the TCP authentication option (TCP-MD5 or TCP-AO).
+    if let Some(auth) = auth {
         socket
-            .set_md5sig(&remote_addr, Some(password))
+            .set_tcp_auth(&remote_addr, Some(auth))
             .map_err(IoError::TcpAuthError)?;
     }
 
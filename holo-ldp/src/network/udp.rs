@@ -9,7 +9,7 @@ use std::str::FromStr;
 use std::sync::{Arc, LazyLock as Lazy};
 
 use holo_utils::ip::{AddressFamily, IpAddrExt};
-use holo_utils::socket::{SocketExt, UdpSocket, UdpSocketExt};
+use holo_utils::socket::{SocketExt, UdpSocket, UdpSocketExt, TTL_MAX};
 use holo_utils::{capabilities, Sender};
 use tokio::sync::mpsc::error::SendError;
 
@@ -45,7 +45,14 @@ pub(crate) fn discovery_socket(
             capabilities::raise(|| UdpSocket::bind_reuseaddr(sockaddr))?;
 
         // Set socket options.
-        socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+        match addr {
+            IpAddr::V4(_) => {
+                socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            }
+            IpAddr::V6(_) => {
+                socket.set_ipv6_tclass(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            }
+        }
 
         Ok(socket)
     }
@@ -57,19 +64,32 @@ pub(crate) fn discovery_socket(
 
 pub(crate) fn interface_discovery_socket(
     iface: &Interface,
+    af: AddressFamily,
 ) -> Result<UdpSocket, std::io::Error> {
     #[cfg(not(feature = "testing"))]
     {
         // Create and bind socket.
-        let socket = UdpSocket::new(AddressFamily::Ipv4)?;
+        let socket = UdpSocket::new(af)?;
         capabilities::raise(|| {
             socket.bind_device(Some(iface.name.as_bytes()))
         })?;
 
         // Set socket options.
-        socket.set_multicast_loop_v4(false)?;
-        socket.set_multicast_ttl_v4(1)?;
-        socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+        match af {
+            AddressFamily::Ipv4 => {
+                socket.set_multicast_loop_v4(false)?;
+                socket.set_multicast_ttl_v4(1)?;
+                socket.set_ipv4_tos(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            }
+            AddressFamily::Ipv6 => {
+                // IPv6 Link Hellos are sent with the maximum hop limit
+                // (RFC 7552).
+                socket.set_multicast_loop_v6(false)?;
+                socket.set_multicast_if_v6(iface.system.ifindex.unwrap())?;
+                socket.set_ipv6_multicast_hopcount(TTL_MAX)?;
+                socket.set_ipv6_tclass(libc::IPTOS_PREC_INTERNETCONTROL)?;
+            }
+        }
 
         Ok(socket)
     }
@@ -81,6 +101,7 @@ pub(crate) fn interface_discovery_socket(
 
 pub(crate) async fn send_packet_multicast(
     socket: &UdpSocket,
+    af: AddressFamily,
     pdu: Pdu,
 ) -> Result<(), std::io::Error> {
     #[cfg(not(feature = "testing"))]
@@ -89,7 +110,11 @@ pub(crate) async fn send_packet_multicast(
         let buf = pdu.encode(Pdu::DFLT_MAX_LEN);
 
         // Send packet.
-        socket.send_to(&buf, &*LDP_MCAST_SOCKADDR_V4).await?;
+        let sockaddr = match af {
+            AddressFamily::Ipv4 => &*LDP_MCAST_SOCKADDR_V4,
+            AddressFamily::Ipv6 => &*LDP_MCAST_SOCKADDR_V6,
+        };
+        socket.send_to(&buf, sockaddr).await?;
     }
 
     Ok(())
@@ -163,8 +188,13 @@ pub(crate) async fn read_loop(
         cxt.pkt_info.src_addr = src_addr;
         let pdu = Pdu::get_pdu_size(&buf, &cxt)
             .and_then(|pdu_size| Pdu::decode(&buf[0..pdu_size], &cxt));
+        let ifindex = match src {
+            SocketAddr::V6(src) if src.scope_id() != 0 => Some(src.scope_id()),
+            _ => None,
+        };
         let msg = UdpRxPduMsg {
             src_addr,
+            ifindex,
             multicast,
             pdu,
         };
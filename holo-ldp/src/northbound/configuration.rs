@@ -4,8 +4,8 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::HashMap;
-use std::net::Ipv4Addr;
+use std::collections::{BTreeMap, HashMap};
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::LazyLock as Lazy;
 
 use async_trait::async_trait;
@@ -16,14 +16,15 @@ use holo_northbound::configuration::{
 };
 use holo_northbound::paths::control_plane_protocol::mpls_ldp;
 use holo_utils::ibus::IbusMsg;
-use holo_utils::ip::AddressFamily;
 use holo_utils::yang::DataNodeRefExt;
+use holo_yang::TryFromYang;
 
 use crate::collections::{InterfaceIndex, TargetedNbrIndex};
 use crate::debug::InterfaceInactiveReason;
 use crate::discovery::TargetedNbr;
 use crate::instance::Instance;
-use crate::{neighbor, network};
+use crate::pw::PwType;
+use crate::{events, neighbor, policy, pw};
 
 #[derive(Debug, Default, EnumAsInner)]
 pub enum ListEntry {
@@ -32,6 +33,7 @@ pub enum ListEntry {
     Interface(InterfaceIndex),
     TargetedNbr(TargetedNbrIndex),
     Neighbor(Ipv4Addr),
+    Pseudowire(String),
 }
 
 #[derive(Debug)]
@@ -46,11 +48,14 @@ pub enum Event {
     TargetedNbrUpdate(TargetedNbrIndex),
     TargetedNbrRemoveCheck(TargetedNbrIndex),
     TargetedNbrRemoveDynamic,
+    TargetedNbrRemoveProtection,
+    LabelPolicyUpdate,
     StopInitBackoff,
     ResetNeighbors,
     ResetNeighbor(Ipv4Addr),
     UpdateNeighborsAuth,
     UpdateNeighborAuth(Ipv4Addr),
+    PseudowireUpdate(String),
     CfgSeqNumberUpdate,
 }
 
@@ -66,18 +71,56 @@ pub struct InstanceCfg {
     pub session_ka_holdtime: u16,
     pub session_ka_interval: u16,
     pub password: Option<String>,
+    pub keychain: Option<String>,
     pub interface_hello_holdtime: u16,
     pub interface_hello_interval: u16,
     pub targeted_hello_holdtime: u16,
     pub targeted_hello_interval: u16,
     pub targeted_hello_accept: bool,
     pub ipv4: Option<InstanceIpv4Cfg>,
+    pub ipv6: Option<InstanceIpv6Cfg>,
+    pub dual_stack_max_wait: u16,
+    pub dual_stack_prefer_ipv4: bool,
+    pub gr: InstanceGrCfg,
+    pub session_protection: SessionProtectionCfg,
     pub neighbors: HashMap<Ipv4Addr, NeighborCfg>,
+    pub pseudowires: BTreeMap<String, PseudowireCfg>,
+}
+
+#[derive(Debug)]
+pub struct InstanceGrCfg {
+    pub enabled: bool,
+    pub reconnect_time: u16,
+    pub recovery_time: u16,
+    pub forwarding_holdtime: u16,
+}
+
+#[derive(Debug)]
+pub struct SessionProtectionCfg {
+    pub enabled: bool,
+    pub duration: Option<u32>,
 }
 
 #[derive(Debug)]
 pub struct InstanceIpv4Cfg {
     pub enabled: bool,
+    pub label_policy: LabelPolicyCfg,
+}
+
+#[derive(Debug)]
+pub struct InstanceIpv6Cfg {
+    pub enabled: bool,
+    pub trans_addr: Option<Ipv6Addr>,
+    pub label_policy: LabelPolicyCfg,
+}
+
+#[derive(Debug)]
+pub struct LabelPolicyCfg {
+    pub advertise_explicit_null: bool,
+    pub advertise_prefix_list: Option<String>,
+    pub accept_prefix_list: Option<String>,
+    pub assign_prefix_list: Option<String>,
+    pub assign_host_routes_only: bool,
 }
 
 #[derive(Debug)]
@@ -85,6 +128,7 @@ pub struct InterfaceCfg {
     pub hello_holdtime: u16,
     pub hello_interval: u16,
     pub ipv4: Option<InterfaceIpv4Cfg>,
+    pub ipv6: Option<InterfaceIpv6Cfg>,
 }
 
 #[derive(Debug)]
@@ -92,9 +136,23 @@ pub struct InterfaceIpv4Cfg {
     pub enabled: bool,
 }
 
+#[derive(Debug)]
+pub struct InterfaceIpv6Cfg {
+    pub enabled: bool,
+}
+
 #[derive(Debug, Default)]
 pub struct NeighborCfg {
     pub password: Option<String>,
+    pub keychain: Option<String>,
+    pub ipv4: NeighborLabelPolicyCfg,
+    pub ipv6: NeighborLabelPolicyCfg,
+}
+
+#[derive(Debug, Default)]
+pub struct NeighborLabelPolicyCfg {
+    pub advertise_prefix_list: Option<String>,
+    pub accept_prefix_list: Option<String>,
 }
 
 #[derive(Debug)]
@@ -104,6 +162,31 @@ pub struct TargetedNbrCfg {
     pub hello_interval: u16,
 }
 
+#[derive(Debug)]
+pub struct PseudowireCfg {
+    pub interface: Option<String>,
+    pub neighbor: Option<Ipv4Addr>,
+    pub pw_type: PwType,
+    pub control_word: bool,
+    pub mtu: Option<u16>,
+    pub group_id: u32,
+    pub pwid: Option<PwidCfg>,
+    pub gen_pwid: Option<GenPwidCfg>,
+}
+
+#[derive(Debug, Default)]
+pub struct PwidCfg {
+    pub pw_id: u32,
+}
+
+#[derive(Debug)]
+pub struct GenPwidCfg {
+    pub agi: Option<u64>,
+    pub global_id: u32,
+    pub local_ac_id: u32,
+    pub remote_ac_id: u32,
+}
+
 // ===== callbacks =====
 
 fn load_callbacks() -> Callbacks<Instance> {
@@ -139,6 +222,7 @@ fn load_callbacks() -> Callbacks<Instance> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
         })
         .path(mpls_ldp::global::address_families::ipv4::enabled::PATH)
         .modify_apply(|instance, args| {
@@ -149,6 +233,210 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::InstanceUpdate);
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::advertise::egress_explicit_null::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            let label_policy = &mut instance.config.ipv4.as_mut().unwrap().label_policy;
+            label_policy.advertise_explicit_null = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::advertise::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.dnode.get_string();
+            let label_policy = &mut instance.config.ipv4.as_mut().unwrap().label_policy;
+            label_policy.advertise_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let label_policy = &mut instance.config.ipv4.as_mut().unwrap().label_policy;
+            label_policy.advertise_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::accept::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.dnode.get_string();
+            let label_policy = &mut instance.config.ipv4.as_mut().unwrap().label_policy;
+            label_policy.accept_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let label_policy = &mut instance.config.ipv4.as_mut().unwrap().label_policy;
+            label_policy.accept_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::assign::independent_mode::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.dnode.get_string();
+            let label_policy = &mut instance.config.ipv4.as_mut().unwrap().label_policy;
+            label_policy.assign_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let label_policy = &mut instance.config.ipv4.as_mut().unwrap().label_policy;
+            label_policy.assign_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv4::label_policy::assign::independent_mode::host_routes_only::PATH)
+        .modify_apply(|instance, args| {
+            let host_routes_only = args.dnode.get_bool();
+            let label_policy = &mut instance.config.ipv4.as_mut().unwrap().label_policy;
+            label_policy.assign_host_routes_only = host_routes_only;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::PATH)
+        .create_apply(|instance, args| {
+            instance.config.ipv6 = Some(InstanceIpv6Cfg::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ipv6 = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.ipv6.as_mut().unwrap().enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::transport_address::PATH)
+        .modify_apply(|instance, args| {
+            let trans_addr = args.dnode.get_ipv6();
+            instance.config.ipv6.as_mut().unwrap().trans_addr = Some(trans_addr);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::ResetNeighbors);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::advertise::egress_explicit_null::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            let label_policy = &mut instance.config.ipv6.as_mut().unwrap().label_policy;
+            label_policy.advertise_explicit_null = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::advertise::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.dnode.get_string();
+            let label_policy = &mut instance.config.ipv6.as_mut().unwrap().label_policy;
+            label_policy.advertise_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let label_policy = &mut instance.config.ipv6.as_mut().unwrap().label_policy;
+            label_policy.advertise_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::accept::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.dnode.get_string();
+            let label_policy = &mut instance.config.ipv6.as_mut().unwrap().label_policy;
+            label_policy.accept_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let label_policy = &mut instance.config.ipv6.as_mut().unwrap().label_policy;
+            label_policy.accept_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::assign::independent_mode::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.dnode.get_string();
+            let label_policy = &mut instance.config.ipv6.as_mut().unwrap().label_policy;
+            label_policy.assign_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let label_policy = &mut instance.config.ipv6.as_mut().unwrap().label_policy;
+            label_policy.assign_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::address_families::ipv6::label_policy::assign::independent_mode::host_routes_only::PATH)
+        .modify_apply(|instance, args| {
+            let host_routes_only = args.dnode.get_bool();
+            let label_policy = &mut instance.config.ipv6.as_mut().unwrap().label_policy;
+            label_policy.assign_host_routes_only = host_routes_only;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::global::graceful_restart::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.gr.enabled = enabled;
+        })
+        .path(mpls_ldp::global::graceful_restart::reconnect_time::PATH)
+        .modify_apply(|instance, args| {
+            let reconnect_time = args.dnode.get_u16();
+            instance.config.gr.reconnect_time = reconnect_time;
+        })
+        .path(mpls_ldp::global::graceful_restart::recovery_time::PATH)
+        .modify_apply(|instance, args| {
+            let recovery_time = args.dnode.get_u16();
+            instance.config.gr.recovery_time = recovery_time;
+        })
+        .path(mpls_ldp::global::graceful_restart::forwarding_holdtime::PATH)
+        .modify_apply(|instance, args| {
+            let forwarding_holdtime = args.dnode.get_u16();
+            instance.config.gr.forwarding_holdtime = forwarding_holdtime;
+        })
+        .path(mpls_ldp::global::session_protection::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.session_protection.enabled = enabled;
+
+            if !enabled {
+                let event_queue = args.event_queue;
+                event_queue.insert(Event::TargetedNbrRemoveProtection);
+            }
+        })
+        .path(mpls_ldp::global::session_protection::duration::PATH)
+        .modify_apply(|instance, args| {
+            let duration = args.dnode.get_u32();
+            instance.config.session_protection.duration = Some(duration);
+        })
+        .delete_apply(|instance, _args| {
+            instance.config.session_protection.duration = None;
+        })
         .path(mpls_ldp::discovery::interfaces::hello_holdtime::PATH)
         .modify_apply(|instance, args| {
             let hello_holdtime = args.dnode.get_u16();
@@ -231,6 +519,39 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::InterfaceUpdate(iface_idx));
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::PATH)
+        .create_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.ipv6 = Some(InterfaceIpv6Cfg::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceUpdate(iface_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            iface.config.ipv6 = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceUpdate(iface_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let iface_idx = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.ipv6.as_mut().unwrap().enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceUpdate(iface_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
         .path(mpls_ldp::discovery::targeted::hello_holdtime::PATH)
         .modify_apply(|instance, args| {
             let hello_holdtime = args.dnode.get_u16();
@@ -269,7 +590,11 @@ fn load_callbacks() -> Callbacks<Instance> {
             // Nothing to do.
         })
         .delete_apply(|instance, args| {
-            for tnbr in instance.tneighbors.iter_mut() {
+            for tnbr in instance
+                .tneighbors
+                .iter_mut()
+                .filter(|tnbr| tnbr.addr.is_ipv4())
+            {
                 tnbr.config.enabled = false;
             }
 
@@ -320,6 +645,66 @@ fn load_callbacks() -> Callbacks<Instance> {
             event_queue.insert(Event::TargetedNbrUpdate(tnbr_idx));
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::PATH)
+        .create_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .delete_apply(|instance, args| {
+            for tnbr in instance
+                .tneighbors
+                .iter_mut()
+                .filter(|tnbr| tnbr.addr.is_ipv6())
+            {
+                tnbr.config.enabled = false;
+            }
+
+            let event_queue = args.event_queue;
+            for tnbr_idx in instance.tneighbors.indexes() {
+                event_queue.insert(Event::TargetedNbrRemoveCheck(tnbr_idx));
+            }
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::target::PATH)
+        .create_apply(|instance, args| {
+            let addr = args.dnode.get_ip_relative("adjacent-address").unwrap();
+            let (tnbr_index, tnbr) =
+                instance.tneighbors.insert(addr);
+            tnbr.configured = true;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TargetedNbrUpdate(tnbr_index));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let tnbr_idx = args.list_entry.into_targeted_nbr().unwrap();
+            let tnbr = &mut instance.tneighbors[tnbr_idx];
+
+            tnbr.configured = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TargetedNbrRemoveCheck(tnbr_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .lookup(|instance, _list_entry, dnode| {
+            let addr = dnode.get_ip_relative("./adjacent-address").unwrap();
+            instance
+                .tneighbors
+                .get_mut_by_addr(&addr)
+                .map(|(tnbr_idx, _)| ListEntry::TargetedNbr(tnbr_idx))
+                .expect("could not find LDP targeted neighbor")
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::target::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let tnbr_idx = args.list_entry.into_targeted_nbr().unwrap();
+            let tnbr = &mut instance.tneighbors[tnbr_idx];
+
+            let enabled = args.dnode.get_bool();
+            tnbr.config.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TargetedNbrUpdate(tnbr_idx));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
         .path(mpls_ldp::peers::authentication::key::PATH)
         .modify_apply(|instance, args| {
             let password = args.dnode.get_string();
@@ -346,6 +731,24 @@ fn load_callbacks() -> Callbacks<Instance> {
         .delete_apply(|_context, _args| {
             // Nothing to do (only TCP MD5 is supported at the moment).
         })
+        .path(mpls_ldp::peers::authentication::key_chain::PATH)
+        .modify_apply(|instance, args| {
+            let keychain = args.dnode.get_string();
+            instance.config.keychain = Some(keychain);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ResetNeighbors);
+            event_queue.insert(Event::UpdateNeighborsAuth);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.keychain = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ResetNeighbors);
+            event_queue.insert(Event::UpdateNeighborsAuth);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
         .path(mpls_ldp::peers::session_ka_holdtime::PATH)
         .modify_apply(|instance, args| {
             let holdtime = args.dnode.get_u16();
@@ -363,6 +766,28 @@ fn load_callbacks() -> Callbacks<Instance> {
             let event_queue = args.event_queue;
             event_queue.insert(Event::CfgSeqNumberUpdate);
         })
+        .path(mpls_ldp::peers::dual_stack_transport_preference::max_wait::PATH)
+        .modify_apply(|instance, args| {
+            let max_wait = args.dnode.get_u16();
+            instance.config.dual_stack_max_wait = max_wait;
+        })
+        .path(mpls_ldp::peers::dual_stack_transport_preference::prefer_ipv4::PATH)
+        .create_apply(|instance, args| {
+            instance.config.dual_stack_prefer_ipv4 = true;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::ResetNeighbors);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.dual_stack_prefer_ipv4 = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::ResetNeighbors);
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
         .path(mpls_ldp::peers::peer::PATH)
         .create_apply(|instance, args| {
             let lsr_id = args.dnode.get_ipv4_relative("lsr-id").unwrap();
@@ -371,6 +796,9 @@ fn load_callbacks() -> Callbacks<Instance> {
         .delete_apply(|instance, args| {
             let lsr_id = args.list_entry.into_neighbor().unwrap();
             instance.config.neighbors.remove(&lsr_id);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
         })
         .lookup(|_instance, _list_entry, dnode| {
             let lsr_id = dnode.get_ipv4_relative("lsr-id").unwrap();
@@ -407,6 +835,30 @@ fn load_callbacks() -> Callbacks<Instance> {
         .delete_apply(|_instance, _args| {
             // Nothing to do (only TCP MD5 is supported at the moment).
         })
+        .path(mpls_ldp::peers::peer::authentication::key_chain::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let keychain = args.dnode.get_string();
+            nbr_cfg.keychain = Some(keychain);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ResetNeighbor(lsr_id));
+            event_queue.insert(Event::UpdateNeighborAuth(lsr_id));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.keychain = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ResetNeighbor(lsr_id));
+            event_queue.insert(Event::UpdateNeighborAuth(lsr_id));
+            event_queue.insert(Event::CfgSeqNumberUpdate);
+        })
         .path(mpls_ldp::peers::peer::address_families::ipv4::PATH)
         .create_apply(|_instance, _args| {
             // Nothing to do.
@@ -414,6 +866,290 @@ fn load_callbacks() -> Callbacks<Instance> {
         .delete_apply(|_instance, _args| {
             // Nothing to do.
         })
+        .path(mpls_ldp::peers::peer::address_families::ipv4::label_policy::advertise::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let name = args.dnode.get_string();
+            nbr_cfg.ipv4.advertise_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv4.advertise_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv4::label_policy::accept::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let name = args.dnode.get_string();
+            nbr_cfg.ipv4.accept_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv4.accept_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::PATH)
+        .create_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::label_policy::advertise::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let name = args.dnode.get_string();
+            nbr_cfg.ipv6.advertise_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv6.advertise_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::label_policy::accept::prefix_list::PATH)
+        .modify_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            let name = args.dnode.get_string();
+            nbr_cfg.ipv6.accept_prefix_list = Some(name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .delete_apply(|instance, args| {
+            let lsr_id = args.list_entry.into_neighbor().unwrap();
+            let nbr_cfg = instance.config.neighbors.get_mut(&lsr_id).unwrap();
+
+            nbr_cfg.ipv6.accept_prefix_list = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LabelPolicyUpdate);
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::PATH)
+        .create_apply(|instance, args| {
+            let name = args.dnode.get_string_relative("name").unwrap();
+            instance.config.pseudowires.insert(name.clone(), Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .delete_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            instance.config.pseudowires.remove(&name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .lookup(|_instance, _list_entry, dnode| {
+            let name = dnode.get_string_relative("name").unwrap();
+            ListEntry::Pseudowire(name)
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::interface::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let ifname = args.dnode.get_string();
+            pw_cfg.interface = Some(ifname.clone());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+            event_queue.insert(Event::InterfaceQuerySouthbound(ifname));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::neighbor::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let neighbor = args.dnode.get_ipv4();
+            pw_cfg.neighbor = Some(neighbor);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::pw_type::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let pw_type = args.dnode.get_string();
+            pw_cfg.pw_type = PwType::try_from_yang(&pw_type).unwrap();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::control_word::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let control_word = args.dnode.get_bool();
+            pw_cfg.control_word = control_word;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::mtu::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let mtu = args.dnode.get_u16();
+            pw_cfg.mtu = Some(mtu);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .delete_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            pw_cfg.mtu = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::group_id::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let group_id = args.dnode.get_u32();
+            pw_cfg.group_id = group_id;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::pwid::PATH)
+        .create_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            pw_cfg.pwid = Some(Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .delete_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            pw_cfg.pwid = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::pwid::pw_id::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let pw_id = args.dnode.get_u32();
+            pw_cfg.pwid.as_mut().unwrap().pw_id = pw_id;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::generalized_pwid::PATH)
+        .create_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            pw_cfg.gen_pwid = Some(Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .delete_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            pw_cfg.gen_pwid = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::generalized_pwid::agi::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let agi = args.dnode.get_u64();
+            pw_cfg.gen_pwid.as_mut().unwrap().agi = Some(agi);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .delete_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            pw_cfg.gen_pwid.as_mut().unwrap().agi = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::generalized_pwid::global_id::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let global_id = args.dnode.get_u32();
+            pw_cfg.gen_pwid.as_mut().unwrap().global_id = global_id;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::generalized_pwid::local_ac_id::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let local_ac_id = args.dnode.get_u32();
+            pw_cfg.gen_pwid.as_mut().unwrap().local_ac_id = local_ac_id;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::generalized_pwid::remote_ac_id::PATH)
+        .modify_apply(|instance, args| {
+            let name = args.list_entry.into_pseudowire().unwrap();
+            let pw_cfg = instance.config.pseudowires.get_mut(&name).unwrap();
+
+            let remote_ac_id = args.dnode.get_u32();
+            pw_cfg.gen_pwid.as_mut().unwrap().remote_ac_id = remote_ac_id;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PseudowireUpdate(name));
+        })
         .build()
 }
 
@@ -471,10 +1207,10 @@ impl Provider for Instance {
             }
             Event::InterfaceQuerySouthbound(ifname) => {
                 if let Some((instance, _, _)) = self.as_up() {
-                    let _ = instance.tx.ibus.send(IbusMsg::InterfaceQuery {
-                        ifname,
-                        af: Some(AddressFamily::Ipv4),
-                    });
+                    let _ = instance
+                        .tx
+                        .ibus
+                        .send(IbusMsg::InterfaceQuery { ifname, af: None });
                 }
             }
             Event::TargetedNbrUpdate(tnbr_idx) => {
@@ -511,6 +1247,25 @@ impl Provider for Instance {
                     }
                 }
             }
+            Event::TargetedNbrRemoveProtection => {
+                if let Some((mut instance, _, tneighbors)) = self.as_up() {
+                    for tnbr_idx in tneighbors.indexes().collect::<Vec<_>>() {
+                        let tnbr = &mut tneighbors[tnbr_idx];
+                        tnbr.session_protection = false;
+                        tnbr.protection_timeout = None;
+                        TargetedNbr::update(
+                            &mut instance,
+                            tneighbors,
+                            tnbr_idx,
+                        );
+                    }
+                }
+            }
+            Event::LabelPolicyUpdate => {
+                if let Some((mut instance, _, _)) = self.as_up() {
+                    policy::process_label_policy_update(&mut instance);
+                }
+            }
             Event::StopInitBackoff => {
                 if let Some((instance, _, _)) = self.as_up() {
                     for nbr in instance.state.neighbors.iter_mut() {
@@ -547,33 +1302,31 @@ impl Provider for Instance {
                 }
             }
             Event::UpdateNeighborsAuth => {
-                if let Some((instance, _, _)) = self.as_up() {
-                    for nbr in instance.state.neighbors.iter_mut() {
-                        let password =
-                            instance.config.get_neighbor_password(nbr.lsr_id);
-                        network::tcp::listen_socket_md5sig_update(
-                            &instance.state.ipv4.session_socket,
-                            &nbr.trans_addr,
-                            password,
-                        );
+                if let Some((mut instance, _, _)) = self.as_up() {
+                    for nbr_idx in
+                        instance.state.neighbors.indexes().collect::<Vec<_>>()
+                    {
+                        events::nbr_tcp_auth_update(&mut instance, nbr_idx);
                     }
                 }
             }
             Event::UpdateNeighborAuth(lsr_id) => {
-                if let Some((instance, _, _)) = self.as_up() {
-                    if let Some((_, nbr)) =
+                if let Some((mut instance, _, _)) = self.as_up() {
+                    if let Some((nbr_idx, _)) =
                         instance.state.neighbors.get_by_lsr_id(&lsr_id)
                     {
-                        let password =
-                            instance.config.get_neighbor_password(nbr.lsr_id);
-                        network::tcp::listen_socket_md5sig_update(
-                            &instance.state.ipv4.session_socket,
-                            &nbr.trans_addr,
-                            password,
-                        );
+                        events::nbr_tcp_auth_update(&mut instance, nbr_idx);
                     }
                 }
             }
+            Event::PseudowireUpdate(name) => {
+                if let Some((mut instance, _, _)) = self.as_up() {
+                    pw::update(&mut instance, &name);
+                }
+
+                // Synchronize the targeted neighbors used by pseudowires.
+                pw::tneighbors_update(self);
+            }
             Event::CfgSeqNumberUpdate => {
                 if let Some((instance, interfaces, tneighbors)) = self.as_up() {
                     instance.state.cfg_seqno += 1;
@@ -594,6 +1347,10 @@ impl Provider for Instance {
                 }
             }
         }
+
+        // Interface or instance state changes might affect LDP-IGP
+        // synchronization.
+        self.igp_sync_update();
     }
 }
 
@@ -624,19 +1381,58 @@ impl Default for InstanceCfg {
             mpls_ldp::discovery::targeted::hello_interval::DFLT;
         let targeted_hello_accept =
             mpls_ldp::discovery::targeted::hello_accept::enabled::DFLT;
+        let dual_stack_max_wait =
+            mpls_ldp::peers::dual_stack_transport_preference::max_wait::DFLT;
 
         InstanceCfg {
             router_id: None,
             session_ka_holdtime,
             session_ka_interval,
             password: None,
+            keychain: None,
             interface_hello_holdtime,
             interface_hello_interval,
             targeted_hello_holdtime,
             targeted_hello_interval,
             targeted_hello_accept,
             ipv4: None,
+            ipv6: None,
+            dual_stack_max_wait,
+            dual_stack_prefer_ipv4: false,
+            gr: Default::default(),
+            session_protection: Default::default(),
             neighbors: Default::default(),
+            pseudowires: Default::default(),
+        }
+    }
+}
+
+impl Default for InstanceGrCfg {
+    fn default() -> InstanceGrCfg {
+        let enabled = mpls_ldp::global::graceful_restart::enabled::DFLT;
+        let reconnect_time =
+            mpls_ldp::global::graceful_restart::reconnect_time::DFLT;
+        let recovery_time =
+            mpls_ldp::global::graceful_restart::recovery_time::DFLT;
+        let forwarding_holdtime =
+            mpls_ldp::global::graceful_restart::forwarding_holdtime::DFLT;
+
+        InstanceGrCfg {
+            enabled,
+            reconnect_time,
+            recovery_time,
+            forwarding_holdtime,
+        }
+    }
+}
+
+impl Default for SessionProtectionCfg {
+    fn default() -> SessionProtectionCfg {
+        let enabled = mpls_ldp::global::session_protection::enabled::DFLT;
+
+        SessionProtectionCfg {
+            enabled,
+            duration: None,
         }
     }
 }
@@ -646,7 +1442,37 @@ impl Default for InstanceIpv4Cfg {
         let enabled =
             mpls_ldp::discovery::targeted::address_families::ipv4::target::enabled::DFLT;
 
-        InstanceIpv4Cfg { enabled }
+        InstanceIpv4Cfg {
+            enabled,
+            label_policy: Default::default(),
+        }
+    }
+}
+
+impl Default for InstanceIpv6Cfg {
+    fn default() -> InstanceIpv6Cfg {
+        let enabled = mpls_ldp::global::address_families::ipv6::enabled::DFLT;
+
+        InstanceIpv6Cfg {
+            enabled,
+            trans_addr: None,
+            label_policy: Default::default(),
+        }
+    }
+}
+
+impl Default for LabelPolicyCfg {
+    fn default() -> LabelPolicyCfg {
+        let advertise_explicit_null = mpls_ldp::global::address_families::ipv4::label_policy::advertise::egress_explicit_null::enabled::DFLT;
+        let assign_host_routes_only = mpls_ldp::global::address_families::ipv4::label_policy::assign::independent_mode::host_routes_only::DFLT;
+
+        LabelPolicyCfg {
+            advertise_explicit_null,
+            advertise_prefix_list: None,
+            accept_prefix_list: None,
+            assign_prefix_list: None,
+            assign_host_routes_only,
+        }
     }
 }
 
@@ -661,6 +1487,7 @@ impl Default for InterfaceCfg {
             hello_holdtime,
             hello_interval,
             ipv4: None,
+            ipv6: None,
         }
     }
 }
@@ -674,6 +1501,15 @@ impl Default for InterfaceIpv4Cfg {
     }
 }
 
+impl Default for InterfaceIpv6Cfg {
+    fn default() -> InterfaceIpv6Cfg {
+        let enabled =
+            mpls_ldp::discovery::interfaces::interface::address_families::ipv6::enabled::DFLT;
+
+        InterfaceIpv6Cfg { enabled }
+    }
+}
+
 impl Default for TargetedNbrCfg {
     fn default() -> TargetedNbrCfg {
         let enabled =
@@ -690,3 +1526,38 @@ impl Default for TargetedNbrCfg {
         }
     }
 }
+
+impl Default for PseudowireCfg {
+    fn default() -> PseudowireCfg {
+        let pw_type = mpls_ldp::pseudowires::pseudowire::pw_type::DFLT;
+        let pw_type = PwType::try_from_yang(pw_type).unwrap();
+        let control_word =
+            mpls_ldp::pseudowires::pseudowire::control_word::DFLT;
+        let group_id = mpls_ldp::pseudowires::pseudowire::group_id::DFLT;
+
+        PseudowireCfg {
+            interface: None,
+            neighbor: None,
+            pw_type,
+            control_word,
+            mtu: None,
+            group_id,
+            pwid: None,
+            gen_pwid: None,
+        }
+    }
+}
+
+impl Default for GenPwidCfg {
+    fn default() -> GenPwidCfg {
+        let global_id =
+            mpls_ldp::pseudowires::pseudowire::generalized_pwid::global_id::DFLT;
+
+        GenPwidCfg {
+            agi: None,
+            global_id,
+            local_ac_id: 0,
+            remote_ac_id: 0,
+        }
+    }
+}
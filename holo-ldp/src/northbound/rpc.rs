@@ -142,14 +142,8 @@ fn clear_adjacencies(
     nexthop_addr: Option<IpAddr>,
     tnbr_addr: Option<IpAddr>,
 ) {
-    for adj_idx in instance
-        .state
-        .ipv4
-        .adjacencies
-        .indexes()
-        .collect::<Vec<_>>()
-    {
-        let adjacencies = &mut instance.state.ipv4.adjacencies;
+    for adj_idx in instance.state.adjacencies.indexes().collect::<Vec<_>>() {
+        let adjacencies = &mut instance.state.adjacencies;
         let adj = &adjacencies[adj_idx];
 
         // Filter by source.
@@ -13,6 +13,7 @@ use holo_northbound::paths::control_plane_protocol::mpls_ldp;
 use holo_northbound::state::{
     Callbacks, CallbacksBuilder, ListEntryKind, NodeAttributes, Provider,
 };
+use holo_utils::ip::AddressFamily;
 use holo_utils::mpls::Label;
 use holo_yang::ToYang;
 
@@ -21,6 +22,9 @@ use crate::fec::Fec;
 use crate::instance::Instance;
 use crate::interface::Interface;
 use crate::neighbor::{LabelAdvMode, LabelDistMode, Neighbor, NeighborFlags};
+use crate::packet::messages::initialization::FtFlags;
+use crate::packet::messages::label::PwStatusFlags;
+use crate::pw::Pseudowire;
 
 pub static CALLBACKS: Lazy<Callbacks<Instance>> = Lazy::new(load_callbacks);
 
@@ -36,11 +40,12 @@ pub enum ListEntry<'a> {
     TargetedNbrAdj(&'a Adjacency),
     Neighbor(&'a Neighbor),
     NeighborAdj(&'a Adjacency),
+    Pseudowire(&'a Pseudowire),
 }
 
 #[derive(Debug, new)]
 pub struct AddrBinding {
-    addr: Ipv4Addr,
+    addr: IpAddr,
     adv_type: AdvertisementType,
     lsr_id: Option<Ipv4Addr>,
 }
@@ -85,7 +90,7 @@ fn load_callbacks() -> Callbacks<Instance> {
                     instance.system.ipv4_addr_list.iter().map(
                         |addr| {
                             let binding = AddrBinding::new(
-                                addr.ip(),
+                                addr.ip().into(),
                                 AdvertisementType::Advertised,
                                 None,
                             );
@@ -108,7 +113,7 @@ fn load_callbacks() -> Callbacks<Instance> {
                     })
                     .map(|(nbr, addr)| {
                         let binding = AddrBinding::new(
-                            *addr,
+                            (*addr).into(),
                             AdvertisementType::Received,
                             Some(nbr.lsr_id),
                         );
@@ -201,6 +206,147 @@ fn load_callbacks() -> Callbacks<Instance> {
             let binding = args.list_entry.as_label_binding().unwrap();
             Some(binding.used_in_fwd)
         })
+        .path(mpls_ldp::global::address_families::ipv6::label_distribution_control_mode::PATH)
+        .get_element_string(|instance: &Instance, _args| {
+            instance.config.ipv6.as_ref()?;
+            let mode = LabelDistMode::Independent.to_yang().into();
+            Some(mode)
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::address::PATH)
+        .get_iterate(|instance, _args| {
+            if let Some(instance_state) = &instance.state {
+                // Skip if there's no neighbor in the operational state.
+                if !instance_state
+                    .neighbors
+                    .iter()
+                    .any(|nbr| nbr.is_operational())
+                {
+                    return None;
+                }
+
+                // Advertised addresses.
+                let advertised = instance
+                    .system
+                    .ipv6_addr_list
+                    .iter()
+                    .filter(|addr| !addr.ip().is_unicast_link_local())
+                    .map(|addr| {
+                        let binding = AddrBinding::new(
+                            addr.ip().into(),
+                            AdvertisementType::Advertised,
+                            None,
+                        );
+                        ListEntry::AddrBinding(binding)
+                    });
+
+                // Received addresses.
+                let received = instance_state
+                    .neighbors
+                    .iter()
+                    .flat_map(|nbr| {
+                        nbr.addr_list.iter().filter_map(move |addr| {
+                            if let IpAddr::V6(addr) = addr {
+                                Some((nbr, addr))
+                            } else {
+                                None
+                            }
+                        })
+                    })
+                    .map(|(nbr, addr)| {
+                        let binding = AddrBinding::new(
+                            (*addr).into(),
+                            AdvertisementType::Received,
+                            Some(nbr.lsr_id),
+                        );
+                        ListEntry::AddrBinding(binding)
+                    });
+
+                // Chain advertised and received addresses.
+                Some(Box::new(advertised.chain(received)))
+            } else {
+                None
+            }
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::address::advertisement_type::PATH)
+        .get_element_string(|_instance, args| {
+            let binding = args.list_entry.as_addr_binding().unwrap();
+            Some(binding.adv_type.to_yang().into())
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::address::peer::lsr_id::PATH)
+        .get_element_ipv4(|_instance, args| {
+            let binding = args.list_entry.as_addr_binding().unwrap();
+            binding.lsr_id
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::address::peer::label_space_id::PATH)
+        .get_element_u16(|_instance, args| {
+            let binding = args.list_entry.as_addr_binding().unwrap();
+            binding.lsr_id.map(|_lsr_id| 0)
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::fec_label::PATH)
+        .get_iterate(|instance, _args| {
+            if let Some(instance_state) = &instance.state {
+                let iter = instance_state
+                    .fecs
+                    .values()
+                    .filter(|fec| fec.inner.prefix.is_ipv6())
+                    .filter(|fec| {
+                        !fec.inner.upstream.is_empty()
+                            || !fec.inner.downstream.is_empty()
+                    })
+                    .map(ListEntry::Fec);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::fec_label::peer::PATH)
+        .get_iterate(|instance, args| {
+            let fec = args.parent_list_entry.as_fec().unwrap();
+
+            // Advertised label mappings.
+            let advertised =
+                fec.inner.upstream.iter().map(|(lsr_id, mapping)| {
+                    let binding = LabelBinding::new(
+                        *lsr_id,
+                        AdvertisementType::Advertised,
+                        mapping.label,
+                        true,
+                    );
+                    ListEntry::LabelBinding(binding)
+                });
+
+            // Received label mappings.
+            let received = fec.inner.downstream.iter().filter_map(|(lsr_id, mapping)| {
+                instance
+                    .state
+                    .as_ref()
+                    .unwrap()
+                    .neighbors
+                    .get_by_lsr_id(lsr_id)
+                    .map(|(_, nbr)| {
+                        let binding = LabelBinding::new(
+                            *lsr_id,
+                            AdvertisementType::Received,
+                            mapping.label,
+                            fec.is_nbr_nexthop(nbr),
+                        );
+                        ListEntry::LabelBinding(binding)
+                    })
+            });
+
+            // Chain advertised and received label mappings.
+            Some(Box::new(advertised.chain(received)))
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::fec_label::peer::label::PATH)
+        .get_element_string(|_instance, args| {
+            let binding = args.list_entry.as_label_binding().unwrap();
+            Some(binding.label.to_yang().into())
+        })
+        .path(mpls_ldp::global::address_families::ipv6::bindings::fec_label::peer::used_in_forwarding::PATH)
+        .get_element_bool(|_instance, args| {
+            let binding = args.list_entry.as_label_binding().unwrap();
+            Some(binding.used_in_fwd)
+        })
         .path(mpls_ldp::discovery::interfaces::interface::PATH)
         .get_iterate(|instance, _args| {
             if instance.is_active() {
@@ -218,9 +364,13 @@ fn load_callbacks() -> Callbacks<Instance> {
         .attributes(NodeAttributes::TIME)
         .get_element_u16(|_instance, args| {
             let iface = args.list_entry.as_interface().unwrap();
-            iface.next_hello().map(|remaining| {
-                u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX)
-            })
+            [AddressFamily::Ipv4, AddressFamily::Ipv6]
+                .into_iter()
+                .filter_map(|af| iface.next_hello(af))
+                .min()
+                .map(|remaining| {
+                    u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX)
+                })
         })
         .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv4::hello_adjacencies::hello_adjacency::PATH)
         .get_iterate(|instance, args| {
@@ -229,11 +379,11 @@ fn load_callbacks() -> Callbacks<Instance> {
                 .state
                 .as_ref()
                 .unwrap()
-                .ipv4
                 .adjacencies
                 .iter_by_iface(&iface.name)
                 .into_iter()
                 .flatten()
+                .filter(|adj| adj.source.addr.is_ipv4())
                 .map(ListEntry::InterfaceAdj);
             Some(Box::new(iter))
         })
@@ -289,14 +439,81 @@ fn load_callbacks() -> Callbacks<Instance> {
         .get_element_u16(|_instance, _args| {
             Some(0)
         })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::PATH)
+        .get_iterate(|instance, args| {
+            let iface = args.parent_list_entry.as_interface().unwrap();
+            let iter = instance
+                .state
+                .as_ref()
+                .unwrap()
+                .adjacencies
+                .iter_by_iface(&iface.name)
+                .into_iter()
+                .flatten()
+                .filter(|adj| adj.source.addr.is_ipv6())
+                .map(ListEntry::InterfaceAdj);
+            Some(Box::new(iter))
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::adjacent::PATH)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Some(adj.holdtime_adjacent)
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::negotiated::PATH)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Some(adj.holdtime_negotiated)
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::remaining::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            adj.holdtime_remaining().map(|remaining| {
+                u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX)
+            })
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::next_hello::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_u16(|instance, args| {
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            let remaining = adj.next_hello(&instance.interfaces, &instance.tneighbors);
+            Some(u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX))
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::discontinuity_time::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_date_and_time(|_instance, args| {
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Some(adj.discontinuity_time)
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::hello_received::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u64(|_instance, args| {
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Some(adj.hello_rcvd)
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::hello_dropped::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u64(|_instance, args| {
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Some(adj.hello_dropped)
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::peer::lsr_id::PATH)
+        .get_element_ipv4(|_instance, args| {
+            let adj = args.list_entry.as_interface_adj().unwrap();
+            Some(adj.lsr_id)
+        })
+        .path(mpls_ldp::discovery::interfaces::interface::address_families::ipv6::hello_adjacencies::hello_adjacency::peer::label_space_id::PATH)
+        .get_element_u16(|_instance, _args| {
+            Some(0)
+        })
         .path(mpls_ldp::discovery::targeted::address_families::ipv4::hello_adjacencies::hello_adjacency::PATH)
         .get_iterate(|instance, _args| {
             if let Some(instance_state) = &instance.state {
                 let iter = instance_state
-                    .ipv4
                     .adjacencies
                     .iter()
                     .filter(|adj| adj.source.ifname.is_none())
+                    .filter(|adj| adj.source.addr.is_ipv4())
                     .map(ListEntry::TargetedNbrAdj);
                 Some(Box::new(iter))
             } else {
@@ -360,6 +577,77 @@ fn load_callbacks() -> Callbacks<Instance> {
             // No operational data under this list.
             None
         })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::PATH)
+        .get_iterate(|instance, _args| {
+            if let Some(instance_state) = &instance.state {
+                let iter = instance_state
+                    .adjacencies
+                    .iter()
+                    .filter(|adj| adj.source.ifname.is_none())
+                    .filter(|adj| adj.source.addr.is_ipv6())
+                    .map(ListEntry::TargetedNbrAdj);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::adjacent::PATH)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Some(adj.holdtime_adjacent)
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::negotiated::PATH)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Some(adj.holdtime_negotiated)
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::remaining::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            adj.holdtime_remaining().map(|remaining| {
+                u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX)
+            })
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::next_hello::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_u16(|instance, args| {
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            let remaining = adj.next_hello(&instance.interfaces, &instance.tneighbors);
+            Some(u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX))
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::discontinuity_time::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_date_and_time(|_instance, args| {
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Some(adj.discontinuity_time)
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::hello_received::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u64(|_instance, args| {
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Some(adj.hello_rcvd)
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::hello_dropped::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u64(|_instance, args| {
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Some(adj.hello_dropped)
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::peer::lsr_id::PATH)
+        .get_element_ipv4(|_instance, args| {
+            let adj = args.list_entry.as_targeted_nbr_adj().unwrap();
+            Some(adj.lsr_id)
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::hello_adjacencies::hello_adjacency::peer::label_space_id::PATH)
+        .get_element_u16(|_instance, _args| {
+            Some(0)
+        })
+        .path(mpls_ldp::discovery::targeted::address_families::ipv6::target::PATH)
+        .get_iterate(|_instance, _args| {
+            // No operational data under this list.
+            None
+        })
         .path(mpls_ldp::peers::peer::PATH)
         .get_iterate(|instance, _args| {
             if let Some(instance_state) = &instance.state {
@@ -379,11 +667,11 @@ fn load_callbacks() -> Callbacks<Instance> {
                 .state
                 .as_ref()
                 .unwrap()
-                .ipv4
                 .adjacencies
                 .iter_by_lsr_id(&nbr.lsr_id)
                 .into_iter()
                 .flatten()
+                .filter(|adj| adj.source.addr.is_ipv4())
                 .map(ListEntry::NeighborAdj);
             Some(Box::new(iter))
         })
@@ -430,6 +718,64 @@ fn load_callbacks() -> Callbacks<Instance> {
             let adj = args.list_entry.as_neighbor_adj().unwrap();
             Some(adj.hello_dropped)
         })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::PATH)
+        .get_iterate(|instance, args| {
+            let nbr = args.parent_list_entry.as_neighbor().unwrap();
+            let iter = instance
+                .state
+                .as_ref()
+                .unwrap()
+                .adjacencies
+                .iter_by_lsr_id(&nbr.lsr_id)
+                .into_iter()
+                .flatten()
+                .filter(|adj| adj.source.addr.is_ipv6())
+                .map(ListEntry::NeighborAdj);
+            Some(Box::new(iter))
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::adjacent::PATH)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            Some(adj.holdtime_adjacent)
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::negotiated::PATH)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            Some(adj.holdtime_negotiated)
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::hello_holdtime::remaining::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_u16(|_instance, args| {
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            adj.holdtime_remaining().map(|remaining| {
+                u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX)
+            })
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::next_hello::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_u16(|instance, args| {
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            let remaining = adj.next_hello(&instance.interfaces, &instance.tneighbors);
+            Some(u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX))
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::discontinuity_time::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_date_and_time(|_instance, args| {
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            Some(adj.discontinuity_time)
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::hello_received::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u64(|_instance, args| {
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            Some(adj.hello_rcvd)
+        })
+        .path(mpls_ldp::peers::peer::address_families::ipv6::hello_adjacencies::hello_adjacency::statistics::hello_dropped::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u64(|_instance, args| {
+            let adj = args.list_entry.as_neighbor_adj().unwrap();
+            Some(adj.hello_dropped)
+        })
         .path(mpls_ldp::peers::peer::label_advertisement_mode::local::PATH)
         .get_element_string(|_instance, args| {
             let nbr = args.list_entry.as_neighbor().unwrap();
@@ -461,6 +807,27 @@ fn load_callbacks() -> Callbacks<Instance> {
                 u16::try_from(remaining.as_secs()).unwrap_or(u16::MAX)
             })
         })
+        .path(mpls_ldp::peers::peer::received_peer_state::graceful_restart::enabled::PATH)
+        .get_element_bool(|_instance, args| {
+            let nbr = args.list_entry.as_neighbor().unwrap();
+            nbr.ft_session_rcvd.as_ref().map(|tlv| {
+                tlv.flags.contains(FtFlags::LEARN) && tlv.reconnect_time != 0
+            })
+        })
+        .path(mpls_ldp::peers::peer::received_peer_state::graceful_restart::reconnect_time::PATH)
+        .get_element_u16(|_instance, args| {
+            let nbr = args.list_entry.as_neighbor().unwrap();
+            nbr.ft_session_rcvd.as_ref().map(|tlv| {
+                u16::try_from(tlv.reconnect_time / 1000).unwrap_or(u16::MAX)
+            })
+        })
+        .path(mpls_ldp::peers::peer::received_peer_state::graceful_restart::recovery_time::PATH)
+        .get_element_u16(|_instance, args| {
+            let nbr = args.list_entry.as_neighbor().unwrap();
+            nbr.ft_session_rcvd.as_ref().map(|tlv| {
+                u16::try_from(tlv.recovery_time / 1000).unwrap_or(u16::MAX)
+            })
+        })
         .path(mpls_ldp::peers::peer::received_peer_state::capability::end_of_lib::enabled::PATH)
         .get_element_bool(|_instance, args| {
             let nbr = args.list_entry.as_neighbor().unwrap();
@@ -705,6 +1072,99 @@ fn load_callbacks() -> Callbacks<Instance> {
                 .count();
             Some(u32::try_from(total).unwrap_or(u32::MAX))
         })
+        .path(mpls_ldp::pseudowires::pseudowire::PATH)
+        .get_iterate(|instance, _args| {
+            if let Some(instance_state) = &instance.state {
+                let iter = instance_state
+                    .pseudowires
+                    .values()
+                    .map(ListEntry::Pseudowire);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::oper_status::PATH)
+        .get_element_string(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            let status = if pw.is_up() { "up" } else { "down" };
+            Some(status.to_owned())
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::local_label::PATH)
+        .get_element_string(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            Some(pw.local_label.to_yang().into())
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::remote_label::PATH)
+        .get_element_string(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            pw.remote.map(|remote| remote.label.to_yang().into())
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::remote_mtu::PATH)
+        .get_element_u16(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            pw.remote.and_then(|remote| remote.mtu)
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::remote_control_word::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            pw.remote.map(|remote| remote.control_word)
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::local_status::not_forwarding::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            Some(pw.local_status.contains(PwStatusFlags::NOT_FORWARDING))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::local_status::ac_rx_fault::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            Some(pw.local_status.contains(PwStatusFlags::AC_RX_FAULT))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::local_status::ac_tx_fault::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            Some(pw.local_status.contains(PwStatusFlags::AC_TX_FAULT))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::local_status::psn_rx_fault::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            Some(pw.local_status.contains(PwStatusFlags::PSN_RX_FAULT))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::local_status::psn_tx_fault::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            Some(pw.local_status.contains(PwStatusFlags::PSN_TX_FAULT))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::remote_status::not_forwarding::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            let status = pw.remote.and_then(|remote| remote.status)?;
+            Some(status.contains(PwStatusFlags::NOT_FORWARDING))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::remote_status::ac_rx_fault::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            let status = pw.remote.and_then(|remote| remote.status)?;
+            Some(status.contains(PwStatusFlags::AC_RX_FAULT))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::remote_status::ac_tx_fault::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            let status = pw.remote.and_then(|remote| remote.status)?;
+            Some(status.contains(PwStatusFlags::AC_TX_FAULT))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::remote_status::psn_rx_fault::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            let status = pw.remote.and_then(|remote| remote.status)?;
+            Some(status.contains(PwStatusFlags::PSN_RX_FAULT))
+        })
+        .path(mpls_ldp::pseudowires::pseudowire::state::remote_status::psn_tx_fault::PATH)
+        .get_element_bool(|_instance, args| {
+            let pw = args.list_entry.as_pseudowire().unwrap();
+            let status = pw.remote.and_then(|remote| remote.status)?;
+            Some(status.contains(PwStatusFlags::PSN_TX_FAULT))
+        })
         .build()
 }
 
@@ -767,6 +1227,11 @@ impl<'a> ListEntryKind for ListEntry<'a> {
                 let keys = list_keys(adj.local_addr, adj.source.addr);
                 Some(keys)
             }
+            ListEntry::Pseudowire(pw) => {
+                use mpls_ldp::pseudowires::pseudowire::list_keys;
+                let keys = list_keys(&pw.name);
+                Some(keys)
+            }
         }
     }
 }
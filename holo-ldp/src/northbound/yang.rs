@@ -6,10 +6,11 @@
 
 use std::borrow::Cow;
 
-use holo_yang::ToYang;
+use holo_yang::{ToYang, TryFromYang};
 
 use crate::neighbor::{self, LabelAdvMode, LabelDistMode};
 use crate::northbound::state::AdvertisementType;
+use crate::pw::PwType;
 
 // ===== ToYang implementations =====
 
@@ -53,3 +54,15 @@ impl ToYang for LabelAdvMode {
         }
     }
 }
+
+// ===== TryFromYang implementations =====
+
+impl TryFromYang for PwType {
+    fn try_from_yang(value: &str) -> Option<PwType> {
+        match value {
+            "ethernet-tagged" => Some(PwType::EthernetTagged),
+            "ethernet" => Some(PwType::Ethernet),
+            _ => None,
+        }
+    }
+}
@@ -9,7 +9,7 @@ use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use bitflags::bitflags;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
 use holo_utils::bytes::{BytesExt, BytesMutExt};
-use holo_utils::ip::{Ipv4AddrExt, Ipv6AddrExt};
+use holo_utils::ip::{AddressFamily, Ipv4AddrExt, Ipv6AddrExt};
 use num_derive::FromPrimitive;
 use num_traits::FromPrimitive;
 use serde::{Deserialize, Serialize};
@@ -410,3 +410,23 @@ impl TlvKind for TlvDualStack {
         Err(DecodeError::InvalidTransportPref(tlvi.clone(), trans_pref))
     }
 }
+
+// ===== impl TransportPref =====
+
+impl From<AddressFamily> for TransportPref {
+    fn from(af: AddressFamily) -> TransportPref {
+        match af {
+            AddressFamily::Ipv4 => TransportPref::LDPOIPV4,
+            AddressFamily::Ipv6 => TransportPref::LDPOIPV6,
+        }
+    }
+}
+
+impl From<TransportPref> for AddressFamily {
+    fn from(trans_pref: TransportPref) -> AddressFamily {
+        match trans_pref {
+            TransportPref::LDPOIPV4 => AddressFamily::Ipv4,
+            TransportPref::LDPOIPV6 => AddressFamily::Ipv6,
+        }
+    }
+}
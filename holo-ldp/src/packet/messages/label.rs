@@ -6,7 +6,9 @@
 
 use std::net::{Ipv4Addr, Ipv6Addr};
 
+use bitflags::bitflags;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
+use holo_utils::bytes::{BytesExt, BytesMutExt};
 use holo_utils::ip::{
     AddressFamily, IpAddrExt, IpNetworkExt, Ipv4AddrExt, Ipv4NetworkExt,
     Ipv6AddrExt, Ipv6NetworkExt,
@@ -105,6 +107,12 @@ pub struct LabelMsg {
     pub label: Option<TlvLabel>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub request_id: Option<TlvLabelRequestId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pw_status: Option<TlvPwStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pw_if_params: Option<TlvPwIfParams>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pw_group_id: Option<TlvPwGroupId>,
 }
 
 //
@@ -142,6 +150,8 @@ pub struct TlvFec(pub Vec<FecElem>);
 pub enum FecElem {
     Wildcard(FecElemWildcard),
     Prefix(IpNetwork),
+    Pwid(FecElemPwid),
+    GenPwid(FecElemGenPwid),
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -157,6 +167,8 @@ pub enum FecElemWildcard {
 pub const TLV_FEC_ELEMENT_WILDCARD: u8 = 1;
 pub const TLV_FEC_ELEMENT_PREFIX: u8 = 2;
 pub const TLV_FEC_ELEMENT_TYPED_WILDCARD: u8 = 5;
+pub const TLV_FEC_ELEMENT_PWID: u8 = 0x80;
+pub const TLV_FEC_ELEMENT_GEN_PWID: u8 = 0x81;
 
 //
 // Typed Wildcard FEC Element value encoding:
@@ -189,6 +201,110 @@ pub enum TypedWildcardFecElem {
     Prefix(AddressFamily),
 }
 
+//
+// PWid FEC Element value encoding (RFC 8077):
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |  PWid (0x80)  |C|         PW type             |PW info Length |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                      Group ID                                 |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                      PW ID                                    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                       Interface Parameter Sub-TLV             |
+// |                                 "                             |
+// |                                 "                             |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+// A zero PW info length means the FEC element refers to all PWs in the given
+// group (wildcard Label Withdraw and Label Release messages only).
+//
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FecElemPwid {
+    pub control_word: bool,
+    pub pw_type: u16,
+    pub group_id: u32,
+    pub pw_id: Option<u32>,
+    pub mtu: Option<u16>,
+}
+
+//
+// Generalized PWid FEC Element value encoding (RFC 8077):
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |Gen PWid (0x81)|C|         PW Type             |PW info Length |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |    AGI Type   |    Length     |      Value                    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+                               |
+// ~                    AGI Value (contd.)                         ~
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |   AII Type    |    Length     |      Value                    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+                               |
+// ~                   SAII Value (contd.)                         ~
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |   AII Type    |    Length     |      Value                    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+                               |
+// ~                   TAII Value (contd.)                         ~
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+// Only the Type 1 AGI (RFC 4446) and the Type 2 AII (RFC 5003) are supported.
+// A zero-length AGI is encoded as `None`.
+//
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct FecElemGenPwid {
+    pub control_word: bool,
+    pub pw_type: u16,
+    pub agi: Option<u64>,
+    pub saii: Aii,
+    pub taii: Aii,
+}
+
+//
+// Type 2 AII value encoding (RFC 5003):
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |  AII Type=02  |    Length     |        Global ID              |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |       Global ID (contd.)      |        Prefix                 |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |       Prefix (contd.)         |        AC ID                  |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |      AC ID (contd.)           |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Aii {
+    pub global_id: u32,
+    pub prefix: Ipv4Addr,
+    pub ac_id: u32,
+}
+
+// PW types (RFC 4446).
+pub const PW_TYPE_ETHERNET_TAGGED: u16 = 0x0004;
+pub const PW_TYPE_ETHERNET: u16 = 0x0005;
+
+// PWid FEC Element C-bit and PW type mask.
+const PW_CONTROL_WORD_FLAG: u16 = 0x8000;
+const PW_TYPE_MASK: u16 = 0x7FFF;
+
+// Interface Parameter Sub-TLV types (RFC 4446).
+const PW_IF_PARAM_MTU: u8 = 0x01;
+
+// Attachment Group and Individual Identifier types.
+const PW_AGI_TYPE1: u8 = 0x01;
+const PW_AGI_TYPE1_LEN: u8 = 8;
+const PW_AII_TYPE2: u8 = 0x02;
+const PW_AII_TYPE2_LEN: u8 = 12;
+
 // Generic Label TLV.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct TlvLabel(pub Label);
@@ -197,6 +313,59 @@ pub struct TlvLabel(pub Label);
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 pub struct TlvLabelRequestId(pub u32);
 
+//
+// PW Status TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |1|0|     PW Status (0x096A)    |            Length             |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                          Status Code                          |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TlvPwStatus(pub PwStatusFlags);
+
+// PW status codes (RFC 8077).
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct PwStatusFlags: u32 {
+        const NOT_FORWARDING = 0x01;
+        const AC_RX_FAULT = 0x02;
+        const AC_TX_FAULT = 0x04;
+        const PSN_RX_FAULT = 0x08;
+        const PSN_TX_FAULT = 0x10;
+    }
+}
+
+//
+// Interface Parameters TLV (Generalized PWid FEC only).
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |0|0|     ID (0x096B)           |            Length             |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                                                               |
+// ~               Interface Parameter Sub-TLVs                    ~
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TlvPwIfParams {
+    pub mtu: Option<u16>,
+}
+
+// PW Grouping ID TLV (Generalized PWid FEC only).
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct TlvPwGroupId(pub u32);
+
 // ===== impl LabelMsg =====
 
 impl MessageKind for LabelMsg {
@@ -221,6 +390,15 @@ impl MessageKind for LabelMsg {
         if let Some(tlv) = &self.request_id {
             tlv.encode(self.msg_type(), buf);
         }
+        if let Some(tlv) = &self.pw_status {
+            tlv.encode(self.msg_type(), buf);
+        }
+        if let Some(tlv) = &self.pw_if_params {
+            tlv.encode(self.msg_type(), buf);
+        }
+        if let Some(tlv) = &self.pw_group_id {
+            tlv.encode(self.msg_type(), buf);
+        }
     }
 
     fn decode_body(
@@ -245,6 +423,9 @@ impl MessageKind for LabelMsg {
             fec,
             label: None,
             request_id: None,
+            pw_status: None,
+            pw_if_params: None,
+            pw_group_id: None,
         };
 
         // Decode optional TLV(s).
@@ -296,6 +477,20 @@ impl MessageKind for LabelMsg {
             {
                 return Err(DecodeError::UnknownFec(tlvi, fec_type))
             }
+            // RFC 8077 - Section 5.2:
+            // "If the PW info length is zero, then [...] the PW ID field is
+            // not present". Such wildcard PWid FEC elements can't be used to
+            // request or advertise labels.
+            MessageType::LabelMapping | MessageType::LabelRequest
+                if msg.fec.0.iter().any(|fec_elem| {
+                    matches!(
+                        fec_elem,
+                        FecElem::Pwid(FecElemPwid { pw_id: None, .. })
+                    )
+                }) =>
+            {
+                return Err(DecodeError::InvalidTlvValue(tlvi));
+            }
             _ => (),
         }
 
@@ -339,6 +534,18 @@ impl MessageKind for LabelMsg {
                 self.request_id =
                     Some(TlvLabelRequestId::decode_value(buf, cxt, tlvi)?);
             }
+            TlvType::PwStatus => {
+                self.pw_status =
+                    Some(TlvPwStatus::decode_value(buf, cxt, tlvi)?);
+            }
+            TlvType::PwIfParams => {
+                self.pw_if_params =
+                    Some(TlvPwIfParams::decode_value(buf, cxt, tlvi)?);
+            }
+            TlvType::PwGroupId => {
+                self.pw_group_id =
+                    Some(TlvPwGroupId::decode_value(buf, cxt, tlvi)?);
+            }
             _ => {
                 return Ok(true);
             }
@@ -399,6 +606,8 @@ impl FecElem {
                 TLV_FEC_ELEMENT_TYPED_WILDCARD
             }
             FecElem::Prefix(_) => TLV_FEC_ELEMENT_PREFIX,
+            FecElem::Pwid(_) => TLV_FEC_ELEMENT_PWID,
+            FecElem::GenPwid(_) => TLV_FEC_ELEMENT_GEN_PWID,
         }
     }
 
@@ -430,6 +639,12 @@ impl FecElem {
                 let plen_wire = prefix_wire_len(plen);
                 buf.put(&prefix_bytes[0..plen_wire]);
             }
+            FecElem::Pwid(pwid) => {
+                pwid.encode(buf);
+            }
+            FecElem::GenPwid(gen_pwid) => {
+                gen_pwid.encode(buf);
+            }
         }
     }
 
@@ -500,6 +715,14 @@ impl FecElem {
                 let elem = TypedWildcardFecElem::decode(buf, tlvi, tlv_rlen)?;
                 Ok(FecElem::Wildcard(FecElemWildcard::Typed(elem)))
             }
+            TLV_FEC_ELEMENT_PWID => {
+                let elem = FecElemPwid::decode(buf, tlvi, tlv_rlen)?;
+                Ok(FecElem::Pwid(elem))
+            }
+            TLV_FEC_ELEMENT_GEN_PWID => {
+                let elem = FecElemGenPwid::decode(buf, tlvi, tlv_rlen)?;
+                Ok(FecElem::GenPwid(elem))
+            }
             _ => Err(DecodeError::UnknownFec(tlvi.clone(), fec_elem_type)),
         }
     }
@@ -579,6 +802,223 @@ impl TypedWildcardFecElem {
     }
 }
 
+// ===== impl FecElemPwid =====
+
+impl FecElemPwid {
+    fn encode(&self, buf: &mut BytesMut) {
+        // FEC element type.
+        buf.put_u8(TLV_FEC_ELEMENT_PWID);
+
+        // C-bit and PW type.
+        buf.put_u16(pw_type_encode(self.control_word, self.pw_type));
+
+        // PW info length (rewritten later).
+        let len_pos = buf.len();
+        buf.put_u8(0);
+
+        // Group ID.
+        buf.put_u32(self.group_id);
+
+        if let Some(pw_id) = self.pw_id {
+            // PW ID.
+            buf.put_u32(pw_id);
+
+            // Interface Parameter Sub-TLVs.
+            pw_if_params_encode(self.mtu, buf);
+        }
+
+        // Rewrite PW info length.
+        let info_len = buf.len() - len_pos - 5;
+        buf[len_pos] = info_len as u8;
+    }
+
+    fn decode(
+        buf: &mut Bytes,
+        tlvi: &TlvDecodeInfo,
+        tlv_rlen: &mut u16,
+    ) -> DecodeResult<Self> {
+        if *tlv_rlen < 7 {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+
+        // C-bit and PW type.
+        let (control_word, pw_type) = pw_type_decode(buf.get_u16());
+
+        // PW info length.
+        let info_len = buf.get_u8() as u16;
+
+        // Group ID.
+        let group_id = buf.get_u32();
+        *tlv_rlen -= 7;
+
+        if info_len > *tlv_rlen || (info_len != 0 && info_len < 4) {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+
+        let mut pw_id = None;
+        let mut mtu = None;
+        if info_len != 0 {
+            // PW ID.
+            pw_id = Some(buf.get_u32());
+            *tlv_rlen -= 4;
+
+            // Interface Parameter Sub-TLVs.
+            mtu = pw_if_params_decode(buf, tlvi, info_len - 4)?;
+            *tlv_rlen -= info_len - 4;
+        }
+
+        Ok(FecElemPwid {
+            control_word,
+            pw_type,
+            group_id,
+            pw_id,
+            mtu,
+        })
+    }
+}
+
+// ===== impl FecElemGenPwid =====
+
+impl FecElemGenPwid {
+    fn encode(&self, buf: &mut BytesMut) {
+        // FEC element type.
+        buf.put_u8(TLV_FEC_ELEMENT_GEN_PWID);
+
+        // C-bit and PW type.
+        buf.put_u16(pw_type_encode(self.control_word, self.pw_type));
+
+        // PW info length (rewritten later).
+        let len_pos = buf.len();
+        buf.put_u8(0);
+
+        // AGI.
+        buf.put_u8(PW_AGI_TYPE1);
+        match self.agi {
+            Some(agi) => {
+                buf.put_u8(PW_AGI_TYPE1_LEN);
+                buf.put_u64(agi);
+            }
+            None => {
+                buf.put_u8(0);
+            }
+        }
+
+        // SAII and TAII.
+        self.saii.encode(buf);
+        self.taii.encode(buf);
+
+        // Rewrite PW info length.
+        let info_len = buf.len() - len_pos - 1;
+        buf[len_pos] = info_len as u8;
+    }
+
+    fn decode(
+        buf: &mut Bytes,
+        tlvi: &TlvDecodeInfo,
+        tlv_rlen: &mut u16,
+    ) -> DecodeResult<Self> {
+        if *tlv_rlen < 3 {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+
+        // C-bit and PW type.
+        let (control_word, pw_type) = pw_type_decode(buf.get_u16());
+
+        // PW info length.
+        let info_len = buf.get_u8() as u16;
+        *tlv_rlen -= 3;
+        if info_len > *tlv_rlen || info_len < 2 {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+
+        let mut info_rlen = info_len;
+        *tlv_rlen -= info_len;
+
+        // AGI.
+        let agi_type = buf.get_u8();
+        let agi_len = buf.get_u8();
+        info_rlen -= 2;
+        if agi_type != PW_AGI_TYPE1 {
+            return Err(DecodeError::UnknownFec(
+                tlvi.clone(),
+                TLV_FEC_ELEMENT_GEN_PWID,
+            ));
+        }
+        let agi = match agi_len {
+            0 => None,
+            PW_AGI_TYPE1_LEN if info_rlen >= PW_AGI_TYPE1_LEN as u16 => {
+                info_rlen -= PW_AGI_TYPE1_LEN as u16;
+                Some(buf.get_u64())
+            }
+            _ => return Err(DecodeError::InvalidTlvValue(tlvi.clone())),
+        };
+
+        // SAII and TAII.
+        let saii = Aii::decode(buf, tlvi, &mut info_rlen)?;
+        let taii = Aii::decode(buf, tlvi, &mut info_rlen)?;
+        if info_rlen != 0 {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+
+        Ok(FecElemGenPwid {
+            control_word,
+            pw_type,
+            agi,
+            saii,
+            taii,
+        })
+    }
+}
+
+// ===== impl Aii =====
+
+impl Aii {
+    fn encode(&self, buf: &mut BytesMut) {
+        buf.put_u8(PW_AII_TYPE2);
+        buf.put_u8(PW_AII_TYPE2_LEN);
+        buf.put_u32(self.global_id);
+        buf.put_ipv4(&self.prefix);
+        buf.put_u32(self.ac_id);
+    }
+
+    fn decode(
+        buf: &mut Bytes,
+        tlvi: &TlvDecodeInfo,
+        info_rlen: &mut u16,
+    ) -> DecodeResult<Self> {
+        if *info_rlen < 2 {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+
+        let aii_type = buf.get_u8();
+        let aii_len = buf.get_u8();
+        *info_rlen -= 2;
+        if aii_len as u16 > *info_rlen {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+        if aii_type != PW_AII_TYPE2 {
+            return Err(DecodeError::UnknownFec(
+                tlvi.clone(),
+                TLV_FEC_ELEMENT_GEN_PWID,
+            ));
+        }
+        if aii_len != PW_AII_TYPE2_LEN {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+        *info_rlen -= aii_len as u16;
+
+        let global_id = buf.get_u32();
+        let prefix = buf.get_ipv4();
+        let ac_id = buf.get_u32();
+
+        Ok(Aii {
+            global_id,
+            prefix,
+            ac_id,
+        })
+    }
+}
+
 // ===== impl TlvLabel =====
 
 impl TlvKind for TlvLabel {
@@ -639,9 +1079,143 @@ impl TlvKind for TlvLabelRequestId {
     }
 }
 
+// ===== impl TlvPwStatus =====
+
+impl TlvKind for TlvPwStatus {
+    const TLV_TYPE: TlvType = TlvType::PwStatus;
+    const U_BIT: bool = true;
+    const F_BIT: bool = false;
+
+    fn encode_value(&self, buf: &mut BytesMut) {
+        buf.put_u32(self.0.bits());
+    }
+
+    fn decode_value(
+        buf: &mut Bytes,
+        _cxt: &DecodeCxt,
+        tlvi: &TlvDecodeInfo,
+    ) -> DecodeResult<Self> {
+        if tlvi.tlv_len != 4 {
+            return Err(DecodeError::InvalidTlvLength(tlvi.tlv_len));
+        }
+
+        let status = PwStatusFlags::from_bits_truncate(buf.get_u32());
+
+        Ok(Self(status))
+    }
+}
+
+// ===== impl TlvPwIfParams =====
+
+impl TlvKind for TlvPwIfParams {
+    const TLV_TYPE: TlvType = TlvType::PwIfParams;
+    const U_BIT: bool = false;
+    const F_BIT: bool = false;
+
+    fn encode_value(&self, buf: &mut BytesMut) {
+        pw_if_params_encode(self.mtu, buf);
+    }
+
+    fn decode_value(
+        buf: &mut Bytes,
+        _cxt: &DecodeCxt,
+        tlvi: &TlvDecodeInfo,
+    ) -> DecodeResult<Self> {
+        let mtu = pw_if_params_decode(buf, tlvi, tlvi.tlv_len)?;
+
+        Ok(Self { mtu })
+    }
+}
+
+// ===== impl TlvPwGroupId =====
+
+impl TlvKind for TlvPwGroupId {
+    const TLV_TYPE: TlvType = TlvType::PwGroupId;
+    const U_BIT: bool = false;
+    const F_BIT: bool = false;
+
+    fn encode_value(&self, buf: &mut BytesMut) {
+        buf.put_u32(self.0);
+    }
+
+    fn decode_value(
+        buf: &mut Bytes,
+        _cxt: &DecodeCxt,
+        tlvi: &TlvDecodeInfo,
+    ) -> DecodeResult<Self> {
+        if tlvi.tlv_len != 4 {
+            return Err(DecodeError::InvalidTlvLength(tlvi.tlv_len));
+        }
+
+        let group_id = buf.get_u32();
+
+        Ok(Self(group_id))
+    }
+}
+
 // ===== global functions =====
 
 // Calculate the number of bytes required to encode a prefix.
 fn prefix_wire_len(len: u8) -> usize {
     (len as usize + 7) / 8
 }
+
+fn pw_type_encode(control_word: bool, pw_type: u16) -> u16 {
+    let mut value = pw_type & PW_TYPE_MASK;
+    if control_word {
+        value |= PW_CONTROL_WORD_FLAG;
+    }
+    value
+}
+
+fn pw_type_decode(value: u16) -> (bool, u16) {
+    let control_word = value & PW_CONTROL_WORD_FLAG != 0;
+    let pw_type = value & PW_TYPE_MASK;
+    (control_word, pw_type)
+}
+
+fn pw_if_params_encode(mtu: Option<u16>, buf: &mut BytesMut) {
+    if let Some(mtu) = mtu {
+        buf.put_u8(PW_IF_PARAM_MTU);
+        buf.put_u8(4);
+        buf.put_u16(mtu);
+    }
+}
+
+// Decodes a list of Interface Parameter Sub-TLVs, returning the interface MTU
+// (if present). Unknown sub-TLVs are ignored.
+fn pw_if_params_decode(
+    buf: &mut Bytes,
+    tlvi: &TlvDecodeInfo,
+    mut len: u16,
+) -> DecodeResult<Option<u16>> {
+    let mut mtu = None;
+
+    while len > 0 {
+        if len < 2 {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+
+        // The sub-TLV length includes the type and length fields.
+        let param_type = buf.get_u8();
+        let param_len = buf.get_u8() as u16;
+        if param_len < 2 || param_len > len {
+            return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+        }
+
+        match param_type {
+            PW_IF_PARAM_MTU => {
+                if param_len != 4 {
+                    return Err(DecodeError::InvalidTlvValue(tlvi.clone()));
+                }
+                mtu = Some(buf.get_u16());
+            }
+            _ => {
+                buf.advance(param_len as usize - 2);
+            }
+        }
+        len -= param_len;
+    }
+
+    Ok(mtu)
+}
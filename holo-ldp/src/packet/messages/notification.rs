@@ -13,7 +13,7 @@ use crate::packet::error::{DecodeError, DecodeResult};
 use crate::packet::message::{
     Message, MessageDecodeInfo, MessageKind, MessageType,
 };
-use crate::packet::messages::label::TlvFec;
+use crate::packet::messages::label::{TlvFec, TlvPwStatus};
 use crate::packet::pdu::Pdu;
 use crate::packet::tlv::{self, TlvDecodeInfo, TlvKind, TlvType};
 use crate::packet::DecodeCxt;
@@ -49,6 +49,8 @@ pub struct NotifMsg {
     pub returned_tlvs: Option<TlvReturnedTlvs>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fec: Option<TlvFec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pw_status: Option<TlvPwStatus>,
 }
 
 //
@@ -133,6 +135,12 @@ pub enum StatusCode {
     UnsupportedAf = 0x0000_0017,
     SessRejKeepalive = 0x0000_0018,
     InternalError = 0x0000_0019,
+    // RFC 8077
+    IllegalCbit = 0x0000_0024,
+    WrongCbit = 0x0000_0025,
+    PwStatus = 0x0000_0028,
+    UnassignedTai = 0x0000_0029,
+    GenericMisconfig = 0x0000_002A,
     // RFC 5561
     UnsupportedCap = 0x0000_002E,
     // RFC 5919
@@ -179,6 +187,9 @@ impl MessageKind for NotifMsg {
         if let Some(tlv) = &self.fec {
             tlv.encode(self.msg_type(), buf);
         }
+        if let Some(tlv) = &self.pw_status {
+            tlv.encode(self.msg_type(), buf);
+        }
     }
 
     fn decode_body(
@@ -235,6 +246,10 @@ impl MessageKind for NotifMsg {
             TlvType::Fec => {
                 self.fec = Some(TlvFec::decode_value(buf, cxt, tlvi)?);
             }
+            TlvType::PwStatus => {
+                self.pw_status =
+                    Some(TlvPwStatus::decode_value(buf, cxt, tlvi)?);
+            }
             _ => {
                 return Ok(true);
             }
@@ -524,6 +539,15 @@ impl std::fmt::Display for StatusCode {
                 write!(f, "Session Rejected: Bad KeepAlive Time")
             }
             StatusCode::InternalError => write!(f, "Internal Error"),
+            StatusCode::IllegalCbit => write!(f, "Illegal C-Bit"),
+            StatusCode::WrongCbit => write!(f, "Wrong C-Bit"),
+            StatusCode::PwStatus => write!(f, "PW Status"),
+            StatusCode::UnassignedTai => {
+                write!(f, "Unassigned/Unrecognized TAI")
+            }
+            StatusCode::GenericMisconfig => {
+                write!(f, "Generic Misconfiguration Error")
+            }
             StatusCode::UnsupportedCap => write!(f, "Unsupported Capability"),
             StatusCode::EndOfLib => write!(f, "End-of-LIB"),
             StatusCode::TransportMismatch => {
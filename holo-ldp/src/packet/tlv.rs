@@ -61,6 +61,8 @@ pub enum TlvType {
     CommonSessParams = 0x0500,
     AtmSessParams = 0x0501,
     FrSessParams = 0x0502,
+    // RFC 3479
+    FtSession = 0x0503,
     LabelRequestId = 0x0600,
     // RFC 5561
     ReturnedTlvs = 0x0304,
@@ -71,6 +73,10 @@ pub enum TlvType {
     CapUnrecNotif = 0x0603,
     // RFC 7552
     DualStack = 0x0701,
+    // RFC 8077
+    PwStatus = 0x096A,
+    PwIfParams = 0x096B,
+    PwGroupId = 0x096C,
 }
 
 //
@@ -160,6 +166,7 @@ impl std::fmt::Display for TlvType {
             TlvType::FrSessParams => {
                 write!(f, "Frame Relay Session Parameters")
             }
+            TlvType::FtSession => write!(f, "Fault Tolerant Session"),
             TlvType::LabelRequestId => write!(f, "Label Request Message ID"),
             TlvType::ReturnedTlvs => write!(f, "Returned TLVs"),
             TlvType::CapDynamic => write!(f, "Dynamic Capability Announcement"),
@@ -168,6 +175,9 @@ impl std::fmt::Display for TlvType {
                 write!(f, "Unrecognized Notification Capability")
             }
             TlvType::DualStack => write!(f, "Dual-Stack capability"),
+            TlvType::PwStatus => write!(f, "PW Status"),
+            TlvType::PwIfParams => write!(f, "PW Interface Parameters"),
+            TlvType::PwGroupId => write!(f, "PW Group ID"),
         }
     }
 }
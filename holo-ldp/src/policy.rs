@@ -0,0 +1,359 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+use std::net::Ipv4Addr;
+
+use holo_protocol::InstanceShared;
+use holo_utils::ip::{AddressFamily, IpNetworkKind};
+use holo_utils::mpls::Label;
+use holo_utils::policy::MatchSets;
+use holo_utils::protocol::Protocol;
+use ipnetwork::IpNetwork;
+
+use crate::debug::Debug;
+use crate::events;
+use crate::fec::Fec;
+use crate::instance::InstanceUpView;
+use crate::northbound::configuration::InstanceCfg;
+use crate::northbound::notification;
+use crate::southbound;
+
+// ===== global functions =====
+
+// Returns whether a local label can be assigned to the given prefix.
+pub(crate) fn label_assign_check(
+    config: &InstanceCfg,
+    match_sets: &MatchSets,
+    prefix: &IpNetwork,
+) -> bool {
+    let af = prefix.address_family();
+    let Some(label_policy) = config.label_policy(af) else {
+        return true;
+    };
+
+    if label_policy.assign_host_routes_only
+        && prefix.prefix() != af.max_prefixlen()
+    {
+        return false;
+    }
+
+    prefix_list_check(match_sets, &label_policy.assign_prefix_list, prefix)
+}
+
+// Returns whether the local label of the given prefix can be advertised to
+// the specified neighbor.
+pub(crate) fn label_advertise_check(
+    config: &InstanceCfg,
+    match_sets: &MatchSets,
+    lsr_id: Ipv4Addr,
+    prefix: &IpNetwork,
+) -> bool {
+    let af = prefix.address_family();
+    if let Some(label_policy) = config.label_policy(af) {
+        if !prefix_list_check(
+            match_sets,
+            &label_policy.advertise_prefix_list,
+            prefix,
+        ) {
+            return false;
+        }
+    }
+
+    if let Some(nbr_cfg) = config.neighbors.get(&lsr_id) {
+        let nbr_label_policy = match af {
+            AddressFamily::Ipv4 => &nbr_cfg.ipv4,
+            AddressFamily::Ipv6 => &nbr_cfg.ipv6,
+        };
+        if !prefix_list_check(
+            match_sets,
+            &nbr_label_policy.advertise_prefix_list,
+            prefix,
+        ) {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Returns whether label mappings received from the specified neighbor for the
+// given prefix can be used.
+pub(crate) fn label_accept_check(
+    config: &InstanceCfg,
+    match_sets: &MatchSets,
+    lsr_id: Ipv4Addr,
+    prefix: &IpNetwork,
+) -> bool {
+    let af = prefix.address_family();
+    if let Some(label_policy) = config.label_policy(af) {
+        if !prefix_list_check(
+            match_sets,
+            &label_policy.accept_prefix_list,
+            prefix,
+        ) {
+            return false;
+        }
+    }
+
+    if let Some(nbr_cfg) = config.neighbors.get(&lsr_id) {
+        let nbr_label_policy = match af {
+            AddressFamily::Ipv4 => &nbr_cfg.ipv4,
+            AddressFamily::Ipv6 => &nbr_cfg.ipv6,
+        };
+        if !prefix_list_check(
+            match_sets,
+            &nbr_label_policy.accept_prefix_list,
+            prefix,
+        ) {
+            return false;
+        }
+    }
+
+    true
+}
+
+// Assigns a local label to the given FEC, unless one is already assigned or
+// the label assignment policies don't allow it.
+pub(crate) fn local_label_update(
+    fec: &mut Fec,
+    config: &InstanceCfg,
+    shared: &InstanceShared,
+    gr_preserved_labels: &mut BTreeMap<IpNetwork, Label>,
+) {
+    if fec.inner.local_label.is_some() {
+        return;
+    }
+
+    let prefix = *fec.inner.prefix;
+    if !label_assign_check(config, &shared.policy_match_sets, &prefix) {
+        return;
+    }
+
+    let protocol = fec.inner.protocol.unwrap();
+    let label = if protocol == Protocol::DIRECT {
+        egress_label(config, prefix.address_family())
+    } else if let Some(label) = gr_preserved_labels.remove(&prefix) {
+        // Reuse the label preserved across the last restart.
+        label
+    } else {
+        let mut label_manager = shared.label_manager.lock().unwrap();
+        label_manager.label_request().unwrap()
+    };
+    let label = Some(label);
+
+    Debug::FecLabelUpdate(fec, &label).log();
+    fec.inner.local_label = label;
+}
+
+// Reevaluates all FECs after a change in the label policies or in the prefix
+// lists they reference.
+pub(crate) fn process_label_policy_update(instance: &mut InstanceUpView<'_>) {
+    let match_sets = instance.shared.policy_match_sets.clone();
+
+    for prefix in instance.state.fecs.keys().copied().collect::<Vec<_>>() {
+        let fec = instance.state.fecs.get_mut(&prefix).unwrap();
+
+        // Skip FECs that aren't reachable. Directly connected FECs don't
+        // have any nexthop, so check the route's protocol instead.
+        if fec.inner.protocol.is_none() {
+            continue;
+        }
+        let mut old_fec_status = fec.is_operational();
+
+        // Update local label.
+        if local_label_outdated(fec, instance.config, &match_sets) {
+            local_label_reassign(instance, prefix);
+        }
+
+        // Update label advertisements.
+        let msg_id = &instance.state.msg_id;
+        let fec = instance.state.fecs.get_mut(&prefix).unwrap();
+        for nbr in instance
+            .state
+            .neighbors
+            .iter_mut()
+            .filter(|nbr| nbr.is_operational())
+        {
+            let advertise = fec.inner.local_label.is_some()
+                && label_advertise_check(
+                    instance.config,
+                    &match_sets,
+                    nbr.lsr_id,
+                    &prefix,
+                );
+            // A mapping whose withdrawal is still pending (i.e. no Label
+            // Release was received yet) isn't advertised anymore.
+            let advertised =
+                nbr.sent_mappings.get(&prefix).is_some_and(|mapping| {
+                    Some(mapping.label) == fec.inner.local_label
+                        && nbr.sent_withdraws.get(&prefix)
+                            != Some(&mapping.label)
+                });
+            if advertise && !advertised {
+                nbr.send_label_mapping(msg_id, fec);
+                // The new mapping supersedes the pending withdrawal.
+                nbr.sent_withdraws.remove(&prefix);
+            } else if !advertise && advertised {
+                nbr.send_label_withdraw(msg_id, fec);
+            }
+        }
+
+        // Update received label mappings.
+        for nbr_idx in instance.state.neighbors.indexes().collect::<Vec<_>>() {
+            let nbr = &instance.state.neighbors[nbr_idx];
+            let Some(mapping) = nbr.rcvd_mappings.get(&prefix).copied() else {
+                continue;
+            };
+            let fec = instance.state.fecs.get_mut(&prefix).unwrap();
+            let accept = label_accept_check(
+                instance.config,
+                &match_sets,
+                nbr.lsr_id,
+                &prefix,
+            );
+            let accepted = fec.inner.downstream.contains_key(&nbr.lsr_id);
+            if accept && !accepted {
+                events::process_nbr_msg_label_mapping(
+                    instance,
+                    nbr_idx,
+                    mapping.label,
+                    prefix.into(),
+                );
+
+                // FEC status changes were already notified.
+                old_fec_status = instance.state.fecs[&prefix].is_operational();
+            } else if !accept && accepted {
+                for nexthop in fec
+                    .nexthops
+                    .values_mut()
+                    .filter(|nexthop| nbr.addr_list.contains(&nexthop.addr))
+                {
+                    southbound::tx::label_uninstall(
+                        &instance.tx.ibus,
+                        &fec.inner,
+                        nexthop,
+                    );
+                    nexthop.set_label(None);
+                }
+                fec.inner.downstream.remove(&nbr.lsr_id);
+            }
+        }
+
+        let fec = &instance.state.fecs[&prefix];
+        if old_fec_status != fec.is_operational() {
+            notification::mpls_ldp_fec_event(
+                &instance.tx.nb,
+                instance.name,
+                fec,
+            );
+        }
+    }
+}
+
+// ===== helper functions =====
+
+fn prefix_list_check(
+    match_sets: &MatchSets,
+    prefix_list: &Option<String>,
+    prefix: &IpNetwork,
+) -> bool {
+    let Some(name) = prefix_list else {
+        return true;
+    };
+
+    let key = (name.clone(), prefix.address_family());
+    match_sets.prefixes.get(&key).is_some_and(|set| {
+        set.prefixes.iter().any(|range| {
+            range.prefix.contains(prefix.ip())
+                && range.prefix.prefix() <= prefix.prefix()
+                && (range.masklen_lower..=range.masklen_upper)
+                    .contains(&prefix.prefix())
+        })
+    })
+}
+
+// Returns the label advertised for directly connected FECs.
+fn egress_label(config: &InstanceCfg, af: AddressFamily) -> Label {
+    let explicit_null = config
+        .label_policy(af)
+        .is_some_and(|label_policy| label_policy.advertise_explicit_null);
+    match (af, explicit_null) {
+        (AddressFamily::Ipv4, true) => Label::new(Label::IPV4_EXPLICIT_NULL),
+        (AddressFamily::Ipv6, true) => Label::new(Label::IPV6_EXPLICIT_NULL),
+        (_, false) => Label::new(Label::IMPLICIT_NULL),
+    }
+}
+
+// Returns whether the local label of the given FEC no longer conforms to the
+// label assignment policies.
+fn local_label_outdated(
+    fec: &Fec,
+    config: &InstanceCfg,
+    match_sets: &MatchSets,
+) -> bool {
+    let prefix = &*fec.inner.prefix;
+    let assign = label_assign_check(config, match_sets, prefix);
+    match fec.inner.local_label {
+        Some(label)
+            if assign && fec.inner.protocol == Some(Protocol::DIRECT) =>
+        {
+            label != egress_label(config, prefix.address_family())
+        }
+        Some(_) => !assign,
+        None => assign,
+    }
+}
+
+// Replaces the local label of the given FEC, withdrawing the old label from
+// all neighbors it was advertised to.
+fn local_label_reassign(instance: &mut InstanceUpView<'_>, prefix: IpNetwork) {
+    let fec = instance.state.fecs.get_mut(&prefix).unwrap();
+
+    if let Some(old_label) = fec.inner.local_label {
+        // Withdraw the old label.
+        let msg_id = &instance.state.msg_id;
+        for nbr in instance.state.neighbors.iter_mut().filter(|nbr| {
+            nbr.is_operational()
+                && nbr.sent_mappings.get(&prefix).map(|mapping| mapping.label)
+                    == Some(old_label)
+        }) {
+            nbr.send_label_withdraw(msg_id, fec);
+        }
+
+        // Uninstall the old label.
+        for nexthop in fec.nexthops.values() {
+            southbound::tx::label_uninstall(
+                &instance.tx.ibus,
+                &fec.inner,
+                nexthop,
+            );
+        }
+
+        // Release the old label.
+        if !old_label.is_reserved() {
+            let mut label_manager =
+                instance.shared.label_manager.lock().unwrap();
+            label_manager.label_release(old_label);
+        }
+
+        Debug::FecLabelUpdate(fec, &None).log();
+        fec.inner.local_label = None;
+    }
+
+    // Assign a new label, if allowed.
+    local_label_update(
+        fec,
+        instance.config,
+        instance.shared,
+        &mut instance.state.gr_preserved_labels,
+    );
+
+    // Install the new label.
+    for nexthop in fec.nexthops.values() {
+        southbound::tx::label_install(&instance.tx.ibus, &fec.inner, nexthop);
+    }
+}
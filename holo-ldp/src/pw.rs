@@ -0,0 +1,709 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::{btree_map, BTreeMap, BTreeSet};
+use std::net::{IpAddr, Ipv4Addr};
+
+use holo_utils::ibus::IbusMsg;
+use holo_utils::mpls::Label;
+use holo_utils::protocol::Protocol;
+use holo_utils::southbound::{self as sb, PwInstallMsg, PwStatusMsg};
+use ipnetwork::{IpNetwork, Ipv4Network};
+
+use crate::collections::NeighborIndex;
+use crate::debug::Debug;
+use crate::discovery::TargetedNbr;
+use crate::fec::Fec;
+use crate::instance::{Instance, InstanceUpView};
+use crate::northbound::configuration::PseudowireCfg;
+use crate::packet::messages::label::{
+    Aii, FecElem, FecElemGenPwid, FecElemPwid, PwStatusFlags, TlvPwIfParams,
+    PW_TYPE_ETHERNET, PW_TYPE_ETHERNET_TAGGED,
+};
+use crate::packet::messages::{LabelMsg, NotifMsg};
+use crate::packet::LabelMessageType;
+use crate::southbound;
+
+// Pseudowire (RFC 4447).
+#[derive(Debug)]
+pub struct Pseudowire {
+    // Pseudowire name.
+    pub name: String,
+    // LSR-ID of the remote PE.
+    pub neighbor: Ipv4Addr,
+    // Local label bound to the pseudowire.
+    pub local_label: Label,
+    // Control word setting signaled to the remote PE. It might differ from
+    // the configured one as a result of the control word negotiation.
+    pub control_word: bool,
+    // PW status and MTU signaled to the remote PE.
+    pub local_status: PwStatusFlags,
+    pub local_mtu: Option<u16>,
+    // FEC element of the label mapping sent to the remote PE (if any).
+    pub sent_fec: Option<FecElem>,
+    // Label mapping received from the remote PE (if any).
+    pub remote: Option<PwMapping>,
+    // Pseudowire installation request sent to the data plane.
+    pub installed: Option<PwInstallMsg>,
+    // Whether the data plane acknowledged the installation request.
+    pub programmed: bool,
+}
+
+// Pseudowire FEC, from the local PE's point of view.
+//
+// Generalized PWid FECs received from a remote PE are stored with their SAII
+// and TAII swapped, so they can be matched against the local configuration.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum PwFecKey {
+    Pwid {
+        pw_type: u16,
+        pw_id: u32,
+    },
+    GenPwid {
+        pw_type: u16,
+        agi: Option<u64>,
+        global_id: u32,
+        local_ac_id: u32,
+        remote_ac_id: u32,
+    },
+}
+
+// Pseudowire label mapping received from a remote PE.
+#[derive(Clone, Copy, Debug)]
+pub struct PwMapping {
+    pub label: Label,
+    pub control_word: bool,
+    pub group_id: u32,
+    pub mtu: Option<u16>,
+    // PW status (`None` if the remote PE doesn't support the PW Status TLV).
+    pub status: Option<PwStatusFlags>,
+}
+
+// Pseudowire type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PwType {
+    EthernetTagged,
+    Ethernet,
+}
+
+// Attachment circuit interface, as learned from the southbound.
+#[derive(Debug)]
+pub struct AcIfaceSys {
+    pub ifindex: u32,
+    pub mtu: u32,
+    pub operative: bool,
+}
+
+// ===== impl Pseudowire =====
+
+impl Pseudowire {
+    fn new(
+        name: String,
+        neighbor: Ipv4Addr,
+        local_label: Label,
+        control_word: bool,
+    ) -> Pseudowire {
+        Debug::PwCreate(&name).log();
+
+        Pseudowire {
+            name,
+            neighbor,
+            local_label,
+            control_word,
+            local_status: PwStatusFlags::empty(),
+            local_mtu: None,
+            sent_fec: None,
+            remote: None,
+            installed: None,
+            programmed: false,
+        }
+    }
+
+    // Returns whether the pseudowire is up and installed in the data plane.
+    pub(crate) fn is_up(&self) -> bool {
+        self.installed.is_some() && self.programmed
+    }
+
+    fn withdraw(&mut self, instance: &mut InstanceUpView<'_>) {
+        let Some(fec_elem) = self.sent_fec.take() else {
+            return;
+        };
+
+        if let Some((_, nbr)) =
+            instance.state.neighbors.get_mut_by_lsr_id(&self.neighbor)
+        {
+            if nbr.is_operational() {
+                nbr.send_pw_withdraw(
+                    &instance.state.msg_id,
+                    fec_elem,
+                    self.local_label,
+                );
+            }
+        }
+    }
+}
+
+impl Drop for Pseudowire {
+    fn drop(&mut self) {
+        Debug::PwDelete(&self.name).log();
+    }
+}
+
+// ===== impl PwFecKey =====
+
+impl PwFecKey {
+    // Returns the key of the given FEC element as received from a remote PE,
+    // or `None` if it's a group wildcard.
+    fn from_rcvd_fec_elem(fec_elem: &FecElem) -> Option<PwFecKey> {
+        match fec_elem {
+            FecElem::Pwid(elem) => elem.pw_id.map(|pw_id| PwFecKey::Pwid {
+                pw_type: elem.pw_type,
+                pw_id,
+            }),
+            FecElem::GenPwid(elem) => Some(PwFecKey::GenPwid {
+                pw_type: elem.pw_type,
+                agi: elem.agi,
+                global_id: elem.taii.global_id,
+                local_ac_id: elem.taii.ac_id,
+                remote_ac_id: elem.saii.ac_id,
+            }),
+            _ => unreachable!(),
+        }
+    }
+
+    fn from_cfg(pw_cfg: &PseudowireCfg) -> Option<PwFecKey> {
+        let pw_type = pw_cfg.pw_type.code();
+        if let Some(pwid) = &pw_cfg.pwid {
+            Some(PwFecKey::Pwid {
+                pw_type,
+                pw_id: pwid.pw_id,
+            })
+        } else {
+            pw_cfg.gen_pwid.as_ref().map(|gen_pwid| PwFecKey::GenPwid {
+                pw_type,
+                agi: gen_pwid.agi,
+                global_id: gen_pwid.global_id,
+                local_ac_id: gen_pwid.local_ac_id,
+                remote_ac_id: gen_pwid.remote_ac_id,
+            })
+        }
+    }
+}
+
+// ===== impl PwType =====
+
+impl PwType {
+    pub(crate) fn code(&self) -> u16 {
+        match self {
+            PwType::EthernetTagged => PW_TYPE_ETHERNET_TAGGED,
+            PwType::Ethernet => PW_TYPE_ETHERNET,
+        }
+    }
+}
+
+// ===== helper functions =====
+
+// Returns the PWid group ID of the given group wildcard FEC element.
+fn group_wildcard(fec_elem: &FecElem) -> Option<u32> {
+    match fec_elem {
+        FecElem::Pwid(elem) if elem.pw_id.is_none() => Some(elem.group_id),
+        _ => None,
+    }
+}
+
+// Returns the FEC element used to advertise the given pseudowire.
+fn build_fec_elem(
+    pw_cfg: &PseudowireCfg,
+    pw: &Pseudowire,
+    router_id: Ipv4Addr,
+    mtu: u16,
+) -> FecElem {
+    let control_word = pw.control_word;
+    let pw_type = pw_cfg.pw_type.code();
+    match (&pw_cfg.pwid, &pw_cfg.gen_pwid) {
+        (Some(pwid), _) => FecElem::Pwid(FecElemPwid {
+            control_word,
+            pw_type,
+            group_id: pw_cfg.group_id,
+            pw_id: Some(pwid.pw_id),
+            mtu: Some(mtu),
+        }),
+        (None, Some(gen_pwid)) => FecElem::GenPwid(FecElemGenPwid {
+            control_word,
+            pw_type,
+            agi: gen_pwid.agi,
+            saii: Aii {
+                global_id: gen_pwid.global_id,
+                prefix: router_id,
+                ac_id: gen_pwid.local_ac_id,
+            },
+            taii: Aii {
+                global_id: gen_pwid.global_id,
+                prefix: pw.neighbor,
+                ac_id: gen_pwid.remote_ac_id,
+            },
+        }),
+        (None, None) => unreachable!(),
+    }
+}
+
+// Returns the nexthops of the LSP towards the given remote PE.
+fn transport_nexthops(
+    fecs: &BTreeMap<IpNetwork, Fec>,
+    addr: Ipv4Addr,
+) -> BTreeSet<sb::Nexthop> {
+    let prefix = IpNetwork::V4(Ipv4Network::new(addr, 32).unwrap());
+    let Some(fec) = fecs.get(&prefix) else {
+        return Default::default();
+    };
+
+    fec.nexthops
+        .values()
+        .filter_map(|nexthop| {
+            let label = nexthop.get_label()?;
+            let ifindex = nexthop.ifindex?;
+            let labels = if label.get() == Label::IMPLICIT_NULL {
+                vec![]
+            } else {
+                vec![label]
+            };
+            Some(sb::Nexthop::Address {
+                ifindex,
+                addr: nexthop.addr,
+                labels,
+                weight: None,
+            })
+        })
+        .collect()
+}
+
+// Reevaluates the signaling and data plane state of the given pseudowire.
+fn evaluate(instance: &mut InstanceUpView<'_>, name: &str) {
+    let pw_cfg = &instance.config.pseudowires[name];
+    let pw = instance.state.pseudowires.get_mut(name).unwrap();
+    let key = PwFecKey::from_cfg(pw_cfg).unwrap();
+    let ifname = pw_cfg.interface.as_ref().unwrap();
+    let ac = instance.system.ac_ifaces.get(ifname);
+    let msg_id = &instance.state.msg_id;
+    let router_id = instance.state.router_id;
+    let was_up = pw.is_up();
+
+    // Local PW status and MTU.
+    let local_status = match ac {
+        Some(ac) if ac.operative => PwStatusFlags::empty(),
+        _ => {
+            PwStatusFlags::NOT_FORWARDING
+                | PwStatusFlags::AC_RX_FAULT
+                | PwStatusFlags::AC_TX_FAULT
+        }
+    };
+    let local_mtu = pw_cfg
+        .mtu
+        .or_else(|| ac.map(|ac| u16::try_from(ac.mtu).unwrap_or(u16::MAX)));
+
+    let mut remote = None;
+    match instance
+        .state
+        .neighbors
+        .get_mut_by_lsr_id(&pw.neighbor)
+        .map(|(_, nbr)| nbr)
+        .filter(|nbr| nbr.is_operational())
+    {
+        Some(nbr) => {
+            remote = nbr.rcvd_pw_mappings.get(&key).copied();
+
+            // RFC 8077 - Section 7:
+            // If the remote PE doesn't support the control word, withdraw the
+            // label mapping and advertise it again without the C-bit set.
+            if let Some(remote) = &remote {
+                if pw.control_word && !remote.control_word {
+                    if let Some(fec_elem) = pw.sent_fec.take() {
+                        nbr.send_pw_withdraw(msg_id, fec_elem, pw.local_label);
+                    }
+                    pw.control_word = false;
+                }
+            }
+
+            // Advertise the label mapping again if the local MTU changed.
+            if pw.sent_fec.is_some() && pw.local_mtu != local_mtu {
+                let fec_elem = pw.sent_fec.take().unwrap();
+                nbr.send_pw_withdraw(msg_id, fec_elem, pw.local_label);
+            }
+
+            // Status changes are signaled using the label withdraw method
+            // when the remote PE doesn't support the PW Status TLV.
+            let status_tlv =
+                remote.map_or(true, |remote| remote.status.is_some());
+            match pw.sent_fec {
+                Some(fec_elem) if pw.local_status != local_status => {
+                    if status_tlv {
+                        nbr.send_pw_status(msg_id, fec_elem, local_status);
+                        pw.local_status = local_status;
+                    } else {
+                        nbr.send_pw_withdraw(msg_id, fec_elem, pw.local_label);
+                        pw.sent_fec = None;
+                    }
+                }
+                None if status_tlv || local_status.is_empty() => {
+                    if let Some(mtu) = local_mtu {
+                        let fec_elem =
+                            build_fec_elem(pw_cfg, pw, router_id, mtu);
+                        let (if_params, group_id) = match fec_elem {
+                            FecElem::GenPwid(_) => (
+                                Some(TlvPwIfParams { mtu: Some(mtu) }),
+                                Some(pw_cfg.group_id),
+                            ),
+                            _ => (None, None),
+                        };
+                        nbr.send_pw_mapping(
+                            msg_id,
+                            fec_elem,
+                            pw.local_label,
+                            local_status,
+                            if_params,
+                            group_id,
+                        );
+                        pw.sent_fec = Some(fec_elem);
+                        pw.local_status = local_status;
+                        pw.local_mtu = local_mtu;
+                    }
+                }
+                _ => (),
+            }
+        }
+        None => {
+            // The label mapping needs to be advertised again once the session
+            // comes back up.
+            pw.sent_fec = None;
+            pw.control_word = pw_cfg.control_word;
+        }
+    }
+
+    pw.remote = remote;
+
+    // Check whether the pseudowire is up.
+    let up = pw.sent_fec.is_some()
+        && pw.local_status.is_empty()
+        && remote.is_some_and(|remote| {
+            remote.control_word == pw.control_word
+                && remote.mtu.map_or(true, |mtu| Some(mtu) == pw.local_mtu)
+                && remote.status.map_or(true, |status| status.is_empty())
+        });
+
+    // Install or uninstall the pseudowire as necessary.
+    let install = remote.filter(|_| up).and_then(|remote| {
+        let nexthops = transport_nexthops(&instance.state.fecs, pw.neighbor);
+        if nexthops.is_empty() {
+            return None;
+        }
+        Some(PwInstallMsg {
+            protocol: Protocol::LDP,
+            name: pw.name.clone(),
+            ifindex: ac.unwrap().ifindex,
+            local_label: pw.local_label,
+            remote_label: remote.label,
+            control_word: pw.control_word,
+            nexthops,
+        })
+    });
+    if pw.installed != install {
+        if let Some(msg) = pw.installed.take() {
+            southbound::tx::pw_uninstall(&instance.tx.ibus, &msg);
+        }
+        if let Some(msg) = install {
+            southbound::tx::pw_install(&instance.tx.ibus, &msg);
+            pw.installed = Some(msg);
+        }
+
+        // Wait for the data plane to acknowledge the new state.
+        pw.programmed = false;
+    }
+    if pw.is_up() != was_up {
+        if pw.is_up() {
+            Debug::PwUp(&pw.name).log();
+        } else {
+            Debug::PwDown(&pw.name).log();
+        }
+    }
+}
+
+fn delete(instance: &mut InstanceUpView<'_>, mut pw: Pseudowire) {
+    // Withdraw the label mapping.
+    pw.withdraw(instance);
+
+    // Uninstall the pseudowire from the data plane.
+    if let Some(msg) = pw.installed.take() {
+        southbound::tx::pw_uninstall(&instance.tx.ibus, &msg);
+    }
+
+    // Release the local label.
+    let mut label_manager = instance.shared.label_manager.lock().unwrap();
+    label_manager.label_release(pw.local_label);
+}
+
+// ===== global functions =====
+
+// Creates, recreates or deletes the given pseudowire according to its
+// configuration.
+pub(crate) fn update(instance: &mut InstanceUpView<'_>, name: &str) {
+    // Tear down the existing pseudowire, if any.
+    if let Some(pw) = instance.state.pseudowires.remove(name) {
+        delete(instance, pw);
+    }
+
+    let Some(pw_cfg) = instance.config.pseudowires.get(name) else {
+        return;
+    };
+    let (Some(_), Some(neighbor)) = (&pw_cfg.interface, pw_cfg.neighbor) else {
+        return;
+    };
+    if PwFecKey::from_cfg(pw_cfg).is_none() {
+        return;
+    }
+
+    // Allocate local label.
+    let local_label = {
+        let mut label_manager = instance.shared.label_manager.lock().unwrap();
+        label_manager.label_request().unwrap()
+    };
+
+    let pw = Pseudowire::new(
+        name.to_owned(),
+        neighbor,
+        local_label,
+        pw_cfg.control_word,
+    );
+    instance.state.pseudowires.insert(name.to_owned(), pw);
+    evaluate(instance, name);
+}
+
+// Reevaluates all pseudowires.
+pub(crate) fn update_all(instance: &mut InstanceUpView<'_>) {
+    for name in instance
+        .state
+        .pseudowires
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>()
+    {
+        evaluate(instance, &name);
+    }
+}
+
+// Creates all configured pseudowires.
+pub(crate) fn start_all(instance: &mut InstanceUpView<'_>) {
+    let config = instance.config;
+    for (name, pw_cfg) in &config.pseudowires {
+        // Request information about unknown attachment circuits.
+        if let Some(ifname) = &pw_cfg.interface {
+            if !instance.system.ac_ifaces.contains_key(ifname) {
+                let _ = instance.tx.ibus.send(IbusMsg::InterfaceQuery {
+                    ifname: ifname.clone(),
+                    af: None,
+                });
+            }
+        }
+
+        update(instance, name);
+    }
+}
+
+// Deletes all pseudowires.
+pub(crate) fn stop_all(instance: &mut InstanceUpView<'_>) {
+    let pseudowires = std::mem::take(&mut instance.state.pseudowires);
+    for pw in pseudowires.into_values() {
+        delete(instance, pw);
+    }
+}
+
+// Synchronizes the targeted neighbors with the remote PEs of the configured
+// pseudowires.
+pub(crate) fn tneighbors_update(instance: &mut Instance) {
+    let addrs = instance
+        .config
+        .pseudowires
+        .values()
+        .filter_map(|pw_cfg| pw_cfg.neighbor)
+        .map(IpAddr::from)
+        .collect::<BTreeSet<_>>();
+
+    for addr in &addrs {
+        instance.tneighbors.insert(*addr);
+    }
+    for tnbr in instance.tneighbors.iter_mut() {
+        tnbr.pseudowire = addrs.contains(&tnbr.addr);
+    }
+
+    if !instance.is_active() {
+        let tneighbors = &mut instance.tneighbors;
+        for tnbr_idx in tneighbors.indexes().collect::<Vec<_>>() {
+            if tneighbors[tnbr_idx].remove_check() {
+                tneighbors.delete(tnbr_idx);
+            }
+        }
+        return;
+    }
+
+    let (mut instance, _, tneighbors) = instance.as_up().unwrap();
+    for tnbr_idx in tneighbors.indexes().collect::<Vec<_>>() {
+        TargetedNbr::update(&mut instance, tneighbors, tnbr_idx);
+    }
+}
+
+pub(crate) fn process_status_update(
+    instance: &mut InstanceUpView<'_>,
+    msg: PwStatusMsg,
+) {
+    // Ignore status updates of pseudowires that were meanwhile uninstalled or
+    // recreated.
+    let Some(pw) = instance.state.pseudowires.get_mut(&msg.name) else {
+        return;
+    };
+    if pw.installed.is_none() || pw.local_label != msg.local_label {
+        return;
+    }
+
+    let was_up = pw.is_up();
+    pw.programmed = msg.installed;
+    if pw.is_up() != was_up {
+        if pw.is_up() {
+            Debug::PwUp(&pw.name).log();
+        } else {
+            Debug::PwDown(&pw.name).log();
+        }
+    }
+}
+
+pub(crate) fn process_nbr_msg_label(
+    instance: &mut InstanceUpView<'_>,
+    nbr_idx: NeighborIndex,
+    msg: &LabelMsg,
+    fec_elem: FecElem,
+) {
+    let nbr = &mut instance.state.neighbors[nbr_idx];
+
+    match msg.msg_type {
+        LabelMessageType::LabelMapping => {
+            // Group wildcards are rejected when decoding mappings.
+            let key = PwFecKey::from_rcvd_fec_elem(&fec_elem).unwrap();
+            let (control_word, group_id, mtu) = match fec_elem {
+                FecElem::Pwid(elem) => {
+                    (elem.control_word, elem.group_id, elem.mtu)
+                }
+                FecElem::GenPwid(elem) => (
+                    elem.control_word,
+                    msg.pw_group_id.as_ref().map_or(0, |tlv| tlv.0),
+                    msg.pw_if_params.as_ref().and_then(|tlv| tlv.mtu),
+                ),
+                _ => unreachable!(),
+            };
+            let mapping = PwMapping {
+                label: msg.get_label().unwrap(),
+                control_word,
+                group_id,
+                mtu,
+                status: msg.pw_status.as_ref().map(|tlv| tlv.0),
+            };
+
+            // Release the previously received label, if it changed.
+            if let Some(old_mapping) = nbr.rcvd_pw_mappings.insert(key, mapping)
+            {
+                if old_mapping.label != mapping.label {
+                    nbr.send_label_release(
+                        &instance.state.msg_id,
+                        fec_elem,
+                        Some(old_mapping.label),
+                    );
+                }
+            }
+        }
+        LabelMessageType::LabelWithdraw => {
+            // Send label release.
+            nbr.send_label_release(
+                &instance.state.msg_id,
+                fec_elem,
+                msg.get_label(),
+            );
+
+            // Remove record of previously received label mapping(s).
+            let label = msg.get_label();
+            match PwFecKey::from_rcvd_fec_elem(&fec_elem) {
+                Some(key) => {
+                    if let btree_map::Entry::Occupied(o) =
+                        nbr.rcvd_pw_mappings.entry(key)
+                    {
+                        if label.is_none() || label == Some(o.get().label) {
+                            o.remove();
+                        }
+                    }
+                }
+                None => {
+                    let group_id = group_wildcard(&fec_elem).unwrap();
+                    nbr.rcvd_pw_mappings.retain(|key, mapping| {
+                        !matches!(key, PwFecKey::Pwid { .. })
+                            || mapping.group_id != group_id
+                    });
+                }
+            }
+        }
+        LabelMessageType::LabelRelease => {
+            // Label releases either acknowledge previously sent withdraws, or
+            // indicate that the remote PE isn't going to use the advertised
+            // label, in which case the pseudowire stays down.
+        }
+        LabelMessageType::LabelRequest | LabelMessageType::LabelAbortReq => {
+            // Pseudowire labels are distributed in the Downstream Unsolicited
+            // mode only.
+        }
+    }
+}
+
+pub(crate) fn process_nbr_withdraw_all(
+    instance: &mut InstanceUpView<'_>,
+    nbr_idx: NeighborIndex,
+    label: Option<Label>,
+) {
+    let nbr = &mut instance.state.neighbors[nbr_idx];
+    nbr.rcvd_pw_mappings
+        .retain(|_, mapping| label.is_some_and(|label| label != mapping.label));
+}
+
+pub(crate) fn process_nbr_msg_notification(
+    instance: &mut InstanceUpView<'_>,
+    nbr_idx: NeighborIndex,
+    msg: NotifMsg,
+) {
+    let nbr = &mut instance.state.neighbors[nbr_idx];
+    let (Some(fec), Some(pw_status)) = (msg.fec, msg.pw_status) else {
+        return;
+    };
+
+    // Update the status of the referred pseudowire(s).
+    for fec_elem in fec.0.iter().filter(|fec_elem| {
+        matches!(fec_elem, FecElem::Pwid(_) | FecElem::GenPwid(_))
+    }) {
+        match PwFecKey::from_rcvd_fec_elem(fec_elem) {
+            Some(key) => {
+                if let Some(mapping) = nbr.rcvd_pw_mappings.get_mut(&key) {
+                    mapping.status = Some(pw_status.0);
+                }
+            }
+            None => {
+                let group_id = group_wildcard(fec_elem).unwrap();
+                for (_, mapping) in
+                    nbr.rcvd_pw_mappings.iter_mut().filter(|(key, mapping)| {
+                        matches!(key, PwFecKey::Pwid { .. })
+                            && mapping.group_id == group_id
+                    })
+                {
+                    mapping.status = Some(pw_status.0);
+                }
+            }
+        }
+    }
+
+    update_all(instance);
+}
@@ -5,56 +5,42 @@
 //
 
 use std::net::Ipv4Addr;
-use std::sync::Mutex;
 
-use holo_utils::mpls::{Label, LabelManager};
-use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
-    AddressFlags, AddressMsg, InterfaceUpdateMsg, Nexthop, RouteKeyMsg,
-    RouteMsg,
+    AddressFlags, AddressMsg, InterfaceFlags, InterfaceUpdateMsg, Nexthop,
+    RouteKeyMsg, RouteMsg,
 };
 use ipnetwork::IpNetwork;
 use maplit::btreeset;
 
-use crate::debug::Debug;
 use crate::fec::Fec;
 use crate::instance::{Instance, InstanceUpView};
 use crate::northbound::notification;
+use crate::packet::messages::address::TlvAddressList;
 use crate::packet::AddressMessageType;
-use crate::{events, southbound};
+use crate::pw::AcIfaceSys;
+use crate::{events, policy, pw, southbound};
 
 // ===== helper functions =====
 
-fn local_label_update(fec: &mut Fec, label_manager: &Mutex<LabelManager>) {
-    if fec.inner.local_label.is_some() {
-        return;
-    }
-
-    let protocol = fec.inner.protocol.unwrap();
-    let label = if protocol == Protocol::DIRECT {
-        Label::new(Label::IMPLICIT_NULL)
-    } else {
-        let mut label_manager = label_manager.lock().unwrap();
-        label_manager.label_request().unwrap()
-    };
-    let label = Some(label);
-
-    Debug::FecLabelUpdate(fec, &label).log();
-    fec.inner.local_label = label;
-}
-
 fn process_new_fec(instance: &mut InstanceUpView<'_>, prefix: IpNetwork) {
     let fec = instance.state.fecs.get_mut(&prefix).unwrap();
 
     // FEC.1: perform lsr label distribution procedure.
-    let msg_id = &instance.state.msg_id;
-    for nbr in instance
-        .state
-        .neighbors
-        .iter_mut()
-        .filter(|nbr| nbr.is_operational())
-    {
-        nbr.send_label_mapping(msg_id, fec);
+    if fec.inner.local_label.is_some() {
+        let msg_id = &instance.state.msg_id;
+        let match_sets = &instance.shared.policy_match_sets;
+        for nbr in instance.state.neighbors.iter_mut().filter(|nbr| {
+            nbr.is_operational()
+                && policy::label_advertise_check(
+                    instance.config,
+                    match_sets,
+                    nbr.lsr_id,
+                    &prefix,
+                )
+        }) {
+            nbr.send_label_mapping(msg_id, fec);
+        }
     }
 
     for nexthop_addr in fec.nexthops.keys().copied().collect::<Vec<_>>() {
@@ -90,6 +76,24 @@ pub(crate) fn process_iface_update(
     instance: &mut Instance,
     msg: InterfaceUpdateMsg,
 ) {
+    // Update the attachment circuits of the configured pseudowires.
+    if instance
+        .config
+        .pseudowires
+        .values()
+        .any(|pw_cfg| pw_cfg.interface.as_ref() == Some(&msg.ifname))
+    {
+        let ac = AcIfaceSys {
+            ifindex: msg.ifindex,
+            mtu: msg.mtu,
+            operative: msg.flags.contains(InterfaceFlags::OPERATIVE),
+        };
+        instance.system.ac_ifaces.insert(msg.ifname.clone(), ac);
+        if let Some((mut instance, _, _)) = instance.as_up() {
+            pw::update_all(&mut instance);
+        }
+    }
+
     let Some((mut instance, interfaces, _)) = instance.as_up() else {
         return;
     };
@@ -102,6 +106,15 @@ pub(crate) fn process_iface_update(
     }
 }
 
+pub(crate) fn process_iface_del(instance: &mut Instance, ifname: String) {
+    // Remove attachment circuit (if any).
+    if instance.system.ac_ifaces.remove(&ifname).is_some() {
+        if let Some((mut instance, _, _)) = instance.as_up() {
+            pw::update_all(&mut instance);
+        }
+    }
+}
+
 pub(crate) fn process_addr_add(instance: &mut Instance, msg: AddressMsg) {
     let Some((mut instance, interfaces, _)) = instance.as_up() else {
         return;
@@ -123,13 +136,30 @@ pub(crate) fn process_addr_add(instance: &mut Instance, msg: AddressMsg) {
                     nbr.send_address(
                         &instance.state.msg_id,
                         AddressMessageType::Address,
-                        btreeset![addr.ip()],
+                        TlvAddressList::Ipv4(btreeset![addr.ip()]),
                     )
                 }
             }
         }
         IpNetwork::V6(addr) => {
-            instance.system.ipv6_addr_list.insert(addr);
+            if instance.system.ipv6_addr_list.insert(addr)
+                && instance.state.ipv6.is_some()
+                && !addr.ip().is_unicast_link_local()
+            {
+                // Inform neighbors about new address.
+                for nbr in instance
+                    .state
+                    .neighbors
+                    .iter_mut()
+                    .filter(|nbr| nbr.is_operational())
+                {
+                    nbr.send_address(
+                        &instance.state.msg_id,
+                        AddressMessageType::Address,
+                        TlvAddressList::Ipv6(btreeset![addr.ip()]),
+                    )
+                }
+            }
         }
     }
 
@@ -142,7 +172,10 @@ pub(crate) fn process_addr_add(instance: &mut Instance, msg: AddressMsg) {
                 }
             }
             IpNetwork::V6(addr) => {
-                iface.system.ipv6_addr_list.insert(addr);
+                if iface.system.ipv6_addr_list.insert(addr) {
+                    // Check if LDP needs to be activated on this interface.
+                    iface.update(&mut instance);
+                }
             }
         }
     }
@@ -169,13 +202,30 @@ pub(crate) fn process_addr_del(instance: &mut Instance, msg: AddressMsg) {
                     nbr.send_address(
                         &instance.state.msg_id,
                         AddressMessageType::AddressWithdraw,
-                        btreeset![addr.ip()],
+                        TlvAddressList::Ipv4(btreeset![addr.ip()]),
                     )
                 }
             }
         }
         IpNetwork::V6(addr) => {
-            instance.system.ipv6_addr_list.remove(&addr);
+            if instance.system.ipv6_addr_list.remove(&addr)
+                && instance.state.ipv6.is_some()
+                && !addr.ip().is_unicast_link_local()
+            {
+                // Inform neighbors about deleted address.
+                for nbr in instance
+                    .state
+                    .neighbors
+                    .iter_mut()
+                    .filter(|nbr| nbr.is_operational())
+                {
+                    nbr.send_address(
+                        &instance.state.msg_id,
+                        AddressMessageType::AddressWithdraw,
+                        TlvAddressList::Ipv6(btreeset![addr.ip()]),
+                    )
+                }
+            }
         }
     }
 
@@ -188,7 +238,10 @@ pub(crate) fn process_addr_del(instance: &mut Instance, msg: AddressMsg) {
                 }
             }
             IpNetwork::V6(addr) => {
-                iface.system.ipv6_addr_list.remove(&addr);
+                if iface.system.ipv6_addr_list.remove(&addr) {
+                    // Check if LDP needs to be disabled on this interface.
+                    iface.update(&mut instance);
+                }
             }
         }
     }
@@ -246,12 +299,20 @@ pub(crate) fn process_route_add(instance: &mut Instance, msg: RouteMsg) {
     }
 
     // Allocate new label if necessary.
-    local_label_update(fec, &instance.shared.label_manager);
+    policy::local_label_update(
+        fec,
+        instance.config,
+        instance.shared,
+        &mut instance.state.gr_preserved_labels,
+    );
     process_new_fec(&mut instance, prefix);
+
+    // Route changes might affect the LSPs used to reach the remote PEs.
+    pw::update_all(&mut instance);
 }
 
 pub(crate) fn process_route_del(instance: &mut Instance, msg: RouteKeyMsg) {
-    let Some((instance, _, _)) = instance.as_up() else {
+    let Some((mut instance, _, _)) = instance.as_up() else {
         return;
     };
 
@@ -260,14 +321,20 @@ pub(crate) fn process_route_del(instance: &mut Instance, msg: RouteKeyMsg) {
         let old_fec_status = fec.is_operational();
 
         // Withdraw previously allocated label.
-        let msg_id = &instance.state.msg_id;
-        for nbr in instance
-            .state
-            .neighbors
-            .iter_mut()
-            .filter(|nbr| nbr.is_operational())
-        {
-            nbr.send_label_withdraw(msg_id, fec);
+        if fec.inner.local_label.is_some() {
+            let msg_id = &instance.state.msg_id;
+            let match_sets = &instance.shared.policy_match_sets;
+            for nbr in instance.state.neighbors.iter_mut().filter(|nbr| {
+                nbr.is_operational()
+                    && policy::label_advertise_check(
+                        instance.config,
+                        match_sets,
+                        nbr.lsr_id,
+                        &prefix,
+                    )
+            }) {
+                nbr.send_label_withdraw(msg_id, fec);
+            }
         }
 
         // Uninstall learned labels.
@@ -288,6 +355,7 @@ pub(crate) fn process_route_del(instance: &mut Instance, msg: RouteKeyMsg) {
 
         // Delete nexthops.
         fec.nexthops.clear();
+        fec.inner.protocol = None;
 
         if old_fec_status != fec.is_operational() {
             notification::mpls_ldp_fec_event(
@@ -297,4 +365,7 @@ pub(crate) fn process_route_del(instance: &mut Instance, msg: RouteKeyMsg) {
             );
         }
     }
+
+    // Route changes might affect the LSPs used to reach the remote PEs.
+    pw::update_all(&mut instance);
 }
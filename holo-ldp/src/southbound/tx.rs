@@ -4,9 +4,11 @@
 // SPDX-License-Identifier: MIT
 //
 
-use holo_utils::ibus::{IbusMsg, IbusSender};
+use holo_utils::ibus::{IbusMsg, IbusSender, LdpSyncState};
 use holo_utils::protocol::Protocol;
-use holo_utils::southbound::{self, LabelInstallMsg, LabelUninstallMsg};
+use holo_utils::southbound::{
+    self, LabelInstallMsg, LabelUninstallMsg, PwInstallMsg, PwUninstallMsg,
+};
 
 use crate::fec::{FecInner, Nexthop};
 
@@ -42,6 +44,7 @@ pub(crate) fn label_install(
             ifindex: nexthop.ifindex.unwrap(),
             addr: nexthop.addr,
             labels: vec![remote_label],
+            weight: None,
         }]
         .into(),
         route: Some((protocol, *fec.prefix)),
@@ -79,6 +82,7 @@ pub(crate) fn label_uninstall(
             ifindex: nexthop.ifindex.unwrap(),
             addr: nexthop.addr,
             labels: vec![remote_label],
+            weight: None,
         }]
         .into(),
         route: Some((protocol, *fec.prefix)),
@@ -88,3 +92,34 @@ pub(crate) fn label_uninstall(
     let msg = IbusMsg::RouteMplsDel(msg);
     let _ = ibus_tx.send(msg);
 }
+
+pub(crate) fn pw_install(ibus_tx: &IbusSender, msg: &PwInstallMsg) {
+    let msg = IbusMsg::RoutePwAdd(msg.clone());
+    let _ = ibus_tx.send(msg);
+}
+
+pub(crate) fn pw_uninstall(ibus_tx: &IbusSender, msg: &PwInstallMsg) {
+    // Fill-in message.
+    let msg = PwUninstallMsg {
+        protocol: Protocol::LDP,
+        name: msg.name.clone(),
+        ifindex: msg.ifindex,
+        local_label: msg.local_label,
+    };
+
+    // Send message.
+    let msg = IbusMsg::RoutePwDel(msg);
+    let _ = ibus_tx.send(msg);
+}
+
+pub(crate) fn ldp_sync_update(
+    ibus_tx: &IbusSender,
+    ifname: &str,
+    state: LdpSyncState,
+) {
+    let msg = IbusMsg::LdpSyncUpd {
+        ifname: ifname.to_owned(),
+        state,
+    };
+    let _ = ibus_tx.send(msg);
+}
@@ -9,8 +9,9 @@ use std::sync::atomic::{self, AtomicU32, AtomicU64};
 use std::sync::Arc;
 use std::time::Duration;
 
+use holo_utils::ip::{AddressFamily, IpAddrKind};
 use holo_utils::socket::{
-    OwnedReadHalf, OwnedWriteHalf, TcpListener, UdpSocket,
+    OwnedReadHalf, OwnedWriteHalf, TcpAuth, TcpListener, UdpSocket,
 };
 use holo_utils::task::{IntervalTask, Task, TimeoutTask};
 use holo_utils::{Sender, UnboundedReceiver};
@@ -43,6 +44,10 @@ use crate::network;
 //                 nbr_rx (Nx) -> |              | -> (Nx) nbr_tx
 //     nbr_kalive_timeout (Nx) -> |              | -> (Nx) nbr_kalive_interval
 //    nbr_backoff_timeout (Nx) -> |              |
+// nbr_trans_pref_timeout (Nx) -> |              |
+//         nbr_gr_timeout (Nx) -> |              |
+//        gr_fwd_holdtime (1x) -> |              |
+//      tnbr_prot_timeout (Nx) -> |              |
 //                                +--------------+
 //                         ibus_tx (1x) | ^ (1x) ibus_rx
 //                                      | |
@@ -80,11 +85,17 @@ pub mod messages {
             NbrRxPdu(NbrRxPduMsg),
             NbrKaTimeout(NbrKaTimeoutMsg),
             NbrBackoffTimeout(NbrBackoffTimeoutMsg),
+            NbrTransPrefTimeout(NbrTransPrefTimeoutMsg),
+            NbrGrTimeout(NbrGrTimeoutMsg),
+            GrFwdHoldtime(GrFwdHoldtimeMsg),
+            TnbrProtTimeout(TnbrProtTimeoutMsg),
         }
 
         #[derive(Debug, Deserialize, Serialize)]
         pub struct UdpRxPduMsg {
             pub src_addr: IpAddr,
+            // Receiving interface (present only for IPv6 link-local sources).
+            pub ifindex: Option<u32>,
             pub multicast: bool,
             pub pdu: Result<Pdu, DecodeError>,
         }
@@ -125,6 +136,24 @@ pub mod messages {
             pub lsr_id: Ipv4Addr,
         }
 
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct NbrTransPrefTimeoutMsg {
+            pub lsr_id: Ipv4Addr,
+        }
+
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct NbrGrTimeoutMsg {
+            pub lsr_id: Ipv4Addr,
+        }
+
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct GrFwdHoldtimeMsg {}
+
+        #[derive(Debug, Deserialize, Serialize)]
+        pub struct TnbrProtTimeoutMsg {
+            pub addr: IpAddr,
+        }
+
         impl TcpAcceptMsg {
             pub(crate) fn stream(&mut self) -> TcpStream {
                 #[cfg(not(feature = "testing"))]
@@ -232,12 +261,13 @@ pub(crate) fn extended_discovery_rx(
 // Send periodic LDP link hello messages.
 pub(crate) fn iface_hello_interval(
     interface: &Interface,
+    af: AddressFamily,
     disc_socket: &Arc<UdpSocket>,
     instance_state: &InstanceState,
 ) -> IntervalTask {
     #[cfg(not(feature = "testing"))]
     {
-        let span1 = debug_span!("interface", name = %interface.name);
+        let span1 = debug_span!("interface", name = %interface.name, %af);
         let _span1_guard = span1.enter();
         let span2 = debug_span!("discovery");
         let _span2_guard = span2.enter();
@@ -247,7 +277,7 @@ pub(crate) fn iface_hello_interval(
         let disc_socket = disc_socket.clone();
         let router_id = instance_state.router_id;
         let msg_id = instance_state.msg_id.clone();
-        let hello = interface.generate_hello(instance_state);
+        let hello = interface.generate_hello(af, instance_state);
 
         IntervalTask::new(
             Duration::from_secs(interface.config.hello_interval.into()),
@@ -257,7 +287,7 @@ pub(crate) fn iface_hello_interval(
                 let msg_id = msg_id.clone();
                 let hello = hello.clone();
 
-                Interface::send_hello(disc_socket, router_id, msg_id, hello)
+                Interface::send_hello(disc_socket, af, router_id, msg_id, hello)
             },
         )
     }
@@ -281,7 +311,11 @@ pub(crate) fn tnbr_hello_interval(
         let span3 = debug_span!("output");
         let _span3_guard = span3.enter();
 
-        let edisc_socket = instance_state.ipv4.edisc_socket.clone();
+        let edisc_socket = instance_state
+            .af(tnbr.addr.address_family())
+            .unwrap()
+            .edisc_socket
+            .clone();
         let addr = tnbr.addr;
         let router_id = instance_state.router_id;
         let msg_id = instance_state.msg_id.clone();
@@ -363,7 +397,7 @@ pub(crate) fn tcp_listener(
 pub(crate) fn tcp_connect(
     nbr: &Neighbor,
     local_addr: IpAddr,
-    password: Option<&str>,
+    auth: Option<TcpAuth>,
     tcp_connectp: &Sender<messages::input::TcpConnectMsg>,
 ) -> Task<()> {
     #[cfg(not(feature = "testing"))]
@@ -374,7 +408,6 @@ pub(crate) fn tcp_connect(
         let nbr_id = nbr.id;
         let remote_addr = nbr.trans_addr;
         let gtsm = nbr.flags.contains(NeighborFlags::GTSM);
-        let password = password.map(String::from);
         let tcp_connectp = tcp_connectp.clone();
         Task::spawn(
             async move {
@@ -383,7 +416,7 @@ pub(crate) fn tcp_connect(
                         local_addr,
                         remote_addr,
                         gtsm,
-                        &password,
+                        &auth,
                     )
                     .await;
 
@@ -597,3 +630,101 @@ pub(crate) fn nbr_backoff_timeout(
         TimeoutTask {}
     }
 }
+
+// Neighbor dual-stack transport preference timeout task.
+pub(crate) fn nbr_trans_pref_timeout(
+    nbr: &Neighbor,
+    max_wait: u16,
+    nbr_trans_pref_timeoutp: &Sender<messages::input::NbrTransPrefTimeoutMsg>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let nbr_trans_pref_timeoutp = nbr_trans_pref_timeoutp.clone();
+        let lsr_id = nbr.lsr_id;
+
+        TimeoutTask::new(
+            Duration::from_secs(max_wait.into()),
+            move || async move {
+                let msg = messages::input::NbrTransPrefTimeoutMsg { lsr_id };
+                let _ = nbr_trans_pref_timeoutp.send(msg).await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Neighbor graceful restart (reconnect or recovery) timeout task.
+pub(crate) fn nbr_gr_timeout(
+    nbr: &Neighbor,
+    timeout: u32,
+    nbr_gr_timeoutp: &Sender<messages::input::NbrGrTimeoutMsg>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let nbr_gr_timeoutp = nbr_gr_timeoutp.clone();
+        let lsr_id = nbr.lsr_id;
+
+        TimeoutTask::new(
+            Duration::from_secs(timeout.into()),
+            move || async move {
+                let msg = messages::input::NbrGrTimeoutMsg { lsr_id };
+                let _ = nbr_gr_timeoutp.send(msg).await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// MPLS forwarding state holding timer task.
+pub(crate) fn gr_fwd_holdtime(
+    holdtime: u16,
+    gr_fwd_holdtimep: &Sender<messages::input::GrFwdHoldtimeMsg>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let gr_fwd_holdtimep = gr_fwd_holdtimep.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(holdtime.into()),
+            move || async move {
+                let msg = messages::input::GrFwdHoldtimeMsg {};
+                let _ = gr_fwd_holdtimep.send(msg).await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Targeted neighbor session protection timeout task.
+pub(crate) fn tnbr_prot_timeout(
+    tnbr: &TargetedNbr,
+    duration: u32,
+    tnbr_prot_timeoutp: &Sender<messages::input::TnbrProtTimeoutMsg>,
+) -> TimeoutTask {
+    #[cfg(not(feature = "testing"))]
+    {
+        let tnbr_prot_timeoutp = tnbr_prot_timeoutp.clone();
+        let addr = tnbr.addr;
+
+        TimeoutTask::new(
+            Duration::from_secs(duration.into()),
+            move || async move {
+                let msg = messages::input::TnbrProtTimeoutMsg { addr };
+                let _ = tnbr_prot_timeoutp.send(msg).await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
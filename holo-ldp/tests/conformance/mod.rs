@@ -9,6 +9,104 @@ mod topologies;
 use holo_ldp::instance::Instance;
 use holo_protocol::test::stub::run_test;
 
+// Test description:
+//
+// Label mappings received from a neighbor should be accepted or rejected
+// according to the configured label acceptance policy, which is reevaluated
+// whenever the policy or the referenced prefix list changes.
+//
+// Input:
+//  * Southbound: prefix list "plist1" containing 4.4.4.4/32
+// Output: no changes
+//
+// Input:
+//  * Northbound: accept only the label mappings from 3.3.3.3 that match
+//    "plist1"
+// Output:
+//  * Southbound: uninstall the labels learned from 3.3.3.3 for 3.3.3.3/32 and
+//    10.0.4.0/24
+//  * Northbound:
+//    - remove the label mappings received from 3.3.3.3, except the one for
+//      4.4.4.4/32
+//    - FEC events for 3.3.3.3/32 and 10.0.4.0/24 (down)
+//
+// Input:
+//  * Southbound: add 3.3.3.3/32 to the "plist1" prefix list
+// Output:
+//  * Southbound: install the label learned from 3.3.3.3 for 3.3.3.3/32
+//  * Northbound:
+//    - accept the label mapping received from 3.3.3.3 for 3.3.3.3/32
+//    - FEC event for 3.3.3.3/32 (up)
+//
+// Input:
+//  * Northbound: remove the label acceptance policy
+// Output:
+//  * Southbound: install the label learned from 3.3.3.3 for 10.0.4.0/24
+//  * Northbound:
+//    - accept all label mappings received from 3.3.3.3
+//    - FEC event for 10.0.4.0/24 (up)
+#[tokio::test]
+async fn label_policy_accept1() {
+    run_test::<Instance>("label-policy-accept1", "topo1-1", "rt1").await;
+}
+
+// Test description:
+//
+// Local labels should be advertised according to the configured label
+// advertisement policy, which is reevaluated whenever the policy or the
+// referenced prefix list changes.
+//
+// Input:
+//  * Southbound: prefix list "plist1" containing 1.1.1.1/32 and 4.4.4.4/32
+// Output: no changes
+//
+// Input:
+//  * Northbound: advertise only the local labels of the FECs that match
+//    "plist1"
+// Output:
+//  * Protocol: send LDP Label Withdraw messages for 2.2.2.2/32, 3.3.3.3/32,
+//    10.0.1.0/24, 10.0.2.0/24, 10.0.3.0/24 and 10.0.4.0/24 to all neighbors
+//
+// Input:
+//  * Southbound: add 10.0.0.0/16 (mask length 24) to the "plist1" prefix list
+// Output:
+//  * Protocol: send LDP Label Mapping messages for 10.0.1.0/24, 10.0.2.0/24,
+//    10.0.3.0/24 and 10.0.4.0/24 to all neighbors
+//
+// Input:
+//  * Northbound: remove the label advertisement policy
+// Output:
+//  * Protocol: send LDP Label Mapping messages for 2.2.2.2/32 and 3.3.3.3/32
+//    to all neighbors
+#[tokio::test]
+async fn label_policy_advertise1() {
+    run_test::<Instance>("label-policy-advertise1", "topo1-1", "rt1").await;
+}
+
+// Test description:
+//
+// Local labels should be assigned according to the configured label
+// assignment policy, which is reevaluated whenever the policy changes.
+//
+// Input:
+//  * Northbound: assign local labels to host routes only
+// Output:
+//  * Protocol: send LDP Label Withdraw messages for 10.0.1.0/24, 10.0.2.0/24,
+//    10.0.3.0/24 and 10.0.4.0/24 to all neighbors
+//  * Southbound: uninstall the local labels of 10.0.3.0/24 and 10.0.4.0/24
+//
+// Input:
+//  * Northbound: assign local labels to all routes
+// Output:
+//  * Protocol: send LDP Label Mapping messages for 10.0.1.0/24, 10.0.2.0/24,
+//    10.0.3.0/24 and 10.0.4.0/24 (new labels) to all neighbors
+//  * Southbound: install the new local labels of 10.0.3.0/24 and 10.0.4.0/24
+//  * Northbound: new labels advertised for 10.0.3.0/24 and 10.0.4.0/24
+#[tokio::test]
+async fn label_policy_assign1() {
+    run_test::<Instance>("label-policy-assign1", "topo1-1", "rt1").await;
+}
+
 // Test description:
 //
 // Received LDP Address messages should be rejected if the neighbor's state
@@ -177,6 +275,41 @@ async fn message_hello3() {
     run_test::<Instance>("message-hello3", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// When both LSRs operate in dual-stack mode, LDP Hello messages advertising a
+// different transport connection preference should be ignored.
+//
+// Input:
+//  * Protocol: LDP Hello message from 10.0.1.3 (new neighbor) preferring
+//    LDPoIPv4
+// Output: no changes
+//
+// Input:
+//  * Protocol: LDP Hello message from 10.0.1.3 (new neighbor) preferring
+//    LDPoIPv6
+// Output:
+//  * Northbound: new adjacency and neighbor (3.3.3.3)
+#[tokio::test]
+async fn message_hello4() {
+    run_test::<Instance>("message-hello4", "topo3-1", "rt1").await;
+}
+
+// Test description:
+//
+// The transport connection preference advertised in LDP Hello messages should
+// be ignored when the local LSR doesn't operate in dual-stack mode.
+//
+// Input:
+//  * Protocol: LDP Hello message from fe80::3 (new neighbor) preferring
+//    LDPoIPv4
+// Output:
+//  * Northbound: new adjacency and neighbor (3.3.3.3)
+#[tokio::test]
+async fn message_hello5() {
+    run_test::<Instance>("message-hello5", "topo4-1", "rt1").await;
+}
+
 // Test description:
 //
 // Receiving LDP Initialization messages while in the OPERATIONAL state should
@@ -1290,3 +1423,34 @@ async fn timeout_nbr1() {
 async fn timeout_nbr2() {
     run_test::<Instance>("timeout-nbr2", "topo1-1", "rt2").await;
 }
+
+// Test description:
+//
+// When both LSRs operate in dual-stack mode, session establishment should be
+// deferred while waiting for a Hello adjacency of the preferred address
+// family, falling back to the other address family once the maximum wait
+// time expires.
+//
+// Input:
+//  * Protocol: LDP Hello message from 10.0.1.3 (new neighbor) preferring
+//    LDPoIPv6
+// Output:
+//  * Northbound: new adjacency and neighbor (3.3.3.3)
+//
+// Input:
+//  * Protocol: TCP connection request from 3.3.3.3
+// Output: no changes
+//
+// Input:
+//  * Protocol: transport connection preference timeout (3.3.3.3)
+// Output: no changes
+//
+// Input:
+//  * Protocol: TCP connection request from 3.3.3.3
+// Output:
+//  * Northbound: neighbor 3.3.3.3 transitioned from NON EXISTENT to
+//    INITIALIZED
+#[tokio::test]
+async fn timeout_trans_pref1() {
+    run_test::<Instance>("timeout-trans-pref1", "topo3-1", "rt1").await;
+}
@@ -22,3 +22,19 @@ async fn topology2_1() {
         run_test_topology::<Instance>("topo2-1", &rt_name).await;
     }
 }
+
+#[tokio::test]
+async fn topology3_1() {
+    for rt_num in 1..=2 {
+        let rt_name = format!("rt{}", rt_num);
+        run_test_topology::<Instance>("topo3-1", &rt_name).await;
+    }
+}
+
+#[tokio::test]
+async fn topology4_1() {
+    for rt_num in 1..=2 {
+        let rt_name = format!("rt{}", rt_num);
+        run_test_topology::<Instance>("topo4-1", &rt_name).await;
+    }
+}
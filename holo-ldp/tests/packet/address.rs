@@ -46,6 +46,25 @@ static ADDRESS_MSG2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
     )
 });
 
+static ADDRESS_MSG3: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0x03, 0x01, 0x00, 0x1a, 0x00, 0x00, 0x00, 0x0c, 0x01, 0x01, 0x00,
+            0x12, 0x00, 0x02, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ],
+        AddressMsg {
+            msg_id: 12,
+            msg_type: AddressMessageType::AddressWithdraw,
+            addr_list: TlvAddressList::Ipv6(btreeset![Ipv6Addr::from_str(
+                "2001:db8:1::1"
+            )
+            .unwrap(),]),
+        }
+        .into(),
+    )
+});
+
 #[test]
 fn test_encode_address1() {
     let (ref bytes, ref msg) = *ADDRESS_MSG1;
@@ -69,3 +88,15 @@ fn test_decode_address2() {
     let (ref bytes, ref msg) = *ADDRESS_MSG2;
     IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
 }
+
+#[test]
+fn test_encode_address3() {
+    let (ref bytes, ref msg) = *ADDRESS_MSG3;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_address3() {
+    let (ref bytes, ref msg) = *ADDRESS_MSG3;
+    IPV6_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
+}
@@ -49,6 +49,50 @@ static HELLO_MSG2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
     )
 });
 
+static HELLO_MSG3: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0x01, 0x00, 0x00, 0x30, 0x00, 0x00, 0x00, 0x03, 0x04, 0x00, 0x00,
+            0x04, 0x00, 0x0f, 0x00, 0x00, 0x04, 0x01, 0x00, 0x04, 0x01, 0x01,
+            0x01, 0x01, 0x04, 0x03, 0x00, 0x10, 0x20, 0x01, 0x0d, 0xb8, 0x10,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x87, 0x01, 0x00, 0x04, 0x40, 0x00, 0x00, 0x00,
+        ],
+        HelloMsg {
+            msg_id: 3,
+            params: TlvCommonHelloParams {
+                holdtime: 15,
+                flags: HelloFlags::empty(),
+            },
+            ipv4_addr: Some(TlvIpv4TransAddr(
+                Ipv4Addr::from_str("1.1.1.1").unwrap(),
+            )),
+            ipv6_addr: Some(TlvIpv6TransAddr(
+                Ipv6Addr::from_str("2001:db8:1000::1").unwrap(),
+            )),
+            cfg_seqno: None,
+            dual_stack: Some(TlvDualStack(TransportPref::LDPOIPV4)),
+        }
+        .into(),
+    )
+});
+
+// Dual-Stack TLV with an invalid transport connection preference.
+static HELLO_MSG4: Lazy<Vec<u8>> = Lazy::new(|| {
+    vec![
+        0x01, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00, 0x04, 0x04, 0x00, 0x00, 0x04,
+        0x00, 0x0f, 0x00, 0x00, 0x87, 0x01, 0x00, 0x04, 0x50, 0x00, 0x00, 0x00,
+    ]
+});
+
+// Targeted Hello.
+static HELLO_MSG5: Lazy<Vec<u8>> = Lazy::new(|| {
+    vec![
+        0x01, 0x00, 0x00, 0x0c, 0x00, 0x00, 0x00, 0x05, 0x04, 0x00, 0x00, 0x04,
+        0x00, 0x2d, 0x80, 0x00,
+    ]
+});
+
 #[test]
 fn test_encode_hello1() {
     let (ref bytes, ref msg) = *HELLO_MSG1;
@@ -72,3 +116,72 @@ fn test_decode_hello2() {
     let (ref bytes, ref msg) = *HELLO_MSG2;
     IPV6_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
 }
+
+#[test]
+fn test_encode_hello3() {
+    let (ref bytes, ref msg) = *HELLO_MSG3;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_hello3_ipv4() {
+    // Only the transport address of the same address family as that of the
+    // packet carrying the Hello message is used (RFC 7552).
+    let (ref bytes, ref msg) = *HELLO_MSG3;
+    let Message::Hello(mut msg) = msg.clone() else {
+        unreachable!()
+    };
+    msg.ipv6_addr = None;
+    IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, &msg.into()));
+}
+
+#[test]
+fn test_decode_hello3_ipv6() {
+    let (ref bytes, ref msg) = *HELLO_MSG3;
+    let Message::Hello(mut msg) = msg.clone() else {
+        unreachable!()
+    };
+    msg.ipv4_addr = None;
+    IPV6_CXT.with(|cxt| test_decode_msg(cxt, bytes, &msg.into()));
+}
+
+#[test]
+fn test_decode_hello4() {
+    let bytes = &*HELLO_MSG4;
+    let error = IPV6_CXT.with(|cxt| test_decode_msg_error(cxt, bytes));
+    assert!(matches!(
+        error,
+        DecodeError::InvalidTransportPref(_, 0x5000)
+    ));
+}
+
+#[test]
+fn test_decode_hello5() {
+    // Targeted Hellos can't be sent from IPv6 link-local addresses.
+    let bytes = &*HELLO_MSG5;
+    let cxt = DecodeCxt {
+        pkt_info: PacketInfo {
+            src_addr: IpAddr::from_str("fe80::1").unwrap(),
+            multicast: None,
+        },
+        pdu_max_len: Pdu::DFLT_MAX_LEN,
+        validate_pdu_hdr: None,
+        validate_msg_hdr: None,
+    };
+    let error = test_decode_msg_error(&cxt, bytes);
+    assert!(matches!(error, DecodeError::InvalidSrcAddr(..)));
+
+    // The same Hello is accepted from a global address.
+    let msg = HelloMsg {
+        msg_id: 5,
+        params: TlvCommonHelloParams {
+            holdtime: 45,
+            flags: HelloFlags::TARGETED,
+        },
+        ipv4_addr: None,
+        ipv6_addr: None,
+        cfg_seqno: None,
+        dual_stack: None,
+    };
+    IPV6_CXT.with(|cxt| test_decode_msg(cxt, bytes, &msg.into()));
+}
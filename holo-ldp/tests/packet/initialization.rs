@@ -22,6 +22,39 @@ static INIT_MSG1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
             cap_dynamic: Some(TlvCapDynamic()),
             cap_twcard_fec: Some(TlvCapTwcardFec(true)),
             cap_unrec_notif: Some(TlvCapUnrecNotif(true)),
+            ft_session: None,
+        }
+        .into(),
+    )
+});
+
+static INIT_MSG2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0x02, 0x00, 0x00, 0x26, 0x00, 0x00, 0x00, 0x36, 0x05, 0x00, 0x00,
+            0x0e, 0x00, 0x01, 0x00, 0xb4, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01,
+            0x01, 0x01, 0x00, 0x00, 0x85, 0x03, 0x00, 0x0c, 0x00, 0x01, 0x00,
+            0x00, 0x00, 0x01, 0xd4, 0xc0, 0x00, 0x01, 0xd4, 0xc0,
+        ],
+        InitMsg {
+            msg_id: 54,
+            params: TlvCommonSessParams {
+                version: 1,
+                keepalive_time: 180,
+                flags: InitFlags::empty(),
+                pvlim: 0,
+                max_pdu_len: 0,
+                lsr_id: Ipv4Addr::from_str("1.1.1.1").unwrap(),
+                lspace_id: 0,
+            },
+            cap_dynamic: None,
+            cap_twcard_fec: None,
+            cap_unrec_notif: None,
+            ft_session: Some(TlvFtSession {
+                flags: FtFlags::LEARN,
+                reconnect_time: 120000,
+                recovery_time: 120000,
+            }),
         }
         .into(),
     )
@@ -38,3 +71,15 @@ fn test_decode_init1() {
     let (ref bytes, ref msg) = *INIT_MSG1;
     IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
 }
+
+#[test]
+fn test_encode_init2() {
+    let (ref bytes, ref msg) = *INIT_MSG2;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_init2() {
+    let (ref bytes, ref msg) = *INIT_MSG2;
+    IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
+}
@@ -18,6 +18,9 @@ static LABEL_MAPPING_MSG1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
             )]),
             label: Some(TlvLabel(Label::new(16))),
             request_id: None,
+            pw_status: None,
+            pw_if_params: None,
+            pw_group_id: None,
         }
         .into(),
     )
@@ -38,6 +41,9 @@ static LABEL_MAPPING_MSG2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
             )]),
             label: Some(TlvLabel(Label::new(18))),
             request_id: None,
+            pw_status: None,
+            pw_if_params: None,
+            pw_group_id: None,
         }
         .into(),
     )
@@ -56,6 +62,102 @@ static LABEL_REQUEST_MSG1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
             ))]),
             label: None,
             request_id: None,
+            pw_status: None,
+            pw_if_params: None,
+            pw_group_id: None,
+        }
+        .into(),
+    )
+});
+static LABEL_MAPPING_MSG3: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0x04, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x42, 0x01, 0x00, 0x00,
+            0x10, 0x80, 0x80, 0x05, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x64, 0x01, 0x04, 0x05, 0xdc, 0x02, 0x00, 0x00, 0x04, 0x00,
+            0x00, 0x00, 0x10, 0x89, 0x6a, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00,
+        ],
+        LabelMsg {
+            msg_id: 66,
+            msg_type: LabelMessageType::LabelMapping,
+            fec: TlvFec(vec![FecElem::Pwid(FecElemPwid {
+                control_word: true,
+                pw_type: PW_TYPE_ETHERNET,
+                group_id: 0,
+                pw_id: Some(100),
+                mtu: Some(1500),
+            })]),
+            label: Some(TlvLabel(Label::new(16))),
+            request_id: None,
+            pw_status: Some(TlvPwStatus(PwStatusFlags::empty())),
+            pw_if_params: None,
+            pw_group_id: None,
+        }
+        .into(),
+    )
+});
+static LABEL_MAPPING_MSG4: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0x04, 0x00, 0x00, 0x52, 0x00, 0x00, 0x00, 0x43, 0x01, 0x00, 0x00,
+            0x2a, 0x81, 0x00, 0x05, 0x26, 0x01, 0x08, 0x00, 0x00, 0xfd, 0xe8,
+            0x00, 0x00, 0x00, 0x01, 0x02, 0x0c, 0x00, 0x00, 0xfd, 0xe8, 0x01,
+            0x01, 0x01, 0x01, 0x00, 0x00, 0x00, 0x01, 0x02, 0x0c, 0x00, 0x00,
+            0xfd, 0xe8, 0x02, 0x02, 0x02, 0x02, 0x00, 0x00, 0x00, 0x02, 0x02,
+            0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x11, 0x89, 0x6a, 0x00, 0x04,
+            0x00, 0x00, 0x00, 0x03, 0x09, 0x6b, 0x00, 0x04, 0x01, 0x04, 0x05,
+            0xdc, 0x09, 0x6c, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00,
+        ],
+        LabelMsg {
+            msg_id: 67,
+            msg_type: LabelMessageType::LabelMapping,
+            fec: TlvFec(vec![FecElem::GenPwid(FecElemGenPwid {
+                control_word: false,
+                pw_type: PW_TYPE_ETHERNET,
+                agi: Some(0x0000_fde8_0000_0001),
+                saii: Aii {
+                    global_id: 65000,
+                    prefix: Ipv4Addr::new(1, 1, 1, 1),
+                    ac_id: 1,
+                },
+                taii: Aii {
+                    global_id: 65000,
+                    prefix: Ipv4Addr::new(2, 2, 2, 2),
+                    ac_id: 2,
+                },
+            })]),
+            label: Some(TlvLabel(Label::new(17))),
+            request_id: None,
+            pw_status: Some(TlvPwStatus(
+                PwStatusFlags::NOT_FORWARDING | PwStatusFlags::AC_RX_FAULT,
+            )),
+            pw_if_params: Some(TlvPwIfParams { mtu: Some(1500) }),
+            pw_group_id: Some(TlvPwGroupId(0)),
+        }
+        .into(),
+    )
+});
+static LABEL_WITHDRAW_MSG1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0x04, 0x02, 0x00, 0x10, 0x00, 0x00, 0x00, 0x44, 0x01, 0x00, 0x00,
+            0x08, 0x80, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x07,
+        ],
+        LabelMsg {
+            msg_id: 68,
+            msg_type: LabelMessageType::LabelWithdraw,
+            fec: TlvFec(vec![FecElem::Pwid(FecElemPwid {
+                control_word: false,
+                pw_type: PW_TYPE_ETHERNET,
+                group_id: 7,
+                pw_id: None,
+                mtu: None,
+            })]),
+            label: None,
+            request_id: None,
+            pw_status: None,
+            pw_if_params: None,
+            pw_group_id: None,
         }
         .into(),
     )
@@ -96,3 +198,39 @@ fn test_decode_label_request1() {
     let (ref bytes, ref msg) = *LABEL_REQUEST_MSG1;
     IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
 }
+
+#[test]
+fn test_encode_label_mapping3() {
+    let (ref bytes, ref msg) = *LABEL_MAPPING_MSG3;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_label_mapping3() {
+    let (ref bytes, ref msg) = *LABEL_MAPPING_MSG3;
+    IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
+}
+
+#[test]
+fn test_encode_label_mapping4() {
+    let (ref bytes, ref msg) = *LABEL_MAPPING_MSG4;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_label_mapping4() {
+    let (ref bytes, ref msg) = *LABEL_MAPPING_MSG4;
+    IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
+}
+
+#[test]
+fn test_encode_label_withdraw1() {
+    let (ref bytes, ref msg) = *LABEL_WITHDRAW_MSG1;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_label_withdraw1() {
+    let (ref bytes, ref msg) = *LABEL_WITHDRAW_MSG1;
+    IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
+}
@@ -71,6 +71,23 @@ fn test_decode_msg(cxt: &DecodeCxt, bytes: &[u8], msg_expected: &Message) {
     assert_eq!(*msg_expected, msg_actual);
 }
 
+fn test_decode_msg_error(cxt: &DecodeCxt, bytes: &[u8]) -> DecodeError {
+    let mut buf = Bytes::copy_from_slice(bytes);
+
+    // Create fake PDU decode information, required to decode LDP messages.
+    let len = buf.len() as u16;
+    let mut pdui = PduDecodeInfo {
+        version: Pdu::VERSION,
+        lsr_id: Ipv4Addr::new(1, 1, 1, 1),
+        lspace_id: 0,
+        pdu_raw: Bytes::new(),
+        pdu_len: len,
+        pdu_rlen: len,
+    };
+
+    Message::decode(&mut buf, cxt, &mut pdui).unwrap_err()
+}
+
 fn test_encode_pdu(bytes_expected: &[u8], pdu: &Pdu) {
     let bytes_actual = pdu.encode(Pdu::DFLT_MAX_LEN);
     assert_eq!(bytes_expected, bytes_actual.as_ref());
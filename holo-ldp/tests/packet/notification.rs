@@ -18,6 +18,39 @@ static NOTIFICATION_MSG1: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
             returned_msg: None,
             returned_tlvs: None,
             fec: None,
+            pw_status: None,
+        }
+        .into(),
+    )
+});
+static NOTIFICATION_MSG2: Lazy<(Vec<u8>, Message)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0x00, 0x2a, 0x00, 0x00, 0x00, 0x28, 0x03, 0x00, 0x00,
+            0x0a, 0x00, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x0c, 0x80, 0x00, 0x05, 0x04, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x64, 0x89, 0x6a, 0x00, 0x04, 0x00, 0x00,
+            0x00, 0x01,
+        ],
+        NotifMsg {
+            msg_id: 40,
+            status: TlvStatus {
+                status_code: StatusCode::PwStatus.encode(false),
+                msg_id: 0,
+                msg_type: 0,
+            },
+            ext_status: None,
+            returned_pdu: None,
+            returned_msg: None,
+            returned_tlvs: None,
+            fec: Some(TlvFec(vec![FecElem::Pwid(FecElemPwid {
+                control_word: false,
+                pw_type: PW_TYPE_ETHERNET,
+                group_id: 0,
+                pw_id: Some(100),
+                mtu: None,
+            })])),
+            pw_status: Some(TlvPwStatus(PwStatusFlags::NOT_FORWARDING)),
         }
         .into(),
     )
@@ -34,3 +67,15 @@ fn test_decode_notification1() {
     let (ref bytes, ref msg) = *NOTIFICATION_MSG1;
     IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
 }
+
+#[test]
+fn test_encode_notification2() {
+    let (ref bytes, ref msg) = *NOTIFICATION_MSG2;
+    test_encode_msg(bytes, msg);
+}
+
+#[test]
+fn test_decode_notification2() {
+    let (ref bytes, ref msg) = *NOTIFICATION_MSG2;
+    IPV4_CXT.with(|cxt| test_decode_msg(cxt, bytes, msg));
+}
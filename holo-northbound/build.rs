@@ -25,7 +25,10 @@ fn snode_normalized_name(snode: &SchemaNode<'_>) -> String {
     // HACK: distinguish nodes with the same names but different namespaces.
     if matches!(
         snode.name(),
-        "destination-prefix" | "address" | "next-hop-address"
+        "destination-prefix"
+            | "destination-address"
+            | "address"
+            | "next-hop-address"
     ) {
         if snode.module().name() == "ietf-ipv4-unicast-routing" {
             name.insert_str(0, "ipv4_");
@@ -131,6 +134,21 @@ fn generate_paths(output: &mut String, snode: SchemaNode<'_>, level: usize) {
         generate_paths(output, snode, level + 1);
     }
 
+    // Iterate over actions.
+    //
+    // NOTE: libyang stores actions in a linked list and not in a sized array,
+    // so only the first element returned by `actions()` is valid.
+    for snode in snode
+        .actions()
+        .and_then(|mut actions| actions.next())
+        .into_iter()
+        .flat_map(|snode| snode.inclusive_siblings())
+        .filter(|snode| snode.is_status_current())
+    {
+        writeln!(output).unwrap();
+        generate_paths(output, snode, level + 1);
+    }
+
     if !snode.is_schema_only() {
         // Close generated module.
         writeln!(output, "{}}}", indent).unwrap();
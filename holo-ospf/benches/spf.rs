@@ -0,0 +1,227 @@
+use std::net::Ipv4Addr;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use holo_ospf::instance::Instance;
+use holo_ospf::version::Ospfv2;
+use holo_protocol::test::setup_bench;
+use holo_protocol::test::stub::{load_snapshot, Stub};
+use holo_protocol::InstanceMsg;
+use serde_json::{json, Value};
+use tokio::runtime::Runtime;
+
+// Number of rows and columns of the grid of routers attached to rt6.
+const GRID_SIZE: u8 = 10;
+
+type TestInstance = Instance<Ospfv2>;
+
+// Router ID of the grid router at the given position.
+fn grid_router_id(row: u8, col: u8) -> Ipv4Addr {
+    Ipv4Addr::new(100, 0, row, col + 1)
+}
+
+fn router_link(
+    link_type: &str,
+    link_id: Ipv4Addr,
+    link_data: Ipv4Addr,
+    metric: u16,
+) -> Value {
+    json!({
+        "link_type": link_type,
+        "link_id": link_id,
+        "link_data": link_data,
+        "metric": metric,
+    })
+}
+
+fn router_lsa(router_id: Ipv4Addr, seq_no: u32, links: Vec<Value>) -> Value {
+    json!({
+        "hdr": {
+            "options": "E",
+            "lsa_type": 1,
+            "lsa_id": router_id,
+            "adv_rtr": router_id,
+            "seq_no": seq_no,
+            "length": 24 + 4 + 12 * links.len(),
+        },
+        "body": {
+            "Router": {
+                "flags": "",
+                "links": links,
+            }
+        }
+    })
+}
+
+// Router-LSA of rt6, with an additional link to the first grid router.
+fn rt6_router_lsa() -> Value {
+    let mask = Ipv4Addr::new(255, 255, 255, 0);
+    let links = vec![
+        router_link(
+            "PointToPoint",
+            Ipv4Addr::new(4, 4, 4, 4),
+            Ipv4Addr::new(10, 0, 7, 6),
+            10,
+        ),
+        router_link("StubNetwork", Ipv4Addr::new(10, 0, 7, 0), mask, 10),
+        router_link(
+            "PointToPoint",
+            Ipv4Addr::new(5, 5, 5, 5),
+            Ipv4Addr::new(10, 0, 8, 6),
+            10,
+        ),
+        router_link("StubNetwork", Ipv4Addr::new(10, 0, 8, 0), mask, 10),
+        router_link(
+            "StubNetwork",
+            Ipv4Addr::new(6, 6, 6, 6),
+            Ipv4Addr::BROADCAST,
+            0,
+        ),
+        router_link(
+            "PointToPoint",
+            grid_router_id(0, 0),
+            Ipv4Addr::new(6, 6, 6, 6),
+            10,
+        ),
+    ];
+    router_lsa(Ipv4Addr::new(6, 6, 6, 6), 0x80001000, links)
+}
+
+// Router-LSA of the grid router at the given position, optionally advertising
+// an extra stub network.
+fn grid_router_lsa(row: u8, col: u8, seq_no: u32, extra_stub: bool) -> Value {
+    let router_id = grid_router_id(row, col);
+    let mut links = vec![];
+    let mut neighbors = vec![];
+    if row > 0 {
+        neighbors.push(grid_router_id(row - 1, col));
+    }
+    if row < GRID_SIZE - 1 {
+        neighbors.push(grid_router_id(row + 1, col));
+    }
+    if col > 0 {
+        neighbors.push(grid_router_id(row, col - 1));
+    }
+    if col < GRID_SIZE - 1 {
+        neighbors.push(grid_router_id(row, col + 1));
+    }
+    if row == 0 && col == 0 {
+        neighbors.push(Ipv4Addr::new(6, 6, 6, 6));
+    }
+    for nbr_id in neighbors {
+        links.push(router_link("PointToPoint", nbr_id, router_id, 10));
+    }
+    links.push(router_link(
+        "StubNetwork",
+        router_id,
+        Ipv4Addr::BROADCAST,
+        0,
+    ));
+    if extra_stub {
+        links.push(router_link(
+            "StubNetwork",
+            Ipv4Addr::new(172, 16, row, col),
+            Ipv4Addr::BROADCAST,
+            10,
+        ));
+    }
+    router_lsa(router_id, seq_no, links)
+}
+
+// LS Update received from rt6 containing the given LSAs.
+fn ls_update(lsas: Vec<Value>) -> InstanceMsg<TestInstance> {
+    let msg = json!({
+        "NetRxPacket": {
+            "area_key": {"Value": "0.0.0.0"},
+            "iface_key": {"Value": "eth-rt6"},
+            "src": "10.0.7.6",
+            "dst": "224.0.0.5",
+            "packet": {
+                "Ok": {
+                    "LsUpdate": {
+                        "hdr": {
+                            "pkt_type": "LsUpdate",
+                            "router_id": "6.6.6.6",
+                            "area_id": "0.0.0.0",
+                        },
+                        "lsas": lsas,
+                    }
+                }
+            }
+        }
+    });
+    InstanceMsg::Protocol(serde_json::from_value(msg).unwrap())
+}
+
+fn spf_run() -> InstanceMsg<TestInstance> {
+    let msg = json!({"SpfDelayEvent": {"event": "DelayTimer"}});
+    InstanceMsg::Protocol(serde_json::from_value(msg).unwrap())
+}
+
+// Loads the topo2-4 snapshot of rt4 and attaches a grid of routers to rt6.
+async fn instance(incremental: bool) -> Stub<TestInstance> {
+    let mut stub = load_snapshot::<TestInstance>("topo2-4", "rt4").await;
+
+    // Disable incremental SPF if requested.
+    if !incremental {
+        let path = format!(
+            "{}/tests/conformance/ospfv2/topologies/topo2-4/rt4/config.json",
+            env!("CARGO_MANIFEST_DIR")
+        );
+        let config = std::fs::read_to_string(path).unwrap();
+        let mut config: Value = serde_json::from_str(&config).unwrap();
+        config["ietf-routing:routing"]["control-plane-protocols"]
+            ["control-plane-protocol"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .filter(|protocol| protocol["type"] == "ietf-ospf:ospfv2")
+            .for_each(|protocol| {
+                protocol["ietf-ospf:ospf"]["spf-control"]
+                    ["holo-ospf:incremental"] = false.into();
+            });
+        stub.commit_replace(&config.to_string()).await;
+    }
+
+    let mut lsas = vec![rt6_router_lsa()];
+    for row in 0..GRID_SIZE {
+        for col in 0..GRID_SIZE {
+            lsas.push(grid_router_lsa(row, col, 0x80000001, false));
+        }
+    }
+    stub.send(ls_update(lsas)).await;
+    stub.send(spf_run()).await;
+    stub.sync().await;
+    stub
+}
+
+// Updates the Router-LSA of the farthest grid router and runs SPF.
+async fn spf_update(stub: &Stub<TestInstance>, seq_no: &mut u32) {
+    *seq_no += 1;
+    let lsa = grid_router_lsa(
+        GRID_SIZE - 1,
+        GRID_SIZE - 1,
+        *seq_no,
+        *seq_no % 2 == 0,
+    );
+    stub.send(ls_update(vec![lsa])).await;
+    stub.send(spf_run()).await;
+    stub.sync().await;
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    setup_bench();
+    let rt = Runtime::new().unwrap();
+
+    for (name, incremental) in [("SPF full", false), ("SPF incremental", true)]
+    {
+        let stub = rt.block_on(instance(incremental));
+        let mut seq_no = 0x80000001;
+        c.bench_function(name, |b| {
+            b.iter(|| rt.block_on(spf_update(&stub, &mut seq_no)))
+        });
+        rt.block_on(stub.close());
+    }
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
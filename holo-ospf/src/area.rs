@@ -19,8 +19,10 @@ use crate::instance::InstanceUpView;
 use crate::interface::Interface;
 use crate::lsdb::{LsaEntry, LsaEntryFlags, LSA_INFINITY};
 use crate::northbound::configuration::{AreaCfg, RangeCfg};
+use crate::nssa::NssaTranslatorState;
 use crate::packet::lsa::{LsaKey, LsaRouterFlagsVersion};
 use crate::packet::PacketType;
+use crate::redistribution::ExternalRoute;
 use crate::route::{
     Nexthops, PathType, RouteNetFlags, RouteRtr, SummaryNet, SummaryNetFlags,
     SummaryRtr,
@@ -60,8 +62,13 @@ pub struct AreaState<V: Version> {
     // Table of summaries originated into this area.
     pub net_summaries: BTreeMap<V::IpNetwork, (u32, SummaryNet<V>)>,
     pub rtr_summaries: BTreeMap<Ipv4Addr, (u32, SummaryRtr<V>)>,
+    // Table of NSSA external routes originated into this area.
+    pub nssa_external_routes: BTreeMap<V::IpNetwork, (u32, ExternalRoute)>,
+    // NSSA translator state.
+    pub nssa_translator_state: NssaTranslatorState,
     // Statistics.
     pub spf_run_count: u32,
+    pub nssa_translator_event_count: u32,
     pub discontinuity_time: DateTime<Utc>,
     // OSPF version-specific data.
     pub version: V::State,
@@ -172,7 +179,10 @@ where
             routers: Default::default(),
             net_summaries: Default::default(),
             rtr_summaries: Default::default(),
+            nssa_external_routes: Default::default(),
+            nssa_translator_state: Default::default(),
             spf_run_count: 0,
+            nssa_translator_event_count: 0,
             discontinuity_time: Utc::now(),
             version: Default::default(),
         }
@@ -320,7 +330,7 @@ fn update_rtr_summary_lsas<V>(
 {
     // Compute summary routes.
     let area = &areas[area_idx];
-    let new_summaries = compute_rtr_summaries(is_abr, area, areas);
+    let new_summaries = compute_rtr_summaries(is_abr, area, instance, areas);
 
     // Save the old table of summary routes.
     let area = &mut areas[area_idx];
@@ -418,11 +428,14 @@ where
         .state
         .rib
         .iter()
-        // AS external routes are never advertised in summary-LSAs.
+        // AS external and NSSA routes are never advertised in summary-LSAs.
         .filter(|(_, route)| {
             !matches!(
                 route.path_type,
-                PathType::Type1External | PathType::Type2External
+                PathType::Type1External
+                    | PathType::Type2External
+                    | PathType::Type1Nssa
+                    | PathType::Type2Nssa
             )
         })
         // Skip unreachable destinations.
@@ -508,6 +521,7 @@ where
 fn compute_rtr_summaries<V>(
     is_abr: bool,
     area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
     areas: &Areas<V>,
 ) -> Vec<(Ipv4Addr, SummaryRtr<V>)>
 where
@@ -522,7 +536,11 @@ where
         .iter()
         // Check the routing table from all other areas.
         .filter(|area_src| area_src.id != area.id)
+        // ASBRs within NSSAs are never advertised in summary-LSAs.
+        .filter(|area_src| area_src.config.area_type != AreaType::Nssa)
         .flat_map(|area_src| area_src.state.routers.iter())
+        // Skip the calculating router itself.
+        .filter(|(router_id, _)| **router_id != instance.state.router_id)
         // Only ASBR routes are advertised in summary-LSAs.
         .filter(|(_, route)| route.flags.is_asbr())
         // Skip unreachable destinations.
@@ -19,7 +19,7 @@ use crate::instance::ProtocolInputChannelsTx;
 use crate::interface::Interface;
 use crate::lsdb::{LsaDelayedOrig, LsaEntry};
 use crate::neighbor::{Neighbor, NeighborNetId};
-use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
+use crate::packet::lsa::{Lsa, LsaBodyVersion, LsaHdrVersion, LsaKey};
 use crate::tasks;
 use crate::version::Version;
 
@@ -84,11 +84,16 @@ pub struct Lsdb<V: Version> {
     // List of LSAs whose origination was delayed due to the MinLSInterval
     // check.
     pub delayed_orig: HashMap<LsaKey<V::LsaType>, LsaDelayedOrig<V>>,
+    // Current generation hold interval (in milliseconds) of self-originated
+    // LSAs.
+    pub orig_hold_interval: HashMap<LsaKey<V::LsaType>, u32>,
     // List of LSAs whose sequence number is wrapping.
     pub seqno_wrapping: HashMap<LsaKey<V::LsaType>, Lsa<V>>,
     next_id: LsaEntryId,
     lsa_count: u32,
     cksum_sum: u32,
+    // Number of non-default AS-external-LSAs (RFC 1765).
+    ext_lsa_count: u32,
 }
 
 #[derive(Debug)]
@@ -147,6 +152,14 @@ impl<T> Arena<T> {
     pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = (Index, &mut T)> {
         self.0.iter_mut()
     }
+
+    pub(crate) fn get2_mut(
+        &mut self,
+        i1: Index,
+        i2: Index,
+    ) -> (Option<&mut T>, Option<&mut T>) {
+        self.0.get2_mut(i1, i2)
+    }
 }
 
 impl<T> Default for Arena<T> {
@@ -802,6 +815,9 @@ where
         lsdb_type.cksum_sum += lse.data.hdr.cksum() as u32;
         self.lsa_count += 1;
         self.cksum_sum += lse.data.hdr.cksum() as u32;
+        if lse.data.body.is_non_default_external() {
+            self.ext_lsa_count += 1;
+        }
 
         (lse_idx, lse)
     }
@@ -820,6 +836,9 @@ where
         lsdb_type.cksum_sum -= lse.data.hdr.cksum() as u32;
         self.lsa_count -= 1;
         self.cksum_sum -= lse.data.hdr.cksum() as u32;
+        if lse.data.body.is_non_default_external() {
+            self.ext_lsa_count -= 1;
+        }
 
         // Unlink LSA from different collections.
         self.id_tree.remove(&lse.id);
@@ -987,6 +1006,10 @@ where
     pub(crate) fn cksum_sum(&self) -> u32 {
         self.cksum_sum
     }
+
+    pub(crate) fn ext_lsa_count(&self) -> u32 {
+        self.ext_lsa_count
+    }
 }
 
 impl<V> Default for Lsdb<V>
@@ -1000,10 +1023,12 @@ where
             maxage_lsas: Default::default(),
             maxage_sweeper: Default::default(),
             delayed_orig: Default::default(),
+            orig_hold_interval: Default::default(),
             seqno_wrapping: Default::default(),
             next_id: Default::default(),
             lsa_count: 0,
             cksum_sum: 0,
+            ext_lsa_count: 0,
         }
     }
 }
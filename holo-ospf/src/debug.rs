@@ -6,11 +6,12 @@
 
 use std::net::Ipv4Addr;
 
+use holo_utils::ibus::LdpSyncState;
 use ipnetwork::{Ipv4Network, Ipv6Network};
 use serde::{Deserialize, Serialize};
-use tracing::{debug, debug_span};
+use tracing::{debug, debug_span, warn};
 
-use crate::gr::GrExitReason;
+use crate::gr::{GrExitReason, GrRestartStatus};
 use crate::interface::{ism, Interface};
 use crate::neighbor::{nsm, NeighborNetId};
 use crate::packet::error::LsaValidationError;
@@ -37,6 +38,8 @@ pub enum Debug<'a, V: Version> {
     InterfacePrimaryAddrDelete(&'a str),
     InterfaceLinkLocalSelect(&'a str, &'a Ipv6Network),
     InterfaceLinkLocalDelete(&'a str),
+    LdpSyncStateChange(&'a str, LdpSyncState),
+    LdpSyncHolddownExpired(&'a str),
     IsmEvent(&'a ism::State, &'a ism::Event),
     IsmTransition(&'a ism::State, &'a ism::State),
     IsmDrElection(
@@ -65,6 +68,7 @@ pub enum Debug<'a, V: Version> {
     QuestionableAck(Ipv4Addr, &'a V::LsaHdr),
     LsaDiscard(Ipv4Addr, &'a V::LsaHdr, &'a LsaValidationError),
     LsaMinArrivalDiscard(Ipv4Addr, &'a V::LsaHdr),
+    LsaOverflowDiscard(Ipv4Addr, &'a V::LsaHdr),
     LsaSelfOriginated(Ipv4Addr, &'a V::LsaHdr),
     // LSDB maintenance
     LsaInstall(&'a V::LsaHdr),
@@ -72,9 +76,15 @@ pub enum Debug<'a, V: Version> {
     LsaOriginateMinInterval(&'a V::LsaHdr),
     LsaFlush(&'a V::LsaHdr, LsaFlushReason),
     LsaRefresh(&'a V::LsaHdr),
+    LsdbOverflowEnter(u32),
+    LsdbOverflowExit,
+    LsdbMaxLsaExceeded(u32, u32),
+    StubRouterStartupEnter,
+    StubRouterStartupExit,
     // SPF
     SpfDelayFsmEvent(&'a spf::fsm::State, &'a spf::fsm::Event),
     SpfDelayFsmTransition(&'a spf::fsm::State, &'a spf::fsm::State),
+    SpfIncrementalFallback(Ipv4Addr),
     SpfNetworkUnreachableAbr(&'a V::IpNetwork, Ipv4Addr),
     SpfRouterUnreachableAbr(&'a Ipv4Addr, Ipv4Addr),
     SpfUnreachableAsbr(&'a V::IpNetwork, Ipv4Addr),
@@ -82,6 +92,9 @@ pub enum Debug<'a, V: Version> {
     GrHelperReject(Ipv4Addr, GrRejectReason),
     GrHelperEnter(Ipv4Addr, GrReason, u32),
     GrHelperExit(Ipv4Addr, GrExitReason),
+    GrRestartPrepare(GrReason, u32),
+    GrRestartEnter(GrRestartStatus, GrReason, u32),
+    GrRestartExit(GrExitReason),
 }
 
 // Reason why an OSPF instance is inactive.
@@ -105,6 +118,8 @@ pub enum InterfaceInactiveReason {
     MissingLinkLocalAddress,
     LoopedBack,
     Resetting,
+    VirtualLinkUnreachable,
+    MultiAreaNbrUnknown,
 }
 
 // Reason why a SeqNoMismatch event was generated.
@@ -191,12 +206,19 @@ where
                     debug!(%addr, "{}", self);
                 })
             }
-            Debug::InterfaceLinkLocalDelete(name) => {
+            Debug::InterfaceLinkLocalDelete(name)
+            | Debug::LdpSyncHolddownExpired(name) => {
                 // Parent span(s): ospf-instance
                 debug_span!("interface", %name).in_scope(|| {
                     debug!("{}", self);
                 })
             }
+            Debug::LdpSyncStateChange(name, state) => {
+                // Parent span(s): ospf-instance
+                debug_span!("interface", %name).in_scope(|| {
+                    debug!(?state, "{}", self);
+                })
+            }
             Debug::IsmEvent(state, event) => {
                 // Parent span(s): ospf-instance
                 debug_span!("fsm").in_scope(|| {
@@ -263,6 +285,7 @@ where
             }
             Debug::QuestionableAck(router_id, lsa_hdr)
             | Debug::LsaMinArrivalDiscard(router_id, lsa_hdr)
+            | Debug::LsaOverflowDiscard(router_id, lsa_hdr)
             | Debug::LsaSelfOriginated(router_id, lsa_hdr) => {
                 // Parent span(s): ospf-instance
                 debug_span!("neighbor", %router_id).in_scope(|| {
@@ -286,6 +309,20 @@ where
                 // Parent span(s): ospf-instance
                 debug!(?lsa_hdr, %reason, "{}", self);
             }
+            Debug::LsdbOverflowEnter(ext_lsdb_limit) => {
+                // Parent span(s): ospf-instance
+                debug!(%ext_lsdb_limit, "{}", self);
+            }
+            Debug::LsdbOverflowExit
+            | Debug::StubRouterStartupEnter
+            | Debug::StubRouterStartupExit => {
+                // Parent span(s): ospf-instance
+                debug!("{}", self);
+            }
+            Debug::LsdbMaxLsaExceeded(lsa_count, max_lsa) => {
+                // Parent span(s): ospf-instance
+                warn!(%lsa_count, %max_lsa, "{}", self);
+            }
 
             Debug::SpfDelayFsmEvent(state, event) => {
                 // Parent span(s): ospf-instance
@@ -295,6 +332,10 @@ where
                 // Parent span(s): ospf-instance
                 debug!(?old_state, ?new_state, "{}", self);
             }
+            Debug::SpfIncrementalFallback(area_id) => {
+                // Parent span(s): ospf-instance
+                debug!(%area_id, "{}", self);
+            }
             Debug::SpfNetworkUnreachableAbr(destination, abr) => {
                 // Parent span(s): ospf-instance
                 debug!(%destination, %abr, "{}", self);
@@ -325,6 +366,18 @@ where
                     debug!(%reason, "{}", self);
                 })
             }
+            Debug::GrRestartPrepare(reason, grace_period) => {
+                // Parent span(s): ospf-instance
+                debug!(%reason, %grace_period, "{}", self);
+            }
+            Debug::GrRestartEnter(status, reason, grace_period) => {
+                // Parent span(s): ospf-instance
+                debug!(%status, %reason, %grace_period, "{}", self);
+            }
+            Debug::GrRestartExit(reason) => {
+                // Parent span(s): ospf-instance
+                debug!(%reason, "{}", self);
+            }
         }
     }
 }
@@ -374,6 +427,12 @@ where
             Debug::InterfaceLinkLocalDelete(..) => {
                 write!(f, "link-local address deleted")
             }
+            Debug::LdpSyncStateChange(..) => {
+                write!(f, "LDP-IGP synchronization state change")
+            }
+            Debug::LdpSyncHolddownExpired(..) => {
+                write!(f, "LDP-IGP synchronization holddown timer expired")
+            }
             Debug::IsmEvent(..) => {
                 write!(f, "event")
             }
@@ -419,6 +478,9 @@ where
             Debug::LsaMinArrivalDiscard(..) => {
                 write!(f, "discarding LSA due to the MinLSArrival check")
             }
+            Debug::LsaOverflowDiscard(..) => {
+                write!(f, "discarding AS-external-LSA due to LSDB overflow")
+            }
             Debug::LsaSelfOriginated(..) => {
                 write!(f, "received self-originated LSA")
             }
@@ -440,12 +502,30 @@ where
             Debug::LsaRefresh(..) => {
                 write!(f, "refreshing LSA")
             }
+            Debug::LsdbOverflowEnter(..) => {
+                write!(f, "entering LSDB overflow state")
+            }
+            Debug::LsdbOverflowExit => {
+                write!(f, "exiting from LSDB overflow state")
+            }
+            Debug::LsdbMaxLsaExceeded(..) => {
+                write!(f, "number of LSAs exceeds the configured maximum")
+            }
+            Debug::StubRouterStartupEnter => {
+                write!(f, "entering stub router state on startup")
+            }
+            Debug::StubRouterStartupExit => {
+                write!(f, "leaving stub router state on startup")
+            }
             Debug::SpfDelayFsmEvent(..) => {
                 write!(f, "SPF Delay FSM event")
             }
             Debug::SpfDelayFsmTransition(..) => {
                 write!(f, "SPF Delay FSM state transition")
             }
+            Debug::SpfIncrementalFallback(..) => {
+                write!(f, "falling back to full SPF computation")
+            }
             Debug::SpfNetworkUnreachableAbr(..)
             | Debug::SpfRouterUnreachableAbr(..) => {
                 write!(f, "no route found for originating ABR")
@@ -462,6 +542,15 @@ where
             Debug::GrHelperExit(..) => {
                 write!(f, "exiting from helper mode")
             }
+            Debug::GrRestartPrepare(..) => {
+                write!(f, "preparing for planned graceful restart")
+            }
+            Debug::GrRestartEnter(..) => {
+                write!(f, "entering graceful restart")
+            }
+            Debug::GrRestartExit(..) => {
+                write!(f, "exiting from graceful restart")
+            }
         }
     }
 }
@@ -516,6 +605,12 @@ impl std::fmt::Display for InterfaceInactiveReason {
             InterfaceInactiveReason::Resetting => {
                 write!(f, "resetting")
             }
+            InterfaceInactiveReason::VirtualLinkUnreachable => {
+                write!(f, "virtual link endpoint unreachable")
+            }
+            InterfaceInactiveReason::MultiAreaNbrUnknown => {
+                write!(f, "multi-area neighbor address unknown")
+            }
         }
     }
 }
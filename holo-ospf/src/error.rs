@@ -51,6 +51,7 @@ pub enum Error<V: Version> {
     SpfDelayUnexpectedEvent(spf::fsm::State, spf::fsm::Event),
     InterfaceStartError(String, IoError),
     BootCountNvmUpdate(DatabaseError),
+    GrRestartNvmUpdate(DatabaseError),
 }
 
 // OSPF I/O errors.
@@ -75,6 +76,7 @@ pub enum InterfaceCfgError {
     HelloIntervalMismatch(u16, u16),
     DeadIntervalMismatch(u32, u32),
     ExternalRoutingCapabilityMismatch(bool),
+    NssaCapabilityMismatch(bool),
     MtuMismatch(u16),
     DuplicateRouterId(Ipv4Addr),
 }
@@ -163,7 +165,8 @@ where
             Error::InterfaceStartError(name, error) => {
                 error!(%name, error = %with_source(error), "{}", self);
             }
-            Error::BootCountNvmUpdate(error) => {
+            Error::BootCountNvmUpdate(error)
+            | Error::GrRestartNvmUpdate(error) => {
                 error!(%error, "{}", self);
             }
         }
@@ -247,6 +250,9 @@ where
             Error::BootCountNvmUpdate(..) => {
                 write!(f, "failed to record updated boot count in non-volatile storage")
             }
+            Error::GrRestartNvmUpdate(..) => {
+                write!(f, "failed to record graceful restart state in non-volatile storage")
+            }
         }
     }
 }
@@ -376,6 +382,9 @@ impl InterfaceCfgError {
             InterfaceCfgError::ExternalRoutingCapabilityMismatch(e_bit) => {
                 warn!(%e_bit, "{}", self);
             }
+            InterfaceCfgError::NssaCapabilityMismatch(n_bit) => {
+                warn!(%n_bit, "{}", self);
+            }
             InterfaceCfgError::MtuMismatch(mtu) => {
                 warn!(%mtu, "{}", self);
             }
@@ -407,6 +416,9 @@ impl std::fmt::Display for InterfaceCfgError {
             InterfaceCfgError::ExternalRoutingCapabilityMismatch(..) => {
                 write!(f, "external routing capability mismatch")
             }
+            InterfaceCfgError::NssaCapabilityMismatch(..) => {
+                write!(f, "NSSA capability mismatch")
+            }
             InterfaceCfgError::MtuMismatch(..) => {
                 write!(f, "MTU mismatch")
             }
@@ -11,7 +11,7 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use holo_utils::bfd;
-use holo_utils::ibus::SrCfgEvent;
+use holo_utils::ibus::{LdpSyncState, SrCfgEvent};
 
 use crate::area::{Area, AreaType};
 use crate::collections::{
@@ -40,7 +40,7 @@ use crate::packet::{
     PacketType,
 };
 use crate::version::Version;
-use crate::{gr, output, spf, tasks};
+use crate::{gr, link_state, multi_area, output, spf, srv6, tasks, vlink};
 
 // ===== Interface FSM event =====
 
@@ -142,26 +142,68 @@ where
         return Ok(());
     }
 
-    // Validate IP destination address.
-    V::validate_packet_dst(iface, dst)?;
-
-    // Validate IP source address.
-    V::validate_packet_src(iface, src)?;
+    // Validate IP destination address. Packets sent over virtual links were
+    // already validated by their receiving interface.
+    if !iface.is_virtual_link() {
+        V::validate_packet_dst(iface, dst)?;
+    }
 
     // Check for Area ID mismatch.
     let pkt_type = packet.hdr().pkt_type();
-    if packet.hdr().area_id() != area.area_id {
+    let pkt_area_id = packet.hdr().area_id();
+    if pkt_area_id != area.area_id {
+        let area_id = area.area_id;
+        let ifname = iface.name.clone();
+        let multi_area = iface.is_multi_area();
+
+        // Backbone packets received on a non-backbone area might have been
+        // sent over a virtual link. In that case, process them as if they
+        // were received on the corresponding virtual interface.
+        if pkt_area_id == Ipv4Addr::UNSPECIFIED
+            && let Some((backbone, vlink)) = vlink::find(
+                area_id,
+                packet.hdr().router_id(),
+                &arenas.areas,
+                &arenas.interfaces,
+            )
+        {
+            let area_key = backbone.id.into();
+            let iface_key = vlink.id.into();
+            return process_packet(
+                instance,
+                arenas,
+                area_key,
+                iface_key,
+                src,
+                dst,
+                Ok(packet),
+            );
+        }
+
+        // Packets sent over a multi-area adjacency are also received by the
+        // raw sockets of the other interfaces attached to the same link. They
+        // are processed by the interface of the matching area only.
+        if let Some((_, pkt_area)) = arenas.areas.get_by_area_id(pkt_area_id)
+            && let Some((_, pkt_iface)) =
+                pkt_area.interfaces.get_by_name(&arenas.interfaces, &ifname)
+            && (multi_area || pkt_iface.is_multi_area())
+        {
+            return Ok(());
+        }
+
         return Err(Error::InterfaceCfgError(
-            iface.name.clone(),
+            ifname,
             src,
             pkt_type,
-            InterfaceCfgError::AreaIdMismatch(
-                packet.hdr().area_id(),
-                area.area_id,
-            ),
+            InterfaceCfgError::AreaIdMismatch(pkt_area_id, area_id),
         ));
     }
 
+    // Validate IP source address.
+    if !iface.is_virtual_link() {
+        V::validate_packet_src(iface, src)?;
+    }
+
     // OSPFv3: check for Instance ID mismatch.
     if !V::packet_instance_id_match(iface, packet.hdr()) {
         // Instance ID mismatches are expected in normal operation and do not
@@ -201,6 +243,9 @@ where
     Debug::<V>::PacketRx(iface, &src, &dst, &packet).log();
 
     if let Packet::Hello(pkt) = packet {
+        let multi_area_primary =
+            !iface.is_multi_area() && !iface.config.multi_areas.is_empty();
+
         process_packet_hello(
             iface,
             area,
@@ -209,7 +254,17 @@ where
             &arenas.lsa_entries,
             src,
             pkt,
-        )
+        )?;
+
+        // Multi-area adjacencies learn the address of their neighbor from the
+        // Hello packets received on the primary interface.
+        if multi_area_primary {
+            multi_area::update_nbr_addr(
+                area_idx, iface_idx, src, instance, arenas,
+            );
+        }
+
+        Ok(())
     } else {
         // Non-Hello packets not matching any active neighbor are discarded.
         let (nbr_idx, nbr) =
@@ -415,6 +470,13 @@ where
         ));
     }
 
+    // Check for NSSA capability mismatch.
+    if hello.options().n_bit() != (area.config.area_type == AreaType::Nssa) {
+        return Err(InterfaceCfgError::NssaCapabilityMismatch(
+            hello.options().n_bit(),
+        ));
+    }
+
     // Check for duplicate Router ID.
     if hello.router_id() == instance.state.router_id {
         return Err(InterfaceCfgError::DuplicateRouterId(hello.router_id()));
@@ -435,8 +497,11 @@ fn process_packet_dbdesc<V>(
 where
     V: Version,
 {
-    // MTU mismatch check.
-    if !iface.config.mtu_ignore && dbdesc.mtu() > iface.system.mtu.unwrap() {
+    // MTU mismatch check (not applicable to virtual links).
+    if !iface.config.mtu_ignore
+        && !iface.is_virtual_link()
+        && dbdesc.mtu() > iface.system.mtu.unwrap()
+    {
         return Err(Error::InterfaceCfgError(
             iface.name.clone(),
             src,
@@ -814,7 +879,8 @@ where
     if matches!(lsa_cmp, None | Some(Ordering::Less)) {
         // (5.a) MinLSArrival check.
         if let Some((_, lse)) = lse_tuple {
-            if lsdb::lsa_min_arrival_check(lse) {
+            if lsdb::lsa_min_arrival_check(lse, instance.config.lsa_min_arrival)
+            {
                 // Log why the LSA is being discarded.
                 Debug::<V>::LsaMinArrivalDiscard(nbr.router_id, &lsa.hdr).log();
 
@@ -823,6 +889,22 @@ where
             }
         }
 
+        // RFC 1765 - Section 2.1:
+        // Discard new non-default AS-external-LSAs once the LSDB limit has
+        // been reached.
+        if let Some(limit) = instance.config.ext_lsdb_limit
+            && lse_tuple.is_none()
+            && !lsa.hdr.is_maxage()
+            && lsa.body.is_non_default_external()
+            && instance.state.lsdb.ext_lsa_count() >= limit
+        {
+            // Log why the LSA is being discarded.
+            Debug::<V>::LsaOverflowDiscard(nbr.router_id, &lsa.hdr).log();
+
+            // Examine the next LSA.
+            return false;
+        }
+
         // (5.b) Immediately flood the new LSA out some subset of the
         // router's interfaces.
         let src = Some((iface_idx, nbr_idx));
@@ -866,7 +948,9 @@ where
         }
 
         // Grace-LSA processing.
-        if let Some((grace_period, reason, addr)) = lse.data.body.as_grace() {
+        if !lse.flags.contains(LsaEntryFlags::SELF_ORIGINATED)
+            && let Some((grace_period, reason, addr)) = lse.data.body.as_grace()
+        {
             // For OSPFv2, on broadcast, NBMA and P2MP segments, the restarting
             // neighbor is identified by the IP interface address in the body of
             // the Grace-LSA.
@@ -973,7 +1057,7 @@ where
         // Examine the next LSA.
         return false;
     }
-    if !lsdb::lsa_min_arrival_check(lse) {
+    if !lsdb::lsa_min_arrival_check(lse, instance.config.lsa_min_arrival) {
         // Send the database copy back to the sending neighbor, encapsulated
         // within a Link State Update Packet.
         nbr.lists.ls_update.insert(lsa_key, lse.data.clone());
@@ -1151,13 +1235,22 @@ where
 // ===== LSA origination event =====
 
 pub(crate) fn process_lsa_orig_event<V>(
-    instance: &InstanceUpView<'_, V>,
-    arenas: &InstanceArenas<V>,
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
     event: LsaOriginateEvent,
 ) -> Result<(), Error<V>>
 where
     V: Version,
 {
+    // While performing a graceful restart, LSAs aren't reoriginated until all
+    // pre-restart adjacencies are reestablished (RFC 3623 - Section 2.2).
+    if instance.state.gr_restart.is_some() {
+        if matches!(event, LsaOriginateEvent::NeighborToFromFull { .. }) {
+            gr::restart_check_exit(instance, arenas);
+        }
+        return Ok(());
+    }
+
     // Check which LSAs need to be reoriginated or flushed.
     V::lsa_orig_event(instance, arenas, event)
 }
@@ -1175,6 +1268,12 @@ pub(crate) fn process_lsa_orig_check<V>(
 where
     V: Version,
 {
+    // Self-originated LSAs aren't (re)originated while performing a graceful
+    // restart.
+    if instance.state.gr_restart.is_some() {
+        return Ok(());
+    }
+
     // Lookup LSDB.
     let (lsdb_idx, _) = lsdb_get(
         &instance.state.lsdb,
@@ -1240,6 +1339,14 @@ where
     let (lse_idx, _) =
         lsdb.get_mut_by_key(&mut arenas.lsa_entries, &lse_key)?;
 
+    // Self-originated LSAs aren't prematurely aged while performing a graceful
+    // restart.
+    if instance.state.gr_restart.is_some()
+        && reason == LsaFlushReason::PrematureAging
+    {
+        return Ok(());
+    }
+
     // Flush LSA.
     lsdb::flush(instance, arenas, lsdb_idx, lse_idx, reason);
 
@@ -1406,6 +1513,102 @@ where
     Ok(())
 }
 
+// ===== Graceful restart timeout (restarting router) =====
+
+pub(crate) fn process_gr_restart_timeout<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    if instance.state.gr_restart.is_some() {
+        // Exit from the graceful restart.
+        gr::restart_exit(instance, arenas, GrExitReason::TimedOut);
+    } else if instance.state.gr_restart_prepare.is_some() {
+        // Cancel the planned graceful restart.
+        gr::restart_prepare_cancel(instance, arenas);
+    }
+
+    Ok(())
+}
+
+// ===== LSDB overflow exit timeout =====
+
+pub(crate) fn process_lsdb_overflow_exit<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    _arenas: &mut InstanceArenas<V>,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    if !instance.state.lsdb_overflow {
+        return Ok(());
+    }
+
+    // Remain in the overflow state if the LSDB limit is still exceeded.
+    if let Some(limit) = instance.config.ext_lsdb_limit {
+        if instance.state.lsdb.ext_lsa_count() >= limit {
+            let interval = instance.config.exit_overflow_interval;
+            let task = tasks::lsdb_overflow_exit_timer(instance, interval);
+            instance.state.lsdb_overflow_exit_timer = Some(task);
+            return Ok(());
+        }
+    }
+
+    // Leave the overflow state.
+    lsdb::overflow_exit(instance);
+
+    Ok(())
+}
+
+// ===== Stub router on startup timeout =====
+
+pub(crate) fn process_stub_router_startup_timeout<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    _arenas: &mut InstanceArenas<V>,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    if instance.state.stub_router_startup.is_some() {
+        lsdb::stub_router_startup_exit(instance);
+    }
+
+    Ok(())
+}
+
+// ===== LDP-IGP synchronization holddown timeout =====
+
+pub(crate) fn process_ldp_sync_holddown<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    area_key: AreaKey,
+    iface_key: InterfaceKey,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    // Lookup area and interface.
+    let (_, area) = arenas.areas.get_mut_by_key(&area_key)?;
+    let (_, iface) = area
+        .interfaces
+        .get_mut_by_key(&mut arenas.interfaces, &iface_key)?;
+
+    // Stop advertising the maximum link metric even though LDP isn't
+    // synchronized yet (RFC 5443 - Section 4).
+    Debug::<V>::LdpSyncHolddownExpired(&iface.name).log();
+    iface.state.ldp_sync_holddown_expired = true;
+    iface.state.tasks.ldp_sync_holddown = None;
+
+    // (Re)originate Router-LSA.
+    instance.tx.protocol_input.lsa_orig_event(
+        LsaOriginateEvent::InterfaceCostChange { area_id: area.id },
+    );
+
+    Ok(())
+}
+
 // ===== SR configuration change event =====
 
 pub(crate) fn process_sr_cfg_change<V>(
@@ -1415,8 +1618,20 @@ pub(crate) fn process_sr_cfg_change<V>(
 where
     V: Version,
 {
-    if let Some((instance, arenas)) = instance.as_up() {
-        if instance.config.sr_enabled {
+    if let Some((mut instance, arenas)) = instance.as_up() {
+        if matches!(change, SrCfgEvent::Srv6LocatorUpdate) {
+            if instance.config.srv6_enabled {
+                // Reallocate the local SRv6 SIDs.
+                srv6::update_all(&mut instance, arenas);
+
+                // Check which LSAs need to be reoriginated or flushed.
+                V::lsa_orig_event(
+                    &instance,
+                    arenas,
+                    LsaOriginateEvent::Srv6Change,
+                )?;
+            }
+        } else if instance.config.sr_enabled {
             // Check which LSAs need to be reoriginated or flushed.
             V::lsa_orig_event(
                 &instance,
@@ -1450,10 +1665,11 @@ where
     };
 
     if let bfd::SessionKey::IpSingleHop { ifname, dst } = sess_key {
-        // Lookup area and interface.
+        // Lookup area and interface (multi-area interfaces don't use BFD).
         let (iface, area) = match arenas.areas.iter().find_map(|area| {
             area.interfaces
                 .get_by_name(&arenas.interfaces, &ifname)
+                .filter(|(_, iface)| !iface.is_multi_area())
                 .map(|(_, iface)| (iface, area))
         }) {
             Some(value) => value,
@@ -1506,3 +1722,95 @@ where
 
     Ok(())
 }
+
+// ===== BGP initial convergence =====
+
+pub(crate) fn process_bgp_initial_convergence<V>(
+    instance: &mut Instance<V>,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    let Some((mut instance, _)) = instance.as_up() else {
+        return Ok(());
+    };
+
+    // Leave the stub router state on startup, if configured to do so.
+    if instance.state.stub_router_startup.is_some()
+        && instance.config.stub_router_startup_wait_bgp
+    {
+        lsdb::stub_router_startup_exit(&mut instance);
+    }
+
+    Ok(())
+}
+
+// ===== BGP-LS topology dump request =====
+
+pub(crate) fn process_link_state_dump<V>(
+    instance: &mut Instance<V>,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    let Some((instance, arenas)) = instance.as_up() else {
+        return Ok(());
+    };
+
+    // Export the topology computed by the last SPF run.
+    if instance.config.distribute_link_state {
+        link_state::topology_update(
+            &instance,
+            &arenas.areas,
+            &arenas.lsa_entries,
+        );
+    }
+
+    Ok(())
+}
+
+// ===== LDP-IGP synchronization state update =====
+
+pub(crate) fn process_ldp_sync_update<V>(
+    instance: &mut Instance<V>,
+    ifname: String,
+    state: LdpSyncState,
+) -> Result<(), Error<V>>
+where
+    V: Version,
+{
+    let Some((instance, arenas)) = instance.as_up() else {
+        return Ok(());
+    };
+
+    // Lookup area and interface (multi-area interfaces don't use LDP-IGP
+    // synchronization).
+    let Some((iface_idx, area)) = arenas.areas.iter().find_map(|area| {
+        area.interfaces
+            .get_by_name(&arenas.interfaces, &ifname)
+            .filter(|(_, iface)| !iface.is_multi_area())
+            .map(|(iface_idx, _)| (iface_idx, area))
+    }) else {
+        return Ok(());
+    };
+    let iface = &mut arenas.interfaces[iface_idx];
+
+    // Ignore updates for interfaces that aren't registered.
+    match iface.state.ldp_sync {
+        Some(old_state) if old_state != state => (),
+        _ => return Ok(()),
+    }
+
+    Debug::<V>::LdpSyncStateChange(&iface.name, state).log();
+    iface.state.ldp_sync = Some(state);
+    if state == LdpSyncState::Synced {
+        iface.state.tasks.ldp_sync_holddown = None;
+    }
+
+    // (Re)originate Router-LSA.
+    instance.tx.protocol_input.lsa_orig_event(
+        LsaOriginateEvent::InterfaceCostChange { area_id: area.id },
+    );
+
+    Ok(())
+}
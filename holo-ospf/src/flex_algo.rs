@@ -0,0 +1,463 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::{IpAddr, Ipv4Addr};
+
+use holo_utils::mpls::Label;
+use holo_utils::southbound::Nexthop;
+use holo_utils::sr::{IgpAlgoType, SrCfgPrefixSid};
+use ipnetwork::IpNetwork;
+use num_traits::FromPrimitive;
+
+use crate::area::Area;
+use crate::collections::{Areas, Arena};
+use crate::instance::InstanceUpView;
+use crate::interface::Interface;
+use crate::lsdb::LsaEntry;
+use crate::neighbor::Neighbor;
+use crate::northbound::configuration::InstanceCfg;
+use crate::packet::tlv::{
+    AslaSabm, AslaTlv, FlexAlgoDefTlv, FlexAlgoMetricType,
+};
+use crate::spf::{Vertex, VertexIdVersion, VertexLsaVersion};
+use crate::version::Version;
+use crate::{southbound, spf, sr};
+
+// MPLS forwarding entries installed for Flex-Algo Prefix-SIDs.
+pub type FlexAlgoLfib = BTreeMap<Label, BTreeSet<Nexthop>>;
+
+// ===== global functions =====
+
+// Returns the set of SR algorithms supported by the local router.
+pub(crate) fn sr_algos(config: &InstanceCfg) -> BTreeSet<IgpAlgoType> {
+    let mut algos = BTreeSet::from([IgpAlgoType::Spf]);
+    algos.extend(config.flex_algos.keys().copied().map(IgpAlgoType::FlexAlgo));
+    algos
+}
+
+// Returns the Flexible Algorithm Definitions advertised by the local router.
+pub(crate) fn local_fads(config: &InstanceCfg) -> Vec<FlexAlgoDefTlv> {
+    config
+        .flex_algos
+        .iter()
+        .filter(|(_, fa_cfg)| fa_cfg.advertise)
+        .map(|(algo, fa_cfg)| {
+            FlexAlgoDefTlv::new(
+                *algo,
+                fa_cfg.metric_type as u8,
+                0,
+                fa_cfg.priority,
+                fa_cfg.exclude_any,
+                fa_cfg.include_any,
+                fa_cfg.include_all,
+            )
+        })
+        .collect()
+}
+
+// Returns the Flex-Algo Prefix-SIDs configured for local prefixes.
+pub(crate) fn local_prefix_sids(
+    config: &InstanceCfg,
+) -> impl Iterator<Item = (IpNetwork, IgpAlgoType, &SrCfgPrefixSid)> + '_ {
+    config.flex_algos.iter().flat_map(|(algo, fa_cfg)| {
+        fa_cfg.prefix_sids.iter().map(|(prefix, prefix_sid)| {
+            (*prefix, IgpAlgoType::FlexAlgo(*algo), prefix_sid)
+        })
+    })
+}
+
+// Returns the Application-Specific Link Attributes advertised for the given
+// interface.
+pub(crate) fn iface_asla<V>(iface: &Interface<V>) -> AslaTlv
+where
+    V: Version,
+{
+    let te = &iface.config.te;
+    AslaTlv::new(AslaSabm::X, te.metric, Some(te.admin_groups), te.min_delay)
+}
+
+// Computes the Flex-Algo constrained shortest-path trees and updates the MPLS
+// forwarding entries of the Flex-Algo Prefix-SIDs.
+pub(crate) fn update_lfib<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    areas: &Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let mut lfib = FlexAlgoLfib::new();
+    if instance.config.sr_enabled {
+        for algo in instance.config.flex_algos.keys() {
+            for area in areas.iter() {
+                compute_area(
+                    area,
+                    instance,
+                    *algo,
+                    &mut lfib,
+                    interfaces,
+                    neighbors,
+                    lsa_entries,
+                );
+            }
+        }
+    }
+
+    // Uninstall stale entries.
+    let old_lfib = std::mem::take(&mut instance.state.flex_algo_lfib);
+    for label in old_lfib.keys().filter(|label| !lfib.contains_key(label)) {
+        southbound::tx::flex_algo_label_uninstall::<V>(
+            &instance.tx.ibus,
+            *label,
+        );
+    }
+
+    // Install new or updated entries.
+    for (label, nexthops) in &lfib {
+        if old_lfib.get(label) != Some(nexthops) {
+            southbound::tx::flex_algo_label_install::<V>(
+                &instance.tx.ibus,
+                *label,
+                nexthops.clone(),
+            );
+        }
+    }
+    instance.state.flex_algo_lfib = lfib;
+}
+
+// Uninstalls all MPLS forwarding entries of the Flex-Algo Prefix-SIDs.
+pub(crate) fn uninstall_all<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    let lfib = std::mem::take(&mut instance.state.flex_algo_lfib);
+    for label in lfib.into_keys() {
+        southbound::tx::flex_algo_label_uninstall::<V>(
+            &instance.tx.ibus,
+            label,
+        );
+    }
+}
+
+// ===== helper functions =====
+
+// Computes the Flex-Algo constrained shortest-path tree of the given area, and
+// adds the resulting Prefix-SID forwarding entries to the provided LFIB.
+fn compute_area<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    algo: u8,
+    lfib: &mut FlexAlgoLfib,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let extended_lsa = instance.config.extended_lsa;
+
+    // Select the winning Flexible Algorithm Definition. The calculating router
+    // doesn't participate in the algorithm if the winning definition isn't
+    // supported.
+    let Some(fad) = fad_election(area, algo, lsa_entries) else {
+        return;
+    };
+    let Some(metric_type) = FlexAlgoMetricType::from_u8(fad.metric_type) else {
+        return;
+    };
+    if fad.calc_type != 0 || !fad.unknown_stlvs.is_empty() {
+        return;
+    }
+
+    // Compute the constrained shortest-path tree.
+    let spt = spf_run(
+        area,
+        instance,
+        algo,
+        &fad,
+        metric_type,
+        interfaces,
+        neighbors,
+        lsa_entries,
+    );
+
+    // Compute the forwarding entries of the Prefix-SIDs advertised by the
+    // routers reachable through the Flex-Algo topology.
+    for stub in V::intra_area_networks(area, extended_lsa, lsa_entries)
+        .filter(|stub| stub.vertex.lsa.is_router() && stub.vertex.hops > 0)
+    {
+        let Some(prefix_sid) =
+            stub.prefix_sids.get(&IgpAlgoType::FlexAlgo(algo))
+        else {
+            continue;
+        };
+        let Some(vertex) = spt.get(&stub.vertex.id) else {
+            continue;
+        };
+        let adv_rtr = vertex.lsa.router_id();
+
+        // Get the input label.
+        let label = match sr::prefix_sid_input_label(
+            area,
+            instance,
+            prefix_sid,
+            false,
+            lsa_entries,
+        ) {
+            Ok(Some(label)) => label,
+            Ok(None) => continue,
+            Err(error) => {
+                error.log();
+                continue;
+            }
+        };
+
+        // The first area that computes the Prefix-SID wins.
+        if lfib.contains_key(&label) {
+            continue;
+        }
+
+        // Get the output labels.
+        let mut nexthops = BTreeSet::new();
+        for nexthop in vertex.nexthops.values() {
+            let (Some(addr), Some(nbr_router_id)) =
+                (nexthop.addr, nexthop.nbr_router_id)
+            else {
+                continue;
+            };
+            let last_hop = nbr_router_id == adv_rtr;
+            match sr::prefix_sid_output_label(
+                area,
+                instance,
+                nbr_router_id,
+                prefix_sid,
+                last_hop,
+                lsa_entries,
+            ) {
+                Ok(out_label) => {
+                    let iface = &interfaces[nexthop.iface_idx];
+                    nexthops.insert(Nexthop::Address {
+                        ifindex: iface.system.ifindex.unwrap(),
+                        addr: <V::IpAddr as Into<IpAddr>>::into(addr),
+                        labels: vec![out_label],
+                        weight: None,
+                    });
+                }
+                Err(error) => error.log(),
+            }
+        }
+        if !nexthops.is_empty() {
+            lfib.insert(label, nexthops);
+        }
+    }
+}
+
+// Selects the Flexible Algorithm Definition advertised with the highest
+// priority (and then highest Router ID) among the reachable routers.
+fn fad_election<V>(
+    area: &Area<V>,
+    algo: u8,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<FlexAlgoDefTlv>
+where
+    V: Version,
+{
+    area.state
+        .spt
+        .values()
+        .filter(|vertex| vertex.lsa.is_router())
+        .filter_map(|vertex| {
+            let router_id = vertex.lsa.router_id();
+            let ri = V::area_router_information(
+                &area.state.lsdb,
+                router_id,
+                lsa_entries,
+            );
+            ri.fads
+                .into_iter()
+                .find(|fad| fad.algo == algo)
+                .map(|fad| (fad.priority, router_id, fad))
+        })
+        .max_by_key(|(priority, router_id, _)| (*priority, *router_id))
+        .map(|(_, _, fad)| fad.clone())
+}
+
+// Checks whether the given router advertises support for the Flex-Algo.
+fn router_participates<V>(
+    area: &Area<V>,
+    router_id: Ipv4Addr,
+    algo: u8,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> bool
+where
+    V: Version,
+{
+    let ri =
+        V::area_router_information(&area.state.lsdb, router_id, lsa_entries);
+    ri.sr_algo.is_some_and(|sr_algo| {
+        sr_algo.get().contains(&IgpAlgoType::FlexAlgo(algo))
+    })
+}
+
+// Returns the Flex-Algo metric of the given link, or `None` if the link is
+// excluded from the Flex-Algo topology.
+fn link_metric(
+    fad: &FlexAlgoDefTlv,
+    metric_type: FlexAlgoMetricType,
+    asla: Option<&AslaTlv>,
+    igp_cost: u16,
+) -> Option<u32> {
+    let admin_group = asla.and_then(|asla| asla.admin_group).unwrap_or(0);
+    if !fad.link_allowed(admin_group) {
+        return None;
+    }
+
+    match metric_type {
+        FlexAlgoMetricType::Igp => Some(igp_cost.into()),
+        FlexAlgoMetricType::MinUniLinkDelay => asla?.min_delay,
+        FlexAlgoMetricType::Te => asla?.te_metric,
+    }
+}
+
+// Runs the constrained SPF computation of the given Flex-Algo.
+fn spf_run<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    algo: u8,
+    fad: &FlexAlgoDefTlv,
+    metric_type: FlexAlgoMetricType,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> BTreeMap<V::VertexId, Vertex<V>>
+where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+    let mut spt = BTreeMap::new();
+
+    // Get root vertex.
+    let root_vid = V::VertexId::new_root(instance.state.router_id);
+    let Some(root_vlsa) =
+        V::vertex_lsa_find(af, root_vid, area, extended_lsa, lsa_entries)
+    else {
+        return spt;
+    };
+    let root_v = Vertex::<V>::new(root_vid, root_vlsa, 0, 0);
+
+    // Initialize candidate list. Vertex distances are tracked separately since
+    // Flex-Algo metrics don't fit in 16 bits.
+    let mut cand_list = BTreeMap::new();
+    cand_list.insert((0u32, root_v.id), root_v);
+
+    // Main SPF loop.
+    while let Some(((vertex_dist, vertex_id), vertex)) = cand_list.pop_first() {
+        spt.insert(vertex.id, vertex);
+        let vertex = spt.get(&vertex_id).unwrap();
+
+        // Iterate over all links described by the vertex's LSA.
+        for link in V::vertex_lsa_links(
+            &vertex.lsa,
+            af,
+            area,
+            extended_lsa,
+            lsa_entries,
+        ) {
+            // Check if the LSAs are mutually linked.
+            if !V::vertex_lsa_links(
+                &link.lsa,
+                af,
+                area,
+                extended_lsa,
+                lsa_entries,
+            )
+            .any(|link| link.id == vertex.id)
+            {
+                continue;
+            }
+
+            // Check if the link's vertex is already on the shortest-path tree.
+            if spt.contains_key(&link.id) {
+                continue;
+            }
+
+            // Routers that don't participate in the Flex-Algo are pruned from
+            // the topology.
+            if link.lsa.is_router()
+                && !router_participates(
+                    area,
+                    link.lsa.router_id(),
+                    algo,
+                    lsa_entries,
+                )
+            {
+                continue;
+            }
+
+            // Calculate the link's Flex-Algo metric. Links from networks to
+            // routers have a cost of zero.
+            let cost = match link.parent {
+                Some((_, router_link)) => {
+                    let asla = V::link_asla(
+                        area,
+                        vertex.lsa.router_id(),
+                        router_link,
+                        lsa_entries,
+                    )
+                    .filter(|asla| asla.sabm.contains(AslaSabm::X));
+                    match link_metric(fad, metric_type, asla, link.cost) {
+                        Some(cost) => cost,
+                        None => continue,
+                    }
+                }
+                None => 0,
+            };
+            let distance = vertex_dist.saturating_add(cost);
+
+            // Increment number of hops to the root.
+            let mut hops = vertex.hops;
+            if link.lsa.is_router() {
+                hops = hops.saturating_add(1);
+            }
+
+            // Check if this vertex is already present on the candidate list.
+            if let Some((cand_dist, _)) =
+                cand_list.keys().find(|(_, id)| *id == link.id).copied()
+            {
+                if distance > cand_dist {
+                    continue;
+                }
+                if distance < cand_dist {
+                    cand_list.remove(&(cand_dist, link.id));
+                }
+            }
+            let cand_v = cand_list
+                .entry((distance, link.id))
+                .or_insert_with(|| Vertex::new(link.id, link.lsa, 0, hops));
+
+            // Update vertex's nexthops.
+            match spf::calc_nexthops(
+                area,
+                vertex,
+                link.parent,
+                link.id,
+                &cand_v.lsa,
+                interfaces,
+                neighbors,
+                extended_lsa,
+                lsa_entries,
+            ) {
+                Ok(nexthops) => cand_v.nexthops.extend(nexthops),
+                Err(error) => error.log(),
+            }
+        }
+    }
+
+    spt
+}
@@ -221,8 +221,16 @@ where
             )
         })
     {
-        flooded_back |=
-            flood_area(area, instance, interfaces, neighbors, lsa, src);
+        for iface_idx in area.interfaces.indexes() {
+            // AS-scope LSAs aren't flooded over virtual links.
+            if interfaces[iface_idx].is_virtual_link() {
+                continue;
+            }
+
+            flooded_back |= flood_interface(
+                iface_idx, area, instance, interfaces, neighbors, lsa, src,
+            );
+        }
     }
 
     flooded_back
@@ -0,0 +1,840 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::net::Ipv4Addr;
+
+use holo_utils::ip::IpNetworkKind;
+use holo_utils::mpls::Label;
+use holo_utils::sr::IgpAlgoType;
+use ipnetwork::IpNetwork;
+
+use crate::area::Area;
+use crate::collections::Arena;
+use crate::instance::InstanceUpView;
+use crate::interface::Interface;
+use crate::lsdb::LsaEntry;
+use crate::neighbor::Neighbor;
+use crate::packet::lsa::PrefixSidVersion;
+use crate::packet::tlv::PrefixSidFlags;
+use crate::route::{Nexthop, RepairType, RouteRepair};
+use crate::spf::{SpfVersion, Vertex, VertexIdVersion, VertexLsaVersion};
+use crate::sr;
+use crate::version::Version;
+
+// Segment of a repair path.
+#[derive(Debug)]
+pub enum RepairSegment<V: Version> {
+    // Prefix-SID of the given router.
+    Node(Ipv4Addr, V::PrefixSid),
+    // Adj-SID label advertised for the adjacency with the given router.
+    Adj(Ipv4Addr, Label),
+}
+
+// Area topology used to evaluate repair paths.
+#[derive(Debug)]
+struct Topology<V: Version> {
+    // Outgoing links of each vertex, along with their costs.
+    links: BTreeMap<V::VertexId, Vec<(V::VertexId, u32)>>,
+    // Router-ID of each router vertex.
+    routers: BTreeMap<V::VertexId, Ipv4Addr>,
+}
+
+// Adjacency of the calculating router, as seen by the SPF computation.
+#[derive(Debug)]
+struct Adjacency<V: Version> {
+    // Neighbor router vertex.
+    id: V::VertexId,
+    // Vertex through which the neighbor is reached (either the root or a
+    // directly attached network).
+    via: V::VertexId,
+    // Router-ID of the neighbor.
+    router_id: Ipv4Addr,
+    // Nexthop used to reach the neighbor.
+    nexthop: Nexthop<V::IpAddr>,
+    // Cost to reach the neighbor.
+    cost: u32,
+}
+
+// Shortest-path distances, along with the parent of each vertex.
+type Distances<V> = BTreeMap<VertexId<V>, (u32, Option<VertexId<V>>)>;
+type VertexId<V> = <V as SpfVersion<V>>::VertexId;
+
+// Cache of shortest-path trees rooted at different vertices.
+struct SpfCache<'a, V: Version> {
+    topology: &'a Topology<V>,
+    trees: BTreeMap<V::VertexId, Distances<V>>,
+}
+
+// ===== impl Topology =====
+
+impl<V> Topology<V>
+where
+    V: Version,
+{
+    // Builds the topology of the area reachable from the given root vertex.
+    fn new(
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+        root: V::VertexId,
+        lsa_entries: &Arena<LsaEntry<V>>,
+    ) -> Self {
+        let af = instance.state.af;
+        let extended_lsa = instance.config.extended_lsa;
+        let mut links = BTreeMap::new();
+        let mut routers = BTreeMap::new();
+
+        let mut queue = VecDeque::from([root]);
+        while let Some(vertex_id) = queue.pop_front() {
+            if links.contains_key(&vertex_id) {
+                continue;
+            }
+            let Some(vertex_lsa) = V::vertex_lsa_find(
+                af,
+                vertex_id,
+                area,
+                extended_lsa,
+                lsa_entries,
+            ) else {
+                continue;
+            };
+            if vertex_lsa.is_router() {
+                routers.insert(vertex_id, vertex_lsa.router_id());
+            }
+
+            let mut vertex_links = vec![];
+            for link in V::vertex_lsa_links(
+                &vertex_lsa,
+                af,
+                area,
+                extended_lsa,
+                lsa_entries,
+            ) {
+                // Check if the LSAs are mutually linked.
+                if !V::vertex_lsa_links(
+                    &link.lsa,
+                    af,
+                    area,
+                    extended_lsa,
+                    lsa_entries,
+                )
+                .any(|link| link.id == vertex_id)
+                {
+                    continue;
+                }
+
+                vertex_links.push((link.id, link.cost as u32));
+                if !links.contains_key(&link.id) {
+                    queue.push_back(link.id);
+                }
+            }
+            links.insert(vertex_id, vertex_links);
+        }
+
+        Topology { links, routers }
+    }
+
+    // Returns the cost of the cheapest link between the two given vertices.
+    fn link_cost(&self, from: V::VertexId, to: V::VertexId) -> Option<u32> {
+        self.links
+            .get(&from)?
+            .iter()
+            .filter(|(id, _)| *id == to)
+            .map(|(_, cost)| *cost)
+            .min()
+    }
+}
+
+// ===== impl SpfCache =====
+
+impl<'a, V> SpfCache<'a, V>
+where
+    V: Version,
+{
+    fn new(topology: &'a Topology<V>) -> Self {
+        SpfCache {
+            topology,
+            trees: Default::default(),
+        }
+    }
+
+    // Returns the shortest-path tree rooted at the given vertex.
+    fn get(&mut self, root: V::VertexId) -> &Distances<V> {
+        let topology = self.topology;
+        self.trees
+            .entry(root)
+            .or_insert_with(|| spf_run(topology, root, None))
+    }
+
+    // Returns the shortest distance between the two given vertices.
+    fn distance(&mut self, from: V::VertexId, to: V::VertexId) -> u32 {
+        self.get(from)
+            .get(&to)
+            .map(|(distance, _)| *distance)
+            .unwrap_or(u32::MAX)
+    }
+}
+
+// ===== global functions =====
+
+// Computes LFA, Remote LFA and TI-LFA repair paths for all destinations in the
+// area's shortest-path tree.
+pub(crate) fn compute_repairs<V>(
+    area: &mut Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    // Skip the computation if fast reroute isn't enabled on any interface.
+    if !area
+        .interfaces
+        .iter(interfaces)
+        .any(|iface| iface.config.frr.lfa_enabled)
+    {
+        return;
+    }
+
+    let root = V::VertexId::new_root(instance.state.router_id);
+    let topology = Topology::new(area, instance, root, lsa_entries);
+    let adjacencies =
+        adjacencies(area, instance, root, interfaces, neighbors, lsa_entries);
+    let node_sids = match instance.config.sr_enabled {
+        true => node_sids(area, instance, lsa_entries),
+        false => Default::default(),
+    };
+    let mut cache = SpfCache::new(&topology);
+    let mut post_cache = BTreeMap::new();
+
+    // Compute a repair path for each destination.
+    let mut repairs = BTreeMap::new();
+    for vertex in area.state.spt.values().filter(|vertex| vertex.hops > 0) {
+        let repair = repair_compute(
+            area,
+            instance,
+            root,
+            vertex,
+            &topology,
+            &adjacencies,
+            &node_sids,
+            &mut cache,
+            &mut post_cache,
+            interfaces,
+            lsa_entries,
+        );
+        if let Some(repair) = repair {
+            repairs.insert(vertex.id, repair);
+        }
+    }
+
+    // Store the repair paths in the SPT and in the router routing table.
+    for (vertex_id, repair) in repairs {
+        let vertex = area.state.spt.get_mut(&vertex_id).unwrap();
+        if vertex.lsa.is_router() {
+            if let Some(route) =
+                area.state.routers.get_mut(&vertex.lsa.router_id())
+            {
+                route.repair = Some(repair.clone());
+            }
+        }
+        vertex.repair = Some(repair);
+    }
+}
+
+// ===== helper functions =====
+
+// Computes the repair path of the given destination vertex.
+fn repair_compute<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    root: V::VertexId,
+    dest: &Vertex<V>,
+    topology: &Topology<V>,
+    adjacencies: &[Adjacency<V>],
+    node_sids: &BTreeMap<V::VertexId, V::PrefixSid>,
+    cache: &mut SpfCache<'_, V>,
+    post_cache: &mut BTreeMap<V::VertexId, Distances<V>>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<RouteRepair<V::IpAddr>>
+where
+    V: Version,
+{
+    // Destinations reachable over multiple equal-cost paths are already
+    // protected.
+    if dest.nexthops.len() != 1 {
+        return None;
+    }
+    let primary_nexthop = dest.nexthops.values().next().unwrap();
+    let primary_iface = &interfaces[primary_nexthop.iface_idx];
+    if !primary_iface.config.frr.lfa_enabled {
+        return None;
+    }
+
+    // Find the primary adjacency.
+    let primary_adj = adjacencies.iter().find(|adj| {
+        adj.nexthop.iface_idx == primary_nexthop.iface_idx
+            && adj.nexthop.addr == primary_nexthop.addr
+    })?;
+
+    // Vertex at the far end of the protected link (the neighbor itself or the
+    // LAN pseudonode).
+    let far_end = match primary_adj.via == root {
+        true => primary_adj.id,
+        false => primary_adj.via,
+    };
+
+    // Try a Loop-Free Alternate first.
+    if let Some(repair) = lfa_compute(
+        root,
+        dest,
+        primary_adj,
+        far_end,
+        adjacencies,
+        cache,
+        interfaces,
+    ) {
+        return Some(repair);
+    }
+
+    // Segment Routing is required for the remaining repair types.
+    if !instance.config.sr_enabled {
+        return None;
+    }
+
+    if primary_iface.config.frr.ti_lfa_enabled {
+        if let Some(repair) = ti_lfa_compute(
+            area,
+            instance,
+            root,
+            dest,
+            primary_adj,
+            far_end,
+            topology,
+            adjacencies,
+            node_sids,
+            cache,
+            post_cache,
+            lsa_entries,
+        ) {
+            return Some(repair);
+        }
+    }
+
+    if primary_iface.config.frr.remote_lfa_enabled {
+        if let Some(repair) = remote_lfa_compute(
+            area,
+            instance,
+            root,
+            dest,
+            primary_adj,
+            far_end,
+            topology,
+            adjacencies,
+            node_sids,
+            cache,
+            interfaces,
+            lsa_entries,
+        ) {
+            return Some(repair);
+        }
+    }
+
+    None
+}
+
+// Computes a Loop-Free Alternate (RFC 5286).
+fn lfa_compute<V>(
+    root: V::VertexId,
+    dest: &Vertex<V>,
+    primary_adj: &Adjacency<V>,
+    far_end: V::VertexId,
+    adjacencies: &[Adjacency<V>],
+    cache: &mut SpfCache<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+) -> Option<RouteRepair<V::IpAddr>>
+where
+    V: Version,
+{
+    let dist_s_d = cache.distance(root, dest.id);
+    let dist_f_d = cache.distance(far_end, dest.id);
+    let dist_e_d = cache.distance(primary_adj.id, dest.id);
+
+    let mut best: Option<((bool, u32), &Adjacency<V>, u32)> = None;
+    for adj in candidate_adjacencies(primary_adj, adjacencies, interfaces) {
+        let dist_n_d = cache.distance(adj.id, dest.id);
+        let dist_n_s = cache.distance(adj.id, root);
+
+        // Inequality 1: Loop-Free Criterion.
+        if dist_n_d >= dist_n_s.saturating_add(dist_s_d) {
+            continue;
+        }
+
+        // Inequality 3: Criteria for a Node-Protecting Loop-Free Alternate.
+        let dist_n_e = cache.distance(adj.id, primary_adj.id);
+        let node_protecting = adj.id != primary_adj.id
+            && dest.id != primary_adj.id
+            && dist_n_d < dist_n_e.saturating_add(dist_e_d);
+
+        // Link protection for broadcast links requires the alternate to avoid
+        // the pseudonode.
+        let link_protecting = far_end == primary_adj.id || {
+            let dist_n_f = cache.distance(adj.id, far_end);
+            dist_n_d < dist_n_f.saturating_add(dist_f_d)
+        };
+        if !link_protecting && !node_protecting {
+            continue;
+        }
+
+        // Prefer node-protecting alternates, then the shortest ones.
+        let metric = adj.cost.saturating_add(dist_n_d);
+        let key = (!node_protecting, metric);
+        if best
+            .as_ref()
+            .is_some_and(|(best_key, _, _)| *best_key <= key)
+        {
+            continue;
+        }
+        best = Some((key, adj, dist_n_d));
+    }
+
+    best.map(
+        |((node_unprotecting, metric), adj, alt_metric)| RouteRepair {
+            repair_type: RepairType::Lfa,
+            node_protecting: !node_unprotecting,
+            nexthop: adj.nexthop,
+            label_rtr: adj.router_id,
+            labels: vec![],
+            metric,
+            alt_metric,
+        },
+    )
+}
+
+// Computes a Topology Independent Loop-Free Alternate that follows the
+// post-convergence path.
+fn ti_lfa_compute<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    root: V::VertexId,
+    dest: &Vertex<V>,
+    primary_adj: &Adjacency<V>,
+    far_end: V::VertexId,
+    topology: &Topology<V>,
+    adjacencies: &[Adjacency<V>],
+    node_sids: &BTreeMap<V::VertexId, V::PrefixSid>,
+    cache: &mut SpfCache<'_, V>,
+    post_cache: &mut BTreeMap<V::VertexId, Distances<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<RouteRepair<V::IpAddr>>
+where
+    V: Version,
+{
+    // Compute the post-convergence shortest-path tree, which excludes the
+    // protected link.
+    let post = post_cache
+        .entry(far_end)
+        .or_insert_with(|| spf_run(topology, root, Some((root, far_end))));
+    let (post_metric, _) = *post.get(&dest.id)?;
+
+    // Get the post-convergence path to the destination.
+    let mut path = vec![dest.id];
+    let mut vertex_id = dest.id;
+    while let Some((_, Some(parent))) = post.get(&vertex_id) {
+        path.push(*parent);
+        vertex_id = *parent;
+    }
+    path.reverse();
+    if path.len() < 2 {
+        return None;
+    }
+
+    // Find the adjacency used to reach the first hop of the path.
+    let adj = adjacencies.iter().find(|adj| {
+        adj.nexthop.iface_idx != primary_adj.nexthop.iface_idx
+            && ((adj.via == root && adj.id == path[1])
+                || (adj.via == path[1] && path.get(2) == Some(&adj.id)))
+    })?;
+
+    // Routers along the post-convergence path, starting from the repair
+    // nexthop.
+    let routers = path
+        .iter()
+        .skip_while(|vertex_id| **vertex_id != adj.id)
+        .filter(|vertex_id| topology.routers.contains_key(vertex_id))
+        .copied()
+        .collect::<Vec<_>>();
+
+    // Check whether a router belongs to the P-space of the calculating router
+    // with respect to the protected link.
+    let p_space = |vertex_id: V::VertexId| -> bool {
+        let Some(vertex) = area.state.spt.get(&vertex_id) else {
+            return false;
+        };
+        post.get(&vertex_id)
+            .is_some_and(|(distance, _)| *distance == vertex.distance as u32)
+            && vertex.nexthops.values().all(|nexthop| {
+                nexthop.iface_idx != primary_adj.nexthop.iface_idx
+            })
+    };
+
+    // Check whether a router belongs to the Q-space of the destination with
+    // respect to the protected link.
+    let link_cost = topology.link_cost(root, far_end)?;
+    let dist_f_d = cache.distance(far_end, dest.id);
+    let mut q_space = |vertex_id: V::VertexId| -> bool {
+        let dist_y_d = cache.distance(vertex_id, dest.id);
+        let dist_y_s = cache.distance(vertex_id, root);
+        dist_y_d < dist_y_s.saturating_add(link_cost).saturating_add(dist_f_d)
+    };
+
+    // Find the last router of the path that belongs to the P-space.
+    let p_idx = routers
+        .iter()
+        .take_while(|vertex_id| p_space(**vertex_id))
+        .count()
+        .checked_sub(1)?;
+    let p_node = routers[p_idx];
+
+    // Build the segment list.
+    let mut segments = vec![];
+    if p_idx > 0 {
+        let prefix_sid = node_sids.get(&p_node)?;
+        segments
+            .push(RepairSegment::Node(topology.routers[&p_node], *prefix_sid));
+    }
+    let label_rtr = if q_space(p_node) {
+        topology.routers[&p_node]
+    } else {
+        // Use the Adj-SID of the P node towards the next router, provided it
+        // belongs to the Q-space.
+        let q_node = *routers.get(p_idx + 1)?;
+        if !q_space(q_node) {
+            return None;
+        }
+        let p_router_id = topology.routers[&p_node];
+        let q_router_id = topology.routers[&q_node];
+        let label = V::adj_sid_find(
+            area,
+            p_router_id,
+            q_router_id,
+            instance.config.extended_lsa,
+            lsa_entries,
+        )?;
+        segments.push(RepairSegment::Adj(q_router_id, label));
+        q_router_id
+    };
+
+    // Resolve the segments to MPLS labels.
+    let labels = match sr::repair_labels(
+        area,
+        instance,
+        adj.router_id,
+        &segments,
+        lsa_entries,
+    ) {
+        Ok(labels) => labels,
+        Err(error) => {
+            error.log();
+            return None;
+        }
+    };
+
+    // The repair path is node-protecting if it doesn't traverse the primary
+    // nexthop router.
+    let node_protecting =
+        dest.id != primary_adj.id && !path.contains(&primary_adj.id);
+
+    Some(RouteRepair {
+        repair_type: RepairType::TiLfa,
+        node_protecting,
+        nexthop: adj.nexthop,
+        label_rtr,
+        labels,
+        metric: post_metric,
+        alt_metric: post_metric.saturating_sub(adj.cost),
+    })
+}
+
+// Computes a Remote Loop-Free Alternate (RFC 7490) using an SR tunnel to the
+// PQ node.
+fn remote_lfa_compute<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    root: V::VertexId,
+    dest: &Vertex<V>,
+    primary_adj: &Adjacency<V>,
+    far_end: V::VertexId,
+    topology: &Topology<V>,
+    adjacencies: &[Adjacency<V>],
+    node_sids: &BTreeMap<V::VertexId, V::PrefixSid>,
+    cache: &mut SpfCache<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<RouteRepair<V::IpAddr>>
+where
+    V: Version,
+{
+    let link_cost = topology.link_cost(root, far_end)?;
+    let dist_s_d = cache.distance(root, dest.id);
+    let dist_f_e = cache.distance(far_end, primary_adj.id);
+    let candidates =
+        candidate_adjacencies(primary_adj, adjacencies, interfaces)
+            .collect::<Vec<_>>();
+
+    let mut best: Option<(u32, &Adjacency<V>, V::VertexId)> = None;
+    for (pq_node, _) in topology
+        .routers
+        .iter()
+        .filter(|(vertex_id, _)| **vertex_id != root)
+        .filter(|(vertex_id, _)| node_sids.contains_key(vertex_id))
+    {
+        // Check whether the router belongs to the Q-space of the primary
+        // nexthop router with respect to the protected link.
+        let dist_y_e = cache.distance(*pq_node, primary_adj.id);
+        let dist_y_s = cache.distance(*pq_node, root);
+        if dist_y_e
+            >= dist_y_s.saturating_add(link_cost).saturating_add(dist_f_e)
+        {
+            continue;
+        }
+
+        // The PQ node must forward traffic to the destination without looping
+        // back through the calculating router.
+        let dist_y_d = cache.distance(*pq_node, dest.id);
+        if dist_y_d >= dist_y_s.saturating_add(dist_s_d) {
+            continue;
+        }
+
+        // Check whether the router belongs to the extended P-space of the
+        // calculating router.
+        let dist_s_y = cache.distance(root, *pq_node);
+        for adj in &candidates {
+            let dist_n_y = cache.distance(adj.id, *pq_node);
+            let dist_n_s = cache.distance(adj.id, root);
+            if dist_n_y >= dist_n_s.saturating_add(dist_s_y) {
+                continue;
+            }
+
+            let metric = adj.cost.saturating_add(dist_n_y);
+            if best
+                .as_ref()
+                .is_some_and(|(best_metric, _, _)| *best_metric <= metric)
+            {
+                continue;
+            }
+            best = Some((metric, adj, *pq_node));
+        }
+    }
+    let (metric, adj, pq_node) = best?;
+    let pq_router_id = topology.routers[&pq_node];
+
+    // Build the segment list.
+    let mut segments = vec![];
+    if pq_node != adj.id {
+        segments.push(RepairSegment::Node(pq_router_id, node_sids[&pq_node]));
+    }
+
+    // Resolve the segments to MPLS labels.
+    let labels = match sr::repair_labels(
+        area,
+        instance,
+        adj.router_id,
+        &segments,
+        lsa_entries,
+    ) {
+        Ok(labels) => labels,
+        Err(error) => {
+            error.log();
+            return None;
+        }
+    };
+
+    let metric = metric.saturating_add(cache.distance(pq_node, dest.id));
+    Some(RouteRepair {
+        repair_type: RepairType::RemoteLfa,
+        node_protecting: false,
+        nexthop: adj.nexthop,
+        label_rtr: pq_router_id,
+        labels,
+        metric,
+        alt_metric: metric.saturating_sub(adj.cost),
+    })
+}
+
+// Returns an iterator over the adjacencies that can be used as alternate
+// nexthops for the given primary adjacency.
+fn candidate_adjacencies<'a, V>(
+    primary_adj: &'a Adjacency<V>,
+    adjacencies: &'a [Adjacency<V>],
+    interfaces: &'a Arena<Interface<V>>,
+) -> impl Iterator<Item = &'a Adjacency<V>> + 'a
+where
+    V: Version,
+{
+    adjacencies
+        .iter()
+        .filter(move |adj| {
+            adj.nexthop.iface_idx != primary_adj.nexthop.iface_idx
+        })
+        .filter(|adj| {
+            interfaces[adj.nexthop.iface_idx].config.frr.lfa_candidate
+        })
+}
+
+// Returns all adjacencies of the calculating router.
+fn adjacencies<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    root: V::VertexId,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Vec<Adjacency<V>>
+where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+    let area_ifaces = area.interfaces.indexes().collect::<BTreeSet<_>>();
+    let mut adjacencies = vec![];
+
+    // Iterate over the root vertex and its directly attached networks.
+    for parent in area.state.spt.values().filter(|vertex| vertex.hops == 0) {
+        for link in V::vertex_lsa_links(
+            &parent.lsa,
+            af,
+            area,
+            extended_lsa,
+            lsa_entries,
+        )
+        .filter(|link| link.lsa.is_router() && link.id != root)
+        {
+            // Check if the LSAs are mutually linked.
+            if !V::vertex_lsa_links(
+                &link.lsa,
+                af,
+                area,
+                extended_lsa,
+                lsa_entries,
+            )
+            .any(|link| link.id == parent.id)
+            {
+                continue;
+            }
+
+            let nexthops = match V::calc_nexthops(
+                area,
+                parent,
+                link.parent,
+                link.id,
+                &link.lsa,
+                interfaces,
+                neighbors,
+                extended_lsa,
+                lsa_entries,
+            ) {
+                Ok(nexthops) => nexthops,
+                Err(error) => {
+                    error.log();
+                    continue;
+                }
+            };
+            let cost = parent.distance as u32 + link.cost as u32;
+            for nexthop in nexthops
+                .into_values()
+                .filter(|nexthop| nexthop.addr.is_some())
+                .filter(|nexthop| area_ifaces.contains(&nexthop.iface_idx))
+            {
+                adjacencies.push(Adjacency {
+                    id: link.id,
+                    via: parent.id,
+                    router_id: link.lsa.router_id(),
+                    nexthop,
+                    cost,
+                });
+            }
+        }
+    }
+
+    adjacencies
+}
+
+// Returns the node SIDs advertised in the area, indexed by router vertex.
+fn node_sids<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> BTreeMap<V::VertexId, V::PrefixSid>
+where
+    V: Version,
+{
+    let extended_lsa = instance.config.extended_lsa;
+    let mut node_sids = BTreeMap::new();
+
+    for stub in V::intra_area_networks(area, extended_lsa, lsa_entries)
+        .filter(|stub| stub.vertex.lsa.is_router())
+    {
+        // Only host prefixes can be used as node SIDs.
+        let prefix: IpNetwork = stub.prefix.into();
+        if prefix.prefix() != prefix.address_family().max_prefixlen() {
+            continue;
+        }
+
+        // Node SIDs must have global significance.
+        let Some(prefix_sid) = stub.prefix_sids.get(&IgpAlgoType::Spf) else {
+            continue;
+        };
+        if prefix_sid.flags().contains(PrefixSidFlags::V) {
+            continue;
+        }
+
+        node_sids.entry(stub.vertex.id).or_insert(*prefix_sid);
+    }
+
+    node_sids
+}
+
+// Runs a distance-only SPF computation over the provided topology, optionally
+// excluding one link.
+fn spf_run<V>(
+    topology: &Topology<V>,
+    root: V::VertexId,
+    excluded: Option<(V::VertexId, V::VertexId)>,
+) -> Distances<V>
+where
+    V: Version,
+{
+    let mut tree = BTreeMap::new();
+    let mut cand_list = BTreeSet::new();
+    cand_list.insert((0u32, root, None));
+
+    while let Some((distance, vertex_id, parent)) = cand_list.pop_first() {
+        if tree.contains_key(&vertex_id) {
+            continue;
+        }
+        tree.insert(vertex_id, (distance, parent));
+
+        for (link_id, cost) in
+            topology.links.get(&vertex_id).into_iter().flatten()
+        {
+            if excluded == Some((vertex_id, *link_id))
+                || tree.contains_key(link_id)
+            {
+                continue;
+            }
+            cand_list.insert((
+                distance.saturating_add(*cost),
+                *link_id,
+                Some(vertex_id),
+            ));
+        }
+    }
+
+    tree
+}
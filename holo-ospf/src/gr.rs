@@ -4,19 +4,29 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::time::Duration;
+use std::collections::BTreeSet;
+use std::net::Ipv4Addr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use holo_utils::ip::IpNetworkKind;
+use holo_utils::task::TimeoutTask;
+use ipnetwork::IpNetwork;
+use serde::{Deserialize, Serialize};
 
 use crate::area::Area;
-use crate::debug::{Debug, GrRejectReason};
+use crate::collections::{LsdbId, LsdbIndex};
+use crate::debug::{Debug, GrRejectReason, LsaFlushReason};
+use crate::error::Error;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::{ism, Interface};
-use crate::lsdb::LsaOriginateEvent;
+use crate::lsdb::{self, LsaEntryFlags, LsaOriginateEvent};
 use crate::neighbor::{nsm, Neighbor, NeighborGrHelper};
 use crate::northbound::notification;
-use crate::packet::lsa::{LsaHdrVersion, LsaTypeVersion};
+use crate::packet::lsa::{LsaBodyVersion, LsaHdrVersion, LsaTypeVersion};
 use crate::packet::tlv::GrReason;
-use crate::tasks;
+use crate::route::RouteNetFlags;
 use crate::version::Version;
+use crate::{southbound, spf, tasks};
 
 // OSPF Graceful Restart exit reason.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -26,6 +36,39 @@ pub enum GrExitReason {
     TopologyChanged,
 }
 
+// OSPF Graceful Restart status (restarting router).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GrRestartStatus {
+    Planned,
+    Unplanned,
+}
+
+// Graceful restart state (restarting router).
+#[derive(Debug)]
+pub struct GrRestart {
+    pub status: GrRestartStatus,
+    pub reason: GrReason,
+    pub grace_period: u32,
+    pub timeout: TimeoutTask,
+    // Routes installed before the restart, to be removed at the end of the
+    // graceful restart unless they're refreshed.
+    pub stale_routes: BTreeSet<IpNetwork>,
+}
+
+// Graceful restart information kept in non-volatile storage.
+#[derive(Debug, Deserialize, Serialize)]
+enum GrRestartNvm {
+    // The instance is running. Finding this record upon startup means the
+    // instance went through an unplanned restart.
+    Running,
+    // The instance was shut down for a planned graceful restart that must
+    // complete before the given UNIX timestamp.
+    PlannedRestart {
+        reason: GrReason,
+        grace_period_end: u64,
+    },
+}
+
 // ===== impl GrExitReason =====
 
 impl std::fmt::Display for GrExitReason {
@@ -44,6 +87,21 @@ impl std::fmt::Display for GrExitReason {
     }
 }
 
+// ===== impl GrRestartStatus =====
+
+impl std::fmt::Display for GrRestartStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GrRestartStatus::Planned => {
+                write!(f, "planned")
+            }
+            GrRestartStatus::Unplanned => {
+                write!(f, "unplanned")
+            }
+        }
+    }
+}
+
 // ===== global functions =====
 
 pub(crate) fn helper_process_grace_lsa<V>(
@@ -187,6 +245,364 @@ pub(crate) fn helper_exit<V>(
     instance.state.gr_helper_count -= 1;
 }
 
+// Prepares the instance for a planned graceful restart.
+pub(crate) fn restart_prepare<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    reason: GrReason,
+) where
+    V: Version,
+{
+    let grace_period = u32::from(instance.config.gr.restart_interval);
+    Debug::<V>::GrRestartPrepare(reason, grace_period).log();
+
+    // Originate Grace-LSAs on all active interfaces.
+    for area_idx in arenas.areas.indexes().collect::<Vec<_>>() {
+        let area = &arenas.areas[area_idx];
+        for iface_idx in area.interfaces.indexes().collect::<Vec<_>>() {
+            let area = &arenas.areas[area_idx];
+            let iface = &arenas.interfaces[iface_idx];
+            if iface.is_down() || iface.is_passive() {
+                continue;
+            }
+
+            let (options, lsa_id, lsa_body) =
+                V::lsa_grace(iface, area, grace_period, reason);
+            let lsdb_idx = LsdbIndex::Link(area_idx, iface_idx);
+            lsdb::originate_check(
+                instance, arenas, lsdb_idx, options, lsa_id, lsa_body,
+            );
+        }
+    }
+
+    // Record the planned restart and the installed routes in non-volatile
+    // storage so that the restart can be resumed once the instance starts
+    // again.
+    restart_routes_save(instance);
+    let grace_period_end = unix_time() + u64::from(grace_period);
+    nvm_update(
+        instance,
+        Some(GrRestartNvm::PlannedRestart {
+            reason,
+            grace_period_end,
+        }),
+    );
+
+    // Cancel the planned restart if the instance isn't shut down within the
+    // grace period.
+    let timeout = tasks::gr_restart_timer(instance, grace_period);
+    let status = GrRestartStatus::Planned;
+    instance.state.gr_restart_prepare = Some(GrRestart {
+        status,
+        reason,
+        grace_period,
+        timeout,
+        stale_routes: Default::default(),
+    });
+    notification::restart_status_change(instance, Some(status), None);
+}
+
+// Cancels a planned graceful restart that didn't take place within the grace
+// period.
+pub(crate) fn restart_prepare_cancel<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    if instance.state.gr_restart_prepare.take().is_none() {
+        return;
+    }
+
+    Debug::<V>::GrRestartExit(GrExitReason::TimedOut).log();
+    notification::restart_status_change(
+        instance,
+        None,
+        Some(GrExitReason::TimedOut),
+    );
+
+    // Flush the self-originated Grace-LSAs.
+    flush_grace_lsas(instance, arenas);
+
+    // Update non-volatile storage.
+    nvm_update(instance, Some(GrRestartNvm::Running));
+}
+
+// Checks whether the instance is starting up from a graceful restart.
+//
+// This should be called before any interface is started.
+pub(crate) fn restart_start<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    let gr = match nvm_get(instance) {
+        Some(GrRestartNvm::PlannedRestart {
+            reason,
+            grace_period_end,
+        }) => {
+            // Check whether the grace period has already expired.
+            let now = unix_time();
+            (grace_period_end > now).then(|| {
+                let grace_period = (grace_period_end - now) as u32;
+                (GrRestartStatus::Planned, reason, grace_period)
+            })
+        }
+        Some(GrRestartNvm::Running) if instance.config.gr.enabled => {
+            let grace_period = u32::from(instance.config.gr.restart_interval);
+            Some((GrRestartStatus::Unplanned, GrReason::Unknown, grace_period))
+        }
+        _ => None,
+    };
+
+    // Routes installed before the restart.
+    let stale_routes = nvm_routes_get(instance);
+
+    if let Some((status, reason, grace_period)) = gr {
+        Debug::<V>::GrRestartEnter(status, reason, grace_period).log();
+
+        // Start the grace period timeout.
+        let timeout = tasks::gr_restart_timer(instance, grace_period);
+        instance.state.gr_restart = Some(GrRestart {
+            status,
+            reason,
+            grace_period,
+            timeout,
+            stale_routes,
+        });
+        notification::restart_status_change(instance, Some(status), None);
+    } else {
+        // Not a graceful restart: remove the preserved forwarding state.
+        for prefix in stale_routes {
+            southbound::tx::route_stale_uninstall::<V>(
+                &instance.tx.ibus,
+                prefix,
+            );
+        }
+        nvm_routes_update(instance, None);
+    }
+
+    // Update non-volatile storage.
+    nvm_update(instance, Some(GrRestartNvm::Running));
+}
+
+// Exits from the graceful restart once all pre-restart adjacencies have been
+// reestablished.
+pub(crate) fn restart_check_exit<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    if instance.state.gr_restart.is_none() {
+        return;
+    }
+
+    if arenas
+        .areas
+        .iter()
+        .all(|area| V::gr_restart_adjs_restored(area, instance, arenas))
+    {
+        restart_exit(instance, arenas, GrExitReason::Completed);
+    }
+}
+
+pub(crate) fn restart_exit<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+    reason: GrExitReason,
+) where
+    V: Version,
+{
+    let Some(gr) = instance.state.gr_restart.take() else {
+        return;
+    };
+
+    Debug::<V>::GrRestartExit(reason).log();
+    notification::restart_status_change(instance, None, Some(reason));
+
+    // Flush the self-originated Grace-LSAs.
+    flush_grace_lsas(instance, arenas);
+
+    // Reoriginate or flush the pre-restart self-originated LSAs that were
+    // relearned from the neighbors.
+    let lsdbs = arenas
+        .areas
+        .iter()
+        .map(|area| (LsdbId::Area(area.id), &area.state.lsdb))
+        .chain(std::iter::once((LsdbId::As, &instance.state.lsdb)));
+    for (lsdb_id, lsdb) in lsdbs {
+        for (_, lse) in lsdb
+            .iter(&arenas.lsa_entries)
+            .filter(|(_, lse)| {
+                lse.flags.contains(
+                    LsaEntryFlags::SELF_ORIGINATED | LsaEntryFlags::RECEIVED,
+                )
+            })
+            .filter(|(_, lse)| lse.data.hdr.lsa_type().is_gr_topology_info())
+        {
+            instance.tx.protocol_input.lsa_orig_event(
+                LsaOriginateEvent::SelfOriginatedLsaRcvd {
+                    lsdb_id,
+                    lse_id: lse.id,
+                },
+            );
+        }
+    }
+
+    // Reoriginate all router-originated LSAs.
+    instance
+        .tx
+        .protocol_input
+        .lsa_orig_event(LsaOriginateEvent::GrRestartExit);
+
+    // Reoriginate the summary LSAs computed during the graceful restart.
+    for area in arenas.areas.iter_mut() {
+        for (prefix, (lsa_id, summary)) in area.state.net_summaries.clone() {
+            V::lsa_orig_inter_area_network(
+                area,
+                instance,
+                prefix,
+                Some(lsa_id),
+                &summary,
+            );
+        }
+        for (router_id, (lsa_id, summary)) in area.state.rtr_summaries.clone() {
+            V::lsa_orig_inter_area_router(
+                area,
+                instance,
+                router_id,
+                Some(lsa_id),
+                &summary,
+            );
+        }
+    }
+
+    // Reoriginate AS-external-LSAs and NSSA-LSAs.
+    for (prefix, (lsa_id, route)) in instance.state.external_routes.clone() {
+        V::lsa_orig_as_external(instance, prefix, Some(lsa_id), &route);
+    }
+    for area in arenas.areas.iter() {
+        for (prefix, (lsa_id, route)) in area.state.nssa_external_routes.clone()
+        {
+            V::lsa_orig_nssa_external(
+                area,
+                instance,
+                prefix,
+                Some(lsa_id),
+                &route,
+            );
+        }
+    }
+
+    // Install the routes computed during the graceful restart, replacing the
+    // preserved forwarding state.
+    for (prefix, route) in instance.state.rib.iter_mut().filter(|(_, route)| {
+        !route.flags.contains(RouteNetFlags::CONNECTED)
+            && !route.nexthops.is_empty()
+    }) {
+        let distance = route.distance(instance.config);
+        southbound::tx::route_install(
+            &instance.tx.ibus,
+            prefix,
+            route,
+            None,
+            distance,
+            instance.config.ucmp_enabled,
+            &arenas.interfaces,
+        );
+        route.flags.insert(RouteNetFlags::INSTALLED);
+    }
+
+    // Uninstall the preserved routes that weren't refreshed.
+    for prefix in gr.stale_routes.into_iter().filter(|prefix| {
+        !V::IpNetwork::get(*prefix)
+            .and_then(|prefix| instance.state.rib.get(&prefix))
+            .is_some_and(|route| route.flags.contains(RouteNetFlags::INSTALLED))
+    }) {
+        southbound::tx::route_stale_uninstall::<V>(&instance.tx.ibus, prefix);
+    }
+    restart_routes_save(instance);
+
+    // Schedule a new SPF run to flush the stale self-originated summary LSAs.
+    instance
+        .tx
+        .protocol_input
+        .spf_delay_event(spf::fsm::Event::Igp);
+}
+
+// Removes the graceful restart information from non-volatile storage.
+pub(crate) fn restart_nvm_clear<V>(instance: &InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    nvm_update(instance, None);
+    nvm_routes_update(instance, None);
+}
+
+// Records the installed routes in non-volatile storage, so that the stale ones
+// can be removed after a graceful restart.
+pub(crate) fn restart_routes_save<V>(instance: &InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    let routes = instance
+        .state
+        .rib
+        .iter()
+        .filter(|(_, route)| route.flags.contains(RouteNetFlags::INSTALLED))
+        .map(|(prefix, _)| (*prefix).into())
+        .collect();
+    nvm_routes_update(instance, Some(routes));
+}
+
+// Returns whether any neighbor in the given area is synchronizing its LSDB.
+pub(crate) fn area_adjs_syncing<V>(
+    area: &Area<V>,
+    arenas: &InstanceArenas<V>,
+) -> bool
+where
+    V: Version,
+{
+    area.interfaces
+        .iter(&arenas.interfaces)
+        .flat_map(|iface| iface.state.neighbors.iter(&arenas.neighbors))
+        .any(|nbr| {
+            nbr.state >= nsm::State::ExStart && nbr.state < nsm::State::Full
+        })
+}
+
+// Returns whether the neighbor with the given Router ID is fully adjacent in
+// any interface of the given area.
+pub(crate) fn area_nbr_full<V>(
+    area: &Area<V>,
+    arenas: &InstanceArenas<V>,
+    router_id: Ipv4Addr,
+) -> bool
+where
+    V: Version,
+{
+    area.interfaces
+        .iter(&arenas.interfaces)
+        .any(|iface| iface_nbr_full(iface, arenas, router_id))
+}
+
+// Returns whether the neighbor with the given Router ID is fully adjacent in
+// the given interface.
+pub(crate) fn iface_nbr_full<V>(
+    iface: &Interface<V>,
+    arenas: &InstanceArenas<V>,
+    router_id: Ipv4Addr,
+) -> bool
+where
+    V: Version,
+{
+    iface
+        .state
+        .neighbors
+        .get_by_router_id(&arenas.neighbors, router_id)
+        .is_some_and(|(_, nbr)| nbr.state == nsm::State::Full)
+}
+
 // ===== helper functions =====
 
 fn helper_enter<V>(
@@ -216,3 +632,124 @@ fn helper_enter<V>(
     // Increment the count of neighbors performing a graceful restart.
     instance.state.gr_helper_count += 1;
 }
+
+fn flush_grace_lsas<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    let mut idxs = vec![];
+    for area_idx in arenas.areas.indexes() {
+        let area = &arenas.areas[area_idx];
+        for iface_idx in area.interfaces.indexes() {
+            let iface = &arenas.interfaces[iface_idx];
+            let lsdb_idx = LsdbIndex::Link(area_idx, iface_idx);
+            idxs.extend(
+                iface
+                    .state
+                    .lsdb
+                    .iter(&arenas.lsa_entries)
+                    .filter(|(_, lse)| {
+                        lse.flags.contains(LsaEntryFlags::SELF_ORIGINATED)
+                    })
+                    .filter(|(_, lse)| lse.data.body.as_grace().is_some())
+                    .map(|(lse_idx, _)| (lsdb_idx, lse_idx)),
+            );
+        }
+    }
+
+    for (lsdb_idx, lse_idx) in idxs {
+        let reason = LsaFlushReason::PrematureAging;
+        lsdb::flush(instance, arenas, lsdb_idx, lse_idx, reason);
+    }
+}
+
+// Returns the current UNIX time in seconds.
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default()
+}
+
+fn nvm_key<V>(instance: &InstanceUpView<'_, V>) -> String
+where
+    V: Version,
+{
+    format!("{}-{}-gr-restart", V::PROTOCOL, instance.name)
+}
+
+// Retrieves the graceful restart information from non-volatile storage.
+fn nvm_get<V>(instance: &InstanceUpView<'_, V>) -> Option<GrRestartNvm>
+where
+    V: Version,
+{
+    let db = instance.shared.db.as_ref()?;
+    let db = db.lock().unwrap();
+    db.get::<GrRestartNvm>(&nvm_key(instance))
+}
+
+// Updates or removes the graceful restart information stored in non-volatile
+// storage.
+fn nvm_update<V>(instance: &InstanceUpView<'_, V>, info: Option<GrRestartNvm>)
+where
+    V: Version,
+{
+    if let Some(db) = &instance.shared.db {
+        let mut db = db.lock().unwrap();
+        let key = nvm_key(instance);
+        match info {
+            Some(info) => {
+                if let Err(error) = db.set(&key, &info) {
+                    Error::<V>::GrRestartNvmUpdate(error).log();
+                }
+            }
+            None => {
+                if let Err(error) = db.rem(&key) {
+                    Error::<V>::GrRestartNvmUpdate(error).log();
+                }
+            }
+        }
+    }
+}
+
+fn nvm_routes_key<V>(instance: &InstanceUpView<'_, V>) -> String
+where
+    V: Version,
+{
+    format!("{}-{}-gr-routes", V::PROTOCOL, instance.name)
+}
+
+// Retrieves the routes installed before the restart from non-volatile storage.
+fn nvm_routes_get<V>(instance: &InstanceUpView<'_, V>) -> BTreeSet<IpNetwork>
+where
+    V: Version,
+{
+    let Some(db) = &instance.shared.db else {
+        return Default::default();
+    };
+    let db = db.lock().unwrap();
+    db.get::<BTreeSet<IpNetwork>>(&nvm_routes_key(instance))
+        .unwrap_or_default()
+}
+
+// Updates or removes the installed routes stored in non-volatile storage.
+fn nvm_routes_update<V>(
+    instance: &InstanceUpView<'_, V>,
+    routes: Option<BTreeSet<IpNetwork>>,
+) where
+    V: Version,
+{
+    if let Some(db) = &instance.shared.db {
+        let mut db = db.lock().unwrap();
+        let key = nvm_routes_key(instance);
+        let result = match routes {
+            Some(routes) => db.set(&key, &routes),
+            None => db.rem(&key).map(|_| ()),
+        };
+        if let Err(error) = result {
+            Error::<V>::GrRestartNvmUpdate(error).log();
+        }
+    }
+}
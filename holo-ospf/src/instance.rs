@@ -5,7 +5,7 @@
 //
 
 use std::collections::{BTreeMap, VecDeque};
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
 use std::time::Instant;
@@ -20,6 +20,7 @@ use holo_utils::ip::AddressFamily;
 use holo_utils::protocol::Protocol;
 use holo_utils::task::TimeoutTask;
 use holo_utils::{Receiver, Sender, UnboundedReceiver, UnboundedSender};
+use ipnetwork::IpNetwork;
 use tokio::sync::mpsc;
 
 use crate::collections::{
@@ -29,22 +30,29 @@ use crate::debug::{
     Debug, InstanceInactiveReason, InterfaceInactiveReason, LsaFlushReason,
 };
 use crate::error::Error;
+use crate::flex_algo::FlexAlgoLfib;
+use crate::gr::GrRestart;
 use crate::interface::{ism, Interface};
 use crate::lsdb::{LsaEntry, LsaLogEntry, LsaOriginateEvent};
 use crate::neighbor::{nsm, Neighbor};
 use crate::northbound::configuration::InstanceCfg;
 use crate::northbound::notification;
+use crate::redistribution::{ExternalRoute, RedistributedRoute};
 use crate::route::{RouteNet, RouteNetFlags};
 use crate::spf::{SpfLogEntry, SpfTriggerLsa};
 use crate::tasks::messages::input::{
-    DbDescFreeMsg, DelayedAckMsg, GracePeriodMsg, IsmEventMsg, LsaFlushMsg,
-    LsaOrigCheckMsg, LsaOrigDelayedMsg, LsaOrigEventMsg, LsaRefreshMsg,
-    LsdbMaxAgeSweepMsg, NetRxPacketMsg, NsmEventMsg, RxmtIntervalMsg,
-    SendLsUpdateMsg, SpfDelayEventMsg,
+    DbDescFreeMsg, DelayedAckMsg, GrRestartTimeoutMsg, GracePeriodMsg,
+    IsmEventMsg, LdpSyncHolddownMsg, LsaFlushMsg, LsaOrigCheckMsg,
+    LsaOrigDelayedMsg, LsaOrigEventMsg, LsaRefreshMsg, LsdbMaxAgeSweepMsg,
+    LsdbOverflowExitMsg, NetRxPacketMsg, NsmEventMsg, RxmtIntervalMsg,
+    SendLsUpdateMsg, SpfDelayEventMsg, StubRouterStartupTimeoutMsg,
 };
 use crate::tasks::messages::{ProtocolInputMsg, ProtocolOutputMsg};
 use crate::version::Version;
-use crate::{events, lsdb, output, southbound, spf};
+use crate::{
+    events, flex_algo, gr, lsdb, output, redistribution, southbound, spf, srv6,
+    vlink,
+};
 
 pub struct Instance<V: Version> {
     // Instance name.
@@ -66,6 +74,8 @@ pub struct Instance<V: Version> {
 #[derive(Debug, Default)]
 pub struct InstanceSys {
     pub router_id: Option<Ipv4Addr>,
+    // Routes from the global RIB that are candidates for redistribution.
+    pub redistributed_routes: BTreeMap<IpNetwork, RedistributedRoute>,
 }
 
 #[derive(Debug)]
@@ -89,6 +99,16 @@ pub struct InstanceState<V: Version> {
     pub spf_schedule_time: Option<Instant>,
     // Routing table.
     pub rib: BTreeMap<V::IpNetwork, RouteNet<V>>,
+    // Redistributed and translated routes advertised in AS-external-LSAs.
+    pub external_routes: BTreeMap<V::IpNetwork, (u32, ExternalRoute)>,
+    // NSSA routes selected for translation into AS-external-LSAs.
+    pub nssa_translated_routes: BTreeMap<V::IpNetwork, ExternalRoute>,
+    // Flex-Algo Prefix-SID forwarding entries.
+    pub flex_algo_lfib: FlexAlgoLfib,
+    // Installed SRv6 End SID.
+    pub srv6_end_sid: Option<Ipv6Addr>,
+    // Next AS-external/NSSA LSA ID (OSPFv3 only).
+    pub next_type5_lsa_id: u32,
     // Statistics.
     pub orig_lsa_count: u32,
     pub rx_lsa_count: u32,
@@ -101,6 +121,18 @@ pub struct InstanceState<V: Version> {
     pub spf_log_next_id: u32,
     // Number of neighbors performing a graceful restart.
     pub gr_helper_count: usize,
+    // Planned graceful restart awaiting the instance shutdown.
+    pub gr_restart_prepare: Option<GrRestart>,
+    // Graceful restart in progress (restarting router).
+    pub gr_restart: Option<GrRestart>,
+    // LSDB overflow state (RFC 1765).
+    pub lsdb_overflow: bool,
+    pub lsdb_overflow_exit_timer: Option<TimeoutTask>,
+    pub lsdb_approaching_overflow: bool,
+    // Whether the total number of LSAs exceeds the configured maximum.
+    pub lsdb_max_lsa_exceeded: bool,
+    // Stub router on startup timer (RFC 6987).
+    pub stub_router_startup: Option<TimeoutTask>,
     // Authentication non-decreasing sequence number.
     pub auth_seqno: Arc<AtomicU64>,
 }
@@ -145,6 +177,14 @@ pub struct ProtocolInputChannelsTx<V: Version> {
     pub spf_delay_event: UnboundedSender<SpfDelayEventMsg>,
     // Grace period timeout.
     pub grace_period: Sender<GracePeriodMsg>,
+    // Graceful restart timeout (restarting router).
+    pub gr_restart_timeout: Sender<GrRestartTimeoutMsg>,
+    // LSDB overflow exit timer.
+    pub lsdb_overflow_exit: Sender<LsdbOverflowExitMsg>,
+    // Stub router on startup timer.
+    pub stub_router_startup: Sender<StubRouterStartupTimeoutMsg>,
+    // LDP-IGP synchronization holddown timer.
+    pub ldp_sync_holddown: Sender<LdpSyncHolddownMsg>,
 }
 
 #[derive(Debug)]
@@ -179,6 +219,14 @@ pub struct ProtocolInputChannelsRx<V: Version> {
     pub spf_delay_event: UnboundedReceiver<SpfDelayEventMsg>,
     // Grace period timeout.
     pub grace_period: Receiver<GracePeriodMsg>,
+    // Graceful restart timeout (restarting router).
+    pub gr_restart_timeout: Receiver<GrRestartTimeoutMsg>,
+    // LSDB overflow exit timer.
+    pub lsdb_overflow_exit: Receiver<LsdbOverflowExitMsg>,
+    // Stub router on startup timer.
+    pub stub_router_startup: Receiver<StubRouterStartupTimeoutMsg>,
+    // LDP-IGP synchronization holddown timer.
+    pub ldp_sync_holddown: Receiver<LdpSyncHolddownMsg>,
 }
 
 pub struct InstanceUpView<'a, V: Version> {
@@ -232,8 +280,17 @@ where
         // Store instance initial state.
         self.state = Some(state);
 
+        // Check whether the instance is undergoing a graceful restart.
+        let (mut instance, arenas) = self.as_up().unwrap();
+        gr::restart_start(&mut instance);
+
+        // Advertise the maximum metric while the network converges.
+        lsdb::stub_router_startup_start(&mut instance);
+
+        // Install the local SRv6 End SID.
+        srv6::end_sid_update(&mut instance);
+
         // Iterate over all configured areas.
-        let (instance, arenas) = self.as_up().unwrap();
         for area in arenas.areas.iter() {
             // Try to start interfaces.
             for iface_idx in area.interfaces.indexes() {
@@ -253,6 +310,15 @@ where
             );
         }
 
+        // Originate AS-external-LSAs and NSSA-LSAs for the redistributed
+        // routes.
+        redistribution::update_all(
+            &mut instance,
+            &mut arenas.areas,
+            &arenas.interfaces,
+            &arenas.lsa_entries,
+        );
+
         // Update boot count in non-volatile storage.
         self.boot_count_update();
     }
@@ -264,17 +330,35 @@ where
 
         Debug::<V>::InstanceStop(reason).log();
 
-        // Flush all self-originated LSAs.
+        // When preparing for a planned graceful restart, the self-originated
+        // LSAs and the forwarding state are preserved.
         let (mut instance, arenas) = self.as_up().unwrap();
-        lsdb::flush_all_self_originated(&mut instance, arenas);
+        if instance.state.gr_restart_prepare.is_none() {
+            // Flush all self-originated LSAs.
+            lsdb::flush_all_self_originated(&mut instance, arenas);
+
+            // Uninstall all routes.
+            for (dest, route) in
+                instance.state.rib.iter().filter(|(_, route)| {
+                    route.flags.contains(RouteNetFlags::INSTALLED)
+                })
+            {
+                southbound::tx::route_uninstall(&instance.tx.ibus, dest, route);
+            }
+            flex_algo::uninstall_all(&mut instance);
+            srv6::end_sid_uninstall(&mut instance);
+
+            // Clear graceful restart state from non-volatile storage.
+            gr::restart_nvm_clear(&instance);
+        }
 
-        // Uninstall all routes.
-        for (dest, route) in
-            instance.state.rib.iter().filter(|(_, route)| {
-                route.flags.contains(RouteNetFlags::INSTALLED)
-            })
-        {
-            southbound::tx::route_uninstall(&instance.tx.ibus, dest, route);
+        // Withdraw the exported BGP-LS topology.
+        if instance.config.distribute_link_state {
+            southbound::tx::link_state_delete(
+                &instance.tx.ibus,
+                V::PROTOCOL,
+                instance.name,
+            );
         }
 
         for area in arenas.areas.iter_mut() {
@@ -308,6 +392,9 @@ where
             }
         }
 
+        // Clear virtual link endpoints.
+        vlink::clear_endpoints(&arenas.areas, &mut arenas.interfaces);
+
         // Clear instance state.
         self.state = None;
     }
@@ -451,9 +538,14 @@ where
                 // Send notification.
                 if let Error::InterfaceCfgError(ifname, src, pkt_type, error) =
                     &error
+                    && let Some((_, iface)) =
+                        arenas.areas.iter().find_map(|area| {
+                            area.interfaces
+                                .get_by_name(&arenas.interfaces, ifname)
+                        })
                 {
                     notification::if_config_error(
-                        &instance, ifname, src, pkt_type, error,
+                        &instance, iface, src, pkt_type, error,
                     );
                 }
             }
@@ -480,6 +572,10 @@ where
             mpsc::channel(4);
         let (spf_delay_eventp, spf_delay_eventc) = mpsc::unbounded_channel();
         let (grace_periodp, grace_periodc) = mpsc::channel(4);
+        let (gr_restart_timeoutp, gr_restart_timeoutc) = mpsc::channel(4);
+        let (lsdb_overflow_exitp, lsdb_overflow_exitc) = mpsc::channel(4);
+        let (stub_router_startupp, stub_router_startupc) = mpsc::channel(4);
+        let (ldp_sync_holddownp, ldp_sync_holddownc) = mpsc::channel(4);
 
         let tx = ProtocolInputChannelsTx {
             ism_event: ism_eventp,
@@ -497,6 +593,10 @@ where
             lsdb_maxage_sweep_interval: lsdb_maxage_sweep_intervalp,
             spf_delay_event: spf_delay_eventp,
             grace_period: grace_periodp,
+            gr_restart_timeout: gr_restart_timeoutp,
+            lsdb_overflow_exit: lsdb_overflow_exitp,
+            stub_router_startup: stub_router_startupp,
+            ldp_sync_holddown: ldp_sync_holddownp,
         };
         let rx = ProtocolInputChannelsRx {
             ism_event: ism_eventc,
@@ -514,6 +614,10 @@ where
             lsdb_maxage_sweep_interval: lsdb_maxage_sweep_intervalc,
             spf_delay_event: spf_delay_eventc,
             grace_period: grace_periodc,
+            gr_restart_timeout: gr_restart_timeoutc,
+            lsdb_overflow_exit: lsdb_overflow_exitc,
+            stub_router_startup: stub_router_startupc,
+            ldp_sync_holddown: ldp_sync_holddownc,
         };
 
         (tx, rx)
@@ -571,6 +675,11 @@ where
             spf_trigger_lsas: Default::default(),
             spf_schedule_time: None,
             rib: Default::default(),
+            external_routes: Default::default(),
+            nssa_translated_routes: Default::default(),
+            flex_algo_lfib: Default::default(),
+            srv6_end_sid: None,
+            next_type5_lsa_id: 0,
             orig_lsa_count: 0,
             rx_lsa_count: 0,
             discontinuity_time: Utc::now(),
@@ -579,11 +688,31 @@ where
             spf_log: Default::default(),
             spf_log_next_id: 0,
             gr_helper_count: 0,
+            gr_restart_prepare: None,
+            gr_restart: None,
+            lsdb_overflow: false,
+            lsdb_overflow_exit_timer: None,
+            lsdb_approaching_overflow: false,
+            lsdb_max_lsa_exceeded: false,
+            stub_router_startup: None,
             auth_seqno: Arc::new(V::initial_auth_seqno(boot_count).into()),
         }
     }
 }
 
+// ===== impl InstanceUpView =====
+
+impl<V> InstanceUpView<'_, V>
+where
+    V: Version,
+{
+    // Returns whether the router is advertising itself as a stub router, either
+    // permanently or while starting up (RFC 6987).
+    pub(crate) fn is_stub_router(&self) -> bool {
+        self.config.stub_router || self.state.stub_router_startup.is_some()
+    }
+}
+
 // ===== impl ProtocolInputChannelsTx =====
 
 impl<V> ProtocolInputChannelsTx<V>
@@ -723,6 +852,18 @@ where
             msg = self.grace_period.recv() => {
                 msg.map(ProtocolInputMsg::GracePeriod)
             }
+            msg = self.gr_restart_timeout.recv() => {
+                msg.map(ProtocolInputMsg::GrRestartTimeout)
+            }
+            msg = self.lsdb_overflow_exit.recv() => {
+                msg.map(ProtocolInputMsg::LsdbOverflowExit)
+            }
+            msg = self.stub_router_startup.recv() => {
+                msg.map(ProtocolInputMsg::StubRouterStartupTimeout)
+            }
+            msg = self.ldp_sync_holddown.recv() => {
+                msg.map(ProtocolInputMsg::LdpSyncHolddown)
+            }
         }
     }
 }
@@ -741,6 +882,18 @@ where
         IbusMsg::BfdStateUpd { sess_key, state } => {
             events::process_bfd_state_update(instance, sess_key, state)?
         }
+        // BGP initial convergence notification.
+        IbusMsg::BgpInitialConvergence => {
+            events::process_bgp_initial_convergence(instance)?
+        }
+        // Request to dump the link-state topology.
+        IbusMsg::LinkStateDump => {
+            events::process_link_state_dump(instance)?
+        }
+        // LDP-IGP synchronization state update.
+        IbusMsg::LdpSyncUpd { ifname, state } => {
+            events::process_ldp_sync_update(instance, ifname, state)?
+        }
         // Interface update notification.
         IbusMsg::InterfaceUpd(msg) => {
             southbound::rx::process_iface_update(instance, msg);
@@ -776,6 +929,41 @@ where
         IbusMsg::RouterIdUpdate(router_id) => {
             southbound::rx::process_router_id_update(instance, router_id);
         }
+        // Route redistribute update notification.
+        IbusMsg::RouteRedistributeAdd(msg) => {
+            southbound::rx::process_route_add(instance, msg);
+        }
+        // Route redistribute delete notification.
+        IbusMsg::RouteRedistributeDel(msg) => {
+            southbound::rx::process_route_del(instance, msg);
+        }
+        // Policy match sets update notification.
+        IbusMsg::PolicyMatchSetsUpd(match_sets) => {
+            // Update the local copy of the policy match sets.
+            instance.shared.policy_match_sets = match_sets;
+
+            // Reevaluate the redistributed routes.
+            redistribution::process_policy_update(instance);
+        }
+        // Policy definition update notification.
+        IbusMsg::PolicyUpd(policy) => {
+            // Update the local copy of the policy definition.
+            instance
+                .shared
+                .policies
+                .insert(policy.name.clone(), policy.clone());
+
+            // Reevaluate the redistributed routes.
+            redistribution::process_policy_update(instance);
+        }
+        // Policy definition delete notification.
+        IbusMsg::PolicyDel(policy_name) => {
+            // Remove the local copy of the policy definition.
+            instance.shared.policies.remove(&policy_name);
+
+            // Reevaluate the redistributed routes.
+            redistribution::process_policy_update(instance);
+        }
         // SR configuration update.
         IbusMsg::SrCfgUpd(sr_config) => {
             instance.shared.sr_config = sr_config;
@@ -922,6 +1110,27 @@ where
                 msg.nbr_key,
             )?
         }
+        // Graceful restart timeout (restarting router).
+        ProtocolInputMsg::GrRestartTimeout(_msg) => {
+            events::process_gr_restart_timeout(instance, arenas)?
+        }
+        // LSDB overflow exit timeout.
+        ProtocolInputMsg::LsdbOverflowExit(_msg) => {
+            events::process_lsdb_overflow_exit(instance, arenas)?
+        }
+        // Stub router on startup timeout.
+        ProtocolInputMsg::StubRouterStartupTimeout(_msg) => {
+            events::process_stub_router_startup_timeout(instance, arenas)?
+        }
+        // LDP-IGP synchronization holddown timeout.
+        ProtocolInputMsg::LdpSyncHolddown(msg) => {
+            events::process_ldp_sync_holddown(
+                instance,
+                arenas,
+                msg.area_key,
+                msg.iface_key,
+            )?
+        }
     }
 
     Ok(())
@@ -11,6 +11,7 @@ use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
 use holo_protocol::InstanceChannelsTx;
+use holo_utils::ibus::LdpSyncState;
 use holo_utils::ip::{AddressFamily, IpAddrKind, IpNetworkKind};
 use holo_utils::keychain::{Key, Keychains};
 use holo_utils::socket::{AsyncFd, Socket};
@@ -26,18 +27,22 @@ use crate::area::Area;
 use crate::collections::{Arena, InterfaceId, Lsdb, NeighborIndex, Neighbors};
 use crate::debug::{Debug, InterfaceInactiveReason};
 use crate::error::{Error, InterfaceCfgError, IoError};
+use crate::gr::GrRestartStatus;
 use crate::instance::{Instance, InstanceUpView};
 use crate::lsdb::{LsaEntry, LsaOriginateEvent};
+use crate::multi_area::MultiArea;
 use crate::neighbor::{nsm, Neighbor, NeighborNetId};
 use crate::network::{MulticastAddr, SendDestination};
 use crate::northbound::configuration::InterfaceCfg;
 use crate::northbound::notification;
 use crate::packet::auth::AuthMethod;
 use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
+use crate::packet::tlv::{TeBandwidth, TeLinkTlv};
 use crate::packet::Packet;
-use crate::tasks;
 use crate::tasks::messages::output::NetTxPacketMsg;
 use crate::version::Version;
+use crate::vlink::{VirtualLink, VirtualLinkEndpoint};
+use crate::{multi_area, output, southbound, tasks, vlink};
 
 #[derive(Debug)]
 pub struct Interface<V: Version> {
@@ -63,6 +68,10 @@ pub struct InterfaceSys<V: Version> {
     pub unnumbered: bool,
     // OSPFv3: link-local address.
     pub linklocal_addr: Option<Ipv6Network>,
+    // Virtual link parameters (virtual interfaces only).
+    pub vlink: Option<VirtualLink<V>>,
+    // Multi-area adjacency parameters (multi-area interfaces only).
+    pub multi_area: Option<MultiArea<V>>,
 }
 
 #[derive(Debug)]
@@ -92,6 +101,9 @@ pub struct InterfaceState<V: Version> {
     pub network_lsa_self: Option<LsaKey<V::LsaType>>,
     // Authentication data.
     pub auth: Option<AuthMethod>,
+    // LDP-IGP synchronization state (`None` when disabled).
+    pub ldp_sync: Option<LdpSyncState>,
+    pub ldp_sync_holddown_expired: bool,
     // Tasks.
     pub tasks: InterfaceTasks<V>,
 }
@@ -119,6 +131,8 @@ pub struct InterfaceTasks<V: Version> {
     pub ls_update_timer: Option<TimeoutTask>,
     // Delayed Ack task.
     pub ls_delayed_ack: Option<TimeoutTask>,
+    // LDP-IGP synchronization holddown timer task.
+    pub ldp_sync_holddown: Option<TimeoutTask>,
 }
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -127,6 +141,7 @@ pub enum InterfaceType {
     NonBroadcast,
     PointToMultipoint,
     PointToPoint,
+    VirtualLink,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -253,7 +268,14 @@ where
         lsa_entries: &Arena<LsaEntry<V>>,
     ) {
         // Check next ISM event to invoke, but only if necessary.
-        let event = match V::is_ready(instance.state.af, self) {
+        let is_ready = if self.is_virtual_link() {
+            vlink::is_ready(self)
+        } else if self.is_multi_area() {
+            multi_area::is_ready(instance.state.af, self)
+        } else {
+            V::is_ready(instance.state.af, self)
+        };
+        let event = match is_ready {
             Ok(_) => {
                 let ism_state = self.state.ism_state;
                 if self.system.flags.contains(InterfaceFlags::LOOPBACK) {
@@ -288,34 +310,64 @@ where
         if !self.is_passive() {
             self.state.auth = self.auth(&instance.shared.keychains);
 
-            // Start network Tx/Rx tasks.
-            match InterfaceNet::new(
-                self,
-                area,
-                instance.state.af,
-                &instance.state.auth_seqno,
-                instance.tx,
-            ) {
-                Ok(net) => self.state.net = Some(net),
-                Err(error) => {
-                    let ifname = self.name.clone();
-                    Error::<V>::InterfaceStartError(ifname, error).log();
+            if self.is_virtual_link() {
+                // Virtual links share the network Tx/Rx tasks of their
+                // outgoing interfaces in the transit area.
+                let Some(endpoint) = self.vlink_endpoint() else {
                     return State::Down;
+                };
+
+                // Initialize source address.
+                self.state.src_addr = Some(endpoint.src_addr);
+            } else {
+                // Start network Tx/Rx tasks.
+                match InterfaceNet::new(
+                    self,
+                    area,
+                    instance.state.af,
+                    &instance.state.auth_seqno,
+                    instance.tx,
+                ) {
+                    Ok(net) => self.state.net = Some(net),
+                    Err(error) => {
+                        let ifname = self.name.clone();
+                        Error::<V>::InterfaceStartError(ifname, error).log();
+                        return State::Down;
+                    }
                 }
+
+                // Initialize source address.
+                self.state.src_addr = Some(V::src_addr(&self.system));
             }
 
-            // Initialize source address.
-            self.state.src_addr = Some(V::src_addr(&self.system));
+            // When performing an unplanned graceful restart, send a Grace-LSA
+            // before any Hello packet (RFC 3623 - Section 2.2).
+            if let Some(gr) = &instance.state.gr_restart
+                && gr.status == GrRestartStatus::Unplanned
+            {
+                output::send_grace_lsa(
+                    self,
+                    area,
+                    instance,
+                    gr.grace_period,
+                    gr.reason,
+                );
+            }
 
             // Start Hello Tx task.
             self.hello_interval_start(area, instance);
         }
 
+        // Start LDP-IGP synchronization.
+        if self.ldp_sync_enabled(instance) {
+            self.ldp_sync_start(area, instance);
+        }
+
         // Get new ISM state.
         let new_ism_state = match self.config.if_type {
-            InterfaceType::PointToPoint | InterfaceType::PointToMultipoint => {
-                State::PointToPoint
-            }
+            InterfaceType::PointToPoint
+            | InterfaceType::PointToMultipoint
+            | InterfaceType::VirtualLink => State::PointToPoint,
             InterfaceType::Broadcast | InterfaceType::NonBroadcast => {
                 if self.config.priority == 0 {
                     State::DrOther
@@ -380,7 +432,9 @@ where
             | InterfaceInactiveReason::MissingIfindex
             | InterfaceInactiveReason::MissingMtu
             | InterfaceInactiveReason::MissingIpv4Address
-            | InterfaceInactiveReason::MissingLinkLocalAddress => {
+            | InterfaceInactiveReason::MissingLinkLocalAddress
+            | InterfaceInactiveReason::VirtualLinkUnreachable
+            | InterfaceInactiveReason::MultiAreaNbrUnknown => {
                 nsm::Event::LinkDown
             }
             _ => nsm::Event::Kill,
@@ -403,6 +457,9 @@ where
         // NOTE: the interface LSDB should be preserved.
         self.state.auth = None;
         self.state.tasks = Default::default();
+
+        // Stop LDP-IGP synchronization.
+        self.ldp_sync_stop(instance);
     }
 
     // Restart the Hello Tx task.
@@ -431,6 +488,87 @@ where
         }
     }
 
+    // Starts or stops LDP-IGP synchronization following a configuration
+    // change.
+    pub(crate) fn ldp_sync_update(
+        &mut self,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+    ) {
+        if self.is_down() {
+            return;
+        }
+
+        match (
+            self.ldp_sync_enabled(instance),
+            self.state.ldp_sync.is_some(),
+        ) {
+            (true, false) => self.ldp_sync_start(area, instance),
+            (false, true) => self.ldp_sync_stop(instance),
+            _ => (),
+        }
+    }
+
+    fn ldp_sync_enabled(&self, instance: &InstanceUpView<'_, V>) -> bool {
+        instance.config.ldp_igp_sync
+            && self.config.ldp_igp_sync
+            && !self.is_virtual_link()
+            && !self.is_multi_area()
+            && !self.system.flags.contains(InterfaceFlags::LOOPBACK)
+    }
+
+    fn ldp_sync_start(
+        &mut self,
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+    ) {
+        // Register interest in the LDP synchronization state. The initial
+        // state is assumed to be "not synchronized" until LDP says otherwise.
+        southbound::tx::ldp_sync_reg(&instance.tx.ibus, &self.name);
+        self.state.ldp_sync = Some(LdpSyncState::NotSynced);
+        self.state.ldp_sync_holddown_expired = false;
+
+        // Start holddown timer.
+        if let Some(holddown) = instance.config.ldp_igp_sync_holddown {
+            let task =
+                tasks::ldp_sync_holddown_timer(self, area, instance, holddown);
+            self.state.tasks.ldp_sync_holddown = Some(task);
+        }
+    }
+
+    fn ldp_sync_stop(&mut self, instance: &InstanceUpView<'_, V>) {
+        if self.state.ldp_sync.is_none() {
+            return;
+        }
+
+        southbound::tx::ldp_sync_unreg(&instance.tx.ibus, &self.name);
+        self.state.ldp_sync = None;
+        self.state.ldp_sync_holddown_expired = false;
+        self.state.tasks.ldp_sync_holddown = None;
+    }
+
+    // Returns whether the maximum link metric should be advertised for this
+    // interface while waiting for LDP to synchronize (RFC 5443).
+    pub(crate) fn ldp_sync_max_metric(&self) -> bool {
+        self.state.ldp_sync == Some(LdpSyncState::NotSynced)
+            && !self.state.ldp_sync_holddown_expired
+    }
+
+    // Returns whether the prefixes of this interface should be hidden from the
+    // self-originated LSAs (RFC 6860). Passive and loopback interfaces are
+    // never subject to prefix suppression since they aren't transit networks.
+    pub(crate) fn prefix_suppression(
+        &self,
+        instance: &InstanceUpView<'_, V>,
+    ) -> bool {
+        !self.is_passive()
+            && self.state.ism_state != State::Loopback
+            && self
+                .config
+                .prefix_suppression
+                .unwrap_or(instance.config.prefix_suppression)
+    }
+
     pub(crate) fn is_down(&self) -> bool {
         self.state.ism_state == State::Down
     }
@@ -451,6 +589,74 @@ where
         )
     }
 
+    pub(crate) fn is_virtual_link(&self) -> bool {
+        self.config.if_type == InterfaceType::VirtualLink
+    }
+
+    pub(crate) fn is_multi_area(&self) -> bool {
+        self.system.multi_area.is_some()
+    }
+
+    // Returns a TE Link TLV containing the configured TE attributes of this
+    // interface. The link identification Sub-TLVs are left to the caller.
+    pub(crate) fn te_link_tlv(&self) -> TeLinkTlv {
+        let te = &self.config.te;
+
+        // Without RSVP-TE, the unreserved bandwidth is the same for all
+        // priority levels and equal to the maximum reservable bandwidth.
+        let max_rsv_bw = te.max_rsv_bw.map(TeBandwidth::from_bps);
+        let unrsv_bw = max_rsv_bw.map(|bw| [bw; 8]);
+
+        // Extended admin groups are encoded as a sequence of 32-bit words.
+        let ext_admin_group = te.ext_admin_groups.last().map(|max_bit| {
+            let mut words = vec![0; *max_bit as usize / 32 + 1];
+            for bit in &te.ext_admin_groups {
+                words[*bit as usize / 32] |= 1 << (bit % 32);
+            }
+            words
+        });
+
+        // Link delay.
+        let min_max_delay = match (te.min_delay, te.max_delay) {
+            (Some(min_delay), Some(max_delay)) => Some((min_delay, max_delay)),
+            (Some(min_delay), None) => Some((min_delay, min_delay)),
+            (None, Some(max_delay)) => Some((max_delay, max_delay)),
+            (None, None) => None,
+        };
+
+        TeLinkTlv {
+            te_metric: te.metric,
+            max_bw: te.max_bw.map(TeBandwidth::from_bps),
+            max_rsv_bw,
+            unrsv_bw,
+            admin_group: (te.admin_groups != 0).then_some(te.admin_groups),
+            ext_admin_group,
+            delay: te.delay,
+            min_max_delay,
+            delay_variation: te.delay_variation,
+            ..Default::default()
+        }
+    }
+
+    // Returns the address of the multi-area neighbor, if any.
+    //
+    // Packets sent over multi-area adjacencies are always unicast to the
+    // neighbor (RFC 5185).
+    pub(crate) fn multi_area_nbr_addr(&self) -> Option<V::NetIpAddr> {
+        self.system
+            .multi_area
+            .as_ref()
+            .and_then(|multi_area| multi_area.nbr_addr)
+    }
+
+    // Returns the virtual link endpoint, if any.
+    pub(crate) fn vlink_endpoint(&self) -> Option<&VirtualLinkEndpoint<V>> {
+        self.system
+            .vlink
+            .as_ref()
+            .and_then(|vlink| vlink.endpoint.as_ref())
+    }
+
     fn auth(&self, keychains: &Keychains) -> Option<AuthMethod> {
         if let (Some(key), Some(key_id), Some(algo)) = (
             &self.config.auth_key,
@@ -604,19 +810,31 @@ where
         area: &Area<V>,
         instance: &InstanceUpView<'_, V>,
     ) {
-        let ifindex = self.system.ifindex.unwrap();
-        let addrs = match self.config.if_type {
+        let dst = self.hello_dst();
+        let interval = self.config.hello_interval;
+        let task = tasks::hello_interval(self, area, instance, dst, interval);
+        self.state.tasks.hello_interval = Some(task);
+    }
+
+    // Returns the destination of Hello packets sent on this interface.
+    pub(crate) fn hello_dst(&self) -> SendDestination<V::NetIpAddr> {
+        match self.config.if_type {
             InterfaceType::PointToPoint | InterfaceType::Broadcast => {
-                smallvec![*V::multicast_addr(MulticastAddr::AllSpfRtrs)]
+                let ifindex = self.system.ifindex.unwrap();
+                let addr = self
+                    .multi_area_nbr_addr()
+                    .unwrap_or(*V::multicast_addr(MulticastAddr::AllSpfRtrs));
+                SendDestination::new(ifindex, smallvec![addr])
             }
             InterfaceType::NonBroadcast | InterfaceType::PointToMultipoint => {
-                self.config.static_nbrs.keys().copied().collect()
+                let ifindex = self.system.ifindex.unwrap();
+                let addrs = self.config.static_nbrs.keys().copied().collect();
+                SendDestination::new(ifindex, addrs)
             }
-        };
-        let dst = SendDestination::new(ifindex, addrs);
-        let interval = self.config.hello_interval;
-        let task = tasks::hello_interval(self, area, instance, dst, interval);
-        self.state.tasks.hello_interval = Some(task);
+            InterfaceType::VirtualLink => {
+                self.vlink_endpoint().unwrap().send_dest()
+            }
+        }
     }
 
     pub(crate) fn nbma_poll_interval_start(
@@ -638,6 +856,11 @@ where
     }
 
     fn update_mcast_groups(&mut self) {
+        // Multi-area interfaces don't use multicast.
+        if self.is_multi_area() {
+            return;
+        }
+
         let socket = match &self.state.net {
             Some(net) => net.socket.get_ref(),
             None => return,
@@ -838,9 +1061,9 @@ where
 
     pub(crate) fn need_adjacency(&self, nbr: &Neighbor<V>) -> bool {
         match self.config.if_type {
-            InterfaceType::PointToPoint | InterfaceType::PointToMultipoint => {
-                true
-            }
+            InterfaceType::PointToPoint
+            | InterfaceType::PointToMultipoint
+            | InterfaceType::VirtualLink => true,
             InterfaceType::Broadcast | InterfaceType::NonBroadcast => {
                 let nbr_net_id = nbr.network_id();
                 self.state.ism_state == State::Dr
@@ -883,7 +1106,18 @@ where
     }
 
     pub(crate) fn send_packet(&self, msg: NetTxPacketMsg<V>) {
-        let _ = self.state.net.as_ref().unwrap().net_tx_packetp.send(msg);
+        let _ = self.net_tx_packetp().send(msg);
+    }
+
+    // Returns the network Tx output channel used to send packets on this
+    // interface.
+    //
+    // Virtual links use the output channel of their outgoing interface.
+    pub(crate) fn net_tx_packetp(&self) -> &UnboundedSender<NetTxPacketMsg<V>> {
+        match self.vlink_endpoint() {
+            Some(endpoint) => &endpoint.net_tx_packetp,
+            None => &self.state.net.as_ref().unwrap().net_tx_packetp,
+        }
     }
 }
 
@@ -943,6 +1177,8 @@ where
             primary_addr: None,
             unnumbered: false,
             linklocal_addr: None,
+            vlink: None,
+            multi_area: None,
         }
     }
 }
@@ -969,6 +1205,8 @@ where
             lsdb: Default::default(),
             network_lsa_self: None,
             auth: None,
+            ldp_sync: None,
+            ldp_sync_holddown_expired: false,
             tasks: Default::default(),
         }
     }
@@ -1075,6 +1313,7 @@ where
             wait_timer: Default::default(),
             ls_update_timer: Default::default(),
             ls_delayed_ack: Default::default(),
+            ldp_sync_holddown: Default::default(),
         }
     }
 }
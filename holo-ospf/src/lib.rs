@@ -16,21 +16,29 @@ pub mod collections;
 pub mod debug;
 pub mod error;
 pub mod events;
+pub mod flex_algo;
 pub mod flood;
+pub mod frr;
 pub mod gr;
 pub mod instance;
 pub mod interface;
+pub mod link_state;
 pub mod lsdb;
+pub mod multi_area;
 pub mod neighbor;
 pub mod network;
 pub mod northbound;
+pub mod nssa;
 pub mod ospfv2;
 pub mod ospfv3;
 pub mod output;
 pub mod packet;
+pub mod redistribution;
 pub mod route;
 pub mod southbound;
 pub mod spf;
 pub mod sr;
+pub mod srv6;
 pub mod tasks;
 pub mod version;
+pub mod vlink;
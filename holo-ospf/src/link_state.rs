@@ -0,0 +1,258 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+use std::net::Ipv4Addr;
+
+use holo_utils::link_state::{
+    LsAdjSid, LsLink, LsLinkDescriptors, LsLinkKey, LsNode, LsNodeKey,
+    LsPrefix, LsPrefixKey, LsPrefixRouteType, LsPrefixSid, LsSidLabelRange,
+    LsTopology, LsTopologyMsg,
+};
+use holo_utils::sr::IgpAlgoType;
+
+use crate::area::Area;
+use crate::collections::{Areas, Arena};
+use crate::instance::InstanceUpView;
+use crate::lsdb::{LsaEntry, LSA_INFINITY};
+use crate::packet::lsa::{LsaRouterFlagsVersion, PrefixSidVersion};
+use crate::southbound;
+use crate::spf::VertexLsaVersion;
+use crate::version::Version;
+
+// OSPF version-specific code.
+pub trait LinkStateVersion<V: Version> {
+    // Return the link descriptors and Adj-SIDs of the provided router link.
+    fn ls_link_info(
+        area: &Area<V>,
+        adv_rtr: Ipv4Addr,
+        link: &V::LsaRouterLink,
+        lsa_entries: &Arena<LsaEntry<V>>,
+    ) -> (LsLinkDescriptors, Vec<LsAdjSid>);
+}
+
+// ===== global functions =====
+
+// Exports the topology of all areas, as computed by the last SPF run, for
+// distribution via BGP-LS.
+pub(crate) fn topology_update<V>(
+    instance: &InstanceUpView<'_, V>,
+    areas: &Areas<V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+    let mut nodes: BTreeMap<LsNodeKey, LsNode> = BTreeMap::new();
+    let mut links = BTreeMap::new();
+    let mut prefixes = BTreeMap::new();
+
+    for area in areas.iter() {
+        // Add nodes and links.
+        for vertex in area.state.spt.values() {
+            let local = node_key(area, &vertex.lsa);
+            let node = nodes.entry(local).or_default();
+            if vertex.lsa.is_router() {
+                let router_id = vertex.lsa.router_id();
+                let flags = vertex.lsa.router_flags();
+                node.abr = flags.is_abr();
+                node.asbr = flags.is_asbr();
+                node_ri_info::<V>(node, area, router_id, lsa_entries);
+            }
+
+            for link in V::vertex_lsa_links(
+                &vertex.lsa,
+                af,
+                area,
+                extended_lsa,
+                lsa_entries,
+            ) {
+                // Ignore links to unreachable vertices.
+                if !area.state.spt.contains_key(&link.id) {
+                    continue;
+                }
+
+                let remote = node_key(area, &link.lsa);
+                let (descriptors, adj_sids) = match link.parent {
+                    Some((_, rtr_link)) => V::ls_link_info(
+                        area,
+                        vertex.lsa.origin().adv_rtr,
+                        rtr_link,
+                        lsa_entries,
+                    ),
+                    None => Default::default(),
+                };
+                let key = LsLinkKey {
+                    local,
+                    remote,
+                    descriptors,
+                };
+                let link = LsLink {
+                    metric: link.cost.into(),
+                    adj_sids,
+                };
+                links.insert(key, link);
+            }
+        }
+
+        // Add intra-area prefixes.
+        for stub in V::intra_area_networks(area, extended_lsa, lsa_entries) {
+            let key = LsPrefixKey {
+                node: node_key(area, &stub.vertex.lsa),
+                prefix: stub.prefix.into(),
+                route_type: LsPrefixRouteType::IntraArea,
+            };
+            let prefix = LsPrefix {
+                metric: stub.metric.into(),
+                prefix_sids: prefix_sids::<V>(&stub.prefix_sids),
+            };
+            prefixes.insert(key, prefix);
+        }
+
+        // Add inter-area prefixes advertised by reachable ABRs.
+        for summary in V::inter_area_networks(area, extended_lsa, lsa_entries)
+            .filter(|summary| summary.metric < LSA_INFINITY)
+        {
+            let node = router_node_key(area, summary.adv_rtr);
+            if !nodes.contains_key(&node) {
+                continue;
+            }
+
+            let key = LsPrefixKey {
+                node,
+                prefix: summary.prefix.into(),
+                route_type: LsPrefixRouteType::InterArea,
+            };
+            let prefix = LsPrefix {
+                metric: summary.metric,
+                prefix_sids: prefix_sids::<V>(&summary.prefix_sids),
+            };
+            prefixes.insert(key, prefix);
+        }
+
+        // Add AS external and NSSA external prefixes advertised by reachable
+        // ASBRs.
+        let external = V::external_networks(
+            &instance.state.lsdb,
+            extended_lsa,
+            lsa_entries,
+        )
+        .map(|ext| (ext, false));
+        let nssa =
+            V::nssa_networks(&area.state.lsdb, extended_lsa, lsa_entries)
+                .map(|ext| (ext, true));
+        for (ext, nssa) in external
+            .chain(nssa)
+            .filter(|(ext, _)| ext.metric < LSA_INFINITY)
+        {
+            let node = router_node_key(area, ext.adv_rtr);
+            if !nodes.get(&node).is_some_and(|node| node.asbr) {
+                continue;
+            }
+
+            let route_type = match (nssa, ext.e_bit) {
+                (false, false) => LsPrefixRouteType::External1,
+                (false, true) => LsPrefixRouteType::External2,
+                (true, false) => LsPrefixRouteType::Nssa1,
+                (true, true) => LsPrefixRouteType::Nssa2,
+            };
+            let key = LsPrefixKey {
+                node,
+                prefix: ext.prefix.into(),
+                route_type,
+            };
+            let prefix = LsPrefix {
+                metric: ext.metric,
+                prefix_sids: vec![],
+            };
+            prefixes.insert(key, prefix);
+        }
+    }
+
+    let topology = LsTopology {
+        nodes: nodes.into_iter().collect(),
+        links: links.into_iter().collect(),
+        prefixes: prefixes.into_iter().collect(),
+    };
+    let msg = LsTopologyMsg {
+        protocol: V::PROTOCOL,
+        instance: instance.name.to_owned(),
+        topology,
+    };
+    southbound::tx::link_state_update(&instance.tx.ibus, msg);
+}
+
+// ===== helper functions =====
+
+fn router_node_key<V>(area: &Area<V>, router_id: Ipv4Addr) -> LsNodeKey
+where
+    V: Version,
+{
+    LsNodeKey {
+        area_id: area.area_id,
+        router_id,
+        pseudonode: None,
+    }
+}
+
+fn node_key<V>(area: &Area<V>, vertex_lsa: &V::VertexLsa) -> LsNodeKey
+where
+    V: Version,
+{
+    if vertex_lsa.is_router() {
+        router_node_key(area, vertex_lsa.router_id())
+    } else {
+        // The pseudonode is identified by the DR's Router ID combined with
+        // its interface address (OSPFv2) or Interface ID (OSPFv3).
+        let origin = vertex_lsa.origin();
+        LsNodeKey {
+            area_id: area.area_id,
+            router_id: origin.adv_rtr,
+            pseudonode: Some(origin.lsa_id.into()),
+        }
+    }
+}
+
+fn node_ri_info<V>(
+    node: &mut LsNode,
+    area: &Area<V>,
+    router_id: Ipv4Addr,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let ri =
+        V::area_router_information(&area.state.lsdb, router_id, lsa_entries);
+    node.hostname = ri.hostname.map(|tlv| tlv.get().to_owned());
+    if let Some(sr_algo) = ri.sr_algo {
+        node.sr_algos = sr_algo.get().clone();
+    }
+    node.srgb = ri
+        .srgb
+        .iter()
+        .map(|tlv| LsSidLabelRange {
+            first: tlv.first,
+            range: tlv.range,
+        })
+        .collect();
+}
+
+fn prefix_sids<V>(
+    prefix_sids: &BTreeMap<IgpAlgoType, V::PrefixSid>,
+) -> Vec<LsPrefixSid>
+where
+    V: Version,
+{
+    prefix_sids
+        .iter()
+        .map(|(algo, prefix_sid)| LsPrefixSid {
+            algo: *algo,
+            flags: prefix_sid.flags().bits(),
+            sid: prefix_sid.sid(),
+        })
+        .collect()
+}
@@ -8,12 +8,13 @@ use std::cmp::Ordering;
 use std::collections::{btree_map, hash_map};
 use std::net::Ipv4Addr;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use bitflags::bitflags;
 use chrono::Utc;
 use derive_new::new;
 use holo_utils::ibus::SrCfgEvent;
+use holo_utils::ip::IpNetworkKind;
 use holo_utils::task::TimeoutTask;
 use holo_utils::UnboundedSender;
 use serde::{Deserialize, Serialize};
@@ -28,9 +29,13 @@ use crate::error::Error;
 use crate::flood::flood;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::Interface;
+use crate::northbound::configuration::InstanceCfg;
+use crate::northbound::notification;
 use crate::packet::lsa::{
     Lsa, LsaBodyVersion, LsaHdrVersion, LsaKey, LsaTypeVersion,
 };
+use crate::packet::tlv::{DynamicHostnameTlv, GrReason, NodeAdminTagTlv};
+use crate::redistribution::ExternalRoute;
 use crate::route::{SummaryNet, SummaryRtr};
 use crate::spf::SpfTriggerLsa;
 use crate::tasks::messages::input::LsaFlushMsg;
@@ -45,8 +50,6 @@ pub const LSA_INFINITY: u32 = 0x00ffffff;
 pub const LSA_INIT_SEQ_NO: u32 = 0x80000001;
 pub const LSA_MAX_SEQ_NO: u32 = 0x7fffffff;
 pub const LSA_RESERVED_SEQ_NO: u32 = 0x80000000;
-pub const LSA_MIN_INTERVAL: u64 = 5;
-pub const LSA_MIN_ARRIVAL: u64 = 1;
 pub const MAX_LINK_METRIC: u16 = 0xffff;
 
 // Maximum size of the LSA log record.
@@ -115,15 +118,23 @@ pub enum LsaOriginateEvent {
         lse_id: LsaEntryId,
     },
     StubRouterChange,
+    AsbrStatusChange,
     SrEnableChange,
+    Srv6Change,
     SrCfgChange {
         change: SrCfgEvent,
     },
+    FlexAlgoChange,
+    TeChange,
+    PrefixSuppressionChange,
+    GrEnableChange,
     GrHelperChange,
     GrHelperExit {
         area_id: AreaId,
         iface_id: InterfaceId,
     },
+    GrRestartExit,
+    RouterInfoChange,
 }
 
 #[derive(Debug)]
@@ -198,6 +209,39 @@ pub trait LsdbVersion<V: Version> {
         summary: &SummaryRtr<V>,
     ) -> u32;
 
+    // Originate Type-5 AS-External-LSA (OSPFv2) or AS-External-LSA (OSPFv3).
+    fn lsa_orig_as_external(
+        instance: &mut InstanceUpView<'_, V>,
+        prefix: V::IpNetwork,
+        lsa_id: Option<u32>,
+        route: &ExternalRoute,
+    ) -> u32;
+
+    // Originate Type-7 NSSA-LSA (OSPFv2) or NSSA-LSA (OSPFv3).
+    fn lsa_orig_nssa_external(
+        area: &Area<V>,
+        instance: &mut InstanceUpView<'_, V>,
+        prefix: V::IpNetwork,
+        lsa_id: Option<u32>,
+        route: &ExternalRoute,
+    ) -> u32;
+
+    // Build the Grace-LSA advertised on the given interface.
+    fn lsa_grace(
+        iface: &Interface<V>,
+        area: &Area<V>,
+        grace_period: u32,
+        reason: GrReason,
+    ) -> (Option<V::PacketOptions>, Ipv4Addr, V::LsaBody);
+
+    // Check whether all adjacencies listed in the pre-restart Router-LSA of
+    // the given area were reestablished.
+    fn gr_restart_adjs_restored(
+        area: &Area<V>,
+        instance: &InstanceUpView<'_, V>,
+        arenas: &InstanceArenas<V>,
+    ) -> bool;
+
     // Return the LSDB index corresponding to the provided LSA type.
     fn lsdb_get_by_lsa_type(
         iface_idx: InterfaceIndex,
@@ -332,8 +376,12 @@ where
 }
 
 // Checks if the given LSA was received via flooding less than MinLSArrival
-// seconds ago.
-pub(crate) fn lsa_min_arrival_check<V>(lse: &LsaEntry<V>) -> bool
+// milliseconds ago.
+#[cfg_attr(feature = "deterministic", allow(unused_variables))]
+pub(crate) fn lsa_min_arrival_check<V>(
+    lse: &LsaEntry<V>,
+    min_arrival: u32,
+) -> bool
 where
     V: Version,
 {
@@ -349,37 +397,72 @@ where
     {
         match lse.data.base_time {
             Some(lsa_base_time) => {
-                lsa_base_time.elapsed().as_secs() < LSA_MIN_ARRIVAL
+                let min_arrival = Duration::from_millis(min_arrival.into());
+                lsa_base_time.elapsed() < min_arrival
             }
             None => false,
         }
     }
 }
 
-// Checks if the given LSA was originated less than MinLSInterval seconds ago.
-fn lsa_min_orig_interval_check<V>(lse: &LsaEntry<V>) -> bool
+// Computes for how long the origination of a new instance of the given LSA
+// needs to be postponed due to LSA generation throttling.
+#[cfg_attr(feature = "deterministic", allow(unused_variables))]
+fn lsa_orig_throttle<V>(
+    lse: &LsaEntry<V>,
+    hold_interval: &mut u32,
+    config: &InstanceCfg,
+) -> Duration
 where
     V: Version,
 {
     if lse.flags.contains(LsaEntryFlags::RECEIVED) {
-        return false;
+        return Duration::ZERO;
     }
 
     #[cfg(feature = "deterministic")]
     {
-        false
+        Duration::ZERO
     }
     #[cfg(not(feature = "deterministic"))]
     {
         match lse.data.base_time {
-            Some(lsa_base_time) => {
-                lsa_base_time.elapsed().as_secs() < LSA_MIN_INTERVAL
-            }
-            None => false,
+            Some(lsa_base_time) => lsa_orig_throttle_delay(
+                lsa_base_time.elapsed(),
+                hold_interval,
+                config,
+            ),
+            None => Duration::ZERO,
         }
     }
 }
 
+// Computes the LSA origination delay given the time elapsed since the previous
+// origination of the same LSA.
+//
+// The hold interval doubles every time the LSA changes before the current hold
+// interval expires, up to the configured maximum. It's reset to its initial
+// value once the LSA remains unchanged for the maximum interval.
+pub fn lsa_orig_throttle_delay(
+    elapsed: Duration,
+    hold_interval: &mut u32,
+    config: &InstanceCfg,
+) -> Duration {
+    let max_interval = config.lsa_throttle_max_interval;
+
+    // The LSA hasn't changed recently.
+    if elapsed >= Duration::from_millis(max_interval.into()) {
+        *hold_interval = config.lsa_throttle_hold_interval;
+        return Duration::from_millis(config.lsa_throttle_initial_delay.into());
+    }
+
+    // Back off.
+    let delay =
+        Duration::from_millis((*hold_interval).into()).saturating_sub(elapsed);
+    *hold_interval = hold_interval.saturating_mul(2).min(max_interval);
+    delay
+}
+
 // Installs the provided LSA to the specified LSDB.
 pub(crate) fn install<V>(
     instance: &mut InstanceUpView<'_, V>,
@@ -496,6 +579,9 @@ where
             .spf_delay_event(spf::fsm::Event::Igp);
     }
 
+    // Check the LSDB size limits.
+    limits_check(instance, arenas, lsdb_idx == LsdbIndex::As);
+
     lse_idx
 }
 
@@ -579,6 +665,13 @@ pub(crate) fn originate_check<V>(
 ) where
     V: Version,
 {
+    // RFC 1765 - Section 2.1:
+    // "When in OverflowState, the router does not originate non-default
+    // AS-external-LSAs".
+    if instance.state.lsdb_overflow && lsa_body.is_non_default_external() {
+        return;
+    }
+
     let (lsdb_id, lsdb) = lsdb_index_mut(
         &mut instance.state.lsdb,
         &mut arenas.areas,
@@ -610,30 +703,33 @@ pub(crate) fn originate_check<V>(
             return;
         }
 
-        // Perform the MinLSInterval check.
-        if lsdb.delayed_orig.contains_key(&lsa_key)
-            || lsa_min_orig_interval_check(old_lse)
-        {
+        // Update the LSA that will be originated, but keep the current
+        // timeout.
+        if let Some(ldo) = lsdb.delayed_orig.get_mut(&lsa_key) {
             Debug::<V>::LsaOriginateMinInterval(&lsa.hdr).log();
+            ldo.data = lsa;
+            return;
+        }
 
-            match lsdb.delayed_orig.entry(lsa_key) {
-                hash_map::Entry::Occupied(mut o) => {
-                    // Update the LSA that will be originated, but keep the
-                    // current timeout.
-                    let ldo = o.get_mut();
-                    ldo.data = lsa;
-                }
-                hash_map::Entry::Vacant(v) => {
-                    // Start timer to postpone originating the LSA.
-                    let timeout = tasks::lsa_orig_delayed_timer(
-                        lsdb_id,
-                        lsa_key,
-                        old_lse.data.base_time,
-                        &instance.tx.protocol_input.lsa_orig_delayed_timer,
-                    );
-                    v.insert(LsaDelayedOrig { data: lsa, timeout });
-                }
-            }
+        // Perform the MinLSInterval check, using exponential backoff.
+        let config = instance.config;
+        let hold_interval = lsdb
+            .orig_hold_interval
+            .entry(lsa_key)
+            .or_insert(config.lsa_throttle_hold_interval);
+        let delay = lsa_orig_throttle(old_lse, hold_interval, config);
+        if !delay.is_zero() {
+            Debug::<V>::LsaOriginateMinInterval(&lsa.hdr).log();
+
+            // Start timer to postpone originating the LSA.
+            let timeout = tasks::lsa_orig_delayed_timer(
+                lsdb_id,
+                lsa_key,
+                delay,
+                &instance.tx.protocol_input.lsa_orig_delayed_timer,
+            );
+            lsdb.delayed_orig
+                .insert(lsa_key, LsaDelayedOrig { data: lsa, timeout });
             return;
         }
     }
@@ -699,6 +795,7 @@ pub(crate) fn flush<V>(
 
     // Remove pending LSA origination, if any.
     lsdb.delayed_orig.remove(&lsa_key);
+    lsdb.orig_hold_interval.remove(&lsa_key);
 }
 
 // Flushes all self-originated LSAs from the LSDB.
@@ -761,6 +858,172 @@ pub(crate) fn flush_all_self_originated<V>(
     }
 }
 
+// Checks the number of LSAs in the LSDB against the configured limits.
+pub(crate) fn limits_check<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &InstanceArenas<V>,
+    ext_check: bool,
+) where
+    V: Version,
+{
+    // Check the total number of LSAs against the warning threshold.
+    if let Some(max_lsa) = instance.config.max_lsa {
+        let lsa_count = instance.state.lsdb.lsa_count()
+            + arenas
+                .areas
+                .iter()
+                .map(|area| area.state.lsdb.lsa_count())
+                .sum::<u32>()
+            + arenas
+                .interfaces
+                .iter()
+                .map(|(_, iface)| iface.state.lsdb.lsa_count())
+                .sum::<u32>();
+        let exceeded = lsa_count > max_lsa;
+        if exceeded && !instance.state.lsdb_max_lsa_exceeded {
+            Debug::<V>::LsdbMaxLsaExceeded(lsa_count, max_lsa).log();
+        }
+        instance.state.lsdb_max_lsa_exceeded = exceeded;
+    }
+
+    // Check the number of non-default AS-external-LSAs (RFC 1765).
+    let Some(limit) = instance.config.ext_lsdb_limit else {
+        return;
+    };
+    if !ext_check || instance.state.lsdb_overflow {
+        return;
+    }
+    let ext_lsa_count = instance.state.lsdb.ext_lsa_count();
+    if ext_lsa_count >= limit {
+        overflow_enter(instance, arenas, limit);
+    } else if ext_lsa_count as u64 * 10 >= limit as u64 * 9 {
+        if !instance.state.lsdb_approaching_overflow {
+            instance.state.lsdb_approaching_overflow = true;
+            notification::lsdb_approaching_overflow(instance, limit);
+        }
+    } else {
+        instance.state.lsdb_approaching_overflow = false;
+    }
+}
+
+// Enters the LSDB overflow state.
+fn overflow_enter<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &InstanceArenas<V>,
+    limit: u32,
+) where
+    V: Version,
+{
+    Debug::<V>::LsdbOverflowEnter(limit).log();
+
+    instance.state.lsdb_overflow = true;
+    instance.state.lsdb_approaching_overflow = false;
+    notification::lsdb_overflow(instance, limit);
+
+    // RFC 1765 - Section 2.1:
+    // "Upon entering OverflowState, the router flushes all non-default
+    // AS-external-LSAs that the router itself has originated".
+    for (_, lse) in instance
+        .state
+        .lsdb
+        .iter(&arenas.lsa_entries)
+        .filter(|(_, lse)| lse.flags.contains(LsaEntryFlags::SELF_ORIGINATED))
+        .filter(|(_, lse)| lse.data.body.is_non_default_external())
+    {
+        instance.tx.protocol_input.lsa_flush(
+            LsdbId::As,
+            lse.id,
+            LsaFlushReason::PrematureAging,
+        );
+    }
+
+    // Start the exit overflow timer, unless the router is configured to
+    // remain in the overflow state until restarted.
+    let interval = instance.config.exit_overflow_interval;
+    if interval != 0 {
+        let task = tasks::lsdb_overflow_exit_timer(instance, interval);
+        instance.state.lsdb_overflow_exit_timer = Some(task);
+    }
+}
+
+// Leaves the LSDB overflow state, reoriginating all non-default
+// AS-external-LSAs that were previously flushed.
+pub(crate) fn overflow_exit<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    Debug::<V>::LsdbOverflowExit.log();
+
+    instance.state.lsdb_overflow = false;
+    instance.state.lsdb_overflow_exit_timer = None;
+
+    let default = V::IpNetwork::default(instance.state.af);
+    for (prefix, (lsa_id, route)) in instance.state.external_routes.clone() {
+        if prefix != default {
+            V::lsa_orig_as_external(instance, prefix, Some(lsa_id), &route);
+        }
+    }
+}
+
+// Enters the stub router state on startup (RFC 6987), which lasts until the
+// configured timeout expires or, optionally, until BGP signals its initial
+// convergence.
+pub(crate) fn stub_router_startup_start<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    // The restarting router must not change its self-originated LSAs while the
+    // graceful restart is in progress.
+    if !instance.config.stub_router_startup
+        || instance.state.gr_restart.is_some()
+    {
+        return;
+    }
+
+    Debug::<V>::StubRouterStartupEnter.log();
+
+    let timeout = instance.config.stub_router_startup_timeout;
+    let task = tasks::stub_router_startup_timer(instance, timeout);
+    instance.state.stub_router_startup = Some(task);
+}
+
+// Leaves the stub router state on startup, reoriginating the Router-LSAs with
+// their normal metrics.
+pub(crate) fn stub_router_startup_exit<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    Debug::<V>::StubRouterStartupExit.log();
+
+    instance.state.stub_router_startup = None;
+    instance
+        .tx
+        .protocol_input
+        .lsa_orig_event(LsaOriginateEvent::StubRouterChange);
+}
+
+// Returns the Dynamic Hostname TLV to be advertised in the Router Information
+// LSAs. The system hostname is used unless one is explicitly configured.
+pub(crate) fn local_hostname(
+    config: &InstanceCfg,
+) -> Option<DynamicHostnameTlv> {
+    let hostname = match &config.hostname {
+        Some(hostname) => hostname.clone(),
+        None => system_hostname()?,
+    };
+    Some(DynamicHostnameTlv::new(hostname))
+}
+
+// Returns the Node Admin Tag TLVs to be advertised in the Router Information
+// LSAs.
+pub(crate) fn local_node_tags(config: &InstanceCfg) -> Vec<NodeAdminTagTlv> {
+    if config.node_tags.is_empty() {
+        return vec![];
+    }
+
+    vec![NodeAdminTagTlv::new(config.node_tags.clone())]
+}
+
 // Removes old instance of the given LSA from all neighbors' Link state
 // retransmission lists.
 fn rxmt_lists_remove<V>(
@@ -835,3 +1098,17 @@ fn log_lsa<V>(
     // Remove old entries if necessary.
     instance.state.lsa_log.truncate(LSA_LOG_MAX_SIZE);
 }
+
+#[cfg(not(feature = "testing"))]
+fn system_hostname() -> Option<String> {
+    let hostname = nix::unistd::gethostname().ok()?;
+    hostname
+        .into_string()
+        .ok()
+        .filter(|hostname| !hostname.is_empty())
+}
+
+#[cfg(feature = "testing")]
+fn system_hostname() -> Option<String> {
+    None
+}
@@ -0,0 +1,154 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::net::Ipv4Addr;
+
+use holo_utils::ip::AddressFamily;
+
+use crate::collections::{AreaIndex, InterfaceIndex};
+use crate::debug::InterfaceInactiveReason;
+use crate::instance::{InstanceArenas, InstanceUpView};
+use crate::interface::{Interface, InterfaceType};
+use crate::northbound::configuration::InterfaceCfg;
+use crate::version::Version;
+
+// Multi-area adjacency parameters (RFC 5185).
+#[derive(Debug)]
+pub struct MultiArea<V: Version> {
+    // Area ID of the primary interface.
+    pub primary_area_id: Ipv4Addr,
+    // Address of the neighbor, as learned over the primary interface.
+    pub nbr_addr: Option<V::NetIpAddr>,
+}
+
+// ===== impl MultiArea =====
+
+impl<V> MultiArea<V>
+where
+    V: Version,
+{
+    pub(crate) fn new(primary_area_id: Ipv4Addr) -> Self {
+        MultiArea {
+            primary_area_id,
+            nbr_addr: None,
+        }
+    }
+}
+
+// ===== global functions =====
+
+// Returns whether the multi-area interface is ready for OSPF operation.
+pub(crate) fn is_ready<V>(
+    af: AddressFamily,
+    iface: &Interface<V>,
+) -> Result<(), InterfaceInactiveReason>
+where
+    V: Version,
+{
+    V::is_ready(af, iface)?;
+
+    if iface
+        .system
+        .multi_area
+        .as_ref()
+        .and_then(|multi_area| multi_area.nbr_addr)
+        .is_none()
+    {
+        return Err(InterfaceInactiveReason::MultiAreaNbrUnknown);
+    }
+
+    Ok(())
+}
+
+// Copies the configuration of the primary interface to the multi-area
+// interface. Returns whether the adjacency needs to be restarted.
+pub(crate) fn inherit_config<V>(
+    config: &mut InterfaceCfg<V>,
+    primary: &InterfaceCfg<V>,
+    cost: Option<u16>,
+) -> bool
+where
+    V: Version,
+{
+    let reset = config.hello_interval != primary.hello_interval
+        || config.dead_interval != primary.dead_interval
+        || config.auth_keychain != primary.auth_keychain
+        || config.auth_keyid != primary.auth_keyid
+        || config.auth_key != primary.auth_key
+        || config.auth_algo != primary.auth_algo;
+
+    // Multi-area interfaces are always treated as point-to-point links.
+    config.if_type = InterfaceType::PointToPoint;
+    config.hello_interval = primary.hello_interval;
+    config.dead_interval = primary.dead_interval;
+    config.retransmit_interval = primary.retransmit_interval;
+    config.transmit_delay = primary.transmit_delay;
+    config.enabled = primary.enabled;
+    config.cost = cost.unwrap_or(primary.cost);
+    config.mtu_ignore = primary.mtu_ignore;
+    config.bandwidth = primary.bandwidth;
+    config.auth_keychain.clone_from(&primary.auth_keychain);
+    config.auth_keyid = primary.auth_keyid;
+    config.auth_key.clone_from(&primary.auth_key);
+    config.auth_algo = primary.auth_algo;
+
+    reset
+}
+
+// Updates the neighbor address of all multi-area interfaces associated to the
+// given primary interface.
+pub(crate) fn update_nbr_addr<V>(
+    primary_area_idx: AreaIndex,
+    primary_iface_idx: InterfaceIndex,
+    nbr_addr: V::NetIpAddr,
+    instance: &InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    let primary_area_id = arenas.areas[primary_area_idx].area_id;
+    let ifname = arenas.interfaces[primary_iface_idx].name.clone();
+
+    for area in arenas
+        .areas
+        .iter()
+        .filter(|area| area.area_id != primary_area_id)
+    {
+        let Some((iface_idx, _)) =
+            area.interfaces.get_by_name(&arenas.interfaces, &ifname)
+        else {
+            continue;
+        };
+        let iface = &mut arenas.interfaces[iface_idx];
+        let Some(multi_area) = &mut iface.system.multi_area else {
+            continue;
+        };
+        if multi_area.primary_area_id != primary_area_id
+            || multi_area.nbr_addr == Some(nbr_addr)
+        {
+            continue;
+        }
+
+        // Restart the multi-area adjacency using the new neighbor address.
+        let reset = multi_area.nbr_addr.is_some();
+        multi_area.nbr_addr = Some(nbr_addr);
+        if reset && !iface.is_down() {
+            iface.reset(
+                area,
+                instance,
+                &mut arenas.neighbors,
+                &arenas.lsa_entries,
+            );
+        } else {
+            iface.update(
+                area,
+                instance,
+                &mut arenas.neighbors,
+                &arenas.lsa_entries,
+            );
+        }
+    }
+}
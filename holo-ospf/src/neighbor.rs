@@ -5,7 +5,7 @@
 //
 
 use std::collections::{BTreeMap, HashMap};
-use std::net::Ipv4Addr;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
 
 use chrono::{DateTime, Utc};
@@ -30,7 +30,7 @@ use crate::packet::{DbDescFlags, DbDescVersion, PacketType};
 use crate::tasks::messages::input::RxmtIntervalMsg;
 use crate::tasks::messages::output::NetTxPacketMsg;
 use crate::version::Version;
-use crate::{output, sr, tasks};
+use crate::{output, sr, srv6, tasks};
 
 #[derive(Debug)]
 pub struct Neighbor<V: Version> {
@@ -54,6 +54,7 @@ pub struct Neighbor<V: Version> {
     pub discontinuity_time: DateTime<Utc>,
 
     pub adj_sids: Vec<V::AdjSid>,
+    pub srv6_endx_sid: Option<Ipv6Addr>,
     pub gr: Option<NeighborGrHelper>,
     pub lists: NeighborLsaLists<V>,
     pub tasks: NeighborTasks,
@@ -197,6 +198,7 @@ where
             event_count: 0,
             discontinuity_time: Utc::now(),
             adj_sids: Default::default(),
+            srv6_endx_sid: None,
             gr: None,
             lists: Default::default(),
             tasks: Default::default(),
@@ -261,7 +263,15 @@ where
                     .lsdb
                     .iter(lsa_entries)
                     .chain(area.state.lsdb.iter(lsa_entries))
-                    .chain(instance.state.lsdb.iter(lsa_entries))
+                    .chain(
+                        instance
+                            .state
+                            .lsdb
+                            .iter(lsa_entries)
+                            // AS-scope LSAs aren't exchanged over virtual
+                            // links.
+                            .filter(|_| !iface.is_virtual_link()),
+                    )
                     .map(|(_, lse)| lse)
                     // Filter out unneeded LSAs.
                     .filter(|lse| {
@@ -443,16 +453,23 @@ where
             );
         }
 
-        // Update Adj-SID(s) associated to this neighbor.
-        if instance.config.sr_enabled && self.gr.is_none() {
+        // Update Adj-SID(s) and SRv6 End.X SID associated to this neighbor.
+        if (instance.config.sr_enabled || instance.config.srv6_enabled)
+            && self.gr.is_none()
+            && !iface.is_virtual_link()
+        {
             let mut two_way_or_higher_change = false;
 
             if new_state >= State::TwoWay && self.state < State::TwoWay {
                 two_way_or_higher_change = true;
-                sr::adj_sid_add(self, iface, instance);
+                if instance.config.sr_enabled {
+                    sr::adj_sid_add(self, iface, instance);
+                }
+                srv6::endx_sid_add(self, iface, instance);
             } else if new_state < State::TwoWay && self.state >= State::TwoWay {
                 two_way_or_higher_change = true;
                 sr::adj_sid_del_all(self, instance);
+                srv6::endx_sid_del(self, instance);
             }
 
             if two_way_or_higher_change {
@@ -4,8 +4,8 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::{BTreeMap, HashSet};
-use std::net::Ipv4Addr;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::sync::LazyLock as Lazy;
 
 use async_trait::async_trait;
@@ -19,8 +19,11 @@ use holo_utils::bfd;
 use holo_utils::crypto::CryptoAlgo;
 use holo_utils::ibus::IbusMsg;
 use holo_utils::ip::{AddressFamily, IpAddrKind, IpNetworkKind};
+use holo_utils::protocol::Protocol;
+use holo_utils::sr::{SidLastHopBehavior, SrCfgPrefixSid};
 use holo_utils::yang::DataNodeRefExt;
 use holo_yang::{ToYang, TryFromYang};
+use ipnetwork::IpNetwork;
 use yang2::data::Data;
 
 use crate::area::{self, AreaType};
@@ -28,11 +31,16 @@ use crate::collections::{AreaIndex, InterfaceIndex};
 use crate::debug::InterfaceInactiveReason;
 use crate::instance::Instance;
 use crate::interface::{ism, InterfaceType};
-use crate::lsdb::LsaOriginateEvent;
+use crate::lsdb::{self, LsaOriginateEvent};
+use crate::multi_area::{self, MultiArea};
 use crate::neighbor::nsm;
+use crate::nssa::NssaTranslatorRole;
+use crate::packet::tlv::FlexAlgoMetricType;
+use crate::redistribution::ExternalMetricType;
 use crate::route::RouteNetFlags;
 use crate::version::{Ospfv2, Ospfv3, Version};
-use crate::{gr, southbound, spf, sr};
+use crate::vlink::VirtualLink;
+use crate::{gr, redistribution, southbound, spf, sr, srv6};
 
 #[derive(Debug, EnumAsInner)]
 pub enum ListEntry<V: Version> {
@@ -41,6 +49,10 @@ pub enum ListEntry<V: Version> {
     AreaRange(AreaIndex, V::IpNetwork),
     Interface(AreaIndex, InterfaceIndex),
     StaticNbr(InterfaceIndex, V::NetIpAddr),
+    MultiArea(InterfaceIndex, Ipv4Addr),
+    Redistribution(Protocol),
+    FlexAlgo(u8),
+    FlexAlgoPrefixSid(u8, IpNetwork),
 }
 
 #[derive(Debug)]
@@ -65,10 +77,23 @@ pub enum Event {
     InterfaceSyncHelloTx(AreaIndex, InterfaceIndex),
     InterfaceUpdateAuth(AreaIndex, InterfaceIndex),
     InterfaceBfdChange(InterfaceIndex),
+    InterfaceTeChange,
     InterfaceQuerySouthbound(String, AddressFamily),
+    MultiAreaSync,
     StubRouterChange,
+    LsdbLimitsChange,
+    GrEnableChange,
+    RouterInfoChange,
     GrHelperChange,
     SrEnableChange(bool),
+    Srv6Change,
+    FlexAlgoChange,
+    TeChange,
+    PrefixSuppressionChange,
+    LdpIgpSyncChange,
+    LinkStateChange,
+    RedistributionChange,
+    RedistributionDump,
     RerunSpf,
     UpdateSummaries,
     ReinstallRoutes,
@@ -98,10 +123,36 @@ pub struct InstanceCfg {
     pub spf_long_delay: u32,
     pub spf_hold_down: u32,
     pub spf_time_to_learn: u32,
+    pub spf_incremental: bool,
     pub stub_router: bool,
+    pub stub_router_startup: bool,
+    pub stub_router_startup_timeout: u32,
+    pub stub_router_startup_wait_bgp: bool,
+    pub lsa_throttle_initial_delay: u32,
+    pub lsa_throttle_hold_interval: u32,
+    pub lsa_throttle_max_interval: u32,
+    pub lsa_min_arrival: u32,
+    pub ucmp_enabled: bool,
+    pub prefix_suppression: bool,
+    pub max_lsa: Option<u32>,
+    pub ext_lsdb_limit: Option<u32>,
+    pub exit_overflow_interval: u32,
     pub extended_lsa: bool,
     pub sr_enabled: bool,
+    pub srv6_enabled: bool,
+    pub srv6_locator: Option<String>,
     pub instance_id: u8,
+    pub distribute_link_state: bool,
+    pub te_enabled: bool,
+    pub te_rid_ipv4: Option<Ipv4Addr>,
+    pub te_rid_ipv6: Option<Ipv6Addr>,
+    pub ldp_igp_sync: bool,
+    pub ldp_igp_sync_holddown: Option<u16>,
+    pub redistribution: BTreeMap<Protocol, RedistributionCfg>,
+    pub default_info_originate: DefaultInfoOriginateCfg,
+    pub flex_algos: BTreeMap<u8, FlexAlgoCfg>,
+    pub hostname: Option<String>,
+    pub node_tags: BTreeSet<u32>,
 }
 
 #[derive(Debug)]
@@ -113,15 +164,45 @@ pub struct Preference {
 
 #[derive(Debug)]
 pub struct InstanceGrCfg {
+    pub enabled: bool,
+    pub restart_interval: u16,
     pub helper_enabled: bool,
     pub helper_strict_lsa_checking: bool,
 }
 
+#[derive(Debug)]
+pub struct RedistributionCfg {
+    pub metric: u32,
+    pub metric_type: ExternalMetricType,
+    pub tag: Option<u32>,
+    pub policy: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct DefaultInfoOriginateCfg {
+    pub enabled: bool,
+    pub always: bool,
+    pub metric: u32,
+    pub metric_type: ExternalMetricType,
+}
+
+#[derive(Debug)]
+pub struct FlexAlgoCfg {
+    pub advertise: bool,
+    pub priority: u8,
+    pub metric_type: FlexAlgoMetricType,
+    pub exclude_any: u32,
+    pub include_any: u32,
+    pub include_all: u32,
+    pub prefix_sids: BTreeMap<IpNetwork, SrCfgPrefixSid>,
+}
+
 #[derive(Debug)]
 pub struct AreaCfg {
     pub area_type: AreaType,
     pub summary: bool,
     pub default_cost: u32,
+    pub nssa_translator_role: NssaTranslatorRole,
 }
 
 #[derive(Debug)]
@@ -144,12 +225,39 @@ pub struct InterfaceCfg<V: Version> {
     pub cost: u16,
     pub mtu_ignore: bool,
     pub static_nbrs: BTreeMap<V::NetIpAddr, StaticNbr>,
+    pub multi_areas: BTreeMap<Ipv4Addr, MultiAreaCfg>,
     pub auth_keychain: Option<String>,
     pub auth_keyid: Option<u32>,
     pub auth_key: Option<String>,
     pub auth_algo: Option<CryptoAlgo>,
     pub bfd_enabled: bool,
     pub bfd_params: bfd::ClientCfg,
+    pub frr: InterfaceFrrCfg,
+    pub te: InterfaceTeCfg,
+    pub ldp_igp_sync: bool,
+    pub bandwidth: Option<u32>,
+    pub prefix_suppression: Option<bool>,
+}
+
+#[derive(Debug)]
+pub struct InterfaceFrrCfg {
+    pub lfa_candidate: bool,
+    pub lfa_enabled: bool,
+    pub remote_lfa_enabled: bool,
+    pub ti_lfa_enabled: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct InterfaceTeCfg {
+    pub metric: Option<u32>,
+    pub max_bw: Option<u64>,
+    pub max_rsv_bw: Option<u64>,
+    pub delay: Option<u32>,
+    pub min_delay: Option<u32>,
+    pub max_delay: Option<u32>,
+    pub delay_variation: Option<u32>,
+    pub admin_groups: u32,
+    pub ext_admin_groups: BTreeSet<u8>,
 }
 
 #[derive(Debug)]
@@ -159,6 +267,11 @@ pub struct StaticNbr {
     pub priority: u8,
 }
 
+#[derive(Debug, Default)]
+pub struct MultiAreaCfg {
+    pub cost: Option<u16>,
+}
+
 // ===== callbacks =====
 
 fn load_callbacks<V>() -> Callbacks<Instance<V>>
@@ -292,6 +405,19 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::ReinstallRoutes);
         })
+        .path(ospf::graceful_restart::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.gr.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::GrEnableChange);
+        })
+        .path(ospf::graceful_restart::restart_interval::PATH)
+        .modify_apply(|instance, args| {
+            let restart_interval = args.dnode.get_u16();
+            instance.config.gr.restart_interval = restart_interval;
+        })
         .path(ospf::graceful_restart::helper_enabled::PATH)
         .modify_apply(|instance, args| {
             let enabled = args.dnode.get_bool();
@@ -338,6 +464,11 @@ where
             let time_to_learn = args.dnode.get_u32();
             instance.config.spf_time_to_learn = time_to_learn;
         })
+        .path(ospf::spf_control::incremental::PATH)
+        .modify_apply(|instance, args| {
+            let incremental = args.dnode.get_bool();
+            instance.config.spf_incremental = incremental;
+        })
         .path(ospf::stub_router::always::PATH)
         .create_apply(|instance, args| {
             instance.config.stub_router = true;
@@ -351,6 +482,59 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::StubRouterChange);
         })
+        .path(ospf::stub_router::on_startup::PATH)
+        .create_apply(|instance, _args| {
+            instance.config.stub_router_startup = true;
+        })
+        .delete_apply(|instance, args| {
+            instance.config.stub_router_startup = false;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::StubRouterChange);
+        })
+        .path(ospf::stub_router::on_startup::timeout::PATH)
+        .modify_apply(|instance, args| {
+            let timeout = args.dnode.get_u32();
+            instance.config.stub_router_startup_timeout = timeout;
+        })
+        .path(ospf::stub_router::on_startup::wait_for_bgp::PATH)
+        .modify_apply(|instance, args| {
+            let wait_for_bgp = args.dnode.get_bool();
+            instance.config.stub_router_startup_wait_bgp = wait_for_bgp;
+        })
+        .path(ospf::database_control::max_lsa::PATH)
+        .modify_apply(|instance, args| {
+            let max_lsa = args.dnode.get_u32();
+            instance.config.max_lsa = Some(max_lsa);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LsdbLimitsChange);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.max_lsa = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LsdbLimitsChange);
+        })
+        .path(ospf::database_control::ext_lsdb_limit::PATH)
+        .modify_apply(|instance, args| {
+            let ext_lsdb_limit = args.dnode.get_u32();
+            instance.config.ext_lsdb_limit = Some(ext_lsdb_limit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LsdbLimitsChange);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.ext_lsdb_limit = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LsdbLimitsChange);
+        })
+        .path(ospf::database_control::exit_overflow_interval::PATH)
+        .modify_apply(|instance, args| {
+            let exit_overflow_interval = args.dnode.get_u32();
+            instance.config.exit_overflow_interval = exit_overflow_interval;
+        })
         .path(ospf::extended_lsa_support::PATH)
         .modify_apply(|instance, args| {
             let extended_lsa = args.dnode.get_bool();
@@ -370,334 +554,1271 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::SrEnableChange(sr_enabled));
         })
-        .path(ospf::areas::area::PATH)
+        .path(ospf::node_tags::node_tag::PATH)
         .create_apply(|instance, args| {
-            let area_id = args.dnode.get_ipv4_relative("area-id").unwrap();
-            let (area_idx, _) = instance.arenas.areas.insert(area_id);
+            let tag = args.dnode.get_u32_relative("tag").unwrap();
+            instance.config.node_tags.insert(tag);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::AreaCreate(area_idx));
+            event_queue.insert(Event::RouterInfoChange);
         })
-        .delete_apply(|_instance, args| {
-            let area_idx = args.list_entry.into_area().unwrap();
+        .delete_apply(|instance, args| {
+            let tag = args.dnode.get_u32_relative("tag").unwrap();
+            instance.config.node_tags.remove(&tag);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::AreaDelete(area_idx));
-            event_queue.insert(Event::RerunSpf);
-        })
-        .lookup(|instance, _list_entry, dnode| {
-            let area_id = dnode.get_ipv4_relative("./area-id").unwrap();
-            instance
-                .arenas
-                .areas
-                .get_mut_by_area_id(area_id)
-                .map(|(area_idx, _)| ListEntry::Area(area_idx))
-                .expect("could not find OSPF area")
+            event_queue.insert(Event::RouterInfoChange);
         })
-        .path(ospf::areas::area::area_type::PATH)
+        .path(ospf::hostname::PATH)
         .modify_apply(|instance, args| {
-            let area_idx = args.list_entry.into_area().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
+            let hostname = args.dnode.get_string();
+            instance.config.hostname = Some(hostname);
 
-            let area_type = args.dnode.get_string();
-            let area_type = AreaType::try_from_yang(&area_type).unwrap();
-            area.config.area_type = area_type;
-            area.config.summary = ospf::areas::area::summary::DFLT;
-            area.config.default_cost = ospf::areas::area::default_cost::DFLT;
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RouterInfoChange);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.hostname = None;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::AreaTypeChange(area_idx));
-            event_queue.insert(Event::AreaSyncHelloTx(area_idx));
+            event_queue.insert(Event::RouterInfoChange);
         })
-        .path(ospf::areas::area::summary::PATH)
+        .path(ospf::lsa_throttle::initial_delay::PATH)
         .modify_apply(|instance, args| {
-            let area_idx = args.list_entry.into_area().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
-
-            let summary = args.dnode.get_bool();
-            area.config.summary = summary;
+            let initial_delay = args.dnode.get_u32();
+            instance.config.lsa_throttle_initial_delay = initial_delay;
+        })
+        .path(ospf::lsa_throttle::hold_interval::PATH)
+        .modify_apply(|instance, args| {
+            let hold_interval = args.dnode.get_u32();
+            instance.config.lsa_throttle_hold_interval = hold_interval;
+        })
+        .path(ospf::lsa_throttle::max_interval::PATH)
+        .modify_apply(|instance, args| {
+            let max_interval = args.dnode.get_u32();
+            instance.config.lsa_throttle_max_interval = max_interval;
+        })
+        .path(ospf::lsa_min_arrival::PATH)
+        .modify_apply(|instance, args| {
+            let min_arrival = args.dnode.get_u32();
+            instance.config.lsa_min_arrival = min_arrival;
+        })
+        .path(ospf::prefix_suppression::PATH)
+        .modify_apply(|instance, args| {
+            let prefix_suppression = args.dnode.get_bool();
+            instance.config.prefix_suppression = prefix_suppression;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::UpdateSummaries);
+            event_queue.insert(Event::PrefixSuppressionChange);
         })
-        .delete_apply(|_instance, _args| {
-            // Nothing to do.
+        .path(ospf::ucmp::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let enabled = args.dnode.get_bool();
+            instance.config.ucmp_enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::ReinstallRoutes);
         })
-        .path(ospf::areas::area::default_cost::PATH)
+        .path(ospf::distribute_link_state::PATH)
         .modify_apply(|instance, args| {
-            let area_idx = args.list_entry.into_area().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
+            let distribute_link_state = args.dnode.get_bool();
+            instance.config.distribute_link_state = distribute_link_state;
 
-            let default_cost = args.dnode.get_u32();
-            area.config.default_cost = default_cost;
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LinkStateChange);
+        })
+        .path(ospf::mpls::te_enabled::PATH)
+        .modify_apply(|instance, args| {
+            let te_enabled = args.dnode.get_bool();
+            instance.config.te_enabled = te_enabled;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::UpdateSummaries);
+            event_queue.insert(Event::TeChange);
         })
-        .delete_apply(|_instance, _args| {
-            // Nothing to do.
+        .path(ospf::mpls::te_rid::ipv4_router_id::PATH)
+        .modify_apply(|instance, args| {
+            let te_rid = args.dnode.get_ipv4();
+            instance.config.te_rid_ipv4 = Some(te_rid);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TeChange);
         })
-        .path(ospf::areas::area::ranges::range::PATH)
-        .create_apply(|instance, args| {
-            let area_idx = args.list_entry.into_area().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
+        .delete_apply(|instance, args| {
+            instance.config.te_rid_ipv4 = None;
 
-            let prefix = args.dnode.get_prefix_relative("prefix").unwrap();
-            let prefix = V::IpNetwork::get(prefix).unwrap();
-            area.ranges.insert(prefix, Default::default());
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TeChange);
+        })
+        .path(ospf::mpls::te_rid::ipv6_router_id::PATH)
+        .modify_apply(|instance, args| {
+            let te_rid = args.dnode.get_ipv6();
+            instance.config.te_rid_ipv6 = Some(te_rid);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::UpdateSummaries);
+            event_queue.insert(Event::TeChange);
         })
         .delete_apply(|instance, args| {
-            let (area_idx, prefix) = args.list_entry.into_area_range().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
+            instance.config.te_rid_ipv6 = None;
 
-            area.ranges.remove(&prefix);
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::TeChange);
+        })
+        .path(ospf::mpls::ldp::igp_sync::PATH)
+        .modify_apply(|instance, args| {
+            let igp_sync = args.dnode.get_bool();
+            instance.config.ldp_igp_sync = igp_sync;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::UpdateSummaries);
+            event_queue.insert(Event::LdpIgpSyncChange);
         })
-        .lookup(|_instance, list_entry, dnode| {
-            let area_idx = list_entry.into_area().unwrap();
+        .delete_apply(|instance, args| {
+            instance.config.ldp_igp_sync = false;
 
-            let prefix = dnode.get_prefix_relative("./prefix").unwrap();
-            let prefix = V::IpNetwork::get(prefix).unwrap();
-            ListEntry::AreaRange(area_idx, prefix)
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LdpIgpSyncChange);
         })
-        .path(ospf::areas::area::ranges::range::advertise::PATH)
+        .path(ospf::mpls::ldp::igp_sync_holddown::PATH)
         .modify_apply(|instance, args| {
-            let (area_idx, prefix) = args.list_entry.into_area_range().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
-            let range = area.ranges.get_mut(&prefix).unwrap();
+            let holddown = args.dnode.get_u16();
+            instance.config.ldp_igp_sync_holddown = Some(holddown);
+        })
+        .delete_apply(|instance, _args| {
+            instance.config.ldp_igp_sync_holddown = None;
+        })
+        .path(ospf::redistribution::redistribute::PATH)
+        .create_apply(|instance, args| {
+            let protocol = args.dnode.get_string_relative("protocol").unwrap();
+            let protocol = Protocol::try_from_yang(&protocol).unwrap();
+            instance
+                .config
+                .redistribution
+                .insert(protocol, Default::default());
 
-            let advertise = args.dnode.get_bool();
-            range.config.advertise = advertise;
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RedistributionDump);
+            event_queue.insert(Event::RedistributionChange);
+        })
+        .delete_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            instance.config.redistribution.remove(&protocol);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::UpdateSummaries);
+            event_queue.insert(Event::RedistributionChange);
         })
-        .path(ospf::areas::area::ranges::range::cost::PATH)
+        .lookup(|_instance, _list_entry, dnode| {
+            let protocol = dnode.get_string_relative("./protocol").unwrap();
+            let protocol = Protocol::try_from_yang(&protocol).unwrap();
+            ListEntry::Redistribution(protocol)
+        })
+        .path(ospf::redistribution::redistribute::metric::PATH)
         .modify_apply(|instance, args| {
-            let (area_idx, prefix) = args.list_entry.into_area_range().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
-            let range = area.ranges.get_mut(&prefix).unwrap();
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redistribution =
+                instance.config.redistribution.get_mut(&protocol).unwrap();
 
-            let cost = args.dnode.get_u32();
-            range.config.cost = Some(cost);
+            let metric = args.dnode.get_u32();
+            redistribution.metric = metric;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::UpdateSummaries);
+            event_queue.insert(Event::RedistributionChange);
         })
-        .delete_apply(|instance, args| {
-            let (area_idx, prefix) = args.list_entry.into_area_range().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
-            let range = area.ranges.get_mut(&prefix).unwrap();
+        .path(ospf::redistribution::redistribute::metric_type::PATH)
+        .modify_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redistribution =
+                instance.config.redistribution.get_mut(&protocol).unwrap();
 
-            range.config.cost = None;
+            let metric_type = args.dnode.get_string();
+            let metric_type =
+                ExternalMetricType::try_from_yang(&metric_type).unwrap();
+            redistribution.metric_type = metric_type;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::UpdateSummaries);
+            event_queue.insert(Event::RedistributionChange);
         })
-        .path(ospf::areas::area::interfaces::interface::PATH)
-        .create_apply(|instance, args| {
-            let area_idx = args.list_entry.into_area().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
+        .path(ospf::redistribution::redistribute::tag::PATH)
+        .modify_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redistribution =
+                instance.config.redistribution.get_mut(&protocol).unwrap();
 
-            let ifname = args.dnode.get_string_relative("name").unwrap();
-            let (iface_idx, _) = area
-                .interfaces
-                .insert(&mut instance.arenas.interfaces, &ifname);
+            let tag = args.dnode.get_u32();
+            redistribution.tag = Some(tag);
 
-            let af = V::address_family(instance);
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InstanceUpdate);
-            event_queue.insert(Event::InterfaceUpdate(area_idx, iface_idx));
-            event_queue.insert(Event::InterfaceQuerySouthbound(ifname, af));
+            event_queue.insert(Event::RedistributionChange);
         })
-        .delete_apply(|_instance, args| {
-            let (area_idx, iface_idx) =
-                args.list_entry.into_interface().unwrap();
+        .delete_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redistribution =
+                instance.config.redistribution.get_mut(&protocol).unwrap();
+
+            redistribution.tag = None;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InstanceUpdate);
-            event_queue.insert(Event::InterfaceDelete(area_idx, iface_idx));
+            event_queue.insert(Event::RedistributionChange);
         })
-        .lookup(|instance, list_entry, dnode| {
-            let area_idx = list_entry.into_area().unwrap();
-            let area = &mut instance.arenas.areas[area_idx];
+        .path(ospf::redistribution::redistribute::policy::PATH)
+        .modify_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redistribution =
+                instance.config.redistribution.get_mut(&protocol).unwrap();
 
-            let ifname = dnode.get_string_relative("./name").unwrap();
-            area.interfaces
-                .get_mut_by_name(&mut instance.arenas.interfaces, &ifname)
-                .map(|(iface_idx, _)| ListEntry::Interface(area_idx, iface_idx))
-                .expect("could not find OSPF interface")
+            let policy = args.dnode.get_string();
+            redistribution.policy = Some(policy);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RedistributionChange);
         })
-        .path(ospf::areas::area::interfaces::interface::interface_type::PATH)
-        .modify_apply(|instance, args| {
-            let (area_idx, iface_idx) =
-                args.list_entry.into_interface().unwrap();
-            let iface = &mut instance.arenas.interfaces[iface_idx];
+        .delete_apply(|instance, args| {
+            let protocol = args.list_entry.into_redistribution().unwrap();
+            let redistribution =
+                instance.config.redistribution.get_mut(&protocol).unwrap();
 
-            let if_type = args.dnode.get_string();
-            let if_type = InterfaceType::try_from_yang(&if_type).unwrap();
-            iface.config.if_type = if_type;
+            redistribution.policy = None;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+            event_queue.insert(Event::RedistributionChange);
         })
-        .path(ospf::areas::area::interfaces::interface::passive::PATH)
+        .path(ospf::default_information_originate::enabled::PATH)
         .modify_apply(|instance, args| {
-            let (area_idx, iface_idx) =
-                args.list_entry.into_interface().unwrap();
-            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let enabled = args.dnode.get_bool();
+            instance.config.default_info_originate.enabled = enabled;
 
-            let passive = args.dnode.get_bool();
-            iface.config.passive = passive;
+            let event_queue = args.event_queue;
+            if enabled {
+                event_queue.insert(Event::RedistributionDump);
+            }
+            event_queue.insert(Event::RedistributionChange);
+        })
+        .path(ospf::default_information_originate::always::PATH)
+        .modify_apply(|instance, args| {
+            let always = args.dnode.get_bool();
+            instance.config.default_info_originate.always = always;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+            event_queue.insert(Event::RedistributionChange);
         })
-        .path(ospf::areas::area::interfaces::interface::priority::PATH)
+        .path(ospf::default_information_originate::metric::PATH)
         .modify_apply(|instance, args| {
-            let (area_idx, iface_idx) =
-                args.list_entry.into_interface().unwrap();
-            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let metric = args.dnode.get_u32();
+            instance.config.default_info_originate.metric = metric;
 
-            let priority = args.dnode.get_u8();
-            iface.config.priority = priority;
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RedistributionChange);
+        })
+        .path(ospf::default_information_originate::metric_type::PATH)
+        .modify_apply(|instance, args| {
+            let metric_type = args.dnode.get_string();
+            let metric_type =
+                ExternalMetricType::try_from_yang(&metric_type).unwrap();
+            instance.config.default_info_originate.metric_type = metric_type;
 
             let event_queue = args.event_queue;
-            event_queue
-                .insert(Event::InterfacePriorityChange(area_idx, iface_idx));
-            event_queue
-                .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+            event_queue.insert(Event::RedistributionChange);
         })
-        .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::PATH)
+        .path(ospf::flex_algo::definition::PATH)
         .create_apply(|instance, args| {
-            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
-            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let algo = args.dnode.get_u8_relative("algorithm").unwrap();
+            instance.config.flex_algos.insert(algo, Default::default());
 
-            let identifier = args.dnode.get_ip_relative("identifier").unwrap();
-            let identifier = V::NetIpAddr::get(identifier).unwrap();
-            iface
-                .config
-                .static_nbrs
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            instance.config.flex_algos.remove(&algo);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .lookup(|_instance, _list_entry, dnode| {
+            let algo = dnode.get_u8_relative("./algorithm").unwrap();
+            ListEntry::FlexAlgo(algo)
+        })
+        .path(ospf::flex_algo::definition::advertise_definition::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let advertise = args.dnode.get_bool();
+            flex_algo.advertise = advertise;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::priority::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let priority = args.dnode.get_u8();
+            flex_algo.priority = priority;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::metric_type::PATH)
+        .modify_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let metric_type = args.dnode.get_string();
+            let metric_type =
+                FlexAlgoMetricType::try_from_yang(&metric_type).unwrap();
+            flex_algo.metric_type = metric_type;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::affinity::exclude_any::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u8();
+            flex_algo.exclude_any |= 1 << bit;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u8();
+            flex_algo.exclude_any &= !(1 << bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::affinity::include_any::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u8();
+            flex_algo.include_any |= 1 << bit;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u8();
+            flex_algo.include_any &= !(1 << bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::affinity::include_all::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u8();
+            flex_algo.include_all |= 1 << bit;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let bit = args.dnode.get_u8();
+            flex_algo.include_all &= !(1 << bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::prefix_sid::PATH)
+        .create_apply(|instance, args| {
+            let algo = args.list_entry.into_flex_algo().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+
+            let prefix = args.dnode.get_prefix_relative("prefix").unwrap();
+            let index = args.dnode.get_u32_relative("index").unwrap();
+            let last_hop =
+                args.dnode.get_string_relative("last-hop-behavior").unwrap();
+            let last_hop =
+                SidLastHopBehavior::try_from_yang(&last_hop).unwrap();
+            flex_algo
+                .prefix_sids
+                .insert(prefix, SrCfgPrefixSid::new(index, last_hop));
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .delete_apply(|instance, args| {
+            let (algo, prefix) =
+                args.list_entry.into_flex_algo_prefix_sid().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+            flex_algo.prefix_sids.remove(&prefix);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .lookup(|_instance, list_entry, dnode| {
+            let algo = list_entry.into_flex_algo().unwrap();
+            let prefix = dnode.get_prefix_relative("./prefix").unwrap();
+            ListEntry::FlexAlgoPrefixSid(algo, prefix)
+        })
+        .path(ospf::flex_algo::definition::prefix_sid::index::PATH)
+        .modify_apply(|instance, args| {
+            let (algo, prefix) =
+                args.list_entry.into_flex_algo_prefix_sid().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+            let prefix_sid = flex_algo.prefix_sids.get_mut(&prefix).unwrap();
+
+            let index = args.dnode.get_u32();
+            prefix_sid.index = index;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::flex_algo::definition::prefix_sid::last_hop_behavior::PATH)
+        .modify_apply(|instance, args| {
+            let (algo, prefix) =
+                args.list_entry.into_flex_algo_prefix_sid().unwrap();
+            let flex_algo = instance.config.flex_algos.get_mut(&algo).unwrap();
+            let prefix_sid = flex_algo.prefix_sids.get_mut(&prefix).unwrap();
+
+            let last_hop = args.dnode.get_string();
+            let last_hop =
+                SidLastHopBehavior::try_from_yang(&last_hop).unwrap();
+            prefix_sid.last_hop = last_hop;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::FlexAlgoChange);
+        })
+        .path(ospf::areas::area::PATH)
+        .create_apply(|instance, args| {
+            let area_id = args.dnode.get_ipv4_relative("area-id").unwrap();
+            let (area_idx, _) = instance.arenas.areas.insert(area_id);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::AreaCreate(area_idx));
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .delete_apply(|_instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::AreaDelete(area_idx));
+            event_queue.insert(Event::MultiAreaSync);
+            event_queue.insert(Event::RerunSpf);
+        })
+        .lookup(|instance, _list_entry, dnode| {
+            let area_id = dnode.get_ipv4_relative("./area-id").unwrap();
+            instance
+                .arenas
+                .areas
+                .get_mut_by_area_id(area_id)
+                .map(|(area_idx, _)| ListEntry::Area(area_idx))
+                .expect("could not find OSPF area")
+        })
+        .path(ospf::areas::area::area_type::PATH)
+        .modify_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let area_type = args.dnode.get_string();
+            let area_type = AreaType::try_from_yang(&area_type).unwrap();
+            area.config.area_type = area_type;
+            area.config.summary = ospf::areas::area::summary::DFLT;
+            area.config.default_cost = ospf::areas::area::default_cost::DFLT;
+            area.config.nssa_translator_role = NssaTranslatorRole::try_from_yang(
+                ospf::areas::area::nssa_translator_role::DFLT,
+            )
+            .unwrap();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::AreaTypeChange(area_idx));
+            event_queue.insert(Event::AreaSyncHelloTx(area_idx));
+        })
+        .path(ospf::areas::area::summary::PATH)
+        .modify_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let summary = args.dnode.get_bool();
+            area.config.summary = summary;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(ospf::areas::area::default_cost::PATH)
+        .modify_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let default_cost = args.dnode.get_u32();
+            area.config.default_cost = default_cost;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(ospf::areas::area::nssa_translator_role::PATH)
+        .modify_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let role = args.dnode.get_string();
+            let role = NssaTranslatorRole::try_from_yang(&role).unwrap();
+            area.config.nssa_translator_role = role;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .delete_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let role = ospf::areas::area::nssa_translator_role::DFLT;
+            let role = NssaTranslatorRole::try_from_yang(role).unwrap();
+            area.config.nssa_translator_role = role;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::areas::area::ranges::range::PATH)
+        .create_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let prefix = args.dnode.get_prefix_relative("prefix").unwrap();
+            let prefix = V::IpNetwork::get(prefix).unwrap();
+            area.ranges.insert(prefix, Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .delete_apply(|instance, args| {
+            let (area_idx, prefix) = args.list_entry.into_area_range().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            area.ranges.remove(&prefix);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .lookup(|_instance, list_entry, dnode| {
+            let area_idx = list_entry.into_area().unwrap();
+
+            let prefix = dnode.get_prefix_relative("./prefix").unwrap();
+            let prefix = V::IpNetwork::get(prefix).unwrap();
+            ListEntry::AreaRange(area_idx, prefix)
+        })
+        .path(ospf::areas::area::ranges::range::advertise::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, prefix) = args.list_entry.into_area_range().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+            let range = area.ranges.get_mut(&prefix).unwrap();
+
+            let advertise = args.dnode.get_bool();
+            range.config.advertise = advertise;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .path(ospf::areas::area::ranges::range::cost::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, prefix) = args.list_entry.into_area_range().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+            let range = area.ranges.get_mut(&prefix).unwrap();
+
+            let cost = args.dnode.get_u32();
+            range.config.cost = Some(cost);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .delete_apply(|instance, args| {
+            let (area_idx, prefix) = args.list_entry.into_area_range().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+            let range = area.ranges.get_mut(&prefix).unwrap();
+
+            range.config.cost = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::UpdateSummaries);
+        })
+        .path(ospf::areas::area::interfaces::interface::PATH)
+        .create_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let ifname = args.dnode.get_string_relative("name").unwrap();
+            let (iface_idx, _) = area
+                .interfaces
+                .insert(&mut instance.arenas.interfaces, &ifname);
+
+            let af = V::address_family(instance);
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::InterfaceUpdate(area_idx, iface_idx));
+            event_queue.insert(Event::InterfaceQuerySouthbound(ifname, af));
+        })
+        .delete_apply(|_instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::InterfaceDelete(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .lookup(|instance, list_entry, dnode| {
+            let area_idx = list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let ifname = dnode.get_string_relative("./name").unwrap();
+            area.interfaces
+                .get_mut_by_name(&mut instance.arenas.interfaces, &ifname)
+                .map(|(iface_idx, _)| ListEntry::Interface(area_idx, iface_idx))
+                .expect("could not find OSPF interface")
+        })
+        .path(ospf::areas::area::interfaces::interface::interface_type::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let if_type = args.dnode.get_string();
+            let if_type = InterfaceType::try_from_yang(&if_type).unwrap();
+            iface.config.if_type = if_type;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+        })
+        .path(ospf::areas::area::interfaces::interface::passive::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let passive = args.dnode.get_bool();
+            iface.config.passive = passive;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceReset(area_idx, iface_idx));
+        })
+        .path(ospf::areas::area::interfaces::interface::priority::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let priority = args.dnode.get_u8();
+            iface.config.priority = priority;
+
+            let event_queue = args.event_queue;
+            event_queue
+                .insert(Event::InterfacePriorityChange(area_idx, iface_idx));
+            event_queue
+                .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+        })
+        .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::PATH)
+        .create_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let identifier = args.dnode.get_ip_relative("identifier").unwrap();
+            let identifier = V::NetIpAddr::get(identifier).unwrap();
+            iface
+                .config
+                .static_nbrs
                 .insert(identifier, Default::default());
         })
         .delete_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.static_nbrs.remove(&addr);
+        })
+        .lookup(|_instance, list_entry, dnode| {
+            let (_, iface_idx) = list_entry.into_interface().unwrap();
+
+            let identifier = dnode.get_ip_relative("./identifier").unwrap();
+            let identifier = V::NetIpAddr::get(identifier).unwrap();
+            ListEntry::StaticNbr(iface_idx, identifier)
+        })
+        .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::cost::PATH)
+        .modify_apply(|instance, args| {
+            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
+
+            let cost = args.dnode.get_u16();
+            snbr.cost = Some(cost);
+        })
+        .delete_apply(|instance, args| {
+            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
+
+            snbr.cost = None;
+        })
+        .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::poll_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
+
+            let poll_interval = args.dnode.get_u16();
+            snbr.poll_interval = poll_interval;
+        })
+        .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::priority::PATH)
+        .modify_apply(|instance, args| {
+            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
+
+            let priority = args.dnode.get_u8();
+            snbr.priority = priority;
+        })
+        .path(ospf::areas::area::interfaces::interface::multi_areas::multi_area::PATH)
+        .create_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let area_id =
+                args.dnode.get_ipv4_relative("multi-area-id").unwrap();
+            iface.config.multi_areas.insert(area_id, Default::default());
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .delete_apply(|instance, args| {
+            let (iface_idx, area_id) =
+                args.list_entry.into_multi_area().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.multi_areas.remove(&area_id);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .lookup(|_instance, list_entry, dnode| {
+            let (_, iface_idx) = list_entry.into_interface().unwrap();
+
+            let area_id = dnode.get_ipv4_relative("./multi-area-id").unwrap();
+            ListEntry::MultiArea(iface_idx, area_id)
+        })
+        .path(ospf::areas::area::interfaces::interface::multi_areas::multi_area::cost::PATH)
+        .modify_apply(|instance, args| {
+            let (iface_idx, area_id) =
+                args.list_entry.into_multi_area().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let multi_area = iface.config.multi_areas.get_mut(&area_id).unwrap();
+
+            let cost = args.dnode.get_u16();
+            multi_area.cost = Some(cost);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .delete_apply(|instance, args| {
+            let (iface_idx, area_id) =
+                args.list_entry.into_multi_area().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+            let multi_area = iface.config.multi_areas.get_mut(&area_id).unwrap();
+
+            multi_area.cost = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .path(ospf::areas::area::interfaces::interface::bfd::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.bfd_enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+        })
+        .path(ospf::areas::area::interfaces::interface::bfd::local_multiplier::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let local_multiplier = args.dnode.get_u8();
+            iface.config.bfd_params.local_multiplier = local_multiplier;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+        })
+        .path(ospf::areas::area::interfaces::interface::bfd::desired_min_tx_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let min_tx = args.dnode.get_u32();
+            iface.config.bfd_params.min_tx = min_tx;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(ospf::areas::area::interfaces::interface::bfd::required_min_rx_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let min_rx = args.dnode.get_u32();
+            iface.config.bfd_params.min_rx = min_rx;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(ospf::areas::area::interfaces::interface::bfd::min_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let min_interval = args.dnode.get_u32();
+            iface.config.bfd_params.min_tx = min_interval;
+            iface.config.bfd_params.min_rx = min_interval;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+        })
+        .delete_apply(|_instance, _args| {
+            // Nothing to do.
+        })
+        .path(ospf::areas::area::interfaces::interface::fast_reroute::lfa::candidate_enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let candidate_enabled = args.dnode.get_bool();
+            iface.config.frr.lfa_candidate = candidate_enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::areas::area::interfaces::interface::fast_reroute::lfa::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.frr.lfa_enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::areas::area::interfaces::interface::fast_reroute::lfa::remote_lfa::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.frr.remote_lfa_enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::areas::area::interfaces::interface::fast_reroute::lfa::ti_lfa::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.frr.ti_lfa_enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::RerunSpf);
+        })
+        .path(ospf::areas::area::interfaces::interface::te_attributes::te_metric::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let metric = args.dnode.get_u32();
+            iface.config.te.metric = Some(metric);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            iface.config.static_nbrs.remove(&addr);
+            iface.config.te.metric = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
         })
-        .lookup(|_instance, list_entry, dnode| {
-            let (_, iface_idx) = list_entry.into_interface().unwrap();
+        .path(ospf::areas::area::interfaces::interface::te_attributes::max_bandwidth::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let identifier = dnode.get_ip_relative("./identifier").unwrap();
-            let identifier = V::NetIpAddr::get(identifier).unwrap();
-            ListEntry::StaticNbr(iface_idx, identifier)
+            let max_bw = args.dnode.get_u64();
+            iface.config.te.max_bw = Some(max_bw);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
         })
-        .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::cost::PATH)
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te.max_bw = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .path(ospf::areas::area::interfaces::interface::te_attributes::max_reservable_bandwidth::PATH)
         .modify_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
-            let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
 
-            let cost = args.dnode.get_u16();
-            snbr.cost = Some(cost);
+            let max_rsv_bw = args.dnode.get_u64();
+            iface.config.te.max_rsv_bw = Some(max_rsv_bw);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
         })
         .delete_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
-            let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
 
-            snbr.cost = None;
+            iface.config.te.max_rsv_bw = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
         })
-        .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::poll_interval::PATH)
+        .path(ospf::areas::area::interfaces::interface::te_attributes::delay::PATH)
         .modify_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
-            let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
 
-            let poll_interval = args.dnode.get_u16();
-            snbr.poll_interval = poll_interval;
+            let delay = args.dnode.get_u32();
+            iface.config.te.delay = Some(delay);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
         })
-        .path(ospf::areas::area::interfaces::interface::static_neighbors::neighbor::priority::PATH)
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te.delay = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .path(ospf::areas::area::interfaces::interface::te_attributes::min_delay::PATH)
         .modify_apply(|instance, args| {
-            let (iface_idx, addr) = args.list_entry.into_static_nbr().unwrap();
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
-            let snbr = iface.config.static_nbrs.get_mut(&addr).unwrap();
 
-            let priority = args.dnode.get_u8();
-            snbr.priority = priority;
+            let min_delay = args.dnode.get_u32();
+            iface.config.te.min_delay = Some(min_delay);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
         })
-        .path(ospf::areas::area::interfaces::interface::bfd::enabled::PATH)
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te.min_delay = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .path(ospf::areas::area::interfaces::interface::te_attributes::max_delay::PATH)
         .modify_apply(|instance, args| {
             let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let enabled = args.dnode.get_bool();
-            iface.config.bfd_enabled = enabled;
+            let max_delay = args.dnode.get_u32();
+            iface.config.te.max_delay = Some(max_delay);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+            event_queue.insert(Event::InterfaceTeChange);
         })
-        .path(ospf::areas::area::interfaces::interface::bfd::local_multiplier::PATH)
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te.max_delay = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .path(ospf::areas::area::interfaces::interface::te_attributes::delay_variation::PATH)
         .modify_apply(|instance, args| {
             let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let local_multiplier = args.dnode.get_u8();
-            iface.config.bfd_params.local_multiplier = local_multiplier;
+            let delay_variation = args.dnode.get_u32();
+            iface.config.te.delay_variation = Some(delay_variation);
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+            event_queue.insert(Event::InterfaceTeChange);
         })
-        .path(ospf::areas::area::interfaces::interface::bfd::desired_min_tx_interval::PATH)
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.te.delay_variation = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .path(ospf::areas::area::interfaces::interface::te_attributes::admin_group::PATH)
+        .create_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let bit = args.dnode.get_u8();
+            iface.config.te.admin_groups |= 1 << bit;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let bit = args.dnode.get_u8();
+            iface.config.te.admin_groups &= !(1 << bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .path(ospf::areas::area::interfaces::interface::te_attributes::extended_admin_group::PATH)
+        .create_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let bit = args.dnode.get_u8();
+            iface.config.te.ext_admin_groups.insert(bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let bit = args.dnode.get_u8();
+            iface.config.te.ext_admin_groups.remove(&bit);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceTeChange);
+        })
+        .path(ospf::areas::area::interfaces::interface::hello_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let hello_interval = args.dnode.get_u16();
+            iface.config.hello_interval = hello_interval;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceResetHelloInterval(
+                area_idx, iface_idx,
+            ));
+            event_queue
+                .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .path(ospf::areas::area::interfaces::interface::dead_interval::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let dead_interval = args.dnode.get_u16();
+            iface.config.dead_interval = dead_interval;
+
+            let event_queue = args.event_queue;
+            event_queue
+                .insert(Event::InterfaceResetDeadInterval(area_idx, iface_idx));
+            event_queue
+                .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .path(
+            ospf::areas::area::interfaces::interface::retransmit_interval::PATH,
+        )
         .modify_apply(|instance, args| {
             let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let min_tx = args.dnode.get_u32();
-            iface.config.bfd_params.min_tx = min_tx;
+            let retransmit_interval = args.dnode.get_u16();
+            iface.config.retransmit_interval = retransmit_interval;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
-        .delete_apply(|_instance, _args| {
-            // Nothing to do.
+        .path(ospf::areas::area::interfaces::interface::transmit_delay::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let transmit_delay = args.dnode.get_u16();
+            iface.config.transmit_delay = transmit_delay;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MultiAreaSync);
         })
-        .path(ospf::areas::area::interfaces::interface::bfd::required_min_rx_interval::PATH)
+        .path(ospf::areas::area::interfaces::interface::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.enabled = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceUpdate(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .path(ospf::areas::area::interfaces::interface::cost::PATH)
+        .modify_apply(|instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let cost = args.dnode.get_u16();
+            iface.config.cost = cost;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InterfaceCostChange(area_idx));
+            event_queue.insert(Event::MultiAreaSync);
+        })
+        .path(ospf::areas::area::interfaces::interface::mtu_ignore::PATH)
         .modify_apply(|instance, args| {
             let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let min_rx = args.dnode.get_u32();
-            iface.config.bfd_params.min_rx = min_rx;
+            let mtu_ignore = args.dnode.get_bool();
+            iface.config.mtu_ignore = mtu_ignore;
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
-        .delete_apply(|_instance, _args| {
-            // Nothing to do.
+        .path(ospf::areas::area::interfaces::interface::ldp_igp_sync::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let enabled = args.dnode.get_bool();
+            iface.config.ldp_igp_sync = enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::LdpIgpSyncChange);
         })
-        .path(ospf::areas::area::interfaces::interface::bfd::min_interval::PATH)
+        .path(ospf::areas::area::interfaces::interface::prefix_suppression::PATH)
+        .modify_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            let prefix_suppression = args.dnode.get_bool();
+            iface.config.prefix_suppression = Some(prefix_suppression);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PrefixSuppressionChange);
+        })
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.prefix_suppression = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::PrefixSuppressionChange);
+        })
+        .path(ospf::areas::area::interfaces::interface::bandwidth::PATH)
         .modify_apply(|instance, args| {
             let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
 
-            let min_interval = args.dnode.get_u32();
-            iface.config.bfd_params.min_tx = min_interval;
-            iface.config.bfd_params.min_rx = min_interval;
+            let bandwidth = args.dnode.get_u32();
+            iface.config.bandwidth = Some(bandwidth);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MultiAreaSync);
+            event_queue.insert(Event::ReinstallRoutes);
+        })
+        .delete_apply(|instance, args| {
+            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
+            let iface = &mut instance.arenas.interfaces[iface_idx];
+
+            iface.config.bandwidth = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::MultiAreaSync);
+            event_queue.insert(Event::ReinstallRoutes);
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::PATH)
+        .create_apply(|instance, args| {
+            let area_idx = args.list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let transit_area_id =
+                args.dnode.get_ipv4_relative("transit-area-id").unwrap();
+            let router_id = args.dnode.get_ipv4_relative("router-id").unwrap();
+            let ifname = VirtualLink::<V>::ifname(transit_area_id, router_id);
+            let (iface_idx, iface) = area
+                .interfaces
+                .insert(&mut instance.arenas.interfaces, &ifname);
+            iface.config.if_type = InterfaceType::VirtualLink;
+            iface.system.vlink =
+                Some(VirtualLink::new(transit_area_id, router_id));
 
             let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceBfdChange(iface_idx));
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::InterfaceUpdate(area_idx, iface_idx));
+            event_queue.insert(Event::RerunSpf);
         })
-        .delete_apply(|_instance, _args| {
-            // Nothing to do.
+        .delete_apply(|_instance, args| {
+            let (area_idx, iface_idx) =
+                args.list_entry.into_interface().unwrap();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::InstanceUpdate);
+            event_queue.insert(Event::InterfaceDelete(area_idx, iface_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::hello_interval::PATH)
+        .lookup(|instance, list_entry, dnode| {
+            let area_idx = list_entry.into_area().unwrap();
+            let area = &mut instance.arenas.areas[area_idx];
+
+            let transit_area_id =
+                dnode.get_ipv4_relative("./transit-area-id").unwrap();
+            let router_id = dnode.get_ipv4_relative("./router-id").unwrap();
+            let ifname = VirtualLink::<V>::ifname(transit_area_id, router_id);
+            area.interfaces
+                .get_mut_by_name(&mut instance.arenas.interfaces, &ifname)
+                .map(|(iface_idx, _)| ListEntry::Interface(area_idx, iface_idx))
+                .expect("could not find OSPF virtual link")
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::hello_interval::PATH)
         .modify_apply(|instance, args| {
             let (area_idx, iface_idx) =
                 args.list_entry.into_interface().unwrap();
@@ -713,7 +1834,7 @@ where
             event_queue
                 .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::dead_interval::PATH)
+        .path(ospf::areas::area::virtual_links::virtual_link::dead_interval::PATH)
         .modify_apply(|instance, args| {
             let (area_idx, iface_idx) =
                 args.list_entry.into_interface().unwrap();
@@ -728,9 +1849,7 @@ where
             event_queue
                 .insert(Event::InterfaceSyncHelloTx(area_idx, iface_idx));
         })
-        .path(
-            ospf::areas::area::interfaces::interface::retransmit_interval::PATH,
-        )
+        .path(ospf::areas::area::virtual_links::virtual_link::retransmit_interval::PATH)
         .modify_apply(|instance, args| {
             let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
@@ -738,7 +1857,7 @@ where
             let retransmit_interval = args.dnode.get_u16();
             iface.config.retransmit_interval = retransmit_interval;
         })
-        .path(ospf::areas::area::interfaces::interface::transmit_delay::PATH)
+        .path(ospf::areas::area::virtual_links::virtual_link::transmit_delay::PATH)
         .modify_apply(|instance, args| {
             let (_, iface_idx) = args.list_entry.into_interface().unwrap();
             let iface = &mut instance.arenas.interfaces[iface_idx];
@@ -746,7 +1865,7 @@ where
             let transmit_delay = args.dnode.get_u16();
             iface.config.transmit_delay = transmit_delay;
         })
-        .path(ospf::areas::area::interfaces::interface::enabled::PATH)
+        .path(ospf::areas::area::virtual_links::virtual_link::enabled::PATH)
         .modify_apply(|instance, args| {
             let (area_idx, iface_idx) =
                 args.list_entry.into_interface().unwrap();
@@ -758,26 +1877,6 @@ where
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdate(area_idx, iface_idx));
         })
-        .path(ospf::areas::area::interfaces::interface::cost::PATH)
-        .modify_apply(|instance, args| {
-            let (area_idx, iface_idx) =
-                args.list_entry.into_interface().unwrap();
-            let iface = &mut instance.arenas.interfaces[iface_idx];
-
-            let cost = args.dnode.get_u16();
-            iface.config.cost = cost;
-
-            let event_queue = args.event_queue;
-            event_queue.insert(Event::InterfaceCostChange(area_idx));
-        })
-        .path(ospf::areas::area::interfaces::interface::mtu_ignore::PATH)
-        .modify_apply(|instance, args| {
-            let (_, iface_idx) = args.list_entry.into_interface().unwrap();
-            let iface = &mut instance.arenas.interfaces[iface_idx];
-
-            let mtu_ignore = args.dnode.get_bool();
-            iface.config.mtu_ignore = mtu_ignore;
-        })
         .build()
 }
 
@@ -795,6 +1894,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) =
@@ -805,6 +1905,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv2_key_id::PATH)
         .modify_apply(|instance, args| {
@@ -817,6 +1918,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) =
@@ -827,6 +1929,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv2_key::PATH)
         .modify_apply(|instance, args| {
@@ -839,6 +1942,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) =
@@ -849,6 +1953,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
         .path(ospf::areas::area::interfaces::interface::authentication::ospfv2_crypto_algorithm::PATH)
         .modify_apply(|instance, args| {
@@ -862,6 +1967,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
         .delete_apply(|instance, args| {
             let (area_idx, iface_idx) =
@@ -872,6 +1978,7 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
 
             let event_queue = args.event_queue;
             event_queue.insert(Event::InterfaceUpdateAuth(area_idx, iface_idx));
+            event_queue.insert(Event::MultiAreaSync);
         })
         .build()
 }
@@ -890,6 +1997,28 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
         .delete_apply(|_instance, _args| {
             // Nothing to do.
         })
+        .path(ospf::srv6::enabled::PATH)
+        .modify_apply(|instance, args| {
+            let srv6_enabled = args.dnode.get_bool();
+            instance.config.srv6_enabled = srv6_enabled;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::Srv6Change);
+        })
+        .path(ospf::srv6::locator::PATH)
+        .modify_apply(|instance, args| {
+            let locator = args.dnode.get_string();
+            instance.config.srv6_locator = Some(locator);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::Srv6Change);
+        })
+        .delete_apply(|instance, args| {
+            instance.config.srv6_locator = None;
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::Srv6Change);
+        })
         .path(ospf::areas::area::interfaces::interface::instance_id::PATH)
         .modify_apply(|instance, args| {
             let (area_idx, iface_idx) =
@@ -1029,14 +2158,68 @@ fn load_validation_callbacks() -> ValidationCallbacks {
 
             Ok(())
         })
+        .path(ospf::areas::area::interfaces::interface::multi_areas::multi_area::PATH)
+        .validate(|args| {
+            let area_id =
+                args.dnode.get_ipv4_relative("./multi-area-id").unwrap();
+            let primary_area_id =
+                args.dnode.get_ipv4_relative("../../../../area-id").unwrap();
+
+            // The multi-area adjacency must be in a different area.
+            if area_id == primary_area_id {
+                return Err(format!(
+                    "multi-area adjacency can't be configured in the interface's own area ({})",
+                    area_id
+                ));
+            }
+
+            // The area of the multi-area adjacency must be configured.
+            let xpath = format!("../../../../../area[area-id='{}']", area_id);
+            if args.dnode.find_xpath(&xpath).unwrap().next().is_none() {
+                return Err(format!(
+                    "multi-area adjacency area {} isn't configured",
+                    area_id
+                ));
+            }
+
+            Ok(())
+        })
+        .path(ospf::redistribution::redistribute::protocol::PATH)
+        .validate(|args| {
+            let valid_options = [
+                Protocol::DIRECT,
+                Protocol::STATIC,
+                Protocol::BGP,
+                Protocol::OSPFV2,
+                Protocol::OSPFV3,
+                Protocol::RIPV2,
+                Protocol::RIPNG,
+            ];
+
+            let protocol = args.dnode.get_string();
+            if !Protocol::try_from_yang(&protocol)
+                .is_some_and(|protocol| valid_options.contains(&protocol))
+            {
+                return Err("unsupported redistribution protocol".to_string());
+            }
+
+            // Routes learned by the instance's own protocol can't be
+            // redistributed.
+            let instance_type =
+                args.dnode.get_string_relative("../../../../type").unwrap();
+            if protocol == instance_type {
+                return Err(
+                    "can't redistribute routes from the same protocol"
+                        .to_string(),
+                );
+            }
+
+            Ok(())
+        })
         .path(ospf::areas::area::area_type::PATH)
         .validate(|args| {
             let area_type = args.dnode.get_string();
             let area_type = AreaType::try_from_yang(&area_type).unwrap();
-            if area_type == AreaType::Nssa {
-                return Err("unsupported area type".to_string());
-            }
-
             let area_id = args.dnode.get_ipv4_relative("../area-id").unwrap();
             if area_type != AreaType::Normal && area_id == Ipv4Addr::UNSPECIFIED
             {
@@ -1102,6 +2285,14 @@ fn load_validation_callbacks_ospfv3() -> ValidationCallbacks {
 
             Ok(())
         })
+        .path(ospf::areas::area::virtual_links::virtual_link::PATH)
+        .validate(|_args| {
+            Err("virtual links aren't supported by OSPFv3".to_string())
+        })
+        .path(ospf::areas::area::interfaces::interface::multi_areas::multi_area::PATH)
+        .validate(|_args| {
+            Err("multi-area adjacencies aren't supported by OSPFv3".to_string())
+        })
         .path(ospf::areas::area::interfaces::interface::instance_id::PATH)
         .validate(|args| {
             let instance_id = args.dnode.get_u8();
@@ -1227,7 +2418,7 @@ where
                 self.arenas.areas.delete(area_idx);
             }
             Event::AreaTypeChange(area_idx) => {
-                if let Some((instance, arenas)) = self.as_up() {
+                if let Some((mut instance, arenas)) = self.as_up() {
                     let area = &arenas.areas[area_idx];
 
                     // Kill all neighbors in the area to speed-up reconvergence.
@@ -1253,6 +2444,21 @@ where
                     }) {
                         instance.state.lsdb = Default::default();
                     }
+
+                    // (Re)originate or flush NSSA-LSAs.
+                    redistribution::update_all(
+                        &mut instance,
+                        &mut arenas.areas,
+                        &arenas.interfaces,
+                        &arenas.lsa_entries,
+                    );
+
+                    // (Re)originate Router-LSAs since the area's ASBR status
+                    // might have changed.
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::AsbrStatusChange);
                 }
             }
             Event::AreaSyncHelloTx(area_idx) => {
@@ -1407,6 +2613,23 @@ where
                     }
                 }
             }
+            Event::LdpIgpSyncChange => {
+                if let Some((instance, arenas)) = self.as_up() {
+                    for area in arenas.areas.iter() {
+                        for iface_idx in area.interfaces.indexes() {
+                            let iface = &mut arenas.interfaces[iface_idx];
+                            iface.ldp_sync_update(area, &instance);
+                        }
+
+                        // (Re)originate Router-LSA.
+                        instance.tx.protocol_input.lsa_orig_event(
+                            LsaOriginateEvent::InterfaceCostChange {
+                                area_id: area.id,
+                            },
+                        );
+                    }
+                }
+            }
             Event::InterfaceQuerySouthbound(ifname, af) => {
                 if self.is_active() {
                     let _ = self.tx.ibus.send(IbusMsg::InterfaceQuery {
@@ -1415,8 +2638,135 @@ where
                     });
                 }
             }
+            Event::MultiAreaSync => {
+                // Get the multi-area interfaces that should exist, indexed by
+                // their area and name.
+                let mut multi_areas = BTreeMap::new();
+                for area in self.arenas.areas.iter() {
+                    for iface_idx in area.interfaces.indexes() {
+                        let iface = &self.arenas.interfaces[iface_idx];
+                        if iface.is_multi_area() {
+                            continue;
+                        }
+
+                        for (area_id, cfg) in &iface.config.multi_areas {
+                            if *area_id == area.area_id {
+                                continue;
+                            }
+                            let Some((ma_area_idx, _)) =
+                                self.arenas.areas.get_by_area_id(*area_id)
+                            else {
+                                continue;
+                            };
+                            multi_areas.insert(
+                                (ma_area_idx, iface.name.clone()),
+                                (area.area_id, iface_idx, cfg.cost),
+                            );
+                        }
+                    }
+                }
+
+                // Delete stale multi-area interfaces.
+                let mut stale = vec![];
+                for area_idx in self.arenas.areas.indexes() {
+                    let area = &self.arenas.areas[area_idx];
+                    for iface_idx in area.interfaces.indexes() {
+                        let iface = &self.arenas.interfaces[iface_idx];
+                        if let Some(multi_area) = &iface.system.multi_area
+                            && !matches!(
+                                multi_areas.get(&(area_idx, iface.name.clone())),
+                                Some((primary_area_id, ..))
+                                    if *primary_area_id
+                                        == multi_area.primary_area_id
+                            )
+                        {
+                            stale.push((area_idx, iface_idx));
+                        }
+                    }
+                }
+                for (area_idx, iface_idx) in stale {
+                    self.process_event(Event::InterfaceDelete(
+                        area_idx, iface_idx,
+                    ))
+                    .await;
+                }
+
+                // Create or update multi-area interfaces.
+                let af = V::address_family(self);
+                for (
+                    (area_idx, ifname),
+                    (primary_area_id, primary_idx, cost),
+                ) in multi_areas
+                {
+                    let area = &mut self.arenas.areas[area_idx];
+                    let (iface_idx, created) = match area
+                        .interfaces
+                        .get_by_name(&self.arenas.interfaces, &ifname)
+                    {
+                        Some((iface_idx, _)) => (iface_idx, false),
+                        None => {
+                            let (iface_idx, iface) = area
+                                .interfaces
+                                .insert(&mut self.arenas.interfaces, &ifname);
+                            iface.system.multi_area =
+                                Some(MultiArea::new(primary_area_id));
+                            (iface_idx, true)
+                        }
+                    };
+
+                    // Inherit the configuration of the primary interface.
+                    let (Some(iface), Some(primary)) =
+                        self.arenas.interfaces.get2_mut(iface_idx, primary_idx)
+                    else {
+                        continue;
+                    };
+                    let old_cost = iface.config.cost;
+                    let reset = multi_area::inherit_config(
+                        &mut iface.config,
+                        &primary.config,
+                        cost,
+                    );
+                    let cost_change = !created && iface.config.cost != old_cost;
+
+                    if created {
+                        self.process_event(Event::InterfaceUpdate(
+                            area_idx, iface_idx,
+                        ))
+                        .await;
+                        self.process_event(Event::InterfaceQuerySouthbound(
+                            ifname, af,
+                        ))
+                        .await;
+                        continue;
+                    }
+                    if reset {
+                        self.process_event(Event::InterfaceReset(
+                            area_idx, iface_idx,
+                        ))
+                        .await;
+                    }
+                    self.process_event(Event::InterfaceUpdate(
+                        area_idx, iface_idx,
+                    ))
+                    .await;
+                    if cost_change {
+                        self.process_event(Event::InterfaceCostChange(
+                            area_idx,
+                        ))
+                        .await;
+                    }
+                }
+            }
             Event::StubRouterChange => {
-                if let Some((instance, _)) = self.as_up() {
+                if let Some((mut instance, _)) = self.as_up() {
+                    // Leave the stub router state on startup if it was
+                    // unconfigured.
+                    if !instance.config.stub_router_startup
+                        && instance.state.stub_router_startup.is_some()
+                    {
+                        lsdb::stub_router_startup_exit(&mut instance);
+                    }
+
                     // (Re)originate Router-LSAs.
                     instance
                         .tx
@@ -1424,6 +2774,42 @@ where
                         .lsa_orig_event(LsaOriginateEvent::StubRouterChange);
                 }
             }
+            Event::LsdbLimitsChange => {
+                if let Some((mut instance, arenas)) = self.as_up() {
+                    // Leave the overflow state if the LSDB limit was removed
+                    // or raised above the current number of LSAs.
+                    let below_limit = match instance.config.ext_lsdb_limit {
+                        Some(limit) => {
+                            instance.state.lsdb.ext_lsa_count() < limit
+                        }
+                        None => true,
+                    };
+                    if instance.state.lsdb_overflow && below_limit {
+                        lsdb::overflow_exit(&mut instance);
+                    }
+
+                    // Check the LSDB against the new limits.
+                    lsdb::limits_check(&mut instance, arenas, true);
+                }
+            }
+            Event::GrEnableChange => {
+                if let Some((instance, _)) = self.as_up() {
+                    // (Re)originate Router Information LSAs.
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::GrEnableChange);
+                }
+            }
+            Event::RouterInfoChange => {
+                if let Some((instance, _)) = self.as_up() {
+                    // (Re)originate Router Information LSAs.
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::RouterInfoChange);
+                }
+            }
             Event::GrHelperChange => {
                 if let Some((mut instance, arenas)) = self.as_up() {
                     // Exit from the helper mode for all neighbors.
@@ -1452,7 +2838,11 @@ where
 
                     // Iterate over all existing adjacencies.
                     for area in arenas.areas.iter_mut() {
-                        for iface in area.interfaces.iter(&arenas.interfaces) {
+                        for iface in area
+                            .interfaces
+                            .iter(&arenas.interfaces)
+                            .filter(|iface| !iface.is_virtual_link())
+                        {
                             for nbr_idx in iface.state.neighbors.indexes() {
                                 let nbr = &mut arenas.neighbors[nbr_idx];
                                 if nbr.state < nsm::State::TwoWay {
@@ -1471,6 +2861,102 @@ where
                     }
                 }
             }
+            Event::Srv6Change => {
+                if let Some((mut instance, arenas)) = self.as_up() {
+                    // Reallocate the local SRv6 SIDs.
+                    srv6::update_all(&mut instance, arenas);
+
+                    // (Re)originate LSAs that might have been affected.
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::Srv6Change);
+                }
+            }
+            Event::FlexAlgoChange => {
+                if let Some((instance, _)) = self.as_up() {
+                    // (Re)originate LSAs that might have been affected.
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::FlexAlgoChange);
+
+                    // Rerun SPF to update the Flex-Algo forwarding entries.
+                    instance
+                        .tx
+                        .protocol_input
+                        .spf_delay_event(spf::fsm::Event::ConfigChange);
+                }
+            }
+            Event::InterfaceTeChange => {
+                if let Some((instance, _)) = self.as_up() {
+                    // (Re)originate the LSAs carrying the link attributes.
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::FlexAlgoChange);
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::TeChange);
+                }
+            }
+            Event::TeChange => {
+                if let Some((instance, _)) = self.as_up() {
+                    // (Re)originate or flush the TE LSAs.
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::TeChange);
+                }
+            }
+            Event::PrefixSuppressionChange => {
+                if let Some((instance, _)) = self.as_up() {
+                    // (Re)originate the LSAs containing interface prefixes.
+                    instance.tx.protocol_input.lsa_orig_event(
+                        LsaOriginateEvent::PrefixSuppressionChange,
+                    );
+                }
+            }
+            Event::LinkStateChange => {
+                if let Some((instance, _)) = self.as_up() {
+                    if instance.config.distribute_link_state {
+                        // Rerun SPF to export the full topology.
+                        instance
+                            .tx
+                            .protocol_input
+                            .spf_delay_event(spf::fsm::Event::ConfigChange);
+                    } else {
+                        southbound::tx::link_state_delete(
+                            &instance.tx.ibus,
+                            V::PROTOCOL,
+                            instance.name,
+                        );
+                    }
+                }
+            }
+            Event::RedistributionChange => {
+                if let Some((mut instance, arenas)) = self.as_up() {
+                    // (Re)originate or flush AS-external-LSAs and NSSA-LSAs.
+                    redistribution::update_all(
+                        &mut instance,
+                        &mut arenas.areas,
+                        &arenas.interfaces,
+                        &arenas.lsa_entries,
+                    );
+
+                    // (Re)originate Router-LSAs since the ASBR status might
+                    // have changed.
+                    instance
+                        .tx
+                        .protocol_input
+                        .lsa_orig_event(LsaOriginateEvent::AsbrStatusChange);
+                }
+            }
+            Event::RedistributionDump => {
+                // Request routes that might be redistributed.
+                southbound::tx::route_redistribute_dump(&self.tx.ibus);
+            }
             Event::RerunSpf => {
                 if let Some((instance, _)) = self.as_up() {
                     instance
@@ -1503,6 +2989,7 @@ where
                             route,
                             None,
                             distance,
+                            instance.config.ucmp_enabled,
                             &arenas.interfaces,
                         );
                     }
@@ -1512,6 +2999,15 @@ where
     }
 }
 
+// ===== impl InstanceCfg =====
+
+impl InstanceCfg {
+    // Returns whether the router is configured as an AS boundary router.
+    pub(crate) fn is_asbr(&self) -> bool {
+        !self.redistribution.is_empty() || self.default_info_originate.enabled
+    }
+}
+
 // ===== impl ListEntry =====
 
 #[allow(clippy::derivable_impls)]
@@ -1539,9 +3035,27 @@ impl Default for InstanceCfg {
         let spf_hold_down = ospf::spf_control::ietf_spf_delay::hold_down::DFLT;
         let spf_time_to_learn =
             ospf::spf_control::ietf_spf_delay::time_to_learn::DFLT;
+        let spf_incremental = ospf::spf_control::incremental::DFLT;
         let extended_lsa = ospf::extended_lsa_support::DFLT;
         let sr_enabled = ospf::segment_routing::enabled::DFLT;
+        let srv6_enabled = ospf::srv6::enabled::DFLT;
         let instance_id = ospf::instance_id::DFLT;
+        let distribute_link_state = ospf::distribute_link_state::DFLT;
+        let te_enabled = ospf::mpls::te_enabled::DFLT;
+        let exit_overflow_interval =
+            ospf::database_control::exit_overflow_interval::DFLT;
+        let stub_router_startup_timeout =
+            ospf::stub_router::on_startup::timeout::DFLT;
+        let stub_router_startup_wait_bgp =
+            ospf::stub_router::on_startup::wait_for_bgp::DFLT;
+        let lsa_throttle_initial_delay =
+            ospf::lsa_throttle::initial_delay::DFLT;
+        let lsa_throttle_hold_interval =
+            ospf::lsa_throttle::hold_interval::DFLT;
+        let lsa_throttle_max_interval = ospf::lsa_throttle::max_interval::DFLT;
+        let lsa_min_arrival = ospf::lsa_min_arrival::DFLT;
+        let ucmp_enabled = ospf::ucmp::enabled::DFLT;
+        let prefix_suppression = ospf::prefix_suppression::DFLT;
 
         InstanceCfg {
             af: None,
@@ -1555,10 +3069,36 @@ impl Default for InstanceCfg {
             spf_long_delay,
             spf_hold_down,
             spf_time_to_learn,
+            spf_incremental,
             stub_router: false,
+            stub_router_startup: false,
+            stub_router_startup_timeout,
+            stub_router_startup_wait_bgp,
+            lsa_throttle_initial_delay,
+            lsa_throttle_hold_interval,
+            lsa_throttle_max_interval,
+            lsa_min_arrival,
+            ucmp_enabled,
+            prefix_suppression,
+            max_lsa: None,
+            ext_lsdb_limit: None,
+            exit_overflow_interval,
             extended_lsa,
             sr_enabled,
+            srv6_enabled,
+            srv6_locator: None,
             instance_id,
+            distribute_link_state,
+            te_enabled,
+            te_rid_ipv4: None,
+            te_rid_ipv6: None,
+            ldp_igp_sync: false,
+            ldp_igp_sync_holddown: None,
+            redistribution: Default::default(),
+            default_info_originate: Default::default(),
+            flex_algos: Default::default(),
+            hostname: None,
+            node_tags: Default::default(),
         }
     }
 }
@@ -1577,13 +3117,72 @@ impl Default for Preference {
     }
 }
 
+impl Default for RedistributionCfg {
+    fn default() -> RedistributionCfg {
+        let metric = ospf::redistribution::redistribute::metric::DFLT;
+        let metric_type = ospf::redistribution::redistribute::metric_type::DFLT;
+        let metric_type =
+            ExternalMetricType::try_from_yang(metric_type).unwrap();
+
+        RedistributionCfg {
+            metric,
+            metric_type,
+            tag: None,
+            policy: None,
+        }
+    }
+}
+
+impl Default for DefaultInfoOriginateCfg {
+    fn default() -> DefaultInfoOriginateCfg {
+        let enabled = ospf::default_information_originate::enabled::DFLT;
+        let always = ospf::default_information_originate::always::DFLT;
+        let metric = ospf::default_information_originate::metric::DFLT;
+        let metric_type =
+            ospf::default_information_originate::metric_type::DFLT;
+        let metric_type =
+            ExternalMetricType::try_from_yang(metric_type).unwrap();
+
+        DefaultInfoOriginateCfg {
+            enabled,
+            always,
+            metric,
+            metric_type,
+        }
+    }
+}
+
+impl Default for FlexAlgoCfg {
+    fn default() -> FlexAlgoCfg {
+        let advertise = ospf::flex_algo::definition::advertise_definition::DFLT;
+        let priority = ospf::flex_algo::definition::priority::DFLT;
+        let metric_type = ospf::flex_algo::definition::metric_type::DFLT;
+        let metric_type =
+            FlexAlgoMetricType::try_from_yang(metric_type).unwrap();
+
+        FlexAlgoCfg {
+            advertise,
+            priority,
+            metric_type,
+            exclude_any: 0,
+            include_any: 0,
+            include_all: 0,
+            prefix_sids: Default::default(),
+        }
+    }
+}
+
 impl Default for InstanceGrCfg {
     fn default() -> InstanceGrCfg {
+        let enabled = ospf::graceful_restart::enabled::DFLT;
+        let restart_interval = ospf::graceful_restart::restart_interval::DFLT;
         let helper_enabled = ospf::graceful_restart::helper_enabled::DFLT;
         let helper_strict_lsa_checking =
             ospf::graceful_restart::helper_strict_lsa_checking::DFLT;
 
         InstanceGrCfg {
+            enabled,
+            restart_interval,
             helper_enabled,
             helper_strict_lsa_checking,
         }
@@ -1596,11 +3195,16 @@ impl Default for AreaCfg {
         let area_type = AreaType::try_from_yang(area_type).unwrap();
         let summary = ospf::areas::area::summary::DFLT;
         let default_cost = ospf::areas::area::default_cost::DFLT;
+        let nssa_translator_role =
+            ospf::areas::area::nssa_translator_role::DFLT;
+        let nssa_translator_role =
+            NssaTranslatorRole::try_from_yang(nssa_translator_role).unwrap();
 
         AreaCfg {
             area_type,
             summary,
             default_cost,
+            nssa_translator_role,
         }
     }
 }
@@ -1641,6 +3245,8 @@ where
             ospf::areas::area::interfaces::interface::mtu_ignore::DFLT;
         let bfd_enabled =
             ospf::areas::area::interfaces::interface::bfd::enabled::DFLT;
+        let ldp_igp_sync =
+            ospf::areas::area::interfaces::interface::ldp_igp_sync::enabled::DFLT;
 
         InterfaceCfg {
             instance_id: InheritableConfig::new(instance_id),
@@ -1655,12 +3261,38 @@ where
             cost,
             mtu_ignore,
             static_nbrs: Default::default(),
+            multi_areas: Default::default(),
             auth_keychain: None,
             auth_keyid: None,
             auth_key: None,
             auth_algo: None,
             bfd_enabled,
             bfd_params: Default::default(),
+            frr: Default::default(),
+            te: Default::default(),
+            ldp_igp_sync,
+            bandwidth: None,
+            prefix_suppression: None,
+        }
+    }
+}
+
+impl Default for InterfaceFrrCfg {
+    fn default() -> InterfaceFrrCfg {
+        let lfa_candidate =
+            ospf::areas::area::interfaces::interface::fast_reroute::lfa::candidate_enabled::DFLT;
+        let lfa_enabled =
+            ospf::areas::area::interfaces::interface::fast_reroute::lfa::enabled::DFLT;
+        let remote_lfa_enabled =
+            ospf::areas::area::interfaces::interface::fast_reroute::lfa::remote_lfa::enabled::DFLT;
+        let ti_lfa_enabled =
+            ospf::areas::area::interfaces::interface::fast_reroute::lfa::ti_lfa::enabled::DFLT;
+
+        InterfaceFrrCfg {
+            lfa_candidate,
+            lfa_enabled,
+            remote_lfa_enabled,
+            ti_lfa_enabled,
         }
     }
 }
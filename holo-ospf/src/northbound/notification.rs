@@ -9,8 +9,9 @@ use std::net::Ipv4Addr;
 use holo_northbound::{notification, paths};
 use holo_yang::ToYang;
 
+use crate::area::Area;
 use crate::error::InterfaceCfgError;
-use crate::gr::GrExitReason;
+use crate::gr::{GrExitReason, GrRestartStatus};
 use crate::instance::InstanceUpView;
 use crate::interface::Interface;
 use crate::neighbor::Neighbor;
@@ -29,20 +30,35 @@ pub(crate) fn if_state_change<V>(
     use paths::if_state_change as base;
 
     let af = instance.state.af.to_yang();
+    let vlink = vlink_ids(iface);
     let state = iface.state.ism_state.to_yang();
 
-    let args = [
+    let mut args = vec![
         (base::routing_protocol_name::PATH, Some(instance.name)),
-        (base::address_family::PATH, Some(&af)),
-        (base::interface::interface::PATH, Some(&iface.name)),
-        (base::state::PATH, Some(&state)),
+        (base::address_family::PATH, Some(af.as_ref())),
     ];
+    match &vlink {
+        Some((transit_area_id, nbr_router_id)) => {
+            args.push((
+                base::virtual_link::transit_area_id::PATH,
+                Some(transit_area_id),
+            ));
+            args.push((
+                base::virtual_link::neighbor_router_id::PATH,
+                Some(nbr_router_id),
+            ));
+        }
+        None => {
+            args.push((base::interface::interface::PATH, Some(&iface.name)));
+        }
+    }
+    args.push((base::state::PATH, Some(&state)));
     notification::send(&instance.tx.nb, base::PATH, &args);
 }
 
 pub(crate) fn if_config_error<V>(
     instance: &InstanceUpView<'_, V>,
-    ifname: &str,
+    iface: &Interface<V>,
     src: &V::NetIpAddr,
     pkt_type: &PacketType,
     error: &InterfaceCfgError,
@@ -52,18 +68,35 @@ pub(crate) fn if_config_error<V>(
     use paths::if_config_error as base;
 
     let af = instance.state.af.to_yang();
+    let vlink = vlink_ids(iface);
     let src = src.to_string();
     let pkt_type = pkt_type.to_yang();
     let error = error.to_yang();
 
-    let args = [
+    let mut args = vec![
         (base::routing_protocol_name::PATH, Some(instance.name)),
-        (base::address_family::PATH, Some(&af)),
-        (base::interface::interface::PATH, Some(ifname)),
-        (base::packet_source::PATH, Some(&src)),
-        (base::packet_type::PATH, Some(&pkt_type)),
-        (base::error::PATH, Some(&error)),
+        (base::address_family::PATH, Some(af.as_ref())),
     ];
+    match &vlink {
+        Some((transit_area_id, nbr_router_id)) => {
+            args.push((
+                base::virtual_link::transit_area_id::PATH,
+                Some(transit_area_id),
+            ));
+            args.push((
+                base::virtual_link::neighbor_router_id::PATH,
+                Some(nbr_router_id),
+            ));
+        }
+        None => {
+            args.push((base::interface::interface::PATH, Some(&iface.name)));
+        }
+    }
+    args.extend([
+        (base::packet_source::PATH, Some(src.as_str())),
+        (base::packet_type::PATH, Some(pkt_type.as_ref())),
+        (base::error::PATH, Some(error.as_ref())),
+    ]);
     notification::send(&instance.tx.nb, base::PATH, &args);
 }
 
@@ -77,18 +110,35 @@ pub(crate) fn nbr_state_change<V>(
     use paths::nbr_state_change as base;
 
     let af = instance.state.af.to_yang();
+    let vlink = vlink_ids(iface);
     let nbr_router_id = nbr.router_id.to_string();
     let nbr_addr = nbr.src.to_string();
     let state = nbr.state.to_yang();
 
-    let args = [
+    let mut args = vec![
         (base::routing_protocol_name::PATH, Some(instance.name)),
-        (base::address_family::PATH, Some(&af)),
-        (base::interface::interface::PATH, Some(&iface.name)),
-        (base::neighbor_router_id::PATH, Some(&nbr_router_id)),
-        (base::neighbor_ip_addr::PATH, Some(&nbr_addr)),
-        (base::state::PATH, Some(&state)),
+        (base::address_family::PATH, Some(af.as_ref())),
     ];
+    match &vlink {
+        Some((transit_area_id, nbr_router_id)) => {
+            args.push((
+                base::virtual_link::transit_area_id::PATH,
+                Some(transit_area_id),
+            ));
+            args.push((
+                base::virtual_link::neighbor_router_id::PATH,
+                Some(nbr_router_id),
+            ));
+        }
+        None => {
+            args.push((base::interface::interface::PATH, Some(&iface.name)));
+        }
+    }
+    args.extend([
+        (base::neighbor_router_id::PATH, Some(nbr_router_id.as_str())),
+        (base::neighbor_ip_addr::PATH, Some(nbr_addr.as_str())),
+        (base::state::PATH, Some(state.as_ref())),
+    ]);
     notification::send(&instance.tx.nb, base::PATH, &args);
 }
 
@@ -103,19 +153,36 @@ pub(crate) fn nbr_restart_helper_enter<V>(
     use paths::nbr_restart_helper_status_change as base;
 
     let af = instance.state.af.to_yang();
+    let vlink = vlink_ids(iface);
     let nbr_router_id = nbr.router_id.to_string();
     let nbr_addr = nbr.src.to_string();
     let age = age.to_string();
 
-    let args = [
+    let mut args = vec![
         (base::routing_protocol_name::PATH, Some(instance.name)),
-        (base::address_family::PATH, Some(&af)),
-        (base::interface::interface::PATH, Some(&iface.name)),
-        (base::neighbor_router_id::PATH, Some(&nbr_router_id)),
-        (base::neighbor_ip_addr::PATH, Some(&nbr_addr)),
-        (base::status::PATH, Some("helping")),
-        (base::age::PATH, Some(&age)),
+        (base::address_family::PATH, Some(af.as_ref())),
     ];
+    match &vlink {
+        Some((transit_area_id, nbr_router_id)) => {
+            args.push((
+                base::virtual_link::transit_area_id::PATH,
+                Some(transit_area_id),
+            ));
+            args.push((
+                base::virtual_link::neighbor_router_id::PATH,
+                Some(nbr_router_id),
+            ));
+        }
+        None => {
+            args.push((base::interface::interface::PATH, Some(&iface.name)));
+        }
+    }
+    args.extend([
+        (base::neighbor_router_id::PATH, Some(nbr_router_id.as_str())),
+        (base::neighbor_ip_addr::PATH, Some(nbr_addr.as_str())),
+        (base::status::PATH, Some("helping")),
+        (base::age::PATH, Some(age.as_str())),
+    ]);
     notification::send(&instance.tx.nb, base::PATH, &args);
 }
 
@@ -130,18 +197,124 @@ pub(crate) fn nbr_restart_helper_exit<V>(
     use paths::nbr_restart_helper_status_change as base;
 
     let af = instance.state.af.to_yang();
+    let vlink = vlink_ids(iface);
     let nbr_router_id = nbr.router_id.to_string();
     let nbr_addr = nbr.src.to_string();
     let reason = reason.to_yang();
 
+    let mut args = vec![
+        (base::routing_protocol_name::PATH, Some(instance.name)),
+        (base::address_family::PATH, Some(af.as_ref())),
+    ];
+    match &vlink {
+        Some((transit_area_id, nbr_router_id)) => {
+            args.push((
+                base::virtual_link::transit_area_id::PATH,
+                Some(transit_area_id),
+            ));
+            args.push((
+                base::virtual_link::neighbor_router_id::PATH,
+                Some(nbr_router_id),
+            ));
+        }
+        None => {
+            args.push((base::interface::interface::PATH, Some(&iface.name)));
+        }
+    }
+    args.extend([
+        (base::neighbor_router_id::PATH, Some(nbr_router_id.as_str())),
+        (base::neighbor_ip_addr::PATH, Some(nbr_addr.as_str())),
+        (base::status::PATH, Some("not-helping")),
+        (base::exit_reason::PATH, Some(reason.as_ref())),
+    ]);
+    notification::send(&instance.tx.nb, base::PATH, &args);
+}
+
+pub(crate) fn restart_status_change<V>(
+    instance: &InstanceUpView<'_, V>,
+    status: Option<GrRestartStatus>,
+    exit_reason: Option<GrExitReason>,
+) where
+    V: Version,
+{
+    use paths::restart_status_change as base;
+
+    let af = instance.state.af.to_yang();
+    let status = match status {
+        Some(status) => status.to_yang(),
+        None => "not-restarting".into(),
+    };
+    let restart_interval = instance.config.gr.restart_interval.to_string();
+    let exit_reason = match exit_reason {
+        Some(exit_reason) => exit_reason.to_yang(),
+        None => "in-progress".into(),
+    };
+
     let args = [
         (base::routing_protocol_name::PATH, Some(instance.name)),
         (base::address_family::PATH, Some(&af)),
-        (base::interface::interface::PATH, Some(&iface.name)),
-        (base::neighbor_router_id::PATH, Some(&nbr_router_id)),
-        (base::neighbor_ip_addr::PATH, Some(&nbr_addr)),
-        (base::status::PATH, Some("not-helping")),
-        (base::exit_reason::PATH, Some(&reason)),
+        (base::status::PATH, Some(&status)),
+        (base::restart_interval::PATH, Some(&restart_interval)),
+        (base::exit_reason::PATH, Some(&exit_reason)),
+    ];
+    notification::send(&instance.tx.nb, base::PATH, &args);
+}
+
+pub(crate) fn nssa_translator_status_change<V>(
+    instance: &InstanceUpView<'_, V>,
+    area: &Area<V>,
+) where
+    V: Version,
+{
+    use paths::nssa_translator_status_change as base;
+
+    let af = instance.state.af.to_yang();
+    let area_id = area.area_id.to_string();
+    let status = area.state.nssa_translator_state.to_yang();
+
+    let args = [
+        (base::routing_protocol_name::PATH, Some(instance.name)),
+        (base::address_family::PATH, Some(&af)),
+        (base::area_id::PATH, Some(&area_id)),
+        (base::status::PATH, Some(&status)),
+    ];
+    notification::send(&instance.tx.nb, base::PATH, &args);
+}
+
+pub(crate) fn lsdb_approaching_overflow<V>(
+    instance: &InstanceUpView<'_, V>,
+    ext_lsdb_limit: u32,
+) where
+    V: Version,
+{
+    use paths::lsdb_approaching_overflow as base;
+
+    let af = instance.state.af.to_yang();
+    let ext_lsdb_limit = ext_lsdb_limit.to_string();
+
+    let args = [
+        (base::routing_protocol_name::PATH, Some(instance.name)),
+        (base::address_family::PATH, Some(&af)),
+        (base::ext_lsdb_limit::PATH, Some(&ext_lsdb_limit)),
+    ];
+    notification::send(&instance.tx.nb, base::PATH, &args);
+}
+
+pub(crate) fn lsdb_overflow<V>(
+    instance: &InstanceUpView<'_, V>,
+    ext_lsdb_limit: u32,
+) where
+    V: Version,
+{
+    use paths::lsdb_overflow as base;
+
+    let af = instance.state.af.to_yang();
+    let ext_lsdb_limit = ext_lsdb_limit.to_string();
+
+    let args = [
+        (base::routing_protocol_name::PATH, Some(instance.name)),
+        (base::address_family::PATH, Some(&af)),
+        (base::ext_lsdb_limit::PATH, Some(&ext_lsdb_limit)),
     ];
     notification::send(&instance.tx.nb, base::PATH, &args);
 }
@@ -208,3 +381,19 @@ pub(crate) fn sr_index_out_of_range<V>(
     ];
     notification::send(&instance.tx.nb, base::PATH, &args);
 }
+
+// ===== helper functions =====
+
+// Returns the transit area ID and the endpoint's Router ID used to identify
+// virtual links in notifications.
+fn vlink_ids<V>(iface: &Interface<V>) -> Option<(String, String)>
+where
+    V: Version,
+{
+    iface.system.vlink.as_ref().map(|vlink| {
+        (
+            vlink.transit_area_id.to_string(),
+            vlink.router_id.to_string(),
+        )
+    })
+}
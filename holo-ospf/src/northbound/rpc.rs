@@ -7,12 +7,16 @@
 use std::sync::LazyLock as Lazy;
 
 use holo_northbound::paths;
+use holo_northbound::paths::control_plane_protocol::ospf;
 use holo_northbound::rpc::{Callbacks, CallbacksBuilder, Provider};
 use holo_utils::yang::DataNodeRefExt;
+use holo_yang::TryFromYang;
 use yang2::data::Data;
 
+use crate::gr;
 use crate::instance::{Instance, InstanceArenas, InstanceUpView};
 use crate::neighbor::nsm;
+use crate::packet::tlv::GrReason;
 use crate::version::{Ospfv2, Ospfv3, Version};
 
 pub static CALLBACKS_OSPFV2: Lazy<Callbacks<Instance<Ospfv2>>> =
@@ -54,6 +58,36 @@ where
                 Ok(())
             })
         })
+        .path(ospf::graceful_restart::planned_restart::PATH)
+        .rpc(|instance, args| {
+            Box::pin(async move {
+                let rpc = args.data.find_path(args.rpc_path).unwrap();
+
+                // Parse input parameters.
+                let reason = rpc
+                    .get_string_relative("./reason")
+                    .and_then(|reason| GrReason::try_from_yang(&reason))
+                    .unwrap_or(GrReason::SoftwareRestart);
+
+                // Prepare for the planned graceful restart.
+                let Some((mut instance, arenas)) = instance.as_up() else {
+                    return Err("OSPF instance is not active".to_string());
+                };
+                if !instance.config.gr.enabled {
+                    return Err("graceful restart is not enabled".to_string());
+                }
+                if instance.state.gr_restart.is_some()
+                    || instance.state.gr_restart_prepare.is_some()
+                {
+                    return Err(
+                        "graceful restart is already in progress".to_string()
+                    );
+                }
+                gr::restart_prepare(&mut instance, arenas, reason);
+
+                Ok(())
+            })
+        })
         .build()
 }
 
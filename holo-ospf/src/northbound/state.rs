@@ -13,12 +13,14 @@ use holo_northbound::paths::control_plane_protocol::ospf;
 use holo_northbound::state::{
     Callbacks, CallbacksBuilder, ListEntryKind, NodeAttributes, Provider,
 };
+use holo_utils::ibus::LdpSyncState;
+use holo_utils::ip::IpNetworkKind;
 use holo_utils::sr::IgpAlgoType;
 use holo_yang::{ToYang, ToYangBits};
 use itertools::Itertools;
 use num_traits::{FromPrimitive, ToPrimitive};
 
-use crate::area::Area;
+use crate::area::{Area, AreaType};
 use crate::collections::LsdbSingleType;
 use crate::instance::Instance;
 use crate::interface::{ism, Interface};
@@ -26,10 +28,11 @@ use crate::lsdb::{LsaEntry, LsaLogEntry, LsaLogId};
 use crate::neighbor::Neighbor;
 use crate::packet::lsa::{LsaBodyVersion, LsaHdrVersion};
 use crate::packet::tlv::{
-    GrReason, SidLabelRangeTlv, SrLocalBlockTlv, UnknownTlv,
+    GrReason, NodeAdminTagTlv, SidLabelRangeTlv, SrLocalBlockTlv, TeBandwidth,
+    UnknownTlv,
 };
-use crate::route::{Nexthop, RouteNet};
-use crate::spf::SpfLogEntry;
+use crate::route::{Nexthop, RouteNet, RouteNetFlags, RouteRepair};
+use crate::spf::{SpfLogEntry, SpfLogType};
 use crate::version::{Ospfv2, Ospfv3, Version};
 use crate::{ospfv2, ospfv3};
 
@@ -46,6 +49,8 @@ pub enum ListEntry<'a, V: Version> {
     LsaLog(&'a LsaLogEntry<V>),
     Route(&'a V::IpNetwork, &'a RouteNet<V>),
     Nexthop(&'a Nexthop<V::IpAddr>),
+    ProtectedRoute(&'a V::IpNetwork, &'a RouteRepair<V::IpAddr>),
+    UnprotectedRoute(&'a V::IpNetwork),
     StatsAsLsaType(&'a LsdbSingleType<V>),
     AsLsaType(&'a LsdbSingleType<V>),
     AsLsa(&'a LsaEntry<V>),
@@ -53,6 +58,8 @@ pub enum ListEntry<'a, V: Version> {
     AreaStatsLsaType(&'a LsdbSingleType<V>),
     AreaLsaType(&'a LsdbSingleType<V>),
     AreaLsa(&'a LsaEntry<V>),
+    VirtualLink(&'a Interface<V>),
+    VirtualLinkNeighbor(&'a Interface<V>, &'a Neighbor<V>),
     Interface(&'a Interface<V>),
     InterfaceStatsLsaType(&'a LsdbSingleType<V>),
     InterfaceLsaType(&'a LsdbSingleType<V>),
@@ -63,9 +70,13 @@ pub enum ListEntry<'a, V: Version> {
     SrAlgo(&'a IgpAlgoType),
     Srgb(&'a SidLabelRangeTlv),
     Srlb(&'a SrLocalBlockTlv),
+    NodeTagTlv(&'a NodeAdminTagTlv),
+    NodeTag(u32),
     UnknownTlv(&'a UnknownTlv),
     Flag(&'static str),
     FlagU32(u32),
+    TeIfAddr(&'a IpAddr),
+    TeUnrsvBw(u8, &'a TeBandwidth),
     // OSPFv2
     Ospfv2RouterLsaLink(&'a ospfv2::packet::lsa::LsaRouterLink),
     Ospfv2ExtPrefixTlv(&'a ospfv2::packet::lsa_opaque::ExtPrefixTlv),
@@ -221,6 +232,85 @@ where
         .get_element_u32(|instance, _args| {
             instance.state.as_ref().map(|state| state.lsdb.cksum_sum())
         })
+        .path(ospf::statistics::protected_routes::address_family_stats::PATH)
+        .get_iterate(|instance, _args| {
+            if let Some(instance_state) = &instance.state {
+                let iter = instance_state
+                    .rib
+                    .iter()
+                    .filter_map(|(destination, route)| {
+                        route.repair.as_ref().map(|repair| {
+                            ListEntry::ProtectedRoute(destination, repair)
+                        })
+                    });
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::statistics::protected_routes::address_family_stats::alternate_type::PATH)
+        .get_element_string(|_instance, args| {
+            let (_, repair) = args.list_entry.as_protected_route().unwrap();
+            Some(repair.repair_type.to_yang().into())
+        })
+        .path(ospf::statistics::protected_routes::address_family_stats::best::PATH)
+        .get_element_bool(|_instance, _args| {
+            Some(true)
+        })
+        .path(ospf::statistics::protected_routes::address_family_stats::protection_available::PATH)
+        .get_element_string(|_instance, args| {
+            let (_, repair) = args.list_entry.as_protected_route().unwrap();
+            let bits = match repair.node_protecting {
+                true => "node-protect link-protect",
+                false => "link-protect",
+            };
+            Some(bits.to_owned())
+        })
+        .path(ospf::statistics::protected_routes::address_family_stats::alternate_metric_1::PATH)
+        .get_element_u32(|_instance, args| {
+            let (_, repair) = args.list_entry.as_protected_route().unwrap();
+            Some(repair.metric)
+        })
+        .path(ospf::statistics::protected_routes::address_family_stats::alternate_metric_2::PATH)
+        .get_element_u32(|_instance, args| {
+            let (_, repair) = args.list_entry.as_protected_route().unwrap();
+            Some(repair.metric - repair.alt_metric)
+        })
+        .path(ospf::statistics::protected_routes::address_family_stats::alternate_metric_3::PATH)
+        .get_element_u32(|_instance, args| {
+            let (_, repair) = args.list_entry.as_protected_route().unwrap();
+            Some(repair.alt_metric)
+        })
+        .path(ospf::statistics::unprotected_routes::address_family_stats::PATH)
+        .get_iterate(|instance, _args| {
+            if let Some(instance_state) = &instance.state {
+                let interfaces = &instance.arenas.interfaces;
+                let iter = instance_state
+                    .rib
+                    .iter()
+                    .filter(|(_, route)| route.repair.is_none())
+                    // Only routes whose primary nexthop is subject to
+                    // protection are reported.
+                    .filter(|(_, route)| {
+                        route.nexthops.len() == 1
+                            && route.nexthops.values().all(|nexthop| {
+                                interfaces[nexthop.iface_idx]
+                                    .config
+                                    .frr
+                                    .lfa_enabled
+                            })
+                    })
+                    .filter(|(_, route)| {
+                        !route.flags.contains(RouteNetFlags::CONNECTED)
+                    })
+                    .map(|(destination, _)| {
+                        ListEntry::UnprotectedRoute(destination)
+                    });
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
         .path(ospf::statistics::database::as_scope_lsa_type::PATH)
         .get_iterate(|instance, _args| {
             if let Some(instance_state) = &instance.state {
@@ -302,6 +392,11 @@ where
             let log = args.list_entry.as_spf_log().unwrap();
             Some(log.spf_type.to_yang().into())
         })
+        .path(ospf::spf_log::event::incremental::PATH)
+        .get_element_bool(|_instance, args| {
+            let log = args.list_entry.as_spf_log().unwrap();
+            Some(matches!(log.spf_type, SpfLogType::Incremental))
+        })
         .path(ospf::spf_log::event::schedule_timestamp::PATH)
         .attributes(NodeAttributes::TIME)
         .get_element_timeticks(|_instance, args| {
@@ -404,6 +499,14 @@ where
             let iter = instance.arenas.areas.iter().map(ListEntry::Area);
             Some(Box::new(iter))
         })
+        .path(ospf::areas::area::nssa_translator_state::PATH)
+        .get_element_string(|_instance, args| {
+            let area = args.list_entry.as_area().unwrap();
+            if area.config.area_type != AreaType::Nssa {
+                return None;
+            }
+            Some(area.state.nssa_translator_state.to_yang().into())
+        })
         .path(ospf::areas::area::ranges::range::PATH)
         .get_iterate(|_instance, _args| {
             // No operational data under this list.
@@ -421,6 +524,12 @@ where
             let area = args.list_entry.as_area().unwrap();
             Some(area.state.spf_run_count)
         })
+        .path(ospf::areas::area::statistics::ar_nssa_translator_event_count::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u32(|_instance, args| {
+            let area = args.list_entry.as_area().unwrap();
+            Some(area.state.nssa_translator_event_count)
+        })
         .path(ospf::areas::area::statistics::abr_count::PATH)
         .get_element_u32(|_instance, args| {
             let area = args.list_entry.as_area().unwrap();
@@ -502,12 +611,113 @@ where
                 lsa.raw.iter().map(|byte| format!("{:02x}", byte)).join(":");
             Some(bytes)
         })
+        .path(ospf::areas::area::virtual_links::virtual_link::PATH)
+        .get_iterate(|instance, args| {
+            let area = args.parent_list_entry.as_area().unwrap();
+            let iter = area
+                .interfaces
+                .iter(&instance.arenas.interfaces)
+                .filter(|iface| iface.is_virtual_link())
+                .map(ListEntry::VirtualLink);
+            Some(Box::new(iter))
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::cost::PATH)
+        .get_element_u16(|_instance, args| {
+            let iface = args.list_entry.as_virtual_link().unwrap();
+            iface.vlink_endpoint().map(|endpoint| endpoint.cost)
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::state::PATH)
+        .get_element_string(|_instance, args| {
+            let iface = args.list_entry.as_virtual_link().unwrap();
+            Some(iface.state.ism_state.to_yang().into())
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::hello_timer::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_timervalue_secs16(|_instance, args| {
+            let iface = args.list_entry.as_virtual_link().unwrap();
+            iface
+                .state
+                .tasks
+                .hello_interval
+                .as_ref()
+                .map(|task| task.remaining())
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::statistics::discontinuity_time::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_date_and_time(|_instance, args| {
+            let iface = args.list_entry.as_virtual_link().unwrap();
+            Some(iface.state.discontinuity_time)
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::statistics::if_event_count::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u32(|_instance, args| {
+            let iface = args.list_entry.as_virtual_link().unwrap();
+            Some(iface.state.event_count)
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::statistics::link_scope_lsa_count::PATH)
+        .get_element_u32(|_instance, args| {
+            let iface = args.list_entry.as_virtual_link().unwrap();
+            Some(iface.state.lsdb.lsa_count())
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::statistics::link_scope_lsa_cksum_sum::PATH)
+        .attributes(NodeAttributes::LS_CKSUM)
+        .get_element_u32(|_instance, args| {
+            let iface = args.list_entry.as_virtual_link().unwrap();
+            Some(iface.state.lsdb.cksum_sum())
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::neighbors::neighbor::PATH)
+        .get_iterate(|instance, args| {
+            let iface = args.parent_list_entry.as_virtual_link().unwrap();
+            let iter = iface
+                .state
+                .neighbors
+                .iter(&instance.arenas.neighbors)
+                .map(|nbr| ListEntry::VirtualLinkNeighbor(iface, nbr));
+            Some(Box::new(iter))
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::neighbors::neighbor::address::PATH)
+        .get_element_ip(|_instance, args| {
+            let (_, nbr) = args.list_entry.as_virtual_link_neighbor().unwrap();
+            Some(nbr.src.into())
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::neighbors::neighbor::state::PATH)
+        .get_element_string(|_instance, args| {
+            let (_, nbr) = args.list_entry.as_virtual_link_neighbor().unwrap();
+            Some(nbr.state.to_yang().into())
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::neighbors::neighbor::dead_timer::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_timervalue_secs16(|_instance, args| {
+            let (_, nbr) = args.list_entry.as_virtual_link_neighbor().unwrap();
+            nbr.tasks
+                .inactivity_timer
+                .as_ref()
+                .map(|task| task.remaining())
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::neighbors::neighbor::statistics::discontinuity_time::PATH)
+        .attributes(NodeAttributes::TIME)
+        .get_element_date_and_time(|_instance, args| {
+            let (_, nbr) = args.list_entry.as_virtual_link_neighbor().unwrap();
+            Some(nbr.discontinuity_time)
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::neighbors::neighbor::statistics::nbr_event_count::PATH)
+        .attributes(NodeAttributes::COUNTER)
+        .get_element_u32(|_instance, args| {
+            let (_, nbr) = args.list_entry.as_virtual_link_neighbor().unwrap();
+            Some(nbr.event_count)
+        })
+        .path(ospf::areas::area::virtual_links::virtual_link::neighbors::neighbor::statistics::nbr_retrans_qlen::PATH)
+        .get_element_u32(|_instance, args| {
+            let (_, nbr) = args.list_entry.as_virtual_link_neighbor().unwrap();
+            Some(nbr.lists.ls_rxmt.len() as u32)
+        })
         .path(ospf::areas::area::interfaces::interface::PATH)
         .get_iterate(|instance, args| {
             let area = args.parent_list_entry.as_area().unwrap();
             let iter = area
                 .interfaces
                 .iter(&instance.arenas.interfaces)
+                .filter(|iface| !iface.is_virtual_link())
                 .map(ListEntry::Interface);
             Some(Box::new(iter))
         })
@@ -614,6 +824,22 @@ where
                 None
             }
         })
+        .path(ospf::areas::area::interfaces::interface::ldp_igp_sync::state::PATH)
+        .get_element_string(|_instance, args| {
+            let iface = args.list_entry.as_interface().unwrap();
+            let state = match iface.state.ldp_sync? {
+                LdpSyncState::Disabled => "ldp-not-running",
+                LdpSyncState::NotSynced => {
+                    if iface.state.ldp_sync_holddown_expired {
+                        "holddown-expired"
+                    } else {
+                        "not-synced"
+                    }
+                }
+                LdpSyncState::Synced => "synced",
+            };
+            Some(state.to_owned())
+        })
         .path(ospf::areas::area::interfaces::interface::statistics::discontinuity_time::PATH)
         .attributes(NodeAttributes::TIME)
         .get_element_date_and_time(|_instance, args| {
@@ -810,6 +1036,23 @@ where
             let (_, nbr) = args.list_entry.as_neighbor().unwrap();
             Some(nbr.lists.ls_rxmt.len() as u32)
         })
+        .path(ospf::areas::area::interfaces::interface::neighbors::neighbor::hostname::PATH)
+        .get_element_string(|instance, args| {
+            let (_, nbr) = args.list_entry.as_neighbor().unwrap();
+            instance
+                .arenas
+                .areas
+                .iter()
+                .find_map(|area| {
+                    V::area_router_information(
+                        &area.state.lsdb,
+                        nbr.router_id,
+                        &instance.arenas.lsa_entries,
+                    )
+                    .hostname
+                })
+                .map(|tlv| tlv.get().to_owned())
+        })
         .path(ospf::areas::area::interfaces::interface::neighbors::neighbor::graceful_restart::restart_reason::PATH)
         .get_element_string(|_instance, args| {
             let (_, nbr) = args.list_entry.as_neighbor().unwrap();
@@ -1246,6 +1489,44 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
                 None
             }
         })
+        .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_as_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_as()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    let iter = lsa_body.node_tags
+                        .iter()
+                        .map(ListEntry::NodeTagTlv);
+                    Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::node_tag::PATH)
+        .get_iterate(|_instance, args| {
+            let tlv = args.parent_list_entry.as_node_tag_tlv().unwrap();
+            let iter = tlv.get().iter().copied().map(ListEntry::NodeTag);
+            Some(Box::new(iter))
+        })
+        .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::node_tag::tag::PATH)
+        .get_element_u32(|_instance, args| {
+            let tag = args.list_entry.as_node_tag().unwrap();
+            Some(*tag)
+        })
+        .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv2::body::opaque::ri_opaque::dynamic_hostname_tlv::hostname::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_as_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_as()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    lsa_body.hostname.as_ref().map(|tlv| tlv.get().to_owned())
+            } else {
+                None
+            }
+        })
         .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv2::body::opaque::extended_prefix_opaque::extended_prefix_tlv::PATH)
         .get_iterate(|_instance, args| {
             let lse: &LsaEntry<Ospfv2> =
@@ -1591,19 +1872,56 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
             Some(lsa_body.metric)
         })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::network_mask::PATH)
-        .get_element_string(|_instance, _args| None)
+        .get_element_ipv4(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body.as_nssa_external().map(|lsa_body| lsa_body.mask)
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::topologies::topology::PATH)
-        .get_iterate(|_instance, _args| None)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(_lsa_body) = lsa.body.as_nssa_external() {
+                let iter = std::iter::once(lse).map(ListEntry::AreaLsa);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::topologies::topology::mt_id::PATH)
-        .get_element_u8(|_instance, _args| None)
+        .get_element_u8(|_instance, _args| {
+            // TOS-based routing is deprecated.
+            Some(0)
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::topologies::topology::flags::PATH)
-        .get_element_string(|_instance, _args| None)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            let lsa_body = lsa.body.as_nssa_external().unwrap();
+            Some(lsa_body.flags.to_yang().into())
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::topologies::topology::metric::PATH)
-        .get_element_u32(|_instance, _args| None)
+        .get_element_u32(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            let lsa_body = lsa.body.as_nssa_external().unwrap();
+            Some(lsa_body.metric)
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::topologies::topology::forwarding_address::PATH)
-        .get_element_string(|_instance, _args| None)
+        .get_element_ipv4(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            let lsa_body = lsa.body.as_nssa_external().unwrap();
+            lsa_body.fwd_addr
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::external::topologies::topology::external_route_tag::PATH)
-        .get_element_u32(|_instance, _args| None)
+        .get_element_u32(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            let lsa_body = lsa.body.as_nssa_external().unwrap();
+            Some(lsa_body.tag)
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::router_capabilities_tlv::router_informational_capabilities::informational_capabilities::PATH)
         .get_iterate(|_instance, args| {
             let lse: &LsaEntry<Ospfv2> =
@@ -1737,82 +2055,318 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
                 args.parent_list_entry.as_area_lsa().unwrap();
             let lsa = &lse.data;
             if let Some(lsa_body) = lsa.body.as_opaque_area()
-                && let Some(lsa_body) = lsa_body.as_router_info() {
-                    let iter = lsa_body.sr_algo
-                        .iter()
-                        .flat_map(|tlv| tlv.get().iter())
-                        .map(ListEntry::SrAlgo);
-                    Some(Box::new(iter))
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    let iter = lsa_body.sr_algo
+                        .iter()
+                        .flat_map(|tlv| tlv.get().iter())
+                        .map(ListEntry::SrAlgo);
+                    Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .get_element_string(|_instance, args| {
+            let algo = args.list_entry.as_sr_algo().unwrap();
+            Some(algo.to_yang().into())
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::sid_range_tlvs::sid_range_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    let iter = lsa_body.srgb
+                        .iter()
+                        .map(ListEntry::Srgb);
+                    Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::sid_range_tlvs::sid_range_tlv::range_size::PATH)
+        .get_element_u32(|_instance, args| {
+            let srgb = args.list_entry.as_srgb().unwrap();
+            Some(srgb.range)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::sid_range_tlvs::sid_range_tlv::sid_sub_tlv::sid::PATH)
+        .get_element_u32(|_instance, args| {
+            let srgb = args.list_entry.as_srgb().unwrap();
+            Some(srgb.first.value())
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::local_block_tlvs::local_block_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    let iter = lsa_body.srlb
+                        .iter()
+                        .map(ListEntry::Srlb);
+                    Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::local_block_tlvs::local_block_tlv::range_size::PATH)
+        .get_element_u32(|_instance, args| {
+            let srlb = args.list_entry.as_srlb().unwrap();
+            Some(srlb.range)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::local_block_tlvs::local_block_tlv::sid_sub_tlv::sid::PATH)
+        .get_element_u32(|_instance, args| {
+            let srlb = args.list_entry.as_srlb().unwrap();
+            Some(srlb.first.value())
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::srms_preference_tlv::preference::PATH)
+        .get_element_u8(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    lsa_body.srms_pref.as_ref().map(|tlv| tlv.get())
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    let iter = lsa_body.node_tags
+                        .iter()
+                        .map(ListEntry::NodeTagTlv);
+                    Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::node_tag::PATH)
+        .get_iterate(|_instance, args| {
+            let tlv = args.parent_list_entry.as_node_tag_tlv().unwrap();
+            let iter = tlv.get().iter().copied().map(ListEntry::NodeTag);
+            Some(Box::new(iter))
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::node_tag::tag::PATH)
+        .get_element_u32(|_instance, args| {
+            let tag = args.list_entry.as_node_tag().unwrap();
+            Some(*tag)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::dynamic_hostname_tlv::hostname::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    lsa_body.hostname.as_ref().map(|tlv| tlv.get().to_owned())
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::router_address_tlv::router_address::PATH)
+        .get_element_ipv4(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body
+                .as_opaque_area()
+                .and_then(|lsa_body| lsa_body.as_te())
+                .and_then(|lsa_body| lsa_body.router_addr)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::link_type::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                    tlv.link_type.map(|link_type| link_type.to_yang().into())
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::link_id::PATH)
+        .get_element_ipv4(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                    tlv.link_id
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::local_if_ipv4_addrs::local_if_ipv4_addr::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                let iter = tlv
+                    .local_addrs
+                    .iter()
+                    .map(ListEntry::TeIfAddr);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .get_element_ipv4(|_instance, args| {
+            let addr = args.list_entry.as_te_if_addr().unwrap();
+            match addr {
+                IpAddr::V4(addr) => Some(*addr),
+                IpAddr::V6(_) => None,
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::remote_if_ipv4_addrs::remote_if_ipv4_addr::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                let iter = tlv
+                    .remote_addrs
+                    .iter()
+                    .map(ListEntry::TeIfAddr);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .get_element_ipv4(|_instance, args| {
+            let addr = args.list_entry.as_te_if_addr().unwrap();
+            match addr {
+                IpAddr::V4(addr) => Some(*addr),
+                IpAddr::V6(_) => None,
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::te_metric::PATH)
+        .get_element_u32(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                    tlv.te_metric
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::max_bandwidth::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                    tlv.max_bw.map(|bw| bw.to_yang().into())
             } else {
                 None
             }
         })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::max_reservable_bandwidth::PATH)
         .get_element_string(|_instance, args| {
-            let algo = args.list_entry.as_sr_algo().unwrap();
-            Some(algo.to_yang().into())
-        })
-        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::sid_range_tlvs::sid_range_tlv::PATH)
-        .get_iterate(|_instance, args| {
             let lse: &LsaEntry<Ospfv2> =
-                args.parent_list_entry.as_area_lsa().unwrap();
+                args.list_entry.as_area_lsa().unwrap();
             let lsa = &lse.data;
             if let Some(lsa_body) = lsa.body.as_opaque_area()
-                && let Some(lsa_body) = lsa_body.as_router_info() {
-                    let iter = lsa_body.srgb
-                        .iter()
-                        .map(ListEntry::Srgb);
-                    Some(Box::new(iter))
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                    tlv.max_rsv_bw.map(|bw| bw.to_yang().into())
             } else {
                 None
             }
         })
-        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::sid_range_tlvs::sid_range_tlv::range_size::PATH)
-        .get_element_u32(|_instance, args| {
-            let srgb = args.list_entry.as_srgb().unwrap();
-            Some(srgb.range)
-        })
-        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::sid_range_tlvs::sid_range_tlv::sid_sub_tlv::sid::PATH)
-        .get_element_u32(|_instance, args| {
-            let srgb = args.list_entry.as_srgb().unwrap();
-            Some(srgb.first.value())
-        })
-        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::local_block_tlvs::local_block_tlv::PATH)
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::unreserved_bandwidths::unreserved_bandwidth::PATH)
         .get_iterate(|_instance, args| {
             let lse: &LsaEntry<Ospfv2> =
                 args.parent_list_entry.as_area_lsa().unwrap();
             let lsa = &lse.data;
             if let Some(lsa_body) = lsa.body.as_opaque_area()
-                && let Some(lsa_body) = lsa_body.as_router_info() {
-                    let iter = lsa_body.srlb
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link
+                && let Some(unrsv_bw) = &tlv.unrsv_bw {
+                    let iter = unrsv_bw
                         .iter()
-                        .map(ListEntry::Srlb);
+                        .enumerate()
+                        .map(|(priority, bw)| ListEntry::TeUnrsvBw(priority as u8, bw));
                     Some(Box::new(iter))
             } else {
                 None
             }
         })
-        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::local_block_tlvs::local_block_tlv::range_size::PATH)
-        .get_element_u32(|_instance, args| {
-            let srlb = args.list_entry.as_srlb().unwrap();
-            Some(srlb.range)
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::unreserved_bandwidths::unreserved_bandwidth::priority::PATH)
+        .get_element_u8(|_instance, args| {
+            let (priority, _) = args.list_entry.as_te_unrsv_bw().unwrap();
+            Some(*priority)
         })
-        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::local_block_tlvs::local_block_tlv::sid_sub_tlv::sid::PATH)
-        .get_element_u32(|_instance, args| {
-            let srlb = args.list_entry.as_srlb().unwrap();
-            Some(srlb.first.value())
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::unreserved_bandwidths::unreserved_bandwidth::unreserved_bandwidth::PATH)
+        .get_element_string(|_instance, args| {
+            let (_, bw) = args.list_entry.as_te_unrsv_bw().unwrap();
+            Some(bw.to_yang().into())
         })
-        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::ri_opaque::srms_preference_tlv::preference::PATH)
-        .get_element_u8(|_instance, args| {
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::admin_group::PATH)
+        .get_element_u32(|_instance, args| {
             let lse: &LsaEntry<Ospfv2> =
                 args.list_entry.as_area_lsa().unwrap();
             let lsa = &lse.data;
             if let Some(lsa_body) = lsa.body.as_opaque_area()
-                && let Some(lsa_body) = lsa_body.as_router_info() {
-                    lsa_body.srms_pref.as_ref().map(|tlv| tlv.get())
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                    tlv.admin_group
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::unknown_tlvs::unknown_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_area()
+                && let Some(lsa_body) = lsa_body.as_te()
+                && let Some(tlv) = &lsa_body.link {
+                let iter = tlv
+                    .unknown_stlvs
+                    .iter()
+                    .map(ListEntry::UnknownTlv);
+                Some(Box::new(iter))
             } else {
                 None
             }
         })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::unknown_tlvs::unknown_tlv::r#type::PATH)
+        .get_element_u16(|_instance, args| {
+            let tlv = args.list_entry.as_unknown_tlv().unwrap();
+            Some(tlv.tlv_type)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::unknown_tlvs::unknown_tlv::length::PATH)
+        .get_element_u16(|_instance, args| {
+            let tlv = args.list_entry.as_unknown_tlv().unwrap();
+            Some(tlv.length)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::te_opaque::link_tlv::unknown_tlvs::unknown_tlv::value::PATH)
+        .get_element_string(|_instance, args| {
+            let tlv = args.list_entry.as_unknown_tlv().unwrap();
+            let bytes =
+                tlv.value.iter().map(|byte| format!("{:02x}", byte)).join(":");
+            Some(bytes)
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv2::body::opaque::extended_prefix_opaque::extended_prefix_tlv::PATH)
         .get_iterate(|_instance, args| {
             let lse: &LsaEntry<Ospfv2> =
@@ -2451,6 +3005,44 @@ fn load_callbacks_ospfv2() -> Callbacks<Instance<Ospfv2>> {
                 None
             }
         })
+        .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.parent_list_entry.as_interface_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_link()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    let iter = lsa_body.node_tags
+                        .iter()
+                        .map(ListEntry::NodeTagTlv);
+                    Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::node_tag::PATH)
+        .get_iterate(|_instance, args| {
+            let tlv = args.parent_list_entry.as_node_tag_tlv().unwrap();
+            let iter = tlv.get().iter().copied().map(ListEntry::NodeTag);
+            Some(Box::new(iter))
+        })
+        .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv2::body::opaque::ri_opaque::node_tag_tlvs::node_tag_tlv::node_tag::tag::PATH)
+        .get_element_u32(|_instance, args| {
+            let tag = args.list_entry.as_node_tag().unwrap();
+            Some(*tag)
+        })
+        .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv2::body::opaque::ri_opaque::dynamic_hostname_tlv::hostname::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv2> =
+                args.list_entry.as_interface_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_opaque_link()
+                && let Some(lsa_body) = lsa_body.as_router_info() {
+                    lsa_body.hostname.as_ref().map(|tlv| tlv.get().to_owned())
+            } else {
+                None
+            }
+        })
         .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv2::body::opaque::extended_prefix_opaque::extended_prefix_tlv::PATH)
         .get_iterate(|_instance, _args| {
             None
@@ -2942,6 +3534,41 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
                 None
             }
         })
+        .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv3> =
+                args.parent_list_entry.as_as_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_router_info() {
+                let iter =
+                    lsa_body.node_tags.iter().map(ListEntry::NodeTagTlv);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::node_tag::PATH)
+        .get_iterate(|_instance, args| {
+            let tlv = args.parent_list_entry.as_node_tag_tlv().unwrap();
+            let iter = tlv.get().iter().copied().map(ListEntry::NodeTag);
+            Some(Box::new(iter))
+        })
+        .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::node_tag::tag::PATH)
+        .get_element_u32(|_instance, args| {
+            let tag = args.list_entry.as_node_tag().unwrap();
+            Some(*tag)
+        })
+        .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv3::body::router_information::dynamic_hostname_tlv::hostname::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv3> =
+                args.list_entry.as_as_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_router_info() {
+                lsa_body.hostname.as_ref().map(|tlv| tlv.get().to_owned())
+            } else {
+                None
+            }
+        })
         .path(ospf::database::as_scope_lsa_type::as_scope_lsas::as_scope_lsa::ospfv3::body::e_as_external::e_external_tlvs::PATH)
         .get_iterate(|_instance, args| {
             let lse: &LsaEntry<Ospfv3> = args.parent_list_entry.as_as_lsa().unwrap();
@@ -3315,6 +3942,89 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
         .get_element_u32(|_instance, _args| None)
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::as_external::referenced_link_state_id::PATH)
         .get_element_u32(|_instance, _args| None)
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::metric::PATH)
+        .get_element_u32(|_instance, args| {
+            let lse = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body.as_std_nssa().map(|lsa_body| lsa_body.metric)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::flags::PATH)
+        .get_element_string(|_instance, args| {
+            let lse = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body
+                .as_std_nssa()
+                .map(|lsa_body| lsa_body.flags.to_yang().into())
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::referenced_ls_type::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv3> = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body.as_std_nssa().and_then(|lsa_body| {
+                lsa_body.ref_lsa_type.map(|lsa_type| lsa_type.to_yang().into())
+            })
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::unknown_referenced_ls_type::PATH)
+        .get_element_u16(|_instance, args| {
+            let lse: &LsaEntry<Ospfv3> = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body.as_std_nssa().and_then(|lsa_body| {
+                lsa_body.ref_lsa_type.and_then(|ref_lsa_type| {
+                    if ref_lsa_type.function_code().is_none() {
+                        Some(ref_lsa_type.0)
+                    } else {
+                        None
+                    }
+                })
+            })
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::prefix::PATH)
+        .get_element_prefix(|_instance, args| {
+            let lse = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body.as_std_nssa().map(|lsa_body| lsa_body.prefix)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::prefix_options::prefix_options::PATH)
+        .get_iterate(|_instance, args| {
+            let lse = args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_std_nssa() {
+                let options = lsa_body.prefix_options.to_yang_bits();
+                let iter = options.into_iter().map(ListEntry::Flag);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .get_element_string(|_instance, args| {
+            let flag = args.list_entry.as_flag().unwrap();
+            Some(flag.to_string())
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::forwarding_address::PATH)
+        .get_element_ipv6(|_instance, args| {
+            let lse = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body.as_std_nssa().and_then(|lsa_body| {
+                lsa_body.fwd_addr.map(|addr| match addr {
+                    IpAddr::V4(addr) => addr.to_ipv6_mapped(),
+                    IpAddr::V6(addr) => addr,
+                })
+            })
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::external_route_tag::PATH)
+        .get_element_u32(|_instance, args| {
+            let lse = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body.as_std_nssa().and_then(|lsa_body| lsa_body.tag)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::nssa::referenced_link_state_id::PATH)
+        .get_element_u32(|_instance, args| {
+            let lse = args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            lsa.body.as_std_nssa().and_then(|lsa_body| {
+                lsa_body.ref_lsa_id.map(|lsa_id| lsa_id.into())
+            })
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::link::rtr_priority::PATH)
         .get_element_u8(|_instance, _args| None)
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::link::lsa_options::lsa_options::PATH)
@@ -3555,6 +4265,41 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
                 None
             }
         })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv3> =
+                args.parent_list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_router_info() {
+                let iter =
+                    lsa_body.node_tags.iter().map(ListEntry::NodeTagTlv);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::node_tag::PATH)
+        .get_iterate(|_instance, args| {
+            let tlv = args.parent_list_entry.as_node_tag_tlv().unwrap();
+            let iter = tlv.get().iter().copied().map(ListEntry::NodeTag);
+            Some(Box::new(iter))
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::node_tag::tag::PATH)
+        .get_element_u32(|_instance, args| {
+            let tag = args.list_entry.as_node_tag().unwrap();
+            Some(*tag)
+        })
+        .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::router_information::dynamic_hostname_tlv::hostname::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv3> =
+                args.list_entry.as_area_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_router_info() {
+                lsa_body.hostname.as_ref().map(|tlv| tlv.get().to_owned())
+            } else {
+                None
+            }
+        })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_router::router_bits::rtr_lsa_bits::PATH)
         .get_iterate(|_instance, args| {
             let lse: &LsaEntry<Ospfv3> =
@@ -3911,7 +4656,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_inter_area_prefix::e_inter_prefix_tlvs::inter_prefix_tlv::prefix_sid_sub_tlvs::prefix_sid_sub_tlv::algorithm::PATH)
         .get_element_u8(|_instance, args| {
             let prefix_sid = args.list_entry.as_ospfv3_prefix_sid().unwrap();
-            Some(prefix_sid.algo as u8)
+            prefix_sid.algo.to_u8()
         })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_inter_area_prefix::e_inter_prefix_tlvs::inter_prefix_tlv::prefix_sid_sub_tlvs::prefix_sid_sub_tlv::sid::PATH)
         .get_element_u32(|_instance, args| {
@@ -4141,7 +4886,7 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_intra_area_prefix::e_intra_prefix_tlvs::intra_prefix_tlv::prefix_sid_sub_tlvs::prefix_sid_sub_tlv::algorithm::PATH)
         .get_element_u8(|_instance, args| {
             let prefix_sid = args.list_entry.as_ospfv3_prefix_sid().unwrap();
-            Some(prefix_sid.algo as u8)
+            prefix_sid.algo.to_u8()
         })
         .path(ospf::areas::area::database::area_scope_lsa_type::area_scope_lsas::area_scope_lsa::ospfv3::body::e_intra_area_prefix::e_intra_prefix_tlvs::intra_prefix_tlv::prefix_sid_sub_tlvs::prefix_sid_sub_tlv::sid::PATH)
         .get_element_u32(|_instance, args| {
@@ -4505,6 +5250,41 @@ fn load_callbacks_ospfv3() -> Callbacks<Instance<Ospfv3>> {
                 None
             }
         })
+        .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::PATH)
+        .get_iterate(|_instance, args| {
+            let lse: &LsaEntry<Ospfv3> =
+                args.parent_list_entry.as_interface_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_router_info() {
+                let iter =
+                    lsa_body.node_tags.iter().map(ListEntry::NodeTagTlv);
+                Some(Box::new(iter))
+            } else {
+                None
+            }
+        })
+        .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::node_tag::PATH)
+        .get_iterate(|_instance, args| {
+            let tlv = args.parent_list_entry.as_node_tag_tlv().unwrap();
+            let iter = tlv.get().iter().copied().map(ListEntry::NodeTag);
+            Some(Box::new(iter))
+        })
+        .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv3::body::router_information::node_tag_tlvs::node_tag_tlv::node_tag::tag::PATH)
+        .get_element_u32(|_instance, args| {
+            let tag = args.list_entry.as_node_tag().unwrap();
+            Some(*tag)
+        })
+        .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv3::body::router_information::dynamic_hostname_tlv::hostname::PATH)
+        .get_element_string(|_instance, args| {
+            let lse: &LsaEntry<Ospfv3> =
+                args.list_entry.as_interface_lsa().unwrap();
+            let lsa = &lse.data;
+            if let Some(lsa_body) = lsa.body.as_router_info() {
+                lsa_body.hostname.as_ref().map(|tlv| tlv.get().to_owned())
+            } else {
+                None
+            }
+        })
         .path(ospf::areas::area::interfaces::interface::database::link_scope_lsa_type::link_scope_lsas::link_scope_lsa::ospfv3::body::e_link::rtr_priority::PATH)
         .get_element_u8(|_instance, args| {
             let lse: &LsaEntry<Ospfv3> =
@@ -4782,6 +5562,25 @@ where
                 // Keyless list.
                 None
             }
+            ListEntry::ProtectedRoute(destination, repair) => {
+                use ospf::statistics::protected_routes::address_family_stats::list_keys;
+                let alternate: Option<IpAddr> =
+                    repair.nexthop.addr.map(std::convert::Into::into);
+                let keys = list_keys(
+                    destination.address_family().to_yang(),
+                    destination,
+                    alternate.unwrap(),
+                );
+                Some(keys)
+            }
+            ListEntry::UnprotectedRoute(destination) => {
+                use ospf::statistics::unprotected_routes::address_family_stats::list_keys;
+                let keys = list_keys(
+                    destination.address_family().to_yang(),
+                    destination,
+                );
+                Some(keys)
+            }
             ListEntry::StatsAsLsaType(_) => {
                 // Keyless list.
                 None
@@ -4817,6 +5616,17 @@ where
                 let keys = list_keys(lsa.hdr.lsa_id(), lsa.hdr.adv_rtr());
                 Some(keys)
             }
+            ListEntry::VirtualLink(iface) => {
+                use ospf::areas::area::virtual_links::virtual_link::list_keys;
+                let vlink = iface.system.vlink.as_ref().unwrap();
+                let keys = list_keys(vlink.transit_area_id, vlink.router_id);
+                Some(keys)
+            }
+            ListEntry::VirtualLinkNeighbor(_, nbr) => {
+                use ospf::areas::area::virtual_links::virtual_link::neighbors::neighbor::list_keys;
+                let keys = list_keys(nbr.router_id);
+                Some(keys)
+            }
             ListEntry::Interface(iface) => {
                 use ospf::areas::area::interfaces::interface::list_keys;
                 let keys = list_keys(&iface.name);
@@ -4846,9 +5656,13 @@ where
             | ListEntry::SrAlgo(..)
             | ListEntry::Srgb(..)
             | ListEntry::Srlb(..)
+            | ListEntry::NodeTagTlv(..)
+            | ListEntry::NodeTag(..)
             | ListEntry::UnknownTlv(..)
             | ListEntry::Flag(..)
             | ListEntry::FlagU32(..)
+            | ListEntry::TeIfAddr(..)
+            | ListEntry::TeUnrsvBw(..)
             | ListEntry::NetworkLsaAttachedRtr(..)
             | ListEntry::Ospfv2RouterLsaLink(..)
             | ListEntry::Ospfv2ExtPrefixTlv(..)
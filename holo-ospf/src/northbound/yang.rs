@@ -11,15 +11,18 @@ use holo_yang::{ToYang, ToYangBits, TryFromYang};
 
 use crate::area::AreaType;
 use crate::error::InterfaceCfgError;
-use crate::gr::GrExitReason;
+use crate::gr::{GrExitReason, GrRestartStatus};
 use crate::interface::{ism, InterfaceType};
 use crate::lsdb::LsaLogReason;
 use crate::neighbor::nsm;
+use crate::nssa::{NssaTranslatorRole, NssaTranslatorState};
 use crate::packet::error::LsaValidationError;
 use crate::packet::tlv::{
-    AdjSidFlags, GrReason, PrefixSidFlags, RouterInfoCaps,
+    AdjSidFlags, FlexAlgoMetricType, GrReason, PrefixSidFlags, RouterInfoCaps,
+    TeBandwidth, TeLinkType,
 };
 use crate::packet::PacketType;
+use crate::route::RepairType;
 use crate::spf::SpfLogType;
 use crate::{ospfv2, ospfv3, spf};
 
@@ -89,7 +92,8 @@ impl ToYang for LsaLogReason {
 impl ToYang for SpfLogType {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
-            SpfLogType::Full => "full".into(),
+            // Incremental runs also recompute all routes.
+            SpfLogType::Full | SpfLogType::Incremental => "full".into(),
             SpfLogType::Intra => "intra".into(),
             SpfLogType::Inter => "inter".into(),
             SpfLogType::External => "external".into(),
@@ -97,6 +101,26 @@ impl ToYang for SpfLogType {
     }
 }
 
+impl ToYang for RepairType {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            RepairType::Lfa => "lfa".into(),
+            RepairType::RemoteLfa => "remote-lfa".into(),
+            RepairType::TiLfa => "ti-lfa".into(),
+        }
+    }
+}
+
+impl ToYang for NssaTranslatorState {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            NssaTranslatorState::Enabled => "enabled".into(),
+            NssaTranslatorState::Elected => "elected".into(),
+            NssaTranslatorState::Disabled => "disabled".into(),
+        }
+    }
+}
+
 impl ToYang for InterfaceCfgError {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
@@ -111,7 +135,8 @@ impl ToYang for InterfaceCfgError {
             InterfaceCfgError::DeadIntervalMismatch(..) => {
                 "dead-interval-mismatch".into()
             }
-            InterfaceCfgError::ExternalRoutingCapabilityMismatch(..) => {
+            InterfaceCfgError::ExternalRoutingCapabilityMismatch(..)
+            | InterfaceCfgError::NssaCapabilityMismatch(..) => {
                 "option-mismatch".into()
             }
             InterfaceCfgError::MtuMismatch(..) => "mtu-mismatch".into(),
@@ -166,19 +191,19 @@ impl ToYangBits for PrefixSidFlags {
         let mut flags = vec![];
 
         if self.contains(PrefixSidFlags::NP) {
-            flags.push("ietf-ospf-sr-mpls:np-bit");
+            flags.push("ietf-ospf-sr-mpls:np-flag");
         }
         if self.contains(PrefixSidFlags::M) {
-            flags.push("ietf-ospf-sr-mpls:m-bit");
+            flags.push("ietf-ospf-sr-mpls:m-flag");
         }
         if self.contains(PrefixSidFlags::E) {
-            flags.push("ietf-ospf-sr-mpls:e-bit");
+            flags.push("ietf-ospf-sr-mpls:e-flag");
         }
         if self.contains(PrefixSidFlags::V) {
-            flags.push("ietf-ospf-sr-mpls:v-bit");
+            flags.push("ietf-ospf-sr-mpls:v-flag");
         }
         if self.contains(PrefixSidFlags::L) {
-            flags.push("ietf-ospf-sr-mpls:l-bit");
+            flags.push("ietf-ospf-sr-mpls:l-flag");
         }
 
         flags
@@ -190,19 +215,19 @@ impl ToYangBits for AdjSidFlags {
         let mut flags = vec![];
 
         if self.contains(AdjSidFlags::B) {
-            flags.push("ietf-ospf-sr-mpls:b-bit");
+            flags.push("ietf-ospf-sr-mpls:b-flag");
         }
         if self.contains(AdjSidFlags::V) {
-            flags.push("ietf-ospf-sr-mpls:vi-bit");
+            flags.push("ietf-ospf-sr-mpls:vi-flag");
         }
         if self.contains(AdjSidFlags::L) {
-            flags.push("ietf-ospf-sr-mpls:lo-bit");
+            flags.push("ietf-ospf-sr-mpls:lo-flag");
         }
         if self.contains(AdjSidFlags::G) {
-            flags.push("ietf-ospf-sr-mpls:g-bit");
+            flags.push("ietf-ospf-sr-mpls:g-flag");
         }
         if self.contains(AdjSidFlags::P) {
-            flags.push("ietf-ospf-sr-mpls:p-bit");
+            flags.push("ietf-ospf-sr-mpls:p-flag");
         }
 
         flags
@@ -222,6 +247,30 @@ impl ToYang for GrReason {
     }
 }
 
+impl ToYang for TeLinkType {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            TeLinkType::PointToPoint => "point-to-point-link".into(),
+            TeLinkType::MultiAccess => "transit-network-link".into(),
+        }
+    }
+}
+
+impl ToYang for TeBandwidth {
+    fn to_yang(&self) -> Cow<'static, str> {
+        // The "bandwidth-ieee-float32" type uses the hexadecimal notation of
+        // the IEEE floating point value, limited to non-negative exponents.
+        let bw = self.get();
+        if bw.is_nan() || bw < 1.0 {
+            return "0x0p0".into();
+        }
+        let bits = bw.to_bits();
+        let exponent = (((bits >> 23) & 0xff) as i32 - 127).min(127);
+        let mantissa = (bits & 0x007fffff) << 1;
+        format!("0x1.{:06x}p{}", mantissa, exponent).into()
+    }
+}
+
 impl ToYang for GrExitReason {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
@@ -232,6 +281,15 @@ impl ToYang for GrExitReason {
     }
 }
 
+impl ToYang for GrRestartStatus {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            GrRestartStatus::Planned => "planned-restart".into(),
+            GrRestartStatus::Unplanned => "unplanned-restart".into(),
+        }
+    }
+}
+
 impl ToYang for ospfv2::packet::lsa::LsaAsExternalFlags {
     fn to_yang(&self) -> Cow<'static, str> {
         use ospfv2::packet::lsa::LsaAsExternalFlags;
@@ -284,6 +342,7 @@ impl ToYang for ospfv2::packet::lsa::LsaType {
                 "ospfv2-asbr-summary-lsa".into()
             }
             Some(LsaTypeCode::AsExternal) => "ospfv2-as-external-lsa".into(),
+            Some(LsaTypeCode::NssaExternal) => "ospfv2-nssa-lsa".into(),
             Some(LsaTypeCode::OpaqueLink) => {
                 "ospfv2-link-scope-opaque-lsa".into()
             }
@@ -377,6 +436,7 @@ impl ToYang for ospfv3::packet::lsa::LsaType {
             Some(LsaFunctionCode::AsExternal) => {
                 "ospfv3-external-lsa-type".into()
             }
+            Some(LsaFunctionCode::Nssa) => "ospfv3-nssa-lsa".into(),
             Some(LsaFunctionCode::Link) => "ospfv3-link-lsa".into(),
             Some(LsaFunctionCode::IntraAreaPrefix) => {
                 "ospfv3-intra-area-prefix-lsa".into()
@@ -384,7 +444,13 @@ impl ToYang for ospfv3::packet::lsa::LsaType {
             Some(LsaFunctionCode::RouterInfo) => {
                 "ospfv3-router-information-lsa".into()
             }
+            Some(LsaFunctionCode::IntraAreaTe) => {
+                "holo-ospf:ospfv3-intra-area-te-lsa".into()
+            }
             Some(LsaFunctionCode::Grace) => "holo-ospf:ospfv3-grace-lsa".into(),
+            Some(LsaFunctionCode::Srv6Locator) => {
+                "holo-ospf:ospfv3-srv6-locator-lsa".into()
+            }
             Some(LsaFunctionCode::ExtRouter) => {
                 "ietf-ospfv3-extended-lsa:ospfv3-e-router-lsa".into()
             }
@@ -400,6 +466,9 @@ impl ToYang for ospfv3::packet::lsa::LsaType {
             Some(LsaFunctionCode::ExtAsExternal) => {
                 "ietf-ospfv3-extended-lsa:ospfv3-e-external-lsa-type".into()
             }
+            Some(LsaFunctionCode::ExtNssa) => {
+                "ietf-ospfv3-extended-lsa:ospfv3-e-nssa-lsa".into()
+            }
             Some(LsaFunctionCode::ExtLink) => {
                 "ietf-ospfv3-extended-lsa:ospfv3-e-link-lsa".into()
             }
@@ -527,6 +596,30 @@ impl TryFromYang for AreaType {
     }
 }
 
+impl TryFromYang for NssaTranslatorRole {
+    fn try_from_yang(value: &str) -> Option<NssaTranslatorRole> {
+        match value {
+            "always" => Some(NssaTranslatorRole::Always),
+            "candidate" => Some(NssaTranslatorRole::Candidate),
+            "never" => Some(NssaTranslatorRole::Never),
+            _ => None,
+        }
+    }
+}
+
+impl TryFromYang for FlexAlgoMetricType {
+    fn try_from_yang(value: &str) -> Option<FlexAlgoMetricType> {
+        match value {
+            "igp-metric" => Some(FlexAlgoMetricType::Igp),
+            "min-unidirectional-link-delay" => {
+                Some(FlexAlgoMetricType::MinUniLinkDelay)
+            }
+            "te-metric" => Some(FlexAlgoMetricType::Te),
+            _ => None,
+        }
+    }
+}
+
 impl TryFromYang for InterfaceType {
     fn try_from_yang(value: &str) -> Option<InterfaceType> {
         match value {
@@ -538,3 +631,17 @@ impl TryFromYang for InterfaceType {
         }
     }
 }
+
+impl TryFromYang for GrReason {
+    fn try_from_yang(value: &str) -> Option<GrReason> {
+        match value {
+            "unknown" => Some(GrReason::Unknown),
+            "software-restart" => Some(GrReason::SoftwareRestart),
+            "software-upgrade" => Some(GrReason::SoftwareUpgrade),
+            "control-processor-switchover" => {
+                Some(GrReason::ControlProcessorSwitchover)
+            }
+            _ => None,
+        }
+    }
+}
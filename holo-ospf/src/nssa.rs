@@ -0,0 +1,199 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeMap;
+
+use chrono::Utc;
+use holo_utils::ip::IpNetworkKind;
+
+use crate::area::{Area, AreaType};
+use crate::collections::{Areas, Arena};
+use crate::instance::InstanceUpView;
+use crate::interface::Interface;
+use crate::lsdb::{LsaEntry, LsaOriginateEvent, LSA_INFINITY};
+use crate::northbound::notification;
+use crate::packet::lsa::LsaRouterFlagsVersion;
+use crate::redistribution::{ExternalMetricType, ExternalRoute};
+use crate::route::PathType;
+use crate::version::Version;
+
+// NSSA translator role.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NssaTranslatorRole {
+    Always,
+    Candidate,
+    Never,
+}
+
+// NSSA translator state.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NssaTranslatorState {
+    Enabled,
+    Elected,
+    #[default]
+    Disabled,
+}
+
+// ===== impl NssaTranslatorState =====
+
+impl NssaTranslatorState {
+    // Returns whether NSSA-LSAs are being translated into AS-external-LSAs.
+    pub(crate) fn is_translating(&self) -> bool {
+        *self != NssaTranslatorState::Disabled
+    }
+}
+
+// ===== global functions =====
+
+// Returns whether the router is translating NSSA-LSAs for any of its attached
+// NSSAs.
+pub(crate) fn is_translator<V>(areas: &Areas<V>) -> bool
+where
+    V: Version,
+{
+    areas
+        .iter()
+        .any(|area| area.state.nssa_translator_state.is_translating())
+}
+
+// Runs the NSSA translator election for all attached NSSAs (RFC 3101,
+// Section 3.1).
+pub(crate) fn update_translator_state<V>(
+    instance: &InstanceUpView<'_, V>,
+    areas: &mut Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+) where
+    V: Version,
+{
+    let is_abr = areas.is_abr(interfaces);
+    let mut changed = false;
+
+    for area in areas.iter_mut() {
+        let state = compute_translator_state(area, instance, is_abr);
+        if state != area.state.nssa_translator_state {
+            area.state.nssa_translator_state = state;
+            area.state.nssa_translator_event_count += 1;
+            area.state.discontinuity_time = Utc::now();
+            notification::nssa_translator_status_change(instance, area);
+            changed = true;
+        }
+    }
+
+    // (Re)originate Router-LSAs since the ASBR status might have changed.
+    if changed {
+        instance
+            .tx
+            .protocol_input
+            .lsa_orig_event(LsaOriginateEvent::AsbrStatusChange);
+    }
+}
+
+// Computes the set of NSSA routes that should be translated into
+// AS-external-LSAs (RFC 3101, Section 3.2).
+pub(crate) fn update_translated_routes<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    areas: &Areas<V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let extended_lsa = instance.config.extended_lsa;
+    let router_id = instance.state.router_id;
+    let default = V::IpNetwork::default(instance.state.af);
+    let mut routes = BTreeMap::new();
+
+    for area in areas
+        .iter()
+        .filter(|area| area.state.nssa_translator_state.is_translating())
+    {
+        for lsa in V::nssa_networks(&area.state.lsdb, extended_lsa, lsa_entries)
+            // Only LSAs with the P-bit set and a non-zero forwarding address
+            // are translated.
+            .filter(|lsa| lsa.p_bit && lsa.fwd_addr.is_some())
+            // Type-7 default routes are never translated.
+            .filter(|lsa| lsa.prefix != default)
+            // Filter out unreachable LSAs.
+            .filter(|lsa| lsa.metric < LSA_INFINITY)
+            // Filter out LSAs originated by the calculating router itself.
+            .filter(|lsa| lsa.adv_rtr != router_id)
+        {
+            // Translate only the NSSA-LSA whose route was selected for the
+            // routing table.
+            if !instance.state.rib.get(&lsa.prefix).is_some_and(|route| {
+                matches!(
+                    route.path_type,
+                    PathType::Type1Nssa | PathType::Type2Nssa
+                ) && route.area_id == Some(area.area_id)
+                    && route
+                        .origin
+                        .as_ref()
+                        .is_some_and(|origin| origin.adv_rtr == lsa.adv_rtr)
+            }) {
+                continue;
+            }
+
+            let metric_type = match lsa.e_bit {
+                true => ExternalMetricType::Type2,
+                false => ExternalMetricType::Type1,
+            };
+            let route = ExternalRoute {
+                metric: lsa.metric,
+                metric_type,
+                tag: lsa.tag,
+                fwd_addr: lsa.fwd_addr.map(Into::into),
+                propagate: false,
+            };
+            routes.insert(lsa.prefix, route);
+        }
+    }
+
+    instance.state.nssa_translated_routes = routes;
+}
+
+// ===== helper functions =====
+
+fn compute_translator_state<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    is_abr: bool,
+) -> NssaTranslatorState
+where
+    V: Version,
+{
+    // Only NSSA border routers can translate NSSA-LSAs.
+    if area.config.area_type != AreaType::Nssa || !is_abr {
+        return NssaTranslatorState::Disabled;
+    }
+
+    match area.config.nssa_translator_role {
+        NssaTranslatorRole::Always => NssaTranslatorState::Enabled,
+        NssaTranslatorRole::Never => NssaTranslatorState::Disabled,
+        NssaTranslatorRole::Candidate => {
+            // TODO: implement the TranslatorStabilityInterval.
+            let router_id = instance.state.router_id;
+            let mut border_routers = area
+                .state
+                .routers
+                .iter()
+                .filter(|(rtr_id, _)| **rtr_id != router_id)
+                .filter(|(_, route)| route.path_type == PathType::IntraArea)
+                .filter(|(_, route)| route.flags.is_abr());
+
+            // Defer to reachable NSSA border routers that translate
+            // unconditionally. Otherwise, the candidate with the highest
+            // Router ID is elected.
+            if border_routers
+                .clone()
+                .any(|(_, route)| route.flags.is_nssa_translator())
+                || border_routers.any(|(rtr_id, _)| *rtr_id > router_id)
+            {
+                NssaTranslatorState::Disabled
+            } else {
+                NssaTranslatorState::Elected
+            }
+        }
+    }
+}
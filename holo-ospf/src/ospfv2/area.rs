@@ -28,8 +28,22 @@ impl AreaVersion<Self> for Ospfv2 {
     fn area_options(area: &Area<Self>, location: OptionsLocation) -> Options {
         let mut options = Options::empty();
 
-        if area.config.area_type == AreaType::Normal {
-            options.insert(Options::E);
+        match area.config.area_type {
+            AreaType::Normal => options.insert(Options::E),
+            AreaType::Stub => (),
+            // The N-bit is only set in Hello packets, while the same bit is
+            // used as the P-bit in NSSA-LSAs.
+            AreaType::Nssa => {
+                if matches!(
+                    location,
+                    OptionsLocation::Packet {
+                        pkt_type: PacketType::Hello,
+                        ..
+                    }
+                ) {
+                    options.insert(Options::NP);
+                }
+            }
         }
 
         // The O-bit is not set in packets other than Database Description
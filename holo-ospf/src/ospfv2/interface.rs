@@ -55,9 +55,15 @@ impl InterfaceVersion<Self> for Ospfv2 {
             auth_seqno: None,
         };
 
+        // The Network Mask field is set to 0.0.0.0 on virtual links.
+        let network_mask = match iface.is_virtual_link() {
+            true => Ipv4Addr::UNSPECIFIED,
+            false => iface.system.primary_addr.unwrap().mask(),
+        };
+
         Packet::Hello(Hello {
             hdr,
-            network_mask: iface.system.primary_addr.unwrap().mask(),
+            network_mask,
             hello_interval: iface.config.hello_interval,
             options: Self::area_options(
                 area,
@@ -115,7 +121,10 @@ impl InterfaceVersion<Self> for Ospfv2 {
         iface: &Interface<Self>,
         hello: &ospfv2::packet::Hello,
     ) -> Result<(), InterfaceCfgError> {
-        if iface.config.if_type != InterfaceType::PointToPoint {
+        if !matches!(
+            iface.config.if_type,
+            InterfaceType::PointToPoint | InterfaceType::VirtualLink
+        ) {
             // Validate the Hello Network mask field.
             let iface_addrmask = iface.system.primary_addr.unwrap().mask();
             if hello.network_mask != iface_addrmask {
@@ -156,7 +165,7 @@ impl InterfaceVersion<Self> for Ospfv2 {
         neighbors: &'a mut Arena<Neighbor<Self>>,
     ) -> Option<(NeighborIndex, &'a mut Neighbor<Self>)> {
         match iface.config.if_type {
-            InterfaceType::PointToPoint => {
+            InterfaceType::PointToPoint | InterfaceType::VirtualLink => {
                 // If the receiving interface connects to a point-to-point
                 // network or a virtual link, the sender is identified by the
                 // Router ID (source router) found in the packet's OSPF header.
@@ -0,0 +1,66 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::net::Ipv4Addr;
+
+use holo_utils::link_state::{LsAdjSid, LsLinkDescriptors};
+
+use crate::area::Area;
+use crate::collections::Arena;
+use crate::link_state::LinkStateVersion;
+use crate::lsdb::LsaEntry;
+use crate::ospfv2::packet::lsa::{LsaRouterLink, LsaTypeCode};
+use crate::packet::lsa::LsaHdrVersion;
+use crate::version::Ospfv2;
+
+// ===== impl Ospfv2 =====
+
+impl LinkStateVersion<Self> for Ospfv2 {
+    fn ls_link_info(
+        area: &Area<Self>,
+        adv_rtr: Ipv4Addr,
+        link: &LsaRouterLink,
+        lsa_entries: &Arena<LsaEntry<Self>>,
+    ) -> (LsLinkDescriptors, Vec<LsAdjSid>) {
+        let descriptors = LsLinkDescriptors {
+            local_addr: Some(link.link_data.into()),
+            ..Default::default()
+        };
+
+        // Look up the Adj-SIDs in the matching Extended Link TLV.
+        let adj_sids = area
+            .state
+            .lsdb
+            .iter_by_type_advrtr(
+                lsa_entries,
+                LsaTypeCode::OpaqueArea.into(),
+                adv_rtr,
+            )
+            .map(|(_, lse)| &lse.data)
+            .filter(|lsa| !lsa.hdr.is_maxage())
+            .filter_map(|lsa| lsa.body.as_opaque_area())
+            .filter_map(|lsa_body| lsa_body.as_ext_link())
+            .filter_map(|lsa_body| lsa_body.link.as_ref())
+            .find(|tlv| {
+                tlv.link_type == link.link_type
+                    && tlv.link_id == link.link_id
+                    && tlv.link_data == link.link_data
+            })
+            .map(|tlv| {
+                tlv.adj_sids
+                    .iter()
+                    .map(|adj_sid| LsAdjSid {
+                        flags: adj_sid.flags.bits(),
+                        weight: adj_sid.weight,
+                        sid: adj_sid.sid,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        (descriptors, adj_sids)
+    }
+}
@@ -8,9 +8,9 @@ use std::collections::BTreeMap;
 use std::net::Ipv4Addr;
 
 use holo_utils::ibus::SrCfgEvent;
-use holo_utils::ip::{AddressFamily, Ipv4NetworkExt};
+use holo_utils::ip::{AddressFamily, IpAddrKind, Ipv4NetworkExt};
 use holo_utils::mpls::Label;
-use holo_utils::sr::{IgpAlgoType, Sid, SidLastHopBehavior};
+use holo_utils::sr::{Sid, SidLastHopBehavior};
 use ipnetwork::{IpNetwork, Ipv4Network};
 use itertools::Itertools;
 
@@ -22,27 +22,34 @@ use crate::debug::LsaFlushReason;
 use crate::error::Error;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::{ism, Interface, InterfaceType};
-use crate::lsdb::{LsaEntry, LsaOriginateEvent, LsdbVersion, MAX_LINK_METRIC};
-use crate::neighbor::nsm;
+use crate::lsdb::{
+    self, LsaEntry, LsaOriginateEvent, LsdbVersion, MAX_LINK_METRIC,
+};
+use crate::neighbor::{nsm, NeighborNetId};
+use crate::nssa::{self, NssaTranslatorRole};
 use crate::ospfv2::packet::lsa::{
-    LsaBody, LsaHdr, LsaNetwork, LsaRouter, LsaRouterFlags, LsaRouterLink,
-    LsaRouterLinkType, LsaSummary, LsaType, LsaTypeCode,
+    LsaAsExternal, LsaAsExternalFlags, LsaBody, LsaHdr, LsaNetwork, LsaRouter,
+    LsaRouterFlags, LsaRouterLink, LsaRouterLinkType, LsaSummary, LsaType,
+    LsaTypeCode,
 };
 use crate::ospfv2::packet::lsa_opaque::{
-    ExtLinkTlv, ExtPrefixRouteType, ExtPrefixTlv, LsaExtLink, LsaExtPrefix,
-    LsaExtPrefixFlags, LsaOpaque, LsaOpaqueType, LsaRouterInfo, OpaqueLsaId,
-    PrefixSid,
+    ExtLinkTlv, ExtPrefixRouteType, ExtPrefixTlv, GrInterfaceAddrTlv,
+    LsaExtLink, LsaExtPrefix, LsaExtPrefixFlags, LsaGrace, LsaOpaque,
+    LsaOpaqueType, LsaRouterInfo, LsaTe, OpaqueLsaId, PrefixSid,
 };
 use crate::ospfv2::packet::Options;
 use crate::packet::lsa::{
     Lsa, LsaHdrVersion, LsaKey, LsaScope, LsaTypeVersion,
 };
 use crate::packet::tlv::{
-    PrefixSidFlags, RouterInfoCaps, RouterInfoCapsTlv, SidLabelRangeTlv,
-    SrAlgoTlv, SrLocalBlockTlv,
+    GrReason, GrReasonTlv, GracePeriodTlv, PrefixSidFlags, RouterInfoCaps,
+    RouterInfoCapsTlv, SidLabelRangeTlv, SrAlgoTlv, SrLocalBlockTlv,
+    TeLinkType,
 };
+use crate::redistribution::{ExternalMetricType, ExternalRoute};
 use crate::route::{SummaryNet, SummaryRtr};
 use crate::version::Ospfv2;
+use crate::{flex_algo, gr, vlink};
 
 // ===== impl Ospfv2 =====
 
@@ -79,6 +86,13 @@ impl LsdbVersion<Self> for Ospfv2 {
             {
                 return false;
             }
+
+            // NSSA-LSAs are only allowed on NSSA areas.
+            if area_type != AreaType::Nssa
+                && lsa_type.type_code() == Some(LsaTypeCode::NssaExternal)
+            {
+                return false;
+            }
         }
 
         true
@@ -122,6 +136,9 @@ impl LsdbVersion<Self> for Ospfv2 {
 
                 // Originate Router Information LSA(s).
                 lsa_orig_router_info(area, instance);
+
+                // Originate TE LSA(s).
+                lsa_orig_te(area, instance, arenas);
             }
             LsaOriginateEvent::InterfaceStateChange { .. } => {
                 // (Re)originate Router-LSA in all areas since the ABR status
@@ -150,6 +167,9 @@ impl LsdbVersion<Self> for Ospfv2 {
                 } else {
                     lsa_flush_network(iface, area, instance, arenas);
                 }
+
+                // (Re)originate TE LSA(s).
+                lsa_orig_te(area, instance, arenas);
             }
             LsaOriginateEvent::InterfaceAddrAddDel { area_id, .. } => {
                 // (Re)originate Router-LSA.
@@ -169,6 +189,9 @@ impl LsdbVersion<Self> for Ospfv2 {
                 // (Re)originate Extended Link Opaque LSA(s).
                 lsa_orig_ext_link(area, instance, arenas);
 
+                // (Re)originate TE LSA(s).
+                lsa_orig_te(area, instance, arenas);
+
                 // (Re)originate Network-LSA.
                 let (_, iface) =
                     area.interfaces.get_by_id(&arenas.interfaces, iface_id)?;
@@ -183,6 +206,15 @@ impl LsdbVersion<Self> for Ospfv2 {
                 } else {
                     lsa_flush_network(iface, area, instance, arenas);
                 }
+
+                // (Re)originate the transit area's Router-LSA since its V-bit
+                // might have changed.
+                if let Some(vlink) = &iface.system.vlink
+                    && let Some((_, transit_area)) =
+                        arenas.areas.get_by_area_id(vlink.transit_area_id)
+                {
+                    lsa_orig_router(transit_area, instance, arenas);
+                }
             }
             LsaOriginateEvent::NeighborTwoWayOrHigherChange {
                 area_id, ..
@@ -194,24 +226,52 @@ impl LsdbVersion<Self> for Ospfv2 {
 
                 // (Re)originate Extended Link Opaque LSA(s).
                 lsa_orig_ext_link(area, instance, arenas);
+
+                // (Re)originate TE LSA(s).
+                lsa_orig_te(area, instance, arenas);
             }
             LsaOriginateEvent::SelfOriginatedLsaRcvd { lsdb_id, lse_id } => {
                 // Check if the received self-originated LSA needs to be
                 // reoriginated or flushed.
                 process_self_originated_lsa(instance, arenas, lsdb_id, lse_id)?;
             }
-            LsaOriginateEvent::StubRouterChange => {
+            LsaOriginateEvent::StubRouterChange
+            | LsaOriginateEvent::AsbrStatusChange => {
                 // (Re)originate Router-LSA in all areas.
                 for area in arenas.areas.iter() {
                     lsa_orig_router(area, instance, arenas);
                 }
             }
-            LsaOriginateEvent::GrHelperChange => {
+            LsaOriginateEvent::GrEnableChange
+            | LsaOriginateEvent::GrHelperChange
+            | LsaOriginateEvent::RouterInfoChange => {
                 // (Re)originate Router Information LSA(s) in all areas.
                 for area in arenas.areas.iter() {
                     lsa_orig_router_info(area, instance);
                 }
             }
+            LsaOriginateEvent::GrRestartExit => {
+                // Reoriginate all router-originated LSAs, now that the
+                // adjacencies have been reestablished.
+                for area in arenas.areas.iter() {
+                    lsa_orig_router(area, instance, arenas);
+                    lsa_orig_router_info(area, instance);
+                    lsa_orig_ext_prefix(area, instance, arenas);
+                    lsa_orig_ext_link(area, instance, arenas);
+                    lsa_orig_te(area, instance, arenas);
+                    for iface in area.interfaces.iter(&arenas.interfaces) {
+                        if iface.state.ism_state == ism::State::Dr
+                            && iface
+                                .state
+                                .neighbors
+                                .iter(&arenas.neighbors)
+                                .any(|nbr| nbr.state == nsm::State::Full)
+                        {
+                            lsa_orig_network(iface, area, instance, arenas);
+                        }
+                    }
+                }
+            }
             LsaOriginateEvent::SrEnableChange => {
                 // (Re)originate Router Information LSA(s), Extended Prefix
                 // Opaque LSA(s) and Extended Link Opaque LSA(s) in all areas.
@@ -229,6 +289,9 @@ impl LsdbVersion<Self> for Ospfv2 {
                             lsa_orig_router_info(area, instance);
                         }
                     }
+                    SrCfgEvent::Srv6LocatorUpdate => {
+                        // SRv6 isn't supported in OSPFv2.
+                    }
                     SrCfgEvent::PrefixSidUpdate(af) => {
                         if af == AddressFamily::Ipv4 {
                             // (Re)originate Extended Prefix Opaque LSA(s) in
@@ -240,6 +303,43 @@ impl LsdbVersion<Self> for Ospfv2 {
                     }
                 }
             }
+            LsaOriginateEvent::FlexAlgoChange => {
+                // (Re)originate Router Information LSA(s), Extended Prefix
+                // Opaque LSA(s) and Extended Link Opaque LSA(s) in all areas.
+                for area in arenas.areas.iter() {
+                    lsa_orig_router_info(area, instance);
+                    lsa_orig_ext_prefix(area, instance, arenas);
+                    lsa_orig_ext_link(area, instance, arenas);
+                }
+            }
+            LsaOriginateEvent::TeChange => {
+                // (Re)originate or flush TE LSA(s) in all areas.
+                for area in arenas.areas.iter() {
+                    lsa_orig_te(area, instance, arenas);
+                }
+            }
+            LsaOriginateEvent::PrefixSuppressionChange => {
+                for area in arenas.areas.iter() {
+                    // (Re)originate Router-LSA.
+                    lsa_orig_router(area, instance, arenas);
+
+                    // (Re)originate Network-LSA(s).
+                    for iface in area
+                        .interfaces
+                        .iter(&arenas.interfaces)
+                        .filter(|iface| {
+                            iface.state.ism_state == ism::State::Dr
+                                && iface
+                                    .state
+                                    .neighbors
+                                    .iter(&arenas.neighbors)
+                                    .any(|nbr| nbr.state == nsm::State::Full)
+                        })
+                    {
+                        lsa_orig_network(iface, area, instance, arenas);
+                    }
+                }
+            }
             _ => (),
         };
 
@@ -305,6 +405,139 @@ impl LsdbVersion<Self> for Ospfv2 {
         lsa_id.into()
     }
 
+    fn lsa_orig_as_external(
+        instance: &mut InstanceUpView<'_, Self>,
+        prefix: Ipv4Network,
+        _lsa_id: Option<u32>,
+        route: &ExternalRoute,
+    ) -> u32 {
+        // TODO: implement Appendix's E algorithm for assigning Link State IDs.
+        let lsa_id = prefix.ip();
+
+        // (Re)originate Type-5 AS-External-LSA.
+        lsa_orig_as_external(instance, prefix, lsa_id, route);
+
+        lsa_id.into()
+    }
+
+    fn lsa_orig_nssa_external(
+        area: &Area<Self>,
+        instance: &mut InstanceUpView<'_, Self>,
+        prefix: Ipv4Network,
+        _lsa_id: Option<u32>,
+        route: &ExternalRoute,
+    ) -> u32 {
+        // TODO: implement Appendix's E algorithm for assigning Link State IDs.
+        let lsa_id = prefix.ip();
+
+        // (Re)originate Type-7 NSSA-LSA.
+        lsa_orig_nssa_external(area, instance, prefix, lsa_id, route);
+
+        lsa_id.into()
+    }
+
+    fn lsa_grace(
+        iface: &Interface<Self>,
+        area: &Area<Self>,
+        grace_period: u32,
+        reason: GrReason,
+    ) -> (Option<Options>, Ipv4Addr, LsaBody) {
+        // LSA's header options.
+        let options = Self::area_options(area, OptionsLocation::Lsa);
+
+        // LSA ID.
+        let lsa_id = OpaqueLsaId::new(LsaOpaqueType::Grace as u8, 0).into();
+
+        // On broadcast, NBMA and P2MP segments, the restarting router is
+        // identified by its IP interface address.
+        let addr = match iface.config.if_type {
+            InterfaceType::Broadcast
+            | InterfaceType::NonBroadcast
+            | InterfaceType::PointToMultipoint => iface
+                .system
+                .primary_addr
+                .map(|addr| GrInterfaceAddrTlv::new(addr.ip())),
+            InterfaceType::PointToPoint | InterfaceType::VirtualLink => None,
+        };
+
+        let lsa_body = LsaBody::OpaqueLink(LsaOpaque::Grace(LsaGrace {
+            grace_period: Some(GracePeriodTlv::new(grace_period)),
+            gr_reason: Some(GrReasonTlv::new(reason as u8)),
+            addr,
+            unknown_tlvs: vec![],
+        }));
+
+        (Some(options), lsa_id, lsa_body)
+    }
+
+    fn gr_restart_adjs_restored(
+        area: &Area<Self>,
+        instance: &InstanceUpView<'_, Self>,
+        arenas: &InstanceArenas<Self>,
+    ) -> bool {
+        let router_id = instance.state.router_id;
+
+        // Lookup the pre-restart Router-LSA.
+        let lsa_key =
+            LsaKey::new(LsaTypeCode::Router.into(), router_id, router_id);
+        let Some((_, lse)) = area.state.lsdb.get(&arenas.lsa_entries, &lsa_key)
+        else {
+            return !gr::area_adjs_syncing(area, arenas);
+        };
+        let lsa_body = lse.data.body.as_router().unwrap();
+
+        // Check whether all adjacencies listed in the Router-LSA were
+        // reestablished.
+        lsa_body.links.iter().all(|link| match link.link_type {
+            LsaRouterLinkType::PointToPoint
+            | LsaRouterLinkType::VirtualLink => {
+                gr::area_nbr_full(area, arenas, link.link_id)
+            }
+            LsaRouterLinkType::TransitNetwork => {
+                let Some(iface) =
+                    area.interfaces.iter(&arenas.interfaces).find(|iface| {
+                        iface.system.primary_addr.map(|addr| addr.ip())
+                            == Some(link.link_data)
+                    })
+                else {
+                    return false;
+                };
+
+                if link.link_id == link.link_data {
+                    // The router was the DR: check all routers listed in its
+                    // Network-LSA.
+                    let lsa_key = LsaKey::new(
+                        LsaTypeCode::Network.into(),
+                        router_id,
+                        link.link_id,
+                    );
+                    let Some((_, lse)) =
+                        area.state.lsdb.get(&arenas.lsa_entries, &lsa_key)
+                    else {
+                        return true;
+                    };
+                    let lsa_body = lse.data.body.as_network().unwrap();
+                    lsa_body
+                        .attached_rtrs
+                        .iter()
+                        .filter(|nbr_router_id| **nbr_router_id != router_id)
+                        .all(|nbr_router_id| {
+                            gr::iface_nbr_full(iface, arenas, *nbr_router_id)
+                        })
+                } else {
+                    // Check the adjacency with the DR.
+                    let net_id = NeighborNetId::from(link.link_id);
+                    iface
+                        .state
+                        .neighbors
+                        .get_by_net_id(&arenas.neighbors, net_id)
+                        .is_some_and(|(_, nbr)| nbr.state == nsm::State::Full)
+                }
+            }
+            LsaRouterLinkType::StubNetwork => true,
+        })
+    }
+
     fn lsdb_get_by_lsa_type(
         iface_idx: InterfaceIndex,
         area_idx: AreaIndex,
@@ -366,6 +599,25 @@ fn lsa_orig_router(
     if arenas.areas.is_abr(&arenas.interfaces) {
         flags.insert(LsaRouterFlags::B);
     }
+    if (instance.config.is_asbr() || nssa::is_translator(&arenas.areas))
+        && area.config.area_type != AreaType::Stub
+    {
+        flags.insert(LsaRouterFlags::E);
+    }
+    if area.config.area_type == AreaType::Nssa
+        && area.config.nssa_translator_role == NssaTranslatorRole::Always
+        && arenas.areas.is_abr(&arenas.interfaces)
+    {
+        flags.insert(LsaRouterFlags::NT);
+    }
+    if vlink::is_transit_area(
+        area,
+        &arenas.areas,
+        &arenas.interfaces,
+        &arenas.neighbors,
+    ) {
+        flags.insert(LsaRouterFlags::V);
+    }
 
     // Router-LSA's links.
     let mut links = vec![];
@@ -375,6 +627,33 @@ fn lsa_orig_router(
         // Skip interfaces in the "Down" state.
         .filter(|iface| !iface.is_down())
     {
+        // Add a Type-4 (virtual) link for each fully adjacent virtual
+        // neighbor.
+        if iface.is_virtual_link() {
+            if let Some(endpoint) = iface.vlink_endpoint() {
+                let cost = if instance.is_stub_router() {
+                    MAX_LINK_METRIC
+                } else {
+                    endpoint.cost
+                };
+                for nbr in iface
+                    .state
+                    .neighbors
+                    .iter(&arenas.neighbors)
+                    .filter(|nbr| nbr.state == nsm::State::Full)
+                {
+                    let link = LsaRouterLink::new(
+                        LsaRouterLinkType::VirtualLink,
+                        nbr.router_id,
+                        endpoint.src_addr,
+                        cost,
+                    );
+                    links.push(link);
+                }
+            }
+            continue;
+        }
+
         let primary_addr = iface.system.primary_addr.unwrap();
 
         // Add Type-3 (stub) links to interfaces in Loopback state.
@@ -391,12 +670,14 @@ fn lsa_orig_router(
         }
 
         // When stub-router is configured (RFC 6987), set the cost of all
-        // non-stub links to MaxLinkMetric.
-        let non_stub_cost = if instance.config.stub_router {
-            MAX_LINK_METRIC
-        } else {
-            iface.config.cost
-        };
+        // non-stub links to MaxLinkMetric. The same applies to links where
+        // LDP isn't synchronized yet (RFC 5443).
+        let non_stub_cost =
+            if instance.is_stub_router() || iface.ldp_sync_max_metric() {
+                MAX_LINK_METRIC
+            } else {
+                iface.config.cost
+            };
 
         let mut add_stub_links = false;
         match iface.config.if_type {
@@ -408,11 +689,14 @@ fn lsa_orig_router(
                     .iter(&arenas.neighbors)
                     .filter(|nbr| nbr.state == nsm::State::Full)
                 {
-                    let link_data = if iface.system.unnumbered {
-                        Ipv4Addr::from(iface.system.ifindex.unwrap())
-                    } else {
-                        primary_addr.ip()
-                    };
+                    // Multi-area interfaces are advertised as unnumbered
+                    // point-to-point links (RFC 5185).
+                    let link_data =
+                        if iface.system.unnumbered || iface.is_multi_area() {
+                            Ipv4Addr::from(iface.system.ifindex.unwrap())
+                        } else {
+                            primary_addr.ip()
+                        };
                     let link = LsaRouterLink::new(
                         LsaRouterLinkType::PointToPoint,
                         nbr.router_id,
@@ -422,8 +706,9 @@ fn lsa_orig_router(
                     links.push(link);
                 }
 
-                // Add Type-3 (stub) links, unless the interface is unnumbered.
-                if !iface.system.unnumbered {
+                // Add Type-3 (stub) links, unless the interface is unnumbered
+                // or a multi-area interface.
+                if !iface.system.unnumbered && !iface.is_multi_area() {
                     add_stub_links = true;
                 }
             }
@@ -464,9 +749,12 @@ fn lsa_orig_router(
                     add_stub_links = true;
                 }
             }
+            InterfaceType::VirtualLink => unreachable!(),
         }
 
-        if add_stub_links {
+        // Omit the Type-3 (stub) links of interfaces subject to prefix
+        // suppression (RFC 6860).
+        if add_stub_links && !iface.prefix_suppression(instance) {
             links.extend(
                 iface
                     .system
@@ -510,8 +798,14 @@ fn lsa_orig_network(
     // Designated Router.
     let lsa_id = iface.system.primary_addr.unwrap().ip();
 
-    // Network-LSA's mask.
-    let mask = iface.system.primary_addr.unwrap().mask();
+    // Network-LSA's mask. When prefix suppression is enabled, a host mask is
+    // used so that only the DR's interface address becomes reachable
+    // (RFC 6860).
+    let mask = if iface.prefix_suppression(instance) {
+        Ipv4Addr::BROADCAST
+    } else {
+        iface.system.primary_addr.unwrap().mask()
+    };
 
     // Network-LSA's attached routers.
     let myself = instance.state.router_id;
@@ -563,9 +857,13 @@ fn lsa_orig_router_info(
     let mut sr_algo = None;
     let mut srgb = vec![];
     let mut srlb = vec![];
+    let mut fads = vec![];
     if instance.config.sr_enabled {
         // Fill in supported SR algorithms.
-        sr_algo = Some(SrAlgoTlv::new([IgpAlgoType::Spf].into()));
+        sr_algo = Some(SrAlgoTlv::new(flex_algo::sr_algos(instance.config)));
+
+        // Fill in Flexible Algorithm Definitions.
+        fads = flex_algo::local_fads(instance.config);
 
         // Fill in local SRGB.
         for range in &sr_config.srgb {
@@ -584,17 +882,23 @@ fn lsa_orig_router_info(
 
     // (Re)originate Router Information LSA.
     let mut info_caps = RouterInfoCaps::STUB_ROUTER;
+    if instance.config.gr.enabled {
+        info_caps.insert(RouterInfoCaps::GR);
+    }
     if instance.config.gr.helper_enabled {
         info_caps.insert(RouterInfoCaps::GR_HELPER);
     }
     let lsa_body = LsaBody::OpaqueArea(LsaOpaque::RouterInfo(LsaRouterInfo {
         info_caps: Some(RouterInfoCapsTlv::new(info_caps)),
         func_caps: None,
+        hostname: lsdb::local_hostname(instance.config),
+        node_tags: lsdb::local_node_tags(instance.config),
         sr_algo,
         srgb,
         srlb,
         msds: None,
         srms_pref: None,
+        fads,
         unknown_tlvs: vec![],
     }));
     instance.tx.protocol_input.lsa_orig_check(
@@ -619,7 +923,12 @@ fn lsa_orig_ext_prefix(
     // Initialize prefixes.
     let mut prefixes = BTreeMap::new();
     if instance.config.sr_enabled {
-        for ((prefix, algo), prefix_sid) in sr_config.prefix_sids.iter() {
+        for (prefix, algo, prefix_sid) in sr_config
+            .prefix_sids
+            .iter()
+            .map(|((prefix, algo), prefix_sid)| (*prefix, *algo, prefix_sid))
+            .chain(flex_algo::local_prefix_sids(instance.config))
+        {
             if let IpNetwork::V4(prefix) = prefix {
                 let mut flags = LsaExtPrefixFlags::empty();
                 if prefix.prefix() == 32 {
@@ -628,7 +937,6 @@ fn lsa_orig_ext_prefix(
 
                 // Add Prefix-SID Sub-TLV.
                 let mut psid_flags = PrefixSidFlags::empty();
-                let mut prefix_sids = BTreeMap::new();
                 match prefix_sid.last_hop {
                     SidLastHopBehavior::ExpNull => {
                         psid_flags.insert(PrefixSidFlags::NP);
@@ -640,20 +948,18 @@ fn lsa_orig_ext_prefix(
                     SidLastHopBehavior::Php => (),
                 }
                 let sid = Sid::Index(prefix_sid.index);
-                prefix_sids
-                    .insert(*algo, PrefixSid::new(psid_flags, *algo, sid));
-
-                prefixes.insert(
-                    *prefix,
-                    ExtPrefixTlv {
+                prefixes
+                    .entry(prefix)
+                    .or_insert_with(|| ExtPrefixTlv {
                         route_type: ExtPrefixRouteType::IntraArea,
                         af: 0,
                         flags,
-                        prefix: *prefix,
-                        prefix_sids,
+                        prefix,
+                        prefix_sids: BTreeMap::new(),
                         unknown_tlvs: vec![],
-                    },
-                );
+                    })
+                    .prefix_sids
+                    .insert(algo, PrefixSid::new(psid_flags, algo, sid));
             }
         }
     }
@@ -755,8 +1061,15 @@ fn lsa_orig_ext_link(
             .filter(|iface| !iface.is_down())
             // Skip loopback interfaces.
             .filter(|iface| iface.state.ism_state != ism::State::Loopback)
+            // Skip virtual links.
+            .filter(|iface| !iface.is_virtual_link())
         {
             let primary_addr = iface.system.primary_addr.unwrap();
+
+            // Application-Specific Link Attributes (Flex-Algo only).
+            let asla = (!instance.config.flex_algos.is_empty())
+                .then(|| flex_algo::iface_asla(iface));
+
             match iface.config.if_type {
                 InterfaceType::PointToPoint
                 | InterfaceType::PointToMultipoint => {
@@ -766,13 +1079,14 @@ fn lsa_orig_ext_link(
                         .iter(&arenas.neighbors)
                         .filter(|nbr| nbr.state == nsm::State::Full)
                     {
-                        let link_tlv = ExtLinkTlv::new(
+                        let mut link_tlv = ExtLinkTlv::new(
                             LsaRouterLinkType::PointToPoint,
                             nbr.router_id,
                             primary_addr.ip(),
                             nbr.adj_sids.clone(),
                             None,
                         );
+                        link_tlv.asla = asla.clone();
                         originate_fn(link_tlv);
                     }
                 }
@@ -804,16 +1118,18 @@ fn lsa_orig_ext_link(
                             .flat_map(|nbr| nbr.adj_sids.iter())
                             .copied()
                             .collect();
-                        let link_tlv = ExtLinkTlv::new(
+                        let mut link_tlv = ExtLinkTlv::new(
                             LsaRouterLinkType::TransitNetwork,
                             iface.state.dr.unwrap().get(),
                             primary_addr.ip(),
                             adj_sids,
                             None,
                         );
+                        link_tlv.asla = asla;
                         originate_fn(link_tlv);
                     };
                 }
+                InterfaceType::VirtualLink => unreachable!(),
             }
         }
     }
@@ -838,6 +1154,206 @@ fn lsa_orig_ext_link(
     }
 }
 
+fn lsa_orig_te(
+    area: &Area<Ospfv2>,
+    instance: &InstanceUpView<'_, Ospfv2>,
+    arenas: &InstanceArenas<Ospfv2>,
+) {
+    let lsdb_id = LsdbId::Area(area.id);
+
+    // LSA's header options.
+    let options = Ospfv2::area_options(area, OptionsLocation::Lsa);
+
+    // Originate as many TE LSAs as necessary. Opaque ID zero is used by the
+    // Router Address TLV, whereas the Link TLVs use the subsequent IDs.
+    let mut opaque_id: u32 = 0;
+    let mut originate_fn = |lsa_te| {
+        // Initialize Opaque LSA ID.
+        let lsa_id =
+            OpaqueLsaId::new(LsaOpaqueType::Te as u8, opaque_id).into();
+
+        // (Re)originate TE LSA.
+        let lsa_body = LsaBody::OpaqueArea(LsaOpaque::Te(lsa_te));
+        instance.tx.protocol_input.lsa_orig_check(
+            lsdb_id,
+            Some(options),
+            lsa_id,
+            lsa_body,
+        );
+
+        // Increment the Opaque ID.
+        opaque_id += 1;
+    };
+
+    if instance.config.te_enabled {
+        // Router Address TLV.
+        let router_addr = instance
+            .config
+            .te_rid_ipv4
+            .unwrap_or(instance.state.router_id);
+        originate_fn(LsaTe {
+            router_addr: Some(router_addr),
+            ..Default::default()
+        });
+
+        // Link TLVs.
+        for iface in area
+            .interfaces
+            .iter(&arenas.interfaces)
+            // Skip interfaces in the "Down" state.
+            .filter(|iface| !iface.is_down())
+            // Skip loopback interfaces.
+            .filter(|iface| iface.state.ism_state != ism::State::Loopback)
+            // Skip virtual links.
+            .filter(|iface| !iface.is_virtual_link())
+        {
+            let primary_addr = iface.system.primary_addr.unwrap();
+
+            match iface.config.if_type {
+                InterfaceType::PointToPoint
+                | InterfaceType::PointToMultipoint => {
+                    for nbr in iface
+                        .state
+                        .neighbors
+                        .iter(&arenas.neighbors)
+                        .filter(|nbr| nbr.state == nsm::State::Full)
+                    {
+                        let mut link_tlv = iface.te_link_tlv();
+                        link_tlv.link_type = Some(TeLinkType::PointToPoint);
+                        link_tlv.link_id = Some(nbr.router_id);
+                        link_tlv.local_addrs = vec![primary_addr.ip().into()];
+                        link_tlv.remote_addrs = vec![nbr.src.into()];
+                        originate_fn(LsaTe {
+                            link: Some(link_tlv),
+                            ..Default::default()
+                        });
+                    }
+                }
+                InterfaceType::Broadcast | InterfaceType::NonBroadcast => {
+                    if (iface.state.ism_state == ism::State::Dr
+                        && iface
+                            .state
+                            .neighbors
+                            .iter(&arenas.neighbors)
+                            .any(|nbr| nbr.state == nsm::State::Full))
+                        || iface
+                            .state
+                            .dr
+                            .and_then(|net_id| {
+                                iface
+                                    .state
+                                    .neighbors
+                                    .get_by_net_id(&arenas.neighbors, net_id)
+                                    .filter(|(_, nbr)| {
+                                        nbr.state == nsm::State::Full
+                                    })
+                            })
+                            .is_some()
+                    {
+                        let mut link_tlv = iface.te_link_tlv();
+                        link_tlv.link_type = Some(TeLinkType::MultiAccess);
+                        link_tlv.link_id = Some(iface.state.dr.unwrap().get());
+                        link_tlv.local_addrs = vec![primary_addr.ip().into()];
+                        originate_fn(LsaTe {
+                            link: Some(link_tlv),
+                            ..Default::default()
+                        });
+                    };
+                }
+                InterfaceType::VirtualLink => unreachable!(),
+            }
+        }
+    }
+
+    // Flush self-originated TE LSAs that are no longer needed.
+    for (_, lse) in area
+        .state
+        .lsdb
+        .iter_by_type_advrtr(
+            &arenas.lsa_entries,
+            LsaTypeCode::OpaqueArea.into(),
+            instance.state.router_id,
+        )
+        .filter(|(_, lse)| {
+            let opaque_lsa_id = OpaqueLsaId::from(lse.data.hdr.lsa_id);
+            opaque_lsa_id.opaque_type == LsaOpaqueType::Te as u8
+                && opaque_lsa_id.opaque_id >= opaque_id
+        })
+    {
+        lsa_flush(instance, lsdb_id, lse.id);
+    }
+}
+
+fn lsa_orig_as_external(
+    instance: &InstanceUpView<'_, Ospfv2>,
+    prefix: Ipv4Network,
+    lsa_id: Ipv4Addr,
+    route: &ExternalRoute,
+) {
+    let lsdb_id = LsdbId::As;
+
+    // LSA's header options.
+    let options = Options::E;
+
+    // AS-External-LSA's flags.
+    let mut flags = LsaAsExternalFlags::empty();
+    if route.metric_type == ExternalMetricType::Type2 {
+        flags.insert(LsaAsExternalFlags::E);
+    }
+
+    // (Re)originate AS-External-LSA.
+    let lsa_body = LsaBody::AsExternal(LsaAsExternal {
+        mask: prefix.mask(),
+        flags,
+        metric: route.metric,
+        fwd_addr: route.fwd_addr.and_then(Ipv4Addr::get),
+        tag: route.tag.unwrap_or(0),
+    });
+    instance.tx.protocol_input.lsa_orig_check(
+        lsdb_id,
+        Some(options),
+        lsa_id,
+        lsa_body,
+    );
+}
+
+fn lsa_orig_nssa_external(
+    area: &Area<Ospfv2>,
+    instance: &InstanceUpView<'_, Ospfv2>,
+    prefix: Ipv4Network,
+    lsa_id: Ipv4Addr,
+    route: &ExternalRoute,
+) {
+    let lsdb_id = LsdbId::Area(area.id);
+
+    // LSA's header options. In NSSA-LSAs, the NP bit is the P-bit.
+    let mut options = Ospfv2::area_options(area, OptionsLocation::Lsa);
+    if route.propagate {
+        options.insert(Options::NP);
+    }
+
+    // NSSA-LSA's flags.
+    let mut flags = LsaAsExternalFlags::empty();
+    if route.metric_type == ExternalMetricType::Type2 {
+        flags.insert(LsaAsExternalFlags::E);
+    }
+
+    // (Re)originate NSSA-LSA.
+    let lsa_body = LsaBody::NssaExternal(LsaAsExternal {
+        mask: prefix.mask(),
+        flags,
+        metric: route.metric,
+        fwd_addr: route.fwd_addr.and_then(Ipv4Addr::get),
+        tag: route.tag.unwrap_or(0),
+    });
+    instance.tx.protocol_input.lsa_orig_check(
+        lsdb_id,
+        Some(options),
+        lsa_id,
+        lsa_body,
+    );
+}
+
 fn process_self_originated_lsa(
     instance: &InstanceUpView<'_, Ospfv2>,
     arenas: &InstanceArenas<Ospfv2>,
@@ -874,7 +1390,10 @@ fn process_self_originated_lsa(
                 .interfaces
                 .iter(&arenas.interfaces)
                 .find(|iface| {
-                    iface.system.primary_addr.unwrap().ip() == lsa.hdr.lsa_id
+                    iface
+                        .system
+                        .primary_addr
+                        .is_some_and(|addr| addr.ip() == lsa.hdr.lsa_id)
                 })
                 .filter(|iface| iface.state.ism_state == ism::State::Dr)
                 .filter(|_| {
@@ -894,9 +1413,60 @@ fn process_self_originated_lsa(
             // once SPF runs and the routing table is computed.
         }
         Some(LsaTypeCode::AsExternal) => {
-            // Flush AS-External-LSA (redistribution of local routes isn't
-            // supported at the moment).
-            flush = true;
+            // Check if the route is still being redistributed.
+            if let Some((prefix, (lsa_id, route))) = instance
+                .state
+                .external_routes
+                .iter()
+                .find(|(_, (lsa_id, _))| {
+                    Ipv4Addr::from(*lsa_id) == lsa.hdr.lsa_id
+                })
+                .filter(|_| {
+                    // Ensure the Router-ID hasn't changed.
+                    lsa.hdr.adv_rtr == instance.state.router_id
+                })
+            {
+                // Reoriginate AS-External-LSA.
+                lsa_orig_as_external(
+                    instance,
+                    *prefix,
+                    (*lsa_id).into(),
+                    route,
+                );
+            } else {
+                // Flush AS-External-LSA.
+                flush = true;
+            }
+        }
+        Some(LsaTypeCode::NssaExternal) => {
+            let area_idx = lsdb_idx.into_area().unwrap();
+            let area = &arenas.areas[area_idx];
+
+            // Check if the route is still being redistributed.
+            if let Some((prefix, (lsa_id, route))) = area
+                .state
+                .nssa_external_routes
+                .iter()
+                .find(|(_, (lsa_id, _))| {
+                    Ipv4Addr::from(*lsa_id) == lsa.hdr.lsa_id
+                })
+                .filter(|_| {
+                    // Ensure the Router-ID hasn't changed.
+                    lsa.hdr.adv_rtr == instance.state.router_id
+                })
+            {
+                // Reoriginate NSSA-LSA.
+                lsa_orig_nssa_external(
+                    area,
+                    instance,
+                    *prefix,
+                    (*lsa_id).into(),
+                    route,
+                );
+            } else {
+                // Flush NSSA-LSA.
+                flush = true;
+            }
         }
         Some(
             LsaTypeCode::OpaqueLink
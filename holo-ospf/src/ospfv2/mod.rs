@@ -7,6 +7,7 @@
 pub mod area;
 pub mod instance;
 pub mod interface;
+pub mod link_state;
 pub mod lsdb;
 pub mod neighbor;
 pub mod network;
@@ -51,6 +51,7 @@ pub enum LsaTypeCode {
     SummaryNetwork = 3,
     SummaryRouter = 4,
     AsExternal = 5,
+    NssaExternal = 7,
     OpaqueLink = 9,
     OpaqueArea = 10,
     OpaqueAs = 11,
@@ -65,6 +66,7 @@ pub enum LsaBody {
     SummaryNetwork(LsaSummary),
     SummaryRouter(LsaSummary),
     AsExternal(LsaAsExternal),
+    NssaExternal(LsaAsExternal),
     OpaqueLink(LsaOpaque),
     OpaqueArea(LsaOpaque),
     OpaqueAs(LsaOpaque),
@@ -307,6 +309,7 @@ impl LsaTypeVersion for LsaType {
                 | LsaTypeCode::Network
                 | LsaTypeCode::SummaryNetwork
                 | LsaTypeCode::SummaryRouter
+                | LsaTypeCode::NssaExternal
                 | LsaTypeCode::OpaqueArea,
             ) => LsaScope::Area,
             Some(LsaTypeCode::AsExternal | LsaTypeCode::OpaqueAs) => {
@@ -325,6 +328,7 @@ impl LsaTypeVersion for LsaType {
                     | LsaTypeCode::SummaryNetwork
                     | LsaTypeCode::SummaryRouter
                     | LsaTypeCode::AsExternal
+                    | LsaTypeCode::NssaExternal
             )
         )
     }
@@ -486,6 +490,10 @@ impl LsaBodyVersion<Ospfv2> for LsaBody {
             Some(LsaTypeCode::AsExternal) => {
                 LsaBody::AsExternal(LsaAsExternal::decode(buf)?)
             }
+            // NSSA-LSAs have the same format as AS-External-LSAs.
+            Some(LsaTypeCode::NssaExternal) => {
+                LsaBody::NssaExternal(LsaAsExternal::decode(buf)?)
+            }
             Some(LsaTypeCode::OpaqueLink) => {
                 LsaBody::OpaqueLink(LsaOpaque::decode(lsa_id, buf)?)
             }
@@ -507,7 +515,9 @@ impl LsaBodyVersion<Ospfv2> for LsaBody {
             LsaBody::Network(lsa) => lsa.encode(buf),
             LsaBody::SummaryNetwork(lsa) => lsa.encode(buf),
             LsaBody::SummaryRouter(lsa) => lsa.encode(buf),
-            LsaBody::AsExternal(lsa) => lsa.encode(buf),
+            LsaBody::AsExternal(lsa) | LsaBody::NssaExternal(lsa) => {
+                lsa.encode(buf)
+            }
             LsaBody::OpaqueLink(lsa) => lsa.encode(buf),
             LsaBody::OpaqueArea(lsa) => lsa.encode(buf),
             LsaBody::OpaqueAs(lsa) => lsa.encode(buf),
@@ -522,6 +532,7 @@ impl LsaBodyVersion<Ospfv2> for LsaBody {
             LsaBody::SummaryNetwork(_lsa) => LsaTypeCode::SummaryNetwork.into(),
             LsaBody::SummaryRouter(_lsa) => LsaTypeCode::SummaryRouter.into(),
             LsaBody::AsExternal(_lsa) => LsaTypeCode::AsExternal.into(),
+            LsaBody::NssaExternal(_lsa) => LsaTypeCode::NssaExternal.into(),
             LsaBody::OpaqueLink(_lsa) => LsaTypeCode::OpaqueLink.into(),
             LsaBody::OpaqueArea(_lsa) => LsaTypeCode::OpaqueArea.into(),
             LsaBody::OpaqueAs(_lsa) => LsaTypeCode::OpaqueAs.into(),
@@ -539,6 +550,10 @@ impl LsaBodyVersion<Ospfv2> for LsaBody {
         )
     }
 
+    fn is_non_default_external(&self) -> bool {
+        matches!(self, LsaBody::AsExternal(lsa) if !lsa.mask.is_unspecified())
+    }
+
     fn validate(&self, hdr: &LsaHdr) -> Result<(), LsaValidationError> {
         match self {
             LsaBody::Router(lsa) => lsa.validate(hdr),
@@ -627,6 +642,10 @@ impl LsaRouterFlagsVersion for LsaRouterFlags {
     fn is_asbr(&self) -> bool {
         self.contains(LsaRouterFlags::E)
     }
+
+    fn is_nssa_translator(&self) -> bool {
+        self.contains(LsaRouterFlags::NT)
+    }
 }
 
 // ===== impl LsaNetwork =====
@@ -754,4 +773,12 @@ impl LsaVersion<Self> for Ospfv2 {
     fn type4_summary(_extended_lsa: bool) -> LsaType {
         LsaTypeCode::SummaryRouter.into()
     }
+
+    fn type5_external(_extended_lsa: bool) -> LsaType {
+        LsaTypeCode::AsExternal.into()
+    }
+
+    fn type7_nssa(_extended_lsa: bool) -> LsaType {
+        LsaTypeCode::NssaExternal.into()
+    }
 }
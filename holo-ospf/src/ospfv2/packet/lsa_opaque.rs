@@ -16,17 +16,18 @@ use holo_utils::mpls::Label;
 use holo_utils::sr::{IgpAlgoType, Sid};
 use ipnetwork::Ipv4Network;
 use num_derive::{FromPrimitive, ToPrimitive};
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::ospfv2::packet::lsa::{LsaRouterLinkType, LsaUnknown};
 use crate::packet::error::{DecodeError, DecodeResult};
 use crate::packet::lsa::{AdjSidVersion, PrefixSidVersion};
 use crate::packet::tlv::{
-    tlv_encode_end, tlv_encode_start, tlv_wire_len, AdjSidFlags, GrReasonTlv,
-    GracePeriodTlv, MsdTlv, PrefixSidFlags, RouterFuncCapsTlv,
-    RouterInfoCapsTlv, RouterInfoTlvType, SidLabelRangeTlv, SrAlgoTlv,
-    SrLocalBlockTlv, SrmsPrefTlv, UnknownTlv, TLV_HDR_SIZE,
+    tlv_encode_end, tlv_encode_start, tlv_wire_len, AdjSidFlags, AslaTlv,
+    DynamicHostnameTlv, FlexAlgoDefTlv, GrReasonTlv, GracePeriodTlv, MsdTlv,
+    NodeAdminTagTlv, PrefixSidFlags, RouterFuncCapsTlv, RouterInfoCapsTlv,
+    RouterInfoTlvType, SidLabelRangeTlv, SrAlgoTlv, SrLocalBlockTlv,
+    SrmsPrefTlv, TeLinkTlv, TeTlvType, UnknownTlv, TLV_HDR_SIZE,
 };
 
 // OSPFv2 opaque LSA types.
@@ -54,6 +55,7 @@ pub struct OpaqueLsaId {
 #[derive(Clone, Debug, EnumAsInner, Eq, PartialEq)]
 #[derive(Deserialize, Serialize)]
 pub enum LsaOpaque {
+    Te(LsaTe),
     Grace(LsaGrace),
     RouterInfo(LsaRouterInfo),
     ExtPrefix(LsaExtPrefix),
@@ -100,6 +102,29 @@ pub struct LsaGrace {
 #[derive(Deserialize, Serialize)]
 pub struct GrInterfaceAddrTlv(Ipv4Addr);
 
+//
+// OSPFv2 Traffic Engineering Opaque LSA.
+//
+// Encoding format (LSA body):
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                                                               |
+// +-                            TLVs                             -+
+// |                             ...                               |
+//
+// An LSA contains one top-level TLV: either a Router Address TLV or a Link
+// TLV.
+//
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsaTe {
+    pub router_addr: Option<Ipv4Addr>,
+    pub link: Option<TeLinkTlv>,
+    pub unknown_tlvs: Vec<UnknownTlv>,
+}
+
 //
 // OSPFv2 Router Information (RI) Opaque LSA.
 //
@@ -117,11 +142,20 @@ pub struct GrInterfaceAddrTlv(Ipv4Addr);
 pub struct LsaRouterInfo {
     pub info_caps: Option<RouterInfoCapsTlv>,
     pub func_caps: Option<RouterFuncCapsTlv>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<DynamicHostnameTlv>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub node_tags: Vec<NodeAdminTagTlv>,
     pub sr_algo: Option<SrAlgoTlv>,
     pub srgb: Vec<SidLabelRangeTlv>,
     pub srlb: Vec<SrLocalBlockTlv>,
     pub msds: Option<MsdTlv>,
     pub srms_pref: Option<SrmsPrefTlv>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fads: Vec<FlexAlgoDefTlv>,
     pub unknown_tlvs: Vec<UnknownTlv>,
 }
 
@@ -308,6 +342,10 @@ pub struct ExtLinkTlv {
     pub adj_sids: Vec<AdjSid>,
     pub msds: Option<MsdTlv>,
     #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asla: Option<AslaTlv>,
+    #[new(default)]
     pub unknown_tlvs: Vec<UnknownTlv>,
 }
 
@@ -323,6 +361,7 @@ pub enum ExtLinkSubTlvType {
     AdjSid = 2,
     LanAdjSid = 3,
     LinkMsd = 6,
+    Asla = 10,
 }
 
 //
@@ -398,6 +437,7 @@ impl LsaOpaque {
     ) -> DecodeResult<Self> {
         let opaque_type = lsa_id.octets()[0];
         let lsa = match LsaOpaqueType::from_u8(opaque_type) {
+            Some(LsaOpaqueType::Te) => LsaOpaque::Te(LsaTe::decode(buf)?),
             Some(LsaOpaqueType::Grace) => {
                 LsaOpaque::Grace(LsaGrace::decode(buf)?)
             }
@@ -418,6 +458,7 @@ impl LsaOpaque {
 
     pub(crate) fn encode(&self, buf: &mut BytesMut) {
         match self {
+            LsaOpaque::Te(lsa) => lsa.encode(buf),
             LsaOpaque::Grace(lsa) => lsa.encode(buf),
             LsaOpaque::RouterInfo(lsa) => lsa.encode(buf),
             LsaOpaque::ExtPrefix(lsa) => lsa.encode(buf),
@@ -427,6 +468,62 @@ impl LsaOpaque {
     }
 }
 
+// ===== impl LsaTe =====
+
+impl LsaTe {
+    fn decode(buf: &mut Bytes) -> DecodeResult<Self> {
+        let mut lsa = LsaTe::default();
+
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse TLV type.
+            let tlv_type = buf.get_u16();
+            let tlv_etype = TeTlvType::from_u16(tlv_type);
+
+            // Parse and validate TLV length.
+            let tlv_len = buf.get_u16();
+            let tlv_wlen = tlv_wire_len(tlv_len);
+            if tlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(tlv_len));
+            }
+
+            // Parse TLV value.
+            let mut buf_tlv = buf.copy_to_bytes(tlv_wlen as usize);
+            match tlv_etype {
+                Some(TeTlvType::RouterAddr) => {
+                    if tlv_len != 4 {
+                        return Err(DecodeError::InvalidTlvLength(tlv_len));
+                    }
+                    let addr = buf_tlv.get_ipv4();
+                    lsa.router_addr.get_or_insert(addr);
+                }
+                Some(TeTlvType::Link) => {
+                    let link_tlv = TeLinkTlv::decode(tlv_len, &mut buf_tlv)?;
+                    lsa.link.get_or_insert(link_tlv);
+                }
+                _ => {
+                    // Save unknown TLV.
+                    let value = buf_tlv.copy_to_bytes(tlv_len as usize);
+                    lsa.unknown_tlvs
+                        .push(UnknownTlv::new(tlv_type, tlv_len, value));
+                }
+            }
+        }
+
+        Ok(lsa)
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        if let Some(router_addr) = &self.router_addr {
+            let start_pos = tlv_encode_start(buf, TeTlvType::RouterAddr);
+            buf.put_ipv4(router_addr);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(link_tlv) = &self.link {
+            link_tlv.encode(buf);
+        }
+    }
+}
+
 // ===== impl LsaGrace =====
 
 impl LsaGrace {
@@ -544,6 +641,16 @@ impl LsaRouterInfo {
                         RouterFuncCapsTlv::decode(tlv_len, &mut buf_tlv)?;
                     router_info.func_caps.get_or_insert(caps);
                 }
+                Some(RouterInfoTlvType::DynamicHostname) => {
+                    let hostname =
+                        DynamicHostnameTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.hostname.get_or_insert(hostname);
+                }
+                Some(RouterInfoTlvType::NodeAdminTag) => {
+                    let node_tags =
+                        NodeAdminTagTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.node_tags.push(node_tags);
+                }
                 Some(RouterInfoTlvType::SrAlgo) => {
                     let sr_algo = SrAlgoTlv::decode(tlv_len, &mut buf_tlv)?;
                     router_info.sr_algo.get_or_insert(sr_algo);
@@ -564,6 +671,10 @@ impl LsaRouterInfo {
                     let srms_pref = SrmsPrefTlv::decode(tlv_len, &mut buf_tlv)?;
                     router_info.srms_pref.get_or_insert(srms_pref);
                 }
+                Some(RouterInfoTlvType::FlexAlgoDef) => {
+                    let fad = FlexAlgoDefTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.fads.push(fad);
+                }
                 _ => {
                     // Save unknown TLV.
                     let value = buf_tlv.copy_to_bytes(tlv_len as usize);
@@ -584,6 +695,12 @@ impl LsaRouterInfo {
         if let Some(func_caps) = &self.func_caps {
             func_caps.encode(buf);
         }
+        if let Some(hostname) = &self.hostname {
+            hostname.encode(buf);
+        }
+        for node_tags in &self.node_tags {
+            node_tags.encode(buf);
+        }
         if let Some(sr_algo) = &self.sr_algo {
             sr_algo.encode(buf);
         }
@@ -599,6 +716,9 @@ impl LsaRouterInfo {
         if let Some(srms_pref) = &self.srms_pref {
             srms_pref.encode(buf);
         }
+        for fad in &self.fads {
+            fad.encode(buf);
+        }
     }
 }
 
@@ -806,7 +926,7 @@ impl ExtPrefixTlv {
             buf.put_u8(prefix_sid.flags.bits());
             buf.put_u8(0);
             buf.put_u8(0);
-            buf.put_u8(*algo as u8);
+            buf.put_u8(algo.to_u8().unwrap());
             match prefix_sid.sid {
                 Sid::Index(index) => buf.put_u32(index),
                 Sid::Label(label) => buf.put_u24(label.get()),
@@ -864,6 +984,10 @@ impl ExtLinkTlv {
                     let msds = MsdTlv::decode(tlv_len, &mut buf_stlv)?;
                     tlv.msds.get_or_insert(msds);
                 }
+                Some(ExtLinkSubTlvType::Asla) => {
+                    let asla = AslaTlv::decode(tlv_len, &mut buf_stlv)?;
+                    tlv.asla.get_or_insert(asla);
+                }
                 Some(
                     ExtLinkSubTlvType::AdjSid | ExtLinkSubTlvType::LanAdjSid,
                 ) => {
@@ -942,6 +1066,10 @@ impl ExtLinkTlv {
         if let Some(msds) = &self.msds {
             msds.encode(ExtLinkSubTlvType::LinkMsd as u16, buf);
         }
+        // ASLA Sub-TLV.
+        if let Some(asla) = &self.asla {
+            asla.encode(ExtLinkSubTlvType::Asla as u16, buf);
+        }
         tlv_encode_end(buf, start_pos);
     }
 }
@@ -252,6 +252,10 @@ impl OptionsVersion<Ospfv2> for Options {
     fn e_bit(&self) -> bool {
         self.contains(Options::E)
     }
+
+    fn n_bit(&self) -> bool {
+        self.contains(Options::NP)
+    }
 }
 
 // ===== impl PacketHdr =====
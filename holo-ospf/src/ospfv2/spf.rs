@@ -10,6 +10,7 @@ use std::sync::Arc;
 
 use enum_as_inner::EnumAsInner;
 use holo_utils::ip::{AddressFamily, Ipv4NetworkExt};
+use holo_utils::mpls::Label;
 use holo_utils::sr::IgpAlgoType;
 use ipnetwork::Ipv4Network;
 
@@ -28,6 +29,7 @@ use crate::ospfv2::packet::lsa_opaque::{
 };
 use crate::ospfv2::packet::Options;
 use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
+use crate::packet::tlv::{AdjSidFlags, AslaTlv};
 use crate::route::{Nexthop, NexthopKey, Nexthops};
 use crate::spf::{
     SpfComputation, SpfExternalNetwork, SpfInterAreaNetwork,
@@ -104,28 +106,46 @@ impl SpfVersion<Self> for Ospfv2 {
     fn spf_computation_type(
         trigger_lsas: &[SpfTriggerLsa<Self>],
     ) -> SpfComputation<Self> {
-        // Router-LSA and Network-LSA changes represent topological changes,
-        // hence a full SPF run is required to recompute the SPT.
-        //
         // Certain Opaque-LSA changes don't strictly require a full SPF run, but
         // doing so greatly simplify things (e.g. no need to keep track of which
         // routes are affected by which SRGBs).
         if trigger_lsas.iter().map(|tlsa| &tlsa.new).any(|lsa| {
             matches!(
                 lsa.body,
-                LsaBody::Router(_)
-                    | LsaBody::Network(_)
-                    | LsaBody::OpaqueArea(
-                        LsaOpaque::RouterInfo(_)
-                            | LsaOpaque::ExtPrefix(_)
-                            | LsaOpaque::ExtLink(_)
-                    )
-                    | LsaBody::OpaqueAs(LsaOpaque::ExtPrefix(_))
+                LsaBody::OpaqueArea(
+                    LsaOpaque::RouterInfo(_)
+                        | LsaOpaque::ExtPrefix(_)
+                        | LsaOpaque::ExtLink(_)
+                ) | LsaBody::OpaqueAs(LsaOpaque::ExtPrefix(_))
             )
         }) {
             return SpfComputation::Full;
         }
 
+        // Router-LSA and Network-LSA changes represent topological changes,
+        // hence the SPT needs to be updated, starting from the vertices whose
+        // LSAs have changed.
+        let mut vertices: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for tlsa in trigger_lsas {
+            let lsa = &tlsa.new;
+            let vertex_id = match lsa.body {
+                LsaBody::Router(_) => VertexId::Router {
+                    router_id: lsa.hdr.adv_rtr,
+                },
+                LsaBody::Network(_) => VertexId::Network {
+                    dr_addr: lsa.hdr.lsa_id,
+                },
+                _ => continue,
+            };
+            let Some(area_id) = tlsa.log_id.area_id else {
+                continue;
+            };
+            vertices.entry(area_id).or_default().insert(vertex_id);
+        }
+        if !vertices.is_empty() {
+            return SpfComputation::Incremental(vertices);
+        }
+
         // In OSPFv2 intra-area information is embedded in Router-LSAs and
         // Network-LSAs.
         let intra = Default::default();
@@ -153,13 +173,14 @@ impl SpfVersion<Self> for Ospfv2 {
             .map(|lsa_hdr| lsa_hdr.lsa_id)
             .collect::<BTreeSet<_>>();
 
-        // Check AS-External LSA changes.
+        // Check AS-External LSA and NSSA LSA changes.
         let external = trigger_lsas
             .iter()
             .map(|tlsa| &tlsa.new)
             .filter_map(|lsa| {
                 lsa.body
                     .as_as_external()
+                    .or_else(|| lsa.body.as_nssa_external())
                     .map(move |lsa_body| (lsa.hdr, lsa_body))
             })
             .map(|(lsa_hdr, lsa_body)| {
@@ -194,7 +215,21 @@ impl SpfVersion<Self> for Ospfv2 {
             VertexLsa::Router(_parent_lsa) => {
                 // The destination is either a directly connected network or
                 // directly connected router.
-                let (parent_link_pos, _parent_link) = parent_link.unwrap();
+                let (parent_link_pos, parent_link) = parent_link.unwrap();
+
+                // For destinations reached over a virtual link, the nexthops
+                // are those used to reach the virtual link endpoint through
+                // the transit area.
+                if parent_link.link_type == LsaRouterLinkType::VirtualLink {
+                    return area
+                        .interfaces
+                        .iter(interfaces)
+                        .filter_map(|iface| iface.system.vlink.as_ref())
+                        .find(|vlink| vlink.router_id == parent_link.link_id)
+                        .and_then(|vlink| vlink.endpoint.as_ref())
+                        .map(|endpoint| endpoint.nexthops.clone())
+                        .ok_or(Error::SpfNexthopCalcError(dest_id));
+                }
 
                 // Get nexthop interface based on the parent's Router-LSA link
                 // position.
@@ -420,7 +455,8 @@ impl SpfVersion<Self> for Ospfv2 {
                     .links
                     .iter()
                     .filter_map(|link| match link.link_type {
-                        LsaRouterLinkType::PointToPoint => {
+                        LsaRouterLinkType::PointToPoint
+                        | LsaRouterLinkType::VirtualLink => {
                             let link_vid = VertexId::Router {
                                 router_id: link.link_id,
                             };
@@ -433,10 +469,6 @@ impl SpfVersion<Self> for Ospfv2 {
                             Some((link, link_vid, link.metric))
                         }
                         LsaRouterLinkType::StubNetwork => None,
-                        LsaRouterLinkType::VirtualLink => {
-                            // TODO: not supported yet.
-                            None
-                        }
                     })
                     .enumerate()
                     .filter_map(move |(link_pos, (link, link_vid, cost))| {
@@ -601,7 +633,38 @@ impl SpfVersion<Self> for Ospfv2 {
 
                 SpfExternalNetwork {
                     adv_rtr: lsa.hdr.adv_rtr,
+                    lsa_id: lsa.hdr.lsa_id,
                     e_bit: lsa_body.flags.contains(LsaAsExternalFlags::E),
+                    p_bit: false,
+                    prefix,
+                    prefix_options: Default::default(),
+                    metric: lsa_body.metric,
+                    fwd_addr: lsa_body.fwd_addr,
+                    tag: Some(lsa_body.tag),
+                }
+            })
+    }
+
+    fn nssa_networks<'a>(
+        lsdb: &'a Lsdb<Self>,
+        _extended_lsa: bool,
+        lsa_entries: &'a Arena<LsaEntry<Self>>,
+    ) -> impl Iterator<Item = SpfExternalNetwork<Self>> + 'a {
+        lsdb.iter_by_type(lsa_entries, LsaTypeCode::NssaExternal.into())
+            .map(|(_, lse)| &lse.data)
+            .filter(|lsa| !lsa.hdr.is_maxage())
+            .map(|lsa| {
+                let lsa_body = lsa.body.as_nssa_external().unwrap();
+                let prefix =
+                    Ipv4Network::with_netmask(lsa.hdr.lsa_id, lsa_body.mask)
+                        .unwrap();
+
+                // In NSSA-LSAs, the NP bit of the options field is the P-bit.
+                SpfExternalNetwork {
+                    adv_rtr: lsa.hdr.adv_rtr,
+                    lsa_id: lsa.hdr.lsa_id,
+                    e_bit: lsa_body.flags.contains(LsaAsExternalFlags::E),
+                    p_bit: lsa.hdr.options.contains(Options::NP),
                     prefix,
                     prefix_options: Default::default(),
                     metric: lsa_body.metric,
@@ -629,6 +692,17 @@ impl SpfVersion<Self> for Ospfv2 {
             .map(|lsa| lsa.body.as_opaque_area().unwrap())
             .filter_map(|lsa_body| lsa_body.as_router_info())
         {
+            // Dynamic Hostname.
+            if let Some(hostname) = &ri_lsa.hostname {
+                ri_agg.hostname.get_or_insert(hostname);
+            }
+
+            // Multiple occurrences of the Node Admin Tag TLV MAY be
+            // advertised.
+            for tlv in &ri_lsa.node_tags {
+                ri_agg.node_tags.extend(tlv.get());
+            }
+
             if let Some(sr_algo) = &ri_lsa.sr_algo {
                 // When multiple SR-Algorithm TLVs are received from a given
                 // router, the receiver MUST use the first occurrence of the TLV
@@ -645,6 +719,9 @@ impl SpfVersion<Self> for Ospfv2 {
             // Multiple occurrences of the SID/Label Range TLV MAY be advertised
             // in order to advertise multiple ranges.
             ri_agg.srgb.extend(&ri_lsa.srgb);
+
+            // Flexible Algorithm Definitions.
+            ri_agg.fads.extend(&ri_lsa.fads);
         }
 
         ri_agg
@@ -684,6 +761,100 @@ impl SpfVersion<Self> for Ospfv2 {
             }
         }
     }
+
+    fn adj_sid_find(
+        area: &Area<Self>,
+        adv_rtr: Ipv4Addr,
+        nbr_router_id: Ipv4Addr,
+        _extended_lsa: bool,
+        lsa_entries: &Arena<LsaEntry<Self>>,
+    ) -> Option<Label> {
+        area.state
+            .lsdb
+            .iter_by_type_advrtr(
+                lsa_entries,
+                LsaTypeCode::OpaqueArea.into(),
+                adv_rtr,
+            )
+            .map(|(_, lse)| &lse.data)
+            .filter(|lsa| !lsa.hdr.is_maxage())
+            .filter_map(|lsa| lsa.body.as_opaque_area().unwrap().as_ext_link())
+            .filter_map(|lsa_body| lsa_body.link.as_ref())
+            .flat_map(|link| {
+                link.adj_sids.iter().filter(move |adj_sid| {
+                    match adj_sid.nbr_router_id {
+                        // LAN Adj-SID.
+                        Some(adj_nbr_router_id) => {
+                            adj_nbr_router_id == nbr_router_id
+                        }
+                        // Point-to-point Adj-SID.
+                        None => {
+                            link.link_type == LsaRouterLinkType::PointToPoint
+                                && link.link_id == nbr_router_id
+                        }
+                    }
+                })
+            })
+            // Prefer unprotected Adj-SIDs.
+            .min_by_key(|adj_sid| adj_sid.flags.contains(AdjSidFlags::B))
+            .and_then(|adj_sid| adj_sid.sid.as_label().copied())
+    }
+
+    fn link_asla<'a>(
+        area: &'a Area<Self>,
+        adv_rtr: Ipv4Addr,
+        link: &'a LsaRouterLink,
+        lsa_entries: &'a Arena<LsaEntry<Self>>,
+    ) -> Option<&'a AslaTlv> {
+        area.state
+            .lsdb
+            .iter_by_type_advrtr(
+                lsa_entries,
+                LsaTypeCode::OpaqueArea.into(),
+                adv_rtr,
+            )
+            .map(|(_, lse)| &lse.data)
+            .filter(|lsa| !lsa.hdr.is_maxage())
+            .filter_map(|lsa| lsa.body.as_opaque_area().unwrap().as_ext_link())
+            .filter_map(|lsa_body| lsa_body.link.as_ref())
+            .find(|ext_link| {
+                ext_link.link_type == link.link_type
+                    && ext_link.link_id == link.link_id
+                    && ext_link.link_data == link.link_data
+            })
+            .and_then(|ext_link| ext_link.asla.as_ref())
+    }
+
+    fn virtual_link_addr(
+        transit_area: &Area<Self>,
+        router_id: Ipv4Addr,
+        lsa_entries: &Arena<LsaEntry<Self>>,
+    ) -> Option<Ipv4Addr> {
+        let vertex_id = VertexId::Router { router_id };
+        let VertexLsa::Router(lsa) = Self::vertex_lsa_find(
+            AddressFamily::Ipv4,
+            vertex_id,
+            transit_area,
+            false,
+            lsa_entries,
+        )?
+        else {
+            return None;
+        };
+
+        // Prefer the address of a transit network link, since the Link Data
+        // of point-to-point links might contain an interface index.
+        let links = &lsa.body.as_router().unwrap().links;
+        links
+            .iter()
+            .find(|link| link.link_type == LsaRouterLinkType::TransitNetwork)
+            .or_else(|| {
+                links.iter().find(|link| {
+                    link.link_type == LsaRouterLinkType::PointToPoint
+                })
+            })
+            .map(|link| link.link_data)
+    }
 }
 
 // ===== helper functions =====
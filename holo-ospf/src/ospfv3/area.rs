@@ -23,8 +23,10 @@ impl AreaVersion<Self> for Ospfv3 {
     fn area_options(area: &Area<Self>, location: OptionsLocation) -> Options {
         let mut options = Options::R | Options::V6 | Options::AF;
 
-        if area.config.area_type == AreaType::Normal {
-            options.insert(Options::E);
+        match area.config.area_type {
+            AreaType::Normal => options.insert(Options::E),
+            AreaType::Stub => (),
+            AreaType::Nssa => options.insert(Options::N),
         }
 
         if let OptionsLocation::Packet { auth: true, .. } = location {
@@ -0,0 +1,44 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::net::Ipv4Addr;
+
+use holo_utils::link_state::{LsAdjSid, LsLinkDescriptors};
+
+use crate::area::Area;
+use crate::collections::Arena;
+use crate::link_state::LinkStateVersion;
+use crate::lsdb::LsaEntry;
+use crate::ospfv3::packet::lsa::LsaRouterLink;
+use crate::version::Ospfv3;
+
+// ===== impl Ospfv3 =====
+
+impl LinkStateVersion<Self> for Ospfv3 {
+    fn ls_link_info(
+        _area: &Area<Self>,
+        _adv_rtr: Ipv4Addr,
+        link: &LsaRouterLink,
+        _lsa_entries: &Arena<LsaEntry<Self>>,
+    ) -> (LsLinkDescriptors, Vec<LsAdjSid>) {
+        let descriptors = LsLinkDescriptors {
+            local_id: Some(link.iface_id),
+            remote_id: Some(link.nbr_iface_id),
+            ..Default::default()
+        };
+        let adj_sids = link
+            .adj_sids
+            .iter()
+            .map(|adj_sid| LsAdjSid {
+                flags: adj_sid.flags.bits(),
+                weight: adj_sid.weight,
+                sid: adj_sid.sid,
+            })
+            .collect();
+
+        (descriptors, adj_sids)
+    }
+}
@@ -10,9 +10,12 @@ use std::net::{IpAddr, Ipv4Addr};
 use holo_utils::ibus::SrCfgEvent;
 use holo_utils::ip::{AddressFamily, IpNetworkKind};
 use holo_utils::mpls::Label;
-use holo_utils::sr::{IgpAlgoType, Sid, SidLastHopBehavior};
+use holo_utils::sr::{
+    IgpAlgoType, Sid, SidLastHopBehavior, Srv6EndpointBehavior,
+};
 use ipnetwork::IpNetwork;
 use itertools::Itertools;
+use num_traits::ToPrimitive;
 
 use crate::area::{Area, AreaType, AreaVersion, OptionsLocation};
 use crate::collections::{
@@ -22,24 +25,31 @@ use crate::debug::LsaFlushReason;
 use crate::error::Error;
 use crate::instance::{InstanceArenas, InstanceUpView};
 use crate::interface::{ism, Interface, InterfaceType};
-use crate::lsdb::{LsaOriginateEvent, LsdbVersion, MAX_LINK_METRIC};
-use crate::neighbor::nsm;
+use crate::lsdb::{self, LsaOriginateEvent, LsdbVersion, MAX_LINK_METRIC};
+use crate::neighbor::{nsm, Neighbor};
+use crate::nssa::{self, NssaTranslatorRole};
 use crate::ospfv3::packet::lsa::{
-    LsaBody, LsaFunctionCode, LsaHdr, LsaInterAreaPrefix, LsaInterAreaRouter,
-    LsaIntraAreaPrefix, LsaIntraAreaPrefixEntry, LsaLink, LsaLinkPrefix,
+    LsaAsExternal, LsaAsExternalFlags, LsaBody, LsaFunctionCode, LsaGrace,
+    LsaHdr, LsaInterAreaPrefix, LsaInterAreaRouter, LsaIntraAreaPrefix,
+    LsaIntraAreaPrefixEntry, LsaIntraAreaTe, LsaLink, LsaLinkPrefix,
     LsaNetwork, LsaRouter, LsaRouterFlags, LsaRouterInfo, LsaRouterLink,
-    LsaRouterLinkType, LsaScopeCode, LsaType, PrefixOptions, PrefixSid,
+    LsaRouterLinkType, LsaScopeCode, LsaSrv6Locator, LsaType, PrefixOptions,
+    PrefixSid, Srv6EndSid, Srv6EndXSid, Srv6EndXSidFlags, Srv6LocatorFlags,
+    Srv6LocatorRouteType, Srv6LocatorTlv,
 };
 use crate::ospfv3::packet::Options;
 use crate::packet::lsa::{
     Lsa, LsaHdrVersion, LsaKey, LsaScope, LsaTypeVersion, PrefixSidVersion,
 };
 use crate::packet::tlv::{
-    PrefixSidFlags, RouterInfoCaps, RouterInfoCapsTlv, SidLabelRangeTlv,
-    SrAlgoTlv, SrLocalBlockTlv,
+    GrReason, GrReasonTlv, GracePeriodTlv, PrefixSidFlags, RouterInfoCaps,
+    RouterInfoCapsTlv, SidLabelRangeTlv, SrAlgoTlv, SrLocalBlockTlv,
+    Srv6CapsFlags, Srv6CapsTlv, TeLinkType, TeNbrId,
 };
+use crate::redistribution::{ExternalMetricType, ExternalRoute};
 use crate::route::{SummaryNet, SummaryNetFlags, SummaryRtr};
 use crate::version::Ospfv3;
+use crate::{flex_algo, gr, srv6};
 
 // ===== impl Ospfv3 =====
 
@@ -64,6 +74,14 @@ impl LsdbVersion<Self> for Ospfv3 {
             {
                 return false;
             }
+
+            // NSSA-LSAs are only allowed on NSSA areas.
+            if area_type != AreaType::Nssa
+                && lsa_type.function_code_normalized()
+                    == Some(LsaFunctionCode::Nssa)
+            {
+                return false;
+            }
         }
 
         true
@@ -90,6 +108,12 @@ impl LsdbVersion<Self> for Ospfv3 {
 
                 // Originate Router Information LSA(s).
                 lsa_orig_router_info(area, instance);
+
+                // Originate Intra-Area-TE-LSA(s).
+                lsa_orig_intra_area_te(area, instance, arenas);
+
+                // Originate SRv6 Locator LSA(s).
+                lsa_orig_srv6_locator(area, instance, arenas);
             }
             LsaOriginateEvent::InterfaceStateChange { area_id, iface_id } => {
                 // (Re)originate Router-LSA(s) in all areas since the ABR status
@@ -149,6 +173,9 @@ impl LsdbVersion<Self> for Ospfv3 {
 
                 // (Re)originate Intra-area-prefix-LSA(s).
                 lsa_orig_intra_area_prefix(area, instance, arenas);
+
+                // (Re)originate Intra-Area-TE-LSA(s).
+                lsa_orig_intra_area_te(area, instance, arenas);
             }
             LsaOriginateEvent::InterfaceAddrAddDel { area_id, iface_id } => {
                 let (_, area) = arenas.areas.get_by_id(area_id)?;
@@ -198,6 +225,9 @@ impl LsdbVersion<Self> for Ospfv3 {
 
                 // (Re)originate Intra-area-prefix-LSA(s).
                 lsa_orig_intra_area_prefix(area, instance, arenas);
+
+                // (Re)originate Intra-Area-TE-LSA(s).
+                lsa_orig_intra_area_te(area, instance, arenas);
             }
             LsaOriginateEvent::NeighborTwoWayOrHigherChange {
                 area_id, ..
@@ -238,18 +268,46 @@ impl LsdbVersion<Self> for Ospfv3 {
                 // reoriginated or flushed.
                 process_self_originated_lsa(instance, arenas, lsdb_id, lse_id)?;
             }
-            LsaOriginateEvent::StubRouterChange => {
+            LsaOriginateEvent::StubRouterChange
+            | LsaOriginateEvent::AsbrStatusChange => {
                 // (Re)originate Router-LSA(s) in all areas.
                 for area in arenas.areas.iter() {
                     lsa_orig_router(area, instance, arenas);
                 }
             }
-            LsaOriginateEvent::GrHelperChange => {
+            LsaOriginateEvent::GrEnableChange
+            | LsaOriginateEvent::GrHelperChange
+            | LsaOriginateEvent::RouterInfoChange => {
                 // (Re)originate Router Information LSA(s) in all areas.
                 for area in arenas.areas.iter() {
                     lsa_orig_router_info(area, instance);
                 }
             }
+            LsaOriginateEvent::GrRestartExit => {
+                // Reoriginate all router-originated LSAs, now that the
+                // adjacencies have been reestablished.
+                for area in arenas.areas.iter() {
+                    lsa_orig_router(area, instance, arenas);
+                    lsa_orig_router_info(area, instance);
+                    lsa_orig_intra_area_te(area, instance, arenas);
+                    lsa_orig_srv6_locator(area, instance, arenas);
+                    for iface in area.interfaces.iter(&arenas.interfaces) {
+                        if iface.state.ism_state >= ism::State::Waiting {
+                            lsa_orig_link(iface, area, instance);
+                        }
+                        if iface.state.ism_state == ism::State::Dr
+                            && iface
+                                .state
+                                .neighbors
+                                .iter(&arenas.neighbors)
+                                .any(|nbr| nbr.state == nsm::State::Full)
+                        {
+                            lsa_orig_network(iface, area, instance, arenas);
+                        }
+                    }
+                    lsa_orig_intra_area_prefix(area, instance, arenas);
+                }
+            }
             LsaOriginateEvent::SrEnableChange => {
                 // Reoriginate Router Information LSA(s) and
                 // Intra-area-prefix-LSA(s) in all areas.
@@ -258,6 +316,17 @@ impl LsdbVersion<Self> for Ospfv3 {
                     lsa_orig_intra_area_prefix(area, instance, arenas);
                 }
             }
+            LsaOriginateEvent::Srv6Change => {
+                // (Re)originate Router-LSA(s), Router Information LSA(s),
+                // Intra-area-prefix-LSA(s) and SRv6 Locator LSA(s) in all
+                // areas.
+                for area in arenas.areas.iter() {
+                    lsa_orig_router(area, instance, arenas);
+                    lsa_orig_router_info(area, instance);
+                    lsa_orig_intra_area_prefix(area, instance, arenas);
+                    lsa_orig_srv6_locator(area, instance, arenas);
+                }
+            }
             LsaOriginateEvent::SrCfgChange { change } => {
                 match change {
                     SrCfgEvent::LabelRangeUpdate => {
@@ -266,6 +335,9 @@ impl LsdbVersion<Self> for Ospfv3 {
                             lsa_orig_router_info(area, instance);
                         }
                     }
+                    SrCfgEvent::Srv6LocatorUpdate => {
+                        // Handled by the `Srv6Change` event.
+                    }
                     SrCfgEvent::PrefixSidUpdate(af) => {
                         if af == instance.state.af {
                             // (Re)originate Intra-area-prefix-LSA(s) in all
@@ -279,6 +351,27 @@ impl LsdbVersion<Self> for Ospfv3 {
                     }
                 }
             }
+            LsaOriginateEvent::FlexAlgoChange => {
+                // (Re)originate Router-LSA(s), Router Information LSA(s) and
+                // Intra-area-prefix-LSA(s) in all areas.
+                for area in arenas.areas.iter() {
+                    lsa_orig_router(area, instance, arenas);
+                    lsa_orig_router_info(area, instance);
+                    lsa_orig_intra_area_prefix(area, instance, arenas);
+                }
+            }
+            LsaOriginateEvent::TeChange => {
+                // (Re)originate or flush Intra-Area-TE-LSA(s) in all areas.
+                for area in arenas.areas.iter() {
+                    lsa_orig_intra_area_te(area, instance, arenas);
+                }
+            }
+            LsaOriginateEvent::PrefixSuppressionChange => {
+                // (Re)originate Intra-Area-Prefix-LSA(s) in all areas.
+                for area in arenas.areas.iter() {
+                    lsa_orig_intra_area_prefix(area, instance, arenas);
+                }
+            }
         };
 
         Ok(())
@@ -371,6 +464,139 @@ impl LsdbVersion<Self> for Ospfv3 {
         lsa_id
     }
 
+    fn lsa_orig_as_external(
+        instance: &mut InstanceUpView<'_, Self>,
+        prefix: IpNetwork,
+        lsa_id: Option<u32>,
+        route: &ExternalRoute,
+    ) -> u32 {
+        // Get LSA-ID.
+        let lsa_id = match lsa_id {
+            Some(lsa_id) => lsa_id,
+            None => {
+                instance.state.next_type5_lsa_id += 1;
+                instance.state.next_type5_lsa_id
+            }
+        };
+
+        // (Re)originate AS-External-LSA.
+        lsa_orig_as_external(instance, prefix, lsa_id, route);
+
+        lsa_id
+    }
+
+    fn lsa_orig_nssa_external(
+        area: &Area<Self>,
+        instance: &mut InstanceUpView<'_, Self>,
+        prefix: IpNetwork,
+        lsa_id: Option<u32>,
+        route: &ExternalRoute,
+    ) -> u32 {
+        // Get LSA-ID.
+        let lsa_id = match lsa_id {
+            Some(lsa_id) => lsa_id,
+            None => {
+                instance.state.next_type5_lsa_id += 1;
+                instance.state.next_type5_lsa_id
+            }
+        };
+
+        // (Re)originate NSSA-LSA.
+        lsa_orig_nssa_external(area, instance, prefix, lsa_id, route);
+
+        lsa_id
+    }
+
+    fn lsa_grace(
+        iface: &Interface<Self>,
+        _area: &Area<Self>,
+        grace_period: u32,
+        reason: GrReason,
+    ) -> (Option<Options>, Ipv4Addr, LsaBody) {
+        // LSA ID.
+        let lsa_id = Ipv4Addr::from(iface.system.ifindex.unwrap());
+
+        let lsa_body = LsaBody::Grace(LsaGrace {
+            grace_period: Some(GracePeriodTlv::new(grace_period)),
+            gr_reason: Some(GrReasonTlv::new(reason as u8)),
+            unknown_tlvs: vec![],
+        });
+
+        (None, lsa_id, lsa_body)
+    }
+
+    fn gr_restart_adjs_restored(
+        area: &Area<Self>,
+        instance: &InstanceUpView<'_, Self>,
+        arenas: &InstanceArenas<Self>,
+    ) -> bool {
+        let router_id = instance.state.router_id;
+        let extended_lsa = instance.config.extended_lsa;
+
+        // Lookup the pre-restart Router-LSA(s).
+        let lsa_type = LsaRouter::lsa_type(extended_lsa);
+        let mut lses = area
+            .state
+            .lsdb
+            .iter_by_type_advrtr(&arenas.lsa_entries, lsa_type, router_id)
+            .map(|(_, lse)| lse)
+            .peekable();
+        if lses.peek().is_none() {
+            return !gr::area_adjs_syncing(area, arenas);
+        }
+
+        // Check whether all adjacencies listed in the Router-LSA(s) were
+        // reestablished.
+        lses.flat_map(|lse| lse.data.body.as_router().unwrap().links.iter())
+            .all(|link| match link.link_type {
+                LsaRouterLinkType::PointToPoint
+                | LsaRouterLinkType::VirtualLink => {
+                    gr::area_nbr_full(area, arenas, link.nbr_router_id)
+                }
+                LsaRouterLinkType::TransitNetwork => {
+                    let Some(iface) =
+                        area.interfaces.iter(&arenas.interfaces).find(
+                            |iface| iface.system.ifindex == Some(link.iface_id),
+                        )
+                    else {
+                        return false;
+                    };
+
+                    if link.nbr_router_id == router_id {
+                        // The router was the DR: check all routers listed in
+                        // its Network-LSA.
+                        let lsa_key = LsaKey::new(
+                            LsaNetwork::lsa_type(extended_lsa),
+                            router_id,
+                            Ipv4Addr::from(link.iface_id),
+                        );
+                        let Some((_, lse)) =
+                            area.state.lsdb.get(&arenas.lsa_entries, &lsa_key)
+                        else {
+                            return true;
+                        };
+                        let lsa_body = lse.data.body.as_network().unwrap();
+                        lsa_body
+                            .attached_rtrs
+                            .iter()
+                            .filter(|nbr_router_id| {
+                                **nbr_router_id != router_id
+                            })
+                            .all(|nbr_router_id| {
+                                gr::iface_nbr_full(
+                                    iface,
+                                    arenas,
+                                    *nbr_router_id,
+                                )
+                            })
+                    } else {
+                        // Check the adjacency with the DR.
+                        gr::iface_nbr_full(iface, arenas, link.nbr_router_id)
+                    }
+                }
+            })
+    }
+
     fn lsdb_get_by_lsa_type(
         iface_idx: InterfaceIndex,
         area_idx: AreaIndex,
@@ -436,6 +662,33 @@ fn lsa_orig_router(
     if arenas.areas.is_abr(&arenas.interfaces) {
         flags.insert(LsaRouterFlags::B);
     }
+    if (instance.config.is_asbr() || nssa::is_translator(&arenas.areas))
+        && area.config.area_type != AreaType::Stub
+    {
+        flags.insert(LsaRouterFlags::E);
+    }
+    if area.config.area_type == AreaType::Nssa
+        && area.config.nssa_translator_role == NssaTranslatorRole::Always
+        && arenas.areas.is_abr(&arenas.interfaces)
+    {
+        flags.insert(LsaRouterFlags::NT);
+    }
+
+    // SRv6 locator used to allocate the End.X SIDs.
+    let srv6_locator = srv6::locator(instance.config, instance.shared);
+    let srv6_endx_sid = |nbr: &Neighbor<Ospfv3>, lan: bool| {
+        let locator = srv6_locator?;
+        let sid = nbr.srv6_endx_sid?;
+        Some(Srv6EndXSid::new(
+            Srv6EndpointBehavior::EndX.to_u16().unwrap(),
+            Srv6EndXSidFlags::empty(),
+            locator.algo,
+            0,
+            lan.then_some(nbr.router_id),
+            sid,
+            Some(locator.sid_structure()),
+        ))
+    };
 
     // Router-LSA's links.
     let mut links = vec![];
@@ -461,13 +714,18 @@ fn lsa_orig_router(
         let ifindex = iface.system.ifindex.unwrap();
 
         // When stub-router is configured (RFC 6987), set the cost of all
-        // links to MaxLinkMetric.
-        let cost = if instance.config.stub_router {
+        // links to MaxLinkMetric. The same applies to links where LDP isn't
+        // synchronized yet (RFC 5443).
+        let cost = if instance.is_stub_router() || iface.ldp_sync_max_metric() {
             MAX_LINK_METRIC
         } else {
             iface.config.cost
         };
 
+        // Application-Specific Link Attributes (Flex-Algo only).
+        let asla = (extended_lsa && !instance.config.flex_algos.is_empty())
+            .then(|| flex_algo::iface_asla(iface));
+
         match iface.config.if_type {
             InterfaceType::PointToPoint | InterfaceType::PointToMultipoint => {
                 // Add a Type-1 link (p2p) for each fully adjacent neighbor.
@@ -477,7 +735,7 @@ fn lsa_orig_router(
                     .iter(&arenas.neighbors)
                     .filter(|nbr| nbr.state == nsm::State::Full)
                 {
-                    let link = LsaRouterLink::new(
+                    let mut link = LsaRouterLink::new(
                         LsaRouterLinkType::PointToPoint,
                         cost,
                         ifindex,
@@ -485,6 +743,9 @@ fn lsa_orig_router(
                         nbr.router_id,
                         nbr.adj_sids.clone(),
                     );
+                    link.asla = asla.clone();
+                    link.srv6_endx_sids =
+                        srv6_endx_sid(nbr, false).into_iter().collect();
                     links.push(link);
                 }
             }
@@ -518,7 +779,7 @@ fn lsa_orig_router(
                     .flat_map(|nbr| nbr.adj_sids.iter())
                     .copied()
                     .collect();
-                let link = LsaRouterLink::new(
+                let mut link = LsaRouterLink::new(
                     LsaRouterLinkType::TransitNetwork,
                     cost,
                     ifindex,
@@ -526,8 +787,18 @@ fn lsa_orig_router(
                     dr_router_id,
                     adj_sids,
                 );
+                link.asla = asla;
+                link.srv6_endx_sids = iface
+                    .state
+                    .neighbors
+                    .iter(&arenas.neighbors)
+                    .filter_map(|nbr| srv6_endx_sid(nbr, true))
+                    .collect();
                 links.push(link);
             }
+            InterfaceType::VirtualLink => {
+                // Virtual links aren't supported in OSPFv3.
+            }
         }
     }
 
@@ -749,6 +1020,8 @@ fn lsa_orig_intra_area_prefix(
                     })
                     .is_some())
         })
+        // Skip interfaces subject to prefix suppression (RFC 6860).
+        .filter(|iface| !iface.prefix_suppression(instance))
         // Get all interface addresses.
         .flat_map(|iface| {
             iface
@@ -793,10 +1066,18 @@ fn lsa_orig_intra_area_prefix(
             )
         };
 
-        // Add Prefix-SID Sub-TLV.
+        // Add Prefix-SID Sub-TLVs.
         if instance.config.sr_enabled {
-            if let Some(prefix_sid) =
-                sr_config.prefix_sids.get(&(prefix, IgpAlgoType::Spf))
+            for (algo, prefix_sid) in sr_config
+                .prefix_sids
+                .get(&(prefix, IgpAlgoType::Spf))
+                .map(|prefix_sid| (IgpAlgoType::Spf, prefix_sid))
+                .into_iter()
+                .chain(
+                    flex_algo::local_prefix_sids(instance.config)
+                        .filter(|(fa_prefix, _, _)| *fa_prefix == prefix)
+                        .map(|(_, algo, prefix_sid)| (algo, prefix_sid)),
+                )
             {
                 let mut flags = PrefixSidFlags::empty();
                 match prefix_sid.last_hop {
@@ -809,7 +1090,6 @@ fn lsa_orig_intra_area_prefix(
                     }
                     SidLastHopBehavior::Php => (),
                 }
-                let algo = IgpAlgoType::Spf;
                 let sid = Sid::Index(prefix_sid.index);
                 entry
                     .prefix_sids
@@ -819,6 +1099,20 @@ fn lsa_orig_intra_area_prefix(
 
         prefixes.push(entry);
     }
+
+    // Local SRv6 locator (algorithm zero only).
+    if let Some(locator) = srv6::locator(instance.config, instance.shared)
+        && locator.algo == IgpAlgoType::Spf
+        && instance.state.af == AddressFamily::Ipv6
+    {
+        let prefix = IpNetwork::V6(locator.prefix);
+        prefixes.push(LsaIntraAreaPrefixEntry::new(
+            PrefixOptions::empty(),
+            prefix,
+            0,
+        ));
+    }
+
     let ref_lsa = LsaKey::new(
         LsaRouter::lsa_type(extended_lsa),
         adv_rtr,
@@ -832,6 +1126,8 @@ fn lsa_orig_intra_area_prefix(
         .iter(&arenas.interfaces)
         // Skip non-DR interfaces.
         .filter(|iface| iface.state.ism_state == ism::State::Dr)
+        // Skip interfaces subject to prefix suppression (RFC 6860).
+        .filter(|iface| !iface.prefix_suppression(instance))
     {
         let mut prefixes = HashMap::new();
         for prefix in iface
@@ -966,9 +1262,13 @@ fn lsa_orig_router_info(
     let mut sr_algo = None;
     let mut srgb = vec![];
     let mut srlb = vec![];
+    let mut fads = vec![];
     if instance.config.sr_enabled {
         // Fill in supported SR algorithms.
-        sr_algo = Some(SrAlgoTlv::new([IgpAlgoType::Spf].into()));
+        sr_algo = Some(SrAlgoTlv::new(flex_algo::sr_algos(instance.config)));
+
+        // Fill in Flexible Algorithm Definitions.
+        fads = flex_algo::local_fads(instance.config);
 
         // Fill in local SRGB.
         for range in &sr_config.srgb {
@@ -988,6 +1288,9 @@ fn lsa_orig_router_info(
     // (Re)originate Router Information LSA.
     let scope = LsaScopeCode::Area;
     let mut info_caps = RouterInfoCaps::STUB_ROUTER;
+    if instance.config.gr.enabled {
+        info_caps.insert(RouterInfoCaps::GR);
+    }
     if instance.config.gr.helper_enabled {
         info_caps.insert(RouterInfoCaps::GR_HELPER);
     }
@@ -995,11 +1298,18 @@ fn lsa_orig_router_info(
         scope,
         info_caps: Some(RouterInfoCapsTlv::new(info_caps)),
         func_caps: None,
+        hostname: lsdb::local_hostname(instance.config),
+        node_tags: lsdb::local_node_tags(instance.config),
         sr_algo,
         srgb,
         srlb,
         msds: None,
         srms_pref: None,
+        fads,
+        srv6_caps: instance
+            .config
+            .srv6_enabled
+            .then(|| Srv6CapsTlv::new(Srv6CapsFlags::empty())),
         unknown_tlvs: vec![],
     });
     instance
@@ -1008,6 +1318,302 @@ fn lsa_orig_router_info(
         .lsa_orig_check(lsdb_id, None, lsa_id, lsa_body);
 }
 
+fn lsa_orig_intra_area_te(
+    area: &Area<Ospfv3>,
+    instance: &InstanceUpView<'_, Ospfv3>,
+    arenas: &InstanceArenas<Ospfv3>,
+) {
+    let lsdb_id = LsdbId::Area(area.id);
+
+    // Originate as many Intra-Area-TE-LSAs as necessary. LSA ID zero is
+    // reserved for the Router IPv6 Address TLV, whereas the Link TLVs use the
+    // subsequent IDs.
+    let mut lsa_id: u32 = 0;
+    let mut originate_fn = |lsa_te: Option<LsaIntraAreaTe>| {
+        // (Re)originate Intra-Area-TE-LSA.
+        if let Some(lsa_te) = lsa_te {
+            let lsa_body = LsaBody::IntraAreaTe(lsa_te);
+            instance.tx.protocol_input.lsa_orig_check(
+                lsdb_id,
+                None,
+                lsa_id.into(),
+                lsa_body,
+            );
+        }
+
+        // Increment the LSA ID.
+        lsa_id += 1;
+    };
+
+    if instance.config.te_enabled {
+        // Router IPv6 Address TLV.
+        let lsa_te =
+            instance
+                .config
+                .te_rid_ipv6
+                .map(|router_addr| LsaIntraAreaTe {
+                    router_addr: Some(router_addr),
+                    ..Default::default()
+                });
+        originate_fn(lsa_te);
+
+        // Link TLVs.
+        for iface in area
+            .interfaces
+            .iter(&arenas.interfaces)
+            // Skip interfaces in the "Down" or "Loopback" states.
+            .filter(|iface| {
+                !matches!(
+                    iface.state.ism_state,
+                    ism::State::Down | ism::State::Loopback,
+                )
+            })
+            // Skip virtual links.
+            .filter(|iface| !iface.is_virtual_link())
+        {
+            let ifindex = iface.system.ifindex.unwrap();
+
+            // Local interface IPv6 addresses.
+            let local_addrs = iface
+                .system
+                .addr_list
+                .iter()
+                .map(|addr| addr.ip())
+                .filter(|addr| match addr {
+                    IpAddr::V4(_) => false,
+                    IpAddr::V6(addr) => !addr.is_unicast_link_local(),
+                })
+                .collect::<Vec<_>>();
+
+            match iface.config.if_type {
+                InterfaceType::PointToPoint
+                | InterfaceType::PointToMultipoint => {
+                    for nbr in iface
+                        .state
+                        .neighbors
+                        .iter(&arenas.neighbors)
+                        .filter(|nbr| nbr.state == nsm::State::Full)
+                    {
+                        let mut link_tlv = iface.te_link_tlv();
+                        link_tlv.link_type = Some(TeLinkType::PointToPoint);
+                        link_tlv.nbr_id = Some(TeNbrId::new(
+                            nbr.iface_id.unwrap(),
+                            nbr.router_id,
+                        ));
+                        link_tlv.local_addrs = local_addrs.clone();
+                        originate_fn(Some(LsaIntraAreaTe {
+                            link: Some(link_tlv),
+                            ..Default::default()
+                        }));
+                    }
+                }
+                InterfaceType::Broadcast | InterfaceType::NonBroadcast => {
+                    let (dr_router_id, dr_iface_id) = if iface.state.ism_state
+                        == ism::State::Dr
+                        && iface
+                            .state
+                            .neighbors
+                            .iter(&arenas.neighbors)
+                            .any(|nbr| nbr.state == nsm::State::Full)
+                    {
+                        // The router itself is the DR.
+                        (instance.state.router_id, ifindex)
+                    } else {
+                        match iface.state.dr.and_then(|net_id| {
+                            iface
+                                .state
+                                .neighbors
+                                .get_by_net_id(&arenas.neighbors, net_id)
+                                .filter(|(_, nbr)| {
+                                    nbr.state == nsm::State::Full
+                                })
+                        }) {
+                            Some((_, nbr)) => {
+                                // The router is fully adjacent to the DR.
+                                (nbr.router_id, nbr.iface_id.unwrap())
+                            }
+                            None => continue,
+                        }
+                    };
+
+                    let mut link_tlv = iface.te_link_tlv();
+                    link_tlv.link_type = Some(TeLinkType::MultiAccess);
+                    link_tlv.nbr_id =
+                        Some(TeNbrId::new(dr_iface_id, dr_router_id));
+                    link_tlv.local_addrs = local_addrs;
+                    originate_fn(Some(LsaIntraAreaTe {
+                        link: Some(link_tlv),
+                        ..Default::default()
+                    }));
+                }
+                InterfaceType::VirtualLink => unreachable!(),
+            }
+        }
+    }
+
+    // Flush self-originated Intra-Area-TE-LSAs that are no longer needed.
+    for (_, lse) in area
+        .state
+        .lsdb
+        .iter_by_type_advrtr(
+            &arenas.lsa_entries,
+            LsaIntraAreaTe::lsa_type(),
+            instance.state.router_id,
+        )
+        .filter(|(_, lse)| {
+            lse.data.hdr.lsa_id >= Ipv4Addr::from(lsa_id)
+                || (lse.data.hdr.lsa_id == Ipv4Addr::from(0)
+                    && instance.config.te_rid_ipv6.is_none())
+        })
+    {
+        lsa_flush(instance, lsdb_id, lse.id);
+    }
+}
+
+fn lsa_orig_srv6_locator(
+    area: &Area<Ospfv3>,
+    instance: &InstanceUpView<'_, Ospfv3>,
+    arenas: &InstanceArenas<Ospfv3>,
+) {
+    let lsdb_id = LsdbId::Area(area.id);
+    let lsa_id = Ipv4Addr::from(0);
+
+    if let Some(locator) = srv6::locator(instance.config, instance.shared) {
+        // Local End SID.
+        let end_sids = instance
+            .state
+            .srv6_end_sid
+            .map(|sid| {
+                Srv6EndSid::new(
+                    Srv6EndpointBehavior::End.to_u16().unwrap(),
+                    sid,
+                    Some(locator.sid_structure()),
+                )
+            })
+            .into_iter()
+            .collect();
+
+        // (Re)originate SRv6 Locator LSA.
+        let locator_tlv = Srv6LocatorTlv::new(
+            Srv6LocatorRouteType::IntraArea,
+            locator.algo,
+            Srv6LocatorFlags::N,
+            0,
+            locator.prefix,
+            end_sids,
+        );
+        let lsa_body = LsaBody::Srv6Locator(LsaSrv6Locator {
+            locators: vec![locator_tlv],
+            unknown_tlvs: vec![],
+        });
+        instance
+            .tx
+            .protocol_input
+            .lsa_orig_check(lsdb_id, None, lsa_id, lsa_body);
+    } else {
+        // Flush SRv6 Locator LSA.
+        let lsa_key = LsaKey::new(
+            LsaSrv6Locator::lsa_type(),
+            instance.state.router_id,
+            lsa_id,
+        );
+        if let Some((_, lse)) =
+            area.state.lsdb.get(&arenas.lsa_entries, &lsa_key)
+        {
+            lsa_flush(instance, lsdb_id, lse.id);
+        }
+    }
+}
+
+fn lsa_orig_as_external(
+    instance: &InstanceUpView<'_, Ospfv3>,
+    prefix: IpNetwork,
+    lsa_id: u32,
+    route: &ExternalRoute,
+) {
+    let lsdb_id = LsdbId::As;
+    let extended_lsa = instance.config.extended_lsa;
+
+    // AS-External-LSA's flags.
+    let mut flags = LsaAsExternalFlags::empty();
+    if route.metric_type == ExternalMetricType::Type2 {
+        flags.insert(LsaAsExternalFlags::E);
+    }
+    if route.fwd_addr.is_some() {
+        flags.insert(LsaAsExternalFlags::F);
+    }
+    if route.tag.is_some() {
+        flags.insert(LsaAsExternalFlags::T);
+    }
+
+    // (Re)originate AS-External-LSA.
+    let lsa_body = LsaBody::AsExternal(LsaAsExternal::new(
+        extended_lsa,
+        flags,
+        route.metric,
+        PrefixOptions::empty(),
+        prefix,
+        route.fwd_addr,
+        route.tag,
+        None,
+        None,
+    ));
+    instance.tx.protocol_input.lsa_orig_check(
+        lsdb_id,
+        None,
+        lsa_id.into(),
+        lsa_body,
+    );
+}
+
+fn lsa_orig_nssa_external(
+    area: &Area<Ospfv3>,
+    instance: &InstanceUpView<'_, Ospfv3>,
+    prefix: IpNetwork,
+    lsa_id: u32,
+    route: &ExternalRoute,
+) {
+    let lsdb_id = LsdbId::Area(area.id);
+    let extended_lsa = instance.config.extended_lsa;
+
+    // NSSA-LSA's flags.
+    let mut flags = LsaAsExternalFlags::empty();
+    if route.metric_type == ExternalMetricType::Type2 {
+        flags.insert(LsaAsExternalFlags::E);
+    }
+    if route.fwd_addr.is_some() {
+        flags.insert(LsaAsExternalFlags::F);
+    }
+    if route.tag.is_some() {
+        flags.insert(LsaAsExternalFlags::T);
+    }
+
+    // NSSA-LSA's prefix options.
+    let mut prefix_options = PrefixOptions::empty();
+    if route.propagate {
+        prefix_options.insert(PrefixOptions::P);
+    }
+
+    // (Re)originate NSSA-LSA.
+    let lsa_body = LsaBody::Nssa(LsaAsExternal::new(
+        extended_lsa,
+        flags,
+        route.metric,
+        prefix_options,
+        prefix,
+        route.fwd_addr,
+        route.tag,
+        None,
+        None,
+    ));
+    instance.tx.protocol_input.lsa_orig_check(
+        lsdb_id,
+        None,
+        lsa_id.into(),
+        lsa_body,
+    );
+}
+
 fn process_self_originated_lsa(
     instance: &InstanceUpView<'_, Ospfv3>,
     arenas: &InstanceArenas<Ospfv3>,
@@ -1062,9 +1668,49 @@ fn process_self_originated_lsa(
             // once SPF runs and the routing table is computed.
         }
         Some(LsaFunctionCode::AsExternal) => {
-            // Flush AS-External-LSA (redistribution of local routes isn't
-            // supported at the moment).
-            flush = true;
+            // Check if the route is still being redistributed.
+            if let Some((prefix, (lsa_id, route))) = instance
+                .state
+                .external_routes
+                .iter()
+                .find(|(_, (lsa_id, _))| {
+                    Ipv4Addr::from(*lsa_id) == lsa.hdr.lsa_id
+                })
+                .filter(|_| {
+                    // Ensure the Router-ID hasn't changed.
+                    lsa.hdr.adv_rtr == instance.state.router_id
+                })
+            {
+                // Reoriginate AS-External-LSA.
+                lsa_orig_as_external(instance, *prefix, *lsa_id, route);
+            } else {
+                // Flush AS-External-LSA.
+                flush = true;
+            }
+        }
+        Some(LsaFunctionCode::Nssa) => {
+            let area_idx = lsdb_idx.into_area().unwrap();
+            let area = &arenas.areas[area_idx];
+
+            // Check if the route is still being redistributed.
+            if let Some((prefix, (lsa_id, route))) = area
+                .state
+                .nssa_external_routes
+                .iter()
+                .find(|(_, (lsa_id, _))| {
+                    Ipv4Addr::from(*lsa_id) == lsa.hdr.lsa_id
+                })
+                .filter(|_| {
+                    // Ensure the Router-ID hasn't changed.
+                    lsa.hdr.adv_rtr == instance.state.router_id
+                })
+            {
+                // Reoriginate NSSA-LSA.
+                lsa_orig_nssa_external(area, instance, *prefix, *lsa_id, route);
+            } else {
+                // Flush NSSA-LSA.
+                flush = true;
+            }
         }
         Some(LsaFunctionCode::Link) => {
             let (area_idx, iface_idx) = lsdb_idx.into_link().unwrap();
@@ -1090,6 +1736,15 @@ fn process_self_originated_lsa(
             // Flush Router-Information-LSA.
             flush = true;
         }
+        Some(LsaFunctionCode::Srv6Locator)
+            if lsa.hdr.lsa_id == Ipv4Addr::from(0) =>
+        {
+            let area_idx = lsdb_idx.into_area().unwrap();
+            let area = &arenas.areas[area_idx];
+
+            // Reoriginate or flush SRv6 Locator LSA.
+            lsa_orig_srv6_locator(area, instance, arenas);
+        }
         _ => {
             // Flush unknown LSA.
             flush = true;
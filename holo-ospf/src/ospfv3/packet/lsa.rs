@@ -14,10 +14,10 @@ use enum_as_inner::EnumAsInner;
 use holo_utils::bytes::{BytesExt, BytesMutExt};
 use holo_utils::ip::{AddressFamily, IpAddrExt, Ipv4AddrExt, Ipv6AddrExt};
 use holo_utils::mpls::Label;
-use holo_utils::sr::{IgpAlgoType, Sid};
-use ipnetwork::IpNetwork;
+use holo_utils::sr::{IgpAlgoType, Sid, Srv6SidStructure};
+use ipnetwork::{IpNetwork, Ipv6Network};
 use num_derive::{FromPrimitive, ToPrimitive};
-use num_traits::FromPrimitive;
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::ospfv3::packet::Options;
@@ -30,10 +30,12 @@ use crate::packet::lsa::{
     PrefixSidVersion,
 };
 use crate::packet::tlv::{
-    tlv_encode_end, tlv_encode_start, tlv_wire_len, AdjSidFlags, GrReason,
-    GrReasonTlv, GracePeriodTlv, MsdTlv, PrefixSidFlags, RouterFuncCapsTlv,
+    tlv_encode_end, tlv_encode_start, tlv_wire_len, AdjSidFlags, AslaTlv,
+    DynamicHostnameTlv, FlexAlgoDefTlv, GrReason, GrReasonTlv, GracePeriodTlv,
+    MsdTlv, NodeAdminTagTlv, PrefixSidFlags, RouterFuncCapsTlv,
     RouterInfoCapsTlv, RouterInfoTlvType, SidLabelRangeTlv, SrAlgoTlv,
-    SrLocalBlockTlv, SrmsPrefTlv, UnknownTlv, TLV_HDR_SIZE,
+    SrLocalBlockTlv, SrmsPrefTlv, Srv6CapsTlv, TeLinkTlv, TeTlvType,
+    UnknownTlv, TLV_HDR_SIZE,
 };
 use crate::version::Ospfv3;
 
@@ -86,6 +88,7 @@ pub enum LsaFunctionCode {
     InterAreaPrefix = 3,
     InterAreaRouter = 4,
     AsExternal = 5,
+    Nssa = 7,
     Link = 8,
     IntraAreaPrefix = 9,
     // Extended LSA Types
@@ -94,11 +97,14 @@ pub enum LsaFunctionCode {
     ExtInterAreaPrefix = 35,
     ExtInterAreaRouter = 36,
     ExtAsExternal = 37,
+    ExtNssa = 39,
     ExtLink = 40,
     ExtIntraAreaPrefix = 41,
     // Other LSA types
+    IntraAreaTe = 10,
     Grace = 11,
     RouterInfo = 12,
+    Srv6Locator = 42,
 }
 
 //
@@ -147,10 +153,13 @@ pub enum LsaBody {
     InterAreaPrefix(LsaInterAreaPrefix),
     InterAreaRouter(LsaInterAreaRouter),
     AsExternal(LsaAsExternal),
+    Nssa(LsaAsExternal),
     Link(LsaLink),
     IntraAreaPrefix(LsaIntraAreaPrefix),
+    IntraAreaTe(LsaIntraAreaTe),
     Grace(LsaGrace),
     RouterInfo(LsaRouterInfo),
+    Srv6Locator(LsaSrv6Locator),
     Unknown(LsaUnknown),
 }
 
@@ -188,6 +197,10 @@ pub enum ExtLsaSubTlv {
     LanAdjSid = 6,
     SidLabel = 7,
     LinkMsd = 9,
+    Asla = 11,
+    Srv6SidStructure = 30,
+    Srv6EndXSid = 31,
+    Srv6LanEndXSid = 32,
 }
 
 // OSPFv3 Extended-LSA Sub-TLVs.
@@ -242,6 +255,9 @@ pub struct ExtLsaSubTlvs {
     pub route_tag: Option<u32>,
     pub prefix_sids: BTreeMap<IgpAlgoType, PrefixSid>,
     pub adj_sids: Vec<AdjSid>,
+    pub asla: Option<AslaTlv>,
+    pub srv6_endx_sids: Vec<Srv6EndXSid>,
+    pub srv6_sid_structure: Option<Srv6SidStructure>,
     pub unknown: Vec<UnknownTlv>,
 }
 
@@ -308,6 +324,75 @@ pub struct AdjSid {
     pub sid: Sid,
 }
 
+// SRv6 End.X SID flags.
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Srv6EndXSidFlags: u8 {
+        const B = 0x80;
+        const S = 0x40;
+        const P = 0x20;
+    }
+}
+
+//
+// SRv6 End.X SID Sub-TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |      Endpoint Behavior        |     Flags     |   Reserved    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |   Algorithm   |    Weight     |           Reserved            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                   SID (128 bits) . . .                        |
+// |                                                               |
+// |                                                               |
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                Sub-TLVs (variable) . . .                      |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+// SRv6 LAN End.X SID Sub-TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |      Endpoint Behavior        |     Flags     |   Reserved    |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |   Algorithm   |    Weight     |           Reserved            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                 OSPFv3 Router-ID of neighbor                  |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                   SID (128 bits) . . .                        |
+// |                                                               |
+// |                                                               |
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                Sub-TLVs (variable) . . .                      |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Copy, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6EndXSid {
+    pub behavior: u16,
+    pub flags: Srv6EndXSidFlags,
+    pub algo: IgpAlgoType,
+    pub weight: u8,
+    pub nbr_router_id: Option<Ipv4Addr>,
+    pub sid: Ipv6Addr,
+    pub sid_structure: Option<Srv6SidStructure>,
+}
+
 //
 // OSPFv3 Router-LSA.
 //
@@ -421,6 +506,14 @@ pub struct LsaRouterLink {
     pub nbr_router_id: Ipv4Addr,
     pub adj_sids: Vec<AdjSid>,
     #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asla: Option<AslaTlv>,
+    #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub srv6_endx_sids: Vec<Srv6EndXSid>,
+    #[new(default)]
     pub unknown_stlvs: Vec<UnknownTlv>,
 }
 
@@ -629,6 +722,8 @@ pub struct LsaInterAreaRouter {
 // |               Referenced Link State ID (Optional)             |
 // +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
 //
+// NSSA-LSAs have the same format as AS-External-LSAs.
+//
 // OSPFv3 E-AS-External-LSA.
 //
 // Encoding format (LSA body):
@@ -662,6 +757,8 @@ pub struct LsaInterAreaRouter {
 // .                                                               .
 // +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
 //
+// E-NSSA-LSAs have the same format as E-AS-External-LSAs.
+//
 #[derive(Clone, Debug, Eq, new, PartialEq)]
 #[derive(Deserialize, Serialize)]
 pub struct LsaAsExternal {
@@ -889,6 +986,29 @@ pub struct LsaIntraAreaPrefixEntry {
     pub unknown_stlvs: Vec<UnknownTlv>,
 }
 
+//
+// OSPFv3 Intra-Area-TE-LSA.
+//
+// Encoding format (LSA body):
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                                                               |
+// +-                            TLVs                             -+
+// |                             ...                               |
+//
+// An LSA contains one top-level TLV: either a Router IPv6 Address TLV or a
+// Link TLV.
+//
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsaIntraAreaTe {
+    pub router_addr: Option<Ipv6Addr>,
+    pub link: Option<TeLinkTlv>,
+    pub unknown_tlvs: Vec<UnknownTlv>,
+}
+
 // OSPFv3 Grace LSA Top Level TLV types.
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 #[derive(FromPrimitive, ToPrimitive)]
@@ -939,6 +1059,14 @@ pub struct LsaRouterInfo {
     #[new(default)]
     pub func_caps: Option<RouterFuncCapsTlv>,
     #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<DynamicHostnameTlv>,
+    #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub node_tags: Vec<NodeAdminTagTlv>,
+    #[new(default)]
     pub sr_algo: Option<SrAlgoTlv>,
     #[new(default)]
     pub srgb: Vec<SidLabelRangeTlv>,
@@ -949,9 +1077,142 @@ pub struct LsaRouterInfo {
     #[new(default)]
     pub srms_pref: Option<SrmsPrefTlv>,
     #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fads: Vec<FlexAlgoDefTlv>,
+    #[new(default)]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub srv6_caps: Option<Srv6CapsTlv>,
+    #[new(default)]
     pub unknown_tlvs: Vec<UnknownTlv>,
 }
 
+// OSPFv3 SRv6 Locator LSA TLV types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/ospfv3-parameters/ospfv3-parameters.xhtml#srv6-locator-lsa-tlvs
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum Srv6LocatorLsaTlv {
+    Locator = 1,
+}
+
+// OSPFv3 SRv6 Locator LSA Sub-TLV types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/ospfv3-parameters/ospfv3-parameters.xhtml#srv6-locator-lsa-sub-tlvs
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum Srv6LocatorLsaSubTlv {
+    EndSid = 1,
+}
+
+//
+// OSPFv3 SRv6 Locator LSA.
+//
+// Encoding format (LSA body):
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                                                               |
+// +-                            TLVs                             -+
+// |                             ...                               |
+//
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsaSrv6Locator {
+    pub locators: Vec<Srv6LocatorTlv>,
+    pub unknown_tlvs: Vec<UnknownTlv>,
+}
+
+// SRv6 Locator route types.
+#[derive(Clone, Copy, Debug, Eq, FromPrimitive, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum Srv6LocatorRouteType {
+    IntraArea = 1,
+    InterArea = 2,
+    External = 3,
+    NssaExternal = 4,
+}
+
+// SRv6 Locator flags.
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Srv6LocatorFlags: u8 {
+        const N = 0x80;
+        const A = 0x40;
+    }
+}
+
+//
+// SRv6 Locator TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |  Route Type   |   Algorithm   | Locator Length|     Flags     |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                            Metric                             |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                       Locator (128 bits)                      |
+// |                                                               |
+// |                                                               |
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                     Sub-TLVs (variable)                       |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6LocatorTlv {
+    pub route_type: Srv6LocatorRouteType,
+    pub algo: IgpAlgoType,
+    pub flags: Srv6LocatorFlags,
+    pub metric: u32,
+    pub locator: Ipv6Network,
+    pub end_sids: Vec<Srv6EndSid>,
+    #[new(default)]
+    pub unknown_stlvs: Vec<UnknownTlv>,
+}
+
+//
+// SRv6 End SID Sub-TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |     Flags     |   Reserved    |      Endpoint Behavior        |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                     SID (128 bits) . . .                      |
+// |                                                               |
+// |                                                               |
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                   Sub-TLVs (variable) . . .                   |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Copy, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6EndSid {
+    pub behavior: u16,
+    pub sid: Ipv6Addr,
+    pub sid_structure: Option<Srv6SidStructure>,
+}
+
 //
 // OSPFv3 Unknown LSA.
 //
@@ -1006,6 +1267,7 @@ impl LsaTypeVersion for LsaType {
                     | LsaFunctionCode::InterAreaPrefix
                     | LsaFunctionCode::InterAreaRouter
                     | LsaFunctionCode::AsExternal
+                    | LsaFunctionCode::Nssa
             )
         )
     }
@@ -1037,6 +1299,7 @@ impl LsaFunctionCode {
                 LsaFunctionCode::InterAreaRouter
             }
             LsaFunctionCode::ExtAsExternal => LsaFunctionCode::AsExternal,
+            LsaFunctionCode::ExtNssa => LsaFunctionCode::Nssa,
             LsaFunctionCode::ExtLink => LsaFunctionCode::Link,
             LsaFunctionCode::ExtIntraAreaPrefix => {
                 LsaFunctionCode::IntraAreaPrefix
@@ -1200,6 +1463,10 @@ impl LsaBody {
         self.as_as_external().filter(|lsa_body| lsa_body.extended)
     }
 
+    pub(crate) fn as_std_nssa(&self) -> Option<&LsaAsExternal> {
+        self.as_nssa().filter(|lsa_body| !lsa_body.extended)
+    }
+
     pub(crate) fn as_std_link(&self) -> Option<&LsaLink> {
         self.as_link().filter(|lsa_body| !lsa_body.extended)
     }
@@ -1248,6 +1515,9 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
             Some(LsaFunctionCode::AsExternal) => {
                 LsaBody::AsExternal(LsaAsExternal::decode_legacy(af, buf)?)
             }
+            Some(LsaFunctionCode::Nssa) => {
+                LsaBody::Nssa(LsaAsExternal::decode_legacy(af, buf)?)
+            }
             Some(LsaFunctionCode::Link) => {
                 LsaBody::Link(LsaLink::decode_legacy(af, buf)?)
             }
@@ -1274,6 +1544,9 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
             Some(LsaFunctionCode::ExtAsExternal) => {
                 LsaBody::AsExternal(LsaAsExternal::decode_extended(af, buf)?)
             }
+            Some(LsaFunctionCode::ExtNssa) => {
+                LsaBody::Nssa(LsaAsExternal::decode_extended(af, buf)?)
+            }
             Some(LsaFunctionCode::ExtLink) => {
                 LsaBody::Link(LsaLink::decode_extended(af, buf)?)
             }
@@ -1283,12 +1556,18 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
                 )?)
             }
             // Other LSA types
+            Some(LsaFunctionCode::IntraAreaTe) => {
+                LsaBody::IntraAreaTe(LsaIntraAreaTe::decode(buf)?)
+            }
             Some(LsaFunctionCode::Grace) => {
                 LsaBody::Grace(LsaGrace::decode(buf)?)
             }
             Some(LsaFunctionCode::RouterInfo) => {
                 LsaBody::RouterInfo(LsaRouterInfo::decode(lsa_scope, buf)?)
             }
+            Some(LsaFunctionCode::Srv6Locator) => {
+                LsaBody::Srv6Locator(LsaSrv6Locator::decode(buf)?)
+            }
             None => LsaBody::Unknown(LsaUnknown::decode(buf)?),
         };
 
@@ -1302,10 +1581,13 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
             LsaBody::InterAreaPrefix(lsa) => lsa.encode(buf),
             LsaBody::InterAreaRouter(lsa) => lsa.encode(buf),
             LsaBody::AsExternal(lsa) => lsa.encode(buf),
+            LsaBody::Nssa(lsa) => lsa.encode(buf),
             LsaBody::Link(lsa) => lsa.encode(buf),
             LsaBody::IntraAreaPrefix(lsa) => lsa.encode(buf),
+            LsaBody::IntraAreaTe(lsa) => lsa.encode(buf),
             LsaBody::Grace(lsa) => lsa.encode(buf),
             LsaBody::RouterInfo(lsa) => lsa.encode(buf),
+            LsaBody::Srv6Locator(lsa) => lsa.encode(buf),
             LsaBody::Unknown(lsa) => lsa.encode(buf),
         }
     }
@@ -1321,12 +1603,15 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
                 LsaInterAreaRouter::lsa_type(lsa.extended)
             }
             LsaBody::AsExternal(lsa) => LsaAsExternal::lsa_type(lsa.extended),
+            LsaBody::Nssa(lsa) => LsaAsExternal::nssa_lsa_type(lsa.extended),
             LsaBody::Link(lsa) => LsaLink::lsa_type(lsa.extended),
             LsaBody::IntraAreaPrefix(lsa) => {
                 LsaIntraAreaPrefix::lsa_type(lsa.extended)
             }
+            LsaBody::IntraAreaTe(_) => LsaIntraAreaTe::lsa_type(),
             LsaBody::Grace(_) => LsaGrace::lsa_type(),
             LsaBody::RouterInfo(lsa) => lsa.lsa_type(),
+            LsaBody::Srv6Locator(_) => LsaSrv6Locator::lsa_type(),
             LsaBody::Unknown(_) => LsaUnknown::lsa_type(),
         }
     }
@@ -1335,6 +1620,10 @@ impl LsaBodyVersion<Ospfv3> for LsaBody {
         matches!(self, LsaBody::Unknown(_))
     }
 
+    fn is_non_default_external(&self) -> bool {
+        matches!(self, LsaBody::AsExternal(lsa) if lsa.prefix.prefix() != 0)
+    }
+
     fn validate(&self, _hdr: &LsaHdr) -> Result<(), LsaValidationError> {
         Ok(())
     }
@@ -1467,6 +1756,10 @@ impl LsaRouterFlagsVersion for LsaRouterFlags {
     fn is_asbr(&self) -> bool {
         self.contains(LsaRouterFlags::E)
     }
+
+    fn is_nssa_translator(&self) -> bool {
+        self.contains(LsaRouterFlags::NT)
+    }
 }
 
 // ===== impl LsaRouterLink =====
@@ -1500,6 +1793,8 @@ impl LsaRouterLink {
         if extended {
             let stlvs = ExtLsaSubTlvs::decode(buf)?;
             link.adj_sids = stlvs.adj_sids;
+            link.asla = stlvs.asla;
+            link.srv6_endx_sids = stlvs.srv6_endx_sids;
             link.unknown_stlvs = stlvs.unknown;
         }
 
@@ -1521,6 +1816,8 @@ impl LsaRouterLink {
     fn sub_tlvs(&self) -> ExtLsaSubTlvs {
         ExtLsaSubTlvs {
             adj_sids: self.adj_sids.clone(),
+            asla: self.asla.clone(),
+            srv6_endx_sids: self.srv6_endx_sids.clone(),
             ..Default::default()
         }
     }
@@ -2027,8 +2324,8 @@ impl LsaAsExternal {
             }
         }
 
-        // If the External-Prefix TLV is not included in the E-External-AS-LSA,
-        // it is treated as malformed.
+        // If the External-Prefix TLV is not included in the E-External-AS-LSA
+        // or E-NSSA-LSA, it is treated as malformed.
         match ext {
             Some(mut ext) => {
                 ext.unknown_tlvs = unknown_tlvs;
@@ -2108,6 +2405,20 @@ impl LsaAsExternal {
             }
         }
     }
+
+    pub(crate) const fn nssa_lsa_type(extended: bool) -> LsaType {
+        let scope = LsaScopeCode::Area;
+        match extended {
+            true => {
+                let function_code = LsaFunctionCode::ExtNssa;
+                LsaType(U_BIT | scope as u16 | function_code as u16)
+            }
+            false => {
+                let function_code = LsaFunctionCode::Nssa;
+                LsaType(scope as u16 | function_code as u16)
+            }
+        }
+    }
 }
 
 // ===== impl LsaLink =====
@@ -2461,6 +2772,68 @@ impl LsaIntraAreaPrefixEntry {
     }
 }
 
+// ===== impl LsaIntraAreaTe =====
+
+impl LsaIntraAreaTe {
+    fn decode(buf: &mut Bytes) -> DecodeResult<Self> {
+        let mut lsa = LsaIntraAreaTe::default();
+
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse TLV type.
+            let tlv_type = buf.get_u16();
+            let tlv_etype = TeTlvType::from_u16(tlv_type);
+
+            // Parse and validate TLV length.
+            let tlv_len = buf.get_u16();
+            let tlv_wlen = tlv_wire_len(tlv_len);
+            if tlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(tlv_len));
+            }
+
+            // Parse TLV value.
+            let mut buf_tlv = buf.copy_to_bytes(tlv_wlen as usize);
+            match tlv_etype {
+                Some(TeTlvType::RouterIpv6Addr) => {
+                    if tlv_len != 16 {
+                        return Err(DecodeError::InvalidTlvLength(tlv_len));
+                    }
+                    let addr = buf_tlv.get_ipv6();
+                    lsa.router_addr.get_or_insert(addr);
+                }
+                Some(TeTlvType::Link) => {
+                    let link_tlv = TeLinkTlv::decode(tlv_len, &mut buf_tlv)?;
+                    lsa.link.get_or_insert(link_tlv);
+                }
+                _ => {
+                    // Save unknown TLV.
+                    let value = buf_tlv.copy_to_bytes(tlv_len as usize);
+                    lsa.unknown_tlvs
+                        .push(UnknownTlv::new(tlv_type, tlv_len, value));
+                }
+            }
+        }
+
+        Ok(lsa)
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        if let Some(router_addr) = &self.router_addr {
+            let start_pos = tlv_encode_start(buf, TeTlvType::RouterIpv6Addr);
+            buf.put_ipv6(router_addr);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(link_tlv) = &self.link {
+            link_tlv.encode(buf);
+        }
+    }
+
+    pub(crate) const fn lsa_type() -> LsaType {
+        let scope = LsaScopeCode::Area;
+        let function_code = LsaFunctionCode::IntraAreaTe;
+        LsaType(U_BIT | scope as u16 | function_code as u16)
+    }
+}
+
 // ===== impl LsaGrace =====
 
 impl LsaGrace {
@@ -2550,6 +2923,16 @@ impl LsaRouterInfo {
                         RouterFuncCapsTlv::decode(tlv_len, &mut buf_tlv)?;
                     router_info.func_caps.get_or_insert(caps);
                 }
+                Some(RouterInfoTlvType::DynamicHostname) => {
+                    let hostname =
+                        DynamicHostnameTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.hostname.get_or_insert(hostname);
+                }
+                Some(RouterInfoTlvType::NodeAdminTag) => {
+                    let node_tags =
+                        NodeAdminTagTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.node_tags.push(node_tags);
+                }
                 Some(RouterInfoTlvType::SrAlgo) => {
                     let sr_algo = SrAlgoTlv::decode(tlv_len, &mut buf_tlv)?;
                     router_info.sr_algo.get_or_insert(sr_algo);
@@ -2570,6 +2953,14 @@ impl LsaRouterInfo {
                     let srms_pref = SrmsPrefTlv::decode(tlv_len, &mut buf_tlv)?;
                     router_info.srms_pref.get_or_insert(srms_pref);
                 }
+                Some(RouterInfoTlvType::FlexAlgoDef) => {
+                    let fad = FlexAlgoDefTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.fads.push(fad);
+                }
+                Some(RouterInfoTlvType::Srv6Caps) => {
+                    let srv6_caps = Srv6CapsTlv::decode(tlv_len, &mut buf_tlv)?;
+                    router_info.srv6_caps.get_or_insert(srv6_caps);
+                }
                 _ => {
                     // Save unknown TLV.
                     let value = buf_tlv.copy_to_bytes(tlv_len as usize);
@@ -2590,6 +2981,12 @@ impl LsaRouterInfo {
         if let Some(func_caps) = &self.func_caps {
             func_caps.encode(buf);
         }
+        if let Some(hostname) = &self.hostname {
+            hostname.encode(buf);
+        }
+        for node_tags in &self.node_tags {
+            node_tags.encode(buf);
+        }
         if let Some(sr_algo) = &self.sr_algo {
             sr_algo.encode(buf);
         }
@@ -2605,6 +3002,12 @@ impl LsaRouterInfo {
         if let Some(srms_pref) = &self.srms_pref {
             srms_pref.encode(buf);
         }
+        for fad in &self.fads {
+            fad.encode(buf);
+        }
+        if let Some(srv6_caps) = &self.srv6_caps {
+            srv6_caps.encode(buf);
+        }
     }
 
     pub(crate) const fn lsa_type(&self) -> LsaType {
@@ -2618,6 +3021,163 @@ impl LsaRouterInfo {
     }
 }
 
+// ===== impl LsaSrv6Locator =====
+
+impl LsaSrv6Locator {
+    fn decode(buf: &mut Bytes) -> DecodeResult<Self> {
+        let mut lsa = LsaSrv6Locator::default();
+
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse TLV type.
+            let tlv_type = buf.get_u16();
+            let tlv_etype = Srv6LocatorLsaTlv::from_u16(tlv_type);
+
+            // Parse and validate TLV length.
+            let tlv_len = buf.get_u16();
+            let tlv_wlen = tlv_wire_len(tlv_len);
+            if tlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(tlv_len));
+            }
+
+            // Parse TLV value.
+            let mut buf_tlv = buf.copy_to_bytes(tlv_wlen as usize);
+            match tlv_etype {
+                Some(Srv6LocatorLsaTlv::Locator) => {
+                    if let Some(locator) =
+                        Srv6LocatorTlv::decode(tlv_len, &mut buf_tlv)?
+                    {
+                        lsa.locators.push(locator);
+                    }
+                }
+                _ => {
+                    // Save unknown TLV.
+                    let value = buf_tlv.copy_to_bytes(tlv_len as usize);
+                    lsa.unknown_tlvs
+                        .push(UnknownTlv::new(tlv_type, tlv_len, value));
+                }
+            }
+        }
+
+        Ok(lsa)
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        for locator in &self.locators {
+            locator.encode(buf);
+        }
+    }
+
+    pub(crate) const fn lsa_type() -> LsaType {
+        let scope = LsaScopeCode::Area;
+        let function_code = LsaFunctionCode::Srv6Locator;
+        LsaType(U_BIT | scope as u16 | function_code as u16)
+    }
+}
+
+// ===== impl Srv6LocatorTlv =====
+
+impl Srv6LocatorTlv {
+    const BASE_LENGTH: u16 = 24;
+
+    // Decodes the SRv6 Locator TLV, returning `None` if it contains an unknown
+    // route type or algorithm.
+    fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Option<Self>> {
+        // Validate minimum TLV length.
+        if tlv_len < Self::BASE_LENGTH {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let route_type = buf.get_u8();
+        let algo = buf.get_u8();
+        let plen = buf.get_u8();
+        let flags = Srv6LocatorFlags::from_bits_truncate(buf.get_u8());
+        let metric = buf.get_u32();
+        let addr = buf.get_ipv6();
+        let locator = Ipv6Network::new(addr, plen)
+            .map_err(|_| DecodeError::InvalidIpPrefix)?;
+        let (Some(route_type), Some(algo)) = (
+            Srv6LocatorRouteType::from_u8(route_type),
+            IgpAlgoType::from_u8(algo),
+        ) else {
+            return Ok(None);
+        };
+        let mut locator = Srv6LocatorTlv::new(
+            route_type,
+            algo,
+            flags,
+            metric,
+            locator,
+            vec![],
+        );
+
+        // Parse Sub-TLVs.
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse Sub-TLV type.
+            let stlv_type = buf.get_u16();
+            let stlv_etype = Srv6LocatorLsaSubTlv::from_u16(stlv_type);
+
+            // Parse and validate Sub-TLV length.
+            let stlv_len = buf.get_u16();
+            let stlv_wlen = tlv_wire_len(stlv_len);
+            if stlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_wlen as usize);
+            match stlv_etype {
+                Some(Srv6LocatorLsaSubTlv::EndSid) => {
+                    if stlv_len < 20 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let _flags = buf_stlv.get_u8();
+                    let _reserved = buf_stlv.get_u8();
+                    let behavior = buf_stlv.get_u16();
+                    let sid = buf_stlv.get_ipv6();
+                    let stlvs = ExtLsaSubTlvs::decode(&mut buf_stlv)?;
+                    let end_sid = Srv6EndSid::new(
+                        behavior,
+                        sid,
+                        stlvs.srv6_sid_structure,
+                    );
+                    locator.end_sids.push(end_sid);
+                }
+                _ => {
+                    // Save unknown Sub-TLV.
+                    let value = buf_stlv.copy_to_bytes(stlv_len as usize);
+                    locator
+                        .unknown_stlvs
+                        .push(UnknownTlv::new(stlv_type, stlv_len, value));
+                }
+            }
+        }
+
+        Ok(Some(locator))
+    }
+
+    fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, Srv6LocatorLsaTlv::Locator);
+        buf.put_u8(self.route_type as u8);
+        buf.put_u8(self.algo.to_u8().unwrap());
+        buf.put_u8(self.locator.prefix());
+        buf.put_u8(self.flags.bits());
+        buf.put_u32(self.metric);
+        buf.put_ipv6(&self.locator.network());
+        for end_sid in &self.end_sids {
+            let stlv_pos = tlv_encode_start(buf, Srv6LocatorLsaSubTlv::EndSid);
+            buf.put_u8(0);
+            buf.put_u8(0);
+            buf.put_u16(end_sid.behavior);
+            buf.put_ipv6(&end_sid.sid);
+            if let Some(sid_structure) = &end_sid.sid_structure {
+                srv6_sid_structure_encode(sid_structure, buf);
+            }
+            tlv_encode_end(buf, stlv_pos);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
 // ===== impl LsaUnknown =====
 
 impl LsaUnknown {
@@ -2732,6 +3292,63 @@ impl ExtLsaSubTlvs {
                         AdjSid::new(flags, weight, nbr_router_id, sid);
                     stlvs.adj_sids.push(adj_sid);
                 }
+                Some(ExtLsaSubTlv::Asla) => {
+                    let asla = AslaTlv::decode(tlv_len, &mut buf_value)?;
+                    stlvs.asla.get_or_insert(asla);
+                }
+                Some(ExtLsaSubTlv::Srv6SidStructure) => {
+                    if tlv_len != 4 {
+                        return Err(DecodeError::InvalidTlvLength(tlv_len));
+                    }
+                    let sid_structure = Srv6SidStructure::new(
+                        buf_value.get_u8(),
+                        buf_value.get_u8(),
+                        buf_value.get_u8(),
+                        buf_value.get_u8(),
+                    );
+                    stlvs.srv6_sid_structure.get_or_insert(sid_structure);
+                }
+                Some(
+                    ExtLsaSubTlv::Srv6EndXSid | ExtLsaSubTlv::Srv6LanEndXSid,
+                ) => {
+                    let lan = tlv_etype == Some(ExtLsaSubTlv::Srv6LanEndXSid);
+                    let min_len = if lan { 28 } else { 24 };
+                    if tlv_len < min_len {
+                        return Err(DecodeError::InvalidTlvLength(tlv_len));
+                    }
+                    let behavior = buf_value.get_u16();
+                    let flags = Srv6EndXSidFlags::from_bits_truncate(
+                        buf_value.get_u8(),
+                    );
+                    let _reserved = buf_value.get_u8();
+                    let algo = buf_value.get_u8();
+                    let weight = buf_value.get_u8();
+                    let _reserved = buf_value.get_u16();
+
+                    // Parse Neighbor ID (LAN End.X SID only).
+                    let nbr_router_id = lan.then(|| buf_value.get_ipv4());
+
+                    let sid = buf_value.get_ipv6();
+                    let sub_stlvs = ExtLsaSubTlvs::decode(&mut buf_value)?;
+                    let algo = match IgpAlgoType::from_u8(algo) {
+                        Some(algo) => algo,
+                        None => {
+                            // Unsupported algorithm - ignore.
+                            continue;
+                        }
+                    };
+
+                    let endx_sid = Srv6EndXSid::new(
+                        behavior,
+                        flags,
+                        algo,
+                        weight,
+                        nbr_router_id,
+                        sid,
+                        sub_stlvs.srv6_sid_structure,
+                    );
+                    stlvs.srv6_endx_sids.push(endx_sid);
+                }
                 _ => {
                     // Save unknown Sub-TLV.
                     let value = buf_value.copy_to_bytes(tlv_len as usize);
@@ -2764,7 +3381,7 @@ impl ExtLsaSubTlvs {
         for (algo, prefix_sid) in &self.prefix_sids {
             let start_pos = tlv_encode_start(buf, ExtLsaSubTlv::PrefixSid);
             buf.put_u8(prefix_sid.flags.bits());
-            buf.put_u8(*algo as u8);
+            buf.put_u8(algo.to_u8().unwrap());
             buf.put_u16(0);
             match prefix_sid.sid {
                 Sid::Index(index) => buf.put_u32(index),
@@ -2790,6 +3407,30 @@ impl ExtLsaSubTlvs {
             }
             tlv_encode_end(buf, start_pos);
         }
+        if let Some(asla) = &self.asla {
+            asla.encode(ExtLsaSubTlv::Asla as u16, buf);
+        }
+        for endx_sid in &self.srv6_endx_sids {
+            let stlv_type = match endx_sid.nbr_router_id.is_some() {
+                true => ExtLsaSubTlv::Srv6LanEndXSid,
+                false => ExtLsaSubTlv::Srv6EndXSid,
+            };
+            let start_pos = tlv_encode_start(buf, stlv_type);
+            buf.put_u16(endx_sid.behavior);
+            buf.put_u8(endx_sid.flags.bits());
+            buf.put_u8(0);
+            buf.put_u8(endx_sid.algo.to_u8().unwrap());
+            buf.put_u8(endx_sid.weight);
+            buf.put_u16(0);
+            if let Some(nbr_router_id) = &endx_sid.nbr_router_id {
+                buf.put_ipv4(nbr_router_id);
+            }
+            buf.put_ipv6(&endx_sid.sid);
+            if let Some(sid_structure) = &endx_sid.sid_structure {
+                srv6_sid_structure_encode(sid_structure, buf);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
     }
 }
 
@@ -2849,6 +3490,14 @@ impl LsaVersion<Self> for Ospfv3 {
     fn type4_summary(extended_lsa: bool) -> LsaType {
         LsaInterAreaRouter::lsa_type(extended_lsa)
     }
+
+    fn type5_external(extended_lsa: bool) -> LsaType {
+        LsaAsExternal::lsa_type(extended_lsa)
+    }
+
+    fn type7_nssa(extended_lsa: bool) -> LsaType {
+        LsaAsExternal::nssa_lsa_type(extended_lsa)
+    }
 }
 
 // ===== global functions =====
@@ -2906,6 +3555,18 @@ fn decode_prefix(
     IpNetwork::new(prefix, plen).map_err(|_| DecodeError::InvalidIpPrefix)
 }
 
+fn srv6_sid_structure_encode(
+    sid_structure: &Srv6SidStructure,
+    buf: &mut BytesMut,
+) {
+    let start_pos = tlv_encode_start(buf, ExtLsaSubTlv::Srv6SidStructure);
+    buf.put_u8(sid_structure.lb_len);
+    buf.put_u8(sid_structure.ln_len);
+    buf.put_u8(sid_structure.fun_len);
+    buf.put_u8(sid_structure.arg_len);
+    tlv_encode_end(buf, start_pos);
+}
+
 fn encode_prefix(prefix: &IpNetwork, buf: &mut BytesMut) {
     let prefix_bytes = prefix.ip().bytes();
     let plen_wire = prefix_wire_len(prefix.prefix());
@@ -262,6 +262,10 @@ impl OptionsVersion<Ospfv3> for Options {
     fn e_bit(&self) -> bool {
         self.contains(Options::E)
     }
+
+    fn n_bit(&self) -> bool {
+        self.contains(Options::N)
+    }
 }
 
 // ===== impl PacketHdr =====
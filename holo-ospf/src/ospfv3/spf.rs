@@ -4,12 +4,13 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::net::{IpAddr, Ipv4Addr};
 use std::sync::Arc;
 
 use enum_as_inner::EnumAsInner;
 use holo_utils::ip::AddressFamily;
+use holo_utils::mpls::Label;
 
 use crate::area::Area;
 use crate::collections::{Arena, Lsdb};
@@ -25,6 +26,7 @@ use crate::ospfv3::packet::lsa::{
 };
 use crate::ospfv3::packet::Options;
 use crate::packet::lsa::{Lsa, LsaHdrVersion, LsaKey};
+use crate::packet::tlv::{AdjSidFlags, AslaTlv};
 use crate::route::{Nexthop, NexthopKey, Nexthops};
 use crate::spf::{
     SpfComputation, SpfExternalNetwork, SpfInterAreaNetwork,
@@ -102,26 +104,43 @@ impl SpfVersion<Self> for Ospfv3 {
     fn spf_computation_type(
         trigger_lsas: &[SpfTriggerLsa<Self>],
     ) -> SpfComputation<Self> {
-        // Router-LSA and Network-LSA changes represent topological changes,
-        // hence a full SPF run is required to recompute the SPT.
-        //
         // Link-LSA and Router Information LSA changes don't strictly require a
         // full SPF run, but doing so greatly simplify things (e.g. no need to
         // keep track of which routes are affected by which SRGBs).
         if trigger_lsas.iter().map(|tlsa| &tlsa.new).any(|lsa| {
             matches!(
                 lsa.hdr.lsa_type.function_code_normalized(),
-                Some(
-                    LsaFunctionCode::Router
-                        | LsaFunctionCode::Network
-                        | LsaFunctionCode::Link
-                        | LsaFunctionCode::RouterInfo
-                )
+                Some(LsaFunctionCode::Link | LsaFunctionCode::RouterInfo)
             )
         }) {
             return SpfComputation::Full;
         }
 
+        // Router-LSA and Network-LSA changes represent topological changes,
+        // hence the SPT needs to be updated, starting from the vertices whose
+        // LSAs have changed.
+        let mut vertices: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for tlsa in trigger_lsas {
+            let lsa = &tlsa.new;
+            let vertex_id = match lsa.hdr.lsa_type.function_code_normalized() {
+                Some(LsaFunctionCode::Router) => VertexId::Router {
+                    router_id: lsa.hdr.adv_rtr,
+                },
+                Some(LsaFunctionCode::Network) => VertexId::Network {
+                    router_id: lsa.hdr.adv_rtr,
+                    iface_id: lsa.hdr.lsa_id.into(),
+                },
+                _ => continue,
+            };
+            let Some(area_id) = tlsa.log_id.area_id else {
+                continue;
+            };
+            vertices.entry(area_id).or_default().insert(vertex_id);
+        }
+        if !vertices.is_empty() {
+            return SpfComputation::Incremental(vertices);
+        }
+
         // Check Intra-Area-Prefix LSA changes.
         //
         // For that to work, for each changed Intra-Area-Prefix LSA, we merge
@@ -151,11 +170,13 @@ impl SpfVersion<Self> for Ospfv3 {
             .map(|lsa_body| lsa_body.router_id)
             .collect::<BTreeSet<_>>();
 
-        // Check AS-External LSA changes.
+        // Check AS-External LSA and NSSA LSA changes.
         let external = trigger_lsas
             .iter()
             .map(|tlsa| &tlsa.new)
-            .filter_map(|lsa| lsa.body.as_as_external())
+            .filter_map(|lsa| {
+                lsa.body.as_as_external().or_else(|| lsa.body.as_nssa())
+            })
             .map(|lsa_body| lsa_body.prefix)
             .collect();
 
@@ -539,7 +560,9 @@ impl SpfVersion<Self> for Ospfv3 {
                 (!lsa_body.prefix_options.contains(PrefixOptions::NU))
                     .then_some(SpfExternalNetwork {
                         adv_rtr: lsa.hdr.adv_rtr,
+                        lsa_id: lsa.hdr.lsa_id,
                         e_bit: lsa_body.flags.contains(LsaAsExternalFlags::E),
+                        p_bit: false,
                         prefix: lsa_body.prefix,
                         prefix_options: lsa_body.prefix_options,
                         metric: lsa_body.metric,
@@ -549,6 +572,35 @@ impl SpfVersion<Self> for Ospfv3 {
             })
     }
 
+    fn nssa_networks<'a>(
+        lsdb: &'a Lsdb<Self>,
+        extended_lsa: bool,
+        lsa_entries: &'a Arena<LsaEntry<Self>>,
+    ) -> impl Iterator<Item = SpfExternalNetwork<Self>> + 'a {
+        lsdb.iter_by_type(
+            lsa_entries,
+            LsaAsExternal::nssa_lsa_type(extended_lsa),
+        )
+        .map(|(_, lse)| &lse.data)
+        .filter(|lsa| !lsa.hdr.is_maxage())
+        .filter_map(|lsa| {
+            let lsa_body = lsa.body.as_nssa().unwrap();
+            (!lsa_body.prefix_options.contains(PrefixOptions::NU)).then_some(
+                SpfExternalNetwork {
+                    adv_rtr: lsa.hdr.adv_rtr,
+                    lsa_id: lsa.hdr.lsa_id,
+                    e_bit: lsa_body.flags.contains(LsaAsExternalFlags::E),
+                    p_bit: lsa_body.prefix_options.contains(PrefixOptions::P),
+                    prefix: lsa_body.prefix,
+                    prefix_options: lsa_body.prefix_options,
+                    metric: lsa_body.metric,
+                    fwd_addr: lsa_body.fwd_addr,
+                    tag: lsa_body.tag,
+                },
+            )
+        })
+    }
+
     fn area_router_information<'a>(
         lsdb: &'a Lsdb<Self>,
         router_id: Ipv4Addr,
@@ -566,6 +618,17 @@ impl SpfVersion<Self> for Ospfv3 {
             .filter(|lsa| !lsa.hdr.is_maxage())
             .filter_map(|lsa| lsa.body.as_router_info())
         {
+            // Dynamic Hostname.
+            if let Some(hostname) = &ri_lsa.hostname {
+                ri_agg.hostname.get_or_insert(hostname);
+            }
+
+            // Multiple occurrences of the Node Admin Tag TLV MAY be
+            // advertised.
+            for tlv in &ri_lsa.node_tags {
+                ri_agg.node_tags.extend(tlv.get());
+            }
+
             if let Some(sr_algo) = &ri_lsa.sr_algo {
                 // When multiple SR-Algorithm TLVs are received from a given
                 // router, the receiver MUST use the first occurrence of the TLV
@@ -582,10 +645,60 @@ impl SpfVersion<Self> for Ospfv3 {
             // Multiple occurrences of the SID/Label Range TLV MAY be advertised
             // in order to advertise multiple ranges.
             ri_agg.srgb.extend(&ri_lsa.srgb);
+
+            // Flexible Algorithm Definitions.
+            ri_agg.fads.extend(&ri_lsa.fads);
         }
 
         ri_agg
     }
+
+    fn adj_sid_find(
+        area: &Area<Self>,
+        adv_rtr: Ipv4Addr,
+        nbr_router_id: Ipv4Addr,
+        extended_lsa: bool,
+        lsa_entries: &Arena<LsaEntry<Self>>,
+    ) -> Option<Label> {
+        area.state
+            .lsdb
+            .iter_by_type_advrtr(
+                lsa_entries,
+                LsaRouter::lsa_type(extended_lsa),
+                adv_rtr,
+            )
+            .map(|(_, lse)| &lse.data)
+            .filter(|lsa| !lsa.hdr.is_maxage())
+            .filter_map(|lsa| lsa.body.as_router())
+            .flat_map(|lsa_body| lsa_body.links.iter())
+            .flat_map(|link| {
+                link.adj_sids.iter().filter(move |adj_sid| {
+                    match adj_sid.nbr_router_id {
+                        // LAN Adj-SID.
+                        Some(adj_nbr_router_id) => {
+                            adj_nbr_router_id == nbr_router_id
+                        }
+                        // Point-to-point Adj-SID.
+                        None => {
+                            link.link_type == LsaRouterLinkType::PointToPoint
+                                && link.nbr_router_id == nbr_router_id
+                        }
+                    }
+                })
+            })
+            // Prefer unprotected Adj-SIDs.
+            .min_by_key(|adj_sid| adj_sid.flags.contains(AdjSidFlags::B))
+            .and_then(|adj_sid| adj_sid.sid.as_label().copied())
+    }
+
+    fn link_asla<'a>(
+        _area: &'a Area<Self>,
+        _adv_rtr: Ipv4Addr,
+        link: &'a LsaRouterLink,
+        _lsa_entries: &'a Arena<LsaEntry<Self>>,
+    ) -> Option<&'a AslaTlv> {
+        link.asla.as_ref()
+    }
 }
 
 // ===== helper functions =====
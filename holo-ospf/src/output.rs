@@ -13,7 +13,8 @@ use crate::interface::{ism, Interface, InterfaceType};
 use crate::lsdb;
 use crate::neighbor::{nsm, Neighbor};
 use crate::network::{MulticastAddr, SendDestination};
-use crate::packet::lsa::LsaHdrVersion;
+use crate::packet::lsa::{Lsa, LsaHdrVersion};
+use crate::packet::tlv::GrReason;
 use crate::packet::{
     DbDescFlags, DbDescVersion, LsAckVersion, LsRequestVersion,
     LsUpdateVersion, PacketHdrVersion, PacketType,
@@ -79,7 +80,13 @@ pub(crate) fn send_dbdesc<V>(
                 iface.state.auth.is_some(),
             ),
         ),
-        iface.system.mtu.unwrap(),
+        // The Interface MTU field is set to 0 when sending Database
+        // Description packets over virtual links.
+        if iface.is_virtual_link() {
+            0
+        } else {
+            iface.system.mtu.unwrap()
+        },
         nbr.dd_flags,
         nbr.dd_seq_no,
         lsa_hdrs,
@@ -389,6 +396,50 @@ pub(crate) fn send_lsack_delayed<V>(
     }
 }
 
+// ===== Grace-LSAs =====
+
+// Sends a Grace-LSA on the given interface. This is used during unplanned
+// graceful restarts, when the Grace-LSA must be sent prior to the first Hello
+// and without being installed in the LSDB.
+pub(crate) fn send_grace_lsa<V>(
+    iface: &Interface<V>,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    grace_period: u32,
+    reason: GrReason,
+) where
+    V: Version,
+{
+    // Initialize source and destination address(es).
+    let src = iface.state.src_addr.unwrap();
+    let dst = iface.hello_dst();
+
+    // Build Grace-LSA.
+    let (options, lsa_id, lsa_body) =
+        V::lsa_grace(iface, area, grace_period, reason);
+    let lsa = Lsa::new(
+        0,
+        options,
+        lsa_id,
+        instance.state.router_id,
+        lsdb::LSA_INIT_SEQ_NO,
+        lsa_body,
+    );
+
+    // Generate Link State Update packet.
+    let pkt_hdr = V::PacketHdr::generate(
+        PacketType::LsUpdate,
+        instance.state.router_id,
+        area.area_id,
+        iface.config.instance_id.resolved,
+    );
+    let packet = V::PacketLsUpdate::generate(pkt_hdr, vec![lsa]);
+
+    // Enqueue packet for network transmission.
+    let msg = NetTxPacketMsg { packet, src, dst };
+    iface.send_packet(msg);
+}
+
 // ===== helper functions =====
 
 // Returns destination used to send a packet directly to the given neighbor.
@@ -399,8 +450,14 @@ fn send_dest_nbr<V>(
 where
     V: Version,
 {
+    if let Some(endpoint) = iface.vlink_endpoint() {
+        return endpoint.send_dest();
+    }
+
     let ifindex = iface.system.ifindex.unwrap();
-    let addr = if iface.config.if_type == InterfaceType::PointToPoint {
+    let addr = if iface.config.if_type == InterfaceType::PointToPoint
+        && !iface.is_multi_area()
+    {
         *V::multicast_addr(MulticastAddr::AllSpfRtrs)
     } else {
         nbr.src
@@ -417,6 +474,10 @@ fn send_dest_iface<V>(
 where
     V: Version,
 {
+    if let Some(endpoint) = iface.vlink_endpoint() {
+        return endpoint.send_dest();
+    }
+
     let ifindex = iface.system.ifindex.unwrap();
     let addrs = match iface.config.if_type {
         InterfaceType::Broadcast => {
@@ -442,9 +503,12 @@ where
                 .collect()
         }
         InterfaceType::PointToPoint => {
-            let addr = MulticastAddr::AllSpfRtrs;
-            smallvec![*V::multicast_addr(addr)]
+            let addr = iface
+                .multi_area_nbr_addr()
+                .unwrap_or(*V::multicast_addr(MulticastAddr::AllSpfRtrs));
+            smallvec![addr]
         }
+        InterfaceType::VirtualLink => unreachable!(),
     };
     SendDestination::new(ifindex, addrs)
 }
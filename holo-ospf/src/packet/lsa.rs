@@ -81,6 +81,12 @@ pub trait LsaVersion<V: Version> {
 
     // Return LSA type for inter-area router LSAs.
     fn type4_summary(extended_lsa: bool) -> Self::LsaType;
+
+    // Return LSA type for AS-external LSAs.
+    fn type5_external(extended_lsa: bool) -> Self::LsaType;
+
+    // Return LSA type for NSSA LSAs.
+    fn type7_nssa(extended_lsa: bool) -> Self::LsaType;
 }
 
 // OSPF version-specific code.
@@ -183,6 +189,7 @@ where
     fn encode(&self, buf: &mut BytesMut);
     fn lsa_type(&self) -> V::LsaType;
     fn is_unknown(&self) -> bool;
+    fn is_non_default_external(&self) -> bool;
     fn validate(&self, hdr: &V::LsaHdr) -> Result<(), LsaValidationError>;
     fn as_grace(&self) -> Option<(u32, GrReason, Option<V::NetIpAddr>)>;
 }
@@ -194,6 +201,7 @@ where
 {
     fn is_abr(&self) -> bool;
     fn is_asbr(&self) -> bool;
+    fn is_nssa_translator(&self) -> bool;
 }
 
 // OSPF version-specific code.
@@ -171,6 +171,9 @@ where
 {
     // Return whether the E-bit is set.
     fn e_bit(&self) -> bool;
+
+    // Return whether the N-bit is set.
+    fn n_bit(&self) -> bool;
 }
 
 // OSPF version-specific code.
@@ -5,6 +5,7 @@
 //
 
 use std::collections::{BTreeMap, BTreeSet};
+use std::net::{IpAddr, Ipv4Addr};
 
 use bitflags::bitflags;
 use bytes::{Buf, BufMut, Bytes, BytesMut};
@@ -31,11 +32,102 @@ pub const TLV_HDR_SIZE: u16 = 4;
 pub enum RouterInfoTlvType {
     InformationalCaps = 1,
     FunctionalCaps = 2,
+    DynamicHostname = 7,
     SrAlgo = 8,
     SidLabelRange = 9,
+    NodeAdminTag = 10,
     NodeMsd = 12,
     SrLocalBlock = 14,
     SrmsPref = 15,
+    FlexAlgoDef = 16,
+    Srv6Caps = 20,
+}
+
+// Flexible Algorithm Definition Sub-TLV types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/ospf-parameters/ospf-parameters.xhtml#fad-subtlvs
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum FlexAlgoDefSubTlvType {
+    ExcludeAny = 1,
+    IncludeAny = 2,
+    IncludeAll = 3,
+}
+
+// Flexible Algorithm metric types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml#igp-metric-type
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum FlexAlgoMetricType {
+    Igp = 0,
+    MinUniLinkDelay = 1,
+    Te = 2,
+}
+
+// Application-Specific Link Attributes Sub-TLV types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/ospf-parameters/ospf-parameters.xhtml#extended-link-tlv-sub-tlvs
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum AslaSubTlvType {
+    TeMetric = 5,
+    AdminGroup = 9,
+    MinMaxUniLinkDelay = 28,
+}
+
+// Traffic Engineering LSA top-level TLV types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/ospf-traffic-eng-tlvs/ospf-traffic-eng-tlvs.xhtml#ospf-traffic-eng-tlvs-1
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum TeTlvType {
+    RouterAddr = 1,
+    Link = 2,
+    RouterIpv6Addr = 3,
+}
+
+// Traffic Engineering Link TLV Sub-TLV types.
+//
+// IANA registry:
+// https://www.iana.org/assignments/ospf-traffic-eng-tlvs/ospf-traffic-eng-tlvs.xhtml#ospf-traffic-eng-tlvs-2
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum TeLinkSubTlvType {
+    LinkType = 1,
+    LinkId = 2,
+    LocalIfAddr = 3,
+    RemoteIfAddr = 4,
+    TeMetric = 5,
+    MaxBw = 6,
+    MaxRsvBw = 7,
+    UnrsvBw = 8,
+    AdminGroup = 9,
+    LocalIfIpv6Addr = 18,
+    RemoteIfIpv6Addr = 19,
+    NbrId = 20,
+    ExtAdminGroup = 26,
+    UniLinkDelay = 27,
+    MinMaxUniLinkDelay = 28,
+    UniDelayVariation = 29,
+}
+
+// Traffic Engineering link types.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum TeLinkType {
+    PointToPoint = 1,
+    MultiAccess = 2,
 }
 
 // SID/Label Sub-TLV type.
@@ -109,6 +201,27 @@ bitflags! {
 #[derive(Deserialize, Serialize)]
 pub struct RouterFuncCapsTlv(RouterFuncCaps);
 
+//
+// Dynamic Hostname TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                                                               |
+// .                                                               .
+// .                          Hostname                             .
+// .                                                               .
+// |                                                               |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct DynamicHostnameTlv(String);
+
 //
 // SR-Algorithm TLV.
 //
@@ -210,6 +323,189 @@ pub struct MsdTlv(BTreeMap<u8, u8>);
 #[derive(Deserialize, Serialize)]
 pub struct SrmsPrefTlv(u8);
 
+//
+// Node Admin Tag TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                 Administrative Tag #1                         |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                 Administrative Tag #2                         |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// //                                                             //
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                 Administrative Tag #N                         |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Default, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct NodeAdminTagTlv(BTreeSet<u32>);
+
+// SRv6 Capabilities flags.
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct Srv6CapsFlags: u16 {
+        const O = 0x4000;
+    }
+}
+
+//
+// SRv6-Capabilities TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |             Flags             |           Reserved            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+#[derive(Clone, Debug, Default, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6CapsTlv(Srv6CapsFlags);
+
+//
+// Flexible Algorithm Definition TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |Flex-Algorithm |  Metric-Type  |   Calc-Type   |    Priority   |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                         Sub-TLVs                              |
+// +                                                               +
+// |                           ...                                 |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+// Only the first 32 bits of the administrative group Sub-TLVs are supported.
+//
+#[derive(Clone, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct FlexAlgoDefTlv {
+    pub algo: u8,
+    pub metric_type: u8,
+    pub calc_type: u8,
+    pub priority: u8,
+    pub exclude_any: u32,
+    pub include_any: u32,
+    pub include_all: u32,
+    #[new(default)]
+    pub unknown_stlvs: Vec<UnknownTlv>,
+}
+
+// Application-Specific Link Attributes SABM bits.
+//
+// IANA registry:
+// https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml#link-attribute-application-identifiers
+bitflags! {
+    #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+    #[derive(Deserialize, Serialize)]
+    #[serde(transparent)]
+    pub struct AslaSabm: u8 {
+        const R = 0x80;
+        const S = 0x40;
+        const F = 0x20;
+        const X = 0x10;
+    }
+}
+
+//
+// Application-Specific Link Attributes (ASLA) Sub-TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |  SABM Length  | UDABM Length  |            Reserved           |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |     Standard Application Identifier Bit Mask (variable)     ...
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |    User-Defined Application Identifier Bit Mask (variable)  ...
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                     Link Attribute Sub-TLVs                 ...
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+//
+// User-defined applications aren't supported.
+//
+#[derive(Clone, Debug, Default, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct AslaTlv {
+    pub sabm: AslaSabm,
+    pub te_metric: Option<u32>,
+    pub admin_group: Option<u32>,
+    pub min_delay: Option<u32>,
+    #[new(default)]
+    pub unknown_stlvs: Vec<UnknownTlv>,
+}
+
+//
+// Traffic Engineering Link TLV.
+//
+// Encoding format:
+//
+//  0                   1                   2                   3
+//  0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1 2 3 4 5 6 7 8 9 0 1
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |              Type             |             Length            |
+// +-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+-+
+// |                      Sub-TLVs (variable)                      |
+// +-                                                             -+
+// |                             ...                               |
+//
+// The same TLV is used by both OSPFv2 and OSPFv3, except that OSPFv2 describes
+// the link using the Link ID and IPv4 interface addresses, whereas OSPFv3 uses
+// the Neighbor ID and IPv6 interface addresses.
+//
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct TeLinkTlv {
+    pub link_type: Option<TeLinkType>,
+    pub link_id: Option<Ipv4Addr>,
+    pub local_addrs: Vec<IpAddr>,
+    pub remote_addrs: Vec<IpAddr>,
+    pub nbr_id: Option<TeNbrId>,
+    pub te_metric: Option<u32>,
+    pub max_bw: Option<TeBandwidth>,
+    pub max_rsv_bw: Option<TeBandwidth>,
+    pub unrsv_bw: Option<[TeBandwidth; 8]>,
+    pub admin_group: Option<u32>,
+    pub ext_admin_group: Option<Vec<u32>>,
+    pub delay: Option<u32>,
+    pub min_max_delay: Option<(u32, u32)>,
+    pub delay_variation: Option<u32>,
+    pub unknown_stlvs: Vec<UnknownTlv>,
+}
+
+// Traffic Engineering Neighbor ID (OSPFv3 only).
+#[derive(Clone, Copy, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct TeNbrId {
+    pub iface_id: u32,
+    pub router_id: Ipv4Addr,
+}
+
+// Traffic Engineering bandwidth, encoded in IEEE floating point format (bytes
+// per second).
+#[derive(Clone, Copy, Debug, Default, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct TeBandwidth(u32);
+
 // Prefix-SID Flags.
 //
 // For simplicity, use a shared struct for both OSPFv2 and OSPFv3 since the
@@ -345,6 +641,33 @@ impl From<RouterFuncCaps> for RouterFuncCapsTlv {
     }
 }
 
+// ===== impl DynamicHostnameTlv =====
+
+impl DynamicHostnameTlv {
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate TLV length.
+        if tlv_len == 0 || tlv_len > 255 {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let hostname = buf.copy_to_bytes(tlv_len as usize);
+        let hostname = String::from_utf8_lossy(&hostname).into_owned();
+
+        Ok(DynamicHostnameTlv(hostname))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos =
+            tlv_encode_start(buf, RouterInfoTlvType::DynamicHostname);
+        buf.put_slice(self.0.as_bytes());
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn get(&self) -> &str {
+        &self.0
+    }
+}
+
 // ===== impl SrAlgoTlv =====
 
 impl SrAlgoTlv {
@@ -368,7 +691,7 @@ impl SrAlgoTlv {
     pub(crate) fn encode(&self, buf: &mut BytesMut) {
         let start_pos = tlv_encode_start(buf, RouterInfoTlvType::SrAlgo);
         for algo in &self.0 {
-            buf.put_u8(*algo as u8);
+            buf.put_u8(algo.to_u8().unwrap());
         }
         tlv_encode_end(buf, start_pos);
     }
@@ -552,6 +875,509 @@ impl MsdTlv {
     }
 }
 
+// ===== impl NodeAdminTagTlv =====
+
+impl NodeAdminTagTlv {
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate TLV length.
+        if tlv_len == 0 || tlv_len % 4 != 0 {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let mut tags = BTreeSet::new();
+        let mut tlv_rlen = tlv_len;
+        while tlv_rlen >= 4 {
+            let tag = buf.get_u32();
+            tags.insert(tag);
+
+            tlv_rlen -= 4;
+        }
+
+        Ok(NodeAdminTagTlv(tags))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, RouterInfoTlvType::NodeAdminTag);
+        for tag in &self.0 {
+            buf.put_u32(*tag);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    pub(crate) fn get(&self) -> &BTreeSet<u32> {
+        &self.0
+    }
+}
+
+// ===== impl FlexAlgoDefTlv =====
+
+impl FlexAlgoDefTlv {
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate minimum TLV length.
+        if tlv_len < 4 {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let algo = buf.get_u8();
+        let metric_type = buf.get_u8();
+        let calc_type = buf.get_u8();
+        let priority = buf.get_u8();
+        let mut fad = FlexAlgoDefTlv::new(
+            algo,
+            metric_type,
+            calc_type,
+            priority,
+            0,
+            0,
+            0,
+        );
+
+        // Parse Sub-TLVs.
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse Sub-TLV type.
+            let stlv_type = buf.get_u16();
+            let stlv_etype = FlexAlgoDefSubTlvType::from_u16(stlv_type);
+
+            // Parse and validate Sub-TLV length.
+            let stlv_len = buf.get_u16();
+            let stlv_wlen = tlv_wire_len(stlv_len);
+            if stlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_wlen as usize);
+            match stlv_etype {
+                Some(stlv_etype) => {
+                    if stlv_len < 4 || stlv_len % 4 != 0 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let groups = buf_stlv.get_u32();
+                    match stlv_etype {
+                        FlexAlgoDefSubTlvType::ExcludeAny => {
+                            fad.exclude_any = groups
+                        }
+                        FlexAlgoDefSubTlvType::IncludeAny => {
+                            fad.include_any = groups
+                        }
+                        FlexAlgoDefSubTlvType::IncludeAll => {
+                            fad.include_all = groups
+                        }
+                    }
+                }
+                None => {
+                    // Save unknown Sub-TLV.
+                    let value = buf_stlv.copy_to_bytes(stlv_len as usize);
+                    fad.unknown_stlvs
+                        .push(UnknownTlv::new(stlv_type, stlv_len, value));
+                }
+            }
+        }
+
+        Ok(fad)
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, RouterInfoTlvType::FlexAlgoDef);
+        buf.put_u8(self.algo);
+        buf.put_u8(self.metric_type);
+        buf.put_u8(self.calc_type);
+        buf.put_u8(self.priority);
+        for (stlv_type, groups) in [
+            (FlexAlgoDefSubTlvType::ExcludeAny, self.exclude_any),
+            (FlexAlgoDefSubTlvType::IncludeAny, self.include_any),
+            (FlexAlgoDefSubTlvType::IncludeAll, self.include_all),
+        ] {
+            if groups != 0 {
+                let start_pos = tlv_encode_start(buf, stlv_type);
+                buf.put_u32(groups);
+                tlv_encode_end(buf, start_pos);
+            }
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+
+    // Checks whether a link with the given administrative groups satisfies
+    // the constraints of this definition.
+    pub(crate) fn link_allowed(&self, admin_group: u32) -> bool {
+        if self.exclude_any & admin_group != 0 {
+            return false;
+        }
+        if self.include_any != 0 && self.include_any & admin_group == 0 {
+            return false;
+        }
+        if self.include_all & admin_group != self.include_all {
+            return false;
+        }
+        true
+    }
+}
+
+// ===== impl AslaTlv =====
+
+impl AslaTlv {
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate minimum TLV length.
+        if tlv_len < 4 {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        // Parse the application identifier bit masks.
+        let sabm_len = buf.get_u8();
+        let udabm_len = buf.get_u8();
+        let _reserved = buf.get_u16();
+        if !matches!(sabm_len, 0 | 4 | 8) || !matches!(udabm_len, 0 | 4 | 8) {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+        let mask_len = (sabm_len + udabm_len) as usize;
+        if buf.remaining() < mask_len {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+        let mut asla = AslaTlv::default();
+        if sabm_len > 0 {
+            let sabm = buf.get_u8();
+            asla.sabm = AslaSabm::from_bits_truncate(sabm);
+            buf.advance(sabm_len as usize - 1);
+        }
+        buf.advance(udabm_len as usize);
+
+        // Parse Sub-TLVs.
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse Sub-TLV type.
+            let stlv_type = buf.get_u16();
+            let stlv_etype = AslaSubTlvType::from_u16(stlv_type);
+
+            // Parse and validate Sub-TLV length.
+            let stlv_len = buf.get_u16();
+            let stlv_wlen = tlv_wire_len(stlv_len);
+            if stlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_wlen as usize);
+            match stlv_etype {
+                Some(AslaSubTlvType::TeMetric) => {
+                    if stlv_len != 4 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    asla.te_metric = Some(buf_stlv.get_u32());
+                }
+                Some(AslaSubTlvType::AdminGroup) => {
+                    if stlv_len != 4 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    asla.admin_group = Some(buf_stlv.get_u32());
+                }
+                Some(AslaSubTlvType::MinMaxUniLinkDelay) => {
+                    if stlv_len != 8 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let min_delay = buf_stlv.get_u32() & 0x00FFFFFF;
+                    let _max_delay = buf_stlv.get_u32() & 0x00FFFFFF;
+                    asla.min_delay = Some(min_delay);
+                }
+                None => {
+                    // Save unknown Sub-TLV.
+                    let value = buf_stlv.copy_to_bytes(stlv_len as usize);
+                    asla.unknown_stlvs
+                        .push(UnknownTlv::new(stlv_type, stlv_len, value));
+                }
+            }
+        }
+
+        Ok(asla)
+    }
+
+    pub(crate) fn encode(&self, tlv_type: u16, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, tlv_type);
+        buf.put_u8(4);
+        buf.put_u8(0);
+        buf.put_u16(0);
+        buf.put_u8(self.sabm.bits());
+        buf.put_bytes(0, 3);
+        if let Some(te_metric) = self.te_metric {
+            let start_pos = tlv_encode_start(buf, AslaSubTlvType::TeMetric);
+            buf.put_u32(te_metric);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(admin_group) = self.admin_group {
+            let start_pos = tlv_encode_start(buf, AslaSubTlvType::AdminGroup);
+            buf.put_u32(admin_group);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(min_delay) = self.min_delay {
+            let start_pos =
+                tlv_encode_start(buf, AslaSubTlvType::MinMaxUniLinkDelay);
+            // The maximum delay isn't measured, so advertise the minimum
+            // delay for both.
+            buf.put_u32(min_delay);
+            buf.put_u32(min_delay);
+            tlv_encode_end(buf, start_pos);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+// ===== impl TeLinkTlv =====
+
+impl TeLinkTlv {
+    // Mask used to clear the Anomalous (A) bit of the link delay Sub-TLVs.
+    const DELAY_MASK: u32 = 0x00FFFFFF;
+
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        let mut tlv = TeLinkTlv::default();
+
+        let mut buf = buf.copy_to_bytes(tlv_len as usize);
+        while buf.remaining() >= TLV_HDR_SIZE as usize {
+            // Parse Sub-TLV type.
+            let stlv_type = buf.get_u16();
+            let stlv_etype = TeLinkSubTlvType::from_u16(stlv_type);
+
+            // Parse and validate Sub-TLV length.
+            let stlv_len = buf.get_u16();
+            let stlv_wlen = tlv_wire_len(stlv_len);
+            if stlv_wlen as usize > buf.remaining() {
+                return Err(DecodeError::InvalidTlvLength(stlv_len));
+            }
+            let expected_len = match stlv_etype {
+                Some(TeLinkSubTlvType::LinkType) => Some(1),
+                Some(TeLinkSubTlvType::NbrId)
+                | Some(TeLinkSubTlvType::MinMaxUniLinkDelay) => Some(8),
+                Some(TeLinkSubTlvType::UnrsvBw) => Some(32),
+                Some(
+                    TeLinkSubTlvType::LinkId
+                    | TeLinkSubTlvType::TeMetric
+                    | TeLinkSubTlvType::MaxBw
+                    | TeLinkSubTlvType::MaxRsvBw
+                    | TeLinkSubTlvType::AdminGroup
+                    | TeLinkSubTlvType::UniLinkDelay
+                    | TeLinkSubTlvType::UniDelayVariation,
+                ) => Some(4),
+                _ => None,
+            };
+            if expected_len.is_some_and(|len| len != stlv_len) {
+                return Err(DecodeError::InvalidTlvLength(stlv_len));
+            }
+
+            // Parse Sub-TLV value.
+            let mut buf_stlv = buf.copy_to_bytes(stlv_wlen as usize);
+            match stlv_etype {
+                Some(TeLinkSubTlvType::LinkType) => {
+                    let link_type = buf_stlv.get_u8();
+                    tlv.link_type = TeLinkType::from_u8(link_type);
+                }
+                Some(TeLinkSubTlvType::LinkId) => {
+                    tlv.link_id = Some(buf_stlv.get_ipv4());
+                }
+                Some(
+                    TeLinkSubTlvType::LocalIfAddr
+                    | TeLinkSubTlvType::RemoteIfAddr,
+                ) => {
+                    if stlv_len % 4 != 0 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let addrs = match stlv_etype {
+                        Some(TeLinkSubTlvType::LocalIfAddr) => {
+                            &mut tlv.local_addrs
+                        }
+                        _ => &mut tlv.remote_addrs,
+                    };
+                    for _ in 0..stlv_len / 4 {
+                        addrs.push(buf_stlv.get_ipv4().into());
+                    }
+                }
+                Some(
+                    TeLinkSubTlvType::LocalIfIpv6Addr
+                    | TeLinkSubTlvType::RemoteIfIpv6Addr,
+                ) => {
+                    if stlv_len % 16 != 0 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let addrs = match stlv_etype {
+                        Some(TeLinkSubTlvType::LocalIfIpv6Addr) => {
+                            &mut tlv.local_addrs
+                        }
+                        _ => &mut tlv.remote_addrs,
+                    };
+                    for _ in 0..stlv_len / 16 {
+                        addrs.push(buf_stlv.get_ipv6().into());
+                    }
+                }
+                Some(TeLinkSubTlvType::NbrId) => {
+                    let iface_id = buf_stlv.get_u32();
+                    let router_id = buf_stlv.get_ipv4();
+                    tlv.nbr_id = Some(TeNbrId::new(iface_id, router_id));
+                }
+                Some(TeLinkSubTlvType::TeMetric) => {
+                    tlv.te_metric = Some(buf_stlv.get_u32());
+                }
+                Some(TeLinkSubTlvType::MaxBw) => {
+                    tlv.max_bw = Some(TeBandwidth(buf_stlv.get_u32()));
+                }
+                Some(TeLinkSubTlvType::MaxRsvBw) => {
+                    tlv.max_rsv_bw = Some(TeBandwidth(buf_stlv.get_u32()));
+                }
+                Some(TeLinkSubTlvType::UnrsvBw) => {
+                    let mut unrsv_bw = [TeBandwidth::default(); 8];
+                    for bw in &mut unrsv_bw {
+                        *bw = TeBandwidth(buf_stlv.get_u32());
+                    }
+                    tlv.unrsv_bw = Some(unrsv_bw);
+                }
+                Some(TeLinkSubTlvType::AdminGroup) => {
+                    tlv.admin_group = Some(buf_stlv.get_u32());
+                }
+                Some(TeLinkSubTlvType::ExtAdminGroup) => {
+                    if stlv_len % 4 != 0 {
+                        return Err(DecodeError::InvalidTlvLength(stlv_len));
+                    }
+                    let ext_admin_group =
+                        (0..stlv_len / 4).map(|_| buf_stlv.get_u32()).collect();
+                    tlv.ext_admin_group = Some(ext_admin_group);
+                }
+                Some(TeLinkSubTlvType::UniLinkDelay) => {
+                    let delay = buf_stlv.get_u32() & Self::DELAY_MASK;
+                    tlv.delay = Some(delay);
+                }
+                Some(TeLinkSubTlvType::MinMaxUniLinkDelay) => {
+                    let min_delay = buf_stlv.get_u32() & Self::DELAY_MASK;
+                    let max_delay = buf_stlv.get_u32() & Self::DELAY_MASK;
+                    tlv.min_max_delay = Some((min_delay, max_delay));
+                }
+                Some(TeLinkSubTlvType::UniDelayVariation) => {
+                    let delay_variation = buf_stlv.get_u32() & Self::DELAY_MASK;
+                    tlv.delay_variation = Some(delay_variation);
+                }
+                None => {
+                    // Save unknown Sub-TLV.
+                    let value = buf_stlv.copy_to_bytes(stlv_len as usize);
+                    tlv.unknown_stlvs
+                        .push(UnknownTlv::new(stlv_type, stlv_len, value));
+                }
+            }
+        }
+
+        Ok(tlv)
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, TeTlvType::Link);
+        if let Some(link_type) = self.link_type {
+            let start_pos = tlv_encode_start(buf, TeLinkSubTlvType::LinkType);
+            buf.put_u8(link_type as u8);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(link_id) = &self.link_id {
+            let start_pos = tlv_encode_start(buf, TeLinkSubTlvType::LinkId);
+            buf.put_ipv4(link_id);
+            tlv_encode_end(buf, start_pos);
+        }
+        for (addrs, stlv_type_ipv4, stlv_type_ipv6) in [
+            (
+                &self.local_addrs,
+                TeLinkSubTlvType::LocalIfAddr,
+                TeLinkSubTlvType::LocalIfIpv6Addr,
+            ),
+            (
+                &self.remote_addrs,
+                TeLinkSubTlvType::RemoteIfAddr,
+                TeLinkSubTlvType::RemoteIfIpv6Addr,
+            ),
+        ] {
+            if addrs.iter().any(|addr| addr.is_ipv4()) {
+                let start_pos = tlv_encode_start(buf, stlv_type_ipv4);
+                for addr in addrs.iter().filter(|addr| addr.is_ipv4()) {
+                    buf.put_ip(addr);
+                }
+                tlv_encode_end(buf, start_pos);
+            }
+            if addrs.iter().any(|addr| addr.is_ipv6()) {
+                let start_pos = tlv_encode_start(buf, stlv_type_ipv6);
+                for addr in addrs.iter().filter(|addr| addr.is_ipv6()) {
+                    buf.put_ip(addr);
+                }
+                tlv_encode_end(buf, start_pos);
+            }
+        }
+        if let Some(nbr_id) = &self.nbr_id {
+            let start_pos = tlv_encode_start(buf, TeLinkSubTlvType::NbrId);
+            buf.put_u32(nbr_id.iface_id);
+            buf.put_ipv4(&nbr_id.router_id);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(te_metric) = self.te_metric {
+            let start_pos = tlv_encode_start(buf, TeLinkSubTlvType::TeMetric);
+            buf.put_u32(te_metric);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(max_bw) = self.max_bw {
+            let start_pos = tlv_encode_start(buf, TeLinkSubTlvType::MaxBw);
+            buf.put_u32(max_bw.0);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(max_rsv_bw) = self.max_rsv_bw {
+            let start_pos = tlv_encode_start(buf, TeLinkSubTlvType::MaxRsvBw);
+            buf.put_u32(max_rsv_bw.0);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(unrsv_bw) = &self.unrsv_bw {
+            let start_pos = tlv_encode_start(buf, TeLinkSubTlvType::UnrsvBw);
+            for bw in unrsv_bw {
+                buf.put_u32(bw.0);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(admin_group) = self.admin_group {
+            let start_pos = tlv_encode_start(buf, TeLinkSubTlvType::AdminGroup);
+            buf.put_u32(admin_group);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(ext_admin_group) = &self.ext_admin_group {
+            let start_pos =
+                tlv_encode_start(buf, TeLinkSubTlvType::ExtAdminGroup);
+            for word in ext_admin_group {
+                buf.put_u32(*word);
+            }
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(delay) = self.delay {
+            let start_pos =
+                tlv_encode_start(buf, TeLinkSubTlvType::UniLinkDelay);
+            buf.put_u32(delay);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some((min_delay, max_delay)) = self.min_max_delay {
+            let start_pos =
+                tlv_encode_start(buf, TeLinkSubTlvType::MinMaxUniLinkDelay);
+            buf.put_u32(min_delay);
+            buf.put_u32(max_delay);
+            tlv_encode_end(buf, start_pos);
+        }
+        if let Some(delay_variation) = self.delay_variation {
+            let start_pos =
+                tlv_encode_start(buf, TeLinkSubTlvType::UniDelayVariation);
+            buf.put_u32(delay_variation);
+            tlv_encode_end(buf, start_pos);
+        }
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
+// ===== impl TeBandwidth =====
+
+impl TeBandwidth {
+    // Creates a bandwidth value from the given rate in bits per second.
+    pub(crate) fn from_bps(bps: u64) -> Self {
+        TeBandwidth((bps as f32 / 8.0).to_bits())
+    }
+
+    // Returns the bandwidth in bytes per second.
+    pub(crate) fn get(&self) -> f32 {
+        f32::from_bits(self.0)
+    }
+}
+
 // ===== impl GracePeriodTlv =====
 
 impl GracePeriodTlv {
@@ -648,6 +1474,30 @@ impl SrmsPrefTlv {
     }
 }
 
+// ===== impl Srv6CapsTlv =====
+
+impl Srv6CapsTlv {
+    pub(crate) fn decode(tlv_len: u16, buf: &mut Bytes) -> DecodeResult<Self> {
+        // Validate minimum TLV length.
+        if tlv_len < 4 {
+            return Err(DecodeError::InvalidTlvLength(tlv_len));
+        }
+
+        let flags = buf.get_u16();
+        let flags = Srv6CapsFlags::from_bits_truncate(flags);
+        let _reserved = buf.get_u16();
+
+        Ok(Srv6CapsTlv(flags))
+    }
+
+    pub(crate) fn encode(&self, buf: &mut BytesMut) {
+        let start_pos = tlv_encode_start(buf, RouterInfoTlvType::Srv6Caps);
+        buf.put_u16(self.0.bits());
+        buf.put_u16(0);
+        tlv_encode_end(buf, start_pos);
+    }
+}
+
 // ===== global functions =====
 
 // The TLV length is padded to 4-byte alignment.
@@ -0,0 +1,500 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::collections::BTreeSet;
+use std::net::IpAddr;
+
+use holo_utils::ip::IpNetworkKind;
+use holo_utils::policy::{
+    MatchSetRestrictedType, MatchSets, MetricModification, MetricType, Policy,
+    PolicyAction, PolicyCondition, PolicyStmt,
+};
+use holo_utils::protocol::Protocol;
+use holo_yang::TryFromYang;
+use ipnetwork::IpNetwork;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::area::{Area, AreaType};
+use crate::collections::{Areas, Arena, LsdbId};
+use crate::debug::LsaFlushReason;
+use crate::instance::{Instance, InstanceUpView};
+use crate::interface::{ism, Interface};
+use crate::lsdb::{LsaEntry, LSA_INFINITY};
+use crate::packet::lsa::LsaKey;
+use crate::version::Version;
+
+// Type of the metric advertised in AS-external-LSAs.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum ExternalMetricType {
+    Type1,
+    Type2,
+}
+
+// Route learned from the global RIB that is a candidate for redistribution.
+#[derive(Clone, Debug)]
+pub struct RedistributedRoute {
+    pub protocol: Protocol,
+    pub metric: u32,
+    pub tag: Option<u32>,
+    pub node_tags: BTreeSet<u32>,
+}
+
+// External route advertised in an AS-external-LSA or NSSA-LSA.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExternalRoute {
+    pub metric: u32,
+    pub metric_type: ExternalMetricType,
+    pub tag: Option<u32>,
+    pub fwd_addr: Option<IpAddr>,
+    // NSSA-LSA P-bit.
+    pub propagate: bool,
+}
+
+// ===== impl ExternalMetricType =====
+
+impl TryFromYang for ExternalMetricType {
+    fn try_from_yang(value: &str) -> Option<ExternalMetricType> {
+        match value {
+            "type-1" => Some(ExternalMetricType::Type1),
+            "type-2" => Some(ExternalMetricType::Type2),
+            _ => None,
+        }
+    }
+}
+
+// ===== global functions =====
+
+// (Re)originates or flushes the AS-external-LSA and NSSA-LSAs of the given
+// prefix.
+pub(crate) fn update_prefix<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    areas: &mut Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+    prefix: V::IpNetwork,
+) where
+    V: Version,
+{
+    update_prefix_as_external(instance, lsa_entries, prefix);
+
+    let is_abr = areas.is_abr(interfaces);
+    for area in areas.iter_mut() {
+        update_prefix_nssa(
+            area,
+            instance,
+            interfaces,
+            lsa_entries,
+            is_abr,
+            prefix,
+        );
+    }
+}
+
+// (Re)originates or flushes all AS-external-LSAs and NSSA-LSAs.
+pub(crate) fn update_all<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    areas: &mut Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let default = V::IpNetwork::default(instance.state.af);
+    let prefixes = instance
+        .system
+        .redistributed_routes
+        .keys()
+        .filter_map(|prefix| V::IpNetwork::get(*prefix))
+        .chain(instance.state.external_routes.keys().copied())
+        .chain(instance.state.nssa_translated_routes.keys().copied())
+        .chain(
+            areas
+                .iter()
+                .flat_map(|area| area.state.nssa_external_routes.keys())
+                .copied(),
+        )
+        .chain(std::iter::once(default))
+        .collect::<BTreeSet<_>>();
+
+    for prefix in prefixes {
+        update_prefix(instance, areas, interfaces, lsa_entries, prefix);
+    }
+}
+
+// Reevaluates all redistributed routes after a routing policy change.
+pub(crate) fn process_policy_update<V>(instance: &mut Instance<V>)
+where
+    V: Version,
+{
+    if let Some((mut instance, arenas)) = instance.as_up() {
+        update_all(
+            &mut instance,
+            &mut arenas.areas,
+            &arenas.interfaces,
+            &arenas.lsa_entries,
+        );
+    }
+}
+
+// ===== helper functions =====
+
+// (Re)originates or flushes the AS-external-LSA of the given prefix.
+fn update_prefix_as_external<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+    prefix: V::IpNetwork,
+) where
+    V: Version,
+{
+    // Locally redistributed routes take precedence over translated NSSA
+    // routes.
+    let new_route = compute_external_route(instance, &prefix).or_else(|| {
+        instance.state.nssa_translated_routes.get(&prefix).cloned()
+    });
+    let old_route = instance.state.external_routes.remove(&prefix);
+
+    match (new_route, old_route) {
+        (Some(new_route), Some((old_lsa_id, old_route))) => {
+            // Reoriginate AS-external-LSA if the route has changed, reusing
+            // the previous LSA-ID.
+            if new_route != old_route {
+                V::lsa_orig_as_external(
+                    instance,
+                    prefix,
+                    Some(old_lsa_id),
+                    &new_route,
+                );
+            }
+            instance
+                .state
+                .external_routes
+                .insert(prefix, (old_lsa_id, new_route));
+        }
+        (Some(new_route), None) => {
+            // Originate new AS-external-LSA.
+            let lsa_id =
+                V::lsa_orig_as_external(instance, prefix, None, &new_route);
+            instance
+                .state
+                .external_routes
+                .insert(prefix, (lsa_id, new_route));
+        }
+        (None, Some((old_lsa_id, _))) => {
+            // Flush AS-external-LSA that is no longer valid.
+            flush_external_lsa(instance, lsa_entries, old_lsa_id);
+        }
+        (None, None) => (),
+    }
+}
+
+// (Re)originates or flushes the NSSA-LSA of the given prefix in the provided
+// area.
+fn update_prefix_nssa<V>(
+    area: &mut Area<V>,
+    instance: &mut InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+    is_abr: bool,
+    prefix: V::IpNetwork,
+) where
+    V: Version,
+{
+    let new_route = (area.config.area_type == AreaType::Nssa)
+        .then(|| compute_external_route(instance, &prefix))
+        .flatten()
+        .map(|mut route| {
+            // NSSA border routers clear the P-bit since they already originate
+            // the corresponding AS-external-LSA. The P-bit can only be set
+            // when a forwarding address is available.
+            route.fwd_addr = nssa_fwd_addr(area, interfaces);
+            route.propagate = !is_abr && route.fwd_addr.is_some();
+            route
+        });
+    let old_route = area.state.nssa_external_routes.remove(&prefix);
+
+    match (new_route, old_route) {
+        (Some(new_route), Some((old_lsa_id, old_route))) => {
+            // Reoriginate NSSA-LSA if the route has changed, reusing the
+            // previous LSA-ID.
+            if new_route != old_route {
+                V::lsa_orig_nssa_external(
+                    area,
+                    instance,
+                    prefix,
+                    Some(old_lsa_id),
+                    &new_route,
+                );
+            }
+            area.state
+                .nssa_external_routes
+                .insert(prefix, (old_lsa_id, new_route));
+        }
+        (Some(new_route), None) => {
+            // Originate new NSSA-LSA.
+            let lsa_id = V::lsa_orig_nssa_external(
+                area, instance, prefix, None, &new_route,
+            );
+            area.state
+                .nssa_external_routes
+                .insert(prefix, (lsa_id, new_route));
+        }
+        (None, Some((old_lsa_id, _))) => {
+            // Flush NSSA-LSA that is no longer valid.
+            flush_nssa_lsa(area, instance, lsa_entries, old_lsa_id);
+        }
+        (None, None) => (),
+    }
+}
+
+// Computes the external route that should be advertised for the given prefix,
+// if any.
+fn compute_external_route<V>(
+    instance: &InstanceUpView<'_, V>,
+    prefix: &V::IpNetwork,
+) -> Option<ExternalRoute>
+where
+    V: Version,
+{
+    // Ignore prefixes from other address families.
+    if prefix.address_family() != instance.state.af {
+        return None;
+    }
+
+    let prefix: IpNetwork = (*prefix).into();
+    let route = instance
+        .system
+        .redistributed_routes
+        .get(&prefix)
+        // Never redistribute routes learned by the instance itself.
+        .filter(|route| route.protocol != V::PROTOCOL);
+
+    // The default route is only advertised when explicitly configured.
+    if prefix.prefix() == 0 {
+        let config = &instance.config.default_info_originate;
+        if !config.enabled || (!config.always && route.is_none()) {
+            return None;
+        }
+
+        return Some(ExternalRoute {
+            metric: config.metric,
+            metric_type: config.metric_type,
+            tag: None,
+            fwd_addr: None,
+            propagate: false,
+        });
+    }
+
+    // Check if routes from the source protocol are configured to be
+    // redistributed.
+    let route = route?;
+    let config = instance.config.redistribution.get(&route.protocol)?;
+    let mut ext_route = ExternalRoute {
+        metric: config.metric,
+        metric_type: config.metric_type,
+        tag: config.tag,
+        fwd_addr: None,
+        propagate: false,
+    };
+
+    // Apply the configured routing policy. Routes are rejected when the
+    // policy doesn't exist.
+    if let Some(policy) = &config.policy {
+        let policy = instance.shared.policies.get(policy)?;
+        let match_sets = &instance.shared.policy_match_sets;
+        ext_route =
+            apply_policy(policy, match_sets, &prefix, route, ext_route)?;
+    }
+
+    // Ensure the metric is valid.
+    ext_route.metric = std::cmp::min(ext_route.metric, LSA_INFINITY - 1);
+
+    Some(ext_route)
+}
+
+// Applies a routing policy to the provided external route.
+//
+// Returns `None` if the route is rejected by the policy.
+fn apply_policy(
+    policy: &Policy,
+    match_sets: &MatchSets,
+    prefix: &IpNetwork,
+    route: &RedistributedRoute,
+    mut ext_route: ExternalRoute,
+) -> Option<ExternalRoute> {
+    for stmt in policy.stmts.values() {
+        // Check if all conditions in the policy statement are satisfied.
+        if !stmt.conditions.values().all(|condition| {
+            process_stmt_condition(stmt, condition, prefix, route, match_sets)
+        }) {
+            continue;
+        }
+
+        // Process actions defined in the policy statement.
+        for action in stmt.actions.values() {
+            if !process_stmt_action(&mut ext_route, action) {
+                return None;
+            }
+        }
+    }
+
+    Some(ext_route)
+}
+
+// Processes a single condition statement within a routing policy.
+//
+// Returns a boolean value indicating whether the condition is met.
+fn process_stmt_condition(
+    stmt: &PolicyStmt,
+    condition: &PolicyCondition,
+    prefix: &IpNetwork,
+    route: &RedistributedRoute,
+    match_sets: &MatchSets,
+) -> bool {
+    match condition {
+        // "source-protocol"
+        PolicyCondition::SrcProtocol(protocol) => route.protocol == *protocol,
+        // "match-prefix-set"
+        PolicyCondition::MatchPrefixSet(value) => {
+            let key = (value.clone(), prefix.address_family());
+            let matches = match_sets.prefixes.get(&key).is_some_and(|set| {
+                set.prefixes.iter().any(|range| {
+                    range.prefix.contains(prefix.ip())
+                        && range.prefix.prefix() <= prefix.prefix()
+                        && (range.masklen_lower..=range.masklen_upper)
+                            .contains(&prefix.prefix())
+                })
+            });
+            match stmt.prefix_set_match_type {
+                MatchSetRestrictedType::Any => matches,
+                MatchSetRestrictedType::Invert => !matches,
+            }
+        }
+        // "match-tag-set"
+        PolicyCondition::MatchTagSet(value) => {
+            // Both the route tag and the node administrative tags of the
+            // advertising router are matched against the tag set.
+            let tags = route
+                .tag
+                .into_iter()
+                .chain(route.node_tags.iter().copied())
+                .collect::<BTreeSet<_>>();
+            if tags.is_empty() {
+                return false;
+            }
+            let Some(set) = match_sets.tags.get(value) else {
+                return false;
+            };
+            stmt.tag_set_match_type.compare(&set.tags, &tags)
+        }
+        // Ignore unsupported conditions.
+        _ => true,
+    }
+}
+
+// Processes a single action statement within a routing policy.
+//
+// Returns a boolean value indicating whether the route should be accepted or
+// not.
+fn process_stmt_action(
+    ext_route: &mut ExternalRoute,
+    action: &PolicyAction,
+) -> bool {
+    match action {
+        // "policy-result"
+        PolicyAction::Accept(accept) => {
+            return *accept;
+        }
+        // "set-metric"
+        PolicyAction::SetMetric { value, mod_type } => match mod_type {
+            MetricModification::Set => {
+                ext_route.metric = *value;
+            }
+            MetricModification::Add => {
+                ext_route.metric = ext_route.metric.saturating_add(*value);
+            }
+            MetricModification::Subtract => {
+                ext_route.metric = ext_route.metric.saturating_sub(*value);
+            }
+        },
+        // "set-metric-type"
+        PolicyAction::SetMetricType(metric_type) => match metric_type {
+            MetricType::OspfType1 => {
+                ext_route.metric_type = ExternalMetricType::Type1;
+            }
+            MetricType::OspfType2 => {
+                ext_route.metric_type = ExternalMetricType::Type2;
+            }
+            _ => (),
+        },
+        // "set-tag"
+        PolicyAction::SetTag(tag) => {
+            ext_route.tag = Some(*tag);
+        }
+        // Ignore unsupported actions.
+        _ => (),
+    }
+
+    true
+}
+
+fn flush_external_lsa<V>(
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+    lsa_id: u32,
+) where
+    V: Version,
+{
+    let lsa_type = V::type5_external(instance.config.extended_lsa);
+    let adv_rtr = instance.state.router_id;
+    let lsa_key = LsaKey::new(lsa_type, adv_rtr, lsa_id.into());
+    if let Some((_, lse)) = instance.state.lsdb.get(lsa_entries, &lsa_key) {
+        instance.tx.protocol_input.lsa_flush(
+            LsdbId::As,
+            lse.id,
+            LsaFlushReason::PrematureAging,
+        );
+    }
+}
+
+fn flush_nssa_lsa<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+    lsa_id: u32,
+) where
+    V: Version,
+{
+    let lsa_type = V::type7_nssa(instance.config.extended_lsa);
+    let adv_rtr = instance.state.router_id;
+    let lsa_key = LsaKey::new(lsa_type, adv_rtr, lsa_id.into());
+    if let Some((_, lse)) = area.state.lsdb.get(lsa_entries, &lsa_key) {
+        instance.tx.protocol_input.lsa_flush(
+            LsdbId::Area(area.id),
+            lse.id,
+            LsaFlushReason::PrematureAging,
+        );
+    }
+}
+
+// Selects the forwarding address advertised in NSSA-LSAs originated into the
+// given area, giving preference to loopback addresses.
+fn nssa_fwd_addr<V>(
+    area: &Area<V>,
+    interfaces: &Arena<Interface<V>>,
+) -> Option<IpAddr>
+where
+    V: Version,
+{
+    area.interfaces
+        .iter(interfaces)
+        .filter(|iface| !iface.is_down())
+        .sorted_by_key(|iface| iface.state.ism_state != ism::State::Loopback)
+        .flat_map(|iface| iface.system.addr_list.iter())
+        .find(|addr| addr.is_routable())
+        .map(|addr| addr.ip().into())
+}
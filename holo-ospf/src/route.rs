@@ -10,12 +10,12 @@ use std::net::Ipv4Addr;
 
 use bitflags::bitflags;
 use derive_new::new;
-use holo_utils::ip::IpAddrKind;
+use holo_utils::ip::{IpAddrKind, IpNetworkKind};
 use holo_utils::mpls::Label;
 use holo_utils::southbound::OspfRouteType;
 use holo_utils::sr::IgpAlgoType;
 
-use crate::area::Area;
+use crate::area::{Area, AreaType};
 use crate::collections::{Areas, Arena, InterfaceIndex};
 use crate::debug::Debug;
 use crate::instance::InstanceUpView;
@@ -25,7 +25,7 @@ use crate::northbound::configuration::InstanceCfg;
 use crate::packet::lsa::{LsaKey, LsaRouterFlagsVersion};
 use crate::spf::{SpfPartialComputation, VertexLsaVersion};
 use crate::version::Version;
-use crate::{southbound, sr};
+use crate::{gr, southbound, sr};
 
 // Network routing table entry.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -37,9 +37,11 @@ pub struct RouteNet<V: Version> {
     pub metric: u32,
     pub type2_metric: Option<u32>,
     pub tag: Option<u32>,
+    pub node_tags: BTreeSet<u32>,
     pub prefix_sid: Option<V::PrefixSid>,
     pub sr_label: Option<Label>,
     pub nexthops: Nexthops<V::IpAddr>,
+    pub repair: Option<RouteRepair<V::IpAddr>>,
     pub flags: RouteNetFlags,
 }
 
@@ -61,6 +63,8 @@ pub struct RouteRtr<V: Version> {
     pub flags: V::LsaRouterFlags,
     pub metric: u32,
     pub nexthops: Nexthops<V::IpAddr>,
+    #[new(default)]
+    pub repair: Option<RouteRepair<V::IpAddr>>,
 }
 
 // Locally originated inter-area "network" route.
@@ -109,6 +113,33 @@ pub struct Nexthop<I: IpAddrKind> {
     pub sr_label: Option<Label>,
 }
 
+// Fast reroute repair path.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RouteRepair<I: IpAddrKind> {
+    // Type of the repair path.
+    pub repair_type: RepairType,
+    // Whether the repair path protects against the failure of the primary
+    // next-hop router (and not only the primary link).
+    pub node_protecting: bool,
+    // Repair nexthop.
+    pub nexthop: Nexthop<I>,
+    // Router whose SRGB is used to derive the destination's Prefix-SID label.
+    pub label_rtr: Ipv4Addr,
+    // Label stack imposed on packets sent over the repair path.
+    pub labels: Vec<Label>,
+    // Metric of the repair path.
+    pub metric: u32,
+    // Metric of the repair path from the repair nexthop.
+    pub alt_metric: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RepairType {
+    Lfa,
+    RemoteLfa,
+    TiLfa,
+}
+
 // Type aliases.
 pub type Nexthops<I: IpAddrKind> = BTreeMap<NexthopKey<I>, Nexthop<I>>;
 pub type PathType = OspfRouteType;
@@ -123,21 +154,38 @@ where
         match self.path_type {
             PathType::IntraArea => config.preference.intra_area,
             PathType::InterArea => config.preference.inter_area,
-            PathType::Type1External | PathType::Type2External => {
-                config.preference.external
-            }
+            PathType::Type1External
+            | PathType::Type2External
+            | PathType::Type1Nssa
+            | PathType::Type2Nssa => config.preference.external,
         }
     }
 
     pub(crate) fn metric(&self) -> u32 {
         match self.path_type {
             PathType::IntraArea | PathType::InterArea => self.metric,
-            PathType::Type1External => self.metric,
-            PathType::Type2External => self.type2_metric.unwrap(),
+            PathType::Type1External | PathType::Type1Nssa => self.metric,
+            PathType::Type2External | PathType::Type2Nssa => {
+                self.type2_metric.unwrap()
+            }
         }
     }
 }
 
+// ===== impl RouteRepair =====
+
+impl<I> RouteRepair<I>
+where
+    I: IpAddrKind,
+{
+    // Returns a copy of the repair path extended by the given metric.
+    pub(crate) fn extend(mut self, metric: u32) -> Self {
+        self.metric = self.metric.saturating_add(metric);
+        self.alt_metric = self.alt_metric.saturating_add(metric);
+        self
+    }
+}
+
 // ===== global functions =====
 
 // Updates the entire OSPF routing table.
@@ -178,12 +226,18 @@ pub(crate) fn update_rib_full<V>(
 
     // Compute external routes.
     update_rib_external(&mut rib, None, instance, areas, lsa_entries);
+    update_rib_nssa(&mut rib, None, instance, areas, interfaces, lsa_entries);
 
     // Update OSPF routes in the global RIB.
-    update_global_rib(&mut rib, old_rib, instance, interfaces);
+    let changed = update_global_rib(&mut rib, old_rib, instance, interfaces);
 
     // Save updated RIB.
     instance.state.rib = rib;
+
+    // Record the installed routes for graceful restart purposes.
+    if changed && instance.config.gr.enabled {
+        gr::restart_routes_save(instance);
+    }
 }
 
 // Updates the affected routes after a partial SPF run.
@@ -292,7 +346,10 @@ pub(crate) fn update_rib_partial<V>(
             (reevaluate_all || partial.external.contains(prefix))
                 && matches!(
                     route.path_type,
-                    PathType::Type1External | PathType::Type2External
+                    PathType::Type1External
+                        | PathType::Type2External
+                        | PathType::Type1Nssa
+                        | PathType::Type2Nssa
                 )
         }));
 
@@ -309,14 +366,28 @@ pub(crate) fn update_rib_partial<V>(
             areas,
             lsa_entries,
         );
+        update_rib_nssa(
+            &mut partial_rib,
+            filter,
+            instance,
+            areas,
+            interfaces,
+            lsa_entries,
+        );
     }
 
     // Update OSPF routes in the global RIB.
-    update_global_rib(&mut partial_rib, old_rib, instance, interfaces);
+    let changed =
+        update_global_rib(&mut partial_rib, old_rib, instance, interfaces);
 
     // Save updated RIB.
     rib.extend(partial_rib);
     instance.state.rib = rib;
+
+    // Record the installed routes for graceful restart purposes.
+    if changed && instance.config.gr.enabled {
+        gr::restart_routes_save(instance);
+    }
 }
 
 // ===== helper functions =====
@@ -392,9 +463,15 @@ fn update_rib_intra_area<V>(
             metric,
             type2_metric: None,
             tag: None,
+            node_tags: node_tags(area, origin.adv_rtr, lsa_entries),
             prefix_sid: None,
             sr_label: None,
             nexthops: stub.vertex.nexthops.clone(),
+            repair: stub
+                .vertex
+                .repair
+                .clone()
+                .map(|repair| repair.extend(stub.metric as u32)),
             flags,
         };
 
@@ -479,9 +556,14 @@ fn update_rib_inter_area_networks<V>(
             metric,
             type2_metric: None,
             tag: None,
+            node_tags: node_tags(area, lsa.adv_rtr, lsa_entries),
             prefix_sid: None,
             sr_label: None,
             nexthops: route_br.nexthops.clone(),
+            repair: route_br
+                .repair
+                .clone()
+                .map(|repair| repair.extend(lsa.metric)),
             flags: RouteNetFlags::empty(),
         };
 
@@ -566,6 +648,10 @@ fn update_rib_inter_area_routers<V>(
             flags: lsa.flags,
             metric,
             nexthops: route_br.nexthops.clone(),
+            repair: route_br
+                .repair
+                .clone()
+                .map(|repair| repair.extend(lsa.metric)),
         };
         area.state.routers.insert(lsa.router_id, new_route);
     }
@@ -600,9 +686,11 @@ fn update_rib_external<V>(
             })
     {
         // Look up the routing table entries (potentially one per attached area)
-        // for the AS boundary router (ASBR) that originated the LSA.
+        // for the AS boundary router (ASBR) that originated the LSA. ASBRs
+        // reachable only through NSSAs can't originate AS-external-LSAs.
         let mut asbr_routes = areas
             .iter()
+            .filter(|area| area.config.area_type == AreaType::Normal)
             .filter_map(|area| {
                 area.state
                     .routers
@@ -664,18 +752,30 @@ fn update_rib_external<V>(
             ),
         };
 
+        // Get the ASBR's node administrative tags.
+        let node_tags = areas
+            .get_by_area_id(route_asbr.area_id)
+            .map(|(_, area)| node_tags(area, lsa.adv_rtr, lsa_entries))
+            .unwrap_or_default();
+
         // Create new external route.
+        let lsa_type = V::type5_external(extended_lsa);
         let new_route = RouteNet {
             prefix_options: lsa.prefix_options,
             area_id: None,
             path_type,
-            origin: None,
+            origin: Some(LsaKey::new(lsa_type, lsa.adv_rtr, lsa.lsa_id)),
             metric,
             type2_metric,
             tag: lsa.tag,
+            node_tags,
             prefix_sid: None,
             sr_label: None,
             nexthops: route_asbr.nexthops.clone(),
+            repair: route_asbr
+                .repair
+                .clone()
+                .map(|repair| repair.extend(metric - route_asbr.metric)),
             flags: RouteNetFlags::empty(),
         };
 
@@ -684,18 +784,119 @@ fn update_rib_external<V>(
     }
 }
 
+// Computes NSSA external routes (RFC 3101, Section 2.5).
+fn update_rib_nssa<V>(
+    rib: &mut BTreeMap<V::IpNetwork, RouteNet<V>>,
+    filter: Option<&BTreeSet<V::IpNetwork>>,
+    instance: &InstanceUpView<'_, V>,
+    areas: &Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let extended_lsa = instance.config.extended_lsa;
+    let router_id = instance.state.router_id;
+    let is_abr = areas.is_abr(interfaces);
+    let default = V::IpNetwork::default(instance.state.af);
+
+    // Examine all NSSA-LSAs of all attached NSSA areas.
+    for area in areas
+        .iter()
+        .filter(|area| area.config.area_type == AreaType::Nssa)
+    {
+        for lsa in V::nssa_networks(&area.state.lsdb, extended_lsa, lsa_entries)
+            // Filter out unreachable LSAs.
+            .filter(|lsa| lsa.metric < LSA_INFINITY)
+            // Filter out LSAs originated by the calculating router itself.
+            .filter(|lsa| lsa.adv_rtr != router_id)
+            // An NSSA border router ignores Type-7 default routes whose P-bit
+            // is clear.
+            .filter(|lsa| !(is_abr && lsa.prefix == default && !lsa.p_bit))
+            // Filter prefixes when running partial SPF.
+            .filter(|lsa| {
+                if let Some(filter) = filter {
+                    filter.contains(&lsa.prefix)
+                } else {
+                    true
+                }
+            })
+        {
+            // Look up the intra-area routing table entry for the NSSA ASBR
+            // that originated the LSA.
+            let Some(route_asbr) = area
+                .state
+                .routers
+                .get(&lsa.adv_rtr)
+                .filter(|route| route.path_type == PathType::IntraArea)
+                .filter(|route| route.flags.is_asbr())
+            else {
+                Debug::<V>::SpfUnreachableAsbr(&lsa.prefix, lsa.adv_rtr).log();
+                continue;
+            };
+
+            // TODO: examine the forwarding address.
+
+            // Get path type and metric.
+            let (path_type, metric, type2_metric) = match lsa.e_bit {
+                true => {
+                    (PathType::Type2Nssa, route_asbr.metric, Some(lsa.metric))
+                }
+                false => {
+                    (PathType::Type1Nssa, route_asbr.metric + lsa.metric, None)
+                }
+            };
+
+            // Create new NSSA external route.
+            let lsa_type = V::type7_nssa(extended_lsa);
+            let new_route = RouteNet {
+                prefix_options: lsa.prefix_options,
+                area_id: Some(area.area_id),
+                path_type,
+                origin: Some(LsaKey::new(lsa_type, lsa.adv_rtr, lsa.lsa_id)),
+                metric,
+                type2_metric,
+                tag: lsa.tag,
+                node_tags: node_tags(area, lsa.adv_rtr, lsa_entries),
+                prefix_sid: None,
+                sr_label: None,
+                nexthops: route_asbr.nexthops.clone(),
+                repair: route_asbr
+                    .repair
+                    .clone()
+                    .map(|repair| repair.extend(metric - route_asbr.metric)),
+                flags: RouteNetFlags::empty(),
+            };
+
+            // Try to add or update NSSA external route in the RIB.
+            route_update(rib, lsa.prefix, new_route, instance.config.max_paths);
+        }
+    }
+}
+
 // Updates OSPF routes in the global RIB.
 //
 // This step should be done at the end of the routing table calculation to
 // prevent transient states from affecting the forwarding plane.
+//
+// Returns whether any route was installed or uninstalled.
 fn update_global_rib<V>(
     rib: &mut BTreeMap<V::IpNetwork, RouteNet<V>>,
     mut old_rib: BTreeMap<V::IpNetwork, RouteNet<V>>,
     instance: &InstanceUpView<'_, V>,
     interfaces: &Arena<Interface<V>>,
-) where
+) -> bool
+where
     V: Version,
 {
+    let mut changed = false;
+
+    // While performing a graceful restart, the preserved forwarding state is
+    // kept untouched until the restart is complete.
+    if instance.state.gr_restart.is_some() {
+        return changed;
+    }
+
     // Install new routes or routes that have changed.
     //
     // TODO: prioritize loopback routes to speedup BGP convergence.
@@ -709,8 +910,10 @@ fn update_global_rib<V>(
             // Skip reinstalling the route if it hasn't changed.
             if old_route.metric() == route.metric()
                 && old_route.tag == route.tag
+                && old_route.node_tags == route.node_tags
                 && old_route.sr_label == route.sr_label
                 && old_route.nexthops == route.nexthops
+                && old_route.repair == route.repair
             {
                 if old_route.flags.contains(RouteNetFlags::INSTALLED) {
                     route.flags.insert(RouteNetFlags::INSTALLED);
@@ -732,12 +935,15 @@ fn update_global_rib<V>(
                 route,
                 old_sr_label,
                 distance,
+                instance.config.ucmp_enabled,
                 interfaces,
             );
             route.flags.insert(RouteNetFlags::INSTALLED);
+            changed = true;
         } else if route.flags.contains(RouteNetFlags::INSTALLED) {
             southbound::tx::route_uninstall(&instance.tx.ibus, prefix, route);
             route.flags.remove(RouteNetFlags::INSTALLED);
+            changed = true;
         }
     }
 
@@ -747,7 +953,10 @@ fn update_global_rib<V>(
         .filter(|(_, route)| route.flags.contains(RouteNetFlags::INSTALLED))
     {
         southbound::tx::route_uninstall(&instance.tx.ibus, &dest, &route);
+        changed = true;
     }
+
+    changed
 }
 
 fn route_update<V>(
@@ -777,6 +986,12 @@ fn route_update<V>(
                 Ordering::Equal => {
                     // Merge nexthops.
                     curr_route.nexthops.extend(route.nexthops);
+
+                    // ECMP routes protect each other, so the repair path of
+                    // the individual paths is no longer needed.
+                    if curr_route.nexthops.len() > 1 {
+                        curr_route.repair = None;
+                    }
                 }
                 Ordering::Greater => {
                     // Ignore less preferred route.
@@ -803,19 +1018,21 @@ fn route_compare<V>(a: &RouteNet<V>, b: &RouteNet<V>) -> Ordering
 where
     V: Version,
 {
-    let cmp = a.path_type.cmp(&b.path_type);
+    // Type-5 and Type-7 routes of the same metric type are compared by their
+    // metrics before anything else (RFC 3101, Section 2.5).
+    let cmp = path_type_rank(a.path_type).cmp(&path_type_rank(b.path_type));
     if cmp != Ordering::Equal {
         return cmp;
     }
 
-    match a.path_type {
+    let cmp = match a.path_type {
         PathType::IntraArea | PathType::InterArea => a.metric.cmp(&b.metric),
-        PathType::Type1External => {
+        PathType::Type1External | PathType::Type1Nssa => {
             // TODO: prefer intra-area paths using non-backbone areas (16.4.1).
 
             a.metric.cmp(&b.metric)
         }
-        PathType::Type2External => {
+        PathType::Type2External | PathType::Type2Nssa => {
             let cmp = a.type2_metric.cmp(&b.type2_metric);
             if cmp != Ordering::Equal {
                 return cmp;
@@ -825,5 +1042,34 @@ where
 
             a.metric.cmp(&b.metric)
         }
+    };
+    if cmp != Ordering::Equal {
+        return cmp;
     }
+
+    // When the metrics are equal, Type-5 routes are preferred over Type-7
+    // routes.
+    a.path_type.cmp(&b.path_type)
+}
+
+fn path_type_rank(path_type: PathType) -> PathType {
+    match path_type {
+        PathType::Type1Nssa => PathType::Type1External,
+        PathType::Type2Nssa => PathType::Type2External,
+        _ => path_type,
+    }
+}
+
+// Returns the node administrative tags advertised by the given router in the
+// specified area.
+fn node_tags<V>(
+    area: &Area<V>,
+    router_id: Ipv4Addr,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> BTreeSet<u32>
+where
+    V: Version,
+{
+    V::area_router_information(&area.state.lsdb, router_id, lsa_entries)
+        .node_tags
 }
@@ -7,11 +7,15 @@
 use std::net::Ipv4Addr;
 
 use holo_utils::ip::IpNetworkKind;
-use holo_utils::southbound::{AddressFlags, AddressMsg, InterfaceUpdateMsg};
+use holo_utils::southbound::{
+    AddressFlags, AddressMsg, InterfaceUpdateMsg, RouteKeyMsg, RouteMsg,
+    RouteOpaqueAttrs,
+};
 
 use crate::instance::Instance;
 use crate::interface::Interface;
 use crate::lsdb::LsaOriginateEvent;
+use crate::redistribution::{self, RedistributedRoute};
 use crate::version::Version;
 
 // OSPF version-specific code.
@@ -47,26 +51,33 @@ pub(crate) fn process_iface_update<V>(
         return;
     };
 
-    // Lookup interface.
-    let Some((iface_idx, area)) = arenas.areas.iter_mut().find_map(|area| {
-        area.interfaces
-            .get_by_name(&arenas.interfaces, &msg.ifname)
-            .map(|(iface_idx, _iface)| (iface_idx, area))
-    }) else {
-        return;
-    };
-    let iface = &mut arenas.interfaces[iface_idx];
-
-    // Update interface data.
-    iface.system.mtu = Some(msg.mtu as u16);
-    iface.system.flags = msg.flags;
-    if iface.system.ifindex != Some(msg.ifindex) {
-        area.interfaces
-            .update_ifindex(iface_idx, iface, Some(msg.ifindex));
-    }
+    // Lookup interfaces. Multi-area interfaces share the same name as their
+    // primary interfaces.
+    for area in arenas.areas.iter_mut() {
+        let Some((iface_idx, _)) =
+            area.interfaces.get_by_name(&arenas.interfaces, &msg.ifname)
+        else {
+            continue;
+        };
+        let iface = &mut arenas.interfaces[iface_idx];
 
-    // Check if OSPF needs to be activated or deactivated on this interface.
-    iface.update(area, &instance, &mut arenas.neighbors, &arenas.lsa_entries);
+        // Update interface data.
+        iface.system.mtu = Some(msg.mtu as u16);
+        iface.system.flags = msg.flags;
+        if iface.system.ifindex != Some(msg.ifindex) {
+            area.interfaces
+                .update_ifindex(iface_idx, iface, Some(msg.ifindex));
+        }
+
+        // Check if OSPF needs to be activated or deactivated on this
+        // interface.
+        iface.update(
+            area,
+            &instance,
+            &mut arenas.neighbors,
+            &arenas.lsa_entries,
+        );
+    }
 }
 
 pub(crate) fn process_addr_add<V>(instance: &mut Instance<V>, msg: AddressMsg)
@@ -82,43 +93,49 @@ where
         return;
     };
 
-    // Lookup interface.
-    let Some((iface_idx, area)) = arenas.areas.iter().find_map(|area| {
-        area.interfaces
-            .get_by_name(&arenas.interfaces, &msg.ifname)
-            .map(|(iface_idx, _iface)| (iface_idx, area))
-    }) else {
-        return;
-    };
-    let iface = &mut arenas.interfaces[iface_idx];
+    // Lookup interfaces. Multi-area interfaces share the same name as their
+    // primary interfaces.
+    for area in arenas.areas.iter() {
+        let Some((iface_idx, _)) =
+            area.interfaces.get_by_name(&arenas.interfaces, &msg.ifname)
+        else {
+            continue;
+        };
+        let iface = &mut arenas.interfaces[iface_idx];
 
-    // Add address to interface.
-    if !iface.system.addr_list.insert(addr) {
-        return;
-    }
+        // Add address to interface.
+        if !iface.system.addr_list.insert(addr) {
+            continue;
+        }
 
-    // Check if the instance does routing for this address-family.
-    if addr.address_family() == instance.state.af {
-        // (Re)originate LSAs that might have been affected.
-        instance.tx.protocol_input.lsa_orig_event(
-            LsaOriginateEvent::InterfaceAddrAddDel {
-                area_id: area.id,
-                iface_id: iface.id,
-            },
-        );
-    }
+        // Check if the instance does routing for this address-family.
+        if addr.address_family() == instance.state.af {
+            // (Re)originate LSAs that might have been affected.
+            instance.tx.protocol_input.lsa_orig_event(
+                LsaOriginateEvent::InterfaceAddrAddDel {
+                    area_id: area.id,
+                    iface_id: iface.id,
+                },
+            );
+        }
+
+        // OSPF version-specific address handling.
+        if let Some(addr) = V::NetIpNetwork::get(msg.addr) {
+            V::process_addr_add(
+                iface,
+                addr,
+                msg.flags.contains(AddressFlags::UNNUMBERED),
+            );
+        }
 
-    // OSPF version-specific address handling.
-    if let Some(addr) = V::NetIpNetwork::get(msg.addr) {
-        V::process_addr_add(
-            iface,
-            addr,
-            msg.flags.contains(AddressFlags::UNNUMBERED),
+        // Check if OSPF needs to be activated on this interface.
+        iface.update(
+            area,
+            &instance,
+            &mut arenas.neighbors,
+            &arenas.lsa_entries,
         );
     }
-
-    // Check if OSPF needs to be activated on this interface.
-    iface.update(area, &instance, &mut arenas.neighbors, &arenas.lsa_entries);
 }
 
 pub(crate) fn process_addr_del<V>(instance: &mut Instance<V>, msg: AddressMsg)
@@ -134,37 +151,114 @@ where
         return;
     };
 
-    // Lookup interface.
-    let Some((iface_idx, area)) = arenas.areas.iter().find_map(|area| {
-        area.interfaces
-            .get_by_name(&arenas.interfaces, &msg.ifname)
-            .map(|(iface_idx, _iface)| (iface_idx, area))
-    }) else {
+    // Lookup interfaces. Multi-area interfaces share the same name as their
+    // primary interfaces.
+    for area in arenas.areas.iter() {
+        let Some((iface_idx, _)) =
+            area.interfaces.get_by_name(&arenas.interfaces, &msg.ifname)
+        else {
+            continue;
+        };
+        let iface = &mut arenas.interfaces[iface_idx];
+
+        // Remove address from interface.
+        if !iface.system.addr_list.remove(&addr) {
+            continue;
+        }
+
+        // Check if the instance does routing for this address-family.
+        if addr.address_family() == instance.state.af {
+            // (Re)originate LSAs that might have been affected.
+            instance.tx.protocol_input.lsa_orig_event(
+                LsaOriginateEvent::InterfaceAddrAddDel {
+                    area_id: area.id,
+                    iface_id: iface.id,
+                },
+            );
+        }
+
+        // OSPF version-specific address handling.
+        if let Some(addr) = V::NetIpNetwork::get(msg.addr) {
+            V::process_addr_del(iface, addr);
+        }
+
+        // Check if OSPF needs to be deactivated on this interface.
+        iface.update(
+            area,
+            &instance,
+            &mut arenas.neighbors,
+            &arenas.lsa_entries,
+        );
+    }
+}
+
+pub(crate) fn process_route_add<V>(instance: &mut Instance<V>, msg: RouteMsg)
+where
+    V: Version,
+{
+    // Get prefix value.
+    let Some(prefix) = V::IpNetwork::get(msg.prefix) else {
         return;
     };
-    let iface = &mut arenas.interfaces[iface_idx];
 
-    // Remove address from interface.
-    if !iface.system.addr_list.remove(&addr) {
-        return;
-    }
+    // Get the node administrative tags of OSPF routes.
+    let node_tags = match msg.opaque_attrs {
+        RouteOpaqueAttrs::Ospf { node_tags, .. } => node_tags,
+        RouteOpaqueAttrs::None => Default::default(),
+    };
+
+    // Update the local copy of the route.
+    let route = RedistributedRoute {
+        protocol: msg.protocol,
+        metric: msg.metric,
+        tag: msg.tag,
+        node_tags,
+    };
+    instance
+        .system
+        .redistributed_routes
+        .insert(msg.prefix, route);
 
-    // Check if the instance does routing for this address-family.
-    if addr.address_family() == instance.state.af {
-        // (Re)originate LSAs that might have been affected.
-        instance.tx.protocol_input.lsa_orig_event(
-            LsaOriginateEvent::InterfaceAddrAddDel {
-                area_id: area.id,
-                iface_id: iface.id,
-            },
+    // (Re)originate or flush the corresponding AS-external-LSA and
+    // NSSA-LSAs.
+    if let Some((mut instance, arenas)) = instance.as_up() {
+        redistribution::update_prefix(
+            &mut instance,
+            &mut arenas.areas,
+            &arenas.interfaces,
+            &arenas.lsa_entries,
+            prefix,
         );
     }
+}
+
+pub(crate) fn process_route_del<V>(instance: &mut Instance<V>, msg: RouteKeyMsg)
+where
+    V: Version,
+{
+    // Get prefix value.
+    let Some(prefix) = V::IpNetwork::get(msg.prefix) else {
+        return;
+    };
 
-    // OSPF version-specific address handling.
-    if let Some(addr) = V::NetIpNetwork::get(msg.addr) {
-        V::process_addr_del(iface, addr);
+    // Remove the local copy of the route.
+    if instance
+        .system
+        .redistributed_routes
+        .remove(&msg.prefix)
+        .is_none()
+    {
+        return;
     }
 
-    // Check if OSPF needs to be deactivated on this interface.
-    iface.update(area, &instance, &mut arenas.neighbors, &arenas.lsa_entries);
+    // Flush the corresponding AS-external-LSA and NSSA-LSAs, if any.
+    if let Some((mut instance, arenas)) = instance.as_up() {
+        redistribution::update_prefix(
+            &mut instance,
+            &mut arenas.areas,
+            &arenas.interfaces,
+            &arenas.lsa_entries,
+            prefix,
+        );
+    }
 }
@@ -5,14 +5,18 @@
 //
 
 use std::collections::BTreeSet;
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv6Addr};
 
 use holo_utils::ibus::{IbusMsg, IbusSender};
+use holo_utils::link_state::LsTopologyMsg;
 use holo_utils::mpls::Label;
+use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
     LabelInstallMsg, LabelUninstallMsg, Nexthop, RouteKeyMsg, RouteMsg,
-    RouteOpaqueAttrs,
+    RouteOpaqueAttrs, Srv6SidInstallMsg, Srv6SidUninstallMsg,
 };
+use holo_utils::sr::Srv6EndpointBehavior;
+use ipnetwork::IpNetwork;
 
 use crate::collections::Arena;
 use crate::interface::Interface;
@@ -25,41 +29,77 @@ pub(crate) fn router_id_query(ibus_tx: &IbusSender) {
     let _ = ibus_tx.send(IbusMsg::RouterIdQuery);
 }
 
+pub(crate) fn route_redistribute_dump(ibus_tx: &IbusSender) {
+    let _ = ibus_tx.send(IbusMsg::RouteRedistributeDump { af: None });
+}
+
+pub(crate) fn ldp_sync_reg(ibus_tx: &IbusSender, ifname: &str) {
+    let _ = ibus_tx.send(IbusMsg::LdpSyncReg(ifname.to_owned()));
+}
+
+pub(crate) fn ldp_sync_unreg(ibus_tx: &IbusSender, ifname: &str) {
+    let _ = ibus_tx.send(IbusMsg::LdpSyncUnreg(ifname.to_owned()));
+}
+
 pub(crate) fn route_install<V>(
     ibus_tx: &IbusSender,
     destination: &V::IpNetwork,
     route: &RouteNet<V>,
     old_sr_label: Option<Label>,
     distance: u8,
+    ucmp: bool,
     interfaces: &Arena<Interface<V>>,
 ) where
     V: Version,
 {
+    // In UCMP mode, nexthops are weighted according to the bandwidth of their
+    // outgoing interfaces. Weights are only used when the bandwidth of all
+    // outgoing interfaces is known.
+    let ucmp = ucmp
+        && route.nexthops.len() > 1
+        && route.nexthops.values().all(|nexthop| {
+            interfaces[nexthop.iface_idx].config.bandwidth.is_some()
+        });
+
     // Fill-in nexthops.
     let nexthops = route
         .nexthops
         .values()
-        .map(|nexthop| match nexthop.addr {
-            Some(addr) => {
-                let iface = &interfaces[nexthop.iface_idx];
-                Nexthop::Address {
-                    ifindex: iface.system.ifindex.unwrap(),
+        .map(|nexthop| {
+            let iface = &interfaces[nexthop.iface_idx];
+            let ifindex = iface.system.ifindex.unwrap();
+            let weight = ucmp.then_some(iface.config.bandwidth).flatten();
+            match nexthop.addr {
+                Some(addr) => Nexthop::Address {
+                    ifindex,
                     addr: <V::IpAddr as Into<IpAddr>>::into(addr),
                     labels: nexthop
                         .sr_label
                         .map(|label| vec![label])
                         .unwrap_or_default(),
-                }
-            }
-            None => {
-                let iface = &interfaces[nexthop.iface_idx];
-                Nexthop::Interface {
-                    ifindex: iface.system.ifindex.unwrap(),
-                }
+                    weight,
+                },
+                None => Nexthop::Interface { ifindex, weight },
             }
         })
         .collect::<BTreeSet<_>>();
 
+    // Fill-in backup nexthops.
+    let backup_nexthops = route
+        .repair
+        .iter()
+        .filter_map(|repair| {
+            let addr = repair.nexthop.addr?;
+            let iface = &interfaces[repair.nexthop.iface_idx];
+            Some(Nexthop::Address {
+                ifindex: iface.system.ifindex.unwrap(),
+                addr: <V::IpAddr as Into<IpAddr>>::into(addr),
+                labels: repair.labels.clone(),
+                weight: None,
+            })
+        })
+        .collect::<BTreeSet<_>>();
+
     // Install route.
     let msg = RouteMsg {
         protocol: V::PROTOCOL,
@@ -69,8 +109,10 @@ pub(crate) fn route_install<V>(
         tag: route.tag,
         opaque_attrs: RouteOpaqueAttrs::Ospf {
             route_type: route.path_type,
+            node_tags: route.node_tags.clone(),
         },
         nexthops: nexthops.clone(),
+        backup_nexthops,
     };
     let msg = IbusMsg::RouteIpAdd(msg);
     let _ = ibus_tx.send(msg);
@@ -131,6 +173,18 @@ pub(crate) fn route_uninstall<V>(
     }
 }
 
+pub(crate) fn route_stale_uninstall<V>(ibus_tx: &IbusSender, prefix: IpNetwork)
+where
+    V: Version,
+{
+    let msg = RouteKeyMsg {
+        protocol: V::PROTOCOL,
+        prefix,
+    };
+    let msg = IbusMsg::RouteIpStaleDel(msg);
+    let _ = ibus_tx.send(msg);
+}
+
 pub(crate) fn adj_sid_install<V>(
     ibus_tx: &IbusSender,
     iface: &Interface<V>,
@@ -146,6 +200,7 @@ pub(crate) fn adj_sid_install<V>(
             ifindex: iface.system.ifindex.unwrap(),
             addr: nbr_addr.into(),
             labels: vec![Label::new(Label::IMPLICIT_NULL)],
+            weight: None,
         }]
         .into(),
         route: None,
@@ -168,3 +223,82 @@ where
     let msg = IbusMsg::RouteMplsDel(msg);
     let _ = ibus_tx.send(msg);
 }
+
+pub(crate) fn flex_algo_label_install<V>(
+    ibus_tx: &IbusSender,
+    label: Label,
+    nexthops: BTreeSet<Nexthop>,
+) where
+    V: Version,
+{
+    let msg = LabelInstallMsg {
+        protocol: V::PROTOCOL,
+        label,
+        nexthops,
+        route: None,
+        replace: true,
+    };
+    let msg = IbusMsg::RouteMplsAdd(msg);
+    let _ = ibus_tx.send(msg);
+}
+
+pub(crate) fn flex_algo_label_uninstall<V>(ibus_tx: &IbusSender, label: Label)
+where
+    V: Version,
+{
+    let msg = LabelUninstallMsg {
+        protocol: V::PROTOCOL,
+        label,
+        nexthops: BTreeSet::new(),
+        route: None,
+    };
+    let msg = IbusMsg::RouteMplsDel(msg);
+    let _ = ibus_tx.send(msg);
+}
+
+pub(crate) fn srv6_sid_install<V>(
+    ibus_tx: &IbusSender,
+    sid: Ipv6Addr,
+    behavior: Srv6EndpointBehavior,
+    ifindex: Option<u32>,
+    nexthop: Option<Ipv6Addr>,
+) where
+    V: Version,
+{
+    let msg = Srv6SidInstallMsg {
+        protocol: V::PROTOCOL,
+        sid,
+        behavior,
+        ifindex,
+        nexthop,
+    };
+    let msg = IbusMsg::RouteSrv6SidAdd(msg);
+    let _ = ibus_tx.send(msg);
+}
+
+pub(crate) fn srv6_sid_uninstall<V>(ibus_tx: &IbusSender, sid: Ipv6Addr)
+where
+    V: Version,
+{
+    let msg = Srv6SidUninstallMsg {
+        protocol: V::PROTOCOL,
+        sid,
+    };
+    let msg = IbusMsg::RouteSrv6SidDel(msg);
+    let _ = ibus_tx.send(msg);
+}
+
+pub(crate) fn link_state_update(ibus_tx: &IbusSender, msg: LsTopologyMsg) {
+    let _ = ibus_tx.send(IbusMsg::LinkStateUpd(msg.into()));
+}
+
+pub(crate) fn link_state_delete(
+    ibus_tx: &IbusSender,
+    protocol: Protocol,
+    instance: &str,
+) {
+    let _ = ibus_tx.send(IbusMsg::LinkStateDel {
+        protocol,
+        instance: instance.to_owned(),
+    });
+}
@@ -13,6 +13,7 @@ use std::time::{Duration, Instant};
 use chrono::Utc;
 use derive_new::new;
 use holo_utils::ip::AddressFamily;
+use holo_utils::mpls::Label;
 use holo_utils::sr::IgpAlgoType;
 
 use crate::area::Area;
@@ -24,10 +25,14 @@ use crate::interface::Interface;
 use crate::lsdb::{LsaEntry, LsaLogId};
 use crate::neighbor::Neighbor;
 use crate::packet::lsa::{Lsa, LsaKey};
-use crate::packet::tlv::{SidLabelRangeTlv, SrAlgoTlv};
-use crate::route::{Nexthops, PathType, RouteRtr};
+use crate::packet::tlv::{
+    AslaTlv, DynamicHostnameTlv, FlexAlgoDefTlv, SidLabelRangeTlv, SrAlgoTlv,
+};
+use crate::route::{Nexthops, PathType, RouteRepair, RouteRtr};
 use crate::version::Version;
-use crate::{area, route, tasks};
+use crate::{
+    area, flex_algo, frr, link_state, nssa, redistribution, route, tasks, vlink,
+};
 
 // Maximum size of the SPF log record.
 const SPF_LOG_MAX_SIZE: usize = 32;
@@ -40,13 +45,23 @@ pub struct Vertex<V: Version> {
     pub lsa: V::VertexLsa,
     pub distance: u16,
     pub hops: u16,
+    // Parents of the vertex in the shortest-path tree (more than one in the
+    // presence of equal-cost paths).
+    #[new(default)]
+    pub parents: Vec<V::VertexId>,
     #[new(default)]
     pub nexthops: Nexthops<V::IpAddr>,
+    #[new(default)]
+    pub repair: Option<RouteRepair<V::IpAddr>>,
 }
 
 #[derive(Debug)]
 pub enum SpfComputation<V: Version> {
     Full,
+    // Full computation where the shortest-path trees are updated
+    // incrementally, recomputing only the subtrees rooted at the vertices
+    // whose LSAs have changed (indexed by area ID).
+    Incremental(BTreeMap<Ipv4Addr, BTreeSet<V::VertexId>>),
     Partial(SpfPartialComputation<V>),
 }
 
@@ -96,7 +111,9 @@ pub struct SpfInterAreaRouter<V: Version> {
 #[derive(Debug)]
 pub struct SpfExternalNetwork<V: Version> {
     pub adv_rtr: Ipv4Addr,
+    pub lsa_id: Ipv4Addr,
     pub e_bit: bool,
+    pub p_bit: bool,
     pub prefix: V::IpNetwork,
     pub prefix_options: V::PrefixOptions,
     pub metric: u32,
@@ -106,8 +123,11 @@ pub struct SpfExternalNetwork<V: Version> {
 
 #[derive(Debug, Default)]
 pub struct SpfRouterInfo<'a> {
+    pub hostname: Option<&'a DynamicHostnameTlv>,
+    pub node_tags: BTreeSet<u32>,
     pub sr_algo: Option<&'a SrAlgoTlv>,
     pub srgb: Vec<&'a SidLabelRangeTlv>,
+    pub fads: Vec<&'a FlexAlgoDefTlv>,
 }
 
 #[derive(Debug, new)]
@@ -120,6 +140,7 @@ pub struct SpfTriggerLsa<V: Version> {
 #[derive(Debug)]
 pub enum SpfLogType {
     Full,
+    Incremental,
     Intra,
     Inter,
     External,
@@ -206,6 +227,13 @@ pub trait SpfVersion<V: Version> {
         lsa_entries: &'a Arena<LsaEntry<V>>,
     ) -> impl Iterator<Item = SpfExternalNetwork<V>> + 'a;
 
+    // Return iterator over all NSSA external networks.
+    fn nssa_networks<'a>(
+        lsdb: &'a Lsdb<V>,
+        extended_lsa: bool,
+        lsa_entries: &'a Arena<LsaEntry<V>>,
+    ) -> impl Iterator<Item = SpfExternalNetwork<V>> + 'a;
+
     // Locate the Router Information LSA for the specified area.
     fn area_router_information<'a>(
         lsdb: &'a Lsdb<V>,
@@ -221,6 +249,37 @@ pub trait SpfVersion<V: Version> {
         _lsa_entries: &Arena<LsaEntry<V>>,
     ) {
     }
+
+    // Find the SR Adj-SID label advertised by the given router for its
+    // adjacency with the specified neighbor.
+    fn adj_sid_find(
+        area: &Area<V>,
+        adv_rtr: Ipv4Addr,
+        nbr_router_id: Ipv4Addr,
+        extended_lsa: bool,
+        lsa_entries: &Arena<LsaEntry<V>>,
+    ) -> Option<Label>;
+
+    // Find the Application-Specific Link Attributes advertised by the given
+    // router for one of its Router-LSA links.
+    fn link_asla<'a>(
+        area: &'a Area<V>,
+        adv_rtr: Ipv4Addr,
+        link: &'a V::LsaRouterLink,
+        lsa_entries: &'a Arena<LsaEntry<V>>,
+    ) -> Option<&'a AslaTlv>;
+
+    // Return the address of the given virtual link endpoint, as advertised in
+    // its Router-LSA for the transit area.
+    //
+    // Virtual links are only supported by OSPFv2.
+    fn virtual_link_addr(
+        _transit_area: &Area<V>,
+        _router_id: Ipv4Addr,
+        _lsa_entries: &Arena<LsaEntry<V>>,
+    ) -> Option<V::NetIpAddr> {
+        None
+    }
 }
 
 // OSPF version-specific code.
@@ -422,8 +481,8 @@ where
             compute_spf(
                 instance,
                 &mut arenas.areas,
-                &arenas.interfaces,
-                &arenas.neighbors,
+                &mut arenas.interfaces,
+                &mut arenas.neighbors,
                 &arenas.lsa_entries,
                 false,
             );
@@ -443,8 +502,8 @@ where
             compute_spf(
                 instance,
                 &mut arenas.areas,
-                &arenas.interfaces,
-                &arenas.neighbors,
+                &mut arenas.interfaces,
+                &mut arenas.neighbors,
                 &arenas.lsa_entries,
                 true,
             );
@@ -475,14 +534,52 @@ where
     Ok(())
 }
 
+// Computes the set of nexthops that should be used to reach the given
+// destination.
+pub(crate) fn calc_nexthops<V>(
+    area: &Area<V>,
+    parent: &Vertex<V>,
+    parent_link: Option<(usize, &V::LsaRouterLink)>,
+    dest_id: V::VertexId,
+    dest_lsa: &V::VertexLsa,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    extended_lsa: bool,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Result<Nexthops<V::IpAddr>, Error<V>>
+where
+    V: Version,
+{
+    if parent.hops == 0 {
+        // The parent vertex is the root or a network that directly connects the
+        // calculating router to the destination router.
+        V::calc_nexthops(
+            area,
+            parent,
+            parent_link,
+            dest_id,
+            dest_lsa,
+            interfaces,
+            neighbors,
+            extended_lsa,
+            lsa_entries,
+        )
+    } else {
+        // If there is at least one intervening router in the current shortest
+        // path between the destination and the root, the destination simply
+        // inherits the set of next hops from the parent.
+        Ok(parent.nexthops.clone())
+    }
+}
+
 // ===== helper functions =====
 
 // This is the SPF main function.
 fn compute_spf<V>(
     instance: &mut InstanceUpView<'_, V>,
     areas: &mut Areas<V>,
-    interfaces: &Arena<Interface<V>>,
-    neighbors: &Arena<Neighbor<V>>,
+    interfaces: &mut Arena<Interface<V>>,
+    neighbors: &mut Arena<Neighbor<V>>,
     lsa_entries: &Arena<LsaEntry<V>>,
     force_full_run: bool,
 ) where
@@ -506,16 +603,72 @@ fn compute_spf<V>(
         true => SpfComputation::Full,
         false => V::spf_computation_type(&trigger_lsas),
     };
+
+    // Incremental SPF isn't used when virtual links are configured, since their
+    // endpoints depend on the SPTs of all transit areas.
+    if matches!(spf_computation_type, SpfComputation::Incremental(_))
+        && (!instance.config.spf_incremental
+            || interfaces.iter().any(|(_, iface)| iface.is_virtual_link()))
+    {
+        spf_computation_type = SpfComputation::Full;
+    }
+
     match &mut spf_computation_type {
         SpfComputation::Full => {
-            // Calculate shortest-path trees.
-            for area in areas.iter_mut() {
+            // Calculate shortest-path trees. The backbone area is processed
+            // last, once the virtual link endpoints are known.
+            for area in areas.iter_mut().filter(|area| !area.is_backbone()) {
                 run_area(area, instance, interfaces, neighbors, lsa_entries);
             }
+            vlink::update_endpoints(
+                instance,
+                areas,
+                interfaces,
+                neighbors,
+                lsa_entries,
+            );
+            if let Some((_, backbone)) =
+                areas.get_mut_by_area_id(Ipv4Addr::UNSPECIFIED)
+            {
+                run_area(
+                    backbone,
+                    instance,
+                    interfaces,
+                    neighbors,
+                    lsa_entries,
+                );
+            }
 
             // Update routing table.
             route::update_rib_full(instance, areas, interfaces, lsa_entries);
         }
+        SpfComputation::Incremental(vertices) => {
+            // Update the shortest-path trees of the areas whose topology has
+            // changed.
+            let mut fallback = false;
+            for area in areas.iter_mut() {
+                let Some(vertices) = vertices.get(&area.area_id) else {
+                    continue;
+                };
+                fallback |= !run_area_incremental(
+                    area,
+                    vertices,
+                    instance,
+                    interfaces,
+                    neighbors,
+                    lsa_entries,
+                );
+            }
+
+            // Update routing table.
+            route::update_rib_full(instance, areas, interfaces, lsa_entries);
+
+            // Log the SPF run as a full one if any area had to fall back to
+            // a full computation.
+            if fallback {
+                spf_computation_type = SpfComputation::Full;
+            }
+        }
         SpfComputation::Partial(partial) => {
             // Update routing table.
             route::update_rib_partial(
@@ -528,9 +681,25 @@ fn compute_spf<V>(
         }
     }
 
+    // Run the NSSA translator election and update the set of translated NSSA
+    // routes.
+    nssa::update_translator_state(instance, areas, interfaces);
+    nssa::update_translated_routes(instance, areas, lsa_entries);
+
     // Update summary LSAs.
     area::update_summary_lsas(instance, areas, interfaces, lsa_entries);
 
+    // (Re)originate or flush AS-external-LSAs and NSSA-LSAs.
+    redistribution::update_all(instance, areas, interfaces, lsa_entries);
+
+    // Update the Flex-Algo Prefix-SID forwarding entries.
+    flex_algo::update_lfib(instance, areas, interfaces, neighbors, lsa_entries);
+
+    // Export the updated topology to BGP-LS.
+    if instance.config.distribute_link_state {
+        link_state::topology_update(instance, areas, lsa_entries);
+    }
+
     // Update time of last SPF computation.
     let end_time = Instant::now();
     instance.state.spf_last_time = Some(end_time);
@@ -555,34 +724,224 @@ fn run_area<V>(
     lsa_entries: &Arena<LsaEntry<V>>,
 ) where
     V: Version,
+{
+    // Parse and compile opaque data that is relevant to the SPF computation.
+    V::area_opaque_data_compile(area, lsa_entries);
+
+    // Compute the shortest-path tree from scratch.
+    let Some(spt) =
+        spt_compute_full(area, instance, interfaces, neighbors, lsa_entries)
+    else {
+        Error::<V>::SpfRootNotFound(area.area_id).log();
+        area.state.transit_capability = false;
+        return;
+    };
+
+    // Update area's SPT.
+    spt_update(area, spt, instance, interfaces, neighbors, lsa_entries);
+}
+
+// Runs incremental SPF in the provided area.
+//
+// Only the vertices whose shortest paths traverse one of the changed vertices
+// are recomputed, while the rest of the previous shortest-path tree is
+// preserved. Whenever the changes might lead to better paths to the preserved
+// vertices, this falls back to a full computation.
+//
+// Returns false if a full computation was performed instead.
+fn run_area_incremental<V>(
+    area: &mut Area<V>,
+    changed: &BTreeSet<V::VertexId>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> bool
+where
+    V: Version,
 {
     let af = instance.state.af;
     let extended_lsa = instance.config.extended_lsa;
-    area.state.transit_capability = false;
+
+    // A change to the root vertex affects the whole tree.
+    let root_vid = V::VertexId::new_root(instance.state.router_id);
+    if area.state.spt.is_empty() || changed.contains(&root_vid) {
+        run_area(area, instance, interfaces, neighbors, lsa_entries);
+        return false;
+    }
 
     // Parse and compile opaque data that is relevant to the SPF computation.
     V::area_opaque_data_compile(area, lsa_entries);
 
+    // Get the vertices that need to be recomputed: the changed vertices and
+    // all their descendants in the previous shortest-path tree.
+    let mut spt = std::mem::take(&mut area.state.spt);
+    let mut children: BTreeMap<_, Vec<_>> = BTreeMap::new();
+    for vertex in spt.values() {
+        for parent in &vertex.parents {
+            children.entry(*parent).or_default().push(vertex.id);
+        }
+    }
+    let mut affected = changed.clone();
+    let mut stack = changed.iter().copied().collect::<Vec<_>>();
+    while let Some(vertex_id) = stack.pop() {
+        for child in children.get(&vertex_id).into_iter().flatten() {
+            if affected.insert(*child) {
+                stack.push(*child);
+            }
+        }
+    }
+    spt.retain(|vertex_id, _| !affected.contains(vertex_id));
+
+    // Initialize the candidate list with the paths from the preserved
+    // vertices to the affected ones.
+    let mut cand_list = BTreeMap::new();
+    for vertex_id in &affected {
+        let Some(vertex_lsa) =
+            V::vertex_lsa_find(af, *vertex_id, area, extended_lsa, lsa_entries)
+        else {
+            continue;
+        };
+        let parents = V::vertex_lsa_links(
+            &vertex_lsa,
+            af,
+            area,
+            extended_lsa,
+            lsa_entries,
+        )
+        .map(|link| link.id)
+        .filter(|link_id| spt.contains_key(link_id))
+        .collect::<BTreeSet<_>>();
+        for parent in parents.iter().map(|parent| &spt[parent]) {
+            for link in V::vertex_lsa_links(
+                &parent.lsa,
+                af,
+                area,
+                extended_lsa,
+                lsa_entries,
+            )
+            .filter(|link| link.id == *vertex_id)
+            {
+                spf_relax(
+                    area,
+                    parent,
+                    link,
+                    &spt,
+                    &mut cand_list,
+                    instance,
+                    interfaces,
+                    neighbors,
+                    lsa_entries,
+                );
+            }
+        }
+    }
+
+    // Recompute the affected part of the shortest-path tree.
+    let spt = spt_compute(
+        area,
+        spt,
+        cand_list,
+        instance,
+        interfaces,
+        neighbors,
+        lsa_entries,
+    );
+
+    // Fall back to a full computation if any of the recomputed vertices
+    // provides an alternative path, of equal or lower cost, to a preserved
+    // vertex.
+    let alt_path = affected
+        .iter()
+        .filter_map(|vertex_id| spt.get(vertex_id))
+        .any(|vertex| {
+            V::vertex_lsa_links(
+                &vertex.lsa,
+                af,
+                area,
+                extended_lsa,
+                lsa_entries,
+            )
+            .filter(|link| !affected.contains(&link.id))
+            .filter(|link| {
+                vertex_lsa_linked(
+                    &link.lsa,
+                    vertex.id,
+                    area,
+                    instance,
+                    lsa_entries,
+                )
+            })
+            .any(|link| {
+                spt.get(&link.id).is_some_and(|dest| {
+                    vertex.distance.saturating_add(link.cost) <= dest.distance
+                })
+            })
+        });
+    if alt_path {
+        Debug::<V>::SpfIncrementalFallback(area.area_id).log();
+        run_area(area, instance, interfaces, neighbors, lsa_entries);
+        return false;
+    }
+
+    // Update area's SPT.
+    spt_update(area, spt, instance, interfaces, neighbors, lsa_entries);
+    true
+}
+
+// Computes the area's shortest-path tree from scratch.
+fn spt_compute_full<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Option<BTreeMap<V::VertexId, Vertex<V>>>
+where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+
     // Get root vertex.
     let root_vid = V::VertexId::new_root(instance.state.router_id);
     let root_vlsa =
-        match V::vertex_lsa_find(af, root_vid, area, extended_lsa, lsa_entries)
-        {
-            Some(vertex) => vertex,
-            None => {
-                Error::<V>::SpfRootNotFound(area.area_id).log();
-                return;
-            }
-        };
+        V::vertex_lsa_find(af, root_vid, area, extended_lsa, lsa_entries)?;
     let root_v = Vertex::<V>::new(root_vid, root_vlsa, 0, 0);
 
     // Initialize SPT and candidate list.
-    let mut spt = BTreeMap::new();
+    let spt = BTreeMap::new();
     let mut cand_list = BTreeMap::new();
     cand_list.insert((root_v.distance, root_v.id), root_v);
 
-    // Clear router's routing table.
-    area.state.routers.clear();
+    let spt = spt_compute(
+        area,
+        spt,
+        cand_list,
+        instance,
+        interfaces,
+        neighbors,
+        lsa_entries,
+    );
+    Some(spt)
+}
+
+// Runs the SPF main loop, starting from the given (possibly partial)
+// shortest-path tree and candidate list.
+fn spt_compute<V>(
+    area: &Area<V>,
+    mut spt: BTreeMap<V::VertexId, Vertex<V>>,
+    mut cand_list: BTreeMap<(u16, V::VertexId), Vertex<V>>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> BTreeMap<V::VertexId, Vertex<V>>
+where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
 
     // Main SPF loop.
     while let Some(((_, vertex_id), vertex)) = cand_list.pop_first() {
@@ -590,24 +949,6 @@ fn run_area<V>(
         spt.insert(vertex.id, vertex);
         let vertex = spt.get(&vertex_id).unwrap();
 
-        if vertex.lsa.is_router() {
-            // Add "router" routing table entry.
-            let route = RouteRtr::new(
-                area.area_id,
-                PathType::IntraArea,
-                vertex.lsa.router_options(),
-                vertex.lsa.router_flags(),
-                vertex.distance.into(),
-                vertex.nexthops.clone(),
-            );
-            area.state.routers.insert(vertex.lsa.router_id(), route);
-
-            // Set TransitCapability.
-            if vertex.lsa.router_v_bit() {
-                area.state.transit_capability = true;
-            }
-        }
-
         // Iterate over all links described by the vertex's LSA.
         for link in V::vertex_lsa_links(
             &vertex.lsa,
@@ -616,120 +957,161 @@ fn run_area<V>(
             extended_lsa,
             lsa_entries,
         ) {
-            // Check if the LSAs are mutually linked.
-            if !V::vertex_lsa_links(
-                &link.lsa,
-                af,
+            spf_relax(
                 area,
-                extended_lsa,
+                vertex,
+                link,
+                &spt,
+                &mut cand_list,
+                instance,
+                interfaces,
+                neighbors,
                 lsa_entries,
-            )
-            .any(|link| link.id == vertex.id)
-            {
-                continue;
-            }
+            );
+        }
+    }
 
-            // Check if the link's vertex is already on the shortest-path tree.
-            if spt.contains_key(&link.id) {
-                continue;
-            }
+    spt
+}
 
-            // Calculate distance to the link's vertex.
-            let distance = vertex.distance.saturating_add(link.cost);
+// Examines the given link of a vertex that was just added to the
+// shortest-path tree, updating the candidate list accordingly.
+fn spf_relax<V>(
+    area: &Area<V>,
+    vertex: &Vertex<V>,
+    link: SpfLink<'_, V>,
+    spt: &BTreeMap<V::VertexId, Vertex<V>>,
+    cand_list: &mut BTreeMap<(u16, V::VertexId), Vertex<V>>,
+    instance: &InstanceUpView<'_, V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let extended_lsa = instance.config.extended_lsa;
 
-            // Increment number of hops to the root.
-            let mut hops = vertex.hops;
-            if link.lsa.is_router() {
-                hops = hops.saturating_add(1);
-            }
+    // Check if the LSAs are mutually linked.
+    if !vertex_lsa_linked(&link.lsa, vertex.id, area, instance, lsa_entries) {
+        return;
+    }
 
-            // Check if this vertex is already present on the candidate list.
-            // TODO: optimize lookup.
-            if let Some((cand_key, cand_v)) = cand_list
-                .iter_mut()
-                .find(|(_, cand_v)| cand_v.id == link.id)
-            {
-                match distance.cmp(&cand_v.distance) {
-                    Ordering::Less => {
-                        // Remove vertex since its key has changed. It will be
-                        // re-added with the correct key below.
-                        let cand_key = *cand_key;
-                        cand_list.remove(&cand_key);
-                    }
-                    Ordering::Equal => {}
-                    Ordering::Greater => {
-                        // Ignore higher cost path.
-                        continue;
-                    }
-                }
-            }
-            let cand_v =
-                cand_list.entry((distance, link.id)).or_insert_with(|| {
-                    Vertex::new(link.id, link.lsa, distance, hops)
-                });
+    // Check if the link's vertex is already on the shortest-path tree.
+    if spt.contains_key(&link.id) {
+        return;
+    }
 
-            // Update vertex's nexthops.
-            match calc_nexthops(
-                area,
-                vertex,
-                link.parent,
-                link.id,
-                &cand_v.lsa,
-                interfaces,
-                neighbors,
-                extended_lsa,
-                lsa_entries,
-            ) {
-                Ok(nexthops) => cand_v.nexthops.extend(nexthops),
-                Err(error) => error.log(),
+    // Calculate distance to the link's vertex.
+    let distance = vertex.distance.saturating_add(link.cost);
+
+    // Increment number of hops to the root.
+    let mut hops = vertex.hops;
+    if link.lsa.is_router() {
+        hops = hops.saturating_add(1);
+    }
+
+    // Check if this vertex is already present on the candidate list.
+    // TODO: optimize lookup.
+    if let Some((cand_key, cand_v)) = cand_list
+        .iter_mut()
+        .find(|(_, cand_v)| cand_v.id == link.id)
+    {
+        match distance.cmp(&cand_v.distance) {
+            Ordering::Less => {
+                // Remove vertex since its key has changed. It will be
+                // re-added with the correct key below.
+                let cand_key = *cand_key;
+                cand_list.remove(&cand_key);
+            }
+            Ordering::Equal => {}
+            Ordering::Greater => {
+                // Ignore higher cost path.
+                return;
             }
         }
     }
-
-    // Update area's SPT.
-    area.state.spt = spt;
-
-    // Update statistics.
-    area.state.spf_run_count += 1;
-    area.state.discontinuity_time = Utc::now();
+    let cand_v = cand_list
+        .entry((distance, link.id))
+        .or_insert_with(|| Vertex::new(link.id, link.lsa, distance, hops));
+    cand_v.parents.push(vertex.id);
+
+    // Update vertex's nexthops.
+    match calc_nexthops(
+        area,
+        vertex,
+        link.parent,
+        link.id,
+        &cand_v.lsa,
+        interfaces,
+        neighbors,
+        extended_lsa,
+        lsa_entries,
+    ) {
+        Ok(nexthops) => cand_v.nexthops.extend(nexthops),
+        Err(error) => error.log(),
+    }
 }
 
-// Computes the set of nexthops that should be used to reach the given
-// destination.
-fn calc_nexthops<V>(
+// Returns whether the given vertex LSA has a link back to the specified
+// vertex.
+fn vertex_lsa_linked<V>(
+    vertex_lsa: &V::VertexLsa,
+    vertex_id: V::VertexId,
     area: &Area<V>,
-    parent: &Vertex<V>,
-    parent_link: Option<(usize, &V::LsaRouterLink)>,
-    dest_id: V::VertexId,
-    dest_lsa: &V::VertexLsa,
+    instance: &InstanceUpView<'_, V>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> bool
+where
+    V: Version,
+{
+    let af = instance.state.af;
+    let extended_lsa = instance.config.extended_lsa;
+    V::vertex_lsa_links(vertex_lsa, af, area, extended_lsa, lsa_entries)
+        .any(|link| link.id == vertex_id)
+}
+
+// Installs the given shortest-path tree in the area, updating all
+// information derived from it.
+fn spt_update<V>(
+    area: &mut Area<V>,
+    spt: BTreeMap<V::VertexId, Vertex<V>>,
+    instance: &InstanceUpView<'_, V>,
     interfaces: &Arena<Interface<V>>,
     neighbors: &Arena<Neighbor<V>>,
-    extended_lsa: bool,
     lsa_entries: &Arena<LsaEntry<V>>,
-) -> Result<Nexthops<V::IpAddr>, Error<V>>
-where
+) where
     V: Version,
 {
-    if parent.hops == 0 {
-        // The parent vertex is the root or a network that directly connects the
-        // calculating router to the destination router.
-        V::calc_nexthops(
-            area,
-            parent,
-            parent_link,
-            dest_id,
-            dest_lsa,
-            interfaces,
-            neighbors,
-            extended_lsa,
-            lsa_entries,
-        )
-    } else {
-        // If there is at least one intervening router in the current shortest
-        // path between the destination and the root, the destination simply
-        // inherits the set of next hops from the parent.
-        Ok(parent.nexthops.clone())
+    // Update router's routing table.
+    area.state.transit_capability = false;
+    area.state.routers.clear();
+    for vertex in spt.values().filter(|vertex| vertex.lsa.is_router()) {
+        // Add "router" routing table entry.
+        let route = RouteRtr::new(
+            area.area_id,
+            PathType::IntraArea,
+            vertex.lsa.router_options(),
+            vertex.lsa.router_flags(),
+            vertex.distance.into(),
+            vertex.nexthops.clone(),
+        );
+        area.state.routers.insert(vertex.lsa.router_id(), route);
+
+        // Set TransitCapability.
+        if vertex.lsa.router_v_bit() {
+            area.state.transit_capability = true;
+        }
     }
+
+    // Update area's SPT.
+    area.state.spt = spt;
+
+    // Compute fast reroute repair paths.
+    frr::compute_repairs(area, instance, interfaces, neighbors, lsa_entries);
+
+    // Update statistics.
+    area.state.spf_run_count += 1;
+    area.state.discontinuity_time = Utc::now();
 }
 
 // Adds log entry for the SPF run.
@@ -750,6 +1132,7 @@ fn log_spf_run<V>(
     // Get SPF computation type in log format.
     let spf_log_type = match spf_computation_type {
         SpfComputation::Full => SpfLogType::Full,
+        SpfComputation::Incremental(_) => SpfLogType::Incremental,
         SpfComputation::Partial(partial) => {
             if !partial.intra.is_empty() {
                 SpfLogType::Intra
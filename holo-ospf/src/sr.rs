@@ -13,6 +13,7 @@ use holo_utils::sr::{IgpAlgoType, Sid};
 use crate::area::Area;
 use crate::collections::Arena;
 use crate::error::Error;
+use crate::frr::RepairSegment;
 use crate::instance::InstanceUpView;
 use crate::interface::Interface;
 use crate::lsdb::LsaEntry;
@@ -20,7 +21,7 @@ use crate::neighbor::Neighbor;
 use crate::northbound::notification;
 use crate::packet::lsa::{AdjSidVersion, PrefixSidVersion};
 use crate::packet::tlv::{PrefixSidFlags, SidLabelRangeTlv};
-use crate::route::RouteNet;
+use crate::route::{PathType, RouteNet};
 use crate::southbound;
 use crate::version::Version;
 
@@ -75,6 +76,66 @@ pub(crate) fn prefix_sid_update<V>(
             Err(error) => error.log(),
         }
     }
+
+    // Update SR output label of the repair path (if any).
+    if let Some(repair) = &mut route.repair {
+        let last_hop = route.path_type == PathType::IntraArea
+            && repair.label_rtr == adv_rtr;
+        match prefix_sid_output_label(
+            area,
+            instance,
+            repair.label_rtr,
+            prefix_sid,
+            last_hop,
+            lsa_entries,
+        ) {
+            Ok(label) => {
+                if label.get() != Label::IMPLICIT_NULL {
+                    repair.labels.push(label);
+                }
+            }
+            Err(error) => error.log(),
+        }
+    }
+}
+
+// Resolves the segments of a repair path to an MPLS label stack, starting from
+// the router at the other end of the repair nexthop.
+pub(crate) fn repair_labels<V>(
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    mut nbr_router_id: Ipv4Addr,
+    segments: &[RepairSegment<V>],
+    lsa_entries: &Arena<LsaEntry<V>>,
+) -> Result<Vec<Label>, Error<V>>
+where
+    V: Version,
+{
+    let mut labels = vec![];
+    for segment in segments {
+        match segment {
+            RepairSegment::Node(router_id, prefix_sid) => {
+                let last_hop = nbr_router_id == *router_id;
+                let label = prefix_sid_output_label(
+                    area,
+                    instance,
+                    nbr_router_id,
+                    prefix_sid,
+                    last_hop,
+                    lsa_entries,
+                )?;
+                labels.push(label);
+                nbr_router_id = *router_id;
+            }
+            RepairSegment::Adj(router_id, label) => {
+                labels.push(*label);
+                nbr_router_id = *router_id;
+            }
+        }
+    }
+    labels.retain(|label| label.get() != Label::IMPLICIT_NULL);
+
+    Ok(labels)
 }
 
 // Adds SR Adj-SID.
@@ -111,10 +172,8 @@ pub(crate) fn adj_sid_del_all<V>(
     }
 }
 
-// ===== helper functions =====
-
 // Resolve Prefix-SID to MPLS input label.
-fn prefix_sid_input_label<V>(
+pub(crate) fn prefix_sid_input_label<V>(
     area: &Area<V>,
     instance: &InstanceUpView<'_, V>,
     prefix_sid: &V::PrefixSid,
@@ -158,7 +217,7 @@ where
 }
 
 // Resolve Prefix-SID to MPLS output label.
-fn prefix_sid_output_label<V>(
+pub(crate) fn prefix_sid_output_label<V>(
     area: &Area<V>,
     instance: &InstanceUpView<'_, V>,
     nbr_router_id: Ipv4Addr,
@@ -214,6 +273,8 @@ where
     }
 }
 
+// ===== helper functions =====
+
 // Maps SID index to MPLS label value.
 fn index_to_label<V>(
     instance: &InstanceUpView<'_, V>,
@@ -0,0 +1,147 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::net::IpAddr;
+
+use holo_protocol::InstanceShared;
+use holo_utils::sr::{Srv6EndpointBehavior, Srv6LocatorCfg};
+
+use crate::instance::{InstanceArenas, InstanceUpView};
+use crate::interface::Interface;
+use crate::neighbor::{nsm, Neighbor};
+use crate::northbound::configuration::InstanceCfg;
+use crate::southbound;
+use crate::version::Version;
+
+// Function value of the local End SID.
+const END_SID_FUNCTION: u32 = 1;
+
+// Base function value of the local End.X SIDs. Each adjacency is assigned a
+// unique function derived from its neighbor ID.
+const ENDX_SID_FUNCTION_BASE: u32 = 0x100;
+
+// ===== global functions =====
+
+// Returns the SRv6 locator used to allocate the local SIDs, if SRv6 is enabled.
+pub(crate) fn locator<'a>(
+    config: &InstanceCfg,
+    shared: &'a InstanceShared,
+) -> Option<&'a Srv6LocatorCfg> {
+    if !config.srv6_enabled {
+        return None;
+    }
+
+    let name = config.srv6_locator.as_ref()?;
+    shared.sr_config.srv6_locators.get(name)
+}
+
+// Installs or uninstalls the local End SID according to the current SRv6
+// configuration.
+pub(crate) fn end_sid_update<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    let sid = locator(instance.config, instance.shared)
+        .and_then(|locator| locator.sid(END_SID_FUNCTION));
+    if instance.state.srv6_end_sid == sid {
+        return;
+    }
+
+    end_sid_uninstall(instance);
+    if let Some(sid) = sid {
+        southbound::tx::srv6_sid_install::<V>(
+            &instance.tx.ibus,
+            sid,
+            Srv6EndpointBehavior::End,
+            None,
+            None,
+        );
+        instance.state.srv6_end_sid = Some(sid);
+    }
+}
+
+// Uninstalls the local End SID.
+pub(crate) fn end_sid_uninstall<V>(instance: &mut InstanceUpView<'_, V>)
+where
+    V: Version,
+{
+    if let Some(sid) = instance.state.srv6_end_sid.take() {
+        southbound::tx::srv6_sid_uninstall::<V>(&instance.tx.ibus, sid);
+    }
+}
+
+// Adds SRv6 End.X SID.
+pub(crate) fn endx_sid_add<V>(
+    nbr: &mut Neighbor<V>,
+    iface: &Interface<V>,
+    instance: &InstanceUpView<'_, V>,
+) where
+    V: Version,
+{
+    let Some(locator) = locator(instance.config, instance.shared) else {
+        return;
+    };
+    let Some(sid) = ENDX_SID_FUNCTION_BASE
+        .checked_add(nbr.id)
+        .and_then(|function| locator.sid(function))
+    else {
+        return;
+    };
+    let IpAddr::V6(nbr_addr) = nbr.src.into() else {
+        return;
+    };
+
+    southbound::tx::srv6_sid_install::<V>(
+        &instance.tx.ibus,
+        sid,
+        Srv6EndpointBehavior::EndX,
+        iface.system.ifindex,
+        Some(nbr_addr),
+    );
+    nbr.srv6_endx_sid = Some(sid);
+}
+
+// Deletes the SRv6 End.X SID associated to the provided neighbor.
+pub(crate) fn endx_sid_del<V>(
+    nbr: &mut Neighbor<V>,
+    instance: &InstanceUpView<'_, V>,
+) where
+    V: Version,
+{
+    if let Some(sid) = nbr.srv6_endx_sid.take() {
+        southbound::tx::srv6_sid_uninstall::<V>(&instance.tx.ibus, sid);
+    }
+}
+
+// Reallocates all local SRv6 SIDs following a configuration change.
+pub(crate) fn update_all<V>(
+    instance: &mut InstanceUpView<'_, V>,
+    arenas: &mut InstanceArenas<V>,
+) where
+    V: Version,
+{
+    // Update End SID.
+    end_sid_update(instance);
+
+    // Iterate over all existing adjacencies.
+    for area in arenas.areas.iter() {
+        for iface in area
+            .interfaces
+            .iter(&arenas.interfaces)
+            .filter(|iface| !iface.is_virtual_link())
+        {
+            for nbr_idx in iface.state.neighbors.indexes() {
+                let nbr = &mut arenas.neighbors[nbr_idx];
+
+                // Update End.X SID.
+                endx_sid_del(nbr, instance);
+                if nbr.state >= nsm::State::TwoWay {
+                    endx_sid_add(nbr, iface, instance);
+                }
+            }
+        }
+    }
+}
@@ -6,7 +6,7 @@
 
 use std::sync::atomic::AtomicU64;
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use holo_utils::ip::AddressFamily;
 use holo_utils::socket::{AsyncFd, Socket};
@@ -106,6 +106,10 @@ pub mod messages {
             LsdbMaxAgeSweep(LsdbMaxAgeSweepMsg),
             SpfDelayEvent(SpfDelayEventMsg),
             GracePeriod(GracePeriodMsg),
+            GrRestartTimeout(GrRestartTimeoutMsg),
+            LsdbOverflowExit(LsdbOverflowExitMsg),
+            StubRouterStartupTimeout(StubRouterStartupTimeoutMsg),
+            LdpSyncHolddown(LdpSyncHolddownMsg),
         }
 
         #[derive(Debug, Deserialize, Serialize)]
@@ -212,6 +216,21 @@ pub mod messages {
             pub iface_key: InterfaceKey,
             pub nbr_key: NeighborKey,
         }
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct GrRestartTimeoutMsg {}
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct LsdbOverflowExitMsg {}
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct StubRouterStartupTimeoutMsg {}
+
+        #[derive(Clone, Debug, Deserialize, Serialize)]
+        pub struct LdpSyncHolddownMsg {
+            pub area_key: AreaKey,
+            pub iface_key: InterfaceKey,
+        }
     }
 
     // Output messages (main task -> child task).
@@ -347,8 +366,7 @@ where
         // Generate hello packet.
         let packet = V::generate_hello(iface, area, instance);
 
-        let net_tx_packetp =
-            iface.state.net.as_ref().unwrap().net_tx_packetp.clone();
+        let net_tx_packetp = iface.net_tx_packetp().clone();
         IntervalTask::new(
             Duration::from_secs(interval.into()),
             true,
@@ -647,7 +665,7 @@ where
 pub(crate) fn lsa_orig_delayed_timer<V>(
     lsdb_id: LsdbId,
     lsa_key: LsaKey<V::LsaType>,
-    lsa_base_time: Option<Instant>,
+    timeout: Duration,
     lsa_orig_delayed_timerp: &Sender<messages::input::LsaOrigDelayedMsg<V>>,
 ) -> TimeoutTask
 where
@@ -657,10 +675,6 @@ where
     {
         let lsa_orig_delayed_timerp = lsa_orig_delayed_timerp.clone();
 
-        let lsa_age = lsa_base_time.unwrap().elapsed();
-        let timeout =
-            Duration::from_secs(lsdb::LSA_MIN_INTERVAL).saturating_sub(lsa_age);
-
         TimeoutTask::new(timeout, move || async move {
             let msg = messages::input::LsaOrigDelayedMsg {
                 lsdb_key: lsdb_id.into(),
@@ -764,3 +778,122 @@ where
         TimeoutTask {}
     }
 }
+
+// Graceful restart timer task (restarting router).
+pub(crate) fn gr_restart_timer<V>(
+    instance: &InstanceUpView<'_, V>,
+    grace_period: u32,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let gr_restart_timeoutp =
+            instance.tx.protocol_input.gr_restart_timeout.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(grace_period.into()),
+            move || async move {
+                let _ = gr_restart_timeoutp
+                    .send(messages::input::GrRestartTimeoutMsg {})
+                    .await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// LSDB overflow exit timer task.
+pub(crate) fn lsdb_overflow_exit_timer<V>(
+    instance: &InstanceUpView<'_, V>,
+    interval: u32,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let lsdb_overflow_exitp =
+            instance.tx.protocol_input.lsdb_overflow_exit.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(interval.into()),
+            move || async move {
+                let _ = lsdb_overflow_exitp
+                    .send(messages::input::LsdbOverflowExitMsg {})
+                    .await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// Stub router on startup timer task.
+pub(crate) fn stub_router_startup_timer<V>(
+    instance: &InstanceUpView<'_, V>,
+    timeout: u32,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let stub_router_startupp =
+            instance.tx.protocol_input.stub_router_startup.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(timeout.into()),
+            move || async move {
+                let _ = stub_router_startupp
+                    .send(messages::input::StubRouterStartupTimeoutMsg {})
+                    .await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
+
+// LDP-IGP synchronization holddown timer task.
+pub(crate) fn ldp_sync_holddown_timer<V>(
+    iface: &Interface<V>,
+    area: &Area<V>,
+    instance: &InstanceUpView<'_, V>,
+    holddown: u16,
+) -> TimeoutTask
+where
+    V: Version,
+{
+    #[cfg(not(feature = "testing"))]
+    {
+        let area_id = area.id;
+        let iface_id = iface.id;
+        let ldp_sync_holddownp =
+            instance.tx.protocol_input.ldp_sync_holddown.clone();
+
+        TimeoutTask::new(
+            Duration::from_secs(holddown.into()),
+            move || async move {
+                let _ = ldp_sync_holddownp
+                    .send(messages::input::LdpSyncHolddownMsg {
+                        area_key: area_id.into(),
+                        iface_key: iface_id.into(),
+                    })
+                    .await;
+            },
+        )
+    }
+    #[cfg(feature = "testing")]
+    {
+        TimeoutTask {}
+    }
+}
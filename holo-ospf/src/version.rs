@@ -13,6 +13,7 @@ use ipnetwork::{IpNetwork, Ipv4Network};
 use crate::area::AreaVersion;
 use crate::instance::InstanceVersion;
 use crate::interface::InterfaceVersion;
+use crate::link_state::LinkStateVersion;
 use crate::lsdb::LsdbVersion;
 use crate::neighbor::NeighborVersion;
 use crate::network::NetworkVersion;
@@ -37,6 +38,7 @@ where
         + LsdbVersion<Self>
         + InstanceVersion<Self>
         + InterfaceVersion<Self>
+        + LinkStateVersion<Self>
         + NeighborVersion<Self>
         + NetworkVersion<Self>
         + NorthboundVersion<Self>
@@ -0,0 +1,280 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::net::Ipv4Addr;
+
+use holo_utils::UnboundedSender;
+use smallvec::smallvec;
+
+use crate::area::Area;
+use crate::collections::{Areas, Arena, InterfaceIndex};
+use crate::debug::InterfaceInactiveReason;
+use crate::instance::InstanceUpView;
+use crate::interface::Interface;
+use crate::lsdb::{LsaEntry, LsaOriginateEvent};
+use crate::neighbor::{nsm, Neighbor};
+use crate::network::SendDestination;
+use crate::route::Nexthops;
+use crate::tasks::messages::output::NetTxPacketMsg;
+use crate::version::Version;
+
+// Virtual link parameters.
+#[derive(Debug)]
+pub struct VirtualLink<V: Version> {
+    // Transit area through which the virtual link is established.
+    pub transit_area_id: Ipv4Addr,
+    // Router ID of the virtual link endpoint.
+    pub router_id: Ipv4Addr,
+    // Virtual link endpoint, as computed by the transit area's SPF run.
+    pub endpoint: Option<VirtualLinkEndpoint<V>>,
+}
+
+// Reachability information of a virtual link endpoint.
+#[derive(Debug)]
+pub struct VirtualLinkEndpoint<V: Version> {
+    // Cost of the intra-area path through the transit area.
+    pub cost: u16,
+    // Local and remote virtual link addresses.
+    pub src_addr: V::NetIpAddr,
+    pub dst_addr: V::NetIpAddr,
+    // Outgoing interface in the transit area.
+    pub iface_idx: InterfaceIndex,
+    pub ifindex: u32,
+    pub mtu: u16,
+    // Nexthops used to reach the virtual link endpoint.
+    pub nexthops: Nexthops<V::IpAddr>,
+    // Network Tx output channel of the outgoing interface.
+    pub net_tx_packetp: UnboundedSender<NetTxPacketMsg<V>>,
+}
+
+// ===== impl VirtualLink =====
+
+impl<V> VirtualLink<V>
+where
+    V: Version,
+{
+    pub(crate) fn new(transit_area_id: Ipv4Addr, router_id: Ipv4Addr) -> Self {
+        VirtualLink {
+            transit_area_id,
+            router_id,
+            endpoint: None,
+        }
+    }
+
+    // Returns the name of the virtual interface associated to the virtual
+    // link.
+    pub(crate) fn ifname(
+        transit_area_id: Ipv4Addr,
+        router_id: Ipv4Addr,
+    ) -> String {
+        format!("vlink-{transit_area_id}-{router_id}")
+    }
+}
+
+// ===== impl VirtualLinkEndpoint =====
+
+impl<V> VirtualLinkEndpoint<V>
+where
+    V: Version,
+{
+    // Returns the unicast destination used to reach the virtual link endpoint.
+    pub(crate) fn send_dest(&self) -> SendDestination<V::NetIpAddr> {
+        SendDestination::new(self.ifindex, smallvec![self.dst_addr])
+    }
+
+    // Checks whether the virtual link needs to be restarted due to a change in
+    // its addressing or outgoing interface.
+    fn needs_reset(&self, other: &VirtualLinkEndpoint<V>) -> bool {
+        self.src_addr != other.src_addr
+            || self.dst_addr != other.dst_addr
+            || self.iface_idx != other.iface_idx
+            || !self.net_tx_packetp.same_channel(&other.net_tx_packetp)
+    }
+}
+
+// ===== global functions =====
+
+// Returns whether the virtual link is ready for OSPF operation.
+pub(crate) fn is_ready<V>(
+    iface: &Interface<V>,
+) -> Result<(), InterfaceInactiveReason>
+where
+    V: Version,
+{
+    if !iface.config.enabled {
+        return Err(InterfaceInactiveReason::AdminDown);
+    }
+
+    if iface.vlink_endpoint().is_none() {
+        return Err(InterfaceInactiveReason::VirtualLinkUnreachable);
+    }
+
+    Ok(())
+}
+
+// Updates the endpoints of all configured virtual links based on the SPF
+// results of their transit areas.
+//
+// This needs to be done after running SPF in the transit areas, but before
+// running SPF in the backbone area.
+pub(crate) fn update_endpoints<V>(
+    instance: &InstanceUpView<'_, V>,
+    areas: &Areas<V>,
+    interfaces: &mut Arena<Interface<V>>,
+    neighbors: &mut Arena<Neighbor<V>>,
+    lsa_entries: &Arena<LsaEntry<V>>,
+) where
+    V: Version,
+{
+    let Some((_, backbone)) = areas.get_by_area_id(Ipv4Addr::UNSPECIFIED)
+    else {
+        return;
+    };
+
+    for iface_idx in backbone.interfaces.indexes() {
+        let iface = &interfaces[iface_idx];
+        let Some(vlink) = &iface.system.vlink else {
+            continue;
+        };
+
+        // Compute the new virtual link endpoint.
+        let endpoint = areas
+            .get_by_area_id(vlink.transit_area_id)
+            .filter(|(_, area)| !area.is_backbone())
+            .and_then(|(_, transit_area)| {
+                let route = transit_area.state.routers.get(&vlink.router_id)?;
+                let nexthop = route.nexthops.values().next()?;
+                let out_iface = &interfaces[nexthop.iface_idx];
+                let dst_addr = V::virtual_link_addr(
+                    transit_area,
+                    vlink.router_id,
+                    lsa_entries,
+                )?;
+                Some(VirtualLinkEndpoint {
+                    cost: u16::try_from(route.metric).unwrap_or(u16::MAX),
+                    src_addr: out_iface.state.src_addr?,
+                    dst_addr,
+                    iface_idx: nexthop.iface_idx,
+                    ifindex: out_iface.system.ifindex?,
+                    mtu: out_iface.system.mtu?,
+                    nexthops: route.nexthops.clone(),
+                    net_tx_packetp: out_iface
+                        .state
+                        .net
+                        .as_ref()?
+                        .net_tx_packetp
+                        .clone(),
+                })
+            });
+
+        // Update the virtual link endpoint.
+        let iface = &mut interfaces[iface_idx];
+        let vlink = iface.system.vlink.as_mut().unwrap();
+        let old_endpoint = std::mem::replace(&mut vlink.endpoint, endpoint);
+        let (reachability_change, reset, cost_change) =
+            match (&old_endpoint, &vlink.endpoint) {
+                (Some(old_endpoint), Some(endpoint)) => (
+                    false,
+                    old_endpoint.needs_reset(endpoint),
+                    old_endpoint.cost != endpoint.cost,
+                ),
+                (None, None) => (false, false, false),
+                _ => (true, false, false),
+            };
+        iface.system.mtu = vlink.endpoint.as_ref().map(|endpoint| endpoint.mtu);
+
+        if reachability_change {
+            // Start or stop the virtual link as necessary.
+            iface.update(backbone, instance, neighbors, lsa_entries);
+        } else if reset && !iface.is_down() {
+            // Restart the virtual link using the new endpoint.
+            iface.reset(backbone, instance, neighbors, lsa_entries);
+        } else if cost_change {
+            // (Re)originate the backbone's Router-LSA.
+            instance.tx.protocol_input.lsa_orig_event(
+                LsaOriginateEvent::InterfaceCostChange {
+                    area_id: backbone.id,
+                },
+            );
+        }
+    }
+}
+
+// Finds the virtual link associated to the given transit area and endpoint.
+pub(crate) fn find<'a, V>(
+    transit_area_id: Ipv4Addr,
+    router_id: Ipv4Addr,
+    areas: &'a Areas<V>,
+    interfaces: &'a Arena<Interface<V>>,
+) -> Option<(&'a Area<V>, &'a Interface<V>)>
+where
+    V: Version,
+{
+    let (_, backbone) = areas.get_by_area_id(Ipv4Addr::UNSPECIFIED)?;
+    backbone
+        .interfaces
+        .iter(interfaces)
+        .find(|iface| {
+            iface.system.vlink.as_ref().is_some_and(|vlink| {
+                vlink.transit_area_id == transit_area_id
+                    && vlink.router_id == router_id
+            })
+        })
+        .map(|iface| (backbone, iface))
+}
+
+// Returns whether the given area is the transit area of one or more fully
+// adjacent virtual links.
+pub(crate) fn is_transit_area<V>(
+    area: &Area<V>,
+    areas: &Areas<V>,
+    interfaces: &Arena<Interface<V>>,
+    neighbors: &Arena<Neighbor<V>>,
+) -> bool
+where
+    V: Version,
+{
+    let Some((_, backbone)) = areas.get_by_area_id(Ipv4Addr::UNSPECIFIED)
+    else {
+        return false;
+    };
+
+    backbone
+        .interfaces
+        .iter(interfaces)
+        .filter(|iface| {
+            iface
+                .system
+                .vlink
+                .as_ref()
+                .is_some_and(|vlink| vlink.transit_area_id == area.area_id)
+        })
+        .any(|iface| {
+            iface
+                .state
+                .neighbors
+                .iter(neighbors)
+                .any(|nbr| nbr.state == nsm::State::Full)
+        })
+}
+
+// Clears the endpoints of all configured virtual links.
+pub(crate) fn clear_endpoints<V>(
+    areas: &Areas<V>,
+    interfaces: &mut Arena<Interface<V>>,
+) where
+    V: Version,
+{
+    for area in areas.iter() {
+        for iface_idx in area.interfaces.indexes() {
+            let iface = &mut interfaces[iface_idx];
+            if let Some(vlink) = &mut iface.system.vlink {
+                vlink.endpoint = None;
+                iface.system.mtu = None;
+            }
+        }
+    }
+}
@@ -10,6 +10,66 @@ use holo_ospf::instance::Instance;
 use holo_ospf::version::Ospfv2;
 use holo_protocol::test::stub::run_test;
 
+// Test description:
+//
+// Input:
+//  * Northbound: enable LFA on all interfaces
+// Output:
+//  * Ibus: reinstall the protected routes along with their backup nexthops
+//  * Northbound: the protected routes are listed
+//
+// Input:
+//  * Northbound: disable LFA on all interfaces
+// Output:
+//  * Ibus: reinstall the protected routes without backup nexthops
+//  * Northbound: no protected or unprotected routes are listed
+#[tokio::test]
+async fn frr_lfa1() {
+    run_test::<Instance<Ospfv2>>("frr-lfa1", "topo2-4", "rt4").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Protocol: received updated Router-LSA from rt6, raising the metric of its
+//    link to rt5 to 30
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA and an LS Ack to rt6
+//  * Northbound: the updated Router-LSA is present in the LSDB
+//
+// Input:
+//  * Northbound: enable LFA and remote LFA on all interfaces
+// Output:
+//  * Ibus: reinstall the protected routes along with their backup nexthops,
+//    using the Prefix-SID of the rt3 PQ-node to reach 5.5.5.5 through rt2
+//  * Northbound: the protected routes are listed
+#[tokio::test]
+async fn frr_remote_lfa1() {
+    run_test::<Instance<Ospfv2>>("frr-remote-lfa1", "topo2-4", "rt4").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Protocol: received updated Router-LSA from rt6, raising the metric of its
+//    link to rt5 to 30
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA and an LS Ack to rt6
+//  * Northbound: the updated Router-LSA is present in the LSDB
+//
+// Input:
+//  * Northbound: enable LFA and TI-LFA on all interfaces
+// Output:
+//  * Ibus: reinstall the protected routes along with their backup nexthops,
+//    using a post-convergence repair path through rt2 to reach 5.5.5.5
+//  * Northbound: the protected routes are listed
+#[tokio::test]
+async fn frr_ti_lfa1() {
+    run_test::<Instance<Ospfv2>>("frr-ti-lfa1", "topo2-4", "rt4").await;
+}
+
 // Test description:
 //
 // Input:
@@ -136,6 +196,135 @@ async fn gr_helper_exit3() {
     run_test::<Instance<Ospfv2>>("gr-helper-exit3", "topo2-1", "rt4").await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: enable graceful restart
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router Information LSA, which now advertises the graceful restart
+//    capability
+//
+// Input:
+//  * Northbound: prepare for a planned graceful restart
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the
+//    self-originated Grace-LSA of the corresponding interface
+//  * Northbound:
+//    - the Grace-LSAs are present in the interface LSDBs
+//    - the instance is preparing for a planned restart
+//      ("status":"planned-restart")
+//
+// Input:
+//  * Protocol: the instance wasn't restarted within the grace period
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the MaxAge
+//    Grace-LSA of the corresponding interface
+//  * Northbound:
+//    - the Grace-LSAs are flushed from the interface LSDBs
+//    - the planned restart is canceled ("exit-reason":"timed-out")
+#[tokio::test]
+async fn gr_restart_prepare1() {
+    run_test::<Instance<Ospfv2>>("gr-restart-prepare1", "topo2-1", "rt4").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable LDP-IGP synchronization with a holddown of 30 seconds
+// Output:
+//  * Southbound: register for LDP synchronization updates on eth-sw1
+//  * Protocol: originate a new Router-LSA advertising the maximum metric
+//    (65535) on the eth-sw1 link
+//  * Northbound: the LDP-IGP sync state of eth-sw1 is "not-synced"
+//
+// Input:
+//  * Protocol: LDP-IGP sync holddown timer expiration
+// Output:
+//  * Protocol: originate a new Router-LSA advertising the normal metric (10)
+//    on the eth-sw1 link
+//  * Northbound: the LDP-IGP sync state of eth-sw1 is "holddown-expired"
+//
+// Input:
+//  * Southbound: LDP is synchronized on eth-sw1
+// Output:
+//  * Northbound: the LDP-IGP sync state of eth-sw1 is "synced"
+//
+// Input:
+//  * Northbound: disable LDP-IGP synchronization
+// Output:
+//  * Southbound: unregister from LDP synchronization updates on eth-sw1
+#[tokio::test]
+async fn ldp_igp_sync1() {
+    run_test::<Instance<Ospfv2>>("ldp-igp-sync1", "topo2-1", "rt1").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable LDP-IGP synchronization without a holddown timer
+// Output:
+//  * Southbound: register for LDP synchronization updates on eth-sw1
+//  * Protocol: originate a new Router-LSA advertising the maximum metric
+//    (65535) on the eth-sw1 link
+//  * Northbound: the LDP-IGP sync state of eth-sw1 is "not-synced"
+//
+// Input:
+//  * Southbound: LDP is synchronized on eth-sw1
+// Output:
+//  * Protocol: originate a new Router-LSA advertising the normal metric (10)
+//    on the eth-sw1 link
+//  * Northbound: the LDP-IGP sync state of eth-sw1 is "synced"
+//
+// Input:
+//  * Southbound: LDP is no longer synchronized on eth-sw1
+// Output:
+//  * Protocol: originate a new Router-LSA advertising the maximum metric
+//    (65535) on the eth-sw1 link
+//  * Northbound: the LDP-IGP sync state of eth-sw1 is "not-synced"
+#[tokio::test]
+async fn ldp_igp_sync2() {
+    run_test::<Instance<Ospfv2>>("ldp-igp-sync2", "topo2-1", "rt1").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable the distribution of the topology via BGP-LS
+// Output:
+//  * Ibus: export the topology, including the intra-area prefixes and the
+//    inter-area prefixes advertised by 2.2.2.2
+//
+// Input:
+//  * Protocol: received LS Update from 2.2.2.2 containing a Router-LSA with
+//    the E-bit set and an AS-external-LSA for 172.16.1.0/24
+// Output:
+//  * Protocol: send an LS Ack to 2.2.2.2
+//  * Northbound: the new LSAs are present in the LSDB
+//
+// Input:
+//  * Protocol: SPF_TIMER expiration
+// Output:
+//  * Southbound: install route to 172.16.1.0/24
+//  * Ibus: export the updated topology, including the 172.16.1.0/24 external
+//    prefix advertised by 2.2.2.2
+//  * Northbound: the route to 172.16.1.0/24 was added and the ASBR count is
+//    now 1
+//
+// Input:
+//  * Ibus: request to dump the link-state topology
+// Output:
+//  * Ibus: export the topology again
+//
+// Input:
+//  * Northbound: disable the distribution of the topology via BGP-LS
+// Output:
+//  * Ibus: delete the exported topology
+#[tokio::test]
+async fn link_state1() {
+    run_test::<Instance<Ospfv2>>("link-state1", "topo1-2", "rt1").await;
+}
+
 // Test description:
 //
 // Input:
@@ -215,6 +404,58 @@ async fn lsa_refresh2() {
     run_test::<Instance<Ospfv2>>("lsa-refresh2", "topo2-1", "rt2").await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: set the external LSDB limit to 3 and redistribute static
+//    routes
+//  * Southbound: redistribute 172.16.1.0/24 and 172.16.2.0/24
+//  * Protocol: received LS Update containing an AS-external-LSA for
+//    172.16.10.0/24
+//  * Protocol: received LS Update containing an AS-external-LSA for
+//    172.16.11.0/24
+//  * Protocol: EXIT_OVERFLOW_INTERVAL expiration
+//  * Southbound: 172.16.2.0/24 is no longer redistributed
+//  * Protocol: flushed LSAs are acknowledged and removed from the LSDB
+//  * Protocol: EXIT_OVERFLOW_INTERVAL expiration
+// Output:
+//  * Protocol: originate AS-external-LSAs for 172.16.1.0/24 and 172.16.2.0/24
+//  * Northbound: send an "lsdb-overflow" YANG notification
+//  * Protocol: flush the self-originated AS-external-LSAs
+//  * Protocol: the AS-external-LSA for 172.16.11.0/24 is discarded
+//  * Protocol: remain in the overflow state since the limit is still reached
+//  * Protocol: leave the overflow state and reoriginate the AS-external-LSA
+//    for 172.16.1.0/24
+#[tokio::test]
+async fn lsdb_overflow1() {
+    run_test::<Instance<Ospfv2>>("lsdb-overflow1", "topo2-1", "rt1").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: configure a multi-area adjacency in area 0.0.0.0 on interface
+//    eth-rt1 of area 0.0.0.1
+//  * Southbound: interface eth-rt1 is operational and has the 10.0.1.2/24
+//    address
+//  * Protocol: received Hello from 1.1.1.1 on the primary interface
+//  * Protocol: adjacency with 1.1.1.1 is formed in area 0.0.0.0
+//  * Southbound: interface eth-rt1 is no longer operational
+// Output:
+//  * Southbound: the interface information of eth-rt1 is requested
+//  * Northbound: the multi-area interface transitions to the "point-to-point"
+//    state once the address of the neighbor is learned
+//  * Protocol: Database Description packets are sent to 10.0.1.1
+//  * Protocol: once the adjacency is full, the self-originated backbone
+//    Router-LSA has an unnumbered point-to-point link to 1.1.1.1
+//  * Northbound: both the primary and the multi-area adjacencies are torn down
+//  * Protocol: the self-originated backbone Router-LSA no longer describes the
+//    multi-area adjacency
+#[tokio::test]
+async fn multi_area1() {
+    run_test::<Instance<Ospfv2>>("multi-area1", "topo1-1", "rt2").await;
+}
+
 // Test description:
 //
 // Input:
@@ -618,6 +859,32 @@ async fn nb_rpc_clear_neighbor2() {
         .await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: change the type of area 0.0.0.1 to NSSA and set the NSSA
+//    translator role to "always"
+//  * Protocol: adjacency with 1.1.1.1 is rebuilt
+//  * Protocol: received LS Update from 1.1.1.1 containing a Router-LSA with
+//    the E-bit set and a Type-7 NSSA-LSA for 172.16.1.0/24 with the P-bit set
+//  * Protocol: SPF_TIMER expiration
+//  * Protocol: received LS Update from 1.1.1.1 flushing the NSSA-LSA
+//  * Protocol: SPF_TIMER expiration
+// Output:
+//  * Northbound: the NSSA translator state transitions to "enabled"
+//  * Protocol: the self-originated Router-LSA of area 0.0.0.1 has the B, E and
+//    NT bits set
+//  * Protocol: the NSSA-LSA is translated into an AS-external-LSA originated
+//    into the backbone area, without originating Type-4 summary-LSAs for the
+//    NSSA ASBRs
+//  * Southbound: install route to 172.16.1.0/24
+//  * Protocol: the translated AS-external-LSA is flushed
+//  * Southbound: uninstall route to 172.16.1.0/24
+#[tokio::test]
+async fn nssa_translation1() {
+    run_test::<Instance<Ospfv2>>("nssa-translation1", "topo1-1", "rt2").await;
+}
+
 // Test description:
 //
 // Input:
@@ -1129,6 +1396,99 @@ async fn packet_src3() {
     run_test::<Instance<Ospfv2>>("packet-src3", "topo2-1", "rt6").await;
 }
 
+// Test description:
+//
+// Input:
+//  * Northbound: enable prefix suppression at the instance level
+// Output:
+//  * Protocol: originate a new Router-LSA without the stub links of the
+//    eth-rt5-1 and eth-rt5-2 point-to-point interfaces (the loopback stub link
+//    is preserved)
+//  * Protocol: originate a new Network-LSA for the eth-sw1 LAN with a host
+//    mask (255.255.255.255)
+//
+// Input:
+//  * Northbound: disable prefix suppression on the eth-rt5-1 interface
+// Output:
+//  * Protocol: originate a new Router-LSA containing the stub link of the
+//    eth-rt5-1 interface
+//
+// Input:
+//  * Northbound: remove the interface-level prefix suppression configuration
+//    of eth-rt5-1
+// Output:
+//  * Protocol: originate a new Router-LSA without the stub link of the
+//    eth-rt5-1 interface
+#[tokio::test]
+async fn prefix_suppression1() {
+    run_test::<Instance<Ospfv2>>("prefix-suppression1", "topo2-1", "rt3").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Ibus: tag sets "tags1" (tag 100) and "tags2" (tag 200), and the "redist"
+//    policy, which sets metric 100 and tag 500 for routes matching "tags1" and
+//    rejects routes matching "tags2"
+// Output: no changes
+//
+// Input:
+//  * Northbound: redistribute static routes using the "redist" policy
+// Output:
+//  * Ibus: request a dump of the routes to be redistributed
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA (E-bit set)
+//  * Northbound: the self-originated Router-LSA was updated
+//
+// Input:
+//  * Ibus: static routes 172.16.1.0/24 (tag 100), 172.16.2.0/24 (tag 200) and
+//    172.16.3.0/24 (no tag)
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing AS-external-LSAs
+//    for 172.16.1.0/24 (metric 100, tag 500) and 172.16.3.0/24 (metric 20)
+//  * Northbound: the new AS-external-LSAs are present in the LSDB
+//
+// Input:
+//  * Ibus: update the "redist" policy to reject routes matching the
+//    nonexistent "tags3" tag set instead
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing an
+//    AS-external-LSA for 172.16.2.0/24 (metric 20)
+//  * Northbound: the new AS-external-LSA is present in the LSDB
+#[tokio::test]
+async fn redistribution_policy1() {
+    run_test::<Instance<Ospfv2>>("redistribution-policy1", "topo2-1", "rt1")
+        .await;
+}
+
+// Test description:
+//
+// Input:
+//  * Ibus: tag set "tags1" (tag 100) and the "redist" policy, which rejects
+//    routes matching "tags1"
+// Output: no changes
+//
+// Input:
+//  * Northbound: redistribute OSPFv3 routes using the "redist" policy
+// Output:
+//  * Ibus: request a dump of the routes to be redistributed
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA (E-bit set)
+//  * Northbound: the self-originated Router-LSA was updated
+//
+// Input:
+//  * Ibus: OSPFv3 routes 172.16.1.0/24 (node tag 100) and 172.16.2.0/24 (node
+//    tag 200)
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing an
+//    AS-external-LSA for 172.16.2.0/24 (metric 20)
+//  * Northbound: the new AS-external-LSA is present in the LSDB
+#[tokio::test]
+async fn redistribution_policy2() {
+    run_test::<Instance<Ospfv2>>("redistribution-policy2", "topo2-1", "rt1")
+        .await;
+}
+
 // Test description:
 //
 // Input:
@@ -1470,6 +1830,168 @@ async fn sb_router_id_update1() {
         .await;
 }
 
+// Test description:
+//
+// Input:
+//  * Protocol: received updated Router-LSA from rt3, raising the metric of its
+//    link to rt4 to 20
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus: update the routes reachable through rt4, whose metrics were
+//    increased by 10
+//  * Protocol: send an LS Ack to rt3 and an LS Update to rt1 containing the
+//    updated Summary-LSAs
+//  * Northbound: the updated Router-LSA and Summary-LSAs are present in the
+//    LSDB and the routes reachable through rt4 were updated
+#[tokio::test]
+async fn spf_incremental1() {
+    run_test::<Instance<Ospfv2>>("spf-incremental1", "topo1-1", "rt2").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: disable incremental SPF
+// Output: no changes
+//
+// Input:
+//  * Protocol: received updated Router-LSA from rt3, raising the metric of its
+//    link to rt4 to 20
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus: update the routes reachable through rt4, whose metrics were
+//    increased by 10 (same as with incremental SPF)
+//  * Protocol: send an LS Ack to rt3 and an LS Update to rt1 containing the
+//    updated Summary-LSAs
+//  * Northbound: the updated Router-LSA and Summary-LSAs are present in the
+//    LSDB and the routes reachable through rt4 were updated
+#[tokio::test]
+async fn spf_incremental2() {
+    run_test::<Instance<Ospfv2>>("spf-incremental2", "topo1-1", "rt2").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Protocol: received updated Router-LSA from rt5, raising the metric of its
+//    links to rt3 to 50
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus: update the route to rt3, which is now reachable only through the
+//    sw1 LAN
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA and an LS Ack to rt5
+//  * Northbound: the updated Router-LSA is present in the LSDB and the routes
+//    to rt3 were updated
+//
+// Input:
+//  * Protocol: received updated Router-LSA from rt5, restoring the metric of
+//    its links to rt3 to 10
+//  * Protocol: SPF delay timer expiration
+// Output:
+//  * Ibus: update the route to rt3, adding back the equal-cost path through
+//    rt5 (which requires falling back to a full SPF run)
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router-LSA and an LS Ack to rt5
+//  * Northbound: the updated Router-LSA is present in the LSDB and the routes
+//    to rt3 were updated
+#[tokio::test]
+async fn spf_incremental3() {
+    run_test::<Instance<Ospfv2>>("spf-incremental3", "topo2-4", "rt4").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: disable the instance and enable the stub router state on
+//    startup, waiting for the BGP initial convergence
+// Output:
+//  * Protocol: send an LS Update to all adjacencies flushing the self-originated
+//    LSAs
+//  * Northbound: the instance is inactive
+//  * Southbound: uninstall all routes
+//
+// Input:
+//  * Northbound: enable the instance
+// Output:
+//  * Northbound: the instance is active again, with no adjacencies
+//
+// Input:
+//  * Protocol: Hello packet from rt4 listing 6.6.6.6 as a neighbor
+// Output:
+//  * Protocol: send a DB Description packet to rt4 (ExStart)
+//  * Northbound: the 4.4.4.4 neighbor is in the ExStart state
+//
+// Input:
+//  * Protocol: DB Description packets from rt4 (slave), with no LSA headers
+// Output:
+//  * Protocol:
+//    - send a DB Description packet to rt4 containing the self-originated LSA
+//      headers
+//    - send an LS Update to rt4 containing the updated Router-LSA, whose
+//      point-to-point link has the maximum metric (65535)
+//  * Northbound: the 4.4.4.4 neighbor is in the Full state
+//
+// Input:
+//  * Ibus: BGP initial convergence notification
+// Output:
+//  * Protocol: send an LS Update to rt4 containing the updated Router-LSA, whose
+//    point-to-point link has the configured metric (10)
+//  * Northbound: the self-originated Router-LSA was updated
+#[tokio::test]
+async fn stub_router_startup1() {
+    run_test::<Instance<Ospfv2>>("stub-router-startup1", "topo2-1", "rt6")
+        .await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: disable the instance and enable the stub router state on
+//    startup, with a timeout of 60 seconds
+// Output:
+//  * Protocol: send an LS Update to all adjacencies flushing the self-originated
+//    LSAs
+//  * Northbound: the instance is inactive
+//  * Southbound: uninstall all routes
+//
+// Input:
+//  * Northbound: enable the instance
+// Output:
+//  * Northbound: the instance is active again, with no adjacencies
+//
+// Input:
+//  * Protocol: Hello packet from rt4 listing 6.6.6.6 as a neighbor
+// Output:
+//  * Protocol: send a DB Description packet to rt4 (ExStart)
+//  * Northbound: the 4.4.4.4 neighbor is in the ExStart state
+//
+// Input:
+//  * Protocol: DB Description packets from rt4 (slave), with no LSA headers
+// Output:
+//  * Protocol:
+//    - send a DB Description packet to rt4 containing the self-originated LSA
+//      headers
+//    - send an LS Update to rt4 containing the updated Router-LSA, whose
+//      point-to-point link has the maximum metric (65535)
+//  * Northbound: the 4.4.4.4 neighbor is in the Full state
+//
+// Input:
+//  * Ibus: BGP initial convergence notification
+// Output: no changes
+//
+// Input:
+//  * Protocol: stub router on startup timeout
+// Output:
+//  * Protocol: send an LS Update to rt4 containing the updated Router-LSA, whose
+//    point-to-point link has the configured metric (10)
+//  * Northbound: the self-originated Router-LSA was updated
+#[tokio::test]
+async fn stub_router_startup2() {
+    run_test::<Instance<Ospfv2>>("stub-router-startup2", "topo2-1", "rt6")
+        .await;
+}
+
 // Test description:
 //
 // Input:
@@ -1526,3 +2048,28 @@ async fn timeout_nbr1() {
 async fn timeout_nbr2() {
     run_test::<Instance<Ospfv2>>("timeout-nbr2", "topo1-2", "rt3").await;
 }
+
+// Test description:
+//
+// Input:
+//  * Northbound: configure a virtual link to 1.1.1.1 through area 0.0.0.1
+//  * Protocol: adjacency with 1.1.1.1 is formed over the virtual link
+//  * Protocol: received LS Update over the virtual link containing the
+//    backbone Router-LSA of 1.1.1.1 with a stub network to 172.16.1.0/24
+//  * Protocol: SPF_TIMER expiration
+//  * Northbound: remove the virtual link
+// Output:
+//  * Northbound: the virtual link transitions to the "point-to-point" state
+//  * Protocol: Database Description packets are sent to 10.0.1.1 with the MTU
+//    field set to zero
+//  * Protocol: once the adjacency is full:
+//    - the self-originated backbone Router-LSA has a virtual link to 1.1.1.1
+//    - the self-originated Router-LSA of area 0.0.0.1 has the V-bit set
+//  * Southbound: install route to 172.16.1.0/24 using the nexthop of the
+//    transit area
+//  * Protocol: the self-originated Router-LSAs no longer describe the virtual
+//    link nor have the V-bit set
+#[tokio::test]
+async fn vlink1() {
+    run_test::<Instance<Ospfv2>>("vlink1", "topo1-1", "rt2").await;
+}
@@ -49,3 +49,98 @@ async fn packet_lsupd_self_orig2() {
     run_test::<Instance<Ospfv3>>("packet-lsupd-self-orig2", "topo2-1", "rt3")
         .await;
 }
+
+// Test description:
+//
+// Input:
+//  * Protocol: received Grace-LSA from rt6
+// Output:
+//  * Protocol: send an LS Ack to rt6 containing the Grace-LSA
+//  * Northbound:
+//    - the Grace-LSA is present in the interface LSDB
+//    - neighbor rt6 is in graceful restart mode
+//
+// Input:
+//  * Protocol: received MaxAge Grace-LSA from rt6
+// Output:
+//  * Protocol: send an LS Ack to rt6 containing the MaxAge Grace-LSA
+//  * Northbound:
+//    - The Grace-LSA is present in the interface LSDB with MaxAge
+//    - neighbor rt6 is no longer in graceful restart mode
+//      ("exit-reason":"completed")
+#[tokio::test]
+async fn gr_helper_exit1() {
+    run_test::<Instance<Ospfv3>>("gr-helper-exit1", "topo2-1", "rt4").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Protocol: received Grace-LSA from rt6
+// Output:
+//  * Protocol: send an LS Ack to rt6 containing the Grace-LSA
+//  * Northbound:
+//    - the Grace-LSA is present in the interface LSDB
+//    - neighbor rt6 is in graceful restart mode
+//
+// Input:
+//  * Protocol: the grace period for rt6 has timed out
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    self-originated Router-LSA, which no longer has a link to rt6
+//  * Northbound:
+//    - neighbor rt6 is no longer in graceful restart mode
+//      ("exit-reason":"timed-out")
+//    - neighbor rt6 is deleted
+//    - the self-originated Router-LSA no longer includes a link to rt6
+#[tokio::test]
+async fn gr_helper_exit2() {
+    run_test::<Instance<Ospfv3>>("gr-helper-exit2", "topo2-1", "rt4").await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: enable graceful restart
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the updated
+//    Router Information LSA, which now advertises the graceful restart
+//    capability
+//
+// Input:
+//  * Northbound: prepare for a planned graceful restart
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the
+//    self-originated Grace-LSA of the corresponding interface
+//  * Northbound:
+//    - the Grace-LSAs are present in the interface LSDBs
+//    - the instance is preparing for a planned restart
+//      ("status":"planned-restart")
+//
+// Input:
+//  * Protocol: the instance wasn't restarted within the grace period
+// Output:
+//  * Protocol: send an LS Update to all adjacencies containing the MaxAge
+//    Grace-LSA of the corresponding interface
+//  * Northbound:
+//    - the Grace-LSAs are flushed from the interface LSDBs
+//    - the planned restart is canceled ("exit-reason":"timed-out")
+#[tokio::test]
+async fn gr_restart_prepare1() {
+    run_test::<Instance<Ospfv3>>("gr-restart-prepare1", "topo2-1", "rt4")
+        .await;
+}
+
+// Test description:
+//
+// Input:
+//  * Northbound: configure the test instance with a multi-area adjacency in
+//    area 0.0.0.0 on interface eth-rt1
+// Output:
+//  * Northbound: the configuration is rejected since multi-area adjacencies
+//    aren't supported by OSPFv3
+#[tokio::test]
+async fn nb_config_multi_area1() {
+    run_test::<Instance<Ospfv3>>("nb-config-multi-area1", "topo1-1", "rt2")
+        .await;
+}
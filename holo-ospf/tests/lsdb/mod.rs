@@ -0,0 +1,103 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use std::time::Duration;
+
+use holo_ospf::lsdb::lsa_orig_throttle_delay;
+use holo_ospf::northbound::configuration::InstanceCfg;
+
+//
+// Helper functions.
+//
+
+fn config(
+    initial_delay: u32,
+    hold_interval: u32,
+    max_interval: u32,
+) -> InstanceCfg {
+    InstanceCfg {
+        lsa_throttle_initial_delay: initial_delay,
+        lsa_throttle_hold_interval: hold_interval,
+        lsa_throttle_max_interval: max_interval,
+        ..Default::default()
+    }
+}
+
+fn ms(ms: u64) -> Duration {
+    Duration::from_millis(ms)
+}
+
+//
+// Tests.
+//
+
+#[test]
+fn test_lsa_throttle_default() {
+    // With the default configuration, LSAs are originated at most once every
+    // five seconds.
+    let config = InstanceCfg::default();
+    let mut hold_interval = config.lsa_throttle_hold_interval;
+
+    let delay = lsa_orig_throttle_delay(ms(5000), &mut hold_interval, &config);
+    assert_eq!(delay, Duration::ZERO);
+
+    let delay = lsa_orig_throttle_delay(ms(1000), &mut hold_interval, &config);
+    assert_eq!(delay, ms(4000));
+    assert_eq!(hold_interval, 5000);
+}
+
+#[test]
+fn test_lsa_throttle_initial_delay() {
+    let config = config(100, 1000, 8000);
+    let mut hold_interval = config.lsa_throttle_hold_interval;
+
+    let delay = lsa_orig_throttle_delay(ms(10000), &mut hold_interval, &config);
+    assert_eq!(delay, ms(100));
+    assert_eq!(hold_interval, 1000);
+}
+
+#[test]
+fn test_lsa_throttle_backoff() {
+    let config = config(0, 1000, 8000);
+    let mut hold_interval = config.lsa_throttle_hold_interval;
+
+    // The hold interval doubles on every change, up to the maximum interval.
+    let delay = lsa_orig_throttle_delay(ms(200), &mut hold_interval, &config);
+    assert_eq!(delay, ms(800));
+    assert_eq!(hold_interval, 2000);
+
+    let delay = lsa_orig_throttle_delay(ms(1500), &mut hold_interval, &config);
+    assert_eq!(delay, ms(500));
+    assert_eq!(hold_interval, 4000);
+
+    let delay = lsa_orig_throttle_delay(ms(0), &mut hold_interval, &config);
+    assert_eq!(delay, ms(4000));
+    assert_eq!(hold_interval, 8000);
+
+    let delay = lsa_orig_throttle_delay(ms(0), &mut hold_interval, &config);
+    assert_eq!(delay, ms(8000));
+    assert_eq!(hold_interval, 8000);
+
+    // The time elapsed since the previous origination is discounted.
+    let delay = lsa_orig_throttle_delay(ms(7999), &mut hold_interval, &config);
+    assert_eq!(delay, ms(1));
+}
+
+#[test]
+fn test_lsa_throttle_reset() {
+    let config = config(0, 1000, 8000);
+    let mut hold_interval = 8000;
+
+    // The backoff is reset once the LSA remains unchanged for the maximum
+    // interval.
+    let delay = lsa_orig_throttle_delay(ms(8000), &mut hold_interval, &config);
+    assert_eq!(delay, Duration::ZERO);
+    assert_eq!(hold_interval, 1000);
+
+    let delay = lsa_orig_throttle_delay(ms(300), &mut hold_interval, &config);
+    assert_eq!(delay, ms(700));
+    assert_eq!(hold_interval, 2000);
+}
@@ -4,7 +4,7 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr};
 use std::str::FromStr;
 use std::sync::atomic::AtomicU64;
 use std::sync::{Arc, LazyLock as Lazy};
@@ -586,6 +586,8 @@ static LSA2: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
             LsaBody::OpaqueArea(LsaOpaque::RouterInfo(LsaRouterInfo {
                 info_caps: Some(RouterInfoCaps::TE.into()),
                 func_caps: None,
+                hostname: None,
+                node_tags: vec![],
                 sr_algo: Some(SrAlgoTlv::new(btreeset!(IgpAlgoType::Spf))),
                 srgb: vec![SidLabelRangeTlv::new(
                     Sid::Label(Label::new(16000)),
@@ -597,6 +599,7 @@ static LSA2: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
                 )],
                 msds: None,
                 srms_pref: None,
+                fads: vec![],
                 unknown_tlvs: vec![],
             })),
         ),
@@ -670,6 +673,7 @@ static LSA4: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
                         sid: Sid::Label(Label::new(4000)),
                     }],
                     msds: Default::default(),
+                    asla: None,
                     unknown_tlvs: vec![],
                 }),
             })),
@@ -677,6 +681,42 @@ static LSA4: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
     )
 });
 
+static TE_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0x42, 0x0a, 0x01, 0x00, 0x00, 0x01, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0x8c, 0xe1, 0x00, 0x50, 0x00, 0x02,
+            0x00, 0x38, 0x00, 0x01, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00, 0x00,
+            0x02, 0x00, 0x04, 0x02, 0x02, 0x02, 0x02, 0x00, 0x03, 0x00, 0x04,
+            0x0a, 0x00, 0x01, 0x01, 0x00, 0x04, 0x00, 0x04, 0x0a, 0x00, 0x01,
+            0x02, 0x00, 0x05, 0x00, 0x04, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x06,
+            0x00, 0x04, 0x4c, 0xee, 0x6b, 0x28, 0x00, 0x09, 0x00, 0x04, 0x00,
+            0x00, 0x00, 0x01,
+        ],
+        Lsa::new(
+            1,
+            Some(Options::O | Options::E),
+            Ipv4Addr::from_str("1.0.0.1").unwrap(),
+            Ipv4Addr::from_str("1.1.1.1").unwrap(),
+            0x80000001,
+            LsaBody::OpaqueArea(LsaOpaque::Te(LsaTe {
+                router_addr: None,
+                link: Some(TeLinkTlv {
+                    link_type: Some(TeLinkType::PointToPoint),
+                    link_id: Some(Ipv4Addr::from_str("2.2.2.2").unwrap()),
+                    local_addrs: vec![IpAddr::from_str("10.0.1.1").unwrap()],
+                    remote_addrs: vec![IpAddr::from_str("10.0.1.2").unwrap()],
+                    te_metric: Some(10),
+                    max_bw: Some(TeBandwidth::new(0x4cee6b28)),
+                    admin_group: Some(1),
+                    ..Default::default()
+                }),
+                unknown_tlvs: vec![],
+            })),
+        ),
+    )
+});
+
 static GRACE_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
     (
         vec![
@@ -701,6 +741,31 @@ static GRACE_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
     )
 });
 
+static NSSA_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv2>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0x0a, 0x07, 0xac, 0x10, 0x01, 0x00, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0x01, 0x81, 0x00, 0x24, 0xff, 0xff,
+            0xff, 0x00, 0x80, 0x00, 0x00, 0x14, 0x0a, 0x00, 0x01, 0x01, 0x00,
+            0x00, 0x00, 0x64,
+        ],
+        Lsa::new(
+            1,
+            Some(Options::NP | Options::E),
+            Ipv4Addr::from_str("172.16.1.0").unwrap(),
+            Ipv4Addr::from_str("1.1.1.1").unwrap(),
+            0x80000001,
+            LsaBody::NssaExternal(LsaAsExternal {
+                mask: Ipv4Addr::from_str("255.255.255.0").unwrap(),
+                flags: LsaAsExternalFlags::E,
+                metric: 20,
+                fwd_addr: Some(Ipv4Addr::from_str("10.0.1.1").unwrap()),
+                tag: 100,
+            }),
+        ),
+    )
+});
+
 //
 // Tests.
 //
@@ -873,6 +938,18 @@ fn test_decode_lsa4() {
     test_decode_lsa(bytes, lsa);
 }
 
+#[test]
+fn test_encode_te_lsa1() {
+    let (ref bytes, ref lsa) = *TE_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_te_lsa1() {
+    let (ref bytes, ref lsa) = *TE_LSA1;
+    test_decode_lsa(bytes, lsa);
+}
+
 #[test]
 fn test_encode_grace_lsa1() {
     let (ref bytes, ref lsa) = *GRACE_LSA1;
@@ -884,3 +961,15 @@ fn test_decode_grace_lsa1() {
     let (ref bytes, ref lsa) = *GRACE_LSA1;
     test_decode_lsa(bytes, lsa);
 }
+
+#[test]
+fn test_encode_nssa_lsa1() {
+    let (ref bytes, ref lsa) = *NSSA_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_nssa_lsa1() {
+    let (ref bytes, ref lsa) = *NSSA_LSA1;
+    test_decode_lsa(bytes, lsa);
+}
@@ -21,8 +21,8 @@ use holo_utils::crypto::CryptoAlgo;
 use holo_utils::ip::AddressFamily;
 use holo_utils::keychain::Key;
 use holo_utils::mpls::Label;
-use holo_utils::sr::{IgpAlgoType, Sid};
-use ipnetwork::IpNetwork;
+use holo_utils::sr::{IgpAlgoType, Sid, Srv6SidStructure};
+use ipnetwork::{IpNetwork, Ipv6Network};
 use maplit::{btreemap, btreeset};
 
 const SRC_ADDR: Ipv6Addr = Ipv6Addr::UNSPECIFIED;
@@ -644,6 +644,8 @@ static LSA3: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
                         .into(),
                 ),
                 func_caps: None,
+                hostname: None,
+                node_tags: vec![],
                 sr_algo: Some(SrAlgoTlv::new(btreeset!(IgpAlgoType::Spf))),
                 srgb: vec![SidLabelRangeTlv::new(
                     Sid::Label(Label::new(16000)),
@@ -655,6 +657,8 @@ static LSA3: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
                 )],
                 msds: Default::default(),
                 srms_pref: None,
+                fads: vec![],
+                srv6_caps: None,
                 unknown_tlvs: vec![],
             }),
         ),
@@ -693,7 +697,8 @@ static EXT_ROUTER_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
                         nbr_router_id: None,
                         sid: Sid::Label(Label::new(4000)),
                     }],
-
+                    asla: None,
+                    srv6_endx_sids: vec![],
                     unknown_stlvs: vec![],
                 }],
                 unknown_tlvs: vec![],
@@ -824,6 +829,42 @@ static EXT_AS_EXTERNAL_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
     )
 });
 
+static NSSA_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0x20, 0x07, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0xc1, 0xc0, 0x00, 0x38, 0x07, 0x00,
+            0x00, 0x14, 0x40, 0x08, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00,
+            0x01, 0x00, 0x00, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+            0x64,
+        ],
+        Lsa::new(
+            1,
+            None,
+            Ipv4Addr::from_str("0.0.0.1").unwrap(),
+            Ipv4Addr::from_str("1.1.1.1").unwrap(),
+            0x80000001,
+            LsaBody::Nssa(LsaAsExternal {
+                extended: false,
+                flags: LsaAsExternalFlags::E
+                    | LsaAsExternalFlags::F
+                    | LsaAsExternalFlags::T,
+                metric: 20,
+                prefix_options: PrefixOptions::P,
+                prefix: IpNetwork::from_str("2001:db8:1::/64").unwrap(),
+                fwd_addr: Some(IpAddr::from_str("2001:db8::1").unwrap()),
+                tag: Some(100),
+                ref_lsa_type: None,
+                ref_lsa_id: None,
+                prefix_sids: Default::default(),
+                unknown_tlvs: vec![],
+                unknown_stlvs: vec![],
+            }),
+        ),
+    )
+});
+
 static EXT_LINK_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
     (
         vec![
@@ -923,6 +964,43 @@ static GRACE_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
     )
 });
 
+static SRV6_LOCATOR_LSA1: Lazy<(Vec<u8>, Lsa<Ospfv3>)> = Lazy::new(|| {
+    (
+        vec![
+            0x00, 0x01, 0xa0, 0x2a, 0x00, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01,
+            0x01, 0x80, 0x00, 0x00, 0x01, 0x87, 0x9b, 0x00, 0x50, 0x00, 0x01,
+            0x00, 0x38, 0x01, 0x00, 0x30, 0x80, 0x00, 0x00, 0x00, 0x00, 0x20,
+            0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x1c, 0x00, 0x00, 0x00,
+            0x01, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1e, 0x00, 0x04, 0x20,
+            0x10, 0x10, 0x00,
+        ],
+        Lsa::new(
+            1,
+            None,
+            Ipv4Addr::from_str("0.0.0.0").unwrap(),
+            Ipv4Addr::from_str("1.1.1.1").unwrap(),
+            0x80000001,
+            LsaBody::Srv6Locator(LsaSrv6Locator {
+                locators: vec![Srv6LocatorTlv::new(
+                    Srv6LocatorRouteType::IntraArea,
+                    IgpAlgoType::Spf,
+                    Srv6LocatorFlags::N,
+                    0,
+                    Ipv6Network::from_str("2001:db8:1::/48").unwrap(),
+                    vec![Srv6EndSid::new(
+                        1,
+                        Ipv6Addr::from_str("2001:db8:1:1::").unwrap(),
+                        Some(Srv6SidStructure::new(32, 16, 16, 0)),
+                    )],
+                )],
+                unknown_tlvs: vec![],
+            }),
+        ),
+    )
+});
+
 //
 // Tests.
 //
@@ -1143,6 +1221,18 @@ fn test_decode_extended_as_external_lsa1() {
     test_decode_lsa(bytes, lsa, AddressFamily::Ipv6);
 }
 
+#[test]
+fn test_encode_nssa_lsa1() {
+    let (ref bytes, ref lsa) = *NSSA_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_nssa_lsa1() {
+    let (ref bytes, ref lsa) = *NSSA_LSA1;
+    test_decode_lsa(bytes, lsa, AddressFamily::Ipv6);
+}
+
 #[test]
 fn test_encode_extended_link_lsa1() {
     let (ref bytes, ref lsa) = *EXT_LINK_LSA1;
@@ -1178,3 +1268,15 @@ fn test_decode_grace_lsa1() {
     let (ref bytes, ref lsa) = *GRACE_LSA1;
     test_decode_lsa(bytes, lsa, AddressFamily::Ipv4);
 }
+
+#[test]
+fn test_encode_srv6_locator_lsa1() {
+    let (ref bytes, ref lsa) = *SRV6_LOCATOR_LSA1;
+    test_encode_lsa(bytes, lsa);
+}
+
+#[test]
+fn test_decode_srv6_locator_lsa1() {
+    let (ref bytes, ref lsa) = *SRV6_LOCATOR_LSA1;
+    test_decode_lsa(bytes, lsa, AddressFamily::Ipv6);
+}
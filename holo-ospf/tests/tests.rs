@@ -7,4 +7,5 @@
 #![feature(lazy_cell)]
 
 mod conformance;
+mod lsdb;
 mod packet;
@@ -75,3 +75,9 @@ pub fn setup() {
         init_yang();
     });
 }
+
+// Common initialization required by all benchmarks (tracing is left disabled
+// to avoid skewing the measurements).
+pub fn setup_bench() {
+    INIT.call_once(init_yang);
+}
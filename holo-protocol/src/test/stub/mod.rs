@@ -70,6 +70,7 @@ enum TestOpInput {
 
 #[derive(Debug)]
 enum TestOpOutput {
+    NorthboundError,
     NorthboundNotif,
     NorthboundState,
     Ibus,
@@ -79,6 +80,7 @@ enum TestOpOutput {
 #[derive(Debug, Default)]
 struct TestStep {
     input: Option<(TestOpInput, String)>,
+    output_nb_error: String,
     output_nb_notif: String,
     output_nb_state: Option<String>,
     output_ibus: String,
@@ -96,9 +98,17 @@ where
         self.tx.send(msg).await.unwrap();
     }
 
+    // Replaces the running configuration of the test instance.
+    pub async fn commit_replace(&mut self, data: &str) {
+        self.nb
+            .commit_replace(data)
+            .await
+            .expect("Failed to commit configuration");
+    }
+
     // Synchronizes the protocol instance to ensure all previously sent instance
     // messages were already received and processed.
-    async fn sync(&self) {
+    pub async fn sync(&self) {
         let (responder_tx, responder_rx) = oneshot::channel();
         let msg = TestMsg::Synchronize(SynchronizeMsg {
             responder: Some(responder_tx),
@@ -179,6 +189,9 @@ impl TestOp {
             }
             "input-ibus.jsonl" => Ok(TestOp::Input(TestOpInput::Ibus)),
             "input-protocol.jsonl" => Ok(TestOp::Input(TestOpInput::Protocol)),
+            "output-northbound-error.txt" => {
+                Ok(TestOp::Output(TestOpOutput::NorthboundError))
+            }
             "output-northbound-notif.jsonl" => {
                 Ok(TestOp::Output(TestOpOutput::NorthboundNotif))
             }
@@ -199,6 +212,7 @@ impl TestOp {
 impl TestOpOutput {
     fn to_filename(&self) -> &'static str {
         match self {
+            TestOpOutput::NorthboundError => "output-northbound-error.txt",
             TestOpOutput::NorthboundNotif => "output-northbound-notif.jsonl",
             TestOpOutput::NorthboundState => "output-northbound-state.json",
             TestOpOutput::Ibus => "output-ibus.jsonl",
@@ -220,38 +234,6 @@ fn output_path(dir: &str, step: usize, op: TestOpOutput) -> String {
     format!("{}/{:0width$}-{}", dir, step, op.to_filename(), width = 2)
 }
 
-// Loads instance snapshot of the provided topology and router.
-async fn load_snapshot<P>(topology: &str, router: &str) -> Stub<P>
-where
-    P: ProtocolInstance,
-{
-    // Get topology base directory.
-    let topo_dir = topology_dir::<P>(topology, router);
-
-    // Spawn protocol instance.
-    let mut stub = start_test_instance::<P>("test").await;
-
-    // Push configuration through stub northbound.
-    let path = format!("{}/{}", topo_dir, "config.json");
-    let config = std::fs::read_to_string(&path)
-        .expect("unable to read configuration file");
-    stub.nb.commit_replace(&config).await;
-
-    // Push events.
-    let path = format!("{}/{}", topo_dir, "events.jsonl");
-    let events =
-        std::fs::read_to_string(&path).expect("unable to read events file");
-    for msg in events.lines() {
-        let msg = serde_json::from_str(msg)
-            .expect("failed to parse instance message");
-        stub.send(msg).await;
-    }
-
-    stub.sync().await;
-
-    stub
-}
-
 // ===== global functions =====
 
 // Starts test instance.
@@ -302,6 +284,38 @@ where
     Stub::new(nb_stub, messages, instance_tx, test_tx)
 }
 
+// Loads instance snapshot of the provided topology and router.
+pub async fn load_snapshot<P>(topology: &str, router: &str) -> Stub<P>
+where
+    P: ProtocolInstance,
+{
+    // Get topology base directory.
+    let topo_dir = topology_dir::<P>(topology, router);
+
+    // Spawn protocol instance.
+    let mut stub = start_test_instance::<P>("test").await;
+
+    // Push configuration through stub northbound.
+    let path = format!("{}/{}", topo_dir, "config.json");
+    let config = std::fs::read_to_string(&path)
+        .expect("unable to read configuration file");
+    stub.commit_replace(&config).await;
+
+    // Push events.
+    let path = format!("{}/{}", topo_dir, "events.jsonl");
+    let events =
+        std::fs::read_to_string(&path).expect("unable to read events file");
+    for msg in events.lines() {
+        let msg = serde_json::from_str(msg)
+            .expect("failed to parse instance message");
+        stub.send(msg).await;
+    }
+
+    stub.sync().await;
+
+    stub
+}
+
 // Starts test instance based on a preset state and runs all tests specified in
 // the corresponding test directory.
 pub async fn run_test<P>(test: &str, topology: &str, router: &str)
@@ -344,6 +358,9 @@ where
                 }
                 step.input = Some((op, data));
             }
+            TestOp::Output(TestOpOutput::NorthboundError) => {
+                step.output_nb_error = data;
+            }
             TestOp::Output(TestOpOutput::NorthboundNotif) => {
                 step.output_nb_notif = data;
             }
@@ -362,12 +379,17 @@ where
         // Process input.
         let (op, data) = step.input.expect("missing test input for this step");
         stub.messages.reset_output();
+        let mut nb_error = String::new();
         match op {
             TestOpInput::NorthboundConfigChange => {
-                stub.nb.commit_changes(&data).await;
+                if let Err(error) = stub.nb.commit_changes(&data).await {
+                    nb_error = error;
+                }
             }
             TestOpInput::NorthboundConfigReplace => {
-                stub.nb.commit_replace(&data).await;
+                if let Err(error) = stub.nb.commit_replace(&data).await {
+                    nb_error = error;
+                }
             }
             TestOpInput::NorthboundRpc => {
                 stub.nb.rpc(&data).await;
@@ -389,6 +411,11 @@ where
         }
         stub.sync().await;
 
+        // Check output: northbound errors.
+        let path =
+            output_path(&test_dir, step_num, TestOpOutput::NorthboundError);
+        stub.assert_output(&step.output_nb_error, &nb_error, &path);
+
         // Check output: northbound notifications.
         let path =
             output_path(&test_dir, step_num, TestOpOutput::NorthboundNotif);
@@ -7,6 +7,7 @@
 use std::sync::Arc;
 
 use holo_northbound::configuration::{self, CommitPhase, ConfigChanges};
+use holo_northbound::error::Error;
 use holo_northbound::state::NodeAttributes;
 use holo_northbound::{api, NbDaemonSender};
 use holo_yang::YANG_CTX;
@@ -40,7 +41,10 @@ impl NorthboundStub {
         }
     }
 
-    pub(crate) async fn commit_changes(&mut self, data_diff_str: &str) {
+    pub(crate) async fn commit_changes(
+        &mut self,
+        data_diff_str: &str,
+    ) -> Result<(), String> {
         let yang_ctx = YANG_CTX.get().unwrap();
 
         // Parse data diff.
@@ -65,10 +69,13 @@ impl NorthboundStub {
             .validate(DataValidationFlags::NO_STATE)
             .expect("Failed to validate updated configuration");
 
-        self.commit(candidate, data_diff).await;
+        self.commit(candidate, data_diff).await
     }
 
-    pub(crate) async fn commit_replace(&mut self, data_str: &str) {
+    pub(crate) async fn commit_replace(
+        &mut self,
+        data_str: &str,
+    ) -> Result<(), String> {
         let yang_ctx = YANG_CTX.get().unwrap();
 
         // Parse candidate configuration.
@@ -87,7 +94,7 @@ impl NorthboundStub {
             .diff(&candidate, DataDiffFlags::empty())
             .expect("Failed to compare data trees");
 
-        self.commit(candidate, data_diff).await;
+        self.commit(candidate, data_diff).await
     }
 
     pub(crate) async fn rpc(&mut self, data: &str) {
@@ -217,12 +224,19 @@ impl NorthboundStub {
         self.state_cache = Some(actual.duplicate().unwrap());
     }
 
-    async fn commit(&mut self, candidate: DataTree, data_diff: DataDiff) {
+    async fn commit(
+        &mut self,
+        candidate: DataTree,
+        data_diff: DataDiff,
+    ) -> Result<(), String> {
         // Get configuration changes from data diff.
         let changes = configuration::changes_from_diff(&data_diff);
 
-        // Send configuration changes.
+        // Validate the candidate configuration.
         let candidate = Arc::new(candidate);
+        self.validate(&candidate).await?;
+
+        // Send configuration changes.
         self.commit_phase_notify(CommitPhase::Prepare, &candidate, &changes)
             .await;
         self.commit_phase_notify(CommitPhase::Apply, &candidate, &changes)
@@ -230,6 +244,32 @@ impl NorthboundStub {
 
         // Update running configuration.
         self.running_config = candidate;
+
+        Ok(())
+    }
+
+    async fn validate(&self, candidate: &Arc<DataTree>) -> Result<(), String> {
+        // Prepare request.
+        let (responder_tx, responder_rx) = oneshot::channel();
+        let request =
+            api::daemon::Request::Validate(api::daemon::ValidateRequest {
+                config: candidate.clone(),
+                responder: Some(responder_tx),
+            });
+
+        // Send the request and receive the response.
+        self.daemon_tx
+            .send(request)
+            .await
+            .expect("Failed to send validation request");
+        responder_rx
+            .await
+            .expect("Failed to receive validation response")
+            .map(|_| ())
+            .map_err(|error| match error {
+                Error::ValidationCallback(error) => error,
+                error => error.to_string(),
+            })
     }
 
     async fn commit_phase_notify(
@@ -38,8 +38,10 @@ pub(crate) fn route_install<V>(
             ifindex: route.ifindex,
             addr: route.nexthop.unwrap().into(),
             labels: Vec::new(),
+            weight: None,
         }]
         .into(),
+        backup_nexthops: Default::default(),
     };
 
     // Send message.
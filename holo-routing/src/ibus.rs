@@ -8,9 +8,12 @@ use std::net::IpAddr;
 
 use holo_utils::ibus::{IbusMsg, IbusSender};
 use holo_utils::protocol::Protocol;
-use holo_utils::southbound::{RouteKeyMsg, RouteMsg};
+use holo_utils::southbound::{
+    PwInstallMsg, PwStatusMsg, RouteKeyMsg, RouteMsg,
+};
 use ipnetwork::IpNetwork;
 
+use crate::netlink;
 use crate::rib::Route;
 use crate::{Interface, Master};
 
@@ -81,6 +84,18 @@ pub(crate) async fn process_msg(master: &mut Master, msg: IbusMsg) {
             // Remove route from the RIB.
             master.rib.ip_route_del(msg).await;
         }
+        IbusMsg::RouteIpStaleDel(msg) => {
+            // Uninstall stale route from the kernel, unless it was refreshed
+            // in the meantime.
+            if !master.rib.ip_route_exists(&msg) {
+                netlink::ip_route_uninstall(
+                    &master.netlink_handle,
+                    &msg.prefix,
+                    msg.protocol,
+                )
+                .await;
+            }
+        }
         IbusMsg::RouteMplsAdd(msg) => {
             // Add MPLS route to the LIB.
             master.rib.mpls_route_add(msg).await;
@@ -89,6 +104,28 @@ pub(crate) async fn process_msg(master: &mut Master, msg: IbusMsg) {
             // Remove MPLS route from the LIB.
             master.rib.mpls_route_del(msg).await;
         }
+        IbusMsg::RoutePwAdd(msg) => {
+            // Install pseudowire and report the result back.
+            let installed =
+                netlink::pw_install(&master.netlink_handle, &msg).await;
+            notify_pw_status(&master.ibus_tx, &msg, installed);
+        }
+        IbusMsg::RoutePwDel(msg) => {
+            // Uninstall pseudowire.
+            netlink::pw_uninstall(&master.netlink_handle, &msg).await;
+        }
+        IbusMsg::RouteSrv6SidAdd(msg) => {
+            // Install SRv6 local SID.
+            netlink::srv6_sid_install(&master.netlink_handle, &msg).await;
+        }
+        IbusMsg::RouteSrv6SidDel(msg) => {
+            // Uninstall SRv6 local SID.
+            netlink::srv6_sid_uninstall(&master.netlink_handle, &msg).await;
+        }
+        IbusMsg::RouteRedistributeDump { af } => {
+            // Redistribute all best routes.
+            master.rib.redistribute_dump(af, &master.ibus_tx);
+        }
         // Ignore other events.
         _ => {}
     }
@@ -113,6 +150,7 @@ pub(crate) fn notify_redistribute_add(
         tag: route.tag,
         opaque_attrs: route.opaque_attrs.clone(),
         nexthops: route.nexthops.clone(),
+        backup_nexthops: route.backup_nexthops.clone(),
     };
     let msg = IbusMsg::RouteRedistributeAdd(msg);
     send(ibus_tx, msg);
@@ -139,6 +177,22 @@ pub(crate) fn notify_nht_update(
     send(ibus_tx, msg);
 }
 
+// Sends pseudowire installation status notification.
+pub(crate) fn notify_pw_status(
+    ibus_tx: &IbusSender,
+    msg: &PwInstallMsg,
+    installed: bool,
+) {
+    let msg = PwStatusMsg {
+        protocol: msg.protocol,
+        name: msg.name.clone(),
+        local_label: msg.local_label,
+        installed,
+    };
+    let msg = IbusMsg::RoutePwStatus(msg);
+    send(ibus_tx, msg);
+}
+
 // ===== helper functions =====
 
 fn send(ibus_tx: &IbusSender, msg: IbusMsg) {
@@ -7,10 +7,21 @@
 use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use capctl::caps::CapState;
+use futures::TryStreamExt;
+use holo_utils::ip::{AddressFamily, IpAddrKind};
 use holo_utils::mpls::Label;
 use holo_utils::protocol::Protocol;
-use holo_utils::southbound::Nexthop;
-use ipnetwork::IpNetwork;
+use holo_utils::southbound::{
+    nexthop_weights_scale, Nexthop, PwInstallMsg, PwUninstallMsg,
+    Srv6SidInstallMsg, Srv6SidUninstallMsg,
+};
+use holo_utils::sr::Srv6EndpointBehavior;
+use ipnetwork::{IpNetwork, Ipv6Network};
+use netlink_packet_route::link::nlas::{
+    Info, InfoData, InfoKind, InfoVrf, Nla as LinkNla,
+};
+use netlink_packet_route::route::nlas::Nla;
+use netlink_packet_route::{AF_INET, AF_INET6, IFF_UP};
 use rtnetlink::{new_connection, Handle, RouteAddRequest};
 use tracing::error;
 
@@ -22,6 +33,46 @@ const NETLINK_PROTO_BGP: u8 = 186;
 const NETLINK_PROTO_OSPF: u8 = 188;
 const NETLINK_PROTO_RIP: u8 = 189;
 
+// Kernel priority of the routes used to install backup nexthops.
+//
+// This value needs to be higher than the default IPv6 route priority.
+const BACKUP_ROUTE_PRIORITY: u32 = 4096;
+
+// Lightweight tunnel encapsulation types and SRv6 local SID attributes as
+// defined in the lwtunnel.h and seg6_local.h kernel headers.
+const LWTUNNEL_ENCAP_SEG6_LOCAL: u16 = 7;
+const SEG6_LOCAL_ACTION: u16 = 1;
+const SEG6_LOCAL_NH6: u16 = 5;
+const SEG6_LOCAL_ACTION_END: u32 = 1;
+const SEG6_LOCAL_ACTION_END_X: u32 = 2;
+
+// MPLS encapsulation attributes as defined in the lwtunnel.h and
+// mpls_iptunnel.h kernel headers.
+const LWTUNNEL_ENCAP_MPLS: u16 = 1;
+const MPLS_IPTUNNEL_DST: u16 = 1;
+
+// Route attribute types as defined in the rtnetlink.h kernel header.
+const RTA_GATEWAY: u16 = 5;
+const RTA_ENCAP_TYPE: u16 = 21;
+const RTA_ENCAP: u16 = 22;
+
+// MPLS address family as defined in the socket.h kernel header.
+const AF_MPLS: u8 = 28;
+
+// Base ID of the VRF tables used by the pseudowires. Labels are 20-bit values,
+// so adding the local label to it never overflows.
+const PW_TABLE_ID_BASE: u32 = 0x1000_0000;
+
+// Interface index of the loopback interface.
+const LOOPBACK_IFINDEX: u32 = 1;
+
+// Nexthop as programmed in the kernel.
+struct KernelNexthop {
+    ifindex: u32,
+    addr: Option<IpAddr>,
+    weight: Option<u32>,
+}
+
 fn netlink_protocol(protocol: Protocol) -> u8 {
     match protocol {
         Protocol::BGP => NETLINK_PROTO_BGP,
@@ -36,13 +87,65 @@ pub(crate) async fn ip_route_install(
     prefix: &IpNetwork,
     route: &Route,
 ) {
+    if let Err(error) = ip_route_install_nexthops(
+        handle,
+        prefix,
+        route.protocol,
+        None,
+        route.nexthops.iter(),
+    )
+    .await
+    {
+        error!(%prefix, %error, "failed to install route");
+    }
+}
+
+// Installs the backup nexthops of the given route.
+//
+// The kernel has no notion of backup nexthops, so they're installed as a
+// separate, less preferred route for the same prefix. When the primary route
+// becomes unusable due to a link failure, the kernel immediately falls back to
+// the backup route without waiting for the routing protocols to reconverge.
+//
+// NOTE: on carrier loss, this requires the "ignore_routes_with_linkdown"
+// sysctl to be enabled.
+pub(crate) async fn ip_route_backup_install(
+    handle: &Handle,
+    prefix: &IpNetwork,
+    route: &Route,
+) {
+    if let Err(error) = ip_route_install_nexthops(
+        handle,
+        prefix,
+        route.protocol,
+        Some(BACKUP_ROUTE_PRIORITY),
+        route.backup_nexthops.iter(),
+    )
+    .await
+    {
+        error!(%prefix, %error, "failed to install backup route");
+    }
+}
+
+async fn ip_route_install_nexthops<'a>(
+    handle: &Handle,
+    prefix: &IpNetwork,
+    protocol: Protocol,
+    priority: Option<u32>,
+    nexthops: impl Iterator<Item = &'a Nexthop>,
+) -> Result<(), rtnetlink::Error> {
     // Create netlink request.
     let mut request = handle.route().add();
 
     // Set route protocol.
-    let protocol = netlink_protocol(route.protocol);
+    let protocol = netlink_protocol(protocol);
     request = request.protocol(protocol);
 
+    // Set route priority.
+    if let Some(priority) = priority {
+        request.message_mut().nlas.push(Nla::Priority(priority));
+    }
+
     match prefix {
         IpNetwork::V4(prefix) => {
             // Set destination prefix.
@@ -52,12 +155,10 @@ pub(crate) async fn ip_route_install(
                 .destination_prefix(prefix.ip(), prefix.prefix());
 
             // Add nexthops.
-            request = add_nexthops_ipv4(request, route.nexthops.iter());
+            request = add_nexthops_ipv4(request, nexthops);
 
             // Execute request.
-            if let Err(error) = request.execute().await {
-                error!(%prefix, %error, "failed to install route");
-            }
+            request.execute().await
         }
         IpNetwork::V6(prefix) => {
             // Set destination prefix.
@@ -67,12 +168,10 @@ pub(crate) async fn ip_route_install(
                 .destination_prefix(prefix.ip(), prefix.prefix());
 
             // Add nexthops.
-            request = add_nexthops_ipv6(request, route.nexthops.iter());
+            request = add_nexthops_ipv6(request, nexthops);
 
             // Execute request.
-            if let Err(error) = request.execute().await {
-                error!(%prefix, %error, "failed to install route");
-            }
+            request.execute().await
         }
     }
 }
@@ -81,58 +180,136 @@ fn add_nexthops_ipv4<'a>(
     mut request: RouteAddRequest<Ipv4Addr>,
     nexthops: impl Iterator<Item = &'a Nexthop>,
 ) -> RouteAddRequest<Ipv4Addr> {
-    for nexthop in nexthops {
-        request = match nexthop {
-            Nexthop::Address { addr, ifindex, .. } => {
-                if let IpAddr::V4(addr) = addr {
-                    request.gateway(*addr).output_interface(*ifindex)
-                } else {
-                    request
-                }
-            }
-            Nexthop::Interface { ifindex } => {
-                request.output_interface(*ifindex)
-            }
-            Nexthop::Special(_) => {
-                // TODO: not supported by the `rtnetlink` crate yet.
-                request
+    let nexthops = nexthops_flatten(nexthops, AddressFamily::Ipv4);
+    match nexthops.as_slice() {
+        [] => request,
+        [nexthop] => {
+            if let Some(IpAddr::V4(addr)) = nexthop.addr {
+                request = request.gateway(addr);
             }
-            Nexthop::Recursive { resolved, .. } => {
-                add_nexthops_ipv4(request, resolved.iter())
-            }
-        };
+            request.output_interface(nexthop.ifindex)
+        }
+        _ => {
+            let multipath = multipath_encode(&nexthops);
+            request.message_mut().nlas.push(Nla::MultiPath(multipath));
+            request
+        }
     }
-
-    request
 }
 
 fn add_nexthops_ipv6<'a>(
     mut request: RouteAddRequest<Ipv6Addr>,
     nexthops: impl Iterator<Item = &'a Nexthop>,
 ) -> RouteAddRequest<Ipv6Addr> {
+    let nexthops = nexthops_flatten(nexthops, AddressFamily::Ipv6);
+    match nexthops.as_slice() {
+        [] => request,
+        [nexthop] => {
+            if let Some(IpAddr::V6(addr)) = nexthop.addr {
+                request = request.gateway(addr);
+            }
+            request.output_interface(nexthop.ifindex)
+        }
+        _ => {
+            let multipath = multipath_encode(&nexthops);
+            request.message_mut().nlas.push(Nla::MultiPath(multipath));
+            request
+        }
+    }
+}
+
+// Returns the list of kernel nexthops corresponding to the given RIB nexthops,
+// resolving recursive nexthops and skipping the ones of a different address
+// family.
+//
+// Recursive nexthops pass their weight down to their resolved nexthops.
+fn nexthops_flatten<'a>(
+    nexthops: impl Iterator<Item = &'a Nexthop>,
+    af: AddressFamily,
+) -> Vec<KernelNexthop> {
+    let mut list = vec![];
     for nexthop in nexthops {
-        request = match nexthop {
-            Nexthop::Address { addr, ifindex, .. } => {
-                if let IpAddr::V6(addr) = addr {
-                    request.gateway(*addr).output_interface(*ifindex)
-                } else {
-                    request
+        match nexthop {
+            Nexthop::Address {
+                ifindex,
+                addr,
+                weight,
+                ..
+            } => {
+                if addr.address_family() == af {
+                    list.push(KernelNexthop {
+                        ifindex: *ifindex,
+                        addr: Some(*addr),
+                        weight: *weight,
+                    });
                 }
             }
-            Nexthop::Interface { ifindex } => {
-                request.output_interface(*ifindex)
+            Nexthop::Interface { ifindex, weight } => {
+                list.push(KernelNexthop {
+                    ifindex: *ifindex,
+                    addr: None,
+                    weight: *weight,
+                });
             }
             Nexthop::Special(_) => {
                 // TODO: not supported by the `rtnetlink` crate yet.
-                request
             }
-            Nexthop::Recursive { resolved, .. } => {
-                add_nexthops_ipv6(request, resolved.iter())
+            Nexthop::Recursive {
+                resolved, weight, ..
+            } => {
+                let mut resolved = nexthops_flatten(resolved.iter(), af);
+                if weight.is_some() {
+                    for nexthop in &mut resolved {
+                        nexthop.weight = *weight;
+                    }
+                }
+                list.extend(resolved);
             }
-        };
+        }
     }
+    list
+}
 
-    request
+// Encodes the RTA_MULTIPATH attribute for the given list of nexthops.
+//
+// Weights are scaled to the range supported by the kernel.
+//
+// The `netlink-packet-route` crate only supports multipath attributes with the
+// "rich_nlas" feature, so they're encoded by hand.
+fn multipath_encode(nexthops: &[KernelNexthop]) -> Vec<u8> {
+    let weights = nexthop_weights_scale(
+        &nexthops
+            .iter()
+            .map(|nexthop| nexthop.weight)
+            .collect::<Vec<_>>(),
+    );
+
+    let mut buf = vec![];
+    for (nexthop, weight) in nexthops.iter().zip(weights) {
+        // struct rtnexthop.
+        let start_pos = buf.len();
+        buf.extend_from_slice(&0u16.to_ne_bytes());
+        buf.push(0);
+        buf.push((weight - 1) as u8);
+        buf.extend_from_slice(&nexthop.ifindex.to_ne_bytes());
+
+        // Nested RTA_GATEWAY attribute.
+        match nexthop.addr {
+            Some(IpAddr::V4(addr)) => {
+                rtattr_encode(&mut buf, RTA_GATEWAY, &addr.octets());
+            }
+            Some(IpAddr::V6(addr)) => {
+                rtattr_encode(&mut buf, RTA_GATEWAY, &addr.octets());
+            }
+            None => (),
+        }
+
+        // Rewrite rtnexthop length.
+        let len = (buf.len() - start_pos) as u16;
+        buf[start_pos..start_pos + 2].copy_from_slice(&len.to_ne_bytes());
+    }
+
+    buf
 }
 
 pub(crate) async fn ip_route_uninstall(
@@ -140,6 +317,37 @@ pub(crate) async fn ip_route_uninstall(
     prefix: &IpNetwork,
     protocol: Protocol,
 ) {
+    if let Err(error) =
+        ip_route_uninstall_priority(handle, prefix, protocol, None).await
+    {
+        error!(%prefix, %error, "failed to uninstall route");
+    }
+}
+
+// Uninstalls the backup route of the given prefix.
+pub(crate) async fn ip_route_backup_uninstall(
+    handle: &Handle,
+    prefix: &IpNetwork,
+    protocol: Protocol,
+) {
+    if let Err(error) = ip_route_uninstall_priority(
+        handle,
+        prefix,
+        protocol,
+        Some(BACKUP_ROUTE_PRIORITY),
+    )
+    .await
+    {
+        error!(%prefix, %error, "failed to uninstall backup route");
+    }
+}
+
+async fn ip_route_uninstall_priority(
+    handle: &Handle,
+    prefix: &IpNetwork,
+    protocol: Protocol,
+    priority: Option<u32>,
+) -> Result<(), rtnetlink::Error> {
     // Create netlink request.
     let mut request = handle.route().add();
 
@@ -147,6 +355,11 @@ pub(crate) async fn ip_route_uninstall(
     let protocol = netlink_protocol(protocol);
     request = request.protocol(protocol);
 
+    // Set route priority.
+    if let Some(priority) = priority {
+        request.message_mut().nlas.push(Nla::Priority(priority));
+    }
+
     match prefix {
         IpNetwork::V4(prefix) => {
             // Set destination prefix.
@@ -156,9 +369,7 @@ pub(crate) async fn ip_route_uninstall(
 
             // Execute request.
             let request = handle.route().del(request.message_mut().clone());
-            if let Err(error) = request.execute().await {
-                error!(%prefix, %error, "failed to uninstall route");
-            }
+            request.execute().await
         }
         IpNetwork::V6(prefix) => {
             // Set destination prefix.
@@ -168,13 +379,67 @@ pub(crate) async fn ip_route_uninstall(
 
             // Execute request.
             let request = handle.route().del(request.message_mut().clone());
-            if let Err(error) = request.execute().await {
-                error!(%prefix, %error, "failed to uninstall route");
-            }
+            request.execute().await
         }
     }
 }
 
+pub(crate) async fn srv6_sid_install(handle: &Handle, msg: &Srv6SidInstallMsg) {
+    // Create netlink request.
+    let protocol = netlink_protocol(msg.protocol);
+    let mut request = handle
+        .route()
+        .add()
+        .protocol(protocol)
+        .v6()
+        .replace()
+        .destination_prefix(msg.sid, 128)
+        .output_interface(msg.ifindex.unwrap_or(LOOPBACK_IFINDEX));
+
+    // Add seg6local encapsulation.
+    let action = match msg.behavior {
+        Srv6EndpointBehavior::End => SEG6_LOCAL_ACTION_END,
+        Srv6EndpointBehavior::EndX => SEG6_LOCAL_ACTION_END_X,
+    };
+    let mut encap = vec![];
+    rtattr_encode(&mut encap, SEG6_LOCAL_ACTION, &action.to_ne_bytes());
+    if let Some(nexthop) = msg.nexthop {
+        rtattr_encode(&mut encap, SEG6_LOCAL_NH6, &nexthop.octets());
+    }
+    let nlas = &mut request.message_mut().nlas;
+    nlas.push(Nla::EncapType(LWTUNNEL_ENCAP_SEG6_LOCAL));
+    nlas.push(Nla::Encap(encap));
+
+    // Execute request.
+    if let Err(error) = request.execute().await {
+        error!(sid = %msg.sid, %error, "failed to install SRv6 SID");
+    }
+}
+
+pub(crate) async fn srv6_sid_uninstall(
+    handle: &Handle,
+    msg: &Srv6SidUninstallMsg,
+) {
+    let prefix = IpNetwork::V6(Ipv6Network::new(msg.sid, 128).unwrap());
+    if let Err(error) =
+        ip_route_uninstall_priority(handle, &prefix, msg.protocol, None).await
+    {
+        error!(sid = %msg.sid, %error, "failed to uninstall SRv6 SID");
+    }
+}
+
+// Encodes a nested route netlink attribute.
+//
+// Used for the attributes the `netlink-packet-route` crate doesn't support
+// yet (e.g. seg6local and multipath), which are encoded by hand.
+fn rtattr_encode(buf: &mut Vec<u8>, attr_type: u16, value: &[u8]) {
+    let len = (4 + value.len()) as u16;
+    buf.extend_from_slice(&len.to_ne_bytes());
+    buf.extend_from_slice(&attr_type.to_ne_bytes());
+    buf.extend_from_slice(value);
+    buf.resize(buf.len().next_multiple_of(4), 0);
+}
+
 pub(crate) async fn mpls_route_install(
     _handle: &Handle,
     _local_label: Label,
@@ -191,6 +456,226 @@ pub(crate) async fn mpls_route_uninstall(
     // TODO: not supported by the `rtnetlink` crate yet.
 }
 
+// Installs the given pseudowire.
+//
+// Linux has no native VPWS support, so the pseudowire is emulated using a VRF
+// device (the VPWS interface) enslaving the attachment circuit:
+// * Traffic received on the attachment circuit is looked up in the VRF table,
+//   whose default routes push the remote and transport labels;
+// * Traffic received with the local label has its label popped and is sent
+//   out the attachment circuit.
+//
+// NOTE: only IP payloads are supported by the kernel MPLS data plane, and the
+// control word isn't supported at all.
+//
+// Returns whether the pseudowire was installed successfully.
+pub(crate) async fn pw_install(handle: &Handle, msg: &PwInstallMsg) -> bool {
+    if let Err(error) = pw_install_vpws(handle, msg).await {
+        error!(name = %msg.name, %error, "failed to install pseudowire");
+        return false;
+    }
+    true
+}
+
+pub(crate) async fn pw_uninstall(handle: &Handle, msg: &PwUninstallMsg) {
+    if let Err(error) = pw_uninstall_vpws(handle, msg).await {
+        error!(name = %msg.name, %error, "failed to uninstall pseudowire");
+    }
+}
+
+async fn pw_install_vpws(
+    handle: &Handle,
+    msg: &PwInstallMsg,
+) -> Result<(), rtnetlink::Error> {
+    let table_id = pw_table_id(msg.local_label);
+
+    // Create the VPWS interface and enslave the attachment circuit to it.
+    let ifname = pw_ifname(msg.local_label);
+    let mut request = handle.link().add();
+    let link_msg = request.message_mut();
+    link_msg.header.flags = IFF_UP;
+    link_msg.header.change_mask = IFF_UP;
+    link_msg.nlas.push(LinkNla::IfName(ifname.clone()));
+    link_msg.nlas.push(LinkNla::Info(vec![
+        Info::Kind(InfoKind::Vrf),
+        Info::Data(InfoData::Vrf(vec![InfoVrf::TableId(table_id)])),
+    ]));
+    request.replace().execute().await?;
+    let vpws_ifindex = link_index(handle, ifname).await?;
+    handle
+        .link()
+        .set(msg.ifindex)
+        .master(vpws_ifindex)
+        .execute()
+        .await?;
+
+    // Install the imposition routes.
+    for af in [AddressFamily::Ipv4, AddressFamily::Ipv6] {
+        let Some(multipath) = pw_multipath_encode(msg, af) else {
+            continue;
+        };
+        let mut request = handle
+            .route()
+            .add()
+            .protocol(netlink_protocol(msg.protocol))
+            .table_id(table_id)
+            .replace();
+        let route_msg = request.message_mut();
+        route_msg.header.address_family = match af {
+            AddressFamily::Ipv4 => AF_INET as u8,
+            AddressFamily::Ipv6 => AF_INET6 as u8,
+        };
+        route_msg.nlas.push(Nla::MultiPath(multipath));
+        request.execute().await?;
+    }
+
+    // Install the disposition route.
+    let mut request = handle
+        .route()
+        .add()
+        .protocol(netlink_protocol(msg.protocol))
+        .output_interface(msg.ifindex)
+        .replace();
+    let route_msg = request.message_mut();
+    route_msg.header.address_family = AF_MPLS;
+    route_msg.header.destination_prefix_length = 20;
+    route_msg
+        .nlas
+        .push(Nla::Destination(mpls_label_encode(msg.local_label, true)));
+    request.execute().await
+}
+
+async fn pw_uninstall_vpws(
+    handle: &Handle,
+    msg: &PwUninstallMsg,
+) -> Result<(), rtnetlink::Error> {
+    // Uninstall the disposition route.
+    let mut request = handle.route().add();
+    let route_msg = request.message_mut();
+    route_msg.header.address_family = AF_MPLS;
+    route_msg.header.destination_prefix_length = 20;
+    route_msg.header.protocol = netlink_protocol(msg.protocol);
+    route_msg
+        .nlas
+        .push(Nla::Destination(mpls_label_encode(msg.local_label, true)));
+    let request = handle.route().del(request.message_mut().clone());
+    request.execute().await?;
+
+    // Uninstall the imposition routes, ignoring the address families that
+    // had none.
+    for address_family in [AF_INET, AF_INET6] {
+        let mut request = handle
+            .route()
+            .add()
+            .protocol(netlink_protocol(msg.protocol))
+            .table_id(pw_table_id(msg.local_label));
+        request.message_mut().header.address_family = address_family as u8;
+        let request = handle.route().del(request.message_mut().clone());
+        let _ = request.execute().await;
+    }
+
+    // Release the attachment circuit and delete the VPWS interface.
+    handle.link().set(msg.ifindex).nomaster().execute().await?;
+    let vpws_ifindex = link_index(handle, pw_ifname(msg.local_label)).await?;
+    handle.link().del(vpws_ifindex).execute().await
+}
+
+// Returns the name of the VPWS interface of the pseudowire with the given
+// local label.
+fn pw_ifname(local_label: Label) -> String {
+    format!("mpw{}", local_label.get())
+}
+
+// Returns the ID of the VRF table of the pseudowire with the given local label.
+fn pw_table_id(local_label: Label) -> u32 {
+    PW_TABLE_ID_BASE + local_label.get()
+}
+
+// Encodes the RTA_MULTIPATH attribute of the imposition routes of the given
+// pseudowire, or returns `None` if it has no nexthops of the given address
+// family.
+//
+// Each nexthop pushes its transport labels followed by the remote label.
+fn pw_multipath_encode(
+    msg: &PwInstallMsg,
+    af: AddressFamily,
+) -> Option<Vec<u8>> {
+    let mut buf = vec![];
+    for nexthop in &msg.nexthops {
+        let Nexthop::Address {
+            ifindex,
+            addr,
+            labels,
+            ..
+        } = nexthop
+        else {
+            continue;
+        };
+        if addr.address_family() != af {
+            continue;
+        }
+
+        // struct rtnexthop.
+        let start_pos = buf.len();
+        buf.extend_from_slice(&0u16.to_ne_bytes());
+        buf.push(0);
+        buf.push(0);
+        buf.extend_from_slice(&ifindex.to_ne_bytes());
+
+        // Nested RTA_GATEWAY attribute.
+        match addr {
+            IpAddr::V4(addr) => {
+                rtattr_encode(&mut buf, RTA_GATEWAY, &addr.octets());
+            }
+            IpAddr::V6(addr) => {
+                rtattr_encode(&mut buf, RTA_GATEWAY, &addr.octets());
+            }
+        }
+
+        // Nested RTA_ENCAP_TYPE and RTA_ENCAP attributes.
+        let mut stack = vec![];
+        for label in labels {
+            stack.extend(mpls_label_encode(*label, false));
+        }
+        stack.extend(mpls_label_encode(msg.remote_label, true));
+        let mut encap = vec![];
+        rtattr_encode(&mut encap, MPLS_IPTUNNEL_DST, &stack);
+        rtattr_encode(
+            &mut buf,
+            RTA_ENCAP_TYPE,
+            &LWTUNNEL_ENCAP_MPLS.to_ne_bytes(),
+        );
+        rtattr_encode(&mut buf, RTA_ENCAP, &encap);
+
+        // Rewrite rtnexthop length.
+        let len = (buf.len() - start_pos) as u16;
+        buf[start_pos..start_pos + 2].copy_from_slice(&len.to_ne_bytes());
+    }
+
+    (!buf.is_empty()).then_some(buf)
+}
+
+// Encodes the given label as an MPLS label stack entry.
+fn mpls_label_encode(label: Label, bos: bool) -> Vec<u8> {
+    let mut entry = label.get() << 12;
+    if bos {
+        entry |= 1 << 8;
+    }
+    entry.to_be_bytes().to_vec()
+}
+
+// Returns the index of the interface with the given name.
+async fn link_index(
+    handle: &Handle,
+    ifname: String,
+) -> Result<u32, rtnetlink::Error> {
+    let mut links = handle.link().get().match_name(ifname).execute();
+    match links.try_next().await? {
+        Some(link_msg) => Ok(link_msg.header.index),
+        None => Err(rtnetlink::Error::RequestFailed),
+    }
+}
+
 pub(crate) fn init() -> Handle {
     // Create netlink connection.
     let (conn, handle, _) = new_connection().unwrap();
@@ -16,7 +16,7 @@ use holo_northbound::configuration::{
 };
 use holo_northbound::paths::control_plane_protocol;
 use holo_northbound::paths::routing::ribs;
-use holo_northbound::paths::routing::segment_routing::sr_mpls;
+use holo_northbound::paths::routing::segment_routing::{sr_mpls, srv6};
 use holo_northbound::{CallbackKey, NbDaemonSender};
 use holo_protocol::spawn_protocol_task;
 use holo_utils::ibus::{IbusMsg, SrCfgEvent};
@@ -26,10 +26,13 @@ use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
     Nexthop, NexthopSpecial, RouteKeyMsg, RouteMsg, RouteOpaqueAttrs,
 };
-use holo_utils::sr::{IgpAlgoType, SidLastHopBehavior, SrCfgPrefixSid};
+use holo_utils::sr::{
+    IgpAlgoType, SidLastHopBehavior, SrCfgPrefixSid, Srv6LocatorCfg,
+};
 use holo_utils::yang::DataNodeRefExt;
 use holo_yang::TryFromYang;
 use ipnetwork::IpNetwork;
+use num_traits::FromPrimitive;
 
 use crate::northbound::REGEX_PROTOCOLS;
 use crate::{InstanceId, Interface, Master};
@@ -47,6 +50,7 @@ pub enum ListEntry {
     StaticRoute(IpNetwork),
     StaticRouteNexthop(IpNetwork, String),
     SrCfgPrefixSid(IpNetwork, IgpAlgoType),
+    SrCfgSrv6Locator(String),
 }
 
 #[derive(Debug, EnumAsInner)]
@@ -62,6 +66,7 @@ pub enum Event {
     SrCfgUpdate,
     SrCfgLabelRangeUpdate,
     SrCfgPrefixSidUpdate(AddressFamily),
+    SrCfgSrv6LocatorUpdate,
 }
 
 // ===== configuration structs =====
@@ -605,6 +610,109 @@ fn load_callbacks() -> Callbacks<Master> {
         .lookup(|_master, _list_entry, _dnode| {
             ListEntry::None
         })
+        .path(srv6::locators::locator::PATH)
+        .create_apply(|master, args| {
+            let name = args.dnode.get_string_relative("./name").unwrap();
+            let prefix = args.dnode.get_prefix6_relative("./prefix").unwrap();
+            let algo = args.dnode.get_u8_relative("./algorithm").unwrap();
+            let algo = IgpAlgoType::from_u8(algo).unwrap();
+            let block_len = args.dnode.get_u8_relative("./block-bits").unwrap();
+            let node_len = args.dnode.get_u8_relative("./node-bits").unwrap();
+            let func_len = args.dnode.get_u8_relative("./function-bits").unwrap();
+            let arg_len = args.dnode.get_u8_relative("./argument-bits").unwrap();
+            let locator = Srv6LocatorCfg {
+                prefix,
+                algo,
+                block_len,
+                node_len,
+                func_len,
+                arg_len,
+            };
+            master.sr_config.srv6_locators.insert(name, locator);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgSrv6LocatorUpdate);
+        })
+        .delete_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_srv6_locator().unwrap();
+            master.sr_config.srv6_locators.remove(&name);
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgSrv6LocatorUpdate);
+        })
+        .lookup(|_master, _list_entry, dnode| {
+            let name = dnode.get_string_relative("./name").unwrap();
+            ListEntry::SrCfgSrv6Locator(name)
+        })
+        .path(srv6::locators::locator::prefix::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.prefix = args.dnode.get_prefix6();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgSrv6LocatorUpdate);
+        })
+        .path(srv6::locators::locator::algorithm::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            let algo = args.dnode.get_u8();
+            locator.algo = IgpAlgoType::from_u8(algo).unwrap();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgSrv6LocatorUpdate);
+        })
+        .path(srv6::locators::locator::block_bits::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.block_len = args.dnode.get_u8();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgSrv6LocatorUpdate);
+        })
+        .path(srv6::locators::locator::node_bits::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.node_len = args.dnode.get_u8();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgSrv6LocatorUpdate);
+        })
+        .path(srv6::locators::locator::function_bits::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.func_len = args.dnode.get_u8();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgSrv6LocatorUpdate);
+        })
+        .path(srv6::locators::locator::argument_bits::PATH)
+        .modify_apply(|master, args| {
+            let name = args.list_entry.into_sr_cfg_srv6_locator().unwrap();
+            let locator = master.sr_config.srv6_locators.get_mut(&name).unwrap();
+
+            locator.arg_len = args.dnode.get_u8();
+
+            let event_queue = args.event_queue;
+            event_queue.insert(Event::SrCfgUpdate);
+            event_queue.insert(Event::SrCfgSrv6LocatorUpdate);
+        })
         .path(ribs::rib::PATH)
         .create_apply(|_master, _args| {
             // Nothing to do.
@@ -653,6 +761,18 @@ fn load_validation_callbacks() -> ValidationCallbacks {
 
             Ok(())
         })
+        .path(sr_mpls::bindings::connected_prefix_sid_map::connected_prefix_sid::PATH)
+        .validate(|args| {
+            let algo = args.dnode.get_string_relative("./algorithm").unwrap();
+
+            // Flexible Algorithm Prefix-SIDs are configured per IGP instance,
+            // since the algorithm number isn't part of the identity.
+            if IgpAlgoType::try_from_yang(&algo).is_none() {
+                return Err("unsupported prefix-sid algorithm".to_owned());
+            }
+
+            Ok(())
+        })
         .build()
 }
 
@@ -853,6 +973,7 @@ impl Provider for Master {
                     tag: None,
                     opaque_attrs: RouteOpaqueAttrs::None,
                     nexthops,
+                    backup_nexthops: Default::default(),
                 };
 
                 // Send message.
@@ -891,6 +1012,12 @@ impl Provider for Master {
                     .ibus_tx
                     .send(IbusMsg::SrCfgEvent(SrCfgEvent::PrefixSidUpdate(af)));
             }
+            Event::SrCfgSrv6LocatorUpdate => {
+                // Notify protocol instances about the updated SRv6 locators.
+                let _ = self
+                    .ibus_tx
+                    .send(IbusMsg::SrCfgEvent(SrCfgEvent::Srv6LocatorUpdate));
+            }
         }
     }
 }
@@ -906,6 +1033,7 @@ fn static_nexthop_get(
             ifindex: iface.ifindex,
             addr,
             labels: Default::default(),
+            weight: None,
         })
     } else {
         None
@@ -29,6 +29,7 @@ impl ProviderBase for Master {
             "ietf-segment-routing",
             "ietf-segment-routing-common",
             "ietf-segment-routing-mpls",
+            "holo-routing",
         ]
     }
 
@@ -8,6 +8,7 @@ use holo_northbound::rpc::Provider;
 use holo_northbound::{CallbackKey, NbDaemonSender};
 use holo_utils::protocol::Protocol;
 use holo_utils::yang::DataNodeRefExt;
+use holo_yang::TryFromYang;
 use yang2::data::DataNodeRef;
 
 use crate::Master;
@@ -64,7 +65,13 @@ fn find_instance(
 ) -> Result<(Protocol, Option<String>), String> {
     let (protocol, name) = match rpc.schema().module().name() {
         "ietf-bgp" => {
-            todo!()
+            // BGP actions are defined under the instance data tree.
+            let protocol = Protocol::BGP;
+            let name = rpc
+                .ancestors()
+                .find(|dnode| dnode.schema().name() == "control-plane-protocol")
+                .and_then(|dnode| dnode.get_string_relative("./name"));
+            (protocol, name)
         }
         "ietf-mpls-ldp" => {
             let protocol = Protocol::LDP;
@@ -86,6 +93,22 @@ fn find_instance(
             let name = rpc.get_string_relative("./routing-protocol-name");
             (protocol, name)
         }
+        "holo-ospf" => {
+            // OSPF actions are defined under the instance data tree.
+            let Some(dnode) = rpc.ancestors().find(|dnode| {
+                dnode.schema().name() == "control-plane-protocol"
+            }) else {
+                return Err("unknown instance protocol".to_string());
+            };
+            let Some(protocol) = dnode
+                .get_string_relative("./type")
+                .and_then(|ptype| Protocol::try_from_yang(&ptype))
+            else {
+                return Err("unknown instance protocol".to_string());
+            };
+            let name = dnode.get_string_relative("./name");
+            (protocol, name)
+        }
         "ietf-rip" => {
             // TODO
             let protocol = Protocol::RIPV2;
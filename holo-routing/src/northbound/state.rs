@@ -402,7 +402,9 @@ fn load_callbacks() -> Callbacks<Master> {
         .path(ribs::rib::routes::route::route_type::PATH)
         .get_element_string(|_master, args| {
             let (_, route) = args.list_entry.as_route().unwrap();
-            if let RouteOpaqueAttrs::Ospf { route_type } = &route.opaque_attrs {
+            if let RouteOpaqueAttrs::Ospf { route_type, .. } =
+                &route.opaque_attrs
+            {
                 Some(route_type.to_yang().into())
             } else {
                 None
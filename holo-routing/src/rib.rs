@@ -11,7 +11,9 @@ use bitflags::bitflags;
 use chrono::{DateTime, Utc};
 use derive_new::new;
 use holo_utils::ibus::IbusSender;
-use holo_utils::ip::{IpNetworkExt, Ipv4NetworkExt, Ipv6NetworkExt};
+use holo_utils::ip::{
+    AddressFamily, IpNetworkExt, Ipv4NetworkExt, Ipv6NetworkExt,
+};
 use holo_utils::mpls::Label;
 use holo_utils::protocol::Protocol;
 use holo_utils::southbound::{
@@ -46,6 +48,8 @@ pub struct Route {
     pub tag: Option<u32>,
     pub opaque_attrs: RouteOpaqueAttrs,
     pub nexthops: BTreeSet<Nexthop>,
+    #[new(default)]
+    pub backup_nexthops: BTreeSet<Nexthop>,
     pub last_updated: DateTime<Utc>,
     pub flags: RouteFlags,
 }
@@ -55,6 +59,7 @@ bitflags! {
     pub struct RouteFlags: u8 {
         const ACTIVE = 0x01;
         const REMOVED = 0x02;
+        const BACKUP_INSTALLED = 0x04;
     }
 }
 
@@ -127,7 +132,7 @@ impl Rib {
         match rib_prefix.entry(msg.distance) {
             btree_map::Entry::Vacant(v) => {
                 // If the IP route does not exist, create a new entry.
-                v.insert(Route::new(
+                let route = v.insert(Route::new(
                     msg.protocol,
                     msg.distance,
                     msg.metric,
@@ -137,6 +142,7 @@ impl Rib {
                     Utc::now(),
                     RouteFlags::empty(),
                 ));
+                route.backup_nexthops = msg.backup_nexthops;
             }
             btree_map::Entry::Occupied(o) => {
                 let route = o.into_mut();
@@ -147,6 +153,7 @@ impl Rib {
                 route.tag = msg.tag;
                 route.opaque_attrs = msg.opaque_attrs;
                 route.nexthops = msg.nexthops;
+                route.backup_nexthops = msg.backup_nexthops;
                 route.last_updated = Utc::now();
                 route.flags.remove(RouteFlags::REMOVED);
             }
@@ -173,6 +180,19 @@ impl Rib {
         }
     }
 
+    // Returns whether the RIB has an IP route from the given protocol.
+    pub(crate) fn ip_route_exists(&self, msg: &RouteKeyMsg) -> bool {
+        let rib_prefix = match msg.prefix {
+            IpNetwork::V4(prefix) => self.ipv4.get(&prefix),
+            IpNetwork::V6(prefix) => self.ipv6.get(&prefix),
+        };
+        rib_prefix.is_some_and(|rib_prefix| {
+            rib_prefix
+                .values()
+                .any(|route| route.protocol == msg.protocol)
+        })
+    }
+
     // Adds MPLS route to the RIB.
     pub(crate) async fn mpls_route_add(&mut self, mut msg: LabelInstallMsg) {
         msg.nexthops = self.resolve_nexthops(msg.nexthops);
@@ -303,6 +323,42 @@ impl Rib {
         }
     }
 
+    // Sends the best route of every prefix to the protocol instances.
+    pub(crate) fn redistribute_dump(
+        &self,
+        af: Option<AddressFamily>,
+        ibus_tx: &IbusSender,
+    ) {
+        if af.is_none() || af == Some(AddressFamily::Ipv4) {
+            for (prefix, routes) in self.ipv4.iter() {
+                if let Some(route) = routes
+                    .values()
+                    .find(|route| route.flags.contains(RouteFlags::ACTIVE))
+                {
+                    ibus::notify_redistribute_add(
+                        ibus_tx,
+                        (*prefix).into(),
+                        route,
+                    );
+                }
+            }
+        }
+        if af.is_none() || af == Some(AddressFamily::Ipv6) {
+            for (prefix, routes) in self.ipv6.iter() {
+                if let Some(route) = routes
+                    .values()
+                    .find(|route| route.flags.contains(RouteFlags::ACTIVE))
+                {
+                    ibus::notify_redistribute_add(
+                        ibus_tx,
+                        (*prefix).into(),
+                        route,
+                    );
+                }
+            }
+        }
+    }
+
     // Nexthop tracking registration.
     pub(crate) fn nht_add(&mut self, addr: IpAddr, ibus_tx: &IbusSender) {
         debug!(%addr, "nexthop tracking add");
@@ -339,6 +395,14 @@ impl Rib {
                 .find(|route| route.flags.contains(RouteFlags::ACTIVE))
                 .map(|route| route.protocol);
 
+            // Find the protocol of the installed backup route, if one exists.
+            let old_backup_protocol = rib_prefix
+                .values()
+                .find(|route| {
+                    route.flags.contains(RouteFlags::BACKUP_INSTALLED)
+                })
+                .map(|route| route.protocol);
+
             // Remove routes marked with the REMOVED flag.
             rib_prefix
                 .retain(|_, route| !route.flags.contains(RouteFlags::REMOVED));
@@ -359,11 +423,31 @@ impl Rib {
                         .await;
                     }
 
+                    // Install or uninstall the route's backup nexthops.
+                    if !route.backup_nexthops.is_empty() {
+                        netlink::ip_route_backup_install(
+                            netlink_handle,
+                            &prefix,
+                            route,
+                        )
+                        .await;
+                        route.flags.insert(RouteFlags::BACKUP_INSTALLED);
+                    } else if let Some(protocol) = old_backup_protocol {
+                        netlink::ip_route_backup_uninstall(
+                            netlink_handle,
+                            &prefix,
+                            protocol,
+                        )
+                        .await;
+                        route.flags.remove(RouteFlags::BACKUP_INSTALLED);
+                    }
+
                     // Notify protocol instances about the updated route.
                     ibus::notify_redistribute_add(ibus_tx, prefix, route);
                 } else {
                     // Remove the preferred flag for other routes.
                     route.flags.remove(RouteFlags::ACTIVE);
+                    route.flags.remove(RouteFlags::BACKUP_INSTALLED);
                 }
             }
 
@@ -379,6 +463,14 @@ impl Rib {
                         )
                         .await;
                     }
+                    if let Some(protocol) = old_backup_protocol {
+                        netlink::ip_route_backup_uninstall(
+                            netlink_handle,
+                            &prefix,
+                            protocol,
+                        )
+                        .await;
+                    }
 
                     // Notify protocol instances about the deleted route.
                     ibus::notify_redistribute_del(ibus_tx, prefix, protocol);
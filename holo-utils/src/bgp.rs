@@ -25,6 +25,7 @@ use serde::{Deserialize, Serialize};
 pub enum AfiSafi {
     Ipv4Unicast,
     Ipv6Unicast,
+    BgpLs,
 }
 
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
@@ -80,6 +81,7 @@ impl ToYang for AfiSafi {
         match self {
             AfiSafi::Ipv4Unicast => "iana-bgp-types:ipv4-unicast".into(),
             AfiSafi::Ipv6Unicast => "iana-bgp-types:ipv6-unicast".into(),
+            AfiSafi::BgpLs => "holo-bgp:bgp-ls".into(),
         }
     }
 }
@@ -89,6 +91,7 @@ impl TryFromYang for AfiSafi {
         match value {
             "iana-bgp-types:ipv4-unicast" => Some(AfiSafi::Ipv4Unicast),
             "iana-bgp-types:ipv6-unicast" => Some(AfiSafi::Ipv6Unicast),
+            "holo-bgp:bgp-ls" => Some(AfiSafi::BgpLs),
             _ => None,
         }
     }
@@ -131,6 +134,18 @@ impl ToYang for Comm {
 
 // ===== impl ExtComm =====
 
+impl ExtComm {
+    // Returns the bandwidth, in bytes per second, advertised in the Link
+    // Bandwidth Extended Community (transitive or non-transitive).
+    pub fn link_bandwidth(&self) -> Option<f32> {
+        if !matches!(self.0[0], 0x00 | 0x40) || self.0[1] != 0x04 {
+            return None;
+        }
+        let bandwidth = f32::from_be_bytes(self.0[4..8].try_into().unwrap());
+        Some(bandwidth)
+    }
+}
+
 impl ToYang for ExtComm {
     fn to_yang(&self) -> Cow<'static, str> {
         // TODO: cover other cases instead of always using the raw format.
@@ -10,7 +10,7 @@ use holo_yang::{ToYang, TryFromYang};
 use num_derive::FromPrimitive;
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[derive(Deserialize, Serialize)]
 pub enum CryptoAlgo {
     Md5,
@@ -19,6 +19,7 @@ pub enum CryptoAlgo {
     HmacSha256,
     HmacSha384,
     HmacSha512,
+    Aes128Cmac,
 }
 
 // Cryptographic Protocol ID.
@@ -47,6 +48,7 @@ impl CryptoAlgo {
             CryptoAlgo::HmacSha256 => 32,
             CryptoAlgo::HmacSha384 => 48,
             CryptoAlgo::HmacSha512 => 64,
+            CryptoAlgo::Aes128Cmac => 16,
         }
     }
 }
@@ -60,6 +62,7 @@ impl ToYang for CryptoAlgo {
             CryptoAlgo::HmacSha256 => "ietf-key-chain:hmac-sha-256".into(),
             CryptoAlgo::HmacSha384 => "ietf-key-chain:hmac-sha-384".into(),
             CryptoAlgo::HmacSha512 => "ietf-key-chain:hmac-sha-512".into(),
+            CryptoAlgo::Aes128Cmac => "ietf-tcp:aes-128".into(),
         }
     }
 }
@@ -73,6 +76,7 @@ impl TryFromYang for CryptoAlgo {
             "ietf-key-chain:hmac-sha-256" => Some(CryptoAlgo::HmacSha256),
             "ietf-key-chain:hmac-sha-384" => Some(CryptoAlgo::HmacSha384),
             "ietf-key-chain:hmac-sha-512" => Some(CryptoAlgo::HmacSha512),
+            "ietf-tcp:aes-128" => Some(CryptoAlgo::Aes128Cmac),
             _ => None,
         }
     }
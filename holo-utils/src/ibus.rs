@@ -13,10 +13,13 @@ use tokio::sync::broadcast::{Receiver, Sender};
 use crate::bfd;
 use crate::ip::AddressFamily;
 use crate::keychain::Keychain;
+use crate::link_state::LsTopologyMsg;
 use crate::policy::{MatchSets, Policy};
+use crate::protocol::Protocol;
 use crate::southbound::{
     AddressMsg, InterfaceUpdateMsg, LabelInstallMsg, LabelUninstallMsg,
-    RouteKeyMsg, RouteMsg,
+    PwInstallMsg, PwStatusMsg, PwUninstallMsg, RouteKeyMsg, RouteMsg,
+    Srv6SidInstallMsg, Srv6SidUninstallMsg,
 };
 use crate::sr::SrCfg;
 
@@ -43,6 +46,8 @@ pub enum IbusMsg {
         sess_key: bfd::SessionKey,
         state: bfd::State,
     },
+    // BGP initial convergence notification.
+    BgpInitialConvergence,
     // Request to dump information about all interfaces.
     InterfaceDump,
     // Query information about a specific interface.
@@ -62,6 +67,24 @@ pub enum IbusMsg {
     KeychainUpd(Arc<Keychain>),
     // Keychain delete notification.
     KeychainDel(String),
+    // LDP-IGP synchronization registration.
+    LdpSyncReg(String),
+    // LDP-IGP synchronization unregistration.
+    LdpSyncUnreg(String),
+    // LDP-IGP synchronization state update.
+    LdpSyncUpd {
+        ifname: String,
+        state: LdpSyncState,
+    },
+    // Request to dump the link-state topologies of all IGP instances.
+    LinkStateDump,
+    // Link-state topology update notification.
+    LinkStateUpd(Arc<LsTopologyMsg>),
+    // Link-state topology delete notification.
+    LinkStateDel {
+        protocol: Protocol,
+        instance: String,
+    },
     // Nexthop tracking registration.
     NexthopTrack(IpAddr),
     // Nexthop tracking unregistration.
@@ -85,10 +108,27 @@ pub enum IbusMsg {
     RouteIpAdd(RouteMsg),
     // Request to uninstall IP route from the RIB.
     RouteIpDel(RouteKeyMsg),
+    // Request to uninstall a stale IP route preserved across a graceful
+    // restart.
+    RouteIpStaleDel(RouteKeyMsg),
     // Request to install MPLS route in the LIB.
     RouteMplsAdd(LabelInstallMsg),
     // Request to uninstall MPLS route from the LIB.
     RouteMplsDel(LabelUninstallMsg),
+    // Request to install pseudowire in the kernel.
+    RoutePwAdd(PwInstallMsg),
+    // Request to uninstall pseudowire from the kernel.
+    RoutePwDel(PwUninstallMsg),
+    // Pseudowire installation status notification.
+    RoutePwStatus(PwStatusMsg),
+    // Request to install SRv6 local SID in the kernel.
+    RouteSrv6SidAdd(Srv6SidInstallMsg),
+    // Request to uninstall SRv6 local SID from the kernel.
+    RouteSrv6SidDel(Srv6SidUninstallMsg),
+    // Request to dump all redistributable routes.
+    RouteRedistributeDump {
+        af: Option<AddressFamily>,
+    },
     // Route redistribute update notification.
     RouteRedistributeAdd(RouteMsg),
     // Route redistribute delete notification.
@@ -99,9 +139,22 @@ pub enum IbusMsg {
     SrCfgEvent(SrCfgEvent),
 }
 
+// LDP-IGP synchronization state of an interface (RFC 5443).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub enum LdpSyncState {
+    // LDP isn't operational on the interface.
+    Disabled,
+    // LDP session is down or the initial label exchange is ongoing.
+    NotSynced,
+    // LDP session is up and the initial label exchange is complete.
+    Synced,
+}
+
 // Type of Segment Routing configuration change.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum SrCfgEvent {
     LabelRangeUpdate,
     PrefixSidUpdate(AddressFamily),
+    Srv6LocatorUpdate,
 }
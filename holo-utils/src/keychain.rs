@@ -90,6 +90,20 @@ impl Keychain {
             .filter(|key| key.accept_lifetime.is_active())
             .map(|key| &key.data)
     }
+
+    // Returns the closest point in the future at which the send or accept
+    // lifetime of any key starts or ends.
+    pub fn next_lifetime_boundary(&self) -> Option<DateTime<Utc>> {
+        let now = Utc::now();
+        self.keys
+            .values()
+            .flat_map(|key| [&key.send_lifetime, &key.accept_lifetime])
+            .flat_map(|lifetime| [lifetime.start, lifetime.end])
+            .flatten()
+            .map(|time| time.with_timezone(&Utc))
+            .filter(|time| *time > now)
+            .min()
+    }
 }
 
 // ===== impl KeyLifetime =====
@@ -22,6 +22,7 @@ pub mod crypto;
 pub mod ibus;
 pub mod ip;
 pub mod keychain;
+pub mod link_state;
 pub mod mpls;
 pub mod policy;
 pub mod protocol;
@@ -0,0 +1,140 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+//! This file contains the IGP topology definitions exchanged between the IGP
+//! protocols and BGP for the purpose of BGP Link-State (BGP-LS) distribution.
+
+use std::collections::BTreeSet;
+use std::net::{IpAddr, Ipv4Addr};
+
+use ipnetwork::IpNetwork;
+use serde::{Deserialize, Serialize};
+
+use crate::protocol::Protocol;
+use crate::sr::{IgpAlgoType, Sid};
+
+// IGP topology snapshot of a single protocol instance.
+//
+// The topology elements are stored as lists of key-value pairs, sorted by key,
+// so that the whole topology can be serialized.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsTopology {
+    pub nodes: Vec<(LsNodeKey, LsNode)>,
+    pub links: Vec<(LsLinkKey, LsLink)>,
+    pub prefixes: Vec<(LsPrefixKey, LsPrefix)>,
+}
+
+// Node descriptors.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsNodeKey {
+    pub area_id: Ipv4Addr,
+    pub router_id: Ipv4Addr,
+    // Pseudonode identifier (DR interface address or interface ID).
+    pub pseudonode: Option<u32>,
+}
+
+// Node attributes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsNode {
+    pub abr: bool,
+    pub asbr: bool,
+    pub hostname: Option<String>,
+    pub sr_algos: BTreeSet<IgpAlgoType>,
+    pub srgb: Vec<LsSidLabelRange>,
+}
+
+// SR Capabilities label range.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsSidLabelRange {
+    pub first: Sid,
+    pub range: u32,
+}
+
+// Link descriptors.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsLinkKey {
+    pub local: LsNodeKey,
+    pub remote: LsNodeKey,
+    pub descriptors: LsLinkDescriptors,
+}
+
+// Link-specific descriptors.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsLinkDescriptors {
+    pub local_id: Option<u32>,
+    pub remote_id: Option<u32>,
+    pub local_addr: Option<IpAddr>,
+    pub remote_addr: Option<IpAddr>,
+}
+
+// Link attributes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsLink {
+    pub metric: u32,
+    pub adj_sids: Vec<LsAdjSid>,
+}
+
+// Adjacency SID.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsAdjSid {
+    pub flags: u8,
+    pub weight: u8,
+    pub sid: Sid,
+}
+
+// Prefix descriptors.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub struct LsPrefixKey {
+    pub node: LsNodeKey,
+    pub prefix: IpNetwork,
+    pub route_type: LsPrefixRouteType,
+}
+
+// OSPF route type of a prefix.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Deserialize, Serialize)]
+pub enum LsPrefixRouteType {
+    IntraArea,
+    InterArea,
+    External1,
+    External2,
+    Nssa1,
+    Nssa2,
+}
+
+// Prefix attributes.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsPrefix {
+    pub metric: u32,
+    pub prefix_sids: Vec<LsPrefixSid>,
+}
+
+// Prefix SID.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct LsPrefixSid {
+    pub algo: IgpAlgoType,
+    pub flags: u8,
+    pub sid: Sid,
+}
+
+// Link-state topology update sent by an IGP instance.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LsTopologyMsg {
+    pub protocol: Protocol,
+    pub instance: String,
+    pub topology: LsTopology,
+}
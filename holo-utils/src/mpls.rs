@@ -39,6 +39,7 @@ pub enum LabelManagerError {
     LabelRangeInvalid,
     LabelRangeUnavailable,
     LabelSpaceExhausted,
+    LabelUnavailable,
 }
 
 // ===== impl Label =====
@@ -180,6 +181,31 @@ impl LabelManager {
         Ok(label)
     }
 
+    // Allocates a specific dynamic label.
+    //
+    // Used to reclaim labels that were preserved across a restart. Since
+    // dynamic labels are assigned sequentially, specific labels must be
+    // requested in ascending order, and any label lower than or equal to the
+    // last allocated one is considered unavailable.
+    pub fn label_request_specific(
+        &mut self,
+        label: Label,
+    ) -> Result<Label, LabelManagerError> {
+        if !Label::UNRESERVED_RANGE.contains(&label.get())
+            || label.get() <= self.next_dynamic
+            || self.reserved_ranges.iter().any(|range| {
+                (range.lower_bound..=range.upper_bound).contains(&label.get())
+            })
+        {
+            return Err(LabelManagerError::LabelUnavailable);
+        }
+
+        // Allocate label.
+        self.next_dynamic = label.get();
+
+        Ok(label)
+    }
+
     // Releases a dynamic label.
     pub fn label_release(&mut self, _label: Label) {}
 }
@@ -207,6 +233,9 @@ impl std::fmt::Display for LabelManagerError {
             LabelManagerError::LabelSpaceExhausted => {
                 write!(f, "Label space has been exhausted")
             }
+            LabelManagerError::LabelUnavailable => {
+                write!(f, "Label is unavailable")
+            }
         }
     }
 }
@@ -46,7 +46,7 @@ pub enum PolicyResult<T> {
 }
 
 // Default policy type.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 #[derive(Deserialize, Serialize)]
 pub enum DefaultPolicyType {
     // TODO should be RejectRoute
@@ -138,6 +138,7 @@ pub enum MatchSetRestrictedType {
 #[derive(Clone, Debug, Default)]
 #[derive(Deserialize, Serialize)]
 pub struct MatchSets {
+    #[serde(with = "vectorize")]
     pub prefixes: BTreeMap<(String, AddressFamily), PrefixSet>,
     pub neighbors: BTreeMap<String, NeighborSet>,
     pub tags: BTreeMap<String, TagSet>,
@@ -4,9 +4,10 @@
 // SPDX-License-Identifier: MIT
 //
 
-use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 use std::os::raw::{c_int, c_void};
 use std::os::unix::io::AsRawFd;
+use std::sync::Arc;
 
 use libc::ip_mreqn;
 use serde::{Deserialize, Serialize};
@@ -31,6 +32,36 @@ pub struct TcpConnInfo {
     pub remote_port: u16,
 }
 
+// TCP authentication method.
+#[derive(Clone, Debug)]
+pub enum TcpAuth {
+    // TCP MD5 Signature Option (RFC 2385).
+    Md5(String),
+    // TCP Authentication Option (RFC 5925).
+    Ao(Arc<Keychain>),
+}
+
+// ===== impl TcpAuth =====
+
+impl TcpAuth {
+    // Returns whether the authentication method can protect a connection.
+    //
+    // TCP-AO requires a key whose send lifetime is active, and a key whose
+    // accept lifetime is active.
+    pub fn is_usable(&self) -> bool {
+        match self {
+            TcpAuth::Md5(_) => true,
+            TcpAuth::Ao(keychain) => {
+                keychain.key_lookup_send().is_some()
+                    && keychain
+                        .keys
+                        .values()
+                        .any(|key| key.accept_lifetime.is_active())
+            }
+        }
+    }
+}
+
 // FFI struct used to set the TCP_MD5SIG socket option.
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -43,7 +74,95 @@ pub struct tcp_md5sig {
     pub tcpm_key: [u8; 108],
 }
 
+// TCP-AO socket options (RFC 5925).
+//
+// These aren't exported by the libc crate yet.
+pub const TCP_AO_ADD_KEY: c_int = 38;
+pub const TCP_AO_DEL_KEY: c_int = 39;
+pub const TCP_AO_INFO: c_int = 40;
+pub const TCP_AO_GET_KEYS: c_int = 41;
+pub const TCP_AO_MAXKEYLEN: usize = 80;
+
+// TCP-AO flags (bitfields in the kernel structures).
+const TCP_AO_SET_CURRENT: u32 = 1 << 0;
+const TCP_AO_SET_RNEXT: u32 = 1 << 1;
+const TCP_AO_GET_ALL: u16 = 1 << 2;
+
+// Length of the TCP-AO MACs, as specified by RFC 5926.
+const TCP_AO_MACLEN: u8 = 12;
+
+// FFI struct used to set the TCP_AO_ADD_KEY socket option.
+#[repr(C, align(8))]
+#[derive(Copy, Clone)]
+pub struct tcp_ao_add {
+    pub addr: nix::sys::socket::SockaddrStorage,
+    pub alg_name: [u8; 64],
+    pub ifindex: i32,
+    pub flags: u32,
+    pub reserved2: u16,
+    pub prefix: u8,
+    pub sndid: u8,
+    pub rcvid: u8,
+    pub maclen: u8,
+    pub keyflags: u8,
+    pub keylen: u8,
+    pub key: [u8; TCP_AO_MAXKEYLEN],
+}
+
+// FFI struct used to set the TCP_AO_DEL_KEY socket option.
+#[repr(C, align(8))]
+#[derive(Copy, Clone)]
+pub struct tcp_ao_del {
+    pub addr: nix::sys::socket::SockaddrStorage,
+    pub ifindex: i32,
+    pub flags: u32,
+    pub reserved2: u16,
+    pub prefix: u8,
+    pub sndid: u8,
+    pub rcvid: u8,
+    pub current_key: u8,
+    pub rnext: u8,
+    pub keyflags: u8,
+}
+
+// FFI struct used to set the TCP_AO_INFO socket option.
+#[repr(C, align(8))]
+#[derive(Copy, Clone)]
+pub struct tcp_ao_info_opt {
+    pub flags: u32,
+    pub reserved2: u16,
+    pub current_key: u8,
+    pub rnext: u8,
+    pub pkt_good: u64,
+    pub pkt_bad: u64,
+    pub pkt_key_not_found: u64,
+    pub pkt_ao_required: u64,
+    pub pkt_dropped_icmp: u64,
+}
+
+// FFI struct used to get the TCP_AO_GET_KEYS socket option.
+#[repr(C, align(8))]
+#[derive(Copy, Clone)]
+pub struct tcp_ao_getsockopt {
+    pub addr: nix::sys::socket::SockaddrStorage,
+    pub alg_name: [u8; 64],
+    pub key: [u8; TCP_AO_MAXKEYLEN],
+    pub nkeys: u32,
+    pub flags: u16,
+    pub sndid: u8,
+    pub rcvid: u8,
+    pub prefix: u8,
+    pub maclen: u8,
+    pub keyflags: u8,
+    pub keylen: u8,
+    pub ifindex: i32,
+    pub pkt_good: u64,
+    pub pkt_bad: u64,
+}
+
+use crate::crypto::CryptoAlgo;
 use crate::ip::{AddressFamily, IpAddrKind};
+use crate::keychain::{Key, Keychain};
 // Test build: export mock sockets.
 #[cfg(feature = "testing")]
 pub use crate::socket::mock::{
@@ -311,6 +430,224 @@ pub trait TcpSocketExt: SocketExt {
         )
     }
 
+    // Adds a TCP-AO key for the given peer address.
+    //
+    // The key ID is used as both the SendID and the RecvID of the TCP-AO
+    // Master Key Tuple.
+    fn tcp_ao_add_key(&self, dst: &IpAddr, key: &Key) -> Result<()> {
+        let key_id = tcp_ao_key_id(key)?;
+        let alg_name = tcp_ao_alg_name(key.algo)?;
+        if key.string.len() > TCP_AO_MAXKEYLEN {
+            return Err(std::io::Error::from_raw_os_error(libc::EINVAL));
+        }
+
+        let mut optval = tcp_ao_add {
+            addr: SocketAddr::from((*dst, 0)).into(),
+            alg_name: [0; 64],
+            ifindex: 0,
+            flags: 0,
+            reserved2: 0,
+            prefix: tcp_ao_prefix(dst),
+            sndid: key_id,
+            rcvid: key_id,
+            maclen: TCP_AO_MACLEN,
+            keyflags: 0,
+            keylen: key.string.len() as u8,
+            key: [0; TCP_AO_MAXKEYLEN],
+        };
+        optval.alg_name[..alg_name.len()].copy_from_slice(alg_name.as_bytes());
+        optval.key[..key.string.len()].copy_from_slice(&key.string);
+
+        setsockopt(
+            self,
+            libc::IPPROTO_TCP,
+            TCP_AO_ADD_KEY,
+            &optval as *const _ as *const libc::c_void,
+            std::mem::size_of::<tcp_ao_add>() as libc::socklen_t,
+        )
+    }
+
+    // Removes the TCP-AO key identified by the given SendID and RecvID.
+    fn tcp_ao_del_key(&self, dst: &IpAddr, sndid: u8, rcvid: u8) -> Result<()> {
+        let optval = tcp_ao_del {
+            addr: SocketAddr::from((*dst, 0)).into(),
+            ifindex: 0,
+            flags: 0,
+            reserved2: 0,
+            prefix: tcp_ao_prefix(dst),
+            sndid,
+            rcvid,
+            current_key: 0,
+            rnext: 0,
+            keyflags: 0,
+        };
+
+        setsockopt(
+            self,
+            libc::IPPROTO_TCP,
+            TCP_AO_DEL_KEY,
+            &optval as *const _ as *const libc::c_void,
+            std::mem::size_of::<tcp_ao_del>() as libc::socklen_t,
+        )
+    }
+
+    // Selects the current and RNext TCP-AO keys of the connection.
+    fn tcp_ao_set_current_key(&self, key_id: u8) -> Result<()> {
+        let optval = tcp_ao_info_opt {
+            flags: TCP_AO_SET_CURRENT | TCP_AO_SET_RNEXT,
+            reserved2: 0,
+            current_key: key_id,
+            rnext: key_id,
+            pkt_good: 0,
+            pkt_bad: 0,
+            pkt_key_not_found: 0,
+            pkt_ao_required: 0,
+            pkt_dropped_icmp: 0,
+        };
+
+        setsockopt(
+            self,
+            libc::IPPROTO_TCP,
+            TCP_AO_INFO,
+            &optval as *const _ as *const libc::c_void,
+            std::mem::size_of::<tcp_ao_info_opt>() as libc::socklen_t,
+        )
+    }
+
+    // Returns the SendID and RecvID of all TCP-AO keys configured for the
+    // given peer address.
+    fn tcp_ao_get_keys(&self, dst: &IpAddr) -> Result<Vec<(u8, u8)>> {
+        const MAX_KEYS: usize = 256;
+
+        // SAFETY: all fields of the structure are plain integers or byte
+        // arrays, for which the all-zeros bit pattern is valid.
+        let mut optval: Vec<tcp_ao_getsockopt> =
+            vec![unsafe { std::mem::zeroed() }; MAX_KEYS];
+        optval[0].nkeys = MAX_KEYS as u32;
+        optval[0].flags = TCP_AO_GET_ALL;
+        let mut optlen =
+            std::mem::size_of::<tcp_ao_getsockopt>() as libc::socklen_t;
+
+        let ret = unsafe {
+            libc::getsockopt(
+                self.as_raw_fd(),
+                libc::IPPROTO_TCP,
+                TCP_AO_GET_KEYS,
+                optval.as_mut_ptr() as *mut libc::c_void,
+                &mut optlen,
+            )
+        };
+        if ret == -1 {
+            let error = std::io::Error::last_os_error();
+            // The socket doesn't have any TCP-AO key, or the kernel doesn't
+            // support TCP-AO at all.
+            if matches!(
+                error.raw_os_error(),
+                Some(libc::ENOENT | libc::ENOPROTOOPT)
+            ) {
+                return Ok(vec![]);
+            }
+            return Err(error);
+        }
+
+        let nkeys = std::cmp::min(optval[0].nkeys as usize, MAX_KEYS);
+        let keys = optval[..nkeys]
+            .iter()
+            .filter(|key| {
+                let addr = if let Some(addr) = key.addr.as_sockaddr_in() {
+                    IpAddr::from(*SocketAddrV4::from(*addr).ip())
+                } else if let Some(addr) = key.addr.as_sockaddr_in6() {
+                    IpAddr::from(*SocketAddrV6::from(*addr).ip())
+                } else {
+                    return false;
+                };
+                addr == *dst
+            })
+            .map(|key| (key.sndid, key.rcvid))
+            .collect();
+        Ok(keys)
+    }
+
+    // Sets the TCP-AO keys for the given peer address from the provided
+    // key-chain, replacing any previously configured keys.
+    //
+    // All keys whose accept lifetime is active are installed so that the
+    // peer can switch to any of them (hitless key rollover). The key whose
+    // send lifetime is active is selected as the current key, except in
+    // listening sockets, whose accepted connections select it themselves.
+    // Passing `None` removes all TCP-AO keys of the peer.
+    //
+    // The keys are updated incrementally, so this can be called again on
+    // established connections whenever the key lifetimes change.
+    fn set_tcp_ao_keychain(
+        &self,
+        dst: &IpAddr,
+        keychain: Option<&Keychain>,
+    ) -> Result<()> {
+        let old_keys = self.tcp_ao_get_keys(dst)?;
+        let new_keys = keychain
+            .into_iter()
+            .flat_map(|keychain| keychain.keys.values())
+            .filter(|key| key.accept_lifetime.is_active())
+            .map(|key| &key.data)
+            .collect::<Vec<_>>();
+
+        // Install new keys.
+        let mut new_key_ids = vec![];
+        for key in new_keys {
+            let key_id = tcp_ao_key_id(key)?;
+            if !old_keys.contains(&(key_id, key_id)) {
+                self.tcp_ao_add_key(dst, key)?;
+            }
+            new_key_ids.push((key_id, key_id));
+        }
+
+        // Select the current key.
+        if let Some(key) = keychain.and_then(|keychain| keychain.key_lookup_send())
+        {
+            let key_id = tcp_ao_key_id(key)?;
+            if new_key_ids.contains(&(key_id, key_id)) && !is_listening(self)? {
+                self.tcp_ao_set_current_key(key_id)?;
+            }
+        }
+
+        // Remove old keys. The kernel refuses to remove the keys in use by an
+        // established connection, which are removed on a later update, once
+        // the peer has switched to the new keys.
+        for (sndid, rcvid) in old_keys
+            .into_iter()
+            .filter(|key_ids| !new_key_ids.contains(key_ids))
+        {
+            match self.tcp_ao_del_key(dst, sndid, rcvid) {
+                Err(error) if error.raw_os_error() == Some(libc::EBUSY) => (),
+                result => result?,
+            }
+        }
+
+        Ok(())
+    }
+
+    // Sets the TCP authentication method used for the given peer address.
+    //
+    // TCP-MD5 and TCP-AO can't be used simultaneously for the same peer, so
+    // the keys of the other method are always removed first.
+    fn set_tcp_auth(&self, dst: &IpAddr, auth: Option<&TcpAuth>) -> Result<()> {
+        match auth {
+            Some(TcpAuth::Md5(password)) => {
+                self.set_tcp_ao_keychain(dst, None)?;
+                self.set_md5sig(dst, Some(password))
+            }
+            Some(TcpAuth::Ao(keychain)) => {
+                md5sig_unset(self, dst)?;
+                self.set_tcp_ao_keychain(dst, Some(keychain))
+            }
+            None => {
+                md5sig_unset(self, dst)?;
+                self.set_tcp_ao_keychain(dst, None)
+            }
+        }
+    }
+
     // Sets the value of the TCP_MAXSEG option on this socket.
     fn set_mss(&self, mss: u32) -> Result<()> {
         let optval = mss as c_int;
@@ -329,6 +666,10 @@ pub trait TcpSocketExt: SocketExt {
 pub trait TcpStreamExt: TcpSocketExt {
     // Returns address and port information about the TCP connection.
     fn conn_info(&self) -> Result<TcpConnInfo>;
+
+    // Returns a new handle to the underlying socket, which can be used to
+    // update the socket options after the stream is split into two halves.
+    fn try_clone_socket(&self) -> Result<Socket>;
 }
 
 // Extension methods for Socket.
@@ -407,6 +748,10 @@ impl TcpStreamExt for TcpStream {
             remote_port: remote_addr.port(),
         })
     }
+
+    fn try_clone_socket(&self) -> Result<Socket> {
+        socket2::SockRef::from(self).try_clone()
+    }
 }
 
 // ===== impl TcpListener =====
@@ -422,6 +767,9 @@ impl TcpSocketExt for TcpListener {}
 #[cfg(not(feature = "testing"))]
 impl SocketExt for Socket {}
 
+#[cfg(not(feature = "testing"))]
+impl TcpSocketExt for Socket {}
+
 #[cfg(not(feature = "testing"))]
 impl RawSocketExt for Socket {
     fn set_ipv4_pktinfo(&self, value: bool) -> Result<()> {
@@ -507,6 +855,61 @@ pub mod mock {
 
 // ===== global functions =====
 
+// Removes the TCP MD5 password of the given peer address, if any.
+fn md5sig_unset<S: TcpSocketExt>(socket: &S, dst: &IpAddr) -> Result<()> {
+    match socket.set_md5sig(dst, None) {
+        Err(error) if error.raw_os_error() == Some(libc::ENOENT) => Ok(()),
+        result => result,
+    }
+}
+
+// Returns whether the given socket is listening for incoming connections.
+fn is_listening<S: AsRawFd>(socket: &S) -> Result<bool> {
+    let mut optval: c_int = 0;
+    let mut optlen = std::mem::size_of::<c_int>() as libc::socklen_t;
+
+    let ret = unsafe {
+        libc::getsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_ACCEPTCONN,
+            &mut optval as *mut _ as *mut libc::c_void,
+            &mut optlen,
+        )
+    };
+    if ret == -1 {
+        return Err(std::io::Error::last_os_error());
+    }
+
+    Ok(optval != 0)
+}
+
+// Returns the TCP-AO key ID (SendID/RecvID) corresponding to the given key.
+fn tcp_ao_key_id(key: &Key) -> Result<u8> {
+    u8::try_from(key.id)
+        .map_err(|_| std::io::Error::from_raw_os_error(libc::EINVAL))
+}
+
+// Returns the name of the kernel crypto algorithm used for TCP-AO.
+fn tcp_ao_alg_name(algo: CryptoAlgo) -> Result<&'static str> {
+    match algo {
+        CryptoAlgo::HmacSha1 => Ok("hmac(sha1)"),
+        CryptoAlgo::HmacSha256 => Ok("hmac(sha256)"),
+        CryptoAlgo::HmacSha384 => Ok("hmac(sha384)"),
+        CryptoAlgo::HmacSha512 => Ok("hmac(sha512)"),
+        CryptoAlgo::Aes128Cmac => Ok("cmac(aes128)"),
+        _ => Err(std::io::Error::from_raw_os_error(libc::EINVAL)),
+    }
+}
+
+// Returns the prefix length used to match a single peer address.
+fn tcp_ao_prefix(dst: &IpAddr) -> u8 {
+    match dst {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
 fn setsockopt<F: AsRawFd>(
     sock: &F,
     opt: c_int,
@@ -6,7 +6,7 @@
 
 use std::borrow::Cow;
 use std::collections::BTreeSet;
-use std::net::IpAddr;
+use std::net::{IpAddr, Ipv6Addr};
 
 use bitflags::bitflags;
 use holo_yang::{ToYang, TryFromYang};
@@ -15,6 +15,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::mpls::Label;
 use crate::protocol::Protocol;
+use crate::sr::Srv6EndpointBehavior;
 
 bitflags! {
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -42,15 +43,24 @@ pub enum Nexthop {
         ifindex: u32,
         addr: IpAddr,
         labels: Vec<Label>,
+        // Relative weight used for unequal-cost load balancing.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weight: Option<u32>,
     },
     Interface {
         ifindex: u32,
+        // Relative weight used for unequal-cost load balancing.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weight: Option<u32>,
     },
     Special(NexthopSpecial),
     Recursive {
         addr: IpAddr,
         labels: Vec<Label>,
         resolved: BTreeSet<Nexthop>,
+        // Relative weight used for unequal-cost load balancing.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        weight: Option<u32>,
     },
 }
 
@@ -89,9 +99,12 @@ pub struct RouteMsg {
     pub distance: u32,
     pub metric: u32,
     pub tag: Option<u32>,
-    #[serde(skip)]
+    #[serde(default, skip_serializing)]
     pub opaque_attrs: RouteOpaqueAttrs,
     pub nexthops: BTreeSet<Nexthop>,
+    // Backup nexthops used when all primary nexthops become unavailable.
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub backup_nexthops: BTreeSet<Nexthop>,
 }
 
 #[derive(Clone, Debug)]
@@ -120,6 +133,57 @@ pub struct LabelUninstallMsg {
     pub route: Option<(Protocol, IpNetwork)>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct PwInstallMsg {
+    pub protocol: Protocol,
+    pub name: String,
+    // Attachment circuit interface.
+    pub ifindex: u32,
+    pub local_label: Label,
+    pub remote_label: Label,
+    pub control_word: bool,
+    // Nexthops towards the remote PE, including their transport labels.
+    pub nexthops: BTreeSet<Nexthop>,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Deserialize, Serialize)]
+pub struct PwUninstallMsg {
+    pub protocol: Protocol,
+    pub name: String,
+    pub ifindex: u32,
+    pub local_label: Label,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Deserialize, Serialize)]
+pub struct PwStatusMsg {
+    pub protocol: Protocol,
+    pub name: String,
+    pub local_label: Label,
+    // Whether the pseudowire was successfully installed in the kernel.
+    pub installed: bool,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6SidInstallMsg {
+    pub protocol: Protocol,
+    pub sid: Ipv6Addr,
+    pub behavior: Srv6EndpointBehavior,
+    // Outgoing interface and nexthop address (End.X only).
+    pub ifindex: Option<u32>,
+    pub nexthop: Option<Ipv6Addr>,
+}
+
+#[derive(Clone, Debug)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6SidUninstallMsg {
+    pub protocol: Protocol,
+    pub sid: Ipv6Addr,
+}
+
 // Route opaque attributes.
 #[derive(Clone, Debug, Default)]
 #[derive(Deserialize, Serialize)]
@@ -128,6 +192,7 @@ pub enum RouteOpaqueAttrs {
     None,
     Ospf {
         route_type: OspfRouteType,
+        node_tags: BTreeSet<u32>,
     },
 }
 
@@ -138,7 +203,9 @@ pub enum OspfRouteType {
     IntraArea,
     InterArea,
     Type1External,
+    Type1Nssa,
     Type2External,
+    Type2Nssa,
 }
 
 // ===== impl Nexthop =====
@@ -166,8 +233,12 @@ impl Nexthop {
                 },
             ) => ifindex1 == ifindex2 && addr1 == addr2,
             (
-                Nexthop::Interface { ifindex: ifindex1 },
-                Nexthop::Interface { ifindex: ifindex2 },
+                Nexthop::Interface {
+                    ifindex: ifindex1, ..
+                },
+                Nexthop::Interface {
+                    ifindex: ifindex2, ..
+                },
             ) => ifindex1 == ifindex2,
             (Nexthop::Special(nexthop1), Nexthop::Special(nexthop2)) => {
                 nexthop1 == nexthop2
@@ -231,6 +302,26 @@ impl ToYang for OspfRouteType {
             OspfRouteType::InterArea => "inter-area".into(),
             OspfRouteType::Type1External => "external-1".into(),
             OspfRouteType::Type2External => "external-2".into(),
+            OspfRouteType::Type1Nssa => "nssa-1".into(),
+            OspfRouteType::Type2Nssa => "nssa-2".into(),
         }
     }
 }
+
+// ===== global functions =====
+
+// Scales the given nexthop weights to the 1-256 range supported by the kernel,
+// relative to the highest weight. Nexthops without weight are assigned the
+// lowest one.
+pub fn nexthop_weights_scale(weights: &[Option<u32>]) -> Vec<u16> {
+    let max_weight =
+        weights.iter().flatten().max().copied().unwrap_or(1).max(1);
+
+    weights
+        .iter()
+        .map(|weight| {
+            let weight = weight.unwrap_or(0) as u64;
+            (weight * 256).div_ceil(max_weight as u64).clamp(1, 256) as u16
+        })
+        .collect()
+}
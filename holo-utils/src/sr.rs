@@ -5,13 +5,15 @@
 //
 
 use std::borrow::Cow;
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::Ipv6Addr;
 
 use derive_new::new;
 use enum_as_inner::EnumAsInner;
 use holo_yang::{ToYang, TryFromYang};
-use ipnetwork::IpNetwork;
+use ipnetwork::{IpNetwork, Ipv6Network};
 use num_derive::{FromPrimitive, ToPrimitive};
+use num_traits::{FromPrimitive, ToPrimitive};
 use serde::{Deserialize, Serialize};
 
 use crate::mpls::{Label, LabelRange};
@@ -21,11 +23,12 @@ use crate::mpls::{Label, LabelRange};
 // IANA registry:
 // https://www.iana.org/assignments/igp-parameters/igp-parameters.xhtml#igp-algorithm-types
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-#[derive(FromPrimitive, ToPrimitive)]
 #[derive(Deserialize, Serialize)]
 pub enum IgpAlgoType {
-    Spf = 0,
-    StrictSpf = 1,
+    Spf,
+    StrictSpf,
+    // Flexible Algorithm (RFC 9350), in the 128-255 range.
+    FlexAlgo(u8),
 }
 
 // IGP MSD-Types.
@@ -55,6 +58,28 @@ pub enum SidLastHopBehavior {
     Php,
 }
 
+// SRv6 Endpoint Behaviors.
+//
+// IANA registry:
+// https://www.iana.org/assignments/segment-routing/segment-routing.xhtml#srv6-endpoint-behaviors
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+#[derive(FromPrimitive, ToPrimitive)]
+#[derive(Deserialize, Serialize)]
+pub enum Srv6EndpointBehavior {
+    End = 1,
+    EndX = 5,
+}
+
+// SRv6 SID Structure.
+#[derive(Clone, Copy, Debug, Eq, new, PartialEq)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6SidStructure {
+    pub lb_len: u8,
+    pub ln_len: u8,
+    pub fun_len: u8,
+    pub arg_len: u8,
+}
+
 // Segment Routing global configuration.
 #[derive(Clone, Debug, Default)]
 #[derive(Deserialize, Serialize)]
@@ -63,6 +88,8 @@ pub struct SrCfg {
     pub prefix_sids: HashMap<(IpNetwork, IgpAlgoType), SrCfgPrefixSid>,
     pub srgb: BTreeSet<LabelRange>,
     pub srlb: BTreeSet<LabelRange>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub srv6_locators: BTreeMap<String, Srv6LocatorCfg>,
 }
 
 // Prefix-SID configuration.
@@ -73,8 +100,25 @@ pub struct SrCfgPrefixSid {
     pub last_hop: SidLastHopBehavior,
 }
 
+// SRv6 locator configuration.
+#[derive(Clone, Debug)]
+#[derive(Deserialize, Serialize)]
+pub struct Srv6LocatorCfg {
+    pub prefix: Ipv6Network,
+    pub algo: IgpAlgoType,
+    pub block_len: u8,
+    pub node_len: u8,
+    pub func_len: u8,
+    pub arg_len: u8,
+}
+
 // ===== impl IgpAlgoType =====
 
+impl IgpAlgoType {
+    pub const FLEX_ALGO_MIN: u8 = 128;
+    pub const FLEX_ALGO_MAX: u8 = 255;
+}
+
 impl ToYang for IgpAlgoType {
     fn to_yang(&self) -> Cow<'static, str> {
         match self {
@@ -86,6 +130,9 @@ impl ToYang for IgpAlgoType {
                 "ietf-segment-routing-common:prefix-sid-algorithm-strict-spf"
                     .into()
             }
+            IgpAlgoType::FlexAlgo(_) => {
+                "holo-ospf:prefix-sid-algorithm-flex-algo".into()
+            }
         }
     }
 }
@@ -104,6 +151,45 @@ impl TryFromYang for IgpAlgoType {
     }
 }
 
+impl FromPrimitive for IgpAlgoType {
+    fn from_i64(value: i64) -> Option<IgpAlgoType> {
+        u8::try_from(value).ok().and_then(IgpAlgoType::from_u8)
+    }
+
+    fn from_u64(value: u64) -> Option<IgpAlgoType> {
+        u8::try_from(value).ok().and_then(IgpAlgoType::from_u8)
+    }
+
+    fn from_u8(value: u8) -> Option<IgpAlgoType> {
+        match value {
+            0 => Some(IgpAlgoType::Spf),
+            1 => Some(IgpAlgoType::StrictSpf),
+            IgpAlgoType::FLEX_ALGO_MIN..=IgpAlgoType::FLEX_ALGO_MAX => {
+                Some(IgpAlgoType::FlexAlgo(value))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl ToPrimitive for IgpAlgoType {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u8().map(i64::from)
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u8().map(u64::from)
+    }
+
+    fn to_u8(&self) -> Option<u8> {
+        match self {
+            IgpAlgoType::Spf => Some(0),
+            IgpAlgoType::StrictSpf => Some(1),
+            IgpAlgoType::FlexAlgo(algo) => Some(*algo),
+        }
+    }
+}
+
 // ===== impl Sid =====
 
 impl Sid {
@@ -115,6 +201,46 @@ impl Sid {
     }
 }
 
+// ===== impl Srv6EndpointBehavior =====
+
+impl ToYang for Srv6EndpointBehavior {
+    fn to_yang(&self) -> Cow<'static, str> {
+        match self {
+            Srv6EndpointBehavior::End => "end".into(),
+            Srv6EndpointBehavior::EndX => "end-x".into(),
+        }
+    }
+}
+
+// ===== impl Srv6LocatorCfg =====
+
+impl Srv6LocatorCfg {
+    // Returns the structure of the SIDs allocated from this locator.
+    pub fn sid_structure(&self) -> Srv6SidStructure {
+        Srv6SidStructure::new(
+            self.block_len,
+            self.node_len,
+            self.func_len,
+            self.arg_len,
+        )
+    }
+
+    // Returns the SID corresponding to the given function value, or `None` if
+    // the function doesn't fit in the configured function length.
+    pub fn sid(&self, function: u32) -> Option<Ipv6Addr> {
+        let func_len = u32::from(self.func_len);
+        if func_len == 0 || (func_len < 32 && function >> func_len != 0) {
+            return None;
+        }
+        let shift = 128_u32
+            .checked_sub(u32::from(self.prefix.prefix()))?
+            .checked_sub(func_len)?;
+        let sid = u128::from(self.prefix.network())
+            | u128::from(function).checked_shl(shift)?;
+        Some(Ipv6Addr::from(sid))
+    }
+}
+
 // ===== impl SidLastHopBehavior =====
 
 impl TryFromYang for SidLastHopBehavior {
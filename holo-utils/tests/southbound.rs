@@ -0,0 +1,45 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+use holo_utils::southbound::nexthop_weights_scale;
+
+#[test]
+fn test_nexthop_weights_scale_proportional() {
+    // 100G, 40G and 10G links.
+    let weights = [Some(100_000), Some(40_000), Some(10_000)];
+    assert_eq!(nexthop_weights_scale(&weights), [256, 103, 26]);
+}
+
+#[test]
+fn test_nexthop_weights_scale_equal() {
+    let weights = [Some(7), Some(7)];
+    assert_eq!(nexthop_weights_scale(&weights), [256, 256]);
+}
+
+#[test]
+fn test_nexthop_weights_scale_small() {
+    // Weights below the range are scaled up, not truncated to zero.
+    let weights = [Some(1), Some(2), Some(4)];
+    assert_eq!(nexthop_weights_scale(&weights), [64, 128, 256]);
+}
+
+#[test]
+fn test_nexthop_weights_scale_tiny() {
+    // The ratio is too big to be represented, so the lowest weight is used.
+    let weights = [Some(u32::MAX), Some(1)];
+    assert_eq!(nexthop_weights_scale(&weights), [256, 1]);
+}
+
+#[test]
+fn test_nexthop_weights_scale_unweighted() {
+    // Nexthops without weight are assigned the lowest weight.
+    let weights = [Some(1000), None];
+    assert_eq!(nexthop_weights_scale(&weights), [256, 1]);
+
+    // Without any weight, all nexthops are treated equally.
+    let weights = [None, None, None];
+    assert_eq!(nexthop_weights_scale(&weights), [1, 1, 1]);
+}
@@ -0,0 +1,162 @@
+//
+// Copyright (c) The Holo Core Contributors
+//
+// SPDX-License-Identifier: MIT
+//
+
+// These tests need real sockets, which aren't available when the "testing"
+// feature is enabled (e.g. when building the whole workspace).
+#![cfg(not(feature = "testing"))]
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::time::Duration;
+
+use chrono::DateTime;
+use holo_utils::crypto::CryptoAlgo;
+use holo_utils::keychain::{Key, Keychain, KeychainKey};
+use holo_utils::socket::{TcpSocket, TcpSocketExt, TcpStreamExt};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+const LOOPBACK: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+fn keychain(keys: &[(u64, CryptoAlgo, &str)]) -> Keychain {
+    let mut keychain = Keychain::new("test".to_owned());
+    for (id, algo, string) in keys {
+        let key = Key::new(*id, *algo, string.as_bytes().to_vec());
+        keychain.keys.insert(*id, KeychainKey::new(key));
+    }
+    keychain
+}
+
+// Connects two loopback TCP sockets protected by the given key-chains, and
+// returns whether data could be exchanged over the connection.
+async fn loopback_exchange(server: &Keychain, client: &Keychain) -> bool {
+    // Listening socket.
+    let socket = TcpSocket::new_v4().unwrap();
+    socket.set_tcp_ao_keychain(&LOOPBACK, Some(server)).unwrap();
+    socket.bind(SocketAddr::from((LOOPBACK, 0))).unwrap();
+    let addr = socket.local_addr().unwrap();
+    let listener = socket.listen(1).unwrap();
+
+    // Client socket.
+    let socket = TcpSocket::new_v4().unwrap();
+    socket.set_tcp_ao_keychain(&LOOPBACK, Some(client)).unwrap();
+
+    let exchange = async {
+        let (mut client, (mut server, _)) =
+            tokio::try_join!(socket.connect(addr), listener.accept())?;
+        client.write_all(b"holo").await?;
+        let mut buf = [0; 4];
+        server.read_exact(&mut buf).await?;
+        Ok::<_, std::io::Error>(&buf == b"holo")
+    };
+    matches!(
+        tokio::time::timeout(Duration::from_secs(2), exchange).await,
+        Ok(Ok(true))
+    )
+}
+
+// NOTE: the tests below require a kernel built with TCP-AO support
+// (CONFIG_TCP_AO). Run them with `cargo test -- --ignored`.
+
+#[tokio::test]
+#[ignore = "requires kernel TCP-AO support"]
+async fn tcp_ao_matching_keys() {
+    for algo in [CryptoAlgo::HmacSha1, CryptoAlgo::Aes128Cmac] {
+        let keychain = keychain(&[(1, algo, "HOLO")]);
+        assert!(loopback_exchange(&keychain, &keychain).await);
+    }
+}
+
+#[tokio::test]
+#[ignore = "requires kernel TCP-AO support"]
+async fn tcp_ao_mismatched_keys() {
+    let server = keychain(&[(1, CryptoAlgo::HmacSha1, "HOLO")]);
+    let client = keychain(&[(1, CryptoAlgo::HmacSha1, "OLOH")]);
+    assert!(!loopback_exchange(&server, &client).await);
+}
+
+#[tokio::test]
+#[ignore = "requires kernel TCP-AO support"]
+async fn tcp_ao_key_rollover() {
+    // The server accepts both the old and the new key, while the client
+    // already sends with the new one.
+    let server = keychain(&[
+        (1, CryptoAlgo::HmacSha1, "OLD"),
+        (2, CryptoAlgo::HmacSha1, "NEW"),
+    ]);
+    let client = keychain(&[(2, CryptoAlgo::HmacSha1, "NEW")]);
+    assert!(loopback_exchange(&server, &client).await);
+}
+
+#[tokio::test]
+#[ignore = "requires kernel TCP-AO support"]
+async fn tcp_ao_connected_key_rollover() {
+    let old = keychain(&[(1, CryptoAlgo::HmacSha1, "OLD")]);
+    let new = keychain(&[(2, CryptoAlgo::HmacSha1, "NEW")]);
+    let mut both = keychain(&[
+        (1, CryptoAlgo::HmacSha1, "OLD"),
+        (2, CryptoAlgo::HmacSha1, "NEW"),
+    ]);
+    // Key 1 is accepted but no longer used for sending.
+    let expired = DateTime::parse_from_rfc3339("2000-01-01T00:00:00Z").unwrap();
+    both.keys.get_mut(&1).unwrap().send_lifetime.end = Some(expired);
+
+    // Listening socket.
+    let socket = TcpSocket::new_v4().unwrap();
+    socket.set_tcp_ao_keychain(&LOOPBACK, Some(&old)).unwrap();
+    socket.bind(SocketAddr::from((LOOPBACK, 0))).unwrap();
+    let addr = socket.local_addr().unwrap();
+    let listener = socket.listen(1).unwrap();
+
+    // Client socket.
+    let socket = TcpSocket::new_v4().unwrap();
+    socket.set_tcp_ao_keychain(&LOOPBACK, Some(&old)).unwrap();
+    let (mut client, (mut server, _)) =
+        tokio::try_join!(socket.connect(addr), listener.accept()).unwrap();
+
+    // Roll over the keys of the established connection in two steps, as done
+    // when crossing key lifetime boundaries.
+    for keychain in [&both, &new] {
+        for stream in [&client, &server] {
+            let socket = stream.try_clone_socket().unwrap();
+            socket
+                .set_tcp_ao_keychain(&LOOPBACK, Some(keychain))
+                .unwrap();
+        }
+
+        let exchange = async {
+            client.write_all(b"holo").await?;
+            let mut buf = [0; 4];
+            server.read_exact(&mut buf).await?;
+            Ok::<_, std::io::Error>(&buf == b"holo")
+        };
+        assert!(matches!(
+            tokio::time::timeout(Duration::from_secs(2), exchange).await,
+            Ok(Ok(true))
+        ));
+    }
+
+    let socket = client.try_clone_socket().unwrap();
+    assert!(socket.tcp_ao_get_keys(&LOOPBACK).unwrap().contains(&(2, 2)));
+}
+
+#[tokio::test]
+#[ignore = "requires kernel TCP-AO support"]
+async fn tcp_ao_keys_replace() {
+    let socket = TcpSocket::new_v4().unwrap();
+    let old = keychain(&[
+        (1, CryptoAlgo::HmacSha1, "HOLO"),
+        (2, CryptoAlgo::HmacSha1, "HOLO"),
+    ]);
+    let new = keychain(&[(3, CryptoAlgo::HmacSha256, "HOLO")]);
+
+    socket.set_tcp_ao_keychain(&LOOPBACK, Some(&old)).unwrap();
+    assert_eq!(socket.tcp_ao_get_keys(&LOOPBACK).unwrap().len(), 2);
+
+    socket.set_tcp_ao_keychain(&LOOPBACK, Some(&new)).unwrap();
+    assert_eq!(socket.tcp_ao_get_keys(&LOOPBACK).unwrap(), vec![(3, 3)]);
+
+    socket.set_tcp_ao_keychain(&LOOPBACK, None).unwrap();
+    assert!(socket.tcp_ao_get_keys(&LOOPBACK).unwrap().is_empty());
+}
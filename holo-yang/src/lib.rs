@@ -88,6 +88,8 @@ pub static YANG_EMBEDDED_MODULES: Lazy<EmbeddedModules> = Lazy::new(|| {
             include_str!("../modules/ietf/ietf-mpls@2020-12-18.yang"),
         EmbeddedModuleKey::new("ietf-mpls-ldp", Some("2022-03-14"), None, None) =>
             include_str!("../modules/ietf/ietf-mpls-ldp@2022-03-14.yang"),
+        EmbeddedModuleKey::new("ietf-mpls-ldp-extended", Some("2022-03-14"), None, None) =>
+            include_str!("../modules/ietf/ietf-mpls-ldp-extended@2022-03-14.yang"),
         EmbeddedModuleKey::new("ietf-netconf-acm", Some("2018-02-14"), None, None) =>
             include_str!("../modules/ietf/ietf-netconf-acm@2018-02-14.yang"),
         EmbeddedModuleKey::new("ietf-ospf", Some("2022-10-19"), None, None) =>
@@ -117,15 +119,21 @@ pub static YANG_EMBEDDED_MODULES: Lazy<EmbeddedModules> = Lazy::new(|| {
         // IETF Holo augmentations
         EmbeddedModuleKey::new("holo-bgp", None, None, None) =>
             include_str!("../modules/augmentations/holo-bgp.yang"),
+        EmbeddedModuleKey::new("holo-mpls-ldp", None, None, None) =>
+            include_str!("../modules/augmentations/holo-mpls-ldp.yang"),
         EmbeddedModuleKey::new("holo-ospf", None, None, None) =>
             include_str!("../modules/augmentations/holo-ospf.yang"),
         EmbeddedModuleKey::new("holo-ospf-dev", None, None, None) =>
             include_str!("../modules/augmentations/holo-ospf-dev.yang"),
+        EmbeddedModuleKey::new("holo-routing", None, None, None) =>
+            include_str!("../modules/augmentations/holo-routing.yang"),
         // IETF Holo deviations
         EmbeddedModuleKey::new("ietf-bgp-holo-deviations", None, None, None) =>
             include_str!("../modules/deviations/ietf-bgp-holo-deviations.yang"),
         EmbeddedModuleKey::new("ietf-mpls-ldp-holo-deviations", None, None, None) =>
             include_str!("../modules/deviations/ietf-mpls-ldp-holo-deviations.yang"),
+        EmbeddedModuleKey::new("ietf-mpls-ldp-extended-holo-deviations", None, None, None) =>
+            include_str!("../modules/deviations/ietf-mpls-ldp-extended-holo-deviations.yang"),
         EmbeddedModuleKey::new("ietf-interfaces-holo-deviations", None, None, None) =>
             include_str!("../modules/deviations/ietf-interfaces-holo-deviations.yang"),
         EmbeddedModuleKey::new("ietf-ip-holo-deviations", None, None, None) =>
@@ -182,14 +190,17 @@ pub static YANG_IMPLEMENTED_MODULES: Lazy<Vec<&'static str>> =
             "ietf-segment-routing-mpls",
             "ietf-mpls",
             "ietf-mpls-ldp",
+            "ietf-mpls-ldp-extended",
             "ietf-ospf",
             "ietf-ospf-sr-mpls",
             "ietf-ospfv3-extended-lsa",
             "ietf-rip",
             "ietf-tcp",
             "holo-bgp",
+            "holo-mpls-ldp",
             "holo-ospf",
             "holo-ospf-dev",
+            "holo-routing",
         ]
     });
 
@@ -198,6 +209,7 @@ pub static YANG_FEATURES: Lazy<HashMap<&'static str, Vec<&'static str>>> =
     Lazy::new(|| {
         hashmap! {
             "iana-bgp-types" => vec![
+                "clear-neighbors",
                 "route-refresh",
                 "ttl-security",
             ],
@@ -209,16 +221,33 @@ pub static YANG_FEATURES: Lazy<HashMap<&'static str, Vec<&'static str>>> =
                 "hex-key-string",
                 "independent-send-accept-lifetime",
             ],
+            "ietf-mpls-ldp-extended" => vec![
+                "key-chain",
+                "peers-dual-stack-transport-preference",
+                "policy-label-assignment-config",
+            ],
             "ietf-ospf" => vec![
                 "bfd",
                 "explicit-router-id",
+                "fast-reroute",
                 "graceful-restart",
                 "ietf-spf-delay",
                 "key-chain",
+                "ldp-igp-sync",
+                "lfa",
                 "max-ecmp",
+                "max-lsa",
                 "mtu-ignore",
+                "multi-area-adj",
+                "node-tag",
                 "ospfv3-authentication-trailer",
+                "prefix-suppression",
+                "remote-lfa",
                 "stub-router",
+                "te-rid",
+            ],
+            "ietf-ospf-sr-mpls" => vec![
+                "ti-lfa",
             ],
             "ietf-rip" => vec![
                 "explicit-neighbors",